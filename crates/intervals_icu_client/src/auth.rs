@@ -0,0 +1,262 @@
+//! Upstream authentication for
+//! [`ReqwestIntervalsClient`](crate::http_client::ReqwestIntervalsClient).
+//!
+//! Intervals.icu accepts either a personal API key, sent as HTTP Basic with the
+//! literal user name `API_KEY`, or an OAuth access token sent as a `Bearer`
+//! token. OAuth tokens may expire: with a [`TokenRefresher`] the client renews
+//! a token shortly before its expiry and once after a `401` response.
+
+use crate::{ConfigError, IntervalsError, Result};
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderValue;
+use secrecy::{ExposeSecret, SecretString};
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// Tokens are refreshed this long before their recorded expiry.
+const REFRESH_MARGIN_SECS: i64 = 60;
+
+/// Credentials used to authenticate against the Intervals.icu API.
+#[derive(Clone, Debug)]
+pub enum Credentials {
+    /// Personal API key from the Intervals.icu settings page.
+    ApiKey(SecretString),
+    /// Access token issued to an Intervals.icu OAuth application.
+    OAuth(OAuthToken),
+}
+
+impl Credentials {
+    pub fn api_key(key: impl Into<String>) -> Self {
+        Self::ApiKey(SecretString::new(key.into().into()))
+    }
+
+    /// OAuth access token without refresh metadata.
+    pub fn bearer(access_token: impl Into<String>) -> Self {
+        Self::OAuth(OAuthToken::new(SecretString::new(
+            access_token.into().into(),
+        )))
+    }
+
+    /// `"api_key"` or `"oauth"`, for logs and metrics.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ApiKey(_) => "api_key",
+            Self::OAuth(_) => "oauth",
+        }
+    }
+
+    /// The secret sent upstream: the API key or the access token.
+    #[must_use]
+    pub fn secret(&self) -> &SecretString {
+        match self {
+            Self::ApiKey(key) => key,
+            Self::OAuth(token) => &token.access_token,
+        }
+    }
+
+    /// The `Authorization` header value for these credentials.
+    ///
+    /// # Errors
+    /// Returns `ConfigError::Other` if an access token contains characters
+    /// that are not allowed in an HTTP header.
+    pub fn authorization_header(&self) -> Result<HeaderValue> {
+        let value = match self {
+            Self::ApiKey(key) => format!(
+                "Basic {}",
+                STANDARD.encode(format!("API_KEY:{}", key.expose_secret()))
+            ),
+            Self::OAuth(token) => format!("Bearer {}", token.access_token.expose_secret()),
+        };
+        let mut header = HeaderValue::from_str(&value).map_err(|_| {
+            IntervalsError::Config(ConfigError::Other(
+                "credentials contain characters not allowed in an HTTP header".to_string(),
+            ))
+        })?;
+        header.set_sensitive(true);
+        Ok(header)
+    }
+}
+
+impl From<SecretString> for Credentials {
+    fn from(api_key: SecretString) -> Self {
+        Self::ApiKey(api_key)
+    }
+}
+
+impl From<OAuthToken> for Credentials {
+    fn from(token: OAuthToken) -> Self {
+        Self::OAuth(token)
+    }
+}
+
+/// An OAuth access token with optional refresh metadata.
+#[derive(Clone, Debug)]
+pub struct OAuthToken {
+    pub access_token: SecretString,
+    pub refresh_token: Option<SecretString>,
+    /// When the access token stops being accepted, if known.
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl OAuthToken {
+    pub fn new(access_token: SecretString) -> Self {
+        Self {
+            access_token,
+            refresh_token: None,
+            expires_at: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_refresh_token(mut self, refresh_token: SecretString) -> Self {
+        self.refresh_token = Some(refresh_token);
+        self
+    }
+
+    #[must_use]
+    pub fn with_expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Whether the token expires within `margin` of `now`. Tokens without an
+    /// expiry never do.
+    #[must_use]
+    pub fn expires_within(&self, margin: chrono::Duration, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expiry| expiry - margin <= now)
+    }
+}
+
+/// Obtains a new OAuth token, typically by exchanging the refresh token at the
+/// OAuth application's token endpoint.
+#[async_trait::async_trait]
+pub trait TokenRefresher: Send + Sync + 'static {
+    async fn refresh(&self, current: &OAuthToken) -> Result<OAuthToken>;
+}
+
+/// Credentials shared by clones of one client, refreshed in place.
+pub(crate) struct AuthState {
+    credentials: RwLock<Credentials>,
+    refresher: Option<Arc<dyn TokenRefresher>>,
+    /// Serialises refreshes so concurrent requests trigger only one.
+    refresh_lock: tokio::sync::Mutex<()>,
+}
+
+impl AuthState {
+    pub(crate) fn new(
+        credentials: Credentials,
+        refresher: Option<Arc<dyn TokenRefresher>>,
+    ) -> Self {
+        Self {
+            credentials: RwLock::new(credentials),
+            refresher,
+            refresh_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    pub(crate) fn current(&self) -> Credentials {
+        self.credentials
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub(crate) fn header(&self) -> Result<HeaderValue> {
+        self.current().authorization_header()
+    }
+
+    /// Whether a rejected request may be replayed after a refresh.
+    pub(crate) fn can_refresh(&self) -> bool {
+        self.refresher.is_some() && matches!(self.current(), Credentials::OAuth(_))
+    }
+
+    async fn refresh(&self, token: &OAuthToken) -> Result<()> {
+        let Some(refresher) = &self.refresher else {
+            return Ok(());
+        };
+        let refreshed = refresher.refresh(token).await?;
+        tracing::debug!("refreshed upstream OAuth access token");
+        *self
+            .credentials
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Credentials::OAuth(refreshed);
+        Ok(())
+    }
+
+    /// Refresh an OAuth token that is about to expire.
+    pub(crate) async fn refresh_if_expiring(&self) -> Result<()> {
+        let margin = chrono::Duration::seconds(REFRESH_MARGIN_SECS);
+        let expiring = |credentials: &Credentials| match credentials {
+            Credentials::OAuth(token) if token.expires_within(margin, Utc::now()) => {
+                Some(token.clone())
+            }
+            _ => None,
+        };
+        if self.refresher.is_none() || expiring(&self.current()).is_none() {
+            return Ok(());
+        }
+        let _guard = self.refresh_lock.lock().await;
+        match expiring(&self.current()) {
+            Some(token) => self.refresh(&token).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Refresh after the API rejected `rejected`. Returns `true` when a
+    /// different token is now available, including one refreshed concurrently.
+    pub(crate) async fn refresh_after_unauthorized(&self, rejected: &HeaderValue) -> Result<bool> {
+        let _guard = self.refresh_lock.lock().await;
+        let Credentials::OAuth(token) = self.current() else {
+            return Ok(false);
+        };
+        if Credentials::OAuth(token.clone()).authorization_header()? != *rejected {
+            return Ok(true);
+        }
+        self.refresh(&token).await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_key_uses_basic_auth_with_fixed_user() {
+        let header = Credentials::api_key("secret")
+            .authorization_header()
+            .expect("header");
+        assert_eq!(
+            header.to_str().expect("ascii"),
+            format!("Basic {}", STANDARD.encode("API_KEY:secret"))
+        );
+        assert!(header.is_sensitive());
+    }
+
+    #[test]
+    fn oauth_uses_bearer_and_rejects_header_unsafe_tokens() {
+        let header = Credentials::bearer("tok-123")
+            .authorization_header()
+            .expect("header");
+        assert_eq!(header.to_str().expect("ascii"), "Bearer tok-123");
+        assert!(
+            Credentials::bearer("bad\ntoken")
+                .authorization_header()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn expiry_margin() {
+        let now = Utc::now();
+        let token = OAuthToken::new(SecretString::new("t".into()))
+            .with_expires_at(now + chrono::Duration::seconds(30));
+        assert!(token.expires_within(chrono::Duration::seconds(60), now));
+        assert!(!token.expires_within(chrono::Duration::seconds(10), now));
+        assert!(
+            !OAuthToken::new(SecretString::new("t".into()))
+                .expires_within(chrono::Duration::seconds(60), now)
+        );
+    }
+}
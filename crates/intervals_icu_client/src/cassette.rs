@@ -0,0 +1,498 @@
+//! Record/replay cassettes for [`ReqwestIntervalsClient`](crate::http_client::ReqwestIntervalsClient).
+//!
+//! In [`CassetteMode::Record`] every upstream request and its final response
+//! are appended to a JSON cassette file. Credentials are never written: request
+//! headers are not recorded, the API key or access token is replaced with
+//! [`REDACTED`] wherever it appears, and the athlete id in paths, query values
+//! and JSON strings is replaced with [`ATHLETE_ID_PLACEHOLDER`].
+//!
+//! In [`CassetteMode::Replay`] responses are served from the cassette without
+//! touching the network. The placeholder is substituted with the replaying
+//! client's athlete id, so a cassette recorded for one athlete replays for any.
+//!
+//! ```no_run
+//! # use intervals_icu_client::cassette::{Cassette, MatchMode};
+//! # use intervals_icu_client::http_client::ReqwestIntervalsClient;
+//! # use secrecy::SecretString;
+//! let cassette = Cassette::replay("tests/fixtures/cassettes/athlete_reads.json", MatchMode::Lenient)?;
+//! let client = ReqwestIntervalsClient::builder(
+//!     "https://intervals.icu",
+//!     "i12345",
+//!     SecretString::new("unused".into()),
+//! )
+//! .cassette(cassette)
+//! .build()?;
+//! # Ok::<(), intervals_icu_client::IntervalsError>(())
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{ConfigError, IntervalsError, Result};
+
+/// Stands in for the athlete id in recorded interactions.
+pub const ATHLETE_ID_PLACEHOLDER: &str = "{athlete_id}";
+/// Replaces credentials in recorded interactions.
+pub const REDACTED: &str = "[REDACTED]";
+/// Response headers kept in recordings; the rest are dropped.
+const RECORDED_HEADERS: [&str; 2] = ["content-type", "content-disposition"];
+/// Query parameters that carry credentials and are always redacted.
+const SECRET_QUERY_PARAMS: [&str; 3] = ["api_key", "access_token", "token"];
+
+/// Whether a cassette captures live traffic or serves it back.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CassetteMode {
+    Record,
+    Replay(MatchMode),
+}
+
+/// How replayed requests are matched against recorded ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MatchMode {
+    /// Requests must arrive in recorded order with the same method, path,
+    /// query and body.
+    #[default]
+    Strict,
+    /// The first unused interaction with the same method and path is served,
+    /// in any order; query and body are ignored. Once all such interactions
+    /// are used, the last one is served again.
+    Lenient,
+}
+
+/// A recorded request body or response body.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CassetteBody {
+    Json(Value),
+    Text(String),
+    /// Binary payloads such as FIT files, base64-encoded.
+    Base64(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// URL path including the `/api/v1` prefix.
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub query: Vec<(String, String)>,
+    /// `None` for requests without a body and for streamed (multipart) bodies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<CassetteBody>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<CassetteBody>,
+}
+
+/// One request/response pair.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Default)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    used: Vec<bool>,
+}
+
+/// A cassette file bound to a record or replay mode.
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Mutex<CassetteState>,
+}
+
+impl std::fmt::Debug for Cassette {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cassette")
+            .field("path", &self.path)
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
+fn cassette_error(message: String) -> IntervalsError {
+    IntervalsError::Config(ConfigError::Other(message))
+}
+
+impl Cassette {
+    /// Record into `path`. The file is rewritten after every interaction, so
+    /// it is complete even if the process exits without dropping the client.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            mode: CassetteMode::Record,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Replay the cassette at `path`.
+    ///
+    /// # Errors
+    /// Returns `ConfigError::Other` if the file cannot be read or parsed.
+    pub fn replay(path: impl Into<PathBuf>, matching: MatchMode) -> Result<Self> {
+        let path = path.into();
+        let raw = std::fs::read_to_string(&path).map_err(|e| {
+            cassette_error(format!("failed to read cassette {}: {e}", path.display()))
+        })?;
+        let file: CassetteFile = serde_json::from_str(&raw)
+            .map_err(|e| cassette_error(format!("invalid cassette {}: {e}", path.display())))?;
+        let used = vec![false; file.interactions.len()];
+        Ok(Self {
+            path,
+            mode: CassetteMode::Replay(matching),
+            state: Mutex::new(CassetteState {
+                interactions: file.interactions,
+                used,
+            }),
+        })
+    }
+
+    #[must_use]
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Recorded interactions, scrubbed as they are stored on disk.
+    #[must_use]
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.lock().interactions.clone()
+    }
+
+    /// Interactions not yet served in replay mode.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.lock().used.iter().filter(|used| !**used).count()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CassetteState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn is_replay(&self) -> bool {
+        matches!(self.mode, CassetteMode::Replay(_))
+    }
+
+    /// Serve the recorded response for `request`.
+    pub(crate) fn replay_response(
+        &self,
+        request: &RecordedRequest,
+        scrubber: &Scrubber<'_>,
+    ) -> Result<reqwest::Response> {
+        let CassetteMode::Replay(matching) = self.mode else {
+            return Err(cassette_error("cassette is not in replay mode".to_string()));
+        };
+        let mut state = self.lock();
+        let index = match matching {
+            MatchMode::Strict => {
+                let next = state.used.iter().position(|used| !used);
+                match next {
+                    Some(index) if state.interactions[index].request == *request => Some(index),
+                    Some(index) => {
+                        let expected = &state.interactions[index].request;
+                        return Err(cassette_error(format!(
+                            "cassette {} expected {} {} as interaction {index}, got {} {}",
+                            self.path.display(),
+                            expected.method,
+                            expected.path,
+                            request.method,
+                            request.path
+                        )));
+                    }
+                    None => None,
+                }
+            }
+            MatchMode::Lenient => {
+                let same_route = |interaction: &Interaction| {
+                    interaction.request.method == request.method
+                        && interaction.request.path == request.path
+                };
+                state
+                    .interactions
+                    .iter()
+                    .zip(&state.used)
+                    .position(|(interaction, used)| !used && same_route(interaction))
+                    .or_else(|| state.interactions.iter().rposition(same_route))
+            }
+        };
+        let Some(index) = index else {
+            return Err(cassette_error(format!(
+                "cassette {} has no interaction for {} {}",
+                self.path.display(),
+                request.method,
+                request.path
+            )));
+        };
+        state.used[index] = true;
+        let response = state.interactions[index].response.clone();
+        drop(state);
+        build_response(&response, scrubber)
+    }
+
+    /// Append a scrubbed interaction and hand back an equivalent response
+    /// with the original, unscrubbed body.
+    pub(crate) async fn record_response(
+        &self,
+        request: RecordedRequest,
+        response: reqwest::Response,
+        scrubber: &Scrubber<'_>,
+    ) -> Result<reqwest::Response> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = response.bytes().await?;
+
+        let recorded = RecordedResponse {
+            status: status.as_u16(),
+            headers: RECORDED_HEADERS
+                .iter()
+                .filter_map(|name| {
+                    let value = headers.get(*name)?.to_str().ok()?;
+                    Some(((*name).to_string(), scrubber.scrub_text(value)))
+                })
+                .collect(),
+            body: scrubber.scrub_body(&bytes),
+        };
+        {
+            let mut state = self.lock();
+            state.interactions.push(Interaction {
+                request,
+                response: recorded,
+            });
+            state.used.push(true);
+            self.save(&state.interactions)?;
+        }
+
+        let mut builder = http::Response::builder().status(status);
+        for (name, value) in &headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(bytes)
+            .map_err(|e| cassette_error(format!("failed to rebuild recorded response: {e}")))?;
+        Ok(reqwest::Response::from(response))
+    }
+
+    fn save(&self, interactions: &[Interaction]) -> Result<()> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                cassette_error(format!("failed to create {}: {e}", parent.display()))
+            })?;
+        }
+        let file = CassetteFile {
+            interactions: interactions.to_vec(),
+        };
+        let json = serde_json::to_string_pretty(&file)?;
+        std::fs::write(&self.path, json + "\n").map_err(|e| {
+            cassette_error(format!(
+                "failed to write cassette {}: {e}",
+                self.path.display()
+            ))
+        })
+    }
+}
+
+fn build_response(
+    recorded: &RecordedResponse,
+    scrubber: &Scrubber<'_>,
+) -> Result<reqwest::Response> {
+    let body = match &recorded.body {
+        None => Vec::new(),
+        Some(CassetteBody::Json(value)) => serde_json::to_vec(&scrubber.restore_json(value))?,
+        Some(CassetteBody::Text(text)) => scrubber.restore_text(text).into_bytes(),
+        Some(CassetteBody::Base64(encoded)) => STANDARD
+            .decode(encoded)
+            .map_err(|e| cassette_error(format!("invalid base64 body in cassette: {e}")))?,
+    };
+    let mut builder = http::Response::builder().status(recorded.status);
+    for (name, value) in &recorded.headers {
+        builder = builder.header(name, value);
+    }
+    let response = builder
+        .body(body)
+        .map_err(|e| cassette_error(format!("invalid recorded response: {e}")))?;
+    Ok(reqwest::Response::from(response))
+}
+
+/// Removes the athlete id and credentials from recorded data and puts the
+/// athlete id back on replay.
+pub(crate) struct Scrubber<'a> {
+    pub(crate) athlete_id: &'a str,
+    pub(crate) secret: &'a str,
+}
+
+impl Scrubber<'_> {
+    /// Describe `request` as it is stored in a cassette.
+    pub(crate) fn request(&self, request: &reqwest::Request) -> RecordedRequest {
+        let url = request.url();
+        let path = url
+            .path()
+            .split('/')
+            .map(|segment| self.scrub_id(segment))
+            .collect::<Vec<_>>()
+            .join("/");
+        let query = url
+            .query_pairs()
+            .map(|(key, value)| {
+                let value = if SECRET_QUERY_PARAMS.contains(&key.as_ref()) {
+                    REDACTED.to_string()
+                } else {
+                    self.scrub_id(&value)
+                };
+                (key.into_owned(), value)
+            })
+            .collect();
+        RecordedRequest {
+            method: request.method().to_string(),
+            path,
+            query,
+            body: request
+                .body()
+                .and_then(reqwest::Body::as_bytes)
+                .and_then(|bytes| self.scrub_body(bytes)),
+        }
+    }
+
+    fn scrub_id(&self, value: &str) -> String {
+        if !self.athlete_id.is_empty() && value == self.athlete_id {
+            ATHLETE_ID_PLACEHOLDER.to_string()
+        } else {
+            self.scrub_text(value)
+        }
+    }
+
+    fn scrub_text(&self, text: &str) -> String {
+        if self.secret.is_empty() {
+            text.to_string()
+        } else {
+            text.replace(self.secret, REDACTED)
+        }
+    }
+
+    fn scrub_body(&self, bytes: &[u8]) -> Option<CassetteBody> {
+        if bytes.is_empty() {
+            return None;
+        }
+        if let Ok(value) = serde_json::from_slice::<Value>(bytes) {
+            return Some(CassetteBody::Json(
+                self.map_strings(value, &|s| self.scrub_id(s)),
+            ));
+        }
+        Some(match std::str::from_utf8(bytes) {
+            Ok(text) => CassetteBody::Text(self.scrub_text(text)),
+            Err(_) => CassetteBody::Base64(STANDARD.encode(bytes)),
+        })
+    }
+
+    fn restore_json(&self, value: &Value) -> Value {
+        self.map_strings(value.clone(), &|s| {
+            if s == ATHLETE_ID_PLACEHOLDER {
+                self.athlete_id.to_string()
+            } else {
+                s.to_string()
+            }
+        })
+    }
+
+    fn restore_text(&self, text: &str) -> String {
+        text.replace(ATHLETE_ID_PLACEHOLDER, self.athlete_id)
+    }
+
+    fn map_strings(&self, value: Value, f: &dyn Fn(&str) -> String) -> Value {
+        match value {
+            Value::String(s) => Value::String(f(&s)),
+            Value::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .map(|item| self.map_strings(item, f))
+                    .collect(),
+            ),
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(key, item)| (key, self.map_strings(item, f)))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SCRUBBER: Scrubber<'static> = Scrubber {
+        athlete_id: "i98765",
+        secret: "sekrit-key",
+    };
+
+    #[test]
+    fn scrubs_athlete_id_and_secret_from_requests() {
+        let request = reqwest::Client::new()
+            .put("https://intervals.icu/api/v1/athlete/i98765/wellness/2026-01-02")
+            .query(&[("athlete", "i98765"), ("api_key", "anything")])
+            .json(&json!({"id": "i98765", "note": "key sekrit-key", "n": 98765}))
+            .build()
+            .unwrap();
+
+        let recorded = SCRUBBER.request(&request);
+        assert_eq!(recorded.method, "PUT");
+        assert_eq!(
+            recorded.path,
+            "/api/v1/athlete/{athlete_id}/wellness/2026-01-02"
+        );
+        assert_eq!(
+            recorded.query,
+            [
+                ("athlete".to_string(), "{athlete_id}".to_string()),
+                ("api_key".to_string(), "[REDACTED]".to_string()),
+            ]
+        );
+        assert_eq!(
+            recorded.body,
+            Some(CassetteBody::Json(
+                json!({"id": "{athlete_id}", "note": "key [REDACTED]", "n": 98765})
+            ))
+        );
+    }
+
+    #[test]
+    fn keeps_binary_bodies_as_base64_and_restores_athlete_id() {
+        assert_eq!(
+            SCRUBBER.scrub_body(&[0x0e, 0xff, 0x00]),
+            Some(CassetteBody::Base64("Dv8A".to_string()))
+        );
+        let restore = Scrubber {
+            athlete_id: "i1",
+            secret: "",
+        };
+        assert_eq!(
+            restore.restore_json(&json!({"athlete": {"id": "{athlete_id}"}})),
+            json!({"athlete": {"id": "i1"}})
+        );
+        assert_eq!(restore.restore_text("id={athlete_id}"), "id=i1");
+    }
+}
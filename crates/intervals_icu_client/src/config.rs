@@ -1,11 +1,192 @@
 use crate::error::{ConfigError, IntervalsError, Result};
+use crate::rate_limit::RateLimitSettings;
 use secrecy::SecretString;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub api_key: SecretString,
     pub athlete_id: String,
     pub base_url: String,
+    /// Transport and resilience settings for the HTTP client.
+    pub http: HttpSettings,
+}
+
+/// Transport and resilience settings applied by
+/// [`ReqwestIntervalsClientBuilder`](crate::http_client::ReqwestIntervalsClientBuilder).
+///
+/// Every field can be set from an `INTERVALS_ICU_*` environment variable; see
+/// [`HttpSettings::from_env_with`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpSettings {
+    /// Total time allowed for one request, `None` for no limit.
+    pub request_timeout: Option<Duration>,
+    /// Time allowed to establish a connection, `None` for no limit.
+    pub connect_timeout: Option<Duration>,
+    /// How long idle pooled connections are kept, `None` to keep them indefinitely.
+    pub pool_idle_timeout: Option<Duration>,
+    /// Maximum idle connections kept per host, `None` for no limit.
+    pub pool_max_idle_per_host: Option<usize>,
+    pub user_agent: String,
+    /// Proxy URL for all requests. When unset, the system proxy variables apply.
+    pub proxy: Option<String>,
+    /// Extra PEM root certificates to trust.
+    pub root_certificates: Vec<PathBuf>,
+    /// Retries for idempotent requests after transport errors or 429/502/503/504.
+    pub retry_max: u32,
+    pub retry_base_delay: Duration,
+    /// Consecutive transport failures before the circuit breaker opens.
+    pub circuit_failure_threshold: u32,
+    pub circuit_reset_timeout: Duration,
+    /// Client-side token buckets for upstream calls, `None` for no limit.
+    pub rate_limit: Option<RateLimitSettings>,
+}
+
+impl Default for HttpSettings {
+    fn default() -> Self {
+        Self {
+            request_timeout: Some(Duration::from_secs(120)),
+            connect_timeout: Some(Duration::from_secs(10)),
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            pool_max_idle_per_host: None,
+            user_agent: concat!("intervals_icu_client/", env!("CARGO_PKG_VERSION")).to_string(),
+            proxy: None,
+            root_certificates: Vec::new(),
+            retry_max: 0,
+            retry_base_delay: Duration::from_millis(100),
+            circuit_failure_threshold: 5,
+            circuit_reset_timeout: Duration::from_secs(30),
+            rate_limit: Some(RateLimitSettings::default()),
+        }
+    }
+}
+
+fn parse_env<T: FromStr, F>(get: &mut F, key: &str) -> Result<Option<T>>
+where
+    F: FnMut(&str) -> Option<String>,
+    T::Err: std::fmt::Display,
+{
+    get(key)
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            value.parse::<T>().map_err(|error| {
+                IntervalsError::Config(ConfigError::InvalidValue {
+                    key: key.to_string(),
+                    message: format!("'{value}': {error}"),
+                })
+            })
+        })
+        .transpose()
+}
+
+/// Seconds from the environment; `0` disables the limit.
+fn parse_timeout<F>(get: &mut F, key: &str, default: Option<Duration>) -> Result<Option<Duration>>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    Ok(match parse_env::<u64, _>(get, key)? {
+        Some(0) => None,
+        Some(secs) => Some(Duration::from_secs(secs)),
+        None => default,
+    })
+}
+
+impl HttpSettings {
+    /// Read settings with the provided lookup, falling back to the defaults:
+    ///
+    /// | Variable | Meaning |
+    /// |---|---|
+    /// | `INTERVALS_ICU_TIMEOUT_SECS` | request timeout (`0` = none) |
+    /// | `INTERVALS_ICU_CONNECT_TIMEOUT_SECS` | connect timeout (`0` = none) |
+    /// | `INTERVALS_ICU_POOL_IDLE_TIMEOUT_SECS` | idle connection lifetime (`0` = none) |
+    /// | `INTERVALS_ICU_POOL_MAX_IDLE_PER_HOST` | idle connections per host |
+    /// | `INTERVALS_ICU_USER_AGENT` | `User-Agent` header |
+    /// | `INTERVALS_ICU_PROXY` | proxy URL for all requests |
+    /// | `INTERVALS_ICU_CA_CERTS` | comma-separated PEM root certificate paths |
+    /// | `INTERVALS_ICU_RETRY_MAX` | retries for idempotent requests |
+    /// | `INTERVALS_ICU_RETRY_BASE_DELAY_MS` | base backoff delay |
+    /// | `INTERVALS_ICU_CIRCUIT_FAILURE_THRESHOLD` | failures before the breaker opens |
+    /// | `INTERVALS_ICU_CIRCUIT_RESET_SECS` | breaker reset timeout |
+    /// | `INTERVALS_ICU_RATE_LIMIT_PER_MINUTE` | requests per minute per key (`0` = no limiter) |
+    /// | `INTERVALS_ICU_RATE_LIMIT_BURST` | burst per key |
+    /// | `INTERVALS_ICU_RATE_LIMIT_GLOBAL_PER_MINUTE` | requests per minute across keys |
+    /// | `INTERVALS_ICU_RATE_LIMIT_GLOBAL_BURST` | burst across keys |
+    ///
+    /// # Errors
+    /// Returns `ConfigError::InvalidValue` when a variable cannot be parsed.
+    pub fn from_env_with<F>(mut get: F) -> Result<Self>
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        let defaults = Self::default();
+        let non_empty = |value: Option<String>| value.filter(|value| !value.trim().is_empty());
+        Ok(Self {
+            request_timeout: parse_timeout(
+                &mut get,
+                "INTERVALS_ICU_TIMEOUT_SECS",
+                defaults.request_timeout,
+            )?,
+            connect_timeout: parse_timeout(
+                &mut get,
+                "INTERVALS_ICU_CONNECT_TIMEOUT_SECS",
+                defaults.connect_timeout,
+            )?,
+            pool_idle_timeout: parse_timeout(
+                &mut get,
+                "INTERVALS_ICU_POOL_IDLE_TIMEOUT_SECS",
+                defaults.pool_idle_timeout,
+            )?,
+            pool_max_idle_per_host: parse_env(&mut get, "INTERVALS_ICU_POOL_MAX_IDLE_PER_HOST")?,
+            user_agent: non_empty(get("INTERVALS_ICU_USER_AGENT")).unwrap_or(defaults.user_agent),
+            proxy: non_empty(get("INTERVALS_ICU_PROXY")),
+            root_certificates: get("INTERVALS_ICU_CA_CERTS")
+                .map(|paths| {
+                    paths
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|path| !path.is_empty())
+                        .map(PathBuf::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            retry_max: parse_env(&mut get, "INTERVALS_ICU_RETRY_MAX")?
+                .unwrap_or(defaults.retry_max),
+            retry_base_delay: parse_env(&mut get, "INTERVALS_ICU_RETRY_BASE_DELAY_MS")?
+                .map_or(defaults.retry_base_delay, Duration::from_millis),
+            circuit_failure_threshold: parse_env(
+                &mut get,
+                "INTERVALS_ICU_CIRCUIT_FAILURE_THRESHOLD",
+            )?
+            .unwrap_or(defaults.circuit_failure_threshold),
+            circuit_reset_timeout: parse_env(&mut get, "INTERVALS_ICU_CIRCUIT_RESET_SECS")?
+                .map_or(defaults.circuit_reset_timeout, Duration::from_secs),
+            rate_limit: parse_rate_limit(&mut get)?,
+        })
+    }
+}
+
+fn parse_rate_limit<F>(get: &mut F) -> Result<Option<RateLimitSettings>>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let defaults = RateLimitSettings::default();
+    let per_key_per_minute = parse_env(get, "INTERVALS_ICU_RATE_LIMIT_PER_MINUTE")?
+        .unwrap_or(defaults.per_key_per_minute);
+    if per_key_per_minute == 0 {
+        return Ok(None);
+    }
+    Ok(Some(RateLimitSettings {
+        per_key_per_minute,
+        per_key_burst: parse_env(get, "INTERVALS_ICU_RATE_LIMIT_BURST")?
+            .unwrap_or(defaults.per_key_burst),
+        global_per_minute: parse_env(get, "INTERVALS_ICU_RATE_LIMIT_GLOBAL_PER_MINUTE")?
+            .unwrap_or(defaults.global_per_minute),
+        global_burst: parse_env(get, "INTERVALS_ICU_RATE_LIMIT_GLOBAL_BURST")?
+            .unwrap_or(defaults.global_burst),
+    }))
 }
 
 impl Config {
@@ -22,7 +203,8 @@ impl Config {
     /// `from_env()` small and safe.
     ///
     /// # Errors
-    /// Returns `ConfigError::MissingEnvVar` if required variables are not set.
+    /// Returns `ConfigError::MissingEnvVar` if required variables are not set,
+    /// or `ConfigError::InvalidValue` if an [`HttpSettings`] variable is malformed.
     pub fn from_env_with<F>(mut get: F) -> Result<Self>
     where
         F: FnMut(&str) -> Option<String>,
@@ -39,10 +221,12 @@ impl Config {
         })?;
         let base_url =
             get("INTERVALS_ICU_BASE_URL").unwrap_or_else(|| "https://intervals.icu".into());
+        let http = HttpSettings::from_env_with(&mut get)?;
         Ok(Self {
             api_key: SecretString::new(api.into()),
             athlete_id,
             base_url,
+            http,
         })
     }
 }
@@ -103,5 +287,86 @@ mod tests {
         };
         let cfg = Config::from_env_with(get).expect("cfg");
         assert_eq!(cfg.base_url, "https://intervals.icu");
+        assert_eq!(cfg.http, HttpSettings::default());
+    }
+
+    #[test]
+    fn from_env_reads_http_settings() {
+        let get = |k: &str| match k {
+            "INTERVALS_ICU_API_KEY" => Some("sekrit".into()),
+            "INTERVALS_ICU_ATHLETE_ID" => Some("42".into()),
+            "INTERVALS_ICU_TIMEOUT_SECS" => Some("15".into()),
+            "INTERVALS_ICU_CONNECT_TIMEOUT_SECS" => Some("0".into()),
+            "INTERVALS_ICU_POOL_MAX_IDLE_PER_HOST" => Some("4".into()),
+            "INTERVALS_ICU_USER_AGENT" => Some("coach-bot/1.0".into()),
+            "INTERVALS_ICU_PROXY" => Some("http://proxy.local:3128".into()),
+            "INTERVALS_ICU_CA_CERTS" => Some("/etc/ca/one.pem, /etc/ca/two.pem".into()),
+            "INTERVALS_ICU_RETRY_MAX" => Some("2".into()),
+            "INTERVALS_ICU_RETRY_BASE_DELAY_MS" => Some("250".into()),
+            "INTERVALS_ICU_CIRCUIT_FAILURE_THRESHOLD" => Some("8".into()),
+            "INTERVALS_ICU_CIRCUIT_RESET_SECS" => Some("60".into()),
+            _ => None,
+        };
+        let http = Config::from_env_with(get).expect("cfg").http;
+        assert_eq!(http.request_timeout, Some(Duration::from_secs(15)));
+        assert_eq!(http.connect_timeout, None);
+        assert_eq!(http.pool_idle_timeout, Some(Duration::from_secs(90)));
+        assert_eq!(http.pool_max_idle_per_host, Some(4));
+        assert_eq!(http.user_agent, "coach-bot/1.0");
+        assert_eq!(http.proxy.as_deref(), Some("http://proxy.local:3128"));
+        assert_eq!(
+            http.root_certificates,
+            vec![
+                PathBuf::from("/etc/ca/one.pem"),
+                PathBuf::from("/etc/ca/two.pem")
+            ]
+        );
+        assert_eq!(http.retry_max, 2);
+        assert_eq!(http.retry_base_delay, Duration::from_millis(250));
+        assert_eq!(http.circuit_failure_threshold, 8);
+        assert_eq!(http.circuit_reset_timeout, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn from_env_reads_rate_limit_settings() {
+        let with = |per_minute: &'static str| {
+            move |k: &str| match k {
+                "INTERVALS_ICU_API_KEY" => Some("sekrit".into()),
+                "INTERVALS_ICU_ATHLETE_ID" => Some("42".into()),
+                "INTERVALS_ICU_RATE_LIMIT_PER_MINUTE" => Some(per_minute.into()),
+                "INTERVALS_ICU_RATE_LIMIT_GLOBAL_BURST" => Some("12".into()),
+                _ => None,
+            }
+        };
+        let limits = Config::from_env_with(with("120"))
+            .expect("cfg")
+            .http
+            .rate_limit
+            .expect("enabled");
+        assert_eq!(limits.per_key_per_minute, 120);
+        assert_eq!(
+            limits.per_key_burst,
+            RateLimitSettings::default().per_key_burst
+        );
+        assert_eq!(limits.global_burst, 12);
+
+        let disabled = Config::from_env_with(with("0")).expect("cfg").http;
+        assert_eq!(disabled.rate_limit, None);
+    }
+
+    #[test]
+    fn from_env_rejects_malformed_http_setting() {
+        let get = |k: &str| match k {
+            "INTERVALS_ICU_API_KEY" => Some("sekrit".into()),
+            "INTERVALS_ICU_ATHLETE_ID" => Some("42".into()),
+            "INTERVALS_ICU_RETRY_MAX" => Some("lots".into()),
+            _ => None,
+        };
+        let err = Config::from_env_with(get).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid configuration value for INTERVALS_ICU_RETRY_MAX"),
+            "{err}"
+        );
     }
 }
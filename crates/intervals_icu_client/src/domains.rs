@@ -2,5 +2,21 @@
 //!
 //! These types replace `serde_json::Value` returns in the most-used endpoints,
 //! providing compile-time safety and documentation of expected shapes.
+//! Models for evolving payloads keep unknown keys in a flattened `extra` map.
 
+pub mod activity;
+pub mod athlete;
+pub mod curve;
+pub mod gear;
+mod lenient;
+pub mod stream;
+pub mod wellness;
 pub mod workout;
+
+/// Error for a payload whose top-level shape matches none of the accepted ones.
+pub(crate) fn shape_error(what: &str) -> crate::IntervalsError {
+    use serde::de::Error;
+    crate::IntervalsError::JsonDecode(serde_json::Error::custom(format!(
+        "unexpected {what} payload shape"
+    )))
+}
@@ -0,0 +1,194 @@
+//! Domain types for activity detail and histogram responses.
+//!
+//! Field names follow the `Activity` schema of the Intervals.icu OpenAPI
+//! document. Anything not modelled here is preserved in `extra` so callers
+//! can still reach new or rarely used fields without a client release.
+
+use serde::{Deserialize, Serialize};
+
+use super::lenient;
+
+/// A single activity as returned by `GET /api/v1/activity/{id}`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ActivityDetail {
+    #[serde(deserialize_with = "lenient::string")]
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Sport type, e.g. `"Ride"`, `"Run"`, `"WeightTraining"`.
+    #[serde(default, rename = "type")]
+    pub sport_type: Option<String>,
+    #[serde(default)]
+    pub start_date_local: Option<String>,
+    /// Distance in metres.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub distance: Option<f64>,
+    /// Moving time in seconds.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub moving_time: Option<f64>,
+    /// Elapsed time in seconds.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub elapsed_time: Option<f64>,
+    /// Elevation gain in metres.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub total_elevation_gain: Option<f64>,
+    /// Average speed in metres per second.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub average_speed: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub average_heartrate: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub max_heartrate: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub average_cadence: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub calories: Option<f64>,
+    #[serde(default)]
+    pub trainer: Option<bool>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub icu_training_load: Option<f64>,
+    /// FTP in effect when the activity was analysed.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub icu_ftp: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub icu_average_watts: Option<f64>,
+    /// Normalized power.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub icu_weighted_avg_watts: Option<f64>,
+    /// Intensity factor as a percentage of FTP.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub icu_intensity: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub icu_efficiency_factor: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub icu_variability_index: Option<f64>,
+    /// Lactate threshold heart rate in effect for the activity.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub lthr: Option<f64>,
+    /// Session RPE on the 1-10 scale.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub icu_rpe: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub feel: Option<f64>,
+    /// Fields not modelled above, kept for forward compatibility.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// One bucket of a power, heart-rate or pace histogram.
+///
+/// Range histograms carry `min`/`max`; zone histograms carry an `id` instead.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct HistogramBucket {
+    #[serde(default, deserialize_with = "lenient::opt_string")]
+    pub id: Option<String>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub min: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub max: Option<f64>,
+    /// Time spent in the bucket, in seconds.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub secs: Option<f64>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Response of `POST /api/v1/athlete/{id}/activities` (file upload).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ActivityUploadResult {
+    /// Id of the first activity created from the file.
+    #[serde(default, deserialize_with = "lenient::opt_string")]
+    pub id: Option<String>,
+    #[serde(default, deserialize_with = "lenient::opt_string")]
+    pub icu_athlete_id: Option<String>,
+    /// Every activity created; multisport files produce more than one.
+    #[serde(default)]
+    pub activities: Vec<UploadedActivity>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ActivityUploadResult {
+    /// Ids of all created activities, falling back to the top-level id.
+    #[must_use]
+    pub fn activity_ids(&self) -> Vec<String> {
+        if self.activities.is_empty() {
+            return self.id.iter().cloned().collect();
+        }
+        self.activities
+            .iter()
+            .map(|activity| activity.id.clone())
+            .collect()
+    }
+}
+
+/// One activity created by a file upload.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct UploadedActivity {
+    #[serde(deserialize_with = "lenient::string")]
+    pub id: String,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn activity_detail_keeps_unknown_fields_in_extra() {
+        let detail: ActivityDetail = serde_json::from_value(json!({
+            "id": "i42",
+            "type": "Ride",
+            "icu_weighted_avg_watts": 231,
+            "icu_intensity": "82.5",
+            "moving_time": 3600,
+            "brand_new_field": {"nested": true}
+        }))
+        .expect("deserialize activity");
+        assert_eq!(detail.id, "i42");
+        assert_eq!(detail.sport_type.as_deref(), Some("Ride"));
+        assert_eq!(detail.icu_weighted_avg_watts, Some(231.0));
+        assert_eq!(detail.icu_intensity, Some(82.5));
+        assert_eq!(detail.moving_time, Some(3600.0));
+        assert_eq!(detail.extra["brand_new_field"], json!({"nested": true}));
+    }
+
+    #[test]
+    fn activity_detail_accepts_numeric_id() {
+        let detail: ActivityDetail =
+            serde_json::from_value(json!({"id": 7})).expect("deserialize activity");
+        assert_eq!(detail.id, "7");
+        assert!(detail.extra.is_empty());
+    }
+
+    #[test]
+    fn histogram_bucket_parses_range_and_zone_shapes() {
+        let buckets: Vec<HistogramBucket> = serde_json::from_value(json!([
+            {"min": 100, "max": 125, "secs": 600},
+            {"id": "z2", "secs": 1200}
+        ]))
+        .expect("deserialize buckets");
+        assert_eq!(buckets[0].max, Some(125.0));
+        assert_eq!(buckets[1].id.as_deref(), Some("z2"));
+        assert_eq!(buckets[1].min, None);
+    }
+
+    #[test]
+    fn upload_result_lists_created_activities() {
+        let result: ActivityUploadResult = serde_json::from_value(json!({
+            "icu_athlete_id": "i1",
+            "id": "i55",
+            "activities": [{"id": "i55", "icu_athlete_id": "i1"}, {"id": 56}]
+        }))
+        .expect("deserialize upload result");
+        assert_eq!(result.activity_ids(), vec!["i55", "56"]);
+
+        let bare: ActivityUploadResult =
+            serde_json::from_value(json!({"id": "i57"})).expect("deserialize upload result");
+        assert_eq!(bare.activity_ids(), vec!["i57"]);
+    }
+}
@@ -0,0 +1,37 @@
+//! Domain types for coach rosters.
+
+use serde::{Deserialize, Serialize};
+
+use super::lenient;
+
+/// An athlete the authenticated coach account can access, from
+/// `GET /api/v1/athlete/{id}/athletes`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CoachedAthlete {
+    #[serde(deserialize_with = "lenient::string")]
+    pub id: String,
+    #[serde(default, deserialize_with = "lenient::opt_string")]
+    pub name: Option<String>,
+    /// Fields not modelled above, kept for forward compatibility.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn coached_athlete_accepts_numeric_ids_and_keeps_extra_fields() {
+        let roster: Vec<CoachedAthlete> = serde_json::from_value(json!([
+            {"id": "i1", "name": "Ana", "city": "Porto"},
+            {"id": 42}
+        ]))
+        .expect("roster");
+        assert_eq!(roster[0].name.as_deref(), Some("Ana"));
+        assert_eq!(roster[0].extra["city"], json!("Porto"));
+        assert_eq!(roster[1].id, "42");
+        assert!(roster[1].name.is_none());
+    }
+}
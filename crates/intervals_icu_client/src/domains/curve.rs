@@ -0,0 +1,116 @@
+//! Domain types for power, heart-rate and pace curve responses.
+//!
+//! `GET /api/v1/athlete/{id}/activity-{kind}-curves` returns
+//! `{"list": [curve, ...]}`. Power and heart-rate curves pair `secs` with
+//! `values`; pace curves pair `distance` (metres) with `values` (seconds).
+
+use serde::{Deserialize, Serialize};
+
+use super::lenient;
+
+/// Which best-effort curve endpoint to read.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum CurveKind {
+    Power,
+    HeartRate,
+    Pace,
+}
+
+impl CurveKind {
+    /// Endpoint segment: `activity-{segment}-curves`.
+    #[must_use]
+    pub fn path_segment(self) -> &'static str {
+        match self {
+            Self::Power => "power",
+            Self::HeartRate => "hr",
+            Self::Pace => "pace",
+        }
+    }
+}
+
+/// One best-effort curve over a date range.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Curve {
+    #[serde(default, deserialize_with = "lenient::opt_string")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Durations in seconds (power and heart-rate curves).
+    #[serde(default, deserialize_with = "lenient::f64_series")]
+    pub secs: Vec<Option<f64>>,
+    /// Distances in metres (pace curves).
+    #[serde(default, deserialize_with = "lenient::f64_series")]
+    pub distance: Vec<Option<f64>>,
+    /// Best value at each anchor, index-aligned with `secs` or `distance`.
+    #[serde(default, deserialize_with = "lenient::f64_series")]
+    pub values: Vec<Option<f64>>,
+    /// Activity that set each value, index-aligned with `values`.
+    #[serde(default, deserialize_with = "lenient::string_series")]
+    pub activity_id: Vec<Option<String>>,
+    /// Fields not modelled above, kept for forward compatibility.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A set of curves as returned by the curve endpoints.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CurveSet {
+    #[serde(default)]
+    pub list: Vec<Curve>,
+}
+
+impl CurveSet {
+    /// Parse a `{"list": [...]}` wrapper, a bare array of curves or a single
+    /// curve object.
+    pub fn from_value(value: &serde_json::Value) -> Option<Self> {
+        let items = match value {
+            serde_json::Value::Array(items) => items.clone(),
+            serde_json::Value::Object(object) => match object.get("list") {
+                Some(serde_json::Value::Array(items)) => items.clone(),
+                _ => vec![value.clone()],
+            },
+            _ => return None,
+        };
+        let list = items
+            .into_iter()
+            .filter_map(|item| serde_json::from_value(item).ok())
+            .collect();
+        Some(Self { list })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn curve_set_parses_wrapped_power_curve() {
+        let set = CurveSet::from_value(&json!({"list": [{
+            "id": "r.90d",
+            "secs": [5, 60, 1200],
+            "values": [900, 420, null],
+            "activity_id": ["i1", 2, null],
+            "vo2max_5m": 58.1
+        }]}))
+        .expect("curve payload");
+        let curve = &set.list[0];
+        assert_eq!(curve.values, vec![Some(900.0), Some(420.0), None]);
+        assert_eq!(
+            curve.activity_id,
+            vec![Some("i1".into()), Some("2".into()), None]
+        );
+        assert_eq!(curve.extra["vo2max_5m"], json!(58.1));
+    }
+
+    #[test]
+    fn curve_set_accepts_bare_curve_and_array() {
+        let single = CurveSet::from_value(&json!({"distance": [1000], "values": [210]}))
+            .expect("single curve");
+        assert_eq!(single.list[0].distance, vec![Some(1000.0)]);
+        let array = CurveSet::from_value(&json!([{"secs": [1]}, {"secs": [2]}])).expect("array");
+        assert_eq!(array.list.len(), 2);
+        assert!(CurveSet::from_value(&json!(3)).is_none());
+    }
+}
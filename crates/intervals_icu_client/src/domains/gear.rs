@@ -0,0 +1,84 @@
+//! Domain types for gear responses.
+
+use serde::{Deserialize, Serialize};
+
+use super::lenient;
+
+/// A piece of equipment from `GET /api/v1/athlete/{id}/gear`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Gear {
+    #[serde(deserialize_with = "lenient::string")]
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Gear type, e.g. `"Shoes"`, `"Bike"`, `"Computer"`.
+    #[serde(default, rename = "type")]
+    pub gear_type: Option<String>,
+    /// Accumulated distance in metres.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub distance: Option<f64>,
+    /// Accumulated moving time in seconds.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub time: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub activities: Option<f64>,
+    #[serde(default)]
+    pub purchased: Option<String>,
+    /// Retirement date; the API sends an empty string for active gear.
+    #[serde(default)]
+    pub retired: Option<String>,
+    #[serde(default)]
+    pub reminders: Vec<GearReminder>,
+    /// Fields not modelled above, kept for forward compatibility.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Gear {
+    /// Whether the gear has a non-empty retirement date.
+    #[must_use]
+    pub fn is_retired(&self) -> bool {
+        self.retired
+            .as_deref()
+            .is_some_and(|date| !date.trim().is_empty())
+    }
+}
+
+/// A maintenance reminder attached to gear.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct GearReminder {
+    #[serde(default, deserialize_with = "lenient::opt_string")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Distance interval in metres.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub distance: Option<f64>,
+    /// Time interval in seconds.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub time: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub days: Option<f64>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn gear_list_parses_retired_flag_and_reminders() {
+        let gear: Vec<Gear> = serde_json::from_value(json!([
+            {"id": "g1", "name": "Shoes", "type": "Shoes", "distance": 500000.0, "retired": "",
+             "reminders": [{"id": 3, "name": "Replace", "distance": 800000}]},
+            {"id": "g2", "type": "Bike", "retired": "2025-01-01", "component": true}
+        ]))
+        .expect("deserialize gear");
+        assert!(!gear[0].is_retired());
+        assert_eq!(gear[0].reminders[0].id.as_deref(), Some("3"));
+        assert!(gear[1].is_retired());
+        assert_eq!(gear[1].extra["component"], json!(true));
+    }
+}
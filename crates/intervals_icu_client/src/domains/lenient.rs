@@ -0,0 +1,78 @@
+//! Lenient field deserializers shared by the typed response models.
+//!
+//! Intervals.icu is not always consistent about scalar encodings: ids arrive
+//! as numbers or strings, and numeric series may contain `null` gaps. These
+//! helpers accept every encoding observed in practice so a single odd field
+//! never fails the whole response.
+
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+fn as_string(value: Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+    .filter(|value| value.is_finite())
+}
+
+/// String or number, `null` and anything else become `None`.
+pub(crate) fn opt_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Value>::deserialize(deserializer)?.and_then(as_string))
+}
+
+/// String or number; used for required ids.
+pub(crate) fn string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde::de::Error;
+    let value = Value::deserialize(deserializer)?;
+    as_string(value.clone())
+        .ok_or_else(|| D::Error::custom(format!("expected string or number, got {value}")))
+}
+
+/// Number or numeric string; anything else becomes `None`.
+pub(crate) fn opt_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Value>::deserialize(deserializer)?
+        .as_ref()
+        .and_then(as_f64))
+}
+
+/// Numeric series with gaps: non-numeric entries become `None`, `null` or a
+/// missing array becomes an empty series.
+pub(crate) fn f64_series<'de, D>(deserializer: D) -> Result<Vec<Option<f64>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::Array(items)) => items.iter().map(as_f64).collect(),
+        _ => Vec::new(),
+    })
+}
+
+/// Series of ids (strings or numbers) with gaps.
+pub(crate) fn string_series<'de, D>(deserializer: D) -> Result<Vec<Option<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::Array(items)) => items.into_iter().map(as_string).collect(),
+        _ => Vec::new(),
+    })
+}
@@ -0,0 +1,109 @@
+//! Domain types for activity stream responses.
+//!
+//! `GET /api/v1/activity/{id}/streams` returns an array of stream objects
+//! (`{"type": "watts", "data": [...]}`). Some proxies and cached exports
+//! instead return a map of stream type to samples, optionally wrapped in a
+//! `"streams"` key; [`ActivityStreams::from_value`] accepts all three.
+
+use serde::{Deserialize, Serialize};
+
+use super::lenient;
+
+/// One sampled stream of an activity.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ActivityStream {
+    /// Stream type, e.g. `"watts"`, `"heartrate"`, `"latlng"`.
+    #[serde(rename = "type")]
+    pub stream_type: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Samples; gaps and non-numeric samples are `None`.
+    #[serde(default, deserialize_with = "lenient::f64_series")]
+    pub data: Vec<Option<f64>>,
+    /// Second component for two-valued streams (longitude for `latlng`).
+    #[serde(default, deserialize_with = "lenient::f64_series")]
+    pub data2: Vec<Option<f64>>,
+    /// Fields not modelled above, kept for forward compatibility.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// All streams returned for one activity.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ActivityStreams {
+    pub streams: Vec<ActivityStream>,
+}
+
+impl ActivityStreams {
+    /// Parse any of the stream payload shapes described in the module docs.
+    pub fn from_value(value: &serde_json::Value) -> Option<Self> {
+        if let Some(items) = value.as_array() {
+            let streams = items
+                .iter()
+                .filter_map(|item| serde_json::from_value(item.clone()).ok())
+                .collect();
+            return Some(Self { streams });
+        }
+        let object = value.as_object()?;
+        if let Some(inner) = object.get("streams") {
+            return Self::from_value(inner);
+        }
+        let streams = object
+            .iter()
+            .filter(|(_, samples)| samples.is_array())
+            .filter_map(|(stream_type, samples)| {
+                serde_json::from_value(serde_json::json!({
+                    "type": stream_type,
+                    "data": samples,
+                }))
+                .ok()
+            })
+            .collect();
+        Some(Self { streams })
+    }
+
+    /// The stream of the given type, if present.
+    #[must_use]
+    pub fn get(&self, stream_type: &str) -> Option<&ActivityStream> {
+        self.streams
+            .iter()
+            .find(|stream| stream.stream_type == stream_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn streams_from_descriptor_array() {
+        let streams = ActivityStreams::from_value(&json!([
+            {"type": "watts", "data": [200, null, 210]},
+            {"type": "latlng", "data": [51.5, 51.6], "data2": [-0.1, -0.2], "valueType": "latlng"}
+        ]))
+        .expect("array payload");
+        assert_eq!(
+            streams.get("watts").map(|s| s.data.clone()),
+            Some(vec![Some(200.0), None, Some(210.0)])
+        );
+        let latlng = streams.get("latlng").expect("latlng stream");
+        assert_eq!(latlng.data2, vec![Some(-0.1), Some(-0.2)]);
+        assert_eq!(latlng.extra["valueType"], json!("latlng"));
+    }
+
+    #[test]
+    fn streams_from_map_and_wrapped_map() {
+        let plain =
+            ActivityStreams::from_value(&json!({"heartrate": [140, 145]})).expect("map payload");
+        assert_eq!(plain.get("heartrate").map(|s| s.data.len()), Some(2));
+
+        let wrapped = ActivityStreams::from_value(&json!({"streams": {"cadence": [90]}}))
+            .expect("wrapped payload");
+        assert_eq!(
+            wrapped.get("cadence").map(|s| s.data.clone()),
+            Some(vec![Some(90.0)])
+        );
+        assert!(ActivityStreams::from_value(&json!("nope")).is_none());
+    }
+}
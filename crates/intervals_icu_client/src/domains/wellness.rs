@@ -0,0 +1,176 @@
+//! Domain types for wellness responses.
+//!
+//! The API uses camelCase keys (`restingHR`, `sleepSecs`); older exports and
+//! some fixtures use snake_case, which is accepted through aliases.
+
+use serde::{Deserialize, Serialize};
+
+use super::lenient;
+
+/// One day of wellness data from `GET /api/v1/athlete/{id}/wellness`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct WellnessEntry {
+    /// ISO date (`YYYY-MM-DD`); the API uses the date as the record id.
+    #[serde(default, alias = "date", deserialize_with = "lenient::opt_string")]
+    pub id: Option<String>,
+    /// Chronic training load (fitness).
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub ctl: Option<f64>,
+    /// Acute training load (fatigue).
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub atl: Option<f64>,
+    #[serde(
+        default,
+        rename = "rampRate",
+        alias = "ramp_rate",
+        deserialize_with = "lenient::opt_f64"
+    )]
+    pub ramp_rate: Option<f64>,
+    #[serde(
+        default,
+        rename = "ctlLoad",
+        alias = "ctl_load",
+        deserialize_with = "lenient::opt_f64"
+    )]
+    pub ctl_load: Option<f64>,
+    #[serde(
+        default,
+        rename = "atlLoad",
+        alias = "atl_load",
+        deserialize_with = "lenient::opt_f64"
+    )]
+    pub atl_load: Option<f64>,
+    /// Body weight in kg.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub weight: Option<f64>,
+    #[serde(
+        default,
+        rename = "restingHR",
+        alias = "resting_hr",
+        deserialize_with = "lenient::opt_f64"
+    )]
+    pub resting_hr: Option<f64>,
+    /// rMSSD heart-rate variability in ms.
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub hrv: Option<f64>,
+    #[serde(
+        default,
+        rename = "hrvSDNN",
+        alias = "hrv_sdnn",
+        deserialize_with = "lenient::opt_f64"
+    )]
+    pub hrv_sdnn: Option<f64>,
+    #[serde(
+        default,
+        rename = "sleepSecs",
+        alias = "sleep_secs",
+        deserialize_with = "lenient::opt_f64"
+    )]
+    pub sleep_secs: Option<f64>,
+    #[serde(
+        default,
+        rename = "sleepScore",
+        alias = "sleep_score",
+        deserialize_with = "lenient::opt_f64"
+    )]
+    pub sleep_score: Option<f64>,
+    /// Subjective sleep quality, 1 (great) to 4 (poor).
+    #[serde(
+        default,
+        rename = "sleepQuality",
+        alias = "sleep_quality",
+        deserialize_with = "lenient::opt_f64"
+    )]
+    pub sleep_quality: Option<f64>,
+    #[serde(
+        default,
+        rename = "avgSleepingHR",
+        alias = "avg_sleeping_hr",
+        deserialize_with = "lenient::opt_f64"
+    )]
+    pub avg_sleeping_hr: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub soreness: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub fatigue: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub stress: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub mood: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub motivation: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub injury: Option<f64>,
+    #[serde(default, deserialize_with = "lenient::opt_f64")]
+    pub readiness: Option<f64>,
+    #[serde(
+        default,
+        rename = "spO2",
+        alias = "spo2",
+        deserialize_with = "lenient::opt_f64"
+    )]
+    pub spo2: Option<f64>,
+    #[serde(default, rename = "menstrualPhase", alias = "menstrual_phase")]
+    pub menstrual_phase: Option<String>,
+    #[serde(default)]
+    pub comments: Option<String>,
+    /// Fields not modelled above, kept for forward compatibility.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl WellnessEntry {
+    /// Training stress balance (form), derived as CTL minus ATL.
+    #[must_use]
+    pub fn form(&self) -> Option<f64> {
+        Some(self.ctl? - self.atl?)
+    }
+
+    /// Parse a single-day payload. The endpoint returns an object, but a
+    /// one-element array (as produced by range queries) is accepted too.
+    pub fn from_value(value: &serde_json::Value) -> Option<Self> {
+        let object = match value {
+            serde_json::Value::Array(items) => items.first()?,
+            other => other,
+        };
+        if !object.is_object() {
+            return None;
+        }
+        serde_json::from_value(object.clone()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn wellness_entry_reads_camel_and_snake_case_keys() {
+        let entries: Vec<WellnessEntry> = serde_json::from_value(json!([
+            {"id": "2026-03-01", "ctl": 60.0, "atl": 72.5, "restingHR": 48, "sleepSecs": 28800},
+            {"date": "2026-03-02", "resting_hr": 50, "sleep_secs": 25200, "hrv": "61"}
+        ]))
+        .expect("deserialize wellness");
+        assert_eq!(entries[0].resting_hr, Some(48.0));
+        assert_eq!(entries[0].form(), Some(-12.5));
+        assert_eq!(entries[1].id.as_deref(), Some("2026-03-02"));
+        assert_eq!(entries[1].sleep_secs, Some(25200.0));
+        assert_eq!(entries[1].hrv, Some(61.0));
+        assert_eq!(entries[1].form(), None);
+    }
+
+    #[test]
+    fn wellness_entry_from_value_accepts_object_or_array() {
+        let entry = WellnessEntry::from_value(&json!({"weight": 70.5, "vo2max": 55}))
+            .expect("object payload");
+        assert_eq!(entry.weight, Some(70.5));
+        assert_eq!(entry.extra["vo2max"], json!(55));
+
+        let entry = WellnessEntry::from_value(&json!([{"weight": 71.0}])).expect("array payload");
+        assert_eq!(entry.weight, Some(71.0));
+
+        assert!(WellnessEntry::from_value(&json!([])).is_none());
+        assert!(WellnessEntry::from_value(&json!(null)).is_none());
+    }
+}
@@ -134,6 +134,9 @@ pub struct SportSetting {
     /// Functional Threshold Power in watts.
     #[serde(default)]
     pub ftp: Option<f64>,
+    /// Indoor (trainer) FTP in watts, when configured separately.
+    #[serde(default)]
+    pub indoor_ftp: Option<f64>,
     /// Lactate Heart Rate Threshold in bpm.
     #[serde(default)]
     pub lthr: Option<f64>,
@@ -161,6 +164,9 @@ pub struct SportSetting {
     /// Power zones.
     #[serde(default)]
     pub power_zones: Vec<serde_json::Value>,
+    /// Pace zones as upper bounds in % of threshold pace.
+    #[serde(default)]
+    pub pace_zones: Vec<serde_json::Value>,
 }
 
 #[cfg(test)]
@@ -302,6 +308,7 @@ mod tests {
                 types: Some(vec!["Run".into()]),
                 sport_type: None,
                 ftp: None,
+                indoor_ftp: None,
                 lthr: Some(170.0),
                 threshold_aet_hr: None,
                 threshold_lt_hr: Some(170.0),
@@ -311,6 +318,7 @@ mod tests {
                 load_order: None,
                 hr_zones: vec![json!(120), json!(145), json!(160), json!(175), json!(190)],
                 power_zones: vec![],
+                pace_zones: vec![],
             }],
             age: Some(28),
             weight: Some(70.0),
@@ -0,0 +1,1107 @@
+//! Stateful in-memory `IntervalsClient` for tests (`fake` feature).
+//!
+//! [`FakeIntervalsClient`] keeps events, activities, wellness, gear, sport
+//! settings and the workout library in memory. Writes are visible to later
+//! reads, so a test can plan workouts, modify them and then analyze the
+//! result through the same client. State is seeded from [`FakeFixtures`]
+//! (usually JSON), and "today" is fixed by the fixtures rather than read from
+//! the system clock. Date-window reads (`days_back`, `days_ahead`) and the
+//! computed fitness summary are therefore deterministic.
+//!
+//! Data the API derives from recordings (streams, intervals, curves) is only
+//! returned when seeded; it is never computed.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::domains::activity::ActivityUploadResult;
+use crate::domains::curve::CurveKind;
+use crate::domains::workout::{Folder, SportSettings, WorkoutItem};
+use crate::http_client::ReqwestIntervalsClient;
+use crate::{
+    ActivityMessage, ActivitySummary, ActivityUpload, AthleteProfile, BestEffortsOptions,
+    DownloadProgress, Event, IntervalsClient, IntervalsError, Result, ValidationError,
+};
+
+/// First id handed out for created resources; seeded ids should stay below it.
+const FIRST_GENERATED_ID: u64 = 1_000_000;
+/// Time constants (days) of the CTL and ATL exponential averages.
+const CTL_DAYS: f64 = 42.0;
+const ATL_DAYS: f64 = 7.0;
+/// Default window of `get_recent_activities`, matching the HTTP client.
+const DEFAULT_ACTIVITY_DAYS: i32 = 7;
+/// Default horizon of `get_upcoming_workouts`, matching the HTTP client.
+const DEFAULT_UPCOMING_DAYS: u32 = 7;
+
+/// Initial state of a [`FakeIntervalsClient`].
+///
+/// Every collection holds raw API-shaped JSON objects, so fixtures can be
+/// captured from real responses. Activities and events need `id` and
+/// `start_date_local`, wellness entries need `id` (the date), and sport
+/// settings need `id` and `types`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct FakeFixtures {
+    pub athlete_id: Option<String>,
+    pub athlete_name: Option<String>,
+    /// `YYYY-MM-DD`; defaults to [`FakeFixtures::DEFAULT_TODAY`].
+    pub today: Option<String>,
+    pub events: Vec<Value>,
+    pub activities: Vec<Value>,
+    pub wellness: Vec<Value>,
+    pub gear: Vec<Value>,
+    pub sport_settings: Vec<Value>,
+    pub workouts: Vec<Value>,
+    pub folders: Vec<Value>,
+    /// Activity id to its `/streams` payload.
+    pub streams: HashMap<String, Value>,
+    /// Activity id to its `/intervals` payload.
+    pub intervals: HashMap<String, Value>,
+    pub power_curves: Option<Value>,
+    pub hr_curves: Option<Value>,
+    pub pace_curves: Option<Value>,
+}
+
+impl FakeFixtures {
+    pub const DEFAULT_TODAY: &'static str = "2026-01-15";
+}
+
+#[derive(Debug, Default)]
+struct FakeState {
+    events: BTreeMap<String, Value>,
+    activities: BTreeMap<String, Value>,
+    wellness: BTreeMap<String, Value>,
+    gear: Vec<Value>,
+    sport_settings: Vec<Value>,
+    workouts: Vec<Value>,
+    folders: Vec<Value>,
+    streams: HashMap<String, Value>,
+    intervals: HashMap<String, Value>,
+    messages: HashMap<String, Vec<ActivityMessage>>,
+    power_curves: Option<Value>,
+    hr_curves: Option<Value>,
+    pace_curves: Option<Value>,
+}
+
+/// In-memory [`IntervalsClient`] whose writes persist across calls.
+#[derive(Debug)]
+pub struct FakeIntervalsClient {
+    athlete: AthleteProfile,
+    today: NaiveDate,
+    state: Mutex<FakeState>,
+    next_id: AtomicU64,
+}
+
+impl FakeIntervalsClient {
+    /// An empty athlete whose "today" is `today`.
+    #[must_use]
+    pub fn new(today: NaiveDate) -> Self {
+        Self {
+            athlete: AthleteProfile {
+                id: "i0".to_string(),
+                name: Some("Fake Athlete".to_string()),
+            },
+            today,
+            state: Mutex::new(FakeState::default()),
+            next_id: AtomicU64::new(FIRST_GENERATED_ID),
+        }
+    }
+
+    /// Seed a client from fixtures.
+    pub fn from_fixtures(fixtures: FakeFixtures) -> Result<Self> {
+        let today = match fixtures.today.as_deref() {
+            Some(today) => parse_date("today", today)?,
+            None => parse_date("today", FakeFixtures::DEFAULT_TODAY)?,
+        };
+        let mut client = Self::new(today);
+        if let Some(id) = fixtures.athlete_id {
+            client.athlete.id = id;
+        }
+        if fixtures.athlete_name.is_some() {
+            client.athlete.name = fixtures.athlete_name;
+        }
+
+        let state = client.state.get_mut().unwrap_or_else(|e| e.into_inner());
+        for event in fixtures.events {
+            let id = required_id(&event, "events")?;
+            state.events.insert(id, event);
+        }
+        for activity in fixtures.activities {
+            let id = required_id(&activity, "activities")?;
+            state.activities.insert(id, activity);
+        }
+        for entry in fixtures.wellness {
+            let date = required_id(&entry, "wellness")?;
+            state.wellness.insert(date, entry);
+        }
+        state.gear = fixtures.gear;
+        state.sport_settings = fixtures.sport_settings;
+        state.workouts = fixtures.workouts;
+        state.folders = fixtures.folders;
+        state.streams = fixtures.streams;
+        state.intervals = fixtures.intervals;
+        state.power_curves = fixtures.power_curves;
+        state.hr_curves = fixtures.hr_curves;
+        state.pace_curves = fixtures.pace_curves;
+        Ok(client)
+    }
+
+    /// Seed a client from a JSON [`FakeFixtures`] document.
+    pub fn from_json(fixtures: &str) -> Result<Self> {
+        Self::from_fixtures(serde_json::from_str(fixtures)?)
+    }
+
+    /// The fixed date date-window reads are relative to.
+    #[must_use]
+    pub fn today(&self) -> NaiveDate {
+        self.today
+    }
+
+    /// Current state as fixtures, e.g. to assert on or to save for another test.
+    #[must_use]
+    pub fn snapshot(&self) -> FakeFixtures {
+        let state = self.lock();
+        FakeFixtures {
+            athlete_id: Some(self.athlete.id.clone()),
+            athlete_name: self.athlete.name.clone(),
+            today: Some(self.today.to_string()),
+            events: state.events.values().cloned().collect(),
+            activities: state.activities.values().cloned().collect(),
+            wellness: state.wellness.values().cloned().collect(),
+            gear: state.gear.clone(),
+            sport_settings: state.sport_settings.clone(),
+            workouts: state.workouts.clone(),
+            folders: state.folders.clone(),
+            streams: state.streams.clone(),
+            intervals: state.intervals.clone(),
+            power_curves: state.power_curves.clone(),
+            hr_curves: state.hr_curves.clone(),
+            pace_curves: state.pace_curves.clone(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, FakeState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn generate_id(&self) -> u64 {
+        self.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn store_event(&self, state: &mut FakeState, event: Event) -> Result<Event> {
+        let mut value = serde_json::to_value(&event)?;
+        let id = match event.id {
+            Some(id) => id,
+            None => self.generate_id().to_string(),
+        };
+        value["id"] = json!(id);
+        state.events.insert(id, value.clone());
+        Ok(serde_json::from_value(value)?)
+    }
+
+    fn events_between(&self, oldest: NaiveDate, newest: NaiveDate) -> Vec<Value> {
+        self.lock()
+            .events
+            .values()
+            .filter(|event| date_of(event).is_some_and(|date| date >= oldest && date <= newest))
+            .cloned()
+            .collect()
+    }
+
+    fn activities_newest_first(&self) -> Vec<Value> {
+        let mut activities = self.lock().activities.values().cloned().collect::<Vec<_>>();
+        activities.sort_by(|a, b| start_of(b).cmp(start_of(a)));
+        activities
+    }
+
+    fn search(&self, query: &str, limit: Option<u32>) -> Result<Vec<Value>> {
+        if query.trim().is_empty() {
+            return Err(IntervalsError::Validation(ValidationError::InvalidFormat {
+                field: "query".to_string(),
+                value: "query must not be empty".to_string(),
+            }));
+        }
+        let needle = query.to_lowercase();
+        Ok(take(
+            self.activities_newest_first()
+                .into_iter()
+                .filter(|activity| {
+                    ["name", "description", "type"].iter().any(|key| {
+                        activity
+                            .get(key)
+                            .and_then(Value::as_str)
+                            .is_some_and(|text| text.to_lowercase().contains(&needle))
+                    })
+                })
+                .collect(),
+            limit,
+        ))
+    }
+
+    fn activity_extra(&self, activity_id: &str, empty: Value) -> Result<Value> {
+        let state = self.lock();
+        if !state.activities.contains_key(activity_id) {
+            return Err(not_found("activity", activity_id));
+        }
+        Ok(empty)
+    }
+
+    fn sport_setting_index(&self, state: &FakeState, sport_type_or_id: &str) -> Result<usize> {
+        let sport = ReqwestIntervalsClient::normalize_sport(sport_type_or_id);
+        state
+            .sport_settings
+            .iter()
+            .position(|setting| {
+                id_of(setting).as_deref() == Some(sport_type_or_id)
+                    || setting
+                        .get("types")
+                        .and_then(Value::as_array)
+                        .is_some_and(|types| types.iter().any(|t| t.as_str() == Some(&sport)))
+            })
+            .ok_or_else(|| not_found("sport settings", sport_type_or_id))
+    }
+
+    /// CTL/ATL from activity `icu_training_load`, as of `date`.
+    fn fitness_on(&self, date: NaiveDate) -> (f64, f64) {
+        let mut daily_load: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+        for activity in self.lock().activities.values() {
+            if let Some(day) = date_of(activity).filter(|day| *day <= date) {
+                *daily_load.entry(day).or_default() += activity
+                    .get("icu_training_load")
+                    .and_then(Value::as_f64)
+                    .unwrap_or(0.0);
+            }
+        }
+        let Some(first) = daily_load.keys().next().copied() else {
+            return (0.0, 0.0);
+        };
+        let (mut ctl, mut atl) = (0.0, 0.0);
+        let mut day = first;
+        while day <= date {
+            let load = daily_load.get(&day).copied().unwrap_or(0.0);
+            ctl += (load - ctl) / CTL_DAYS;
+            atl += (load - atl) / ATL_DAYS;
+            day += Duration::days(1);
+        }
+        (ctl, atl)
+    }
+}
+
+fn parse_date(field: &str, value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value.get(..10).unwrap_or(value), "%Y-%m-%d").map_err(|_| {
+        IntervalsError::Validation(ValidationError::InvalidFormat {
+            field: field.to_string(),
+            value: value.to_string(),
+        })
+    })
+}
+
+fn not_found(kind: &str, id: &str) -> IntervalsError {
+    IntervalsError::NotFound(format!("{kind} {id}"))
+}
+
+/// `id` as a string, whether the API sent a number or a string.
+fn id_of(value: &Value) -> Option<String> {
+    match value.get("id")? {
+        Value::String(id) => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+fn required_id(value: &Value, collection: &str) -> Result<String> {
+    id_of(value).ok_or_else(|| {
+        IntervalsError::Validation(ValidationError::EmptyField {
+            field: format!("{collection}[].id"),
+        })
+    })
+}
+
+fn start_of(value: &Value) -> &str {
+    value
+        .get("start_date_local")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+}
+
+fn date_of(value: &Value) -> Option<NaiveDate> {
+    parse_date("start_date_local", start_of(value)).ok()
+}
+
+/// Shallow-merge `fields` into `target`, like the API's partial updates.
+fn merge(target: &mut Value, fields: &Value) {
+    if let (Some(target), Some(fields)) = (target.as_object_mut(), fields.as_object()) {
+        for (key, value) in fields {
+            if key != "id" {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+fn take(mut values: Vec<Value>, limit: Option<u32>) -> Vec<Value> {
+    if let Some(limit) = limit {
+        values.truncate(limit as usize);
+    }
+    values
+}
+
+fn summaries(values: Vec<Value>) -> Result<Vec<ActivitySummary>> {
+    values
+        .into_iter()
+        .map(|mut value| {
+            if let Some(id) = id_of(&value) {
+                value["id"] = json!(id);
+            }
+            Ok(serde_json::from_value(value)?)
+        })
+        .collect()
+}
+
+#[async_trait::async_trait]
+impl IntervalsClient for FakeIntervalsClient {
+    async fn get_athlete_profile(&self) -> Result<AthleteProfile> {
+        Ok(self.athlete.clone())
+    }
+
+    fn athlete_id(&self) -> Option<&str> {
+        Some(&self.athlete.id)
+    }
+
+    async fn get_recent_activities(
+        &self,
+        limit: Option<u32>,
+        days_back: Option<i32>,
+    ) -> Result<Vec<ActivitySummary>> {
+        let oldest =
+            self.today - Duration::days(i64::from(days_back.unwrap_or(DEFAULT_ACTIVITY_DAYS)));
+        let recent = self
+            .activities_newest_first()
+            .into_iter()
+            .filter(|activity| {
+                date_of(activity).is_some_and(|date| date >= oldest && date <= self.today)
+            })
+            .collect();
+        summaries(take(recent, limit))
+    }
+
+    async fn get_activities_between(
+        &self,
+        oldest: NaiveDate,
+        newest: NaiveDate,
+    ) -> Result<Vec<ActivitySummary>> {
+        let activities = self
+            .activities_newest_first()
+            .into_iter()
+            .filter(|activity| {
+                date_of(activity).is_some_and(|date| date >= oldest && date <= newest)
+            })
+            .collect();
+        summaries(activities)
+    }
+
+    async fn create_event(&self, event: Event) -> Result<Event> {
+        let mut state = self.lock();
+        self.store_event(&mut state, event)
+    }
+
+    async fn get_event(&self, event_id: &str) -> Result<Event> {
+        let event = self.lock().events.get(event_id).cloned();
+        Ok(serde_json::from_value(
+            event.ok_or_else(|| not_found("event", event_id))?,
+        )?)
+    }
+
+    async fn delete_event(&self, event_id: &str) -> Result<()> {
+        self.lock()
+            .events
+            .remove(event_id)
+            .map(|_| ())
+            .ok_or_else(|| not_found("event", event_id))
+    }
+
+    async fn get_events(&self, days_back: Option<i32>, limit: Option<u32>) -> Result<Vec<Event>> {
+        let mut events = match days_back {
+            Some(days) => {
+                self.events_between(self.today - Duration::days(i64::from(days)), self.today)
+            }
+            None => self.lock().events.values().cloned().collect(),
+        };
+        events.sort_by(|a, b| start_of(a).cmp(start_of(b)));
+        take(events, limit)
+            .into_iter()
+            .map(|event| Ok(serde_json::from_value(event)?))
+            .collect()
+    }
+
+    async fn get_events_between(&self, oldest: NaiveDate, newest: NaiveDate) -> Result<Vec<Event>> {
+        let mut events = self.events_between(oldest, newest);
+        events.sort_by(|a, b| start_of(a).cmp(start_of(b)));
+        events
+            .into_iter()
+            .map(|event| Ok(serde_json::from_value(event)?))
+            .collect()
+    }
+
+    async fn bulk_create_events(&self, events: Vec<Event>) -> Result<Vec<Event>> {
+        let mut state = self.lock();
+        events
+            .into_iter()
+            .map(|event| self.store_event(&mut state, event))
+            .collect()
+    }
+
+    async fn get_activity_streams(
+        &self,
+        activity_id: &str,
+        streams: Option<Vec<String>>,
+    ) -> Result<Value> {
+        let seeded = self.lock().streams.get(activity_id).cloned();
+        let Some(value) = seeded else {
+            return self.activity_extra(activity_id, json!([]));
+        };
+        Ok(match (value, streams) {
+            (Value::Object(all), Some(keys)) => Value::Object(
+                all.into_iter()
+                    .filter(|(key, _)| keys.contains(key))
+                    .collect(),
+            ),
+            (Value::Array(all), Some(keys)) => Value::Array(
+                all.into_iter()
+                    .filter(|stream| {
+                        stream
+                            .get("type")
+                            .and_then(Value::as_str)
+                            .is_some_and(|kind| keys.iter().any(|key| key == kind))
+                    })
+                    .collect(),
+            ),
+            (value, _) => value,
+        })
+    }
+
+    async fn get_activity_intervals(&self, activity_id: &str) -> Result<Value> {
+        let seeded = self.lock().intervals.get(activity_id).cloned();
+        match seeded {
+            Some(intervals) => Ok(intervals),
+            None => self.activity_extra(
+                activity_id,
+                json!({"id": activity_id, "icu_intervals": [], "icu_groups": []}),
+            ),
+        }
+    }
+
+    async fn get_best_efforts(
+        &self,
+        activity_id: &str,
+        _options: Option<BestEffortsOptions>,
+    ) -> Result<Value> {
+        self.activity_extra(activity_id, json!({"efforts": []}))
+    }
+
+    async fn get_activity_details(&self, activity_id: &str) -> Result<Value> {
+        self.lock()
+            .activities
+            .get(activity_id)
+            .cloned()
+            .ok_or_else(|| not_found("activity", activity_id))
+    }
+
+    async fn get_activity_messages(&self, activity_id: &str) -> Result<Vec<ActivityMessage>> {
+        self.activity_extra(activity_id, Value::Null)?;
+        Ok(self
+            .lock()
+            .messages
+            .get(activity_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn add_activity_message(&self, activity_id: &str, content: &str) -> Result<Value> {
+        self.activity_extra(activity_id, Value::Null)?;
+        let id = self.generate_id() as i64;
+        let message = ActivityMessage {
+            id,
+            athlete_id: Some(self.athlete.id.clone()),
+            name: self.athlete.name.clone(),
+            created: Some(format!("{}T00:00:00Z", self.today)),
+            message_type: Some("TEXT".to_string()),
+            content: Some(content.to_string()),
+            activity_id: Some(activity_id.to_string()),
+            start_index: None,
+            end_index: None,
+            attachment_url: None,
+            attachment_mime_type: None,
+            deleted: None,
+        };
+        self.lock()
+            .messages
+            .entry(activity_id.to_string())
+            .or_default()
+            .push(message);
+        Ok(json!({"id": id}))
+    }
+
+    async fn search_activities(
+        &self,
+        query: &str,
+        limit: Option<u32>,
+    ) -> Result<Vec<ActivitySummary>> {
+        summaries(self.search(query, limit)?)
+    }
+
+    async fn search_activities_full(&self, query: &str, limit: Option<u32>) -> Result<Value> {
+        Ok(Value::Array(self.search(query, limit)?))
+    }
+
+    async fn get_activities_csv(&self) -> Result<String> {
+        let mut csv =
+            String::from("id,start_date_local,name,type,moving_time,distance,icu_training_load\n");
+        for activity in self.activities_newest_first() {
+            let field = |key: &str| match activity.get(key) {
+                Some(Value::String(text)) => text.replace(',', " "),
+                Some(Value::Null) | None => String::new(),
+                Some(other) => other.to_string(),
+            };
+            csv.push_str(
+                &[
+                    "id",
+                    "start_date_local",
+                    "name",
+                    "type",
+                    "moving_time",
+                    "distance",
+                    "icu_training_load",
+                ]
+                .map(field)
+                .join(","),
+            );
+            csv.push('\n');
+        }
+        Ok(csv)
+    }
+
+    async fn update_activity(&self, activity_id: &str, fields: &Value) -> Result<Value> {
+        let mut state = self.lock();
+        let activity = state
+            .activities
+            .get_mut(activity_id)
+            .ok_or_else(|| not_found("activity", activity_id))?;
+        merge(activity, fields);
+        Ok(activity.clone())
+    }
+
+    async fn download_activity_file(
+        &self,
+        activity_id: &str,
+        _output_path: Option<std::path::PathBuf>,
+    ) -> Result<Option<String>> {
+        Err(not_found("activity file", activity_id))
+    }
+
+    async fn download_activity_file_with_progress(
+        &self,
+        activity_id: &str,
+        _output_path: Option<std::path::PathBuf>,
+        _progress_tx: tokio::sync::mpsc::Sender<DownloadProgress>,
+        _cancel_rx: tokio::sync::watch::Receiver<bool>,
+    ) -> Result<Option<String>> {
+        Err(not_found("activity file", activity_id))
+    }
+
+    async fn download_fit_file(
+        &self,
+        activity_id: &str,
+        _output_path: Option<std::path::PathBuf>,
+    ) -> Result<Option<String>> {
+        Err(not_found("FIT file", activity_id))
+    }
+
+    async fn download_gpx_file(
+        &self,
+        activity_id: &str,
+        _output_path: Option<std::path::PathBuf>,
+    ) -> Result<Option<String>> {
+        Err(not_found("GPX file", activity_id))
+    }
+
+    /// Creates an activity from the upload. FIT files are decoded for the
+    /// start time, sport, duration, distance and averages; other formats
+    /// start "today" with no metrics.
+    async fn upload_activity_file(&self, upload: &ActivityUpload) -> Result<ActivityUploadResult> {
+        let id = format!("i{}", self.generate_id());
+        let file_name = upload
+            .path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("Upload")
+            .to_string();
+        let mut activity = json!({
+            "id": id,
+            "name": upload.name.clone().unwrap_or(file_name),
+            "description": upload.description,
+            "external_id": upload.external_id,
+            "start_date_local": format!("{}T00:00:00", self.today),
+        });
+        let session = std::fs::read(&upload.path)
+            .ok()
+            .and_then(|bytes| crate::fit::FitActivity::decode(&bytes).ok())
+            .and_then(|fit| fit.sessions.into_iter().next());
+        if let Some(session) = session {
+            if let Some(start) = session
+                .start_time
+                .and_then(|start| chrono::DateTime::from_timestamp(start, 0))
+            {
+                let start = start.naive_utc().format("%Y-%m-%dT%H:%M:%S").to_string();
+                activity["start_date_local"] = json!(start);
+                activity["start_date"] = json!(format!("{start}Z"));
+            }
+            activity["type"] = json!(match session.sport.as_deref() {
+                Some("running") => "Run",
+                Some("cycling") => "Ride",
+                Some("swimming") => "Swim",
+                Some("walking") => "Walk",
+                Some("hiking") => "Hike",
+                _ => "Workout",
+            });
+            activity["moving_time"] = json!(session.total_timer_time.map(|secs| secs as i64));
+            activity["elapsed_time"] = json!(session.total_elapsed_time.map(|secs| secs as i64));
+            activity["distance"] = json!(session.total_distance);
+            activity["average_heartrate"] = json!(session.avg_heart_rate);
+            activity["average_watts"] = json!(session.avg_power);
+            activity["icu_weighted_avg_watts"] = json!(session.normalized_power);
+        }
+        self.lock().activities.insert(id.clone(), activity);
+        Ok(serde_json::from_value(json!({
+            "id": id,
+            "icu_athlete_id": self.athlete.id,
+            "activities": [{"id": id}],
+        }))?)
+    }
+
+    async fn get_gear_list(&self) -> Result<Value> {
+        Ok(Value::Array(self.lock().gear.clone()))
+    }
+
+    async fn get_sport_settings(&self) -> Result<SportSettings> {
+        let settings = Value::Array(self.lock().sport_settings.clone());
+        Ok(SportSettings::from_value(&settings).unwrap_or_default())
+    }
+
+    async fn get_power_curves(&self, _days_back: Option<i32>, _sport: &str) -> Result<Value> {
+        Ok(self
+            .lock()
+            .power_curves
+            .clone()
+            .unwrap_or_else(|| json!({"list": []})))
+    }
+
+    async fn get_gap_histogram(&self, activity_id: &str) -> Result<Value> {
+        self.activity_extra(activity_id, json!([]))
+    }
+
+    async fn delete_activity(&self, activity_id: &str) -> Result<()> {
+        let mut state = self.lock();
+        state
+            .activities
+            .remove(activity_id)
+            .ok_or_else(|| not_found("activity", activity_id))?;
+        state.streams.remove(activity_id);
+        state.intervals.remove(activity_id);
+        state.messages.remove(activity_id);
+        Ok(())
+    }
+
+    async fn get_activities_around(
+        &self,
+        activity_id: &str,
+        limit: Option<u32>,
+        _route_id: Option<i64>,
+    ) -> Result<Value> {
+        let activities = self.activities_newest_first();
+        let index = activities
+            .iter()
+            .position(|activity| id_of(activity).as_deref() == Some(activity_id))
+            .ok_or_else(|| not_found("activity", activity_id))?;
+        let half = limit.unwrap_or(10) as usize / 2;
+        let start = index.saturating_sub(half);
+        let end = (index + half + 1).min(activities.len());
+        Ok(Value::Array(activities[start..end].to_vec()))
+    }
+
+    async fn search_intervals(
+        &self,
+        _min_secs: u32,
+        _max_secs: u32,
+        _min_intensity: u32,
+        _max_intensity: u32,
+        _interval_type: Option<String>,
+        _min_reps: Option<u32>,
+        _max_reps: Option<u32>,
+        _limit: Option<u32>,
+    ) -> Result<Value> {
+        Ok(json!([]))
+    }
+
+    async fn get_power_histogram(&self, activity_id: &str) -> Result<Value> {
+        self.activity_extra(activity_id, json!([]))
+    }
+
+    async fn get_hr_histogram(&self, activity_id: &str) -> Result<Value> {
+        self.activity_extra(activity_id, json!([]))
+    }
+
+    async fn get_pace_histogram(&self, activity_id: &str) -> Result<Value> {
+        self.activity_extra(activity_id, json!([]))
+    }
+
+    /// CTL, ATL, form and 7-day ramp rate from activity training loads.
+    async fn get_fitness_summary(&self) -> Result<Value> {
+        let (ctl, atl) = self.fitness_on(self.today);
+        let (week_ago_ctl, _) = self.fitness_on(self.today - Duration::days(7));
+        Ok(json!([{
+            "date": self.today.to_string(),
+            "fitness": ctl,
+            "fatigue": atl,
+            "form": ctl - atl,
+            "rampRate": ctl - week_ago_ctl,
+        }]))
+    }
+
+    async fn get_wellness(&self, days_back: Option<i32>) -> Result<Value> {
+        let oldest = days_back.map(|days| self.today - Duration::days(i64::from(days)));
+        Ok(Value::Array(
+            self.lock()
+                .wellness
+                .iter()
+                .filter(|(date, _)| {
+                    let date = parse_date("wellness", date).ok();
+                    oldest.is_none_or(|oldest| {
+                        date.is_some_and(|date| date >= oldest && date <= self.today)
+                    })
+                })
+                .map(|(_, entry)| entry.clone())
+                .collect(),
+        ))
+    }
+
+    async fn get_wellness_for_date(&self, date: &str) -> Result<Value> {
+        Ok(self
+            .lock()
+            .wellness
+            .get(date)
+            .cloned()
+            .unwrap_or_else(|| json!({"id": date})))
+    }
+
+    async fn update_wellness(&self, date: &str, data: &Value) -> Result<Value> {
+        parse_date("date", date)?;
+        let mut state = self.lock();
+        let entry = state
+            .wellness
+            .entry(date.to_string())
+            .or_insert_with(|| json!({"id": date}));
+        merge(entry, data);
+        Ok(entry.clone())
+    }
+
+    async fn update_wellness_bulk(&self, entries: &[Value]) -> Result<()> {
+        for entry in entries {
+            let date = required_id(entry, "wellness")?;
+            self.update_wellness(&date, entry).await?;
+        }
+        Ok(())
+    }
+
+    async fn get_upcoming_workouts(
+        &self,
+        days_ahead: Option<u32>,
+        limit: Option<u32>,
+        category: Option<String>,
+    ) -> Result<Value> {
+        let newest =
+            self.today + Duration::days(i64::from(days_ahead.unwrap_or(DEFAULT_UPCOMING_DAYS)));
+        let mut events = self
+            .events_between(self.today, newest)
+            .into_iter()
+            .filter(|event| {
+                category.as_deref().is_none_or(|category| {
+                    event.get("category").and_then(Value::as_str) == Some(category)
+                })
+            })
+            .collect::<Vec<_>>();
+        events.sort_by(|a, b| start_of(a).cmp(start_of(b)));
+        Ok(Value::Array(take(events, limit)))
+    }
+
+    async fn update_event(&self, event_id: &str, fields: &Value) -> Result<Value> {
+        let mut state = self.lock();
+        let event = state
+            .events
+            .get_mut(event_id)
+            .ok_or_else(|| not_found("event", event_id))?;
+        merge(event, fields);
+        Ok(event.clone())
+    }
+
+    async fn bulk_delete_events(&self, event_ids: Vec<String>) -> Result<()> {
+        let mut state = self.lock();
+        for id in event_ids {
+            state.events.remove(&id);
+        }
+        Ok(())
+    }
+
+    async fn duplicate_event(
+        &self,
+        event_id: &str,
+        num_copies: Option<u32>,
+        weeks_between: Option<u32>,
+    ) -> Result<Vec<Event>> {
+        let mut state = self.lock();
+        let original = state
+            .events
+            .get(event_id)
+            .cloned()
+            .ok_or_else(|| not_found("event", event_id))?;
+        let start = parse_date("start_date_local", start_of(&original))?;
+        let time = start_of(&original)
+            .get(10..)
+            .unwrap_or_default()
+            .to_string();
+        let weeks = i64::from(weeks_between.unwrap_or(1));
+        (1..=i64::from(num_copies.unwrap_or(1)))
+            .map(|copy| {
+                let id = self.generate_id().to_string();
+                let mut event = original.clone();
+                event["id"] = json!(id);
+                event["start_date_local"] =
+                    json!(format!("{}{time}", start + Duration::weeks(copy * weeks)));
+                state.events.insert(id, event.clone());
+                Ok(serde_json::from_value(event)?)
+            })
+            .collect()
+    }
+
+    async fn get_hr_curves(&self, _days_back: Option<i32>, _sport: &str) -> Result<Value> {
+        Ok(self
+            .lock()
+            .hr_curves
+            .clone()
+            .unwrap_or_else(|| json!({"list": []})))
+    }
+
+    async fn get_pace_curves(&self, _days_back: Option<i32>, _sport: &str) -> Result<Value> {
+        Ok(self
+            .lock()
+            .pace_curves
+            .clone()
+            .unwrap_or_else(|| json!({"list": []})))
+    }
+
+    async fn get_curves_between(
+        &self,
+        kind: CurveKind,
+        _sport: &str,
+        _oldest: NaiveDate,
+        _newest: NaiveDate,
+    ) -> Result<Value> {
+        let state = self.lock();
+        let curves = match kind {
+            CurveKind::Power => &state.power_curves,
+            CurveKind::HeartRate => &state.hr_curves,
+            CurveKind::Pace => &state.pace_curves,
+        };
+        Ok(curves.clone().unwrap_or_else(|| json!({"list": []})))
+    }
+
+    async fn get_workout_library(&self) -> Result<Vec<WorkoutItem>> {
+        let state = self.lock();
+        state
+            .folders
+            .iter()
+            .chain(&state.workouts)
+            .map(|item| Ok(serde_json::from_value(item.clone())?))
+            .collect()
+    }
+
+    async fn get_workouts_in_folder(&self, folder_id: &str) -> Result<Vec<WorkoutItem>> {
+        let state = self.lock();
+        if !state
+            .folders
+            .iter()
+            .any(|folder| id_of(folder).as_deref() == Some(folder_id))
+        {
+            return Err(not_found("folder", folder_id));
+        }
+        state
+            .workouts
+            .iter()
+            .filter(|workout| {
+                workout
+                    .get("folder_id")
+                    .is_some_and(|id| id.to_string().trim_matches('"') == folder_id)
+            })
+            .map(|item| Ok(serde_json::from_value(item.clone())?))
+            .collect()
+    }
+
+    async fn create_folder(&self, folder: &Value) -> Result<Folder> {
+        let mut folder = folder.clone();
+        folder["id"] = json!(self.generate_id());
+        if folder.get("type").is_none() {
+            folder["type"] = json!("FOLDER");
+        }
+        self.lock().folders.push(folder.clone());
+        Ok(serde_json::from_value(folder)?)
+    }
+
+    async fn update_folder(&self, folder_id: &str, fields: &Value) -> Result<Value> {
+        let mut state = self.lock();
+        let folder = state
+            .folders
+            .iter_mut()
+            .find(|folder| id_of(folder).as_deref() == Some(folder_id))
+            .ok_or_else(|| not_found("folder", folder_id))?;
+        merge(folder, fields);
+        Ok(folder.clone())
+    }
+
+    async fn delete_folder(&self, folder_id: &str) -> Result<()> {
+        let mut state = self.lock();
+        let before = state.folders.len();
+        state
+            .folders
+            .retain(|folder| id_of(folder).as_deref() != Some(folder_id));
+        if state.folders.len() == before {
+            return Err(not_found("folder", folder_id));
+        }
+        state.workouts.retain(|workout| {
+            workout
+                .get("folder_id")
+                .is_none_or(|id| id.to_string().trim_matches('"') != folder_id)
+        });
+        Ok(())
+    }
+
+    async fn create_gear(&self, gear: &Value) -> Result<Value> {
+        let mut gear = gear.clone();
+        gear["id"] = json!(format!("g{}", self.generate_id()));
+        self.lock().gear.push(gear.clone());
+        Ok(gear)
+    }
+
+    async fn update_gear(&self, gear_id: &str, fields: &Value) -> Result<Value> {
+        let mut state = self.lock();
+        let gear = state
+            .gear
+            .iter_mut()
+            .find(|gear| id_of(gear).as_deref() == Some(gear_id))
+            .ok_or_else(|| not_found("gear", gear_id))?;
+        merge(gear, fields);
+        Ok(gear.clone())
+    }
+
+    async fn delete_gear(&self, gear_id: &str) -> Result<()> {
+        let mut state = self.lock();
+        let before = state.gear.len();
+        state
+            .gear
+            .retain(|gear| id_of(gear).as_deref() != Some(gear_id));
+        if state.gear.len() == before {
+            return Err(not_found("gear", gear_id));
+        }
+        Ok(())
+    }
+
+    async fn create_gear_reminder(&self, gear_id: &str, reminder: &Value) -> Result<Value> {
+        let mut reminder = reminder.clone();
+        reminder["id"] = json!(self.generate_id());
+        let mut state = self.lock();
+        let gear = state
+            .gear
+            .iter_mut()
+            .find(|gear| id_of(gear).as_deref() == Some(gear_id))
+            .ok_or_else(|| not_found("gear", gear_id))?;
+        if !gear.get("reminders").is_some_and(Value::is_array) {
+            gear["reminders"] = json!([]);
+        }
+        if let Some(reminders) = gear["reminders"].as_array_mut() {
+            reminders.push(reminder.clone());
+        }
+        Ok(reminder)
+    }
+
+    /// Merges `fields`; `reset` records today as `last_reset` and
+    /// `snooze_days` sets `snoozed_until`.
+    async fn update_gear_reminder(
+        &self,
+        gear_id: &str,
+        reminder_id: &str,
+        reset: bool,
+        snooze_days: u32,
+        fields: &Value,
+    ) -> Result<Value> {
+        let mut state = self.lock();
+        let reminder = state
+            .gear
+            .iter_mut()
+            .find(|gear| id_of(gear).as_deref() == Some(gear_id))
+            .ok_or_else(|| not_found("gear", gear_id))?
+            .get_mut("reminders")
+            .and_then(Value::as_array_mut)
+            .and_then(|reminders| {
+                reminders
+                    .iter_mut()
+                    .find(|reminder| id_of(reminder).as_deref() == Some(reminder_id))
+            })
+            .ok_or_else(|| not_found("gear reminder", reminder_id))?;
+        merge(reminder, fields);
+        if reset {
+            reminder["last_reset"] = json!(self.today.to_string());
+        }
+        if snooze_days > 0 {
+            reminder["snoozed_until"] =
+                json!((self.today + Duration::days(i64::from(snooze_days))).to_string());
+        }
+        Ok(reminder.clone())
+    }
+
+    async fn update_sport_settings(
+        &self,
+        sport_type: &str,
+        _recalc_hr_zones: bool,
+        fields: &Value,
+    ) -> Result<Value> {
+        let mut state = self.lock();
+        let index = self.sport_setting_index(&state, sport_type)?;
+        let setting = &mut state.sport_settings[index];
+        merge(setting, fields);
+        Ok(setting.clone())
+    }
+
+    async fn apply_sport_settings(&self, sport_type: &str) -> Result<Value> {
+        let state = self.lock();
+        let index = self.sport_setting_index(&state, sport_type)?;
+        Ok(state.sport_settings[index].clone())
+    }
+
+    async fn create_sport_settings(&self, settings: &Value) -> Result<Value> {
+        let mut settings = settings.clone();
+        settings["id"] = json!(self.generate_id());
+        self.lock().sport_settings.push(settings.clone());
+        Ok(settings)
+    }
+
+    async fn delete_sport_settings(&self, sport_type: &str) -> Result<()> {
+        let mut state = self.lock();
+        let index = self.sport_setting_index(&state, sport_type)?;
+        state.sport_settings.remove(index);
+        Ok(())
+    }
+}
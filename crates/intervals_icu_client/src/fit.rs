@@ -0,0 +1,751 @@
+//! Decoder for FIT activity files.
+//!
+//! `download_fit_file` returns the original recording, which keeps what the
+//! `/streams` endpoint drops: native per-second samples, developer fields
+//! written by Connect IQ apps and sensors (Stryd power metrics, CORE body
+//! temperature) and beat-to-beat RR intervals. [`FitActivity::decode`] reads
+//! the records, laps, sessions, device info, developer fields and HRV
+//! messages; [`FitActivity::to_streams`] converts them to the stream map
+//! returned by `GET /api/v1/activity/{id}/streams`.
+//!
+//! Only the FIT binary protocol is implemented here, not the full profile:
+//! fields outside the messages above are skipped.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use thiserror::Error;
+
+/// Seconds between the Unix epoch and the FIT epoch (1989-12-31T00:00:00Z).
+pub const FIT_EPOCH_OFFSET: i64 = 631_065_600;
+
+const MESG_FILE_ID: u16 = 0;
+const MESG_SESSION: u16 = 18;
+const MESG_LAP: u16 = 19;
+const MESG_RECORD: u16 = 20;
+const MESG_DEVICE_INFO: u16 = 23;
+const MESG_HRV: u16 = 78;
+const MESG_FIELD_DESCRIPTION: u16 = 206;
+
+const FIELD_TIMESTAMP: u8 = 253;
+const SEMICIRCLES_TO_DEGREES: f64 = 180.0 / 2_147_483_648.0;
+
+/// Reads one native stream sample from a record.
+type RecordSample = fn(&FitRecord) -> Option<f64>;
+
+/// Errors raised while decoding a FIT file.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FitError {
+    #[error("not a FIT file: {0}")]
+    InvalidHeader(String),
+    #[error("FIT data ends early at byte {0}")]
+    Truncated(usize),
+    #[error("FIT CRC mismatch: file says {expected:#06x}, computed {computed:#06x}")]
+    CrcMismatch { expected: u16, computed: u16 },
+    #[error("data message at byte {offset} uses undefined local message type {local}")]
+    UndefinedLocalMessage { local: u8, offset: usize },
+}
+
+/// One `record` message: the per-sample data of an activity.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FitRecord {
+    /// Unix seconds.
+    pub timestamp: Option<i64>,
+    /// Degrees.
+    pub latitude: Option<f64>,
+    /// Degrees.
+    pub longitude: Option<f64>,
+    /// Metres; `enhanced_altitude` when present.
+    pub altitude: Option<f64>,
+    pub heart_rate: Option<f64>,
+    /// Revolutions or steps per minute, including fractional cadence.
+    pub cadence: Option<f64>,
+    /// Metres from the start.
+    pub distance: Option<f64>,
+    /// Metres per second; `enhanced_speed` when present.
+    pub speed: Option<f64>,
+    /// Watts.
+    pub power: Option<f64>,
+    /// Device temperature in °C.
+    pub temperature: Option<f64>,
+    /// Developer fields by stream key (see [`FitDeveloperField::stream_key`]).
+    pub developer: BTreeMap<String, f64>,
+}
+
+/// One `lap` message.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FitLap {
+    /// Unix seconds.
+    pub start_time: Option<i64>,
+    /// Seconds.
+    pub total_elapsed_time: Option<f64>,
+    /// Seconds.
+    pub total_timer_time: Option<f64>,
+    /// Metres.
+    pub total_distance: Option<f64>,
+    pub avg_heart_rate: Option<f64>,
+    pub max_heart_rate: Option<f64>,
+    pub avg_power: Option<f64>,
+    pub max_power: Option<f64>,
+    /// Metres per second.
+    pub avg_speed: Option<f64>,
+    pub total_calories: Option<f64>,
+}
+
+/// One `session` message; multisport files have one per leg.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FitSession {
+    /// FIT sport name, e.g. `"running"`, `"cycling"`.
+    pub sport: Option<String>,
+    /// Unix seconds.
+    pub start_time: Option<i64>,
+    /// Seconds.
+    pub total_elapsed_time: Option<f64>,
+    /// Seconds.
+    pub total_timer_time: Option<f64>,
+    /// Metres.
+    pub total_distance: Option<f64>,
+    pub avg_heart_rate: Option<f64>,
+    pub max_heart_rate: Option<f64>,
+    pub avg_power: Option<f64>,
+    pub max_power: Option<f64>,
+    pub normalized_power: Option<f64>,
+    /// Metres per second.
+    pub avg_speed: Option<f64>,
+    pub total_calories: Option<f64>,
+}
+
+/// One `device_info` message: the recording device or a paired sensor.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FitDevice {
+    /// 0 is the recording device.
+    pub device_index: Option<u8>,
+    pub manufacturer: Option<u16>,
+    pub product: Option<u16>,
+    pub product_name: Option<String>,
+    pub serial_number: Option<u32>,
+    pub software_version: Option<f64>,
+}
+
+/// A developer field declared by a `field_description` message.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FitDeveloperField {
+    pub developer_data_index: u8,
+    pub field_number: u8,
+    pub name: String,
+    pub units: Option<String>,
+    /// Global message the field belongs to (20 for record fields).
+    pub native_message: Option<u16>,
+    #[serde(skip)]
+    base_type: u8,
+    #[serde(skip)]
+    scale: Option<f64>,
+    #[serde(skip)]
+    offset: Option<f64>,
+}
+
+impl FitDeveloperField {
+    /// Stream key for the field: its name in snake case, e.g. `"Form Power"`
+    /// becomes `"form_power"`.
+    #[must_use]
+    pub fn stream_key(&self) -> String {
+        stream_key(&self.name)
+    }
+}
+
+/// Decoded contents of a FIT activity file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct FitActivity {
+    /// `file_id.time_created`, Unix seconds.
+    pub time_created: Option<i64>,
+    pub records: Vec<FitRecord>,
+    pub laps: Vec<FitLap>,
+    pub sessions: Vec<FitSession>,
+    pub devices: Vec<FitDevice>,
+    pub developer_fields: Vec<FitDeveloperField>,
+    /// Beat-to-beat (RR) intervals in milliseconds, in recording order.
+    pub rr_intervals: Vec<f64>,
+}
+
+impl FitActivity {
+    /// Decode a FIT file, verifying the file CRC when one is present.
+    pub fn decode(bytes: &[u8]) -> Result<Self, FitError> {
+        Decoder::new(bytes)?.run()
+    }
+
+    /// Record streams keyed like the `/streams` endpoint: `time` (seconds
+    /// from the first record), `watts`, `heartrate`, `cadence`,
+    /// `velocity_smooth`, `altitude`, `distance`, `temp`, `lat`, `lng`, one
+    /// key per developer record field, and `rr_intervals` (milliseconds, not
+    /// aligned with `time`). Missing samples are `null`; streams with no
+    /// samples are omitted.
+    #[must_use]
+    pub fn to_streams(&self) -> Value {
+        let start = self.records.iter().find_map(|record| record.timestamp);
+        let mut streams = Map::new();
+        let time = self
+            .records
+            .iter()
+            .enumerate()
+            .map(|(index, record)| match (record.timestamp, start) {
+                (Some(timestamp), Some(start)) => (timestamp - start) as f64,
+                _ => index as f64,
+            })
+            .map(Some)
+            .collect::<Vec<_>>();
+        insert_stream(&mut streams, "time", time);
+
+        let native: [(&str, RecordSample); 9] = [
+            ("watts", |r| r.power),
+            ("heartrate", |r| r.heart_rate),
+            ("cadence", |r| r.cadence),
+            ("velocity_smooth", |r| r.speed),
+            ("altitude", |r| r.altitude),
+            ("distance", |r| r.distance),
+            ("temp", |r| r.temperature),
+            ("lat", |r| r.latitude),
+            ("lng", |r| r.longitude),
+        ];
+        for (key, sample) in native {
+            insert_stream(&mut streams, key, self.records.iter().map(sample).collect());
+        }
+
+        let developer_keys = self
+            .records
+            .iter()
+            .flat_map(|record| record.developer.keys())
+            .collect::<std::collections::BTreeSet<_>>();
+        for key in developer_keys {
+            let target = if streams.contains_key(key.as_str()) {
+                format!("dev_{key}")
+            } else {
+                key.clone()
+            };
+            insert_stream(
+                &mut streams,
+                &target,
+                self.records
+                    .iter()
+                    .map(|record| record.developer.get(key).copied())
+                    .collect(),
+            );
+        }
+
+        if !self.rr_intervals.is_empty() {
+            streams.insert("rr_intervals".into(), json!(self.rr_intervals));
+        }
+        Value::Object(streams)
+    }
+}
+
+fn insert_stream(streams: &mut Map<String, Value>, key: &str, samples: Vec<Option<f64>>) {
+    if samples.iter().any(Option::is_some) {
+        streams.insert(key.to_string(), json!(samples));
+    }
+}
+
+fn stream_key(name: &str) -> String {
+    let mut key = String::with_capacity(name.len());
+    for c in name.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            key.push(c.to_ascii_lowercase());
+        } else if !key.ends_with('_') && !key.is_empty() {
+            key.push('_');
+        }
+    }
+    key.trim_end_matches('_').to_string()
+}
+
+/// FIT CRC-16 as defined by the FIT protocol.
+#[must_use]
+pub fn fit_crc(bytes: &[u8]) -> u16 {
+    const TABLE: [u16; 16] = [
+        0x0000, 0xCC01, 0xD801, 0x1400, 0xF001, 0x3C00, 0x2800, 0xE401, 0xA001, 0x6C00, 0x7800,
+        0xB401, 0x5000, 0x9C01, 0x8801, 0x4400,
+    ];
+    bytes.iter().fold(0u16, |mut crc, &byte| {
+        for nibble in [byte & 0x0F, byte >> 4] {
+            let tmp = TABLE[usize::from(crc & 0x0F)];
+            crc = (crc >> 4) & 0x0FFF;
+            crc = crc ^ tmp ^ TABLE[usize::from(nibble)];
+        }
+        crc
+    })
+}
+
+/// A decoded field: numeric elements (invalid ones as `None`) or text.
+#[derive(Debug, Clone)]
+enum FieldValue {
+    Numbers(Vec<Option<f64>>),
+    Text(String),
+}
+
+impl FieldValue {
+    fn number(&self) -> Option<f64> {
+        match self {
+            Self::Numbers(values) => values.first().copied().flatten(),
+            Self::Text(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FieldDefinition {
+    number: u8,
+    size: usize,
+    base_type: u8,
+}
+
+#[derive(Debug, Clone)]
+struct DeveloperFieldDefinition {
+    number: u8,
+    size: usize,
+    developer_data_index: u8,
+}
+
+#[derive(Debug, Clone)]
+struct MessageDefinition {
+    global: u16,
+    big_endian: bool,
+    fields: Vec<FieldDefinition>,
+    developer_fields: Vec<DeveloperFieldDefinition>,
+}
+
+struct Message {
+    global: u16,
+    fields: HashMap<u8, FieldValue>,
+    developer: Vec<(u8, u8, FieldValue)>,
+}
+
+impl Message {
+    fn number(&self, field: u8) -> Option<f64> {
+        self.fields.get(&field).and_then(FieldValue::number)
+    }
+
+    fn scaled(&self, field: u8, scale: f64, offset: f64) -> Option<f64> {
+        self.number(field).map(|value| value / scale - offset)
+    }
+
+    fn time(&self, field: u8) -> Option<i64> {
+        self.number(field)
+            .map(|seconds| FIT_EPOCH_OFFSET + seconds as i64)
+    }
+
+    fn text(&self, field: u8) -> Option<String> {
+        match self.fields.get(&field)? {
+            FieldValue::Text(text) if !text.is_empty() => Some(text.clone()),
+            _ => None,
+        }
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    end: usize,
+    definitions: [Option<MessageDefinition>; 16],
+    last_timestamp: Option<u32>,
+    developer_fields: Vec<FitDeveloperField>,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(bytes: &'a [u8]) -> Result<Self, FitError> {
+        let header_size = usize::from(*bytes.first().ok_or(FitError::Truncated(0))?);
+        if header_size != 12 && header_size != 14 {
+            return Err(FitError::InvalidHeader(format!(
+                "header size {header_size}"
+            )));
+        }
+        let header = bytes.get(..header_size).ok_or(FitError::Truncated(0))?;
+        if &header[8..12] != b".FIT" {
+            return Err(FitError::InvalidHeader("missing .FIT signature".into()));
+        }
+        let data_size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let end = header_size + data_size;
+        if bytes.len() < end {
+            return Err(FitError::Truncated(bytes.len()));
+        }
+        if let Some(crc_bytes) = bytes.get(end..end + 2) {
+            let expected = u16::from_le_bytes([crc_bytes[0], crc_bytes[1]]);
+            let computed = fit_crc(&bytes[..end]);
+            if expected != 0 && expected != computed {
+                return Err(FitError::CrcMismatch { expected, computed });
+            }
+        }
+        Ok(Self {
+            bytes,
+            offset: header_size,
+            end,
+            definitions: Default::default(),
+            last_timestamp: None,
+            developer_fields: Vec::new(),
+        })
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], FitError> {
+        let slice = self
+            .bytes
+            .get(self.offset..self.offset + len)
+            .filter(|_| self.offset + len <= self.end)
+            .ok_or(FitError::Truncated(self.offset))?;
+        self.offset += len;
+        Ok(slice)
+    }
+
+    fn run(mut self) -> Result<FitActivity, FitError> {
+        let mut activity = FitActivity::default();
+        while self.offset < self.end {
+            let header_offset = self.offset;
+            let header = self.take(1)?[0];
+            if header & 0x80 != 0 {
+                // Compressed timestamp header: a data message whose timestamp is
+                // a 5-bit offset from the last full timestamp.
+                let local = (header >> 5) & 0x03;
+                let time_offset = u32::from(header & 0x1F);
+                let mut message = self.read_data(local, header_offset)?;
+                if let Some(last) = self.last_timestamp {
+                    let mut timestamp = (last & !0x1F) + time_offset;
+                    if time_offset < (last & 0x1F) {
+                        timestamp += 0x20;
+                    }
+                    self.last_timestamp = Some(timestamp);
+                    message.fields.insert(
+                        FIELD_TIMESTAMP,
+                        FieldValue::Numbers(vec![Some(f64::from(timestamp))]),
+                    );
+                }
+                self.apply(message, &mut activity);
+            } else if header & 0x40 != 0 {
+                let definition = self.read_definition(header & 0x20 != 0)?;
+                self.definitions[usize::from(header & 0x0F)] = Some(definition);
+            } else {
+                let message = self.read_data(header & 0x0F, header_offset)?;
+                if let Some(timestamp) = message.number(FIELD_TIMESTAMP) {
+                    self.last_timestamp = Some(timestamp as u32);
+                }
+                self.apply(message, &mut activity);
+            }
+        }
+        activity.developer_fields = self.developer_fields;
+        Ok(activity)
+    }
+
+    fn read_definition(
+        &mut self,
+        has_developer_fields: bool,
+    ) -> Result<MessageDefinition, FitError> {
+        let fixed = self.take(5)?;
+        let big_endian = fixed[1] == 1;
+        let global = if big_endian {
+            u16::from_be_bytes([fixed[2], fixed[3]])
+        } else {
+            u16::from_le_bytes([fixed[2], fixed[3]])
+        };
+        let field_count = usize::from(fixed[4]);
+        let fields = self
+            .take(field_count * 3)?
+            .chunks(3)
+            .map(|field| FieldDefinition {
+                number: field[0],
+                size: usize::from(field[1]),
+                base_type: field[2],
+            })
+            .collect();
+        let developer_fields = if has_developer_fields {
+            let count = usize::from(self.take(1)?[0]);
+            self.take(count * 3)?
+                .chunks(3)
+                .map(|field| DeveloperFieldDefinition {
+                    number: field[0],
+                    size: usize::from(field[1]),
+                    developer_data_index: field[2],
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Ok(MessageDefinition {
+            global,
+            big_endian,
+            fields,
+            developer_fields,
+        })
+    }
+
+    fn read_data(&mut self, local: u8, header_offset: usize) -> Result<Message, FitError> {
+        let definition = self.definitions[usize::from(local)].clone().ok_or(
+            FitError::UndefinedLocalMessage {
+                local,
+                offset: header_offset,
+            },
+        )?;
+        let mut fields = HashMap::with_capacity(definition.fields.len());
+        for field in &definition.fields {
+            let raw = self.take(field.size)?;
+            fields.insert(
+                field.number,
+                decode_field(raw, field.base_type, definition.big_endian),
+            );
+        }
+        let mut developer = Vec::with_capacity(definition.developer_fields.len());
+        for field in &definition.developer_fields {
+            let raw = self.take(field.size)?;
+            let base_type = self
+                .developer_field(field.developer_data_index, field.number)
+                .map_or(0x0D, |description| description.base_type);
+            developer.push((
+                field.developer_data_index,
+                field.number,
+                decode_field(raw, base_type, definition.big_endian),
+            ));
+        }
+        Ok(Message {
+            global: definition.global,
+            fields,
+            developer,
+        })
+    }
+
+    fn developer_field(&self, index: u8, number: u8) -> Option<&FitDeveloperField> {
+        self.developer_fields
+            .iter()
+            .find(|field| field.developer_data_index == index && field.field_number == number)
+    }
+
+    fn apply(&mut self, message: Message, activity: &mut FitActivity) {
+        match message.global {
+            MESG_FILE_ID => activity.time_created = message.time(4),
+            MESG_RECORD => {
+                let record = self.record(&message);
+                activity.records.push(record);
+            }
+            MESG_LAP => activity.laps.push(FitLap {
+                start_time: message.time(2),
+                total_elapsed_time: message.scaled(7, 1000.0, 0.0),
+                total_timer_time: message.scaled(8, 1000.0, 0.0),
+                total_distance: message.scaled(9, 100.0, 0.0),
+                avg_speed: message.scaled(13, 1000.0, 0.0),
+                avg_heart_rate: message.number(15),
+                max_heart_rate: message.number(16),
+                avg_power: message.number(19),
+                max_power: message.number(20),
+                total_calories: message.number(11),
+            }),
+            MESG_SESSION => activity.sessions.push(FitSession {
+                sport: message.number(5).map(|sport| sport_name(sport as u8)),
+                start_time: message.time(2),
+                total_elapsed_time: message.scaled(7, 1000.0, 0.0),
+                total_timer_time: message.scaled(8, 1000.0, 0.0),
+                total_distance: message.scaled(9, 100.0, 0.0),
+                avg_speed: message.scaled(14, 1000.0, 0.0),
+                avg_heart_rate: message.number(16),
+                max_heart_rate: message.number(17),
+                avg_power: message.number(20),
+                max_power: message.number(21),
+                normalized_power: message.number(34),
+                total_calories: message.number(11),
+            }),
+            MESG_DEVICE_INFO => activity.devices.push(FitDevice {
+                device_index: message.number(0).map(|value| value as u8),
+                manufacturer: message.number(2).map(|value| value as u16),
+                product: message.number(4).map(|value| value as u16),
+                product_name: message.text(27),
+                serial_number: message.number(3).map(|value| value as u32),
+                software_version: message.scaled(5, 100.0, 0.0),
+            }),
+            MESG_HRV => {
+                if let Some(FieldValue::Numbers(times)) = message.fields.get(&0) {
+                    activity.rr_intervals.extend(times.iter().flatten());
+                }
+            }
+            MESG_FIELD_DESCRIPTION => {
+                let (Some(index), Some(number)) = (message.number(0), message.number(1)) else {
+                    return;
+                };
+                let description = FitDeveloperField {
+                    developer_data_index: index as u8,
+                    field_number: number as u8,
+                    name: message
+                        .text(3)
+                        .unwrap_or_else(|| format!("developer_{index}_{number}")),
+                    units: message.text(8),
+                    native_message: message.number(14).map(|value| value as u16),
+                    base_type: message.number(2).map_or(0x0D, |value| value as u8),
+                    scale: message.number(6),
+                    offset: message.number(7),
+                };
+                self.developer_fields.retain(|field| {
+                    field.developer_data_index != description.developer_data_index
+                        || field.field_number != description.field_number
+                });
+                self.developer_fields.push(description);
+            }
+            _ => {}
+        }
+    }
+
+    fn record(&self, message: &Message) -> FitRecord {
+        let mut record = FitRecord {
+            timestamp: message.time(FIELD_TIMESTAMP),
+            latitude: message
+                .number(0)
+                .map(|value| value * SEMICIRCLES_TO_DEGREES),
+            longitude: message
+                .number(1)
+                .map(|value| value * SEMICIRCLES_TO_DEGREES),
+            altitude: message
+                .scaled(78, 5.0, 500.0)
+                .or_else(|| message.scaled(2, 5.0, 500.0)),
+            heart_rate: message.number(3),
+            cadence: message
+                .number(4)
+                .map(|cadence| cadence + message.scaled(53, 128.0, 0.0).unwrap_or(0.0)),
+            distance: message.scaled(5, 100.0, 0.0),
+            speed: message
+                .scaled(73, 1000.0, 0.0)
+                .or_else(|| message.scaled(6, 1000.0, 0.0)),
+            power: message.number(7),
+            temperature: message.number(13),
+            developer: BTreeMap::new(),
+        };
+        for (index, number, value) in &message.developer {
+            let (Some(description), Some(value)) =
+                (self.developer_field(*index, *number), value.number())
+            else {
+                continue;
+            };
+            let value = value
+                / description
+                    .scale
+                    .filter(|scale| *scale != 0.0)
+                    .unwrap_or(1.0)
+                - description.offset.unwrap_or(0.0);
+            record.developer.insert(description.stream_key(), value);
+        }
+        record
+    }
+}
+
+/// Decode a field's bytes according to its FIT base type.
+fn decode_field(raw: &[u8], base_type: u8, big_endian: bool) -> FieldValue {
+    let type_number = base_type & 0x1F;
+    if type_number == 7 {
+        let text = raw.split(|byte| *byte == 0).next().unwrap_or_default();
+        return FieldValue::Text(String::from_utf8_lossy(text).into_owned());
+    }
+    let size = match type_number {
+        0 | 1 | 2 | 10 | 13 => 1,
+        3 | 4 | 11 => 2,
+        5 | 6 | 8 | 12 => 4,
+        9 | 14 | 15 | 16 => 8,
+        _ => 1,
+    };
+    if !raw.len().is_multiple_of(size) {
+        return FieldValue::Numbers(Vec::new());
+    }
+    let values = raw
+        .chunks(size)
+        .map(|chunk| {
+            let mut bytes = [0u8; 8];
+            if big_endian {
+                bytes[8 - size..].copy_from_slice(chunk);
+                bytes.reverse();
+            } else {
+                bytes[..size].copy_from_slice(chunk);
+            }
+            let unsigned = u64::from_le_bytes(bytes);
+            let all_ones = if size == 8 {
+                u64::MAX
+            } else {
+                (1u64 << (size * 8)) - 1
+            };
+            let sign_bit = 1u64 << (size * 8 - 1);
+            match type_number {
+                // Unsigned and enum types: all bits set means invalid.
+                0 | 2 | 4 | 6 | 13 | 15 => (unsigned != all_ones).then_some(unsigned as f64),
+                // "z" types: zero means invalid.
+                10 | 11 | 12 | 16 => (unsigned != 0).then_some(unsigned as f64),
+                // Signed types: the maximum positive value means invalid.
+                1 | 3 | 5 | 14 => (unsigned != sign_bit - 1).then(|| {
+                    if unsigned & sign_bit != 0 {
+                        unsigned as i64 as f64 - (all_ones as f64 + 1.0) * f64::from(size < 8)
+                    } else {
+                        unsigned as f64
+                    }
+                }),
+                8 => Some(f64::from(f32::from_bits(unsigned as u32)))
+                    .filter(|value| value.is_finite()),
+                9 => Some(f64::from_bits(unsigned)).filter(|value| value.is_finite()),
+                _ => None,
+            }
+        })
+        .collect();
+    FieldValue::Numbers(values)
+}
+
+/// FIT `sport` enum names for the common sports; others as `sport_<n>`.
+fn sport_name(sport: u8) -> String {
+    match sport {
+        0 => "generic",
+        1 => "running",
+        2 => "cycling",
+        3 => "transition",
+        4 => "fitness_equipment",
+        5 => "swimming",
+        10 => "training",
+        11 => "walking",
+        12 => "cross_country_skiing",
+        13 => "alpine_skiing",
+        15 => "rowing",
+        17 => "hiking",
+        _ => return format!("sport_{sport}"),
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc_matches_protocol_reference() {
+        assert_eq!(fit_crc(&[]), 0);
+        assert_eq!(fit_crc(b"123456789"), 0xBB3D);
+    }
+
+    #[test]
+    fn decode_field_handles_signed_invalid_and_big_endian() {
+        let number =
+            |raw: &[u8], base_type, big_endian| decode_field(raw, base_type, big_endian).number();
+        assert_eq!(number(&[0xFE, 0xFF], 0x83, false), Some(-2.0));
+        assert_eq!(number(&[0xFF, 0x7F], 0x83, false), None);
+        assert_eq!(number(&[0x01, 0x2C], 0x84, true), Some(300.0));
+        assert_eq!(number(&[0xFF, 0xFF], 0x84, false), None);
+        assert_eq!(number(&[0, 0, 0, 0], 0x8C, false), None);
+        assert_eq!(number(&[0x9C], 0x01, false), Some(-100.0));
+        match decode_field(b"CORE\0\0", 0x07, false) {
+            FieldValue::Text(text) => assert_eq!(text, "CORE"),
+            other => panic!("expected text, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn stream_key_is_snake_case() {
+        assert_eq!(stream_key("Form Power"), "form_power");
+        assert_eq!(stream_key("core_temperature"), "core_temperature");
+        assert_eq!(
+            stream_key(" Leg Spring Stiffness (kN/m) "),
+            "leg_spring_stiffness_kn_m"
+        );
+    }
+
+    #[test]
+    fn rejects_files_without_fit_signature() {
+        assert!(matches!(
+            FitActivity::decode(b"<gpx></gpx>"),
+            Err(FitError::InvalidHeader(_))
+        ));
+        assert_eq!(FitActivity::decode(&[]), Err(FitError::Truncated(0)));
+    }
+}
@@ -2,7 +2,15 @@
 //!
 //! This module provides a reqwest-based implementation of the [`IntervalsClient`](crate::IntervalsClient) trait.
 
+use crate::auth::{AuthState, Credentials, TokenRefresher};
+use crate::cassette::{Cassette, Scrubber};
 use crate::circuit_breaker::CircuitBreaker;
+use crate::config::HttpSettings;
+use crate::domains::athlete::CoachedAthlete;
+use crate::domains::curve::CurveKind;
+use crate::pagination::{self, DateRange, PageStream};
+use crate::rate_limit::{RateLimiter, parse_retry_after};
+use crate::retry::RetryPolicy;
 use crate::traits::{
     ActivityService, AthleteService, EventService, FitnessService, GearService, RouteService,
     SportSettingsService, WeatherService, WellnessService, WorkoutService,
@@ -13,9 +21,9 @@ use crate::{
 use ::metrics::{counter, histogram};
 use async_trait::async_trait;
 use base64::{Engine as _, engine::general_purpose::STANDARD};
-use chrono::{Duration, Utc};
+use chrono::{Duration, NaiveDate, Utc};
 use futures_util::StreamExt;
-use secrecy::{ExposeSecret, SecretString};
+use secrecy::ExposeSecret;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::io::AsyncWriteExt;
@@ -25,9 +33,227 @@ use tokio::io::AsyncWriteExt;
 pub struct ReqwestIntervalsClient {
     base_url: String,
     athlete_id: String,
-    api_key: SecretString,
+    auth: Arc<AuthState>,
     client: reqwest::Client,
     circuit_breaker: Arc<CircuitBreaker>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    cassette: Option<Arc<Cassette>>,
+}
+
+/// Status codes that are retried for idempotent requests.
+const RETRYABLE_STATUSES: [u16; 4] = [429, 502, 503, 504];
+
+/// Builder for [`ReqwestIntervalsClient`] with transport and resilience settings.
+///
+/// ```no_run
+/// # use intervals_icu_client::http_client::ReqwestIntervalsClient;
+/// # use secrecy::SecretString;
+/// # use std::time::Duration;
+/// let client = ReqwestIntervalsClient::builder(
+///     "https://intervals.icu",
+///     "i12345",
+///     SecretString::new("key".into()),
+/// )
+/// .request_timeout(Some(Duration::from_secs(30)))
+/// .user_agent("my-app/1.0")
+/// .circuit_breaker(10, Duration::from_secs(60))
+/// .build()?;
+/// # Ok::<(), intervals_icu_client::IntervalsError>(())
+/// ```
+pub struct ReqwestIntervalsClientBuilder {
+    base_url: String,
+    athlete_id: String,
+    credentials: Credentials,
+    token_refresher: Option<Arc<dyn TokenRefresher>>,
+    settings: HttpSettings,
+    http_client: Option<reqwest::Client>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    cassette: Option<Arc<Cassette>>,
+}
+
+impl std::fmt::Debug for ReqwestIntervalsClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReqwestIntervalsClientBuilder")
+            .field("base_url", &self.base_url)
+            .field("athlete_id", &self.athlete_id)
+            .field("credentials", &self.credentials.kind())
+            .field("settings", &self.settings)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ReqwestIntervalsClientBuilder {
+    /// Replace all transport and resilience settings, e.g. with `Config::http`.
+    #[must_use]
+    pub fn settings(mut self, settings: HttpSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    #[must_use]
+    pub fn request_timeout(mut self, timeout: Option<std::time::Duration>) -> Self {
+        self.settings.request_timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub fn connect_timeout(mut self, timeout: Option<std::time::Duration>) -> Self {
+        self.settings.connect_timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub fn pool_idle_timeout(mut self, timeout: Option<std::time::Duration>) -> Self {
+        self.settings.pool_idle_timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.settings.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.settings.user_agent = user_agent.into();
+        self
+    }
+
+    /// Route every request through this proxy URL.
+    #[must_use]
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.settings.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Trust an additional PEM root certificate.
+    #[must_use]
+    pub fn root_certificate(mut self, path: impl Into<PathBuf>) -> Self {
+        self.settings.root_certificates.push(path.into());
+        self
+    }
+
+    #[must_use]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.settings.retry_max = policy.max_retries;
+        self.settings.retry_base_delay = policy.base_delay;
+        self
+    }
+
+    #[must_use]
+    pub fn circuit_breaker(
+        mut self,
+        failure_threshold: u32,
+        reset_timeout: std::time::Duration,
+    ) -> Self {
+        self.settings.circuit_failure_threshold = failure_threshold;
+        self.settings.circuit_reset_timeout = reset_timeout;
+        self
+    }
+
+    /// Renew OAuth access tokens with `refresher` before they expire and once
+    /// after a `401`. Ignored for API-key credentials.
+    #[must_use]
+    pub fn token_refresher(mut self, refresher: Arc<dyn TokenRefresher>) -> Self {
+        self.token_refresher = Some(refresher);
+        self
+    }
+
+    /// Use a preconfigured `reqwest::Client`. Its own timeouts, pool, proxy and
+    /// TLS configuration apply; the transport settings of this builder are ignored.
+    #[must_use]
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Throttle requests with a limiter shared with other clients, for example
+    /// every tenant of a server. Without one, `build` creates a limiter from
+    /// [`HttpSettings::rate_limit`] that only this client and its clones use.
+    #[must_use]
+    pub fn rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Record upstream traffic to, or replay it from, a [`Cassette`]. Replay
+    /// never touches the network.
+    #[must_use]
+    pub fn cassette(mut self, cassette: impl Into<Arc<Cassette>>) -> Self {
+        self.cassette = Some(cassette.into());
+        self
+    }
+
+    fn build_http_client(settings: &HttpSettings) -> Result<reqwest::Client> {
+        let config_error =
+            |message: String| IntervalsError::Config(crate::ConfigError::Other(message));
+        let mut builder = reqwest::Client::builder()
+            .user_agent(settings.user_agent.clone())
+            .pool_idle_timeout(settings.pool_idle_timeout);
+        if let Some(timeout) = settings.request_timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = settings.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(max) = settings.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(proxy) = &settings.proxy {
+            let proxy = reqwest::Proxy::all(proxy)
+                .map_err(|e| config_error(format!("invalid proxy '{proxy}': {e}")))?;
+            builder = builder.proxy(proxy);
+        }
+        for path in &settings.root_certificates {
+            let pem = std::fs::read(path).map_err(|e| {
+                config_error(format!(
+                    "failed to read certificate {}: {e}",
+                    path.display()
+                ))
+            })?;
+            let certificate = reqwest::Certificate::from_pem(&pem).map_err(|e| {
+                config_error(format!("invalid certificate {}: {e}", path.display()))
+            })?;
+            builder = builder.add_root_certificate(certificate);
+        }
+        builder
+            .build()
+            .map_err(|e| config_error(format!("failed to build HTTP client: {e}")))
+    }
+
+    /// Build the client.
+    ///
+    /// # Errors
+    /// Returns `ConfigError::Other` if the proxy URL or a root certificate is
+    /// invalid, or the HTTP client cannot be built.
+    pub fn build(self) -> Result<ReqwestIntervalsClient> {
+        let client = match self.http_client {
+            Some(client) => client,
+            None => Self::build_http_client(&self.settings)?,
+        };
+        Ok(ReqwestIntervalsClient {
+            base_url: self.base_url.trim_end_matches('/').to_string(),
+            athlete_id: self.athlete_id,
+            auth: Arc::new(AuthState::new(self.credentials, self.token_refresher)),
+            client,
+            circuit_breaker: Arc::new(CircuitBreaker::new(
+                self.settings.circuit_failure_threshold,
+                self.settings.circuit_reset_timeout,
+            )),
+            retry_policy: RetryPolicy {
+                max_retries: self.settings.retry_max,
+                base_delay: self.settings.retry_base_delay,
+            },
+            rate_limiter: self.rate_limiter.or_else(|| {
+                self.settings
+                    .rate_limit
+                    .map(|limits| Arc::new(RateLimiter::new(limits)))
+            }),
+            cassette: self.cassette,
+        })
+    }
 }
 
 impl std::fmt::Debug for ReqwestIntervalsClient {
@@ -36,34 +262,56 @@ impl std::fmt::Debug for ReqwestIntervalsClient {
             .field("base_url", &self.base_url)
             .field("athlete_id", &self.athlete_id)
             .field("circuit_breaker", &self.circuit_breaker)
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("cassette", &self.cassette)
             .finish_non_exhaustive()
     }
 }
 
 impl ReqwestIntervalsClient {
-    /// Create a new client instance.
+    /// Create a new client instance with the default [`HttpSettings`].
     ///
     /// # Arguments
     /// * `base_url` - The base URL of the Intervals.icu API (e.g., `<https://intervals.icu>`)
     /// * `athlete_id` - The athlete ID for authentication
-    /// * `api_key` - The API key for authentication
+    /// * `credentials` - An API key (`SecretString`) or OAuth [`Credentials`]
     pub fn new(
         base_url: &str,
         athlete_id: impl Into<String>,
-        api_key: SecretString,
+        credentials: impl Into<Credentials>,
     ) -> Result<Self> {
-        let client = reqwest::Client::builder().build().map_err(|e| {
-            IntervalsError::Config(crate::ConfigError::Other(format!(
-                "failed to build HTTP client: {e}"
-            )))
-        })?;
-        Ok(Self {
-            base_url: base_url.trim_end_matches('/').to_string(),
+        Self::builder(base_url, athlete_id, credentials).build()
+    }
+
+    /// Start a [`ReqwestIntervalsClientBuilder`] for custom transport and
+    /// resilience settings.
+    pub fn builder(
+        base_url: &str,
+        athlete_id: impl Into<String>,
+        credentials: impl Into<Credentials>,
+    ) -> ReqwestIntervalsClientBuilder {
+        ReqwestIntervalsClientBuilder {
+            base_url: base_url.to_string(),
             athlete_id: athlete_id.into(),
-            api_key,
-            client,
-            circuit_breaker: Arc::new(CircuitBreaker::default()),
-        })
+            credentials: credentials.into(),
+            token_refresher: None,
+            settings: HttpSettings::default(),
+            http_client: None,
+            rate_limiter: None,
+            cassette: None,
+        }
+    }
+
+    /// A client for `athlete_id` sharing this client's credentials, connection
+    /// pool, circuit breaker and retry policy. Coach accounts use it to act on
+    /// their athletes.
+    #[must_use]
+    pub fn with_athlete_id(&self, athlete_id: impl Into<String>) -> Self {
+        Self {
+            athlete_id: athlete_id.into(),
+            ..self.clone()
+        }
     }
 
     /// Build an API URL from path segments.
@@ -129,73 +377,168 @@ impl ReqwestIntervalsClient {
         }))
     }
 
-    /// Build an authenticated GET request.
+    /// Build a GET request; credentials are applied in `execute_raw`.
     fn get_request(&self, url: &str) -> reqwest::RequestBuilder {
-        self.client
-            .get(url)
-            .basic_auth("API_KEY", Some(self.api_key.expose_secret()))
+        self.client.get(url)
     }
 
-    /// Build an authenticated POST request.
+    /// Build a POST request; credentials are applied in `execute_raw`.
     fn post_request(&self, url: &str) -> reqwest::RequestBuilder {
-        self.client
-            .post(url)
-            .basic_auth("API_KEY", Some(self.api_key.expose_secret()))
+        self.client.post(url)
     }
 
-    /// Build an authenticated PUT request.
+    /// Build a PUT request; credentials are applied in `execute_raw`.
     fn put_request(&self, url: &str) -> reqwest::RequestBuilder {
-        self.client
-            .put(url)
-            .basic_auth("API_KEY", Some(self.api_key.expose_secret()))
+        self.client.put(url)
     }
 
-    /// Build an authenticated DELETE request.
+    /// Build a DELETE request; credentials are applied in `execute_raw`.
     fn delete_request(&self, url: &str) -> reqwest::RequestBuilder {
-        self.client
-            .delete(url)
-            .basic_auth("API_KEY", Some(self.api_key.expose_secret()))
+        self.client.delete(url)
     }
 
     /// Execute a request and return the raw response.
     ///
-    /// Handles circuit breaker, timing, metrics, and transport errors.
+    /// Handles authentication, circuit breaker, timing, metrics, transport
+    /// errors, and retries: idempotent requests (GET, HEAD, PUT, DELETE) are
+    /// retried per the retry policy after transport errors or a
+    /// 429/502/503/504 response. With a token refresher, an OAuth token is
+    /// renewed before it expires, and a request rejected with 401 is replayed
+    /// once with a refreshed token. Every attempt first waits for the rate
+    /// limiter, which adapts to 429 and `Retry-After`. With a cassette, the
+    /// final response is recorded, or served from the cassette without any
+    /// network access.
     /// The caller is responsible for interpreting the response body.
     async fn execute_raw(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
-        if !self.circuit_breaker.allow_request() {
-            return Err(IntervalsError::Api(crate::error::ApiError::new(
-                503,
-                "circuit breaker open — upstream is unavailable",
-                "",
-            )));
-        }
-
-        let start = std::time::Instant::now();
-        let resp = request.send().await;
-        let duration = start.elapsed().as_secs_f64();
-
-        let resp = match resp {
-            Ok(r) => {
-                self.circuit_breaker.record_success();
-                r
-            }
-            Err(e) => {
-                self.circuit_breaker.record_failure();
-                histogram!("intervals_icu_mcp_upstream_request_duration_seconds").record(duration);
-                return Err(IntervalsError::Http(e));
+        let (client, request) = request.build_split();
+        let mut request = request?;
+        let recorded = match &self.cassette {
+            Some(cassette) => {
+                let credentials = self.auth.current();
+                let scrubber = Scrubber {
+                    athlete_id: &self.athlete_id,
+                    secret: credentials.secret().expose_secret(),
+                };
+                let recorded = scrubber.request(&request);
+                if cassette.is_replay() {
+                    return cassette.replay_response(&recorded, &scrubber);
+                }
+                Some(recorded)
             }
+            None => None,
         };
+        let idempotent = matches!(
+            *request.method(),
+            reqwest::Method::GET
+                | reqwest::Method::HEAD
+                | reqwest::Method::PUT
+                | reqwest::Method::DELETE
+        );
+        let mut attempt = 0u32;
+        let mut auth_replayed = false;
+        self.auth.refresh_if_expiring().await?;
+
+        loop {
+            let limiter_key = match &self.rate_limiter {
+                Some(limiter) => {
+                    let key = RateLimiter::key_for(self.auth.current().secret().expose_secret());
+                    limiter.acquire(&key).await;
+                    Some(key)
+                }
+                None => None,
+            };
+            if !self.circuit_breaker.allow_request() {
+                return Err(IntervalsError::Api(crate::error::ApiError::new(
+                    503,
+                    "circuit breaker open — upstream is unavailable",
+                    "",
+                )));
+            }
 
-        let status = resp.status().as_u16();
-
-        histogram!("intervals_icu_mcp_upstream_request_duration_seconds").record(duration);
-        counter!(
-            "intervals_icu_mcp_upstream_requests_total",
-            "status" => status.to_string()
-        )
-        .increment(1);
+            let authorization = self.auth.header()?;
+            request
+                .headers_mut()
+                .insert(reqwest::header::AUTHORIZATION, authorization.clone());
+
+            let can_retry = idempotent && attempt < self.retry_policy.max_retries;
+            let can_reauth = !auth_replayed && self.auth.can_refresh();
+            // Streaming bodies cannot be cloned and are never replayed.
+            let replay = if can_retry || can_reauth {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            let start = std::time::Instant::now();
+            let resp = client.execute(request).await;
+            let duration = start.elapsed().as_secs_f64();
+            histogram!("intervals_icu_mcp_upstream_request_duration_seconds").record(duration);
+
+            let resp = match resp {
+                Ok(r) => {
+                    self.circuit_breaker.record_success();
+                    r
+                }
+                Err(e) => {
+                    self.circuit_breaker.record_failure();
+                    match replay {
+                        Some(next) if can_retry && (e.is_timeout() || e.is_connect()) => {
+                            attempt += 1;
+                            counter!("intervals_icu_mcp_upstream_retries_total").increment(1);
+                            tokio::time::sleep(self.retry_policy.backoff_delay(attempt)).await;
+                            request = next;
+                            continue;
+                        }
+                        _ => return Err(IntervalsError::Http(e)),
+                    }
+                }
+            };
+
+            let status = resp.status().as_u16();
+            counter!(
+                "intervals_icu_mcp_upstream_requests_total",
+                "status" => status.to_string()
+            )
+            .increment(1);
+            if let (Some(limiter), Some(key)) = (&self.rate_limiter, &limiter_key) {
+                let retry_after = resp
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| parse_retry_after(value, Utc::now()));
+                limiter.observe(key, status, retry_after);
+            }
 
-        Ok(resp)
+            match replay {
+                Some(next)
+                    if can_reauth
+                        && status == 401
+                        && self.auth.refresh_after_unauthorized(&authorization).await? =>
+                {
+                    auth_replayed = true;
+                    request = next;
+                }
+                Some(next) if can_retry && RETRYABLE_STATUSES.contains(&status) => {
+                    attempt += 1;
+                    counter!("intervals_icu_mcp_upstream_retries_total").increment(1);
+                    tokio::time::sleep(self.retry_policy.backoff_delay(attempt)).await;
+                    request = next;
+                }
+                _ => {
+                    return match (&self.cassette, recorded) {
+                        (Some(cassette), Some(recorded)) => {
+                            let credentials = self.auth.current();
+                            let scrubber = Scrubber {
+                                athlete_id: &self.athlete_id,
+                                secret: credentials.secret().expose_secret(),
+                            };
+                            cassette.record_response(recorded, resp, &scrubber).await
+                        }
+                        _ => Ok(resp),
+                    };
+                }
+            }
+        }
     }
 
     /// Execute a request and expect a JSON response.
@@ -273,6 +616,35 @@ impl ReqwestIntervalsClient {
         Self::error_from_response_parts(status, &body)
     }
 
+    /// File name sent with an upload; rejects anything but FIT, TCX or GPX
+    /// (optionally `.gz` compressed) and zip archives.
+    fn upload_file_name(path: &std::path::Path) -> Result<String> {
+        let invalid = || {
+            IntervalsError::Validation(ValidationError::InvalidFormat {
+                field: "path".into(),
+                value: format!(
+                    "{} (expected a .fit, .tcx or .gpx file, optionally .gz, or a .zip)",
+                    path.display()
+                ),
+            })
+        };
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(invalid)?;
+        let lower = file_name.to_ascii_lowercase();
+        let inner = lower.strip_suffix(".gz").unwrap_or(&lower);
+        let supported = [".fit", ".tcx", ".gpx"]
+            .iter()
+            .any(|extension| inner.ends_with(extension))
+            || lower.ends_with(".zip");
+        if supported {
+            Ok(file_name.to_string())
+        } else {
+            Err(invalid())
+        }
+    }
+
     fn error_from_response_parts(status: u16, body: &str) -> IntervalsError {
         let body_snippet = Self::truncate_error_body(body);
         IntervalsError::from_status(status, body_snippet)
@@ -288,7 +660,7 @@ impl ReqwestIntervalsClient {
         url: String,
         output_path: Option<PathBuf>,
     ) -> Result<Option<String>> {
-        let resp = self.get_request(&url).send().await?;
+        let resp = self.execute_raw(self.get_request(&url)).await?;
         if !resp.status().is_success() {
             return Err(self.error_from_response(resp).await);
         }
@@ -412,21 +784,31 @@ impl ReqwestIntervalsClient {
         sport: &str,
         curve_type: &str,
     ) -> Result<serde_json::Value> {
-        let url = format!(
-            "{}/api/v1/athlete/{}/activity-{}-curves",
-            self.base_url, self.athlete_id, curve_type
-        );
         let today = chrono::Utc::now().date_naive();
         let oldest = if let Some(days) = days_back {
             today - chrono::Duration::days(i64::from(days))
         } else {
             today - chrono::Duration::days(90)
         };
+        self.get_curves_range(curve_type, sport, oldest, today)
+            .await
+    }
 
+    async fn get_curves_range(
+        &self,
+        curve_type: &str,
+        sport: &str,
+        oldest: NaiveDate,
+        newest: NaiveDate,
+    ) -> Result<serde_json::Value> {
+        let url = format!(
+            "{}/api/v1/athlete/{}/activity-{}-curves",
+            self.base_url, self.athlete_id, curve_type
+        );
         let pairs = crate::utils::QueryBuilder::new()
             .add("ext", "")
             .add("oldest", oldest.to_string())
-            .add("newest", today.to_string())
+            .add("newest", newest.to_string())
             .add("type", sport)
             .build_owned();
         let qp: Vec<(&str, &str)> = pairs.iter().map(|(k, v)| (*k, v.as_str())).collect();
@@ -477,7 +859,7 @@ struct ProfileAthlete {
 impl AthleteService for ReqwestIntervalsClient {
     async fn get_athlete_profile(&self) -> Result<AthleteProfile> {
         let url = self.api_url(&["athlete", &self.athlete_id, "profile"]);
-        let resp = self.get_request(&url).send().await?;
+        let resp = self.execute_raw(self.get_request(&url)).await?;
         let status = resp.status();
         if !status.is_success() {
             return Err(self.error_from_response(resp).await);
@@ -496,6 +878,15 @@ impl AthleteService for ReqwestIntervalsClient {
                 ))
             })
     }
+
+    async fn list_coached_athletes(&self) -> Result<Vec<CoachedAthlete>> {
+        let url = self.api_url(&["athlete", &self.athlete_id, "athletes"]);
+        let resp = self.execute_raw(self.get_request(&url)).await?;
+        if !resp.status().is_success() {
+            return Err(self.error_from_response(resp).await);
+        }
+        Ok(resp.json().await?)
+    }
 }
 
 #[async_trait]
@@ -521,6 +912,20 @@ impl ActivityService for ReqwestIntervalsClient {
             .await
     }
 
+    async fn get_activities_between(
+        &self,
+        oldest: NaiveDate,
+        newest: NaiveDate,
+    ) -> Result<Vec<crate::ActivitySummary>> {
+        let url = self.api_url(&["athlete", &self.athlete_id, "activities"]);
+        let pairs: Vec<(&str, String)> = vec![
+            ("oldest", oldest.to_string()),
+            ("newest", newest.to_string()),
+        ];
+        self.execute_json(self.get_request(&url).query(&Self::build_query(&pairs)))
+            .await
+    }
+
     async fn get_activity_details(&self, activity_id: &str) -> Result<serde_json::Value> {
         let url = format!("{}/api/v1/activity/{}", self.base_url, activity_id);
         self.execute_json(self.get_request(&url)).await
@@ -531,6 +936,16 @@ impl ActivityService for ReqwestIntervalsClient {
         self.execute_json(self.get_request(&url)).await
     }
 
+    async fn add_activity_message(
+        &self,
+        activity_id: &str,
+        content: &str,
+    ) -> Result<serde_json::Value> {
+        let url = format!("{}/api/v1/activity/{}/messages", self.base_url, activity_id);
+        let body = serde_json::json!({ "content": content });
+        self.execute_json(self.post_request(&url).json(&body)).await
+    }
+
     async fn get_activity_streams(
         &self,
         activity_id: &str,
@@ -620,7 +1035,7 @@ impl ActivityService for ReqwestIntervalsClient {
 
         for params in &attempts {
             let qp: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, *v)).collect();
-            let resp = self.get_request(&url).query(&qp).send().await?;
+            let resp = self.execute_raw(self.get_request(&url).query(&qp)).await?;
 
             if resp.status().is_success() {
                 let value = resp.json().await?;
@@ -675,7 +1090,7 @@ impl ActivityService for ReqwestIntervalsClient {
 
                     for params in param_sets.iter().chain(param_sets_extended.iter()) {
                         let qp: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, *v)).collect();
-                        let resp = self.get_request(&url).query(&qp).send().await?;
+                        let resp = self.execute_raw(self.get_request(&url).query(&qp)).await?;
                         if resp.status().is_success() {
                             let value = resp.json().await?;
                             let stream = params
@@ -822,7 +1237,7 @@ impl ActivityService for ReqwestIntervalsClient {
         mut cancel_rx: tokio::sync::watch::Receiver<bool>,
     ) -> Result<Option<String>> {
         let url = format!("{}/api/v1/activity/{}/file", self.base_url, activity_id);
-        let resp = self.get_request(&url).send().await?;
+        let resp = self.execute_raw(self.get_request(&url)).await?;
         if !resp.status().is_success() {
             return Err(self.error_from_response(resp).await);
         }
@@ -920,6 +1335,46 @@ impl ActivityService for ReqwestIntervalsClient {
         self.download_file(url, output_path).await
     }
 
+    async fn upload_activity_file(
+        &self,
+        upload: &crate::ActivityUpload,
+    ) -> Result<crate::domains::activity::ActivityUploadResult> {
+        let file_name = Self::upload_file_name(&upload.path)?;
+        let io_error = |e: std::io::Error| {
+            IntervalsError::Config(crate::ConfigError::Other(format!(
+                "{}: {e}",
+                upload.path.display()
+            )))
+        };
+        let file = tokio::fs::File::open(&upload.path)
+            .await
+            .map_err(io_error)?;
+        let length = file.metadata().await.map_err(io_error)?.len();
+        let part = reqwest::multipart::Part::stream_with_length(file, length)
+            .file_name(file_name)
+            .mime_str("application/octet-stream")?;
+        let form = reqwest::multipart::Form::new().part("file", part);
+
+        let mut pairs: Vec<(&str, String)> = Vec::new();
+        if let Some(name) = &upload.name {
+            pairs.push(("name", name.clone()));
+        }
+        if let Some(description) = &upload.description {
+            pairs.push(("description", description.clone()));
+        }
+        if let Some(external_id) = &upload.external_id {
+            pairs.push(("external_id", external_id.clone()));
+        }
+
+        let url = self.api_url(&["athlete", &self.athlete_id, "activities"]);
+        self.execute_json(
+            self.post_request(&url)
+                .query(&Self::build_query(&pairs))
+                .multipart(form),
+        )
+        .await
+    }
+
     async fn get_gap_histogram(&self, activity_id: &str) -> Result<serde_json::Value> {
         let url = format!(
             "{}/api/v1/activity/{}/gap-histogram",
@@ -993,7 +1448,7 @@ impl EventService for ReqwestIntervalsClient {
                 })
             })?;
 
-        let resp = self.post_request(&url).json(&ev).send().await?;
+        let resp = self.execute_raw(self.post_request(&url).json(&ev)).await?;
         if !resp.status().is_success() {
             return Err(self.error_from_response(resp).await);
         }
@@ -1002,7 +1457,7 @@ impl EventService for ReqwestIntervalsClient {
 
     async fn get_event(&self, event_id: &str) -> Result<crate::Event> {
         let url = self.api_url(&["athlete", &self.athlete_id, "events", event_id]);
-        let resp = self.get_request(&url).send().await?;
+        let resp = self.execute_raw(self.get_request(&url)).await?;
         if !resp.status().is_success() {
             return Err(self.error_from_response(resp).await);
         }
@@ -1040,6 +1495,20 @@ impl EventService for ReqwestIntervalsClient {
             .await
     }
 
+    async fn get_events_between(
+        &self,
+        oldest: NaiveDate,
+        newest: NaiveDate,
+    ) -> Result<Vec<crate::Event>> {
+        let url = self.api_url(&["athlete", &self.athlete_id, "events"]);
+        let pairs: Vec<(&str, String)> = vec![
+            ("oldest", oldest.to_string()),
+            ("newest", newest.to_string()),
+        ];
+        self.execute_json(self.get_request(&url).query(&Self::build_query(&pairs)))
+            .await
+    }
+
     async fn bulk_create_events(&self, events: Vec<crate::Event>) -> Result<Vec<crate::Event>> {
         let url = format!(
             "{}/api/v1/athlete/{}/events/bulk",
@@ -1149,6 +1618,17 @@ impl FitnessService for ReqwestIntervalsClient {
     ) -> Result<serde_json::Value> {
         self.get_curves(days_back, sport, "pace").await
     }
+
+    async fn get_curves_between(
+        &self,
+        kind: CurveKind,
+        sport: &str,
+        oldest: NaiveDate,
+        newest: NaiveDate,
+    ) -> Result<serde_json::Value> {
+        self.get_curves_range(kind.path_segment(), sport, oldest, newest)
+            .await
+    }
 }
 
 #[async_trait]
@@ -1420,6 +1900,18 @@ impl crate::IntervalsClient for ReqwestIntervalsClient {
         <Self as AthleteService>::get_athlete_profile(self).await
     }
 
+    async fn list_coached_athletes(&self) -> Result<Vec<CoachedAthlete>> {
+        <Self as AthleteService>::list_coached_athletes(self).await
+    }
+
+    fn athlete_id(&self) -> Option<&str> {
+        Some(&self.athlete_id)
+    }
+
+    fn for_athlete(&self, athlete_id: &str) -> Option<Arc<dyn crate::IntervalsClient>> {
+        Some(Arc::new(self.with_athlete_id(athlete_id)))
+    }
+
     async fn get_recent_activities(
         &self,
         limit: Option<u32>,
@@ -1448,6 +1940,22 @@ impl crate::IntervalsClient for ReqwestIntervalsClient {
         <Self as EventService>::get_events(self, days_back, limit).await
     }
 
+    async fn get_events_between(
+        &self,
+        oldest: NaiveDate,
+        newest: NaiveDate,
+    ) -> Result<Vec<crate::Event>> {
+        <Self as EventService>::get_events_between(self, oldest, newest).await
+    }
+
+    fn events_stream(&self, range: DateRange) -> PageStream<'_, crate::Event> {
+        pagination::paginate(
+            range,
+            |e: &crate::Event| e.start_date_local.as_str(),
+            move |oldest, newest| <Self as EventService>::get_events_between(self, oldest, newest),
+        )
+    }
+
     async fn bulk_create_events(&self, events: Vec<crate::Event>) -> Result<Vec<crate::Event>> {
         <Self as EventService>::bulk_create_events(self, events).await
     }
@@ -1472,6 +1980,24 @@ impl crate::IntervalsClient for ReqwestIntervalsClient {
         <Self as ActivityService>::get_best_efforts(self, activity_id, options).await
     }
 
+    async fn get_activities_between(
+        &self,
+        oldest: NaiveDate,
+        newest: NaiveDate,
+    ) -> Result<Vec<crate::ActivitySummary>> {
+        <Self as ActivityService>::get_activities_between(self, oldest, newest).await
+    }
+
+    fn activities_stream(&self, range: DateRange) -> PageStream<'_, crate::ActivitySummary> {
+        pagination::paginate(
+            range,
+            |a: &crate::ActivitySummary| a.start_date_local.as_str(),
+            move |oldest, newest| {
+                <Self as ActivityService>::get_activities_between(self, oldest, newest)
+            },
+        )
+    }
+
     async fn get_activity_details(&self, activity_id: &str) -> Result<serde_json::Value> {
         <Self as ActivityService>::get_activity_details(self, activity_id).await
     }
@@ -1480,6 +2006,14 @@ impl crate::IntervalsClient for ReqwestIntervalsClient {
         <Self as ActivityService>::get_activity_messages(self, activity_id).await
     }
 
+    async fn add_activity_message(
+        &self,
+        activity_id: &str,
+        content: &str,
+    ) -> Result<serde_json::Value> {
+        <Self as ActivityService>::add_activity_message(self, activity_id, content).await
+    }
+
     async fn search_activities(
         &self,
         query: &str,
@@ -1549,6 +2083,13 @@ impl crate::IntervalsClient for ReqwestIntervalsClient {
         <Self as ActivityService>::download_gpx_file(self, activity_id, output_path).await
     }
 
+    async fn upload_activity_file(
+        &self,
+        upload: &crate::ActivityUpload,
+    ) -> Result<crate::domains::activity::ActivityUploadResult> {
+        <Self as ActivityService>::upload_activity_file(self, upload).await
+    }
+
     async fn get_gear_list(&self) -> Result<serde_json::Value> {
         <Self as GearService>::get_gear_list(self).await
     }
@@ -1823,6 +2364,16 @@ impl crate::IntervalsClient for ReqwestIntervalsClient {
     ) -> Result<serde_json::Value> {
         <Self as RouteService>::get_route_similarity(self, route_id, other_id).await
     }
+
+    async fn get_curves_between(
+        &self,
+        kind: CurveKind,
+        sport: &str,
+        oldest: NaiveDate,
+        newest: NaiveDate,
+    ) -> Result<serde_json::Value> {
+        <Self as FitnessService>::get_curves_between(self, kind, sport, oldest, newest).await
+    }
 }
 
 /// Extract available stream names from a JSON response.
@@ -2015,6 +2566,19 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn upload_file_name_accepts_activity_formats_only() {
+        for name in ["ride.fit", "RUN.TCX", "hike.gpx.gz", "export.zip"] {
+            assert_eq!(
+                ReqwestIntervalsClient::upload_file_name(std::path::Path::new(name)).unwrap(),
+                name
+            );
+        }
+        let err = ReqwestIntervalsClient::upload_file_name(std::path::Path::new("notes.txt"))
+            .unwrap_err();
+        assert!(matches!(err, IntervalsError::Validation(_)));
+    }
+
     #[test]
     fn error_from_response_parts_maps_not_found() {
         let err = ReqwestIntervalsClient::error_from_response_parts(404, "missing activity");
@@ -19,21 +19,37 @@
 //! - [`traits::WeatherService`] - Weather configuration
 //! - [`traits::WorkoutService`] - Workout library
 //! - [`traits::SportSettingsService`] - Sport configuration
+//!
+//! # Testing
+//!
+//! The `fake` feature adds [`fake::FakeIntervalsClient`], a stateful in-memory
+//! client seeded from JSON fixtures, for tests that span several calls.
 
+use chrono::NaiveDate;
 use schemars::JsonSchema;
 use serde::{Deserialize, Deserializer, Serialize};
+use std::sync::Arc;
 
+pub mod auth;
+pub mod cassette;
 pub mod circuit_breaker;
 pub mod config;
 pub mod domains;
 pub mod error;
+#[cfg(feature = "fake")]
+pub mod fake;
+pub mod fit;
 pub mod http_client;
 pub mod observability;
+pub mod pagination;
+pub mod rate_limit;
 pub mod retry;
 pub mod traits;
 pub mod utils;
 
+pub use auth::{Credentials, OAuthToken, TokenRefresher};
 pub use error::{ApiError, ConfigError, IntervalsError, Result, ValidationError};
+pub use pagination::{DateRange, PageStream};
 // Service traits are available in the `traits` module for modular usage
 pub use traits::{
     ActivityService, AthleteService, EventService, FitnessService, GearService, RouteService,
@@ -56,6 +72,48 @@ pub struct BestEffortsOptions {
     pub end_index: Option<i32>,
 }
 
+/// An activity file to upload with `upload_activity_file`.
+///
+/// `path` must point to a FIT, TCX or GPX file, optionally gzipped
+/// (`.fit.gz`) or zipped. The file is streamed from disk, never buffered.
+#[derive(Clone, Debug, Default)]
+pub struct ActivityUpload {
+    pub path: std::path::PathBuf,
+    /// Activity name; Intervals.icu derives one from the file when absent.
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// Caller-side identifier stored on the activity for later lookups.
+    pub external_id: Option<String>,
+}
+
+impl ActivityUpload {
+    #[must_use]
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 pub struct AthleteProfile {
     pub id: String,
@@ -94,9 +152,10 @@ pub struct ActivityMessage {
     pub deleted: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum EventCategory {
+    #[default]
     Workout,
     RaceA,
     RaceB,
@@ -115,13 +174,17 @@ pub enum EventCategory {
     Unknown,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct Event {
     #[serde(default, deserialize_with = "deserialize_opt_string")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(rename = "start_date_local")]
     pub start_date_local: String, // YYYY-MM-DD
+    /// Last day of a multi-day entry (illness, injury, holiday); absent for
+    /// single-day events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date_local: Option<String>,
     pub name: String,
     pub category: EventCategory,
     pub description: Option<String>,
@@ -154,6 +217,20 @@ where
 #[allow(clippy::too_many_arguments)]
 pub trait IntervalsClient: Send + Sync + 'static {
     async fn get_athlete_profile(&self) -> Result<AthleteProfile>;
+    async fn list_coached_athletes(&self) -> Result<Vec<domains::athlete::CoachedAthlete>> {
+        Err(IntervalsError::Config(ConfigError::Other(
+            "list_coached_athletes is not implemented for this client".to_string(),
+        )))
+    }
+    /// The athlete this client reads and writes, when known.
+    fn athlete_id(&self) -> Option<&str> {
+        None
+    }
+    /// A client for another athlete that shares this client's credentials,
+    /// for coach accounts. `None` when the client cannot be re-scoped.
+    fn for_athlete(&self, _athlete_id: &str) -> Option<Arc<dyn IntervalsClient>> {
+        None
+    }
     async fn get_recent_activities(
         &self,
         limit: Option<u32>,
@@ -163,6 +240,61 @@ pub trait IntervalsClient: Send + Sync + 'static {
     async fn get_event(&self, event_id: &str) -> Result<Event>;
     async fn delete_event(&self, event_id: &str) -> Result<()>;
     async fn get_events(&self, days_back: Option<i32>, limit: Option<u32>) -> Result<Vec<Event>>;
+    /// Activities that started between `oldest` and `newest` (inclusive),
+    /// without a result cap. The default filters `get_recent_activities`, so
+    /// it downloads everything from `oldest` up to today.
+    async fn get_activities_between(
+        &self,
+        oldest: NaiveDate,
+        newest: NaiveDate,
+    ) -> Result<Vec<ActivitySummary>> {
+        let activities = self
+            .get_recent_activities(None, Some(pagination::days_back_to(oldest)))
+            .await?;
+        Ok(pagination::within(activities, oldest, newest, |a| {
+            a.start_date_local.as_str()
+        }))
+    }
+    /// Calendar events between `oldest` and `newest` (inclusive), without a
+    /// result cap. The default filters `get_events`.
+    async fn get_events_between(&self, oldest: NaiveDate, newest: NaiveDate) -> Result<Vec<Event>> {
+        let events = self
+            .get_events(Some(pagination::days_back_to(oldest)), None)
+            .await?;
+        Ok(pagination::within(events, oldest, newest, |e| {
+            e.start_date_local.as_str()
+        }))
+    }
+    /// Activities in `range`; see [`pagination`]. The default makes a single
+    /// `get_activities_between` call for the whole range and splits it, since
+    /// the default `get_activities_between` cannot bound a chunk's far end.
+    /// Clients with bounded windows override this to request one chunk at a
+    /// time.
+    fn activities_stream(&self, range: DateRange) -> PageStream<'_, ActivitySummary> {
+        pagination::split_fetched(
+            range,
+            |a: &ActivitySummary| a.start_date_local.as_str(),
+            self.get_activities_between(range.oldest, range.newest),
+        )
+    }
+    /// Calendar events in `range`. Like `activities_stream`, the default
+    /// fetches the whole range once through `get_events_between`.
+    fn events_stream(&self, range: DateRange) -> PageStream<'_, Event> {
+        pagination::split_fetched(
+            range,
+            |e: &Event| e.start_date_local.as_str(),
+            self.get_events_between(range.oldest, range.newest),
+        )
+    }
+    /// Activities in `range` whose name contains every term of `query`.
+    /// Unlike `search_activities`, results are not capped.
+    fn search_activities_stream<'a>(
+        &'a self,
+        query: &'a str,
+        range: DateRange,
+    ) -> PageStream<'a, ActivitySummary> {
+        pagination::filter_by_name(self.activities_stream(range), query)
+    }
     async fn bulk_create_events(&self, events: Vec<Event>) -> Result<Vec<Event>>;
     async fn get_activity_streams(
         &self,
@@ -181,6 +313,15 @@ pub trait IntervalsClient: Send + Sync + 'static {
             "get_activity_messages is not implemented for this client".to_string(),
         )))
     }
+    async fn add_activity_message(
+        &self,
+        _activity_id: &str,
+        _content: &str,
+    ) -> Result<serde_json::Value> {
+        Err(IntervalsError::Config(ConfigError::Other(
+            "add_activity_message is not implemented for this client".to_string(),
+        )))
+    }
     async fn search_activities(
         &self,
         query: &str,
@@ -219,6 +360,14 @@ pub trait IntervalsClient: Send + Sync + 'static {
         activity_id: &str,
         output_path: Option<std::path::PathBuf>,
     ) -> Result<Option<String>>;
+    async fn upload_activity_file(
+        &self,
+        _upload: &ActivityUpload,
+    ) -> Result<domains::activity::ActivityUploadResult> {
+        Err(IntervalsError::Config(ConfigError::Other(
+            "upload_activity_file is not implemented for this client".to_string(),
+        )))
+    }
     async fn get_gear_list(&self) -> Result<serde_json::Value>;
     async fn get_sport_settings(&self) -> Result<domains::workout::SportSettings>;
     async fn get_power_curves(
@@ -371,6 +520,107 @@ pub trait IntervalsClient: Send + Sync + 'static {
             "get_route_similarity is not implemented for this client".to_string(),
         )))
     }
+
+    /// One curve for a sport over `oldest..=newest`, for windows that do not
+    /// end today.
+    async fn get_curves_between(
+        &self,
+        _kind: domains::curve::CurveKind,
+        _sport: &str,
+        _oldest: NaiveDate,
+        _newest: NaiveDate,
+    ) -> Result<serde_json::Value> {
+        Err(IntervalsError::Config(ConfigError::Other(
+            "get_curves_between is not implemented for this client".to_string(),
+        )))
+    }
+
+    // Typed variants of the `Value`-returning reads. The defaults decode the
+    // raw payload, so every implementation gets them for free and callers can
+    // migrate one endpoint at a time.
+
+    async fn get_activity_details_typed(
+        &self,
+        activity_id: &str,
+    ) -> Result<domains::activity::ActivityDetail> {
+        Ok(serde_json::from_value(
+            self.get_activity_details(activity_id).await?,
+        )?)
+    }
+    async fn get_activity_streams_typed(
+        &self,
+        activity_id: &str,
+        streams: Option<Vec<String>>,
+    ) -> Result<domains::stream::ActivityStreams> {
+        let value = self.get_activity_streams(activity_id, streams).await?;
+        domains::stream::ActivityStreams::from_value(&value)
+            .ok_or_else(|| domains::shape_error("activity streams"))
+    }
+    async fn get_wellness_typed(
+        &self,
+        days_back: Option<i32>,
+    ) -> Result<Vec<domains::wellness::WellnessEntry>> {
+        Ok(serde_json::from_value(self.get_wellness(days_back).await?)?)
+    }
+    async fn get_wellness_for_date_typed(
+        &self,
+        date: &str,
+    ) -> Result<domains::wellness::WellnessEntry> {
+        let value = self.get_wellness_for_date(date).await?;
+        domains::wellness::WellnessEntry::from_value(&value)
+            .ok_or_else(|| domains::shape_error("wellness"))
+    }
+    async fn get_gear_list_typed(&self) -> Result<Vec<domains::gear::Gear>> {
+        Ok(serde_json::from_value(self.get_gear_list().await?)?)
+    }
+    async fn get_power_curves_typed(
+        &self,
+        days_back: Option<i32>,
+        sport: &str,
+    ) -> Result<domains::curve::CurveSet> {
+        let value = self.get_power_curves(days_back, sport).await?;
+        domains::curve::CurveSet::from_value(&value).ok_or_else(|| domains::shape_error("curve"))
+    }
+    async fn get_hr_curves_typed(
+        &self,
+        days_back: Option<i32>,
+        sport: &str,
+    ) -> Result<domains::curve::CurveSet> {
+        let value = self.get_hr_curves(days_back, sport).await?;
+        domains::curve::CurveSet::from_value(&value).ok_or_else(|| domains::shape_error("curve"))
+    }
+    async fn get_pace_curves_typed(
+        &self,
+        days_back: Option<i32>,
+        sport: &str,
+    ) -> Result<domains::curve::CurveSet> {
+        let value = self.get_pace_curves(days_back, sport).await?;
+        domains::curve::CurveSet::from_value(&value).ok_or_else(|| domains::shape_error("curve"))
+    }
+    async fn get_power_histogram_typed(
+        &self,
+        activity_id: &str,
+    ) -> Result<Vec<domains::activity::HistogramBucket>> {
+        Ok(serde_json::from_value(
+            self.get_power_histogram(activity_id).await?,
+        )?)
+    }
+    async fn get_hr_histogram_typed(
+        &self,
+        activity_id: &str,
+    ) -> Result<Vec<domains::activity::HistogramBucket>> {
+        Ok(serde_json::from_value(
+            self.get_hr_histogram(activity_id).await?,
+        )?)
+    }
+    async fn get_pace_histogram_typed(
+        &self,
+        activity_id: &str,
+    ) -> Result<Vec<domains::activity::HistogramBucket>> {
+        Ok(serde_json::from_value(
+            self.get_pace_histogram(activity_id).await?,
+        )?)
+    }
 }
 
 #[derive(Clone, Debug, Serialize, PartialEq, JsonSchema)]
@@ -0,0 +1,297 @@
+//! Date-window pagination for list endpoints.
+//!
+//! `get_recent_activities`, `get_events` and `search_activities` return a
+//! single, capped page. The stream variants on [`IntervalsClient`](crate::IntervalsClient)
+//! (`activities_stream`, `events_stream`, `search_activities_stream`) instead
+//! walk a [`DateRange`] in fixed-size chunks with one request per chunk and no
+//! result cap. Streams are lazy: the next chunk is only requested once the
+//! consumer has taken every item of the current one, so long histories are
+//! never buffered in full unless the caller collects them.
+//!
+//! Clients that can only ask for "the last N days" cannot bound a chunk's far
+//! end, so their default streams fetch the range once and split it instead
+//! (see [`split_fetched`]).
+
+use std::collections::VecDeque;
+use std::future::Future;
+
+use chrono::{Duration, NaiveDate};
+use futures_util::future;
+use futures_util::stream::{self, BoxStream, StreamExt, TryStreamExt};
+
+use crate::{ActivitySummary, IntervalsError, Result, ValidationError};
+
+/// Days covered by one request unless [`DateRange::chunk_days`] says otherwise.
+pub const DEFAULT_CHUNK_DAYS: u32 = 90;
+
+/// A stream of items fetched page by page.
+pub type PageStream<'a, T> = BoxStream<'a, Result<T>>;
+
+/// An inclusive range of local dates, walked in chunks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateRange {
+    pub oldest: NaiveDate,
+    pub newest: NaiveDate,
+    /// Days requested per page.
+    pub chunk_days: u32,
+    /// Walk from `newest` back to `oldest` instead of forwards.
+    pub newest_first: bool,
+}
+
+impl DateRange {
+    /// Walk `oldest..=newest` forwards in [`DEFAULT_CHUNK_DAYS`] chunks.
+    #[must_use]
+    pub fn new(oldest: NaiveDate, newest: NaiveDate) -> Self {
+        Self {
+            oldest,
+            newest,
+            chunk_days: DEFAULT_CHUNK_DAYS,
+            newest_first: false,
+        }
+    }
+
+    #[must_use]
+    pub fn chunk_days(mut self, days: u32) -> Self {
+        self.chunk_days = days.max(1);
+        self
+    }
+
+    /// Yield the most recent items first, for example to stop after the
+    /// latest matches.
+    #[must_use]
+    pub fn newest_first(mut self) -> Self {
+        self.newest_first = true;
+        self
+    }
+
+    /// Inclusive `(oldest, newest)` windows in walk order. Empty when
+    /// `oldest` is after `newest`.
+    #[must_use]
+    pub fn chunks(&self) -> Vec<(NaiveDate, NaiveDate)> {
+        let step = i64::from(self.chunk_days.max(1));
+        let mut chunks = Vec::new();
+        let mut start = self.oldest;
+        while start <= self.newest {
+            let end = (start + Duration::days(step - 1)).min(self.newest);
+            chunks.push((start, end));
+            start = end + Duration::days(1);
+        }
+        if self.newest_first {
+            chunks.reverse();
+        }
+        chunks
+    }
+}
+
+/// Local date of an item's `start_date_local`, when it has one.
+fn item_date(start_date_local: &str) -> Option<NaiveDate> {
+    start_date_local
+        .get(..10)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+}
+
+/// Keep the items dated within `oldest..=newest`. Items without a parseable
+/// date are kept.
+pub fn within<T>(
+    items: Vec<T>,
+    oldest: NaiveDate,
+    newest: NaiveDate,
+    date_of: fn(&T) -> &str,
+) -> Vec<T> {
+    items
+        .into_iter()
+        .filter(|item| {
+            item_date(date_of(item)).is_none_or(|date| (oldest..=newest).contains(&date))
+        })
+        .collect()
+}
+
+/// Stream the items `fetch` returns for each chunk of `range`. Every page is
+/// trimmed to its chunk, so each dated item is yielded once, and is sorted
+/// in walk order by `date_of`.
+pub fn paginate<'a, T, F, Fut>(
+    range: DateRange,
+    date_of: fn(&T) -> &str,
+    fetch: F,
+) -> PageStream<'a, T>
+where
+    T: Send + 'a,
+    F: FnMut(NaiveDate, NaiveDate) -> Fut + Send + 'a,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'a,
+{
+    let chunks: VecDeque<_> = range.chunks().into();
+    stream::try_unfold((chunks, fetch), move |(mut chunks, mut fetch)| async move {
+        let Some((oldest, newest)) = chunks.pop_front() else {
+            return Result::Ok(None);
+        };
+        let mut page = within(fetch(oldest, newest).await?, oldest, newest, date_of);
+        page.sort_by(|a, b| date_of(a).cmp(date_of(b)));
+        if range.newest_first {
+            page.reverse();
+        }
+        Ok(Some((page, (chunks, fetch))))
+    })
+    .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+    .try_flatten()
+    .boxed()
+}
+
+/// Stream the items of one `fetch` covering all of `range`, trimmed to the
+/// range and yielded in the order [`paginate`] would yield them. Nothing is
+/// fetched for an empty range.
+pub fn split_fetched<'a, T, Fut>(
+    range: DateRange,
+    date_of: fn(&T) -> &str,
+    fetch: Fut,
+) -> PageStream<'a, T>
+where
+    T: Send + 'a,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'a,
+{
+    if range.oldest > range.newest {
+        return stream::empty().boxed();
+    }
+    stream::once(fetch)
+        .map_ok(move |items| {
+            let mut items = within(items, range.oldest, range.newest, date_of);
+            items.sort_by(|a, b| date_of(a).cmp(date_of(b)));
+            if range.newest_first {
+                items.reverse();
+            }
+            stream::iter(items.into_iter().map(Ok))
+        })
+        .try_flatten()
+        .boxed()
+}
+
+/// `get_recent_activities`/`get_events` window that reaches back to `oldest`.
+pub(crate) fn days_back_to(oldest: NaiveDate) -> i32 {
+    let days = (chrono::Utc::now().date_naive() - oldest).num_days().max(0);
+    i32::try_from(days).unwrap_or(i32::MAX)
+}
+
+/// Keep the activities whose name contains every whitespace-separated term
+/// of `query`, ignoring case. An empty query yields a validation error.
+pub fn filter_by_name<'a>(
+    activities: PageStream<'a, ActivitySummary>,
+    query: &'a str,
+) -> PageStream<'a, ActivitySummary> {
+    if query.trim().is_empty() {
+        return stream::once(future::ready(Err(IntervalsError::Validation(
+            ValidationError::InvalidFormat {
+                field: "query".to_string(),
+                value: "query must not be empty".to_string(),
+            },
+        ))))
+        .boxed();
+    }
+    activities
+        .try_filter(move |activity| future::ready(name_matches(activity.name.as_deref(), query)))
+        .boxed()
+}
+
+fn name_matches(name: Option<&str>, query: &str) -> bool {
+    let name = name.unwrap_or_default().to_lowercase();
+    query
+        .split_whitespace()
+        .all(|term| name.contains(&term.to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn chunks_cover_the_range_without_overlap() {
+        let range = DateRange::new(date("2024-01-01"), date("2024-01-10")).chunk_days(4);
+        assert_eq!(
+            range.chunks(),
+            [
+                (date("2024-01-01"), date("2024-01-04")),
+                (date("2024-01-05"), date("2024-01-08")),
+                (date("2024-01-09"), date("2024-01-10")),
+            ]
+        );
+        assert_eq!(
+            range.newest_first().chunks()[0],
+            (date("2024-01-09"), date("2024-01-10"))
+        );
+        assert!(
+            DateRange::new(date("2024-02-01"), date("2024-01-01"))
+                .chunks()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn paginate_requests_chunks_lazily_and_trims_pages() {
+        let all = [
+            "2024-01-09",
+            "2024-01-02",
+            "2024-01-05T07:00:00",
+            "2024-01-05",
+        ];
+        let requested = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = std::sync::Arc::clone(&requested);
+        let range = DateRange::new(date("2024-01-01"), date("2024-01-10")).chunk_days(4);
+        let mut stream = paginate(
+            range,
+            |item: &String| item.as_str(),
+            move |oldest, newest| {
+                log.lock().unwrap().push((oldest, newest));
+                // Like a client that ignores the window: pages must be trimmed.
+                let page = all.iter().map(|d| (*d).to_string()).collect::<Vec<_>>();
+                async move { Ok(page) }
+            },
+        );
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), "2024-01-02");
+        assert_eq!(requested.lock().unwrap().len(), 1);
+        let rest = stream.try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(rest, ["2024-01-05", "2024-01-05T07:00:00", "2024-01-09"]);
+        assert_eq!(requested.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn split_fetched_fetches_once_for_the_whole_range() {
+        let fetches = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let count = std::sync::Arc::clone(&fetches);
+        let range = DateRange::new(date("2024-01-01"), date("2024-12-31"))
+            .chunk_days(30)
+            .newest_first();
+        let fetch = async move {
+            count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            // Like a "last N days" client: reaches past the end of the range.
+            Ok(["2024-03-01", "2025-02-01", "2023-12-31", "2024-11-20"]
+                .map(String::from)
+                .to_vec())
+        };
+
+        let items = split_fetched(range, |item: &String| item.as_str(), fetch)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(items, ["2024-11-20", "2024-03-01"]);
+        assert_eq!(fetches.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let empty = DateRange::new(date("2024-02-01"), date("2024-01-01"));
+        let items = split_fetched(empty, |item: &String| item.as_str(), async {
+            panic!("an empty range must not fetch")
+        })
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn name_matching_needs_every_term() {
+        assert!(name_matches(Some("Track 6x800m"), "track 800"));
+        assert!(!name_matches(Some("Track 6x800m"), "track 400"));
+        assert!(!name_matches(None, "track"));
+    }
+}
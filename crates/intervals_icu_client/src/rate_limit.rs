@@ -0,0 +1,404 @@
+//! Client-side token-bucket rate limiting for upstream Intervals.icu calls.
+//!
+//! A [`RateLimiter`] holds one bucket per credential and one global bucket.
+//! Every request takes a token from both, so a single API key cannot use the
+//! whole upstream quota and all keys together stay under the global rate.
+//!
+//! Requests for one key wait in FIFO order for that key's bucket. Only the
+//! head of each key's queue then waits for the global bucket, also in FIFO
+//! order, so keys take turns and a key with a long queue cannot starve the
+//! others.
+//!
+//! The limiter adapts to upstream signals. A `429` or `503` halves the key's
+//! rate and the global rate, and `Retry-After` pauses the key's bucket until
+//! the given time. Each later successful response restores a tenth of the
+//! configured rate.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use ::metrics::{counter, gauge, histogram};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+
+/// Lowest fraction of the configured rate a bucket adapts down to.
+const MIN_RATE_FRACTION: f64 = 1.0 / 16.0;
+/// Fraction of the configured rate restored per successful response.
+const RECOVERY_FRACTION: f64 = 0.1;
+/// Idle per-key buckets are dropped once more than this many are tracked.
+const MAX_TRACKED_KEYS: usize = 1024;
+
+/// Token-bucket rates for [`RateLimiter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimitSettings {
+    /// Sustained requests per minute for one API key or access token.
+    pub per_key_per_minute: u32,
+    /// Requests one key may send at once before the rate applies.
+    pub per_key_burst: u32,
+    /// Sustained requests per minute across all keys.
+    pub global_per_minute: u32,
+    pub global_burst: u32,
+}
+
+impl Default for RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            per_key_per_minute: 600,
+            per_key_burst: 30,
+            global_per_minute: 3000,
+            global_burst: 100,
+        }
+    }
+}
+
+struct Bucket {
+    base_rate: f64,
+    /// Current tokens per second, lowered after `429` and `503` responses.
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+    updated: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl Bucket {
+    fn new(per_minute: u32, burst: u32, now: Instant) -> Self {
+        let rate = f64::from(per_minute.max(1)) / 60.0;
+        let capacity = f64::from(burst.max(1));
+        Self {
+            base_rate: rate,
+            rate,
+            capacity,
+            tokens: capacity,
+            updated: now,
+            paused_until: None,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated = now;
+    }
+
+    /// Take a token, or return how long to wait before trying again.
+    fn try_take(&mut self, now: Instant) -> Option<Duration> {
+        if let Some(until) = self.paused_until {
+            if until > now {
+                return Some(until - now);
+            }
+            self.paused_until = None;
+            self.updated = now;
+        }
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - self.tokens) / self.rate))
+        }
+    }
+
+    fn throttle(&mut self, now: Instant, retry_after: Option<Duration>) {
+        self.refill(now);
+        self.rate = (self.rate / 2.0).max(self.base_rate * MIN_RATE_FRACTION);
+        if let Some(delay) = retry_after {
+            let until = now + delay;
+            self.paused_until = Some(
+                self.paused_until
+                    .map_or(until, |current| current.max(until)),
+            );
+            self.tokens = 0.0;
+        }
+    }
+
+    fn recover(&mut self, now: Instant) {
+        if self.rate < self.base_rate {
+            self.refill(now);
+            self.rate = (self.rate + self.base_rate * RECOVERY_FRACTION).min(self.base_rate);
+        }
+    }
+
+    fn is_idle(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.paused_until.is_none_or(|until| until <= now)
+            && self.rate >= self.base_rate
+            && self.tokens >= self.capacity
+    }
+}
+
+/// One key's bucket and the FIFO queue in front of it.
+struct KeyLane {
+    queue: tokio::sync::Mutex<()>,
+    bucket: Mutex<Bucket>,
+}
+
+/// Token-bucket limiter shared by every client built with it; see the
+/// [module documentation](self).
+pub struct RateLimiter {
+    settings: RateLimitSettings,
+    global_queue: tokio::sync::Mutex<()>,
+    global: Mutex<Bucket>,
+    keys: Mutex<HashMap<String, Arc<KeyLane>>>,
+    waiting: AtomicUsize,
+}
+
+impl std::fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("settings", &self.settings)
+            .field("waiting", &self.waiting.load(Ordering::Relaxed))
+            .finish_non_exhaustive()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+async fn wait_for_token(bucket: &Mutex<Bucket>) {
+    loop {
+        let wait = lock(bucket).try_take(Instant::now());
+        match wait {
+            None => return,
+            Some(delay) => tokio::time::sleep(delay).await,
+        }
+    }
+}
+
+/// A caller counted in the queue-depth gauge until dropped, so cancelled or
+/// timed-out acquires leave the gauge.
+struct QueueSlot<'a>(&'a AtomicUsize);
+
+impl<'a> QueueSlot<'a> {
+    fn enter(waiting: &'a AtomicUsize) -> Self {
+        let depth = waiting.fetch_add(1, Ordering::Relaxed) + 1;
+        gauge!("intervals_icu_mcp_upstream_rate_limit_queue_depth").set(depth as f64);
+        Self(waiting)
+    }
+}
+
+impl Drop for QueueSlot<'_> {
+    fn drop(&mut self) {
+        let depth = self.0.fetch_sub(1, Ordering::Relaxed) - 1;
+        gauge!("intervals_icu_mcp_upstream_rate_limit_queue_depth").set(depth as f64);
+    }
+}
+
+impl RateLimiter {
+    #[must_use]
+    pub fn new(settings: RateLimitSettings) -> Self {
+        Self {
+            settings,
+            global_queue: tokio::sync::Mutex::new(()),
+            global: Mutex::new(Bucket::new(
+                settings.global_per_minute,
+                settings.global_burst,
+                Instant::now(),
+            )),
+            keys: Mutex::new(HashMap::new()),
+            waiting: AtomicUsize::new(0),
+        }
+    }
+
+    #[must_use]
+    pub fn settings(&self) -> RateLimitSettings {
+        self.settings
+    }
+
+    /// Bucket key for a credential. Secrets are hashed so the limiter never
+    /// holds them.
+    #[must_use]
+    pub fn key_for(secret: &str) -> String {
+        hex::encode(&Sha256::digest(secret.as_bytes())[..8])
+    }
+
+    fn lane(&self, key: &str) -> Arc<KeyLane> {
+        let now = Instant::now();
+        let mut keys = lock(&self.keys);
+        if keys.len() > MAX_TRACKED_KEYS {
+            keys.retain(|_, lane| Arc::strong_count(lane) > 1 || !lock(&lane.bucket).is_idle(now));
+        }
+        Arc::clone(keys.entry(key.to_string()).or_insert_with(|| {
+            Arc::new(KeyLane {
+                queue: tokio::sync::Mutex::new(()),
+                bucket: Mutex::new(Bucket::new(
+                    self.settings.per_key_per_minute,
+                    self.settings.per_key_burst,
+                    now,
+                )),
+            })
+        }))
+    }
+
+    /// Wait until `key` and the global bucket both grant a token. Returns the
+    /// time spent queued.
+    pub async fn acquire(&self, key: &str) -> Duration {
+        let start = Instant::now();
+        let lane = self.lane(key);
+        {
+            let _slot = QueueSlot::enter(&self.waiting);
+            let _turn = lane.queue.lock().await;
+            wait_for_token(&lane.bucket).await;
+            let _global_turn = self.global_queue.lock().await;
+            wait_for_token(&self.global).await;
+        }
+
+        let waited = start.elapsed();
+        histogram!("intervals_icu_mcp_upstream_rate_limit_wait_seconds")
+            .record(waited.as_secs_f64());
+        waited
+    }
+
+    /// Adapt to an upstream response for `key`. Only `429` and `503` back
+    /// off: they halve the rates and, with `Retry-After`, pause the key until
+    /// then. `Retry-After` on any other status is ignored. Successes (2xx/3xx)
+    /// recover the rate.
+    pub fn observe(&self, key: &str, status: u16, retry_after: Option<Duration>) {
+        let now = Instant::now();
+        let lane = self.lane(key);
+        if matches!(status, 429 | 503) {
+            counter!("intervals_icu_mcp_upstream_throttled_total").increment(1);
+            lock(&lane.bucket).throttle(now, retry_after);
+            lock(&self.global).throttle(now, None);
+        } else if (200..400).contains(&status) {
+            lock(&lane.bucket).recover(now);
+            lock(&self.global).recover(now);
+        }
+    }
+
+    /// Current sustained rate for `key` in requests per second.
+    #[must_use]
+    pub fn current_rate(&self, key: &str) -> f64 {
+        lock(&self.lane(key).bucket).rate
+    }
+}
+
+/// Parse a `Retry-After` value: delay seconds or an HTTP date.
+#[must_use]
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (at.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_spends_burst_then_waits_for_refill() {
+        let now = Instant::now();
+        let mut bucket = Bucket::new(60, 2, now);
+        assert_eq!(bucket.try_take(now), None);
+        assert_eq!(bucket.try_take(now), None);
+        let wait = bucket.try_take(now).expect("empty bucket");
+        assert!((wait.as_secs_f64() - 1.0).abs() < 1e-9, "{wait:?}");
+        assert_eq!(bucket.try_take(now + Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    fn throttle_halves_rate_pauses_and_recovers() {
+        let now = Instant::now();
+        let mut bucket = Bucket::new(600, 10, now);
+        bucket.throttle(now, Some(Duration::from_secs(3)));
+        assert!((bucket.rate - 5.0).abs() < 1e-9);
+        assert_eq!(bucket.try_take(now), Some(Duration::from_secs(3)));
+
+        for _ in 0..20 {
+            bucket.throttle(now, None);
+        }
+        assert!((bucket.rate - 10.0 * MIN_RATE_FRACTION).abs() < 1e-9);
+        for _ in 0..20 {
+            bucket.recover(now);
+        }
+        assert!((bucket.rate - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn parses_retry_after_seconds_and_dates() {
+        let now = DateTime::parse_from_rfc3339("2026-01-15T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(parse_retry_after(" 7 ", now), Some(Duration::from_secs(7)));
+        assert_eq!(
+            parse_retry_after("Thu, 15 Jan 2026 10:00:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Thu, 15 Jan 2026 09:59:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[test]
+    fn only_429_and_503_back_off() {
+        let limiter = RateLimiter::new(RateLimitSettings::default());
+        let base = limiter.current_rate("k");
+        limiter.observe("k", 500, Some(Duration::from_secs(5)));
+        limiter.observe("k", 404, None);
+        assert!((limiter.current_rate("k") - base).abs() < 1e-9);
+
+        limiter.observe("k", 503, Some(Duration::from_secs(5)));
+        assert!((limiter.current_rate("k") - base / 2.0).abs() < 1e-9);
+        limiter.observe("k", 429, None);
+        assert!((limiter.current_rate("k") - base / 4.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn cancelled_acquire_leaves_the_queue() {
+        let limiter = RateLimiter::new(RateLimitSettings {
+            per_key_per_minute: 1,
+            per_key_burst: 1,
+            ..RateLimitSettings::default()
+        });
+        limiter.acquire("k").await;
+        let queued = tokio::time::timeout(Duration::from_millis(20), limiter.acquire("k")).await;
+        assert!(queued.is_err(), "second token should not be granted");
+        assert_eq!(limiter.waiting.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn keys_take_turns_for_the_global_bucket() {
+        let limiter = Arc::new(RateLimiter::new(RateLimitSettings {
+            per_key_per_minute: 60_000,
+            per_key_burst: 100,
+            global_per_minute: 1_200,
+            global_burst: 1,
+        }));
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut tasks = Vec::new();
+        for (index, key) in ["heavy", "heavy", "heavy", "heavy", "heavy", "light"]
+            .into_iter()
+            .enumerate()
+        {
+            let limiter = Arc::clone(&limiter);
+            let order = Arc::clone(&order);
+            tasks.push(tokio::spawn(async move {
+                limiter.acquire(key).await;
+                lock(&order).push(index);
+            }));
+            tokio::task::yield_now().await;
+        }
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let order = lock(&order).clone();
+        let light = order.iter().position(|index| *index == 5).unwrap();
+        assert!(
+            light <= 2,
+            "light key served at position {light}: {order:?}"
+        );
+    }
+}
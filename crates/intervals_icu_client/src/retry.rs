@@ -2,6 +2,7 @@ use rand::{RngExt, rng};
 use std::time::Duration;
 
 /// A simple retry policy with exponential backoff and jitter.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RetryPolicy {
     pub max_retries: u32,
     pub base_delay: Duration,
@@ -17,6 +18,28 @@ impl Default for RetryPolicy {
 }
 
 impl RetryPolicy {
+    /// A policy that never retries.
+    #[must_use]
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Jittered delay before retry number `attempt` (1-based): uniform in
+    /// `0..base_delay * 2^attempt`.
+    #[must_use]
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let base_ms = u64::from(u32::try_from(self.base_delay.as_millis()).unwrap_or(u32::MAX));
+        let max_delay = base_ms.saturating_mul(1u64 << attempt.min(32));
+        if max_delay == 0 {
+            return Duration::ZERO;
+        }
+        let mut rng = rng();
+        Duration::from_millis(rng.random_range(0..max_delay))
+    }
+
     /// Execute an async operation with retry and exponential backoff.
     ///
     /// # Errors
@@ -35,14 +58,7 @@ impl RetryPolicy {
                     if attempt > self.max_retries {
                         return Err(e);
                     }
-                    // exponential backoff with jitter
-                    let base_ms =
-                        u64::from(u32::try_from(self.base_delay.as_millis()).unwrap_or(u32::MAX));
-                    let max_delay = base_ms * (1u64 << attempt);
-                    let mut rng = rng();
-                    let jitter = rng.random_range(0..max_delay);
-                    let delay = Duration::from_millis(jitter.min(max_delay));
-                    tokio::time::sleep(delay).await;
+                    tokio::time::sleep(self.backoff_delay(attempt)).await;
                 }
             }
         }
@@ -53,6 +69,26 @@ impl RetryPolicy {
 mod tests {
     use super::*;
 
+    #[test]
+    fn backoff_delay_stays_within_exponential_bound() {
+        let policy = RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(10),
+        };
+        for attempt in 1..=3 {
+            assert!(policy.backoff_delay(attempt) < Duration::from_millis(10 << attempt));
+        }
+        assert_eq!(RetryPolicy::none().max_retries, 0);
+        assert_eq!(
+            RetryPolicy {
+                max_retries: 1,
+                base_delay: Duration::ZERO
+            }
+            .backoff_delay(1),
+            Duration::ZERO
+        );
+    }
+
     #[tokio::test]
     async fn retry_succeeds_after_retries() {
         use std::sync::Arc;
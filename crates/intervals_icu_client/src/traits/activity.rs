@@ -1,6 +1,12 @@
 //! Activity service trait for activity-related operations.
 
-use crate::{ActivityMessage, ActivitySummary, BestEffortsOptions, Result};
+use chrono::NaiveDate;
+
+use crate::domains::activity::ActivityUploadResult;
+use crate::{
+    ActivityMessage, ActivitySummary, ActivityUpload, BestEffortsOptions, ConfigError,
+    IntervalsError, Result,
+};
 
 /// Service for activity-related operations.
 #[async_trait::async_trait]
@@ -13,6 +19,14 @@ pub trait ActivityService: Send + Sync + 'static {
         days_back: Option<i32>,
     ) -> Result<Vec<ActivitySummary>>;
 
+    /// Get activities that started between `oldest` and `newest` (inclusive),
+    /// without a result cap.
+    async fn get_activities_between(
+        &self,
+        oldest: NaiveDate,
+        newest: NaiveDate,
+    ) -> Result<Vec<ActivitySummary>>;
+
     /// Get detailed information about a specific activity.
     async fn get_activity_details(&self, activity_id: &str) -> Result<serde_json::Value>;
 
@@ -21,6 +35,18 @@ pub trait ActivityService: Send + Sync + 'static {
         Ok(Vec::new())
     }
 
+    /// Post a comment message on an activity. Services without comment support
+    /// return a configuration error.
+    async fn add_activity_message(
+        &self,
+        _activity_id: &str,
+        _content: &str,
+    ) -> Result<serde_json::Value> {
+        Err(IntervalsError::Config(ConfigError::Other(
+            "add_activity_message is not implemented for this client".to_string(),
+        )))
+    }
+
     /// Get activity stream data (power, heart rate, etc.).
     async fn get_activity_streams(
         &self,
@@ -96,6 +122,9 @@ pub trait ActivityService: Send + Sync + 'static {
         output_path: Option<std::path::PathBuf>,
     ) -> Result<Option<String>>;
 
+    /// Upload a FIT, TCX or GPX file as a new activity (multipart, streamed from disk).
+    async fn upload_activity_file(&self, upload: &ActivityUpload) -> Result<ActivityUploadResult>;
+
     /// Download GPX file.
     async fn download_gpx_file(
         &self,
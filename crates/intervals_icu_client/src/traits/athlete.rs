@@ -1,5 +1,6 @@
 //! Athlete service trait for profile-related operations.
 
+use crate::domains::athlete::CoachedAthlete;
 use crate::{AthleteProfile, Result};
 
 /// Service for athlete profile operations.
@@ -7,4 +8,7 @@ use crate::{AthleteProfile, Result};
 pub trait AthleteService: Send + Sync + 'static {
     /// Get the athlete's profile information.
     async fn get_athlete_profile(&self) -> Result<AthleteProfile>;
+
+    /// List the athletes a coach account can access.
+    async fn list_coached_athletes(&self) -> Result<Vec<CoachedAthlete>>;
 }
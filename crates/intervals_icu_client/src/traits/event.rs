@@ -1,5 +1,7 @@
 //! Event service trait for calendar/event-related operations.
 
+use chrono::NaiveDate;
+
 use crate::{Event, Result};
 
 /// Service for event/calendar operations.
@@ -17,6 +19,10 @@ pub trait EventService: Send + Sync + 'static {
     /// Get events with optional filtering.
     async fn get_events(&self, days_back: Option<i32>, limit: Option<u32>) -> Result<Vec<Event>>;
 
+    /// Get events between `oldest` and `newest` (inclusive), without a result
+    /// cap.
+    async fn get_events_between(&self, oldest: NaiveDate, newest: NaiveDate) -> Result<Vec<Event>>;
+
     /// Create multiple events in bulk.
     async fn bulk_create_events(&self, events: Vec<Event>) -> Result<Vec<Event>>;
 
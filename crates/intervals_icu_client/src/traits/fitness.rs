@@ -1,6 +1,9 @@
 //! Fitness service trait for fitness metrics and summaries.
 
+use chrono::NaiveDate;
+
 use crate::Result;
+use crate::domains::curve::CurveKind;
 
 /// Service for fitness metrics and CTL/ATL/TSB analysis.
 #[async_trait::async_trait]
@@ -25,4 +28,14 @@ pub trait FitnessService: Send + Sync + 'static {
         days_back: Option<i32>,
         sport: &str,
     ) -> Result<serde_json::Value>;
+
+    /// Get one curve for a sport over `oldest..=newest`, for windows that do
+    /// not end today (seasons, calendar months).
+    async fn get_curves_between(
+        &self,
+        kind: CurveKind,
+        sport: &str,
+        oldest: NaiveDate,
+        newest: NaiveDate,
+    ) -> Result<serde_json::Value>;
 }
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use intervals_icu_client::http_client::ReqwestIntervalsClient;
+use intervals_icu_client::{Credentials, IntervalsClient, OAuthToken, TokenRefresher};
+use secrecy::{ExposeSecret, SecretString};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Hands out `fresh-1`, `fresh-2`, ... and counts calls.
+#[derive(Default)]
+struct CountingRefresher {
+    calls: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl TokenRefresher for CountingRefresher {
+    async fn refresh(&self, current: &OAuthToken) -> intervals_icu_client::Result<OAuthToken> {
+        assert_eq!(
+            current
+                .refresh_token
+                .as_ref()
+                .map(|token| token.expose_secret().to_string()),
+            Some("refresh".to_string())
+        );
+        let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        Ok(
+            OAuthToken::new(SecretString::new(format!("fresh-{call}").into()))
+                .with_refresh_token(SecretString::new("refresh".into())),
+        )
+    }
+}
+
+fn token(access: &str) -> OAuthToken {
+    OAuthToken::new(SecretString::new(access.into()))
+        .with_refresh_token(SecretString::new("refresh".into()))
+}
+
+#[tokio::test]
+async fn oauth_credentials_send_bearer_token() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/wellness"))
+        .and(header("authorization", "Bearer access-abc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client =
+        ReqwestIntervalsClient::new(&mock_server.uri(), "ath", Credentials::bearer("access-abc"))
+            .expect("build");
+    client.get_wellness(Some(1)).await.expect("wellness");
+}
+
+#[tokio::test]
+async fn unauthorized_request_is_replayed_once_with_refreshed_token() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/athlete/ath/events/bulk"))
+        .and(header("authorization", "Bearer stale"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/athlete/ath/events/bulk"))
+        .and(header("authorization", "Bearer fresh-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let refresher = Arc::new(CountingRefresher::default());
+    let client = ReqwestIntervalsClient::builder(&mock_server.uri(), "ath", token("stale"))
+        .token_refresher(refresher.clone())
+        .build()
+        .expect("build");
+    let created = client.bulk_create_events(vec![]).await.expect("events");
+    assert!(created.is_empty());
+    assert_eq!(refresher.calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn persistent_unauthorized_is_not_refreshed_twice() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/gear"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let refresher = Arc::new(CountingRefresher::default());
+    let client = ReqwestIntervalsClient::builder(&mock_server.uri(), "ath", token("stale"))
+        .token_refresher(refresher.clone())
+        .build()
+        .expect("build");
+    let err = client.get_gear_list().await.unwrap_err();
+    assert!(
+        matches!(err, intervals_icu_client::IntervalsError::Auth(_)),
+        "{err}"
+    );
+    assert_eq!(refresher.calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn expiring_token_is_refreshed_before_the_request() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/gear"))
+        .and(header("authorization", "Bearer fresh-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let refresher = Arc::new(CountingRefresher::default());
+    let expiring = token("old").with_expires_at(chrono::Utc::now());
+    let client = ReqwestIntervalsClient::builder(&mock_server.uri(), "ath", expiring)
+        .token_refresher(refresher.clone())
+        .build()
+        .expect("build");
+    client.get_gear_list().await.expect("first");
+    client.get_gear_list().await.expect("second");
+    assert_eq!(
+        refresher.calls.load(Ordering::SeqCst),
+        1,
+        "the refreshed token has no expiry and is reused"
+    );
+}
+
+#[tokio::test]
+async fn api_key_unauthorized_is_returned_without_replay() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/gear"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client =
+        ReqwestIntervalsClient::builder(&mock_server.uri(), "ath", SecretString::new("key".into()))
+            .token_refresher(Arc::new(CountingRefresher::default()))
+            .build()
+            .expect("build");
+    assert!(client.get_gear_list().await.is_err());
+}
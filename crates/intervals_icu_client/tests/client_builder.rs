@@ -0,0 +1,249 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use intervals_icu_client::config::HttpSettings;
+use intervals_icu_client::http_client::ReqwestIntervalsClient;
+use intervals_icu_client::rate_limit::{RateLimitSettings, RateLimiter};
+use intervals_icu_client::retry::RetryPolicy;
+use intervals_icu_client::{IntervalsClient, IntervalsError};
+use secrecy::SecretString;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn fast_retries(max_retries: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_retries,
+        base_delay: Duration::from_millis(1),
+    }
+}
+
+#[tokio::test]
+async fn builder_sends_configured_user_agent() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/wellness"))
+        .and(header("user-agent", "coach-bot/1.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client =
+        ReqwestIntervalsClient::builder(&mock_server.uri(), "ath", SecretString::new("key".into()))
+            .user_agent("coach-bot/1.0")
+            .build()
+            .expect("build");
+    client.get_wellness(Some(1)).await.expect("wellness");
+}
+
+#[tokio::test]
+async fn idempotent_requests_retry_on_unavailable() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/wellness"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/wellness"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{"id": "d"}])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client =
+        ReqwestIntervalsClient::builder(&mock_server.uri(), "ath", SecretString::new("key".into()))
+            .retry_policy(fast_retries(3))
+            .build()
+            .expect("build");
+    let wellness = client.get_wellness(Some(1)).await.expect("wellness");
+    assert_eq!(wellness, serde_json::json!([{"id": "d"}]));
+}
+
+#[tokio::test]
+async fn post_requests_are_not_retried() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/athlete/ath/events/bulk"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client =
+        ReqwestIntervalsClient::builder(&mock_server.uri(), "ath", SecretString::new("key".into()))
+            .retry_policy(fast_retries(3))
+            .build()
+            .expect("build");
+    assert!(client.bulk_create_events(vec![]).await.is_err());
+}
+
+#[tokio::test]
+async fn request_timeout_and_settings_from_config_apply() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/wellness"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([]))
+                .set_delay(Duration::from_millis(500)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let settings = HttpSettings::from_env_with(|key| match key {
+        "INTERVALS_ICU_TIMEOUT_SECS" => Some("1".into()),
+        _ => None,
+    })
+    .expect("settings");
+    let client =
+        ReqwestIntervalsClient::builder(&mock_server.uri(), "ath", SecretString::new("key".into()))
+            .settings(settings)
+            .request_timeout(Some(Duration::from_millis(50)))
+            .build()
+            .expect("build");
+    let err = client.get_wellness(Some(1)).await.unwrap_err();
+    assert!(
+        matches!(err, IntervalsError::Http(ref e) if e.is_timeout()),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn circuit_breaker_threshold_is_configurable() {
+    let client = ReqwestIntervalsClient::builder(
+        "http://127.0.0.1:9",
+        "ath",
+        SecretString::new("key".into()),
+    )
+    .circuit_breaker(1, Duration::from_secs(60))
+    .build()
+    .expect("build");
+
+    assert!(client.get_wellness(Some(1)).await.is_err());
+    let err = client.get_wellness(Some(1)).await.unwrap_err();
+    assert!(err.to_string().contains("circuit breaker open"), "{err}");
+}
+
+#[test]
+fn builder_rejects_invalid_proxy_and_missing_certificate() {
+    let builder = || {
+        ReqwestIntervalsClient::builder(
+            "https://intervals.icu",
+            "ath",
+            SecretString::new("key".into()),
+        )
+    };
+    let err = builder().proxy("not a url").build().unwrap_err();
+    assert!(err.to_string().contains("invalid proxy"), "{err}");
+
+    let err = builder()
+        .root_certificate("/nonexistent/ca.pem")
+        .build()
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("failed to read certificate"),
+        "{err}"
+    );
+
+    let injected = builder()
+        .proxy("not a url")
+        .http_client(reqwest::Client::new())
+        .build();
+    assert!(
+        injected.is_ok(),
+        "an injected client skips transport settings"
+    );
+}
+
+#[tokio::test]
+async fn shared_rate_limiter_throttles_every_client() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(4)
+        .mount(&mock_server)
+        .await;
+    let limiter = Arc::new(RateLimiter::new(RateLimitSettings {
+        per_key_per_minute: 60_000,
+        per_key_burst: 100,
+        global_per_minute: 600,
+        global_burst: 2,
+    }));
+    let client_for = |key: &str| {
+        ReqwestIntervalsClient::builder(&mock_server.uri(), "ath", SecretString::new(key.into()))
+            .rate_limiter(Arc::clone(&limiter))
+            .build()
+            .expect("build")
+    };
+    let (first, second) = (client_for("key-one"), client_for("key-two"));
+
+    let start = Instant::now();
+    for client in [&first, &second, &first, &second] {
+        client.get_wellness(None).await.expect("wellness");
+    }
+    // Two requests fit the burst; the other two wait 100 ms each for a token.
+    assert!(
+        start.elapsed() >= Duration::from_millis(180),
+        "{:?}",
+        start.elapsed()
+    );
+}
+
+#[tokio::test]
+async fn rate_limiter_honours_retry_after_on_429() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/wellness"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "1"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/wellness"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let limiter = Arc::new(RateLimiter::new(RateLimitSettings::default()));
+    let client =
+        ReqwestIntervalsClient::builder(&mock_server.uri(), "ath", SecretString::new("key".into()))
+            .retry_policy(fast_retries(1))
+            .rate_limiter(Arc::clone(&limiter))
+            .build()
+            .expect("build");
+
+    let start = Instant::now();
+    client
+        .get_wellness(None)
+        .await
+        .expect("wellness after retry");
+    assert!(
+        start.elapsed() >= Duration::from_millis(950),
+        "{:?}",
+        start.elapsed()
+    );
+    // Halved by the 429, then one success restores a tenth of the base rate.
+    let key = RateLimiter::key_for("key");
+    assert!((limiter.current_rate(&key) - 10.0 * 0.6).abs() < 1e-9);
+}
+
+#[test]
+fn rate_limit_settings_default_to_enabled_and_can_be_disabled() {
+    assert_eq!(
+        HttpSettings::default().rate_limit,
+        Some(RateLimitSettings::default())
+    );
+    let client =
+        ReqwestIntervalsClient::builder("http://localhost", "ath", SecretString::new("k".into()))
+            .settings(HttpSettings {
+                rate_limit: None,
+                ..HttpSettings::default()
+            })
+            .build()
+            .expect("build");
+    assert!(format!("{client:?}").contains("rate_limiter: None"));
+}
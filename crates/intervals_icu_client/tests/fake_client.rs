@@ -0,0 +1,267 @@
+use intervals_icu_client::fake::{FakeFixtures, FakeIntervalsClient};
+use intervals_icu_client::{ActivityUpload, Event, EventCategory, IntervalsClient, IntervalsError};
+use serde_json::{Value, json};
+
+const FIXTURES: &str = r#"{
+    "athlete_id": "i42",
+    "athlete_name": "Sam",
+    "today": "2026-03-10",
+    "activities": [
+        {"id": "i1", "name": "Easy Run", "type": "Run", "start_date_local": "2026-03-08T07:00:00", "moving_time": 3600, "distance": 10000.0, "icu_training_load": 60},
+        {"id": "i2", "name": "Threshold Ride", "type": "Ride", "start_date_local": "2026-03-09T18:00:00", "moving_time": 5400, "icu_training_load": 110},
+        {"id": "i3", "name": "Old Long Run", "type": "Run", "start_date_local": "2026-01-02T08:00:00", "icu_training_load": 150}
+    ],
+    "events": [
+        {"id": 501, "start_date_local": "2026-03-12T00:00:00", "name": "VO2 Intervals", "category": "WORKOUT", "type": "Ride"},
+        {"id": 502, "start_date_local": "2026-03-01T00:00:00", "name": "Past Tempo", "category": "WORKOUT", "type": "Run"}
+    ],
+    "wellness": [{"id": "2026-03-09", "restingHR": 48, "hrv": 72}],
+    "gear": [{"id": "g1", "name": "Trainers", "type": "Shoes", "distance": 400000}],
+    "sport_settings": [{"id": 7, "types": ["Ride", "VirtualRide"], "ftp": 250}],
+    "streams": {"i2": {"time": [0, 1, 2], "watts": [200, 210, 220], "heartrate": [120, 121, 122]}}
+}"#;
+
+fn client() -> FakeIntervalsClient {
+    FakeIntervalsClient::from_json(FIXTURES).expect("valid fixtures")
+}
+
+fn workout(date: &str, name: &str) -> Event {
+    Event {
+        start_date_local: date.to_string(),
+        name: name.to_string(),
+        category: EventCategory::Workout,
+        r#type: Some("Run".to_string()),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn reads_are_relative_to_the_fixture_today() {
+    let client = client();
+    assert_eq!(client.today().to_string(), "2026-03-10");
+    assert_eq!(client.athlete_id(), Some("i42"));
+
+    let recent = client.get_recent_activities(None, None).await.unwrap();
+    let ids = recent.iter().map(|a| a.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(ids, ["i2", "i1"]);
+    assert_eq!(recent[0].training_load, Some(110));
+    assert_eq!(
+        client
+            .get_recent_activities(Some(10), Some(90))
+            .await
+            .unwrap()
+            .len(),
+        3
+    );
+
+    let past = client.get_events(Some(14), None).await.unwrap();
+    assert_eq!(past.len(), 1);
+    assert_eq!(past[0].id.as_deref(), Some("502"));
+    let upcoming = client
+        .get_upcoming_workouts(None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(upcoming[0]["name"], "VO2 Intervals");
+}
+
+#[tokio::test]
+async fn plan_modify_and_delete_events_persist() {
+    let client = client();
+    let created = client
+        .bulk_create_events(vec![
+            workout("2026-03-11T00:00:00", "Easy 45"),
+            workout("2026-03-13T00:00:00", "Long Run"),
+        ])
+        .await
+        .unwrap();
+    let long_run = created[1].id.clone().unwrap();
+
+    let moved = client
+        .update_event(
+            &long_run,
+            &json!({"start_date_local": "2026-03-14T00:00:00", "description": "90 min Z2"}),
+        )
+        .await
+        .unwrap();
+    assert_eq!(moved["name"], "Long Run");
+    let fetched = client.get_event(&long_run).await.unwrap();
+    assert_eq!(fetched.start_date_local, "2026-03-14T00:00:00");
+    assert_eq!(fetched.description.as_deref(), Some("90 min Z2"));
+
+    let copies = client
+        .duplicate_event(&long_run, Some(2), Some(1))
+        .await
+        .unwrap();
+    assert_eq!(copies[1].start_date_local, "2026-03-28T00:00:00");
+
+    client.delete_event("501").await.unwrap();
+    let upcoming = client
+        .get_upcoming_workouts(Some(7), None, Some("WORKOUT".into()))
+        .await
+        .unwrap();
+    let names = upcoming
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|event| event["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Easy 45", "Long Run"]);
+    assert!(matches!(
+        client.get_event("501").await,
+        Err(IntervalsError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn fitness_summary_follows_activity_loads() {
+    let client = client();
+    let before = client.get_fitness_summary().await.unwrap();
+    assert_eq!(before, client.get_fitness_summary().await.unwrap());
+
+    client
+        .update_activity("i2", &json!({"icu_training_load": 300}))
+        .await
+        .unwrap();
+    let after = client.get_fitness_summary().await.unwrap();
+    let fatigue = |summary: &Value| summary[0]["fatigue"].as_f64().unwrap();
+    assert!(fatigue(&after) > fatigue(&before));
+    assert_eq!(after[0]["date"], "2026-03-10");
+
+    client.delete_activity("i2").await.unwrap();
+    assert!(client.get_activity_streams("i2", None).await.is_err());
+}
+
+#[tokio::test]
+async fn wellness_gear_and_sport_settings_persist() {
+    let client = client();
+    client
+        .update_wellness("2026-03-10", &json!({"restingHR": 50, "sleepSecs": 28800}))
+        .await
+        .unwrap();
+    client
+        .update_wellness("2026-03-09", &json!({"hrv": 65}))
+        .await
+        .unwrap();
+    let wellness = client.get_wellness(Some(7)).await.unwrap();
+    assert_eq!(wellness.as_array().unwrap().len(), 2);
+    assert_eq!(wellness[0]["hrv"], 65);
+    assert_eq!(wellness[0]["restingHR"], 48);
+    let typed = client
+        .get_wellness_for_date_typed("2026-03-10")
+        .await
+        .unwrap();
+    assert_eq!(typed.resting_hr, Some(50.0));
+
+    let reminder = client
+        .create_gear_reminder("g1", &json!({"name": "Replace", "distance": 800000}))
+        .await
+        .unwrap();
+    let reminder_id = reminder["id"].to_string();
+    let updated = client
+        .update_gear_reminder("g1", &reminder_id, true, 0, &json!({}))
+        .await
+        .unwrap();
+    assert_eq!(updated["last_reset"], "2026-03-10");
+    let gear = client.get_gear_list().await.unwrap();
+    assert_eq!(gear[0]["reminders"][0]["last_reset"], "2026-03-10");
+
+    client
+        .update_sport_settings("virtualride", false, &json!({"ftp": 265}))
+        .await
+        .unwrap();
+    let settings = client.get_sport_settings().await.unwrap();
+    assert_eq!(settings.sports[0].ftp, Some(265.0));
+    assert!(client.apply_sport_settings("Swim").await.is_err());
+}
+
+#[tokio::test]
+async fn streams_are_served_from_fixtures_and_filtered() {
+    let client = client();
+    let streams = client
+        .get_activity_streams("i2", Some(vec!["watts".into()]))
+        .await
+        .unwrap();
+    assert_eq!(streams, json!({"watts": [200, 210, 220]}));
+    assert_eq!(
+        client.get_activity_streams("i1", None).await.unwrap(),
+        json!([])
+    );
+    assert!(client.get_activity_streams("nope", None).await.is_err());
+}
+
+#[tokio::test]
+async fn uploaded_fit_file_becomes_an_activity() {
+    let client = client();
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/fit/run_stryd_core_hrv.fit"
+    );
+    let result = client
+        .upload_activity_file(&ActivityUpload::new(path).with_name("Imported Run"))
+        .await
+        .unwrap();
+    let id = result.id.clone().unwrap();
+    assert_eq!(result.icu_athlete_id.as_deref(), Some("i42"));
+
+    let detail = client.get_activity_details(&id).await.unwrap();
+    assert_eq!(detail["name"], "Imported Run");
+    assert_eq!(detail["type"], "Run");
+    assert_eq!(detail["moving_time"], 900);
+    assert_eq!(detail["start_date"], "2024-11-08T11:33:20Z");
+}
+
+#[tokio::test]
+async fn snapshot_round_trips_written_state() {
+    let client = client();
+    client
+        .create_event(workout("2026-03-15", "Race Prep"))
+        .await
+        .unwrap();
+    let snapshot = client.snapshot();
+    let restored = FakeIntervalsClient::from_fixtures(
+        serde_json::from_value(serde_json::to_value(&snapshot).unwrap()).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(restored.snapshot(), snapshot);
+    assert_eq!(restored.get_events(None, None).await.unwrap().len(), 3);
+
+    let invalid = FakeFixtures {
+        today: Some("tomorrow".into()),
+        ..FakeFixtures::default()
+    };
+    assert!(FakeIntervalsClient::from_fixtures(invalid).is_err());
+}
+
+#[tokio::test]
+async fn streams_walk_absolute_date_ranges() {
+    use futures_util::TryStreamExt;
+    use intervals_icu_client::DateRange;
+
+    let client = client();
+    let date = |value: &str| chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap();
+    let range = DateRange::new(date("2025-12-01"), date("2026-03-31")).chunk_days(31);
+
+    let ids = client
+        .activities_stream(range)
+        .map_ok(|activity| activity.id)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(ids, ["i3", "i1", "i2"]);
+
+    let runs = client
+        .search_activities_stream("run", range.newest_first())
+        .map_ok(|activity| activity.id)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(runs, ["i1", "i3"]);
+
+    let events = client
+        .events_stream(range)
+        .map_ok(|event| event.name)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(events, ["Past Tempo", "VO2 Intervals"]);
+}
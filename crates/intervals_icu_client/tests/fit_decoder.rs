@@ -0,0 +1,143 @@
+use intervals_icu_client::fit::{FIT_EPOCH_OFFSET, FitActivity, FitError};
+use serde_json::Value;
+
+const RUN: &[u8] = include_bytes!("fixtures/fit/run_stryd_core_hrv.fit");
+const RIDE: &[u8] = include_bytes!("fixtures/fit/ride_big_endian_compressed.fit");
+
+fn stream<'a>(streams: &'a Value, key: &str) -> &'a Vec<Value> {
+    streams
+        .get(key)
+        .and_then(Value::as_array)
+        .unwrap_or_else(|| panic!("missing stream {key}"))
+}
+
+#[test]
+fn run_fixture_decodes_records_laps_sessions_and_devices() {
+    let activity = FitActivity::decode(RUN).expect("decode run fixture");
+
+    assert_eq!(
+        activity.time_created,
+        Some(FIT_EPOCH_OFFSET + 1_100_000_000)
+    );
+    assert_eq!(activity.records.len(), 900);
+    let first = &activity.records[0];
+    assert_eq!(first.timestamp, activity.time_created);
+    assert!((first.latitude.unwrap() - 47.377).abs() < 0.01);
+    assert!((first.longitude.unwrap() - 8.5417).abs() < 0.001);
+    assert!((first.altitude.unwrap() - 430.0).abs() < 1.0);
+    assert!((first.speed.unwrap() - 3.2).abs() < 0.01);
+    assert_eq!(first.temperature, Some(24.0));
+
+    assert_eq!(activity.laps.len(), 1);
+    assert_eq!(activity.laps[0].total_elapsed_time, Some(900.0));
+    assert_eq!(activity.sessions.len(), 1);
+    let session = &activity.sessions[0];
+    assert_eq!(session.sport.as_deref(), Some("running"));
+    assert_eq!(session.normalized_power, Some(252.0));
+    assert_eq!(session.avg_speed, Some(3.2));
+
+    let names = activity
+        .devices
+        .iter()
+        .filter_map(|device| device.product_name.as_deref())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Forerunner 965", "Stryd", "CORE"]);
+    assert_eq!(activity.devices[0].software_version, Some(15.2));
+    assert_eq!(activity.devices[2].manufacturer, None);
+}
+
+#[test]
+fn run_fixture_decodes_developer_fields_and_rr_intervals() {
+    let activity = FitActivity::decode(RUN).unwrap();
+
+    let keys = activity
+        .developer_fields
+        .iter()
+        .map(|field| field.stream_key())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        ["form_power", "leg_spring_stiffness", "core_temperature"]
+    );
+    let record = &activity.records[0];
+    let core = record.developer["core_temperature"];
+    assert!((37.3..37.5).contains(&core), "core temperature {core}");
+    assert!((9.0..10.0).contains(&record.developer["leg_spring_stiffness"]));
+    assert_eq!(
+        record.developer["form_power"],
+        (record.power.unwrap() * 0.22).trunc()
+    );
+
+    assert!(activity.rr_intervals.len() > 1_500);
+    assert!(
+        activity
+            .rr_intervals
+            .iter()
+            .all(|rr| (300.0..1_000.0).contains(rr))
+    );
+}
+
+#[test]
+fn run_fixture_converts_to_stream_shape() {
+    let streams = FitActivity::decode(RUN).unwrap().to_streams();
+
+    for key in [
+        "time",
+        "watts",
+        "heartrate",
+        "cadence",
+        "velocity_smooth",
+        "altitude",
+        "distance",
+        "temp",
+        "lat",
+        "lng",
+        "form_power",
+        "leg_spring_stiffness",
+        "core_temperature",
+    ] {
+        assert_eq!(stream(&streams, key).len(), 900, "stream {key}");
+    }
+    assert_eq!(stream(&streams, "time")[899], 899.0);
+    assert!(stream(&streams, "rr_intervals").len() > 1_500);
+}
+
+#[test]
+fn ride_fixture_handles_big_endian_and_compressed_timestamps() {
+    let activity = FitActivity::decode(RIDE).expect("decode ride fixture");
+    let start = FIT_EPOCH_OFFSET + 1_100_086_400;
+
+    assert_eq!(activity.records.len(), 121);
+    assert!(
+        activity
+            .records
+            .iter()
+            .enumerate()
+            .all(|(index, record)| record.timestamp == Some(start + index as i64))
+    );
+    assert_eq!(activity.records[1].power, Some(201.0));
+    assert_eq!(activity.records[60].power, None);
+    assert_eq!(activity.records[120].cadence, None);
+    assert_eq!(activity.sessions[0].sport.as_deref(), Some("cycling"));
+    assert_eq!(activity.sessions[0].total_elapsed_time, Some(121.0));
+
+    let streams = activity.to_streams();
+    assert_eq!(stream(&streams, "watts")[60], Value::Null);
+    assert_eq!(stream(&streams, "time")[120], 120.0);
+    assert!(streams.get("lat").is_none());
+    assert!(streams.get("rr_intervals").is_none());
+}
+
+#[test]
+fn corrupt_and_truncated_files_are_rejected() {
+    let mut corrupt = RIDE.to_vec();
+    corrupt[40] ^= 0xFF;
+    assert!(matches!(
+        FitActivity::decode(&corrupt),
+        Err(FitError::CrcMismatch { .. })
+    ));
+    assert!(matches!(
+        FitActivity::decode(&RIDE[..RIDE.len() / 2]),
+        Err(FitError::Truncated(_))
+    ));
+}
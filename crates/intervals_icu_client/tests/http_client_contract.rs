@@ -1,9 +1,11 @@
 use base64::{Engine as _, engine::general_purpose::STANDARD};
 use chrono::{Duration, Utc};
 use intervals_icu_client::IntervalsClient;
+use intervals_icu_client::cassette::{Cassette, MatchMode};
 use intervals_icu_client::http_client::ReqwestIntervalsClient;
 use secrecy::SecretString;
-use wiremock::matchers::{method, path, query_param};
+use std::sync::Arc;
+use wiremock::matchers::{body_string_contains, header_regex, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 const LIVE_OPENAPI_SPEC_URL: &str = "https://intervals.icu/api/v1/docs";
@@ -21,89 +23,97 @@ async fn fetch_live_openapi_spec() -> serde_json::Value {
         .expect("parse live OpenAPI spec")
 }
 
-fn spec_operation<'a>(
-    spec: &'a serde_json::Value,
-    path_name: &str,
-    method_name: &str,
-) -> &'a serde_json::Value {
-    spec.get("paths")
-        .and_then(|paths| paths.get(path_name))
-        .and_then(|path_item| path_item.get(method_name))
-        .unwrap_or_else(|| panic!("missing {method_name} {path_name} in live spec"))
+const CASSETTE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/cassettes");
+
+/// Strict replay of `contract/<name>.json`: requests must arrive in the
+/// recorded order with the recorded path, query and body.
+fn contract_cassette(name: &str) -> Arc<Cassette> {
+    Arc::new(
+        Cassette::replay(
+            format!("{CASSETTE_DIR}/contract/{name}.json"),
+            MatchMode::Strict,
+        )
+        .expect("cassette"),
+    )
 }
 
-fn assert_query_param(
-    spec: &serde_json::Value,
-    path_name: &str,
-    method_name: &str,
-    param_name: &str,
-) {
-    let params = spec_operation(spec, path_name, method_name)
+/// Committed cassettes; `live/` recordings are local and not checked.
+fn fixture_cassettes() -> Vec<std::path::PathBuf> {
+    let mut files = [CASSETTE_DIR.to_string(), format!("{CASSETTE_DIR}/contract")]
+        .iter()
+        .flat_map(|dir| std::fs::read_dir(dir).expect("cassette dir"))
+        .map(|entry| entry.expect("cassette entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+/// Whether a spec path template such as `/api/v1/athlete/{id}/events{format}`
+/// covers a recorded path. A placeholder segment matches any non-empty segment;
+/// a trailing placeholder (`events{format}`) matches any suffix.
+fn template_matches(template: &str, path: &str) -> bool {
+    let template = template.split('/').collect::<Vec<_>>();
+    let path = path.split('/').collect::<Vec<_>>();
+    template.len() == path.len()
+        && template
+            .iter()
+            .zip(&path)
+            .all(|(template, segment)| match template.find('{') {
+                None => template == segment,
+                Some(0) => !segment.is_empty(),
+                Some(at) => segment.starts_with(&template[..at]),
+            })
+}
+
+fn has_query_param(operation: &serde_json::Value, param_name: &str) -> bool {
+    operation
         .get("parameters")
         .and_then(serde_json::Value::as_array)
-        .unwrap_or_else(|| panic!("missing parameters for {method_name} {path_name}"));
-    assert!(
-        params.iter().any(|param| {
-            param.get("in").and_then(serde_json::Value::as_str) == Some("query")
-                && param.get("name").and_then(serde_json::Value::as_str) == Some(param_name)
-        }),
-        "missing query param {param_name} for {method_name} {path_name}"
-    );
+        .is_some_and(|params| {
+            params.iter().any(|param| {
+                param.get("in").and_then(serde_json::Value::as_str) == Some("query")
+                    && param.get("name").and_then(serde_json::Value::as_str) == Some(param_name)
+            })
+        })
 }
 
+/// Requests only exercised by the wiremock tests below, whose query depends on
+/// today's date or whose body is a multipart stream.
+const WIREMOCK_ONLY_REQUESTS: [(&str, &str, &[&str]); 2] = [
+    (
+        "GET",
+        "/api/v1/athlete/{athlete_id}/events",
+        &["oldest", "newest"],
+    ),
+    (
+        "POST",
+        "/api/v1/athlete/{athlete_id}/activities",
+        &["name", "external_id"],
+    ),
+];
+
 #[tokio::test]
 async fn get_activities_around_uses_activities_around_path() {
-    let mock_server = MockServer::start().await;
-    let athlete = "ath";
-    let expected = serde_json::json!([
-        {"id": "a-prev", "name": "Warmup Ride"}
-    ]);
+    let cassette = contract_cassette("activities_around");
+    let client = offline_client("i24680", Arc::clone(&cassette));
 
-    Mock::given(method("GET"))
-        .and(path("/api/v1/athlete/ath/activities-around"))
-        .and(query_param("activity_id", "a1"))
-        .and(query_param("limit", "5"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(&expected))
-        .mount(&mock_server)
-        .await;
-
-    let client =
-        ReqwestIntervalsClient::new(&mock_server.uri(), athlete, SecretString::new("key".into()))
-            .expect("new");
     let res = client
-        .get_activities_around("a1", Some(5), None)
+        .get_activities_around("i71234567", Some(5), None)
         .await
         .expect("activities around response");
-    assert_eq!(res, expected);
+    assert_eq!(res[0]["name"], "Warmup Ride");
+    assert_eq!(cassette.remaining(), 0);
 }
 
 #[tokio::test]
 async fn apply_sport_settings_uses_put() {
-    let mock_server = MockServer::start().await;
-    let sport = "Run";
-    let athlete = "ath";
-
-    Mock::given(method("GET"))
-        .and(path(format!("/api/v1/athlete/{athlete}/sport-settings")))
-        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
-            {"id": 1_783_043, "types": ["Run", "VirtualRun", "TrailRun"]}
-        ])))
-        .mount(&mock_server)
-        .await;
-
-    Mock::given(method("PUT"))
-        .and(path("/api/v1/athlete/ath/sport-settings/1783043/apply"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"status":"ok"})))
-        .mount(&mock_server)
-        .await;
+    let cassette = contract_cassette("apply_sport_settings");
+    let client = offline_client("i24680", Arc::clone(&cassette));
 
-    let client =
-        ReqwestIntervalsClient::new(&mock_server.uri(), athlete, SecretString::new("key".into()))
-            .expect("new");
-    let res = client.apply_sport_settings(sport).await;
-    assert!(res.is_ok());
-    let v = res.unwrap();
-    assert_eq!(v.get("status").and_then(|s| s.as_str()), Some("ok"));
+    let res = client.apply_sport_settings("Run").await.expect("apply");
+    assert_eq!(res.get("status").and_then(|s| s.as_str()), Some("ok"));
+    assert_eq!(cassette.remaining(), 0);
 }
 
 #[tokio::test]
@@ -217,113 +227,66 @@ async fn get_events_translates_days_back_to_oldest_and_newest() {
 }
 
 #[tokio::test]
-async fn download_fit_file_uses_fit_file_endpoint() {
-    let mock_server = MockServer::start().await;
-    let bytes = vec![1u8, 2, 3];
-
-    Mock::given(method("GET"))
-        .and(path("/api/v1/activity/a1/fit-file"))
-        .respond_with(ResponseTemplate::new(200).set_body_bytes(bytes.clone()))
-        .mount(&mock_server)
-        .await;
-
-    let client =
-        ReqwestIntervalsClient::new(&mock_server.uri(), "ath", SecretString::new("key".into()))
-            .expect("new");
+async fn activity_file_downloads_use_file_endpoints() {
+    let cassette = contract_cassette("activity_files");
+    let client = offline_client("i24680", Arc::clone(&cassette));
 
-    let res = client
-        .download_fit_file("a1", None)
+    let fit = client
+        .download_fit_file("i71234567", None)
         .await
-        .expect("fit file download");
-    assert_eq!(res, Some(STANDARD.encode(bytes)));
-}
+        .expect("fit file download")
+        .expect("fit payload");
+    let fit = STANDARD.decode(fit).expect("base64 FIT");
+    assert_eq!(&fit[8..12], b".FIT");
 
-#[tokio::test]
-async fn download_gpx_file_uses_gpx_file_endpoint() {
-    let mock_server = MockServer::start().await;
-    let bytes = vec![4u8, 5, 6];
-
-    Mock::given(method("GET"))
-        .and(path("/api/v1/activity/a1/gpx-file"))
-        .respond_with(ResponseTemplate::new(200).set_body_bytes(bytes.clone()))
-        .mount(&mock_server)
-        .await;
-
-    let client =
-        ReqwestIntervalsClient::new(&mock_server.uri(), "ath", SecretString::new("key".into()))
-            .expect("new");
-
-    let res = client
-        .download_gpx_file("a1", None)
+    let gpx = client
+        .download_gpx_file("i71234567", None)
         .await
-        .expect("gpx file download");
-    assert_eq!(res, Some(STANDARD.encode(bytes)));
+        .expect("gpx file download")
+        .expect("gpx payload");
+    let gpx = String::from_utf8(STANDARD.decode(gpx).expect("base64 GPX")).expect("utf-8");
+    assert!(gpx.contains("<name>Easy aerobic run</name>"));
+    assert_eq!(cassette.remaining(), 0);
 }
 
 #[tokio::test]
-async fn create_gear_reminder_uses_singular_reminder_endpoint() {
-    let mock_server = MockServer::start().await;
-    let expected = serde_json::json!({"id":"g1"});
-
-    Mock::given(method("POST"))
-        .and(path("/api/v1/athlete/ath/gear/g1/reminder"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(&expected))
-        .mount(&mock_server)
-        .await;
-
-    let client =
-        ReqwestIntervalsClient::new(&mock_server.uri(), "ath", SecretString::new("key".into()))
-            .expect("new");
-
-    let res = client
-        .create_gear_reminder("g1", &serde_json::json!({"note":"check chain"}))
+async fn gear_reminders_use_singular_reminder_endpoint() {
+    let cassette = contract_cassette("gear_reminders");
+    let client = offline_client("i24680", Arc::clone(&cassette));
+
+    let created = client
+        .create_gear_reminder(
+            "g1",
+            &serde_json::json!({"name": "Replace", "distance": 800000.0}),
+        )
         .await
         .expect("gear reminder create response");
-    assert_eq!(res, expected);
+    assert_eq!(created["id"], 7);
+
+    let reset = client
+        .update_gear_reminder("g1", "7", true, 0, &serde_json::json!({}))
+        .await
+        .expect("gear reminder reset response");
+    assert_eq!(reset["last_reset"], "2026-01-15");
+    assert_eq!(cassette.remaining(), 0);
 }
 
 #[tokio::test]
 async fn update_wellness_bulk_uses_bulk_endpoint() {
-    let mock_server = MockServer::start().await;
-
-    Mock::given(method("PUT"))
-        .and(path("/api/v1/athlete/ath/wellness-bulk"))
-        .respond_with(ResponseTemplate::new(200))
-        .mount(&mock_server)
-        .await;
+    let cassette = contract_cassette("wellness_bulk");
+    let client = offline_client("i24680", Arc::clone(&cassette));
 
-    let client =
-        ReqwestIntervalsClient::new(&mock_server.uri(), "ath", SecretString::new("key".into()))
-            .expect("new");
-    let res = client
+    client
         .update_wellness_bulk(&[serde_json::json!({"id": "2026-03-01", "sleepSecs": 28800})])
-        .await;
-    assert!(res.is_ok());
+        .await
+        .expect("bulk update");
+    assert_eq!(cassette.remaining(), 0);
 }
 
 #[tokio::test]
 async fn weather_config_uses_spec_endpoints() {
-    let mock_server = MockServer::start().await;
-
-    Mock::given(method("GET"))
-        .and(path("/api/v1/athlete/ath/weather-config"))
-        .respond_with(
-            ResponseTemplate::new(200).set_body_json(serde_json::json!({"provider": "yr.no"})),
-        )
-        .mount(&mock_server)
-        .await;
-
-    Mock::given(method("PUT"))
-        .and(path("/api/v1/athlete/ath/weather-config"))
-        .respond_with(
-            ResponseTemplate::new(200).set_body_json(serde_json::json!({"provider": "open-meteo"})),
-        )
-        .mount(&mock_server)
-        .await;
-
-    let client =
-        ReqwestIntervalsClient::new(&mock_server.uri(), "ath", SecretString::new("key".into()))
-            .expect("new");
+    let cassette = contract_cassette("weather_config");
+    let client = offline_client("i24680", Arc::clone(&cassette));
 
     let current = client.get_weather_config().await.expect("weather config");
     assert_eq!(
@@ -339,50 +302,13 @@ async fn weather_config_uses_spec_endpoints() {
         updated.get("provider").and_then(|v| v.as_str()),
         Some("open-meteo")
     );
+    assert_eq!(cassette.remaining(), 0);
 }
 
 #[tokio::test]
 async fn routes_use_current_spec_paths() {
-    let mock_server = MockServer::start().await;
-
-    Mock::given(method("GET"))
-        .and(path("/api/v1/athlete/ath/routes"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
-            {"route": {"id": 11}, "count": 4}
-        ])))
-        .mount(&mock_server)
-        .await;
-
-    Mock::given(method("GET"))
-        .and(path("/api/v1/athlete/ath/routes/11"))
-        .and(query_param("includePath", "true"))
-        .respond_with(
-            ResponseTemplate::new(200)
-                .set_body_json(serde_json::json!({"id": 11, "name": "Lunch Loop"})),
-        )
-        .mount(&mock_server)
-        .await;
-
-    Mock::given(method("PUT"))
-        .and(path("/api/v1/athlete/ath/routes/11"))
-        .respond_with(
-            ResponseTemplate::new(200)
-                .set_body_json(serde_json::json!({"id": 11, "name": "Updated Loop"})),
-        )
-        .mount(&mock_server)
-        .await;
-
-    Mock::given(method("GET"))
-        .and(path("/api/v1/athlete/ath/routes/11/similarity/12"))
-        .respond_with(
-            ResponseTemplate::new(200).set_body_json(serde_json::json!({"similarity": 0.97})),
-        )
-        .mount(&mock_server)
-        .await;
-
-    let client =
-        ReqwestIntervalsClient::new(&mock_server.uri(), "ath", SecretString::new("key".into()))
-            .expect("new");
+    let cassette = contract_cassette("routes");
+    let client = offline_client("i24680", Arc::clone(&cassette));
 
     let routes = client.list_routes().await.expect("list routes");
     assert_eq!(routes.as_array().map(Vec::len), Some(1));
@@ -412,67 +338,485 @@ async fn routes_use_current_spec_paths() {
             .and_then(serde_json::Value::as_f64),
         Some(0.97)
     );
+    assert_eq!(cassette.remaining(), 0);
 }
 
+/// Every request replayed from a committed cassette must exist in the live
+/// spec with its query parameters, so hand-written fixtures cannot drift from
+/// the API they stand in for.
 #[tokio::test]
 #[ignore = "hits the live Intervals.icu OpenAPI endpoint"]
-async fn live_openapi_spec_contract_smoke() {
+async fn live_openapi_spec_covers_cassette_requests() {
     let spec = fetch_live_openapi_spec().await;
+    let paths = spec
+        .get("paths")
+        .and_then(serde_json::Value::as_object)
+        .expect("spec paths");
+
+    let mut requests = fixture_cassettes()
+        .into_iter()
+        .flat_map(|file| {
+            Cassette::replay(&file, MatchMode::Lenient)
+                .expect("cassette")
+                .interactions()
+        })
+        .map(|interaction| {
+            let query = interaction
+                .request
+                .query
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>();
+            (interaction.request.method, interaction.request.path, query)
+        })
+        .collect::<Vec<_>>();
+    requests.extend(WIREMOCK_ONLY_REQUESTS.iter().map(|(method, path, query)| {
+        (
+            (*method).to_string(),
+            (*path).to_string(),
+            query.iter().map(|name| (*name).to_string()).collect(),
+        )
+    }));
+    assert!(requests.len() > WIREMOCK_ONLY_REQUESTS.len());
+
+    for (method, path, query) in requests {
+        let covered = paths
+            .iter()
+            .filter(|(template, _)| template_matches(template, &path))
+            .filter_map(|(_, item)| item.get(method.to_lowercase()))
+            .any(|operation| query.iter().all(|name| has_query_param(operation, name)));
+        assert!(
+            covered,
+            "live spec has no {method} {path} accepting query {query:?}"
+        );
+    }
+}
 
-    spec_operation(&spec, "/api/v1/activity/{id}/fit-file", "get");
-    spec_operation(&spec, "/api/v1/activity/{id}/gpx-file", "get");
-    spec_operation(
-        &spec,
-        "/api/v1/athlete/{id}/gear/{gearId}/reminder/{reminderId}",
-        "put",
-    );
-    spec_operation(&spec, "/api/v1/athlete/{id}/weather-config", "get");
-    spec_operation(&spec, "/api/v1/athlete/{id}/weather-config", "put");
-    spec_operation(&spec, "/api/v1/athlete/{id}/routes", "get");
-    spec_operation(&spec, "/api/v1/athlete/{id}/routes/{route_id}", "get");
-    spec_operation(&spec, "/api/v1/athlete/{id}/routes/{route_id}", "put");
-    spec_operation(
-        &spec,
-        "/api/v1/athlete/{id}/routes/{route_id}/similarity/{other_id}",
-        "get",
+#[tokio::test]
+async fn typed_reads_decode_wellness_gear_and_streams() {
+    let cassette = contract_cassette("typed_reads");
+    let client = offline_client("i24680", Arc::clone(&cassette));
+
+    let wellness = client
+        .get_wellness_for_date_typed("2026-03-01")
+        .await
+        .expect("wellness");
+    assert_eq!(wellness.resting_hr, Some(47.0));
+    assert_eq!(wellness.form(), Some(-5.0));
+    assert_eq!(wellness.extra["newMetric"], serde_json::json!(1));
+
+    let gear = client.get_gear_list_typed().await.expect("gear");
+    assert_eq!(gear[0].gear_type.as_deref(), Some("Shoes"));
+    assert!(!gear[0].is_retired());
+
+    let streams = client
+        .get_activity_streams_typed("i9", None)
+        .await
+        .expect("streams");
+    assert_eq!(
+        streams.get("watts").map(|stream| stream.data.clone()),
+        Some(vec![Some(250.0), Some(260.0), None])
     );
-    spec_operation(&spec, "/api/v1/athlete/{id}/wellness-bulk", "put");
-    spec_operation(
-        &spec,
-        "/api/v1/athlete/{athleteId}/sport-settings/{id}/apply",
-        "put",
+    assert_eq!(cassette.remaining(), 0);
+}
+
+#[tokio::test]
+async fn typed_reads_report_shape_mismatch_as_decode_error() {
+    let client = offline_client("i24680", contract_cassette("typed_read_mismatch"));
+    let err = client.get_gear_list_typed().await.unwrap_err();
+    assert!(
+        matches!(err, intervals_icu_client::IntervalsError::JsonDecode(_)),
+        "{err}"
     );
+}
 
-    assert_query_param(
-        &spec,
-        "/api/v1/athlete/{id}/activities-around",
-        "get",
-        "activity_id",
+#[tokio::test]
+async fn coach_client_lists_roster_and_scopes_to_athlete() {
+    let cassette = contract_cassette("coach_roster");
+    let client = offline_client("i10000", Arc::clone(&cassette));
+    let roster = client.list_coached_athletes().await.expect("roster");
+    assert_eq!(
+        roster
+            .iter()
+            .map(|athlete| athlete.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["i1", "i2"]
     );
-    assert_query_param(
-        &spec,
-        "/api/v1/athlete/{id}/activities-around",
-        "get",
-        "route_id",
+
+    let scoped = client.for_athlete("i2").expect("re-scoped client");
+    assert_eq!(scoped.athlete_id(), Some("i2"));
+    assert_eq!(client.athlete_id(), Some("i10000"));
+    scoped.get_gear_list().await.expect("athlete gear");
+    assert_eq!(cassette.remaining(), 0);
+}
+
+#[tokio::test]
+async fn coach_roster_response_shape_and_errors() {
+    let cassette = contract_cassette("coach_roster_shapes");
+    let client = offline_client("i10000", Arc::clone(&cassette));
+    let roster = client.list_coached_athletes().await.expect("roster");
+    assert_eq!(roster.len(), 2);
+    assert_eq!(roster[0].id, "123456");
+    assert_eq!(roster[0].name.as_deref(), Some("Ana"));
+    assert_eq!(roster[0].extra["email"], "ana@example.com");
+    assert_eq!(roster[1].id, "i2");
+    assert_eq!(roster[1].name, None);
+
+    let solo = offline_client("i30000", Arc::clone(&cassette));
+    let err = solo.list_coached_athletes().await.unwrap_err();
+    assert!(err.is_auth_error(), "{err}");
+    assert_eq!(cassette.remaining(), 0);
+}
+
+#[tokio::test]
+async fn upload_activity_file_streams_multipart_with_metadata() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/athlete/i1/activities"))
+        .and(query_param("name", "Morning ride"))
+        .and(query_param("external_id", "garmin-42"))
+        .and(header_regex(
+            "content-type",
+            "^multipart/form-data; boundary=",
+        ))
+        .and(body_string_contains("filename=\"ride.gpx\""))
+        .and(body_string_contains("<gpx>track</gpx>"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "icu_athlete_id": "i1",
+            "id": "i99",
+            "activities": [{"id": "i99"}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let file = dir.path().join("ride.gpx");
+    std::fs::write(&file, "<gpx>track</gpx>").expect("write gpx");
+
+    let client =
+        ReqwestIntervalsClient::new(&mock_server.uri(), "i1", SecretString::new("key".into()))
+            .expect("new");
+    let upload = intervals_icu_client::ActivityUpload::new(&file)
+        .with_name("Morning ride")
+        .with_external_id("garmin-42");
+    let result = client.upload_activity_file(&upload).await.expect("upload");
+    assert_eq!(result.activity_ids(), vec!["i99"]);
+
+    let unsupported = intervals_icu_client::ActivityUpload::new(dir.path().join("notes.txt"));
+    assert!(client.upload_activity_file(&unsupported).await.is_err());
+}
+
+/// Every field a typed model declares must exist on the matching schema of
+/// the live spec; `extra` absorbs the reverse direction.
+fn assert_model_fields_in_schema<T: Default + serde::Serialize>(
+    spec: &serde_json::Value,
+    schema_name: &str,
+    ignored: &[&str],
+) {
+    let properties = spec
+        .pointer(&format!("/components/schemas/{schema_name}/properties"))
+        .and_then(serde_json::Value::as_object)
+        .unwrap_or_else(|| panic!("missing schema {schema_name} in live spec"));
+    let model = serde_json::to_value(T::default()).expect("serialize model");
+    for field in model.as_object().expect("model object").keys() {
+        if ignored.contains(&field.as_str()) {
+            continue;
+        }
+        assert!(
+            properties.contains_key(field),
+            "{schema_name} has no property {field}"
+        );
+    }
+}
+
+#[tokio::test]
+#[ignore = "hits the live Intervals.icu OpenAPI endpoint"]
+async fn live_openapi_spec_matches_typed_models() {
+    use intervals_icu_client::domains::{activity, gear, wellness};
+
+    let spec = fetch_live_openapi_spec().await;
+    assert_model_fields_in_schema::<activity::ActivityDetail>(&spec, "Activity", &[]);
+    assert_model_fields_in_schema::<wellness::WellnessEntry>(&spec, "Wellness", &[]);
+    assert_model_fields_in_schema::<gear::Gear>(&spec, "Gear", &[]);
+}
+
+const ATHLETE_READS_CASSETTE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/cassettes/athlete_reads.json"
+);
+
+/// Live recordings hold real athlete data; the directory is git-ignored.
+const LIVE_ATHLETE_READS_CASSETTE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/cassettes/live/athlete_reads.json"
+);
+
+struct AthleteReads {
+    profile: intervals_icu_client::AthleteProfile,
+    activities: Vec<intervals_icu_client::ActivitySummary>,
+    details: serde_json::Value,
+    intervals: serde_json::Value,
+    wellness: serde_json::Value,
+    sport_settings: intervals_icu_client::domains::workout::SportSettings,
+}
+
+/// The calls recorded in `athlete_reads.json`, in recorded order.
+async fn exercise_athlete_reads(client: &ReqwestIntervalsClient) -> AthleteReads {
+    let profile = client.get_athlete_profile().await.expect("profile");
+    let activities = client
+        .get_recent_activities(Some(5), Some(30))
+        .await
+        .expect("activities");
+    let newest = &activities.first().expect("at least one activity").id;
+    let details = client.get_activity_details(newest).await.expect("details");
+    let intervals = client
+        .get_activity_intervals(newest)
+        .await
+        .expect("intervals");
+    let wellness = client.get_wellness(Some(7)).await.expect("wellness");
+    let sport_settings = client.get_sport_settings().await.expect("sport settings");
+    AthleteReads {
+        profile,
+        activities,
+        details,
+        intervals,
+        wellness,
+        sport_settings,
+    }
+}
+
+fn offline_client(athlete: &str, cassette: impl Into<Arc<Cassette>>) -> ReqwestIntervalsClient {
+    // Nothing listens on the discard port; replay must not reach it.
+    ReqwestIntervalsClient::builder(
+        "http://127.0.0.1:9",
+        athlete,
+        SecretString::new("unused".into()),
+    )
+    .cassette(cassette)
+    .build()
+    .expect("build")
+}
+
+#[tokio::test]
+async fn cassette_replays_athlete_reads_offline() {
+    let cassette =
+        Arc::new(Cassette::replay(ATHLETE_READS_CASSETTE, MatchMode::Lenient).expect("cassette"));
+    let client = offline_client("i24680", Arc::clone(&cassette));
+
+    let reads = exercise_athlete_reads(&client).await;
+
+    assert_eq!(reads.profile.id, "i24680");
+    assert_eq!(reads.profile.name.as_deref(), Some("Cassette Athlete"));
+    assert_eq!(reads.activities.len(), 3);
+    assert_eq!(reads.activities[0].training_load, Some(48));
+    assert_eq!(reads.details["icu_athlete_id"], "i24680");
+    assert_eq!(reads.details["device_name"], "Garmin Forerunner 965");
+    assert_eq!(
+        reads.intervals["icu_intervals"].as_array().map(Vec::len),
+        Some(3)
     );
-    assert_query_param(
-        &spec,
-        "/api/v1/athlete/{id}/events{format}",
-        "get",
-        "oldest",
+    assert_eq!(reads.wellness.as_array().map(Vec::len), Some(8));
+    assert_eq!(reads.sport_settings.sports.len(), 2);
+    assert_eq!(reads.sport_settings.sports[0].ftp, Some(265.0));
+    assert_eq!(cassette.remaining(), 0);
+}
+
+#[tokio::test]
+async fn strict_cassette_replay_rejects_out_of_order_requests() {
+    let cassette = Cassette::replay(ATHLETE_READS_CASSETTE, MatchMode::Strict).expect("cassette");
+    let client = offline_client("i24680", cassette);
+
+    let err = client
+        .get_sport_settings()
+        .await
+        .expect_err("profile is recorded first");
+    let message = err.to_string();
+    assert!(
+        message.contains("expected GET /api/v1/athlete/{athlete_id}/profile"),
+        "{message}"
     );
-    assert_query_param(
-        &spec,
-        "/api/v1/athlete/{id}/events{format}",
-        "get",
-        "newest",
+    assert!(message.contains("got GET /api/v1/athlete/{athlete_id}/sport-settings"));
+}
+
+#[tokio::test]
+async fn cassette_records_scrubbed_interactions_that_replay_strictly() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/i98765/profile"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "athlete": {"id": "i98765", "name": "Recorder", "api_key": "sekrit-key"}
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/athlete/i98765/wellness/2026-01-02"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"id": "2026-01-02", "restingHR": 44})),
+        )
+        .mount(&mock_server)
+        .await;
+    let dir = tempfile::tempdir().expect("tempdir");
+    let file = dir.path().join("nested/recorded.json");
+
+    let recorder = ReqwestIntervalsClient::builder(
+        &mock_server.uri(),
+        "i98765",
+        SecretString::new("sekrit-key".into()),
+    )
+    .cassette(Cassette::record(&file))
+    .build()
+    .expect("build");
+    let profile = recorder.get_athlete_profile().await.expect("profile");
+    assert_eq!(profile.id, "i98765");
+    recorder
+        .update_wellness("2026-01-02", &serde_json::json!({"restingHR": 44}))
+        .await
+        .expect("update wellness");
+
+    let raw = std::fs::read_to_string(&file).expect("cassette written");
+    assert!(!raw.contains("i98765"), "{raw}");
+    assert!(!raw.contains("sekrit-key"), "{raw}");
+    assert!(raw.contains("/api/v1/athlete/{athlete_id}/profile"));
+    assert!(raw.contains("[REDACTED]"));
+
+    let replayer = offline_client(
+        "i13579",
+        Cassette::replay(&file, MatchMode::Strict).expect("replay"),
     );
-    assert_query_param(&spec, "/api/v1/athlete/{id}/wellness{ext}", "get", "oldest");
-    assert_query_param(&spec, "/api/v1/athlete/{id}/wellness{ext}", "get", "newest");
-    assert_query_param(
-        &spec,
-        "/api/v1/athlete/{id}/routes/{route_id}",
-        "get",
-        "includePath",
+    let profile = replayer.get_athlete_profile().await.expect("profile");
+    assert_eq!(profile.id, "i13579");
+    let updated = replayer
+        .update_wellness("2026-01-02", &serde_json::json!({"restingHR": 44}))
+        .await
+        .expect("update wellness");
+    assert_eq!(updated["restingHR"], 44);
+}
+
+#[tokio::test]
+#[ignore = "records from the live Intervals.icu API; needs INTERVALS_ICU_API_KEY and INTERVALS_ICU_ATHLETE_ID"]
+async fn record_live_athlete_reads_cassette() {
+    let config = intervals_icu_client::config::Config::from_env().expect("live credentials");
+    let client = ReqwestIntervalsClient::builder(
+        &config.base_url,
+        config.athlete_id.clone(),
+        config.api_key.clone(),
+    )
+    .settings(config.http.clone())
+    .cassette(Cassette::record(LIVE_ATHLETE_READS_CASSETTE))
+    .build()
+    .expect("build");
+    let live = exercise_athlete_reads(&client).await;
+
+    // Real data changes between recordings: check that the recording replays
+    // the same calls strictly, and only the shape of what came back.
+    let replayer = offline_client(
+        &config.athlete_id,
+        Cassette::replay(LIVE_ATHLETE_READS_CASSETTE, MatchMode::Strict).expect("replay"),
     );
+    let replayed = exercise_athlete_reads(&replayer).await;
+    assert_eq!(replayed.profile.id, config.athlete_id);
+    assert_eq!(replayed.profile, live.profile);
+    assert_eq!(replayed.activities.len(), live.activities.len());
+    assert!(replayed.details.is_object());
+    assert!(replayed.intervals.is_object());
+    assert!(replayed.wellness.is_array());
+}
+
+fn ymd(value: &str) -> chrono::NaiveDate {
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("date")
+}
+
+#[tokio::test]
+async fn activities_stream_walks_date_chunks_lazily() {
+    use futures_util::{StreamExt, TryStreamExt};
+    use intervals_icu_client::DateRange;
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/activities"))
+        .and(query_param("oldest", "2023-01-01"))
+        .and(query_param("newest", "2023-03-31"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": "a2", "name": "Long Run", "start_date_local": "2023-03-12T08:00:00"},
+            {"id": "a1", "name": "Easy Ride", "start_date_local": "2023-01-04T17:30:00"}
+        ])))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/ath/activities"))
+        .and(query_param("oldest", "2023-04-01"))
+        .and(query_param("newest", "2023-05-10"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": "a3", "name": "Long Run Hills", "start_date_local": "2023-05-01T07:45:00"}
+        ])))
+        // Not requested while the first stream is only polled once.
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let client =
+        ReqwestIntervalsClient::new(&mock_server.uri(), "ath", SecretString::new("key".into()))
+            .expect("client");
+    let range = DateRange::new(ymd("2023-01-01"), ymd("2023-05-10"));
+
+    let first = client
+        .activities_stream(range)
+        .next()
+        .await
+        .expect("item")
+        .expect("activity");
+    assert_eq!(first.id, "a1");
+
+    let ids = client
+        .activities_stream(range)
+        .map_ok(|activity| activity.id)
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("activities");
+    assert_eq!(ids, ["a1", "a2", "a3"]);
+
+    let matches = client
+        .search_activities_stream("long run", range.newest_first())
+        .map_ok(|activity| activity.id)
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("search");
+    assert_eq!(matches, ["a3", "a2"]);
+}
+
+#[tokio::test]
+async fn events_stream_requests_bounded_windows() {
+    use futures_util::TryStreamExt;
+    use intervals_icu_client::DateRange;
+
+    let mock_server = MockServer::start().await;
+    for (oldest, newest, name) in [
+        ("2022-01-01", "2022-01-30", "Base block"),
+        ("2022-01-31", "2022-02-15", "A race"),
+    ] {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/athlete/ath/events"))
+            .and(query_param("oldest", oldest))
+            .and(query_param("newest", newest))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"id": name, "name": name, "category": "NOTE", "start_date_local": format!("{newest}T00:00:00")}
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    let client =
+        ReqwestIntervalsClient::new(&mock_server.uri(), "ath", SecretString::new("key".into()))
+            .expect("client");
+
+    let names = client
+        .events_stream(DateRange::new(ymd("2022-01-01"), ymd("2022-02-15")).chunk_days(30))
+        .map_ok(|event| event.name)
+        .try_collect::<Vec<_>>()
+        .await
+        .expect("events");
+    assert_eq!(names, ["Base block", "A race"]);
 }
@@ -1,7 +1,7 @@
 use base64::{Engine as _, engine::general_purpose::STANDARD};
 use intervals_icu_client::{AthleteProfile, IntervalsClient};
 use secrecy::SecretString;
-use wiremock::matchers::{method, path, query_param};
+use wiremock::matchers::{body_json, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -160,6 +160,34 @@ async fn get_activity_messages_reads_activity_comments() {
     );
 }
 
+#[tokio::test]
+async fn add_activity_message_posts_comment() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/activity/act-comments-1/messages"))
+        .and(body_json(
+            serde_json::json!({"content": "Legs heavy on the climbs."}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": 103})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = intervals_icu_client::http_client::ReqwestIntervalsClient::new(
+        &server.uri(),
+        "ath",
+        SecretString::new("tok".into()),
+    )
+    .expect("new");
+
+    let created = client
+        .add_activity_message("act-comments-1", "Legs heavy on the climbs.")
+        .await
+        .expect("add message");
+    assert_eq!(created["id"], 103);
+}
+
 #[tokio::test]
 async fn best_efforts_returns_payload() {
     let server = MockServer::start().await;
@@ -393,12 +421,10 @@ async fn create_event_validates_date_and_posts() {
     )
     .expect("new");
     let ev = intervals_icu_client::Event {
-        id: None,
         start_date_local: "2025-12-15".into(),
         name: "Test".into(),
         category: intervals_icu_client::EventCategory::Note,
-        description: None,
-        r#type: None,
+        ..Default::default()
     };
     let created = client.create_event(ev).await.expect("create");
     assert_eq!(created.start_date_local, "2025-12-15");
@@ -411,6 +437,7 @@ async fn create_event_validates_date_and_posts() {
         category: intervals_icu_client::EventCategory::Note,
         description: None,
         r#type: None,
+        end_date_local: None,
     };
     let err = client.create_event(bad).await;
     assert!(err.is_err());
@@ -452,6 +479,7 @@ async fn create_event_accepts_iso_and_preserves_time() {
         category: intervals_icu_client::EventCategory::Note,
         description: None,
         r#type: None,
+        end_date_local: None,
     };
     let created2 = client.create_event(ev2).await.expect("create iso");
     assert_eq!(created2.start_date_local, "2026-01-19T06:30:00");
@@ -493,6 +521,7 @@ async fn create_event_accepts_date_and_sets_midnight() {
         category: intervals_icu_client::EventCategory::Note,
         description: None,
         r#type: None,
+        end_date_local: None,
     };
     let created = client.create_event(ev).await.expect("create date");
     assert_eq!(created.start_date_local, "2026-01-19T00:00:00");
@@ -1123,6 +1152,7 @@ async fn bulk_create_events_propagates_error_body() {
         category: intervals_icu_client::EventCategory::Workout,
         description: None,
         r#type: None,
+        end_date_local: None,
     };
 
     let res = client.bulk_create_events(vec![ev]).await;
@@ -1350,6 +1380,7 @@ async fn create_event_handles_non_success() {
         category: intervals_icu_client::EventCategory::Note,
         description: None,
         r#type: None,
+        end_date_local: None,
     };
     let res = client.create_event(event).await;
     assert!(res.is_err());
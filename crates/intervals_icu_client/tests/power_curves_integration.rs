@@ -25,3 +25,38 @@ async fn get_power_curves_normalizes_type_and_sends_curves() {
     let res: serde_json::Value = client.get_power_curves(Some(7), "run").await.unwrap();
     assert_eq!(res, serde_json::json!({"ok": true}));
 }
+
+#[tokio::test]
+async fn get_curves_between_sends_the_requested_window() {
+    use intervals_icu_client::domains::curve::CurveKind;
+    use wiremock::matchers::query_param;
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/athlete/test_ath/activity-pace-curves"))
+        .and(query_param("oldest", "2025-03-01"))
+        .and(query_param("newest", "2025-03-31"))
+        .and(query_param("type", "Run"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"list": []})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ReqwestIntervalsClient::new(
+        &mock_server.uri(),
+        "test_ath",
+        SecretString::new("key".into()),
+    )
+    .expect("new");
+    let date = |value: &str| chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap();
+    let res = client
+        .get_curves_between(
+            CurveKind::Pace,
+            "Run",
+            date("2025-03-01"),
+            date("2025-03-31"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(res, serde_json::json!({"list": []}));
+}
@@ -13,9 +13,9 @@
 use aes_gcm::{Aes256Gcm, KeyInit, Nonce, aead::Aead};
 use base64::{Engine as _, engine::general_purpose::STANDARD};
 use hkdf::Hkdf;
-use intervals_icu_client::IntervalsClient;
+use intervals_icu_client::{Credentials, IntervalsClient, OAuthToken};
 use jwt_simple::prelude::*;
-use secrecy::SecretString;
+use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use sha2::Sha256;
 use std::collections::HashSet;
@@ -25,26 +25,39 @@ use thiserror::Error;
 
 use crate::metrics;
 
-#[cfg(test)]
-use secrecy::ExposeSecret;
-
 /// Generate random bytes using OS CSPRNG
 fn fill_random(buf: &mut [u8]) -> Result<(), AuthError> {
     getrandom::fill(buf).map_err(|_| AuthError::EncryptionError)
 }
 
+/// Kind of upstream secret sealed in a JWT.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialKind {
+    /// Personal Intervals.icu API key. Tokens issued before OAuth support
+    /// carry no kind and default to this.
+    #[default]
+    ApiKey,
+    /// Access token of an Intervals.icu OAuth application.
+    OAuth,
+}
+
 /// Custom claims for JWT tokens
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct IntervalsClaims {
     pub athlete_id: String,
-    pub encrypted_api_key: String,
+    /// The encrypted API key or OAuth access token, per `credential_kind`.
+    #[serde(alias = "encrypted_api_key")]
+    pub encrypted_secret: String,
+    #[serde(default)]
+    pub credential_kind: CredentialKind,
 }
 
 /// Decrypted credentials after JWT verification
 #[derive(Clone, Debug)]
 pub struct DecryptedCredentials {
     pub athlete_id: String,
-    pub api_key: SecretString,
+    pub credentials: Credentials,
 }
 
 /// Base URL injected into HTTP requests so rmcp handlers can build per-request clients.
@@ -65,6 +78,9 @@ pub struct AppState {
     pub jwt_manager: Arc<JwtManager>,
     pub jwt_ttl_seconds: u64,
     pub base_url: String,
+    /// Whether `/auth` and the web UI accept raw API keys. Deployments that
+    /// only accept OAuth access tokens set `MCP_AUTH_ALLOW_API_KEYS=false`.
+    pub allow_api_keys: bool,
 }
 
 /// Master key configuration with HKDF-derived keys
@@ -113,6 +129,8 @@ pub enum AuthError {
     ServerConfig,
     #[error("Invalid credentials")]
     InvalidCredentials,
+    #[error("API keys are not accepted; authenticate with an OAuth access token")]
+    ApiKeysDisabled,
     #[error("Invalid key format")]
     InvalidKeyFormat,
     #[error("Invalid key length")]
@@ -148,11 +166,25 @@ impl JwtManager {
         api_key: &str,
         ttl_secs: u64,
     ) -> Result<String, AuthError> {
-        let encrypted = self.encrypt_api_key(api_key)?;
+        self.issue_token_for(athlete_id, &Credentials::api_key(api_key), ttl_secs)
+    }
+
+    /// Issue a token sealing either an API key or an OAuth access token.
+    pub fn issue_token_for(
+        &self,
+        athlete_id: &str,
+        credentials: &Credentials,
+        ttl_secs: u64,
+    ) -> Result<String, AuthError> {
+        let encrypted = self.seal_credential(credentials.secret().expose_secret())?;
 
         let custom = IntervalsClaims {
             athlete_id: athlete_id.to_string(),
-            encrypted_api_key: encrypted,
+            encrypted_secret: encrypted,
+            credential_kind: match credentials {
+                Credentials::ApiKey(_) => CredentialKind::ApiKey,
+                Credentials::OAuth(_) => CredentialKind::OAuth,
+            },
         };
 
         let claims = Claims::with_custom_claims(custom, Duration::from_secs(ttl_secs))
@@ -184,21 +216,28 @@ impl JwtManager {
                 }
             })?;
 
-        let api_key = self.decrypt_api_key(&claims.custom.encrypted_api_key)?;
+        let secret = SecretString::new(
+            self.open_credential(&claims.custom.encrypted_secret)?
+                .into(),
+        );
 
         Ok(DecryptedCredentials {
             athlete_id: claims.custom.athlete_id.clone(),
-            api_key: SecretString::new(api_key.into()),
+            credentials: match claims.custom.credential_kind {
+                CredentialKind::ApiKey => Credentials::ApiKey(secret),
+                CredentialKind::OAuth => Credentials::OAuth(OAuthToken::new(secret)),
+            },
         })
     }
 
-    fn encrypt_api_key(&self, api_key: &str) -> Result<String, AuthError> {
+    /// Encrypt an upstream secret (API key or OAuth access token) for a JWT claim.
+    fn seal_credential(&self, secret: &str) -> Result<String, AuthError> {
         let cipher = Aes256Gcm::new((&self.encryption_key).into());
         let mut nonce = [0u8; 12];
         fill_random(&mut nonce)?;
 
         let ciphertext = cipher
-            .encrypt(Nonce::from_slice(&nonce), api_key.as_bytes())
+            .encrypt(Nonce::from_slice(&nonce), secret.as_bytes())
             .map_err(|_| AuthError::EncryptionError)?;
 
         let mut result = nonce.to_vec();
@@ -206,7 +245,8 @@ impl JwtManager {
         Ok(STANDARD.encode(&result))
     }
 
-    fn decrypt_api_key(&self, encrypted: &str) -> Result<String, AuthError> {
+    /// Decrypt a secret sealed by [`Self::seal_credential`].
+    fn open_credential(&self, encrypted: &str) -> Result<String, AuthError> {
         let data = STANDARD
             .decode(encrypted)
             .map_err(|_| AuthError::EncryptionError)?;
@@ -239,12 +279,47 @@ use axum::{
     response::{IntoResponse, Response},
 };
 
+/// Body of `POST /auth`: exactly one of `api_key` or `access_token`.
 #[derive(Deserialize)]
 pub struct AuthRequest {
+    #[serde(default)]
     pub api_key: String,
+    /// Access token obtained through an Intervals.icu OAuth application.
+    #[serde(default)]
+    pub access_token: Option<String>,
+    /// Seconds until `access_token` expires. Hosted sessions cannot refresh
+    /// upstream tokens, so the issued JWT never outlives it.
+    #[serde(default)]
+    pub expires_in: Option<u64>,
     pub athlete_id: String,
 }
 
+impl AuthRequest {
+    fn credentials(&self, allow_api_keys: bool) -> Result<Credentials, AuthError> {
+        let access_token = self
+            .access_token
+            .as_deref()
+            .map(str::trim)
+            .filter(|token| !token.is_empty());
+        let api_key = Some(self.api_key.trim()).filter(|key| !key.is_empty());
+        match (api_key, access_token) {
+            (None, Some(token)) => Ok(Credentials::bearer(token)),
+            (Some(_), None) if !allow_api_keys => Err(AuthError::ApiKeysDisabled),
+            (Some(key), None) => Ok(Credentials::api_key(key)),
+            _ => Err(AuthError::MissingCredentials),
+        }
+    }
+
+    /// JWT lifetime: `default_ttl`, capped at the access token's `expires_in`.
+    fn token_ttl(&self, credentials: &Credentials, default_ttl: u64) -> Result<u64, AuthError> {
+        match (credentials, self.expires_in) {
+            (Credentials::OAuth(_), Some(0)) => Err(AuthError::InvalidCredentials),
+            (Credentials::OAuth(_), Some(expires_in)) => Ok(default_ttl.min(expires_in)),
+            _ => Ok(default_ttl),
+        }
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct AuthResponse {
     pub token: String,
@@ -259,6 +334,7 @@ impl IntoResponse for AuthError {
             AuthError::InvalidToken => StatusCode::UNAUTHORIZED,
             AuthError::TokenExpired => StatusCode::UNAUTHORIZED,
             AuthError::InvalidCredentials => StatusCode::UNAUTHORIZED,
+            AuthError::ApiKeysDisabled => StatusCode::FORBIDDEN,
             AuthError::EncryptionError => StatusCode::INTERNAL_SERVER_ERROR,
             AuthError::ServerConfig => StatusCode::INTERNAL_SERVER_ERROR,
             AuthError::InvalidKeyFormat => StatusCode::INTERNAL_SERVER_ERROR,
@@ -278,22 +354,33 @@ pub async fn auth_endpoint(
     Json(req): Json<AuthRequest>,
 ) -> Result<Json<AuthResponse>, AuthError> {
     let client_ip = client_addr.to_string();
+    let (credentials, ttl) = req
+        .credentials(state.allow_api_keys)
+        .and_then(|credentials| {
+            let ttl = req.token_ttl(&credentials, state.jwt_ttl_seconds)?;
+            Ok((credentials, ttl))
+        })
+        .inspect_err(|e| {
+            tracing::warn!(
+                client_ip = %client_ip,
+                athlete_id = %req.athlete_id,
+                error = %e,
+                "Rejected credentials at /auth"
+            );
+        })?;
 
     // Validate credentials against intervals.icu API
-    let client = intervals_icu_client::http_client::ReqwestIntervalsClient::new(
-        &state.base_url,
-        req.athlete_id.clone(),
-        SecretString::new(req.api_key.clone().into()),
-    )
-    .map_err(|e| {
-        tracing::warn!(
-            client_ip = %client_ip,
-            athlete_id = %req.athlete_id,
-            error = %e,
-            "Failed to create client at /auth"
-        );
-        AuthError::ServerConfig
-    })?;
+    let client =
+        crate::upstream_client(&state.base_url, req.athlete_id.clone(), credentials.clone())
+            .map_err(|e| {
+                tracing::warn!(
+                    client_ip = %client_ip,
+                    athlete_id = %req.athlete_id,
+                    error = %e,
+                    "Failed to create client at /auth"
+                );
+                AuthError::ServerConfig
+            })?;
 
     // Simple validation call - get_athlete_profile
     client.get_athlete_profile().await.map_err(|e| {
@@ -307,10 +394,9 @@ pub async fn auth_endpoint(
     })?;
 
     // Validation successful - issue JWT
-    let token =
-        state
-            .jwt_manager
-            .issue_token(&req.athlete_id, &req.api_key, state.jwt_ttl_seconds)?;
+    let token = state
+        .jwt_manager
+        .issue_token_for(&req.athlete_id, &credentials, ttl)?;
 
     // Record token issuance metric
     metrics::record_token_issued();
@@ -318,12 +404,13 @@ pub async fn auth_endpoint(
     tracing::info!(
         client_ip = %client_ip,
         athlete_id = %req.athlete_id,
+        credential_kind = credentials.kind(),
         "Issued JWT token"
     );
 
     Ok(Json(AuthResponse {
         token,
-        expires_in: state.jwt_ttl_seconds,
+        expires_in: ttl,
         athlete_id: req.athlete_id,
     }))
 }
@@ -420,8 +507,8 @@ mod tests {
     fn test_encrypt_decrypt_round_trip() {
         let manager = create_test_manager();
         let api_key = "test_api_key_12345";
-        let encrypted = manager.encrypt_api_key(api_key).unwrap();
-        let decrypted = manager.decrypt_api_key(&encrypted).unwrap();
+        let encrypted = manager.seal_credential(api_key).unwrap();
+        let decrypted = manager.open_credential(&encrypted).unwrap();
         assert_eq!(api_key, decrypted);
     }
 
@@ -429,8 +516,8 @@ mod tests {
     fn test_encryption_produces_different_output() {
         let manager = create_test_manager();
         let api_key = "test_api_key";
-        let encrypted1 = manager.encrypt_api_key(api_key).unwrap();
-        let encrypted2 = manager.encrypt_api_key(api_key).unwrap();
+        let encrypted1 = manager.seal_credential(api_key).unwrap();
+        let encrypted2 = manager.seal_credential(api_key).unwrap();
         assert_ne!(encrypted1, encrypted2);
     }
 
@@ -438,13 +525,13 @@ mod tests {
     fn test_decrypt_with_wrong_key_fails() {
         let manager = create_test_manager();
         let api_key = "test_api_key";
-        let encrypted = manager.encrypt_api_key(api_key).unwrap();
+        let encrypted = manager.seal_credential(api_key).unwrap();
 
         let wrong_manager = JwtManager::new(
             b"wrong_key_________________________________________",
             [1u8; 32],
         );
-        let result = wrong_manager.decrypt_api_key(&encrypted);
+        let result = wrong_manager.open_credential(&encrypted);
         assert!(matches!(result, Err(AuthError::EncryptionError)));
     }
 
@@ -466,7 +553,10 @@ mod tests {
             .unwrap();
         let credentials = manager.verify_token(&token).unwrap();
         assert_eq!(credentials.athlete_id, "i123456");
-        assert_eq!(credentials.api_key.expose_secret(), "test_api_key");
+        assert_eq!(
+            credentials.credentials.secret().expose_secret(),
+            "test_api_key"
+        );
     }
 
     #[test]
@@ -497,7 +587,7 @@ mod tests {
         let token = manager.issue_token(athlete_id, api_key, 3600).unwrap();
         let credentials = manager.verify_token(&token).unwrap();
         assert_eq!(credentials.athlete_id, athlete_id);
-        assert_eq!(credentials.api_key.expose_secret(), api_key);
+        assert_eq!(credentials.credentials.secret().expose_secret(), api_key);
     }
 
     #[test]
@@ -506,7 +596,8 @@ mod tests {
         let claims = Claims::with_custom_claims(
             IntervalsClaims {
                 athlete_id: "i123456".to_string(),
-                encrypted_api_key: manager.encrypt_api_key("test_api_key").unwrap(),
+                encrypted_secret: manager.seal_credential("test_api_key").unwrap(),
+                credential_kind: CredentialKind::ApiKey,
             },
             Duration::from_secs(3600),
         )
@@ -526,7 +617,8 @@ mod tests {
         let claims = Claims::with_custom_claims(
             IntervalsClaims {
                 athlete_id: "i123456".to_string(),
-                encrypted_api_key: manager.encrypt_api_key("test_api_key").unwrap(),
+                encrypted_secret: manager.seal_credential("test_api_key").unwrap(),
+                credential_kind: CredentialKind::ApiKey,
             },
             Duration::from_secs(3600),
         )
@@ -549,8 +641,8 @@ mod tests {
         let creds2 = manager.verify_token(&token2).unwrap();
         assert_ne!(creds1.athlete_id, creds2.athlete_id);
         assert_ne!(
-            creds1.api_key.expose_secret(),
-            creds2.api_key.expose_secret()
+            creds1.credentials.secret().expose_secret(),
+            creds2.credentials.secret().expose_secret()
         );
     }
 
@@ -560,7 +652,7 @@ mod tests {
         let api_key = "special!@#$%^&*()_+-=[]{}|;':\",./<>?";
         let token = manager.issue_token("i123456", api_key, 3600).unwrap();
         let credentials = manager.verify_token(&token).unwrap();
-        assert_eq!(credentials.api_key.expose_secret(), api_key);
+        assert_eq!(credentials.credentials.secret().expose_secret(), api_key);
     }
 
     #[test]
@@ -614,6 +706,7 @@ mod tests {
             jwt_manager: jwt_manager.clone(),
             jwt_ttl_seconds: 3600,
             base_url: "https://intervals.icu".to_string(),
+            allow_api_keys: true,
         };
 
         let cloned = state.clone();
@@ -626,12 +719,12 @@ mod tests {
     fn test_decrypted_credentials_clone() {
         let creds = DecryptedCredentials {
             athlete_id: "i123456".to_string(),
-            api_key: SecretString::new("test_key".to_string().into()),
+            credentials: SecretString::new("test_key".to_string().into()).into(),
         };
 
         let cloned = creds.clone();
         assert_eq!(cloned.athlete_id, "i123456");
-        assert_eq!(cloned.api_key.expose_secret(), "test_key");
+        assert_eq!(cloned.credentials.secret().expose_secret(), "test_key");
     }
 
     #[tokio::test]
@@ -642,11 +735,14 @@ mod tests {
             jwt_manager,
             jwt_ttl_seconds: 3600,
             base_url: "https://intervals.icu".to_string(),
+            allow_api_keys: true,
         });
 
         let req = AuthRequest {
             athlete_id: "i123456".to_string(),
             api_key: "invalid_key".to_string(),
+            access_token: None,
+            expires_in: None,
         };
 
         // Use wiremock or mock to test this properly
@@ -661,6 +757,107 @@ mod tests {
         assert!(matches!(result, Err(AuthError::InvalidCredentials)));
     }
 
+    #[test]
+    fn test_oauth_token_round_trip_keeps_kind() {
+        let manager = create_test_manager();
+        let token = manager
+            .issue_token_for("i123456", &Credentials::bearer("oauth-access"), 3600)
+            .unwrap();
+
+        let credentials = manager.verify_token(&token).unwrap();
+        assert!(matches!(credentials.credentials, Credentials::OAuth(_)));
+        assert_eq!(
+            credentials.credentials.secret().expose_secret(),
+            "oauth-access"
+        );
+    }
+
+    #[test]
+    fn test_tokens_issued_before_oauth_support_verify_as_api_key() {
+        #[derive(Serialize, Deserialize)]
+        struct LegacyClaims {
+            athlete_id: String,
+            encrypted_api_key: String,
+        }
+
+        let manager = create_test_manager();
+        let claims = Claims::with_custom_claims(
+            LegacyClaims {
+                athlete_id: "i123456".to_string(),
+                encrypted_api_key: manager.seal_credential("legacy_key").unwrap(),
+            },
+            Duration::from_secs(3600),
+        )
+        .with_issuer(&manager.issuer)
+        .with_audience(manager.audience.clone());
+        let token = manager.signing_key.authenticate(claims).unwrap();
+
+        let credentials = manager.verify_token(&token).unwrap();
+        assert!(matches!(credentials.credentials, Credentials::ApiKey(_)));
+        assert_eq!(
+            credentials.credentials.secret().expose_secret(),
+            "legacy_key"
+        );
+    }
+
+    #[test]
+    fn test_oauth_jwt_ttl_is_capped_at_token_expiry() {
+        let request = |access_token: Option<&str>, expires_in| AuthRequest {
+            api_key: if access_token.is_some() { "" } else { "key" }.to_string(),
+            access_token: access_token.map(str::to_string),
+            expires_in,
+            athlete_id: "i123456".to_string(),
+        };
+        let ttl = |request: AuthRequest| {
+            let credentials = request.credentials(true).unwrap();
+            request.token_ttl(&credentials, 3600)
+        };
+
+        assert_eq!(ttl(request(Some("tok"), Some(600))).unwrap(), 600);
+        assert_eq!(ttl(request(Some("tok"), Some(86_400))).unwrap(), 3600);
+        assert_eq!(ttl(request(Some("tok"), None)).unwrap(), 3600);
+        assert_eq!(ttl(request(None, Some(600))).unwrap(), 3600);
+        assert!(matches!(
+            ttl(request(Some("tok"), Some(0))),
+            Err(AuthError::InvalidCredentials)
+        ));
+    }
+
+    #[test]
+    fn test_auth_request_requires_exactly_one_credential() {
+        let request = |api_key: &str, access_token: Option<&str>| AuthRequest {
+            api_key: api_key.to_string(),
+            access_token: access_token.map(str::to_string),
+            expires_in: None,
+            athlete_id: "i123456".to_string(),
+        };
+
+        assert!(matches!(
+            request("key", None).credentials(true),
+            Ok(Credentials::ApiKey(_))
+        ));
+        assert!(matches!(
+            request("", Some("tok")).credentials(false),
+            Ok(Credentials::OAuth(_))
+        ));
+        assert!(matches!(
+            request("key", None).credentials(false),
+            Err(AuthError::ApiKeysDisabled)
+        ));
+        assert!(matches!(
+            request("key", Some("tok")).credentials(true),
+            Err(AuthError::MissingCredentials)
+        ));
+        assert!(matches!(
+            request(" ", Some(" ")).credentials(true),
+            Err(AuthError::MissingCredentials)
+        ));
+        assert_eq!(
+            AuthError::ApiKeysDisabled.into_response().status(),
+            StatusCode::FORBIDDEN
+        );
+    }
+
     // ========================================
     // TDD Tests for HKDF-based JWT Manager
     // ========================================
@@ -747,7 +944,7 @@ mod tests {
         let credentials = manager.verify_token(&token).unwrap();
 
         assert_eq!(credentials.athlete_id, athlete_id);
-        assert_eq!(credentials.api_key.expose_secret(), api_key);
+        assert_eq!(credentials.credentials.secret().expose_secret(), api_key);
     }
 
     // RED: Test that different master keys produce different tokens
@@ -503,8 +503,14 @@ pub async fn ui_create_token(
     let Some(token_request) = TokenRequestData::from_form(form) else {
         return redirect_with_session("/ui?error=Missing+credentials", &session.session_id);
     };
+    if !ui.app_state.allow_api_keys {
+        return redirect_with_session(
+            "/ui?error=API+keys+are+not+accepted+by+this+server",
+            &session.session_id,
+        );
+    }
 
-    let client = match intervals_icu_client::http_client::ReqwestIntervalsClient::new(
+    let client = match crate::upstream_client(
         &ui.app_state.base_url,
         token_request.athlete_id.clone(),
         secrecy::SecretString::new(token_request.api_key.clone().into()),
@@ -867,6 +873,7 @@ mod tests {
             jwt_manager,
             jwt_ttl_seconds: 3600,
             base_url: "https://intervals.icu".to_string(),
+            allow_api_keys: true,
         });
 
         UiState::new(app_state, None)
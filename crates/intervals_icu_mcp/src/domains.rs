@@ -25,7 +25,10 @@ pub mod fitness;
 pub mod gear;
 pub mod nutrition;
 pub mod progress;
+pub mod records;
 pub mod resources;
+pub mod roster;
+pub mod season;
 pub mod sport_settings;
 pub mod wellness;
 pub mod workout_validator;
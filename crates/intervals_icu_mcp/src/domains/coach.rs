@@ -67,6 +67,9 @@ pub struct DataAudit {
     pub intervals_available: bool,
     pub streams_available: bool,
     pub degraded_mode_reasons: Vec<String>,
+    /// Days whose load was estimated from session RPE × duration.
+    #[serde(default)]
+    pub estimated_load_days: Vec<NaiveDate>,
 }
 
 impl DataAudit {
@@ -165,6 +168,18 @@ pub struct WellnessMetrics {
     pub hrv_recovery_flag: bool,
     pub hrv_trend_slope: Option<f64>,
     pub recovery_quality_index: Option<f64>,
+    /// Menstrual-cycle phase on the latest wellness entry.
+    #[serde(default)]
+    pub cycle_phase: Option<String>,
+    /// Expected phase shift applied to the HRV baseline (%).
+    #[serde(default)]
+    pub cycle_hrv_shift_pct: Option<f64>,
+    /// Expected phase shift applied to the resting-HR baseline (bpm).
+    #[serde(default)]
+    pub cycle_rhr_shift_bpm: Option<f64>,
+    /// HRV dip or RHR rise against the personal baseline is within the phase shift.
+    #[serde(default)]
+    pub cycle_explained_deviation: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -289,6 +304,61 @@ pub struct HeatMetrics {
     pub heat_state: String,
 }
 
+/// Heat-acclimation state built from exposure minutes above a temperature
+/// threshold, with exponential decay between exposures.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct HeatAcclimationMetrics {
+    pub supported: bool,
+    /// 0.0 = unacclimated, 1.0 = fully acclimated.
+    pub acclimation_index: Option<f64>,
+    /// unacclimated / partial / acclimated
+    pub state: String,
+    pub exposure_minutes_14d: f64,
+    pub heat_sessions_14d: usize,
+    pub days_since_exposure: Option<i64>,
+    /// Mean efficiency factor across the window after removing heat penalty.
+    pub heat_adjusted_ef: Option<f64>,
+}
+
+/// EWMA acute:chronic ratio for a single tissue / sport load channel.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChannelLoadRisk {
+    /// run_impact / vertical / cycling / strength
+    pub channel: String,
+    pub acwr: AcwrMetrics,
+    /// Raw channel load over the trailing 7 days (channel units).
+    pub load_7d: f64,
+    /// low / moderate / high
+    pub risk: String,
+}
+
+/// Week-over-week spike on long-run distance or weekly descent.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoadSpike {
+    /// long_run_distance (km) / weekly_descent (m)
+    pub kind: String,
+    /// Channel the spike loads.
+    pub channel: String,
+    pub previous: f64,
+    pub current: f64,
+    pub ratio: f64,
+    /// moderate / high
+    pub severity: String,
+}
+
+/// Per-channel injury-risk model: ACWR per load channel plus week-over-week
+/// spikes, with the channel that drives the overall risk.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct InjuryRiskMetrics {
+    pub supported: bool,
+    pub channels: Vec<ChannelLoadRisk>,
+    pub spikes: Vec<LoadSpike>,
+    /// Channel with the highest risk when overall risk is moderate or high.
+    pub driving_channel: Option<String>,
+    /// low / moderate / high
+    pub risk_level: String,
+}
+
 /// Seiler 80/20 polarisation metrics — collapses standard 5-zone model into 3 macro-zones:
 ///   Z1 (Easy)      = zones 1+2 (below LT1)
 ///   Z2 (Threshold) = zone 3 (LT1–LT2)
@@ -351,6 +421,8 @@ pub struct CoachMetrics {
     pub wdrm: Option<WdrMetrics>,
     pub ndli: Option<NdliMetrics>,
     pub heat: Option<HeatMetrics>,
+    pub heat_acclimation: Option<HeatAcclimationMetrics>,
+    pub injury_risk: Option<InjuryRiskMetrics>,
     pub race_readiness: Option<RaceReadinessMetrics>,
 }
 
@@ -547,6 +619,7 @@ mod tests {
             intervals_available: true,
             streams_available: true,
             degraded_mode_reasons: vec![],
+            estimated_load_days: vec![],
         };
 
         assert!(audit.all_available());
@@ -561,6 +634,7 @@ mod tests {
             intervals_available: true,
             streams_available: true,
             degraded_mode_reasons: vec!["wellness data unavailable".to_string()],
+            estimated_load_days: vec![],
         };
 
         assert!(!audit.all_available());
@@ -575,6 +649,7 @@ mod tests {
             intervals_available: false,
             streams_available: true,
             degraded_mode_reasons: vec![],
+            estimated_load_days: vec![],
         };
 
         let summary = audit.availability_summary();
@@ -219,6 +219,7 @@ mod tests {
             category: intervals_icu_client::EventCategory::Note,
             description: None,
             r#type: None,
+            end_date_local: None,
         };
         matches!(
             validate_and_prepare_event(ev),
@@ -235,6 +236,7 @@ mod tests {
             category: intervals_icu_client::EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         };
 
         let prepared = validate_and_prepare_event(ev).expect("should prepare event");
@@ -251,6 +253,7 @@ mod tests {
             category: intervals_icu_client::EventCategory::Unknown,
             description: None,
             r#type: None,
+            end_date_local: None,
         };
         assert_eq!(
             validate_and_prepare_event(ev),
@@ -268,6 +271,7 @@ mod tests {
             category: intervals_icu_client::EventCategory::Note,
             description: Some("desc".into()),
             r#type: None,
+            end_date_local: None,
         }
     }
 
@@ -56,6 +56,36 @@ const CARB_OVER_FRACTION: f64 = 1.2;
 /// Protein fraction threshold for underfuelled.
 const PROTEIN_UNDER_FRACTION: f64 = 0.7;
 
+/// In-race duration threshold below which only small carb amounts are needed (hours).
+const IN_RACE_SHORT_HOURS: f64 = 1.0;
+
+/// In-race duration threshold above which multiple-transportable carbs are advised (hours).
+const IN_RACE_LONG_HOURS: f64 = 2.5;
+
+/// In-race carb intake for efforts up to 1h (g/h). Small amounts / mouth rinse.
+const IN_RACE_CARB_SHORT_G_PER_HOUR: f64 = 30.0;
+
+/// In-race carb intake for 1-2.5h efforts (g/h).
+const IN_RACE_CARB_MODERATE_G_PER_HOUR: f64 = 60.0;
+
+/// In-race carb intake for >2.5h efforts (g/h), glucose:fructose blend.
+const IN_RACE_CARB_LONG_G_PER_HOUR: f64 = 90.0;
+
+/// Baseline fluid intake in temperate conditions (ml/h).
+const FLUID_BASE_ML_PER_HOUR: f64 = 500.0;
+
+/// Fluid intake in warm conditions (ml/h).
+const FLUID_WARM_ML_PER_HOUR: f64 = 700.0;
+
+/// Fluid intake in hot conditions (ml/h).
+const FLUID_HOT_ML_PER_HOUR: f64 = 900.0;
+
+/// Ambient temperature above which conditions count as warm (°C).
+const FLUID_WARM_TEMP_C: f64 = 20.0;
+
+/// Ambient temperature above which conditions count as hot (°C).
+const FLUID_HOT_TEMP_C: f64 = 27.0;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NutritionDemand {
     pub supported: bool,
@@ -139,6 +169,30 @@ pub fn compute_nutrition_balance(
     }
 }
 
+/// Compute in-race carbohydrate intake rate (g/h) from expected race duration.
+/// Source: Jeukendrup, Sports Med 2014; Thomas et al. JISSN 2016.
+/// ≤1h: 30 g/h, 1-2.5h: 60 g/h, >2.5h: 90 g/h (multiple transportable carbs).
+pub fn compute_in_race_carb_rate(duration_hours: f64) -> f64 {
+    if duration_hours <= IN_RACE_SHORT_HOURS {
+        IN_RACE_CARB_SHORT_G_PER_HOUR
+    } else if duration_hours <= IN_RACE_LONG_HOURS {
+        IN_RACE_CARB_MODERATE_G_PER_HOUR
+    } else {
+        IN_RACE_CARB_LONG_G_PER_HOUR
+    }
+}
+
+/// Compute in-race fluid intake rate (ml/h) from ambient temperature.
+/// Source: ACSM Position Stand, Sawka et al. MSSE 2007 (0.4-0.8 L/h, more in heat).
+/// Unknown temperature falls back to the temperate baseline.
+pub fn compute_fluid_rate(temperature_c: Option<f64>) -> f64 {
+    match temperature_c {
+        Some(temp) if temp > FLUID_HOT_TEMP_C => FLUID_HOT_ML_PER_HOUR,
+        Some(temp) if temp > FLUID_WARM_TEMP_C => FLUID_WARM_ML_PER_HOUR,
+        _ => FLUID_BASE_ML_PER_HOUR,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -253,4 +307,19 @@ mod tests {
         assert!(!nd.supported);
         assert_eq!(nd.unsupported_reason, "no nutrition tracking data");
     }
+
+    #[test]
+    fn in_race_carb_rate_scales_with_duration() {
+        assert!((compute_in_race_carb_rate(0.8) - 30.0).abs() < 0.01);
+        assert!((compute_in_race_carb_rate(2.0) - 60.0).abs() < 0.01);
+        assert!((compute_in_race_carb_rate(5.0) - 90.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn fluid_rate_rises_with_temperature() {
+        assert!((compute_fluid_rate(None) - 500.0).abs() < 0.01);
+        assert!((compute_fluid_rate(Some(15.0)) - 500.0).abs() < 0.01);
+        assert!((compute_fluid_rate(Some(24.0)) - 700.0).abs() < 0.01);
+        assert!((compute_fluid_rate(Some(31.0)) - 900.0).abs() < 0.01);
+    }
 }
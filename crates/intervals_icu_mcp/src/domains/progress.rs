@@ -1,7 +1,10 @@
 //! Domain types for deterministic progress tracking.
 
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+use crate::domains::coach::InjuryRiskMetrics;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub enum TrendState {
     Rising,
@@ -74,6 +77,31 @@ pub struct ProgressHypothesis {
     pub tracking_metric: String,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EpisodeKind {
+    Illness,
+    Injury,
+}
+
+/// A contiguous illness or injury period built from calendar events and wellness flags.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HealthEpisode {
+    pub kind: EpisodeKind,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// Calendar event names or wellness flags that make up the episode.
+    pub sources: Vec<String>,
+    /// First day with a real training session after the episode started.
+    pub return_date: Option<NaiveDate>,
+    /// Days from the episode end to the return session.
+    pub days_to_return: Option<i64>,
+    /// CTL lost from the pre-episode peak to the trough before return.
+    pub ctl_lost: Option<f64>,
+    /// No return session yet.
+    pub ongoing: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct ProgressReport {
     pub plateau: ChangepointResult,
@@ -86,9 +114,15 @@ pub struct ProgressReport {
     pub hrv_ratio: Option<f64>,
     pub hrv_trend_state: Option<String>,
     pub hrv_suppressed: bool,
+    pub injury_risk: InjuryRiskMetrics,
     pub hypotheses: Vec<ProgressHypothesis>,
     pub recommendations: Vec<String>,
     pub warnings: Vec<String>,
+    #[serde(default)]
+    pub episodes: Vec<HealthEpisode>,
+    /// CTL points inside episode windows left out of plateau detection.
+    #[serde(default)]
+    pub excluded_episode_days: usize,
 }
 
 #[cfg(test)]
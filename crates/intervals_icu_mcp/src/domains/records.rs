@@ -0,0 +1,72 @@
+//! Domain types for personal records and best-effort progression.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domains::season::BestEffort;
+
+/// Curve a record is read from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordMetric {
+    /// Best average power for a duration (anchor in seconds, value in watts).
+    Power,
+    /// Fastest time over a distance (anchor in metres, value in seconds).
+    Pace,
+}
+
+/// Lookback a record is held over.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordWindow {
+    AllTime,
+    /// Since 1 January of the current year.
+    Season,
+    Rolling90,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PersonalRecord {
+    pub metric: RecordMetric,
+    /// Seconds for power, metres for pace.
+    pub anchor: u32,
+    /// Watts for power, seconds for pace.
+    pub value: f64,
+    /// Activity that set the record, when the curve carries it.
+    pub activity_id: Option<String>,
+}
+
+/// Bests for one anchor across the three windows.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecordRow {
+    pub metric: RecordMetric,
+    pub anchor: u32,
+    pub all_time: Option<PersonalRecord>,
+    pub season: Option<PersonalRecord>,
+    pub rolling_90: Option<PersonalRecord>,
+}
+
+/// All-time, season and rolling-90-day bests for one sport.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RecordBook {
+    /// Intervals.icu curve sport (`Ride`, `Run`).
+    pub sport: String,
+    pub rows: Vec<RecordRow>,
+}
+
+/// A record held by the analysed activity.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NewRecord {
+    pub metric: RecordMetric,
+    pub anchor: u32,
+    pub value: f64,
+    /// Broadest window the activity holds the record in.
+    pub window: RecordWindow,
+}
+
+/// Best value of the progression anchor in one calendar month.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProgressionPoint {
+    /// `YYYY-MM`.
+    pub month: String,
+    pub best: Option<BestEffort>,
+}
@@ -0,0 +1,65 @@
+//! Domain types for coach roster summaries.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domains::coach::{AcwrMetrics, CoachAlertSeverity};
+
+/// Overall state of one athlete on the roster, most urgent first.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum RosterStatus {
+    /// At least one priority flag.
+    Attention,
+    /// At least one caution flag.
+    Watch,
+    /// Wellness could not be fetched or has no recent entries.
+    NoData,
+    Ready,
+}
+
+impl RosterStatus {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Attention => "attention",
+            Self::Watch => "watch",
+            Self::NoData => "no data",
+            Self::Ready => "ready",
+        }
+    }
+}
+
+/// A reason an athlete needs the coach's attention.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RosterFlag {
+    pub severity: CoachAlertSeverity,
+    pub code: String,
+    pub message: String,
+}
+
+/// Latest load and recovery state of one coached athlete.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AthleteSnapshot {
+    pub athlete_id: String,
+    pub name: Option<String>,
+    /// Date of the latest wellness entry with fitness values (`YYYY-MM-DD`).
+    pub latest_date: Option<String>,
+    pub ctl: Option<f64>,
+    pub atl: Option<f64>,
+    pub form: Option<f64>,
+    pub acwr: Option<AcwrMetrics>,
+    /// Device readiness, or the subjective readiness score (0–10).
+    pub readiness: Option<f64>,
+    /// Latest HRV over its rolling baseline.
+    pub hrv_ratio: Option<f64>,
+    pub flags: Vec<RosterFlag>,
+    pub status: RosterStatus,
+}
+
+impl AthleteSnapshot {
+    /// Display name, falling back to the athlete id.
+    #[must_use]
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.athlete_id)
+    }
+}
@@ -0,0 +1,111 @@
+//! Domain types for season and year-over-year reviews.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::domains::coach::ConsistencyMetrics;
+use crate::domains::progress::HealthEpisode;
+
+/// Volume, load and 3-zone intensity distribution for one calendar month.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MonthSummary {
+    /// `YYYY-MM`.
+    pub month: String,
+    pub sessions: usize,
+    pub hours: f64,
+    pub distance_km: f64,
+    pub load: f64,
+    /// Share of zone time below / at / above threshold (Z1-2 / Z3 / Z4+).
+    pub tid: Option<(f64, f64, f64)>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum BestEffortKind {
+    /// Highest modelled FTP (eFTP) from the wellness eFTP history.
+    Eftp,
+    /// Highest 1-second power from the ride power curve.
+    PeakPower,
+    /// Best 20-minute power from the ride power curve.
+    Power20m,
+    /// Fastest 5 km from the run pace curve.
+    Pace5k,
+    /// Fastest 10 km from the run pace curve.
+    Pace10k,
+    /// Fastest half marathon from the run pace curve.
+    PaceHalf,
+    /// Highest 1-second heart rate from the ride and run HR curves.
+    MaxHr,
+    /// Best 20-minute heart rate from the ride and run HR curves.
+    Hr20m,
+}
+
+/// Best value of one effort kind in the season and the session that set it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BestEffort {
+    pub kind: BestEffortKind,
+    /// Watts, seconds per km, or bpm depending on `kind`.
+    pub value: f64,
+    /// Unknown when a curve value is interpolated or its activity is not listed.
+    pub date: Option<NaiveDate>,
+    pub activity_id: Option<String>,
+}
+
+/// A RACE_A/B/C calendar entry and the activity completed that day, if any.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RaceOutcome {
+    pub date: NaiveDate,
+    pub name: String,
+    /// `A`, `B` or `C`.
+    pub priority: String,
+    pub activity_id: Option<String>,
+    pub moving_time_secs: Option<i64>,
+    pub distance_km: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct GearUsage {
+    pub gear_id: String,
+    pub name: Option<String>,
+    pub sessions: usize,
+    pub distance_km: f64,
+    pub hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct SeasonReview {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub sessions: usize,
+    pub hours: f64,
+    pub distance_km: f64,
+    pub load: f64,
+    pub months: Vec<MonthSummary>,
+    pub peak_ctl: Option<f64>,
+    pub peak_ctl_date: Option<NaiveDate>,
+    pub best_efforts: Vec<BestEffort>,
+    pub races: Vec<RaceOutcome>,
+    /// Completed vs planned sessions.
+    pub consistency: ConsistencyMetrics,
+    /// Weeks with at least the minimum number of sessions.
+    pub consistent_weeks: usize,
+    pub total_weeks: usize,
+    pub episodes: Vec<HealthEpisode>,
+    /// Days inside the season lost to illness or injury.
+    pub downtime_days: i64,
+    pub gear: Vec<GearUsage>,
+}
+
+/// One metric compared against the same span a year earlier.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SeasonDelta {
+    pub metric: String,
+    /// Set when the metric is a best effort, for unit-aware formatting.
+    pub effort: Option<BestEffortKind>,
+    pub current: Option<f64>,
+    pub previous: Option<f64>,
+    /// Percentage change, current over previous.
+    pub change_pct: Option<f64>,
+    /// Whether the change is an improvement (pace and downtime improve downward).
+    pub improved: Option<bool>,
+}
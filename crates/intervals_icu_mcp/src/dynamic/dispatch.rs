@@ -41,7 +41,9 @@ pub async fn dispatch_operation(
                 })?;
                 path = path.replace(&format!("{{{}}}", p.name), &replacement);
             }
-            crate::dynamic::types::ParamLocation::Query => {}
+            crate::dynamic::types::ParamLocation::Query
+            | crate::dynamic::types::ParamLocation::FormField
+            | crate::dynamic::types::ParamLocation::FormFile => {}
         }
     }
 
@@ -61,6 +63,10 @@ pub async fn dispatch_operation(
         req = req.json(&body);
     }
 
+    if let Some(form) = build_multipart_form(&args, &operation.params).await? {
+        req = req.multipart(form);
+    }
+
     let resp = req.send().await.map_err(|e| {
         ErrorData::internal_error(
             format!("HTTP request failed for {}: {e}", operation.name),
@@ -191,6 +197,60 @@ fn collect_query_pairs(
     query
 }
 
+/// Build the `multipart/form-data` body, streaming file fields from disk.
+///
+/// Returns `None` for operations without form parameters.
+async fn build_multipart_form(
+    args: &JsonObject,
+    params: &[crate::dynamic::types::ParamSpec],
+) -> Result<Option<reqwest::multipart::Form>, ErrorData> {
+    use crate::dynamic::types::ParamLocation;
+
+    let mut form = None;
+    for param in params {
+        let Some(value) = args.get(&param.name) else {
+            continue;
+        };
+        let part = match param.location {
+            ParamLocation::FormField => reqwest::multipart::Part::text(value_to_query(value)),
+            ParamLocation::FormFile => {
+                let path = value.as_str().ok_or_else(|| {
+                    ErrorData::invalid_params(
+                        format!("{} must be a local file path", param.name),
+                        None,
+                    )
+                })?;
+                let path = crate::uploads::resolve_upload_path(path)
+                    .map_err(|e| ErrorData::invalid_params(e, None))?;
+                let file = tokio::fs::File::open(&path).await.map_err(|e| {
+                    ErrorData::invalid_params(format!("cannot open {}: {e}", path.display()), None)
+                })?;
+                let length = file
+                    .metadata()
+                    .await
+                    .map_err(|e| {
+                        ErrorData::internal_error(
+                            format!("cannot stat {}: {e}", path.display()),
+                            None,
+                        )
+                    })?
+                    .len();
+                let file_name = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| param.name.clone());
+                reqwest::multipart::Part::stream_with_length(file, length).file_name(file_name)
+            }
+            ParamLocation::Path | ParamLocation::Query => continue,
+        };
+        form = Some(
+            form.unwrap_or_else(reqwest::multipart::Form::new)
+                .part(param.name.clone(), part),
+        );
+    }
+    Ok(form)
+}
+
 fn value_to_query(value: &Value) -> String {
     match value {
         Value::String(s) => s.clone(),
@@ -37,21 +37,6 @@ pub fn parse_openapi_spec(
                 continue;
             }
 
-            if contains_multipart(op) {
-                let op_id = op
-                    .get("operationId")
-                    .and_then(Value::as_str)
-                    .unwrap_or("<unnamed>");
-                tracing::warn!(
-                    operation_id = op_id,
-                    path = %path,
-                    method = method_name.to_uppercase(),
-                    "skipping multipart operation: dynamic dispatcher does not handle multipart/form-data; \
-                     use a typed IntervalsClient method or extend dispatch to support file uploads"
-                );
-                continue;
-            }
-
             let operation_id = op.get("operationId").and_then(Value::as_str);
             let name = operation_id
                 .map(ToOwned::to_owned)
@@ -73,8 +58,11 @@ pub fn parse_openapi_spec(
                 })
                 .unwrap_or_else(|| generate_human_readable_description(method_name, path));
 
-            let (params, schema_props, mut required) =
+            let (mut params, mut schema_props, mut required) =
                 extract_parameters(op, &path_level_params, path);
+            if contains_multipart(op) {
+                add_multipart_parameters(op, spec, &mut params, &mut schema_props, &mut required);
+            }
 
             let has_json_body = op
                 .get("requestBody")
@@ -157,6 +145,87 @@ fn contains_multipart(op: &Map<String, Value>) -> bool {
         .is_some_and(|content| content.keys().any(|k| k.contains("multipart")))
 }
 
+/// Register the fields of a `multipart/form-data` body as form parameters.
+///
+/// Binary properties become file fields taking a local path; everything else
+/// is sent as text.
+fn add_multipart_parameters(
+    op: &Map<String, Value>,
+    spec: &Value,
+    params: &mut Vec<ParamSpec>,
+    schema_props: &mut Map<String, Value>,
+    required: &mut Vec<Value>,
+) {
+    let Some(content) = op
+        .get("requestBody")
+        .and_then(|rb| rb.get("content"))
+        .and_then(Value::as_object)
+    else {
+        return;
+    };
+    let Some(schema) = content
+        .iter()
+        .find(|(media_type, _)| media_type.contains("multipart"))
+        .and_then(|(_, media)| media.get("schema"))
+        .map(|schema| resolve_schema_ref(spec, schema))
+    else {
+        return;
+    };
+
+    let required_fields: HashSet<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return;
+    };
+
+    for (name, property) in properties {
+        let property = resolve_schema_ref(spec, property);
+        let is_file = is_binary_schema(property)
+            || property
+                .get("items")
+                .is_some_and(|items| is_binary_schema(resolve_schema_ref(spec, items)));
+        let (location, property_schema) = if is_file {
+            (
+                ParamLocation::FormFile,
+                serde_json::json!({
+                    "type": "string",
+                    "description": "Local path of the file to upload"
+                }),
+            )
+        } else {
+            (ParamLocation::FormField, property.clone())
+        };
+        params.push(ParamSpec {
+            name: name.clone(),
+            location,
+            auto_injected: false,
+        });
+        schema_props.insert(name.clone(), property_schema);
+        if required_fields.contains(name.as_str()) {
+            required.push(Value::String(name.clone()));
+        }
+    }
+}
+
+fn is_binary_schema(schema: &Value) -> bool {
+    schema.get("format").and_then(Value::as_str) == Some("binary")
+}
+
+/// Follow a local `#/components/...` reference, or return the schema as is.
+fn resolve_schema_ref<'a>(spec: &'a Value, schema: &'a Value) -> &'a Value {
+    schema
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|reference| reference.strip_prefix('#'))
+        .and_then(|pointer| spec.pointer(pointer))
+        .unwrap_or(schema)
+}
+
 fn extract_parameters(
     op: &Map<String, Value>,
     path_level_params: &[Value],
@@ -653,7 +722,7 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_skips_multipart() {
+    fn test_parse_registers_multipart_form_fields() {
         let spec = json!({
             "openapi": "3.0.0",
             "info": {"title": "Test API", "version": "1.0.0"},
@@ -662,10 +731,13 @@ mod tests {
                     "post": {
                         "operationId": "upload_file",
                         "summary": "Upload file",
+                        "parameters": [
+                            {"name": "name", "in": "query", "schema": {"type": "string"}}
+                        ],
                         "requestBody": {
                             "content": {
                                 "multipart/form-data": {
-                                    "schema": {"type": "object"}
+                                    "schema": {"$ref": "#/components/schemas/Upload"}
                                 }
                             }
                         }
@@ -677,15 +749,44 @@ mod tests {
                         "summary": "List files"
                     }
                 }
+            },
+            "components": {
+                "schemas": {
+                    "Upload": {
+                        "type": "object",
+                        "required": ["file"],
+                        "properties": {
+                            "file": {"type": "string", "format": "binary"},
+                            "comment": {"type": "string"}
+                        }
+                    }
+                }
             }
         });
 
-        let result = parse_openapi_spec(&spec, &HashSet::new(), &HashSet::new());
-        let registry = result.unwrap();
-        // Multipart operation skipped, non-multipart included.
-        assert!(registry.operation("upload_file").is_none());
-        assert!(registry.operation("list_files").is_some());
-        assert_eq!(registry.len(), 1);
+        let registry = parse_openapi_spec(&spec, &HashSet::new(), &HashSet::new()).unwrap();
+        assert_eq!(registry.len(), 2);
+        let upload = registry.operation("upload_file").unwrap();
+        assert!(!upload.has_json_body);
+        let location = |name: &str| {
+            upload
+                .params
+                .iter()
+                .find(|param| param.name == name)
+                .map(|param| param.location.clone())
+        };
+        assert_eq!(location("file"), Some(ParamLocation::FormFile));
+        assert_eq!(location("comment"), Some(ParamLocation::FormField));
+        assert_eq!(location("name"), Some(ParamLocation::Query));
+
+        let schema = upload.tool.input_schema.as_ref();
+        assert_eq!(schema["required"], json!(["file"]));
+        assert!(
+            schema["properties"]["file"]["description"]
+                .as_str()
+                .unwrap()
+                .contains("Local path")
+        );
     }
 
     #[test]
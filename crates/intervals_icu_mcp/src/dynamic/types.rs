@@ -8,6 +8,10 @@ use std::collections::HashMap;
 pub enum ParamLocation {
     Path,
     Query,
+    /// Text field of a `multipart/form-data` body.
+    FormField,
+    /// File field of a `multipart/form-data` body; the argument is a local path.
+    FormFile,
 }
 
 /// Specification for a single parameter.
@@ -1,3 +1,4 @@
+pub mod activity_import;
 pub mod adaptation;
 pub mod ade;
 pub mod analysis;
@@ -8,11 +9,26 @@ pub mod coach_guidance;
 pub mod coach_metrics;
 pub mod coach_metrics_constants;
 pub mod cp_regression;
+pub mod fit_metrics;
 pub mod forecast;
+pub mod gear_wear;
+pub mod health_episodes;
+pub mod heat_acclimation;
+pub mod injury_risk;
+pub mod menstrual_cycle;
+pub mod personal_records;
 pub mod planning;
 pub mod progress_tracking;
+pub mod race_pacing;
 pub mod race_readiness;
+pub mod roster;
+pub mod season_review;
+pub mod session_rpe;
+pub mod strength;
+pub mod threshold_drift;
+pub mod threshold_estimation;
 pub mod trail_execution;
+pub mod zone_models;
 
 pub use analysis::{AnalysisEngine, WorkoutInsights};
 pub use planning::PeriodizationRules;
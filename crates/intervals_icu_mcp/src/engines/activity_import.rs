@@ -0,0 +1,319 @@
+//! Activity file import helpers.
+//! Reads the start time from the head of a FIT, TCX or GPX file and matches it
+//! against existing activities so the same recording is not uploaded twice.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// Bytes read from the start of a file when looking for its start time.
+pub const START_TIME_SCAN_BYTES: usize = 64 * 1024;
+/// Activities starting within this many seconds of the file count as duplicates.
+pub const DUPLICATE_TOLERANCE_SECS: i64 = 60;
+
+/// Seconds between the Unix epoch and the FIT epoch (1989-12-31T00:00:00Z).
+const FIT_EPOCH_OFFSET: i64 = 631_065_600;
+const FIT_FILE_ID: u16 = 0;
+const FIT_RECORD: u16 = 20;
+const FIT_TIME_CREATED: u8 = 4;
+const FIT_TIMESTAMP: u8 = 253;
+
+/// Activity file formats the importer can inspect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityFileFormat {
+    Fit,
+    Tcx,
+    Gpx,
+    /// Gzip or zip archives: uploaded as is, contents not inspected.
+    Compressed,
+}
+
+impl ActivityFileFormat {
+    /// Format from the file name, or `None` for unsupported files.
+    #[must_use]
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        let lower = file_name.to_ascii_lowercase();
+        if lower.ends_with(".gz") || lower.ends_with(".zip") {
+            let inner = lower.trim_end_matches(".gz");
+            return (inner.ends_with(".fit")
+                || inner.ends_with(".tcx")
+                || inner.ends_with(".gpx")
+                || lower.ends_with(".zip"))
+            .then_some(Self::Compressed);
+        }
+        match lower.rsplit_once('.').map(|(_, extension)| extension) {
+            Some("fit") => Some(Self::Fit),
+            Some("tcx") => Some(Self::Tcx),
+            Some("gpx") => Some(Self::Gpx),
+            _ => None,
+        }
+    }
+}
+
+/// Start time recorded in the file head, if it can be read.
+#[must_use]
+pub fn detect_start_time(format: ActivityFileFormat, head: &[u8]) -> Option<DateTime<Utc>> {
+    match format {
+        ActivityFileFormat::Fit => fit_start_time(head),
+        ActivityFileFormat::Tcx => {
+            let text = String::from_utf8_lossy(head);
+            attribute_value(&text, "StartTime=")
+                .or_else(|| element_text(&text, "<Id>"))
+                .and_then(parse_timestamp)
+        }
+        ActivityFileFormat::Gpx => {
+            let text = String::from_utf8_lossy(head);
+            let track = text
+                .find("<trkpt")
+                .map_or(&text[..], |start| &text[start..]);
+            element_text(track, "<time>")
+                .or_else(|| element_text(&text, "<time>"))
+                .and_then(parse_timestamp)
+        }
+        ActivityFileFormat::Compressed => None,
+    }
+}
+
+/// True when the activity's UTC `start_date` is within the duplicate tolerance.
+#[must_use]
+pub fn starts_at(activity: &Value, start: DateTime<Utc>) -> bool {
+    activity
+        .get("start_date")
+        .and_then(Value::as_str)
+        .and_then(parse_timestamp)
+        .is_some_and(|existing| (existing - start).num_seconds().abs() <= DUPLICATE_TOLERANCE_SECS)
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+                .ok()
+                .map(|naive| naive.and_utc())
+        })
+}
+
+fn element_text<'a>(text: &'a str, open_tag: &str) -> Option<&'a str> {
+    let start = text.find(open_tag)? + open_tag.len();
+    let end = text[start..].find('<')?;
+    Some(&text[start..start + end])
+}
+
+fn attribute_value<'a>(text: &'a str, attribute: &str) -> Option<&'a str> {
+    let start = text.find(attribute)? + attribute.len();
+    let quote = text[start..].chars().next()?;
+    let value = &text[start + 1..];
+    value.find(quote).map(|end| &value[..end])
+}
+
+struct FitField {
+    number: u8,
+    size: usize,
+}
+
+struct FitDefinition {
+    global: u16,
+    big_endian: bool,
+    fields: Vec<FitField>,
+    developer_bytes: usize,
+}
+
+/// `file_id.time_created`, falling back to the first record timestamp.
+fn fit_start_time(head: &[u8]) -> Option<DateTime<Utc>> {
+    let header_size = usize::from(*head.first()?);
+    if head.get(8..12)? != b".FIT" {
+        return None;
+    }
+    let mut definitions: [Option<FitDefinition>; 16] = Default::default();
+    let mut offset = header_size;
+
+    while let Some(&record_header) = head.get(offset) {
+        offset += 1;
+        if record_header & 0x80 != 0 {
+            // Compressed timestamp header: a data message for a local type.
+            let local = usize::from((record_header >> 5) & 0x03);
+            offset += definitions[local].as_ref().map(message_size)?;
+            continue;
+        }
+        let local = usize::from(record_header & 0x0F);
+        if record_header & 0x40 != 0 {
+            let (definition, size) =
+                fit_definition(head.get(offset..)?, record_header & 0x20 != 0)?;
+            definitions[local] = Some(definition);
+            offset += size;
+            continue;
+        }
+
+        let definition = definitions[local].as_ref()?;
+        let data = head.get(offset..offset + message_size(definition))?;
+        offset += data.len();
+        let wanted = match definition.global {
+            FIT_FILE_ID => FIT_TIME_CREATED,
+            FIT_RECORD => FIT_TIMESTAMP,
+            _ => continue,
+        };
+        let mut field_offset = 0;
+        for field in &definition.fields {
+            if field.number == wanted && field.size == 4 {
+                let bytes: [u8; 4] = data[field_offset..field_offset + 4].try_into().ok()?;
+                let seconds = if definition.big_endian {
+                    u32::from_be_bytes(bytes)
+                } else {
+                    u32::from_le_bytes(bytes)
+                };
+                if seconds != u32::MAX {
+                    return DateTime::from_timestamp(FIT_EPOCH_OFFSET + i64::from(seconds), 0);
+                }
+            }
+            field_offset += field.size;
+        }
+    }
+    None
+}
+
+fn fit_definition(bytes: &[u8], has_developer_fields: bool) -> Option<(FitDefinition, usize)> {
+    let big_endian = *bytes.get(1)? == 1;
+    let global_bytes = [*bytes.get(2)?, *bytes.get(3)?];
+    let global = if big_endian {
+        u16::from_be_bytes(global_bytes)
+    } else {
+        u16::from_le_bytes(global_bytes)
+    };
+    let field_count = usize::from(*bytes.get(4)?);
+    let mut size = 5 + field_count * 3;
+    let fields = bytes
+        .get(5..size)?
+        .as_chunks::<3>()
+        .0
+        .iter()
+        .map(|field| FitField {
+            number: field[0],
+            size: usize::from(field[1]),
+        })
+        .collect();
+    let mut developer_bytes = 0;
+    if has_developer_fields {
+        let developer_count = usize::from(*bytes.get(size)?);
+        let developer = bytes.get(size + 1..size + 1 + developer_count * 3)?;
+        developer_bytes = developer
+            .as_chunks::<3>()
+            .0
+            .iter()
+            .map(|field| usize::from(field[1]))
+            .sum();
+        size += 1 + developer_count * 3;
+    }
+    Some((
+        FitDefinition {
+            global,
+            big_endian,
+            fields,
+            developer_bytes,
+        },
+        size,
+    ))
+}
+
+fn message_size(definition: &FitDefinition) -> usize {
+    definition
+        .fields
+        .iter()
+        .map(|field| field.size)
+        .sum::<usize>()
+        + definition.developer_bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    /// Minimal FIT file: header, a file_id definition and one data message.
+    fn fit_with_time_created(seconds: u32) -> Vec<u8> {
+        let mut bytes = vec![14, 0x10, 0, 0, 0, 0, 0, 0];
+        bytes.extend_from_slice(b".FIT");
+        bytes.extend_from_slice(&[0, 0]);
+        // Definition, local type 0: little endian, global 0, fields type(1) and time_created(4).
+        bytes.extend_from_slice(&[0x40, 0, 0, 0, 0, 2, 0, 1, 0, 4, 4, 0x86]);
+        bytes.push(0x00);
+        bytes.push(4);
+        bytes.extend_from_slice(&seconds.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn format_from_file_name() {
+        assert_eq!(
+            ActivityFileFormat::from_file_name("Ride.FIT"),
+            Some(ActivityFileFormat::Fit)
+        );
+        assert_eq!(
+            ActivityFileFormat::from_file_name("run.gpx.gz"),
+            Some(ActivityFileFormat::Compressed)
+        );
+        assert_eq!(ActivityFileFormat::from_file_name("notes.txt"), None);
+        assert_eq!(ActivityFileFormat::from_file_name("notes.txt.gz"), None);
+    }
+
+    #[test]
+    fn detects_fit_time_created() {
+        // 1_100_000_000 s after the FIT epoch.
+        let start = detect_start_time(
+            ActivityFileFormat::Fit,
+            &fit_with_time_created(1_100_000_000),
+        );
+        assert_eq!(
+            start,
+            DateTime::from_timestamp(FIT_EPOCH_OFFSET + 1_100_000_000, 0)
+        );
+        assert_eq!(
+            detect_start_time(ActivityFileFormat::Fit, b"not a fit file"),
+            None
+        );
+    }
+
+    #[test]
+    fn detects_tcx_and_gpx_start_times() {
+        let tcx = br#"<Activity Sport="Running"><Id>2026-03-10T06:00:00Z</Id><Lap StartTime="2026-03-10T06:00:05Z">"#;
+        assert_eq!(
+            detect_start_time(ActivityFileFormat::Tcx, tcx),
+            Some(utc("2026-03-10T06:00:05Z"))
+        );
+
+        let gpx = b"<gpx><metadata><time>2026-03-10T07:59:00Z</time></metadata>\
+            <trk><trkseg><trkpt lat=\"1\" lon=\"2\"><time>2026-03-10T08:00:00.000Z</time></trkpt>";
+        assert_eq!(
+            detect_start_time(ActivityFileFormat::Gpx, gpx),
+            Some(utc("2026-03-10T08:00:00Z"))
+        );
+        assert_eq!(
+            detect_start_time(ActivityFileFormat::Gpx, b"<gpx></gpx>"),
+            None
+        );
+    }
+
+    #[test]
+    fn starts_at_uses_utc_start_within_tolerance() {
+        let start = utc("2026-03-10T06:00:00Z");
+        assert!(starts_at(
+            &json!({"start_date": "2026-03-10T06:00:40Z"}),
+            start
+        ));
+        assert!(!starts_at(
+            &json!({"start_date": "2026-03-10T06:05:00Z"}),
+            start
+        ));
+        assert!(!starts_at(
+            &json!({"start_date_local": "2026-03-10T06:00:00"}),
+            start
+        ));
+    }
+}
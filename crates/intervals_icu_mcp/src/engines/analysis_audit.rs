@@ -1,6 +1,9 @@
 use crate::domains::coach::DataAudit;
 
-use super::analysis_fetch::FetchedAnalysisData;
+use super::analysis_fetch::{FetchedAnalysisData, estimated_load_days};
+
+/// Estimated-load dates listed individually before the audit summarises them.
+const ESTIMATED_LOAD_DAYS_LISTED: usize = 7;
 
 pub fn build_data_audit(fetched: &FetchedAnalysisData) -> DataAudit {
     let wellness_available = fetched
@@ -37,6 +40,26 @@ pub fn build_data_audit(fetched: &FetchedAnalysisData) -> DataAudit {
     }
     degraded_mode_reasons.extend(fetched.fetch_warnings.iter().cloned());
 
+    let estimated_load_days = estimated_load_days(&fetched.activities, &fetched.activity_details);
+    if !estimated_load_days.is_empty() {
+        let mut listed = estimated_load_days
+            .iter()
+            .take(ESTIMATED_LOAD_DAYS_LISTED)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        if estimated_load_days.len() > ESTIMATED_LOAD_DAYS_LISTED {
+            listed.push(format!(
+                "+{} more",
+                estimated_load_days.len() - ESTIMATED_LOAD_DAYS_LISTED
+            ));
+        }
+        degraded_mode_reasons.push(format!(
+            "load estimated from session RPE × duration (no power/HR) on {} day(s): {}",
+            estimated_load_days.len(),
+            listed.join(", ")
+        ));
+    }
+
     DataAudit {
         activities_available: !fetched.activities.is_empty() || fetched.workout_detail.is_some(),
         wellness_available,
@@ -44,6 +67,7 @@ pub fn build_data_audit(fetched: &FetchedAnalysisData) -> DataAudit {
         intervals_available,
         streams_available,
         degraded_mode_reasons,
+        estimated_load_days,
     }
 }
 
@@ -85,6 +109,47 @@ mod tests {
         assert!(!audit.wellness_available);
     }
 
+    #[test]
+    fn audit_reports_days_with_session_rpe_load() {
+        let fetched = FetchedAnalysisData {
+            activities: vec![
+                intervals_icu_client::ActivitySummary {
+                    id: "strength".into(),
+                    start_date_local: "2026-03-04T18:00:00".into(),
+                    ..Default::default()
+                },
+                intervals_icu_client::ActivitySummary {
+                    id: "ride".into(),
+                    start_date_local: "2026-03-05T08:00:00".into(),
+                    ..Default::default()
+                },
+            ],
+            activity_details: std::collections::HashMap::from([
+                (
+                    "strength".to_string(),
+                    serde_json::json!({"icu_rpe": 7, "moving_time": 3000}),
+                ),
+                (
+                    "ride".to_string(),
+                    serde_json::json!({"icu_training_load": 80, "average_watts": 210.0}),
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        let audit = build_data_audit(&fetched);
+        assert_eq!(
+            audit.estimated_load_days,
+            vec![chrono::NaiveDate::from_ymd_opt(2026, 3, 4).unwrap()]
+        );
+        assert!(
+            audit
+                .degraded_mode_reasons
+                .iter()
+                .any(|reason| reason.contains("session RPE") && reason.contains("2026-03-04"))
+        );
+    }
+
     #[test]
     fn audit_records_missing_fitness_summary_reason() {
         let fetched = FetchedAnalysisData::default();
@@ -1,14 +1,43 @@
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::time::Instant;
 use std::{collections::HashMap, collections::HashSet};
 
 use chrono::{Duration, NaiveDate, NaiveDateTime};
-use intervals_icu_client::{ActivityMessage, ActivitySummary, Event, IntervalsClient};
+use futures_util::TryStreamExt;
+use intervals_icu_client::{ActivityMessage, ActivitySummary, DateRange, Event, IntervalsClient};
 use serde_json::Value;
 
-use crate::domains::coach::AnalysisWindow;
+use crate::domains::coach::{AnalysisWindow, HeatAcclimationMetrics};
+use crate::domains::records::{RecordBook, RecordMetric, RecordWindow};
+use crate::domains::season::BestEffortKind;
+use crate::engines::coach_metrics::get_number;
+use crate::engines::heat_acclimation::{
+    compute_heat_acclimation_from_details, is_heat_stream_candidate,
+};
+use crate::engines::personal_records::{build_record_rows, sport_metrics, window_days};
+use crate::engines::season_review::{EffortCurves, effort_curve_requests};
+use crate::engines::session_rpe::{
+    LoadSource, SrpeCalibration, calibrate_srpe_to_tss, effective_session_load,
+};
 use crate::intents::IntentError;
 
 const ADAPTIVE_HRV_LOOKBACK_DAYS: i32 = 35;
 
+/// History used to calibrate the sRPE → TSS estimate for sensorless sessions.
+const SRPE_CALIBRATION_DAYS: i64 = 90;
+const SRPE_CALIBRATION_ACTIVITY_LIMIT: usize = 60;
+/// How long an athlete's history calibration is reused before it is refetched.
+const SRPE_CALIBRATION_TTL_SECS: u64 = 6 * 60 * 60;
+/// How long a fetched activity detail is reused by load-history and heat reads.
+const ACTIVITY_DETAIL_TTL_SECS: u64 = 10 * 60;
+
+/// Number of days of activity scanned for heat exposure.
+const HEAT_HISTORY_DAYS: i64 = 21;
+/// Most recent sessions read for heat exposure, and `temp` streams fetched
+/// among them; older or further sessions do not count.
+const MAX_HEAT_DETAIL_FETCHES: usize = 14;
+const MAX_HEAT_STREAM_FETCHES: usize = 5;
+
 #[derive(Debug, Clone)]
 pub struct PeriodFetchRequest {
     pub window: AnalysisWindow,
@@ -67,33 +96,36 @@ pub fn build_previous_window(current: &AnalysisWindow) -> AnalysisWindow {
 }
 
 pub fn extract_activity_load(detail: Option<&Value>) -> Option<f64> {
-    let object = detail?.as_object()?;
+    estimate_activity_load(detail, &SrpeCalibration::default()).map(|(load, _)| load)
+}
 
-    ["icu_training_load", "training_load", "icuTrainingLoad"]
-        .iter()
-        .find_map(|key| {
-            object
-                .get(*key)
-                .and_then(|value| value.as_f64().or_else(|| value.as_i64().map(|n| n as f64)))
-        })
-        .or_else(|| {
-            object
-                .get("moving_time")
-                .and_then(|value| value.as_f64().or_else(|| value.as_i64().map(|n| n as f64)))
-                .map(|seconds| seconds / 60.0)
-        })
+/// Load for one activity: recorded training load, the calibrated session-RPE
+/// estimate for sessions without power/HR, or moving minutes as a last resort.
+pub fn estimate_activity_load(
+    detail: Option<&Value>,
+    calibration: &SrpeCalibration,
+) -> Option<(f64, LoadSource)> {
+    let detail = detail?;
+    let object = detail.as_object()?;
+
+    effective_session_load(detail, calibration.factor).or_else(|| {
+        get_number(object, &["moving_time"]).map(|seconds| (seconds / 60.0, LoadSource::Recorded))
+    })
 }
 
 pub fn build_daily_load_series(
     activities: &[&ActivitySummary],
     details: &HashMap<String, Value>,
     window: &AnalysisWindow,
+    calibration: &SrpeCalibration,
 ) -> Vec<(NaiveDate, f64)> {
     let mut totals = HashMap::<NaiveDate, f64>::new();
 
     for activity in activities {
         if let Some(activity_date) = parse_activity_date(&activity.start_date_local) {
-            let load = extract_activity_load(details.get(&activity.id)).unwrap_or(0.0);
+            let load = estimate_activity_load(details.get(&activity.id), calibration)
+                .map(|(load, _)| load)
+                .unwrap_or(0.0);
             totals
                 .entry(activity_date)
                 .and_modify(|total| *total += load)
@@ -111,6 +143,27 @@ pub fn build_daily_load_series(
     series
 }
 
+/// Days whose load includes a session-RPE estimate (sessions without power/HR).
+/// Only the load source matters here, and it does not depend on the sRPE factor.
+pub fn estimated_load_days(
+    activities: &[ActivitySummary],
+    details: &HashMap<String, Value>,
+) -> Vec<NaiveDate> {
+    let mut days = activities
+        .iter()
+        .filter(|activity| {
+            matches!(
+                estimate_activity_load(details.get(&activity.id), &SrpeCalibration::default()),
+                Some((_, LoadSource::SessionRpe))
+            )
+        })
+        .filter_map(|activity| parse_activity_date(&activity.start_date_local))
+        .collect::<Vec<_>>();
+    days.sort_unstable();
+    days.dedup();
+    days
+}
+
 fn parse_activity_date(value: &str) -> Option<NaiveDate> {
     NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
         .ok()
@@ -195,6 +248,32 @@ fn upcoming_rate_limit_warning() -> String {
     "planned workouts unavailable due to Intervals.icu rate limiting; continuing with completed-activity history only".to_string()
 }
 
+/// Every activity dated `oldest..=newest`, newest first. The range is walked
+/// in date chunks, so long histories are not capped at one page.
+pub async fn fetch_activities_between(
+    client: &dyn IntervalsClient,
+    oldest: NaiveDate,
+    newest: NaiveDate,
+) -> Result<Vec<ActivitySummary>, IntentError> {
+    client
+        .activities_stream(DateRange::new(oldest, newest).newest_first())
+        .try_collect()
+        .await
+        .map_err(|e| IntentError::api(format!("Failed to fetch activities: {}", e)))
+}
+
+async fn fetch_events_between(
+    client: &dyn IntervalsClient,
+    oldest: NaiveDate,
+    newest: NaiveDate,
+) -> Result<Vec<Event>, IntentError> {
+    client
+        .events_stream(DateRange::new(oldest, newest))
+        .try_collect()
+        .await
+        .map_err(|e| IntentError::api(format!("Failed to fetch events: {}", e)))
+}
+
 pub async fn fetch_calendar_events_between(
     client: &dyn IntervalsClient,
     start_date: &NaiveDate,
@@ -205,11 +284,7 @@ pub async fn fetch_calendar_events_between(
     let mut events = Vec::new();
 
     if *start_date <= today {
-        let days_back = (today - *start_date).num_days() as i32;
-        let mut historical = client
-            .get_events(Some(days_back), Some(limit))
-            .await
-            .map_err(|e| IntentError::api(format!("Failed to fetch events: {}", e)))?;
+        let mut historical = fetch_events_between(client, *start_date, today).await?;
         events.append(&mut historical);
     }
 
@@ -335,15 +410,81 @@ fn normalize_streams_payload(payload: Value) -> Value {
     payload
 }
 
+/// Fetch `temp` streams for activities whose detail suggests time above the heat
+/// threshold. Returns the streams keyed by activity id and the number of failed
+/// requests.
+pub async fn fetch_heat_streams(
+    client: &dyn IntervalsClient,
+    activity_details: &HashMap<String, Value>,
+    activity_ids: &[String],
+) -> (HashMap<String, Value>, usize) {
+    let mut streams = HashMap::new();
+    let mut failed = 0;
+    for id in activity_ids {
+        if !activity_details
+            .get(id)
+            .is_some_and(is_heat_stream_candidate)
+        {
+            continue;
+        }
+        match client
+            .get_activity_streams(id, Some(vec!["temp".to_string()]))
+            .await
+        {
+            Ok(payload) => {
+                streams.insert(id.clone(), normalize_streams_payload(payload));
+            }
+            Err(_) => failed += 1,
+        }
+    }
+    (streams, failed)
+}
+
+/// Heat-acclimation state on `as_of`, from the latest `MAX_HEAT_DETAIL_FETCHES`
+/// activities of the preceding `HEAT_HISTORY_DAYS` and the `temp` streams of up
+/// to `MAX_HEAT_STREAM_FETCHES` sessions near the heat threshold. Details come
+/// through the per-athlete detail cache, so recent reads are not repeated.
+pub async fn fetch_heat_acclimation(
+    client: &dyn IntervalsClient,
+    as_of: NaiveDate,
+) -> HeatAcclimationMetrics {
+    let mut activities =
+        fetch_activities_between(client, as_of - Duration::days(HEAT_HISTORY_DAYS), as_of)
+            .await
+            .unwrap_or_default();
+    activities.truncate(MAX_HEAT_DETAIL_FETCHES);
+    let details = fetch_cached_activity_details(client, &activities).await;
+    let dated_ids = activities
+        .iter()
+        .filter(|activity| details.contains_key(&activity.id))
+        .filter_map(|activity| {
+            Some((
+                parse_activity_date(&activity.start_date_local)?,
+                activity.id.clone(),
+            ))
+        })
+        .collect::<Vec<_>>();
+    let candidates = dated_ids
+        .iter()
+        .map(|(_, id)| id.clone())
+        .filter(|id| details.get(id).is_some_and(is_heat_stream_candidate))
+        .take(MAX_HEAT_STREAM_FETCHES)
+        .collect::<Vec<_>>();
+    let (streams, _) = fetch_heat_streams(client, &details, &candidates).await;
+    compute_heat_acclimation_from_details(&details, &streams, &dated_ids, as_of)
+}
+
 pub async fn fetch_period_data(
     client: &dyn IntervalsClient,
     request: &PeriodFetchRequest,
 ) -> Result<FetchedAnalysisData, IntentError> {
-    let days_back = ((request.window.end_date - request.window.start_date).num_days() as i32) + 30;
-    let activities = client
-        .get_recent_activities(Some(200), Some(days_back))
-        .await
-        .map_err(|e| IntentError::api(format!("Failed to fetch activities: {}", e)))?;
+    let today = chrono::Utc::now().date_naive();
+    let activities = fetch_activities_between(
+        client,
+        request.window.start_date - Duration::days(30),
+        today,
+    )
+    .await?;
 
     let known_activity_ids = activities
         .iter()
@@ -355,16 +496,11 @@ pub async fn fetch_period_data(
         ..Default::default()
     };
 
-    let today = chrono::Utc::now().date_naive();
     let mut calendar_events = Vec::new();
     let mut upcoming_workouts_payload: Option<Value> = None;
 
     if request.window.start_date <= today {
-        let days_back = (today - request.window.start_date).num_days() as i32;
-        let mut historical = client
-            .get_events(Some(days_back), Some(500))
-            .await
-            .map_err(|e| IntentError::api(format!("Failed to fetch events: {}", e)))?;
+        let mut historical = fetch_events_between(client, request.window.start_date, today).await?;
         calendar_events.append(&mut historical);
     }
 
@@ -470,6 +606,199 @@ pub async fn fetch_recovery_data(
     })
 }
 
+type ActivityDetailCache = Mutex<HashMap<(String, String), (Instant, Value)>>;
+
+/// Activity details per (athlete id, activity id), shared by the load-history
+/// reads of the injury-risk model and sRPE calibration and by heat acclimation.
+fn activity_detail_cache() -> &'static ActivityDetailCache {
+    static CACHE: OnceLock<ActivityDetailCache> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Drop a cached activity detail after the activity is edited.
+pub fn forget_cached_activity_detail(client: &dyn IntervalsClient, activity_id: &str) {
+    if let Some(athlete_id) = client.athlete_id() {
+        activity_detail_cache()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&(athlete_id.to_string(), activity_id.to_string()));
+    }
+}
+
+/// Details for `activities`, reusing those fetched for the same athlete within
+/// `ACTIVITY_DETAIL_TTL_SECS`. Detail failures are skipped.
+async fn fetch_cached_activity_details(
+    client: &dyn IntervalsClient,
+    activities: &[ActivitySummary],
+) -> HashMap<String, Value> {
+    let athlete_id = client.athlete_id().map(str::to_string);
+    let mut details = HashMap::new();
+    if let Some(athlete_id) = &athlete_id {
+        let mut cache = activity_detail_cache()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        cache
+            .retain(|_, (fetched_at, _)| fetched_at.elapsed().as_secs() < ACTIVITY_DETAIL_TTL_SECS);
+        for activity in activities {
+            if let Some((_, detail)) = cache.get(&(athlete_id.clone(), activity.id.clone())) {
+                details.insert(activity.id.clone(), detail.clone());
+            }
+        }
+    }
+    for activity in activities {
+        if details.contains_key(&activity.id) {
+            continue;
+        }
+        let Ok(detail) = client.get_activity_details(&activity.id).await else {
+            continue;
+        };
+        if let Some(athlete_id) = &athlete_id {
+            activity_detail_cache()
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(
+                    (athlete_id.clone(), activity.id.clone()),
+                    (Instant::now(), detail.clone()),
+                );
+        }
+        details.insert(activity.id.clone(), detail);
+    }
+    details
+}
+
+/// Fetch the `days` of activities ending on `end_date` (the latest `limit`)
+/// and their details for the per-channel injury-risk model. Details are
+/// shared through a short-lived per-athlete cache; failures are skipped so the
+/// model degrades gracefully.
+pub async fn fetch_load_channel_history(
+    client: &dyn IntervalsClient,
+    end_date: NaiveDate,
+    days: i64,
+    limit: usize,
+) -> Result<FetchedAnalysisData, IntentError> {
+    let mut activities = client
+        .get_activities_between(end_date - Duration::days(days - 1), end_date)
+        .await
+        .map_err(|e| IntentError::api(format!("Failed to fetch activities: {}", e)))?;
+    activities.sort_by(|a, b| b.start_date_local.cmp(&a.start_date_local));
+    activities.truncate(limit);
+
+    let activity_details = fetch_cached_activity_details(client, &activities).await;
+
+    Ok(FetchedAnalysisData {
+        activities,
+        activity_details,
+        ..Default::default()
+    })
+}
+
+/// History calibrations per athlete id, so repeated calls within
+/// `SRPE_CALIBRATION_TTL_SECS` do not refetch the calibration window.
+fn srpe_calibration_cache() -> &'static Mutex<HashMap<String, (Instant, SrpeCalibration)>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, (Instant, SrpeCalibration)>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// The athlete's sRPE → TSS calibration, computed once per call so every load
+/// figure uses the same factor whatever subset of details it reads. Details the
+/// caller already holds are used when they pair enough RPE and sensor load;
+/// otherwise the last `SRPE_CALIBRATION_DAYS` of sessions are fetched, cached per
+/// athlete. Falls back to the default factor when history is unavailable.
+pub async fn fetch_srpe_calibration<'a>(
+    client: &dyn IntervalsClient,
+    in_hand: impl IntoIterator<Item = &'a Value>,
+) -> SrpeCalibration {
+    let from_details = calibrate_srpe_to_tss(in_hand);
+    if from_details.calibrated {
+        return from_details;
+    }
+    let athlete_id = client.athlete_id().map(str::to_string);
+    if let Some(athlete_id) = &athlete_id {
+        let cache = srpe_calibration_cache()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some((fetched_at, calibration)) = cache.get(athlete_id)
+            && fetched_at.elapsed().as_secs() < SRPE_CALIBRATION_TTL_SECS
+        {
+            return *calibration;
+        }
+    }
+    let Ok(history) = fetch_load_channel_history(
+        client,
+        chrono::Utc::now().date_naive(),
+        SRPE_CALIBRATION_DAYS,
+        SRPE_CALIBRATION_ACTIVITY_LIMIT,
+    )
+    .await
+    else {
+        return SrpeCalibration::default();
+    };
+    let calibration = calibrate_srpe_to_tss(history.activity_details.values());
+    if let Some(athlete_id) = athlete_id {
+        srpe_calibration_cache()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(athlete_id, (Instant::now(), calibration));
+    }
+    calibration
+}
+
+/// Fetch all-time, season and rolling-90-day curves for a sport and read the
+/// record book from them. A curve that fails to load leaves its window empty.
+pub async fn fetch_record_book(
+    client: &dyn IntervalsClient,
+    sport: &str,
+    today: NaiveDate,
+) -> RecordBook {
+    let mut rows = Vec::new();
+    for &metric in sport_metrics(sport) {
+        let mut curves = Vec::new();
+        for window in [
+            RecordWindow::AllTime,
+            RecordWindow::Season,
+            RecordWindow::Rolling90,
+        ] {
+            let days = Some(window_days(window, today));
+            let curve = match metric {
+                RecordMetric::Power => client.get_power_curves(days, sport).await,
+                RecordMetric::Pace => client.get_pace_curves(days, sport).await,
+            };
+            curves.push(curve.ok());
+        }
+        rows.extend(build_record_rows(
+            metric,
+            curves[0].as_ref(),
+            curves[1].as_ref(),
+            curves[2].as_ref(),
+        ));
+    }
+    RecordBook {
+        sport: sport.to_string(),
+        rows,
+    }
+}
+
+/// Fetch the curves `kinds` are read from over `oldest..=newest`. Returns the
+/// curves that loaded and how many requests failed.
+pub async fn fetch_effort_curves(
+    client: &dyn IntervalsClient,
+    kinds: impl IntoIterator<Item = BestEffortKind>,
+    oldest: NaiveDate,
+    newest: NaiveDate,
+) -> (EffortCurves, usize) {
+    let mut curves = EffortCurves::new();
+    let mut failed = 0;
+    for (kind, sport) in effort_curve_requests(kinds) {
+        match client.get_curves_between(kind, sport, oldest, newest).await {
+            Ok(payload) => {
+                curves.insert((kind, sport), payload);
+            }
+            Err(_) => failed += 1,
+        }
+    }
+    (curves, failed)
+}
+
 pub async fn fetch_single_workout_data(
     client: &dyn IntervalsClient,
     request: &SingleWorkoutFetchRequest,
@@ -662,7 +991,7 @@ mod tests {
             ("a2".to_string(), json!({"icu_training_load": 70.0})),
         ]);
 
-        let series = build_daily_load_series(&refs, &details, &window);
+        let series = build_daily_load_series(&refs, &details, &window, &SrpeCalibration::default());
 
         assert_eq!(series.len(), 4);
         assert_eq!(series[0].1, 50.0);
@@ -671,6 +1000,51 @@ mod tests {
         assert_eq!(series[3].1, 0.0);
     }
 
+    #[test]
+    fn daily_load_series_blends_calibrated_session_rpe_load() {
+        let window = AnalysisWindow::new(
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 3, 6).unwrap(),
+        );
+        let ids = ["r1", "r2", "r3", "r4", "r5", "s1"];
+        let activities = ids
+            .iter()
+            .enumerate()
+            .map(|(day, id)| activity(id, &format!("2026-03-0{}", day + 1)))
+            .collect::<Vec<_>>();
+        let refs = activities.iter().collect::<Vec<_>>();
+        let mut details = HashMap::new();
+        for id in &ids[..5] {
+            // 60 load for RPE 4 × 60 min (240 AU): factor 0.25
+            details.insert(
+                id.to_string(),
+                json!({"icu_training_load": 60.0, "average_heartrate": 140.0, "icu_rpe": 4, "moving_time": 3600}),
+            );
+        }
+        details.insert(
+            "s1".to_string(),
+            json!({"type": "WeightTraining", "icu_rpe": 8, "moving_time": 2700}),
+        );
+
+        let calibration = calibrate_srpe_to_tss(details.values());
+        let series = build_daily_load_series(&refs, &details, &window, &calibration);
+
+        assert_eq!(series[0].1, 60.0);
+        assert!((series[5].1 - 90.0).abs() < 1e-9);
+        // The factor comes from the caller's calibration, not the details at hand.
+        let fixed = SrpeCalibration {
+            factor: 0.5,
+            sessions: 12,
+            calibrated: true,
+        };
+        let series = build_daily_load_series(&refs, &details, &window, &fixed);
+        assert!((series[5].1 - 180.0).abs() < 1e-9);
+        assert_eq!(
+            estimated_load_days(&activities, &details),
+            vec![NaiveDate::from_ymd_opt(2026, 3, 6).unwrap()]
+        );
+    }
+
     #[test]
     fn extract_activity_load_prefers_canonical_load_over_moving_time_proxy() {
         let detail = json!({
@@ -704,7 +1078,7 @@ mod tests {
             ("a2".to_string(), json!({"icu_training_load": 40.0})),
         ]);
 
-        let series = build_daily_load_series(&refs, &details, &window);
+        let series = build_daily_load_series(&refs, &details, &window, &SrpeCalibration::default());
 
         assert_eq!(series[0].1, 75.0);
         assert_eq!(series[1].1, 0.0);
@@ -720,6 +1094,7 @@ mod tests {
                 category: intervals_icu_client::EventCategory::Injured,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: Some("e1".into()),
@@ -728,6 +1103,7 @@ mod tests {
                 category: intervals_icu_client::EventCategory::RaceA,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: Some("e1".into()),
@@ -736,6 +1112,7 @@ mod tests {
                 category: intervals_icu_client::EventCategory::RaceA,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
         ];
 
@@ -1095,6 +1472,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: Some("e1".to_string()),
@@ -1103,6 +1481,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
         ];
         let result = dedupe_and_sort_events(events);
@@ -1120,6 +1499,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: Some("e1".to_string()),
@@ -1128,6 +1508,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
         ];
         let result = dedupe_and_sort_events(events);
@@ -1146,6 +1527,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: None,
@@ -1154,6 +1536,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
         ];
         let result = dedupe_and_sort_events(events);
@@ -1466,6 +1849,106 @@ mod tests {
         assert_eq!(activity.id, "event:event_1");
     }
 
+    #[tokio::test]
+    async fn fetch_activities_between_downloads_a_long_history_once() {
+        let today = chrono::Utc::now().date_naive();
+        let client = MockIntervalsClient::builder().with_activities(vec![
+            activity("old", &(today - Duration::days(400)).to_string()),
+            activity("a1", &(today - Duration::days(300)).to_string()),
+            activity("a2", &(today - Duration::days(10)).to_string()),
+        ]);
+        let observations = client.observations();
+
+        let activities = fetch_activities_between(&client, today - Duration::days(365), today)
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = activities.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, ["a2", "a1"]);
+        assert_eq!(observations.recent_activity_calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn load_channel_history_ends_at_end_date_and_reuses_cached_details() {
+        let today = chrono::Utc::now().date_naive();
+        let end_date = today - Duration::days(10);
+        let client = MockIntervalsClient::builder()
+            .with_athlete_id("load-history-athlete")
+            .with_activities(vec![
+                activity("after", &(today - Duration::days(2)).to_string()),
+                activity("inside", &(end_date - Duration::days(3)).to_string()),
+                activity("before", &(end_date - Duration::days(45)).to_string()),
+            ]);
+        let observations = client.observations();
+
+        let history = fetch_load_channel_history(&client, end_date, 42, 60)
+            .await
+            .unwrap();
+        let ids: Vec<_> = history.activities.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, ["inside"]);
+        assert!(history.activity_details.contains_key("inside"));
+        assert_eq!(observations.activity_detail_calls(), 1);
+
+        // A second read reuses the cached detail until the activity is edited.
+        fetch_load_channel_history(&client, end_date, 42, 60)
+            .await
+            .unwrap();
+        assert_eq!(observations.activity_detail_calls(), 1);
+        forget_cached_activity_detail(&client, "inside");
+        fetch_load_channel_history(&client, end_date, 42, 60)
+            .await
+            .unwrap();
+        assert_eq!(observations.activity_detail_calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn heat_acclimation_reads_a_bounded_set_of_recent_sessions() {
+        let today = chrono::Utc::now().date_naive();
+        let activities = (1..=20)
+            .map(|day| {
+                activity(
+                    &format!("h{day}"),
+                    &(today - Duration::days(day)).to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let client = MockIntervalsClient::builder()
+            .with_activities(activities)
+            .with_workout_detail(json!({"average_temp": 31.0, "moving_time": 3600}));
+        let observations = client.observations();
+
+        let acclimation = fetch_heat_acclimation(&client, today).await;
+
+        assert!(acclimation.supported);
+        assert_eq!(
+            observations.activity_detail_calls(),
+            MAX_HEAT_DETAIL_FETCHES
+        );
+    }
+
+    #[tokio::test]
+    async fn srpe_calibration_uses_details_in_hand_before_history() {
+        let paired = (0..5)
+            .map(|_| {
+                json!({"icu_rpe": 5, "moving_time": 3600, "average_heartrate": 140,
+                            "icu_training_load": 60})
+            })
+            .collect::<Vec<_>>();
+        let client = MockIntervalsClient::builder();
+        let observations = client.observations();
+
+        let calibration = fetch_srpe_calibration(&client, &paired).await;
+        assert!(calibration.calibrated);
+        assert_eq!(calibration.sessions, 5);
+        assert!((calibration.factor - 0.2).abs() < 1e-9);
+        assert_eq!(observations.recent_activity_calls(), 0);
+
+        // Too few paired sessions in hand: the history window is fetched instead.
+        let calibration = fetch_srpe_calibration(&client, &paired[..2]).await;
+        assert!(!calibration.calibrated);
+        assert_eq!(observations.recent_activity_calls(), 1);
+    }
+
     #[tokio::test]
     async fn fetch_period_data_reuses_single_upcoming_fetch_for_calendar_and_planned_workouts() {
         let today = chrono::Utc::now().date_naive();
@@ -4,7 +4,10 @@ use crate::domains::coach::{
     WorkoutMetricsContext,
 };
 use crate::engines::coach_metrics_constants::*;
+use crate::engines::menstrual_cycle::{deviation_explained_by_phase, expected_shift, latest_phase};
+use crate::engines::session_rpe::{SrpeCalibration, effective_session_load};
 use intervals_icu_client::ActivitySummary;
+use intervals_icu_client::domains::wellness::WellnessEntry;
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -523,6 +526,23 @@ pub fn parse_fitness_metrics(payload: Option<&Value>) -> Option<FitnessMetrics>
     Some(interpret_fitness_metrics(ctl, atl, tsb, ramp_rate))
 }
 
+/// Fitness metrics from a typed wellness day; form is derived from CTL and ATL.
+#[must_use]
+pub fn fitness_metrics_from_wellness(entry: &WellnessEntry) -> FitnessMetrics {
+    interpret_fitness_metrics(entry.ctl, entry.atl, entry.form(), entry.ramp_rate)
+}
+
+/// ACWR from a typed wellness day: the API's acute/chronic loads when present,
+/// otherwise ATL over CTL.
+#[must_use]
+pub fn acwr_from_wellness(entry: &WellnessEntry) -> Option<AcwrMetrics> {
+    let (acute, chronic) = entry
+        .atl_load
+        .zip(entry.ctl_load)
+        .or_else(|| entry.atl.zip(entry.ctl))?;
+    build_acwr_metrics(acute, chronic)
+}
+
 pub fn parse_wellness_metrics(payload: Option<&Value>) -> Option<WellnessMetrics> {
     let entries = payload?.as_array()?;
     if entries.is_empty() {
@@ -556,9 +576,24 @@ pub fn parse_wellness_metrics(payload: Option<&Value>) -> Option<WellnessMetrics
     let avg_sleep_hours = average(&sleep_values);
     let avg_resting_hr = average(&rhr_values);
     let resting_hr_baseline = average(&baseline_rhr_values);
-    let hrv_trend_state = classify_hrv_trend_state(hrv_deviation_pct);
+
+    // Compare against the baseline the cycle phase predicts (luteal RHR rise, HRV dip).
+    let cycle_phase = latest_phase(recent_entries);
+    let shift = cycle_phase.map(expected_shift).unwrap_or_default();
+    let hrv_reference = hrv_baseline.map(|baseline| baseline * (1.0 + shift.hrv_pct / 100.0));
+    let resting_hr_reference = resting_hr_baseline.map(|baseline| baseline + shift.rhr_bpm);
+    let cycle_explained_deviation = deviation_explained_by_phase(
+        shift,
+        hrv_deviation_pct,
+        avg_resting_hr
+            .zip(resting_hr_baseline)
+            .map(|(current, baseline)| current - baseline),
+    );
+
+    let hrv_trend_state =
+        classify_hrv_trend_state(hrv_deviation_pct.map(|deviation| deviation - shift.hrv_pct));
     let recovery_index = avg_hrv.zip(avg_resting_hr).and_then(|(hrv, resting_hr)| {
-        compute_recovery_index(hrv, resting_hr, hrv_baseline, resting_hr_baseline)
+        compute_recovery_index(hrv, resting_hr, hrv_reference, resting_hr_reference)
     });
 
     let readiness_values = collect_numbers(recent_entries, READINESS_KEYS);
@@ -575,21 +610,28 @@ pub fn parse_wellness_metrics(payload: Option<&Value>) -> Option<WellnessMetrics
     let hrv_ratio = avg_hrv
         .zip(hrv_baseline)
         .and_then(|(current, baseline)| compute_hrv_ratio(current, baseline));
-    let (hrv_suppression_flag, hrv_recovery_flag) =
-        hrv_ratio.map(classify_hrv_state).unwrap_or((false, false));
+    let phase_adjusted_hrv_ratio = avg_hrv
+        .zip(hrv_reference)
+        .and_then(|(current, reference)| compute_hrv_ratio(current, reference));
+    let (hrv_suppression_flag, hrv_recovery_flag) = phase_adjusted_hrv_ratio
+        .map(classify_hrv_state)
+        .unwrap_or((false, false));
     let hrv_trend_slope = if hrv_values.len() >= HRV_TREND_MIN_VALUES {
         compute_hrv_trend_slope(&hrv_values)
     } else {
         None
     };
-    let recovery_quality_index = hrv_ratio.zip(avg_resting_hr).and_then(|(ratio, rhr)| {
-        compute_recovery_quality_index(
-            ratio,
-            resting_hr_baseline.unwrap_or(rhr),
-            rhr,
-            avg_sleep_hours.unwrap_or(WELLNESS_DEFAULT_SLEEP_HOURS),
-        )
-    });
+    let recovery_quality_index =
+        phase_adjusted_hrv_ratio
+            .zip(avg_resting_hr)
+            .and_then(|(ratio, rhr)| {
+                compute_recovery_quality_index(
+                    ratio,
+                    resting_hr_reference.unwrap_or(rhr),
+                    rhr,
+                    avg_sleep_hours.unwrap_or(WELLNESS_DEFAULT_SLEEP_HOURS),
+                )
+            });
 
     Some(WellnessMetrics {
         avg_sleep_hours,
@@ -610,6 +652,10 @@ pub fn parse_wellness_metrics(payload: Option<&Value>) -> Option<WellnessMetrics
         hrv_recovery_flag,
         hrv_trend_slope,
         recovery_quality_index,
+        cycle_phase: cycle_phase.map(|phase| phase.as_str().to_string()),
+        cycle_hrv_shift_pct: (!shift.is_zero()).then_some(shift.hrv_pct),
+        cycle_rhr_shift_bpm: (!shift.is_zero()).then_some(shift.rhr_bpm),
+        cycle_explained_deviation,
     })
 }
 
@@ -757,7 +803,7 @@ fn percent_delta(previous: f64, current: f64) -> Option<f64> {
     }
 }
 
-fn get_number(object: &serde_json::Map<String, Value>, keys: &[&str]) -> Option<f64> {
+pub(crate) fn get_number(object: &serde_json::Map<String, Value>, keys: &[&str]) -> Option<f64> {
     keys.iter().find_map(|key| {
         object
             .get(*key)
@@ -765,6 +811,13 @@ fn get_number(object: &serde_json::Map<String, Value>, keys: &[&str]) -> Option<
     })
 }
 
+/// [`get_number`] on a JSON value; `None` when the value is not an object.
+pub(crate) fn value_number(value: &Value, keys: &[&str]) -> Option<f64> {
+    value
+        .as_object()
+        .and_then(|object| get_number(object, keys))
+}
+
 fn collect_numbers(entries: &[Value], keys: &[&str]) -> Vec<f64> {
     entries
         .iter()
@@ -773,7 +826,7 @@ fn collect_numbers(entries: &[Value], keys: &[&str]) -> Vec<f64> {
         .collect()
 }
 
-fn extract_numeric_stream(streams: &Value, keys: &[&str]) -> Option<Vec<f64>> {
+pub fn extract_numeric_stream(streams: &Value, keys: &[&str]) -> Option<Vec<f64>> {
     let object = streams.as_object()?;
     let values = keys
         .iter()
@@ -1232,6 +1285,7 @@ pub fn compute_wdr_7d_rollup(
 pub fn compute_ndli_7d(
     activity_details: &std::collections::HashMap<String, Value>,
     activity_ids: &[String],
+    calibration: &SrpeCalibration,
 ) -> NdliMetrics {
     let mut high_intensity_days: usize = 0;
     let mut if_values: Vec<f64> = Vec::new();
@@ -1240,7 +1294,10 @@ pub fn compute_ndli_7d(
     let mut days_with_data: usize = 0;
 
     for id in activity_ids {
-        let Some(detail) = activity_details.get(id).and_then(Value::as_object) else {
+        let Some(raw_detail) = activity_details.get(id) else {
+            continue;
+        };
+        let Some(detail) = raw_detail.as_object() else {
             continue;
         };
         days_with_data += 1;
@@ -1252,7 +1309,10 @@ pub fn compute_ndli_7d(
         } else {
             // Fallback for running: icu_training_load > 80 TSS/day
             // Scaled relative to typical CTL: 80 TSS ≈ ~1.2× CTL for moderate athletes.
-            get_number(detail, &["icu_training_load", "training_load", "tss"])
+            // Sessions without power/HR use the calibrated session-RPE estimate.
+            effective_session_load(raw_detail, calibration.factor)
+                .map(|(load, _)| load)
+                .or_else(|| get_number(detail, &["tss"]))
                 .map(|load| load > NDLI_RUNNING_TSS_PROXY_THRESHOLD)
                 .unwrap_or(false)
         };
@@ -1526,6 +1586,36 @@ mod tests {
         assert_eq!(snapshot.total_elevation_m, 350.0);
     }
 
+    #[test]
+    fn fitness_metrics_from_wellness_derives_form() {
+        let entry: WellnessEntry = serde_json::from_value(
+            json!({"id": "2026-03-01", "ctl": 50.0, "atl": 65.0, "rampRate": 4.2}),
+        )
+        .unwrap();
+
+        let metrics = fitness_metrics_from_wellness(&entry);
+        assert_eq!(metrics.ctl, Some(50.0));
+        assert_eq!(metrics.tsb, Some(-15.0));
+        assert_eq!(metrics.ramp_rate, Some(4.2));
+    }
+
+    #[test]
+    fn acwr_from_wellness_prefers_api_loads_over_fitness() {
+        let with_loads: WellnessEntry = serde_json::from_value(json!({
+            "id": "2026-03-01", "ctl": 50.0, "atl": 75.0, "atlLoad": 60.0, "ctlLoad": 40.0
+        }))
+        .unwrap();
+        let acwr = acwr_from_wellness(&with_loads).unwrap();
+        assert!((acwr.ratio - 1.5).abs() < 1e-9);
+
+        let fitness_only: WellnessEntry =
+            serde_json::from_value(json!({"id": "2026-03-01", "ctl": 50.0, "atl": 75.0})).unwrap();
+        assert!((acwr_from_wellness(&fitness_only).unwrap().ratio - 1.5).abs() < 1e-9);
+
+        let empty: WellnessEntry = serde_json::from_value(json!({"id": "2026-03-01"})).unwrap();
+        assert!(acwr_from_wellness(&empty).is_none());
+    }
+
     #[test]
     fn parse_fitness_metrics_supports_summary_payload() {
         let payload = json!([{"fitness": 50.0, "fatigue": 70.0, "form": -20.0}]);
@@ -1577,6 +1667,57 @@ mod tests {
         assert_eq!(metrics.hrv_trend_state.as_deref(), Some("suppressed"));
     }
 
+    #[test]
+    fn parse_wellness_metrics_adjusts_baseline_for_luteal_phase() {
+        let mut entries = Vec::new();
+        entries.extend((0..28).map(|_| wellness_entry(28_800.0, 50.0, 80.0)));
+        entries.extend((0..7).map(|_| {
+            let mut entry = wellness_entry(28_800.0, 52.0, 75.0);
+            entry["menstrualPhase"] = json!("LUTEAL");
+            entry
+        }));
+
+        let metrics = parse_wellness_metrics(Some(&Value::Array(entries))).unwrap();
+
+        assert_eq!(metrics.cycle_phase.as_deref(), Some("luteal"));
+        assert_eq!(metrics.cycle_hrv_shift_pct, Some(-6.0));
+        assert_eq!(metrics.cycle_rhr_shift_bpm, Some(2.5));
+        // Raw deviation is -6.25%: "below_range" without the phase, normal with it.
+        assert_eq!(metrics.hrv_deviation_pct, Some(-6.3));
+        assert_eq!(metrics.hrv_trend_state.as_deref(), Some("within_range"));
+        assert!(metrics.cycle_explained_deviation);
+        assert!(!metrics.hrv_suppression_flag);
+    }
+
+    #[test]
+    fn parse_wellness_metrics_luteal_phase_does_not_explain_large_hrv_drop() {
+        let mut entries = Vec::new();
+        entries.extend((0..28).map(|_| wellness_entry(28_800.0, 50.0, 80.0)));
+        entries.extend((0..7).map(|_| {
+            let mut entry = wellness_entry(28_800.0, 52.0, 60.0);
+            entry["menstrualPhase"] = json!("LUTEAL");
+            entry
+        }));
+
+        let metrics = parse_wellness_metrics(Some(&Value::Array(entries))).unwrap();
+
+        // RHR +2 bpm fits the luteal shift, HRV -25% does not.
+        assert_eq!(metrics.hrv_deviation_pct, Some(-25.0));
+        assert!(!metrics.cycle_explained_deviation);
+        assert!(metrics.hrv_suppression_flag);
+    }
+
+    #[test]
+    fn parse_wellness_metrics_without_phase_has_no_cycle_context() {
+        let entries = (0..10)
+            .map(|_| wellness_entry(28_800.0, 50.0, 80.0))
+            .collect::<Vec<_>>();
+        let metrics = parse_wellness_metrics(Some(&Value::Array(entries))).unwrap();
+        assert!(metrics.cycle_phase.is_none());
+        assert!(metrics.cycle_hrv_shift_pct.is_none());
+        assert!(!metrics.cycle_explained_deviation);
+    }
+
     #[test]
     fn parse_api_load_snapshot_supports_wellness_load_fields() {
         let payload = json!({"atlLoad": 432.0, "ctlLoad": 360.0});
@@ -2265,7 +2406,7 @@ mod tests {
 
     #[test]
     fn compute_ndli_7d_empty_returns_not_supported() {
-        let metrics = compute_ndli_7d(&HashMap::new(), &[]);
+        let metrics = compute_ndli_7d(&HashMap::new(), &[], &SrpeCalibration::default());
         assert!(!metrics.supported);
     }
 
@@ -2275,6 +2416,16 @@ mod tests {
         assert!(!metrics.supported);
     }
 
+    #[test]
+    fn compute_ndli_7d_counts_hard_session_rpe_days() {
+        let details = HashMap::from([(
+            "s1".to_string(),
+            serde_json::json!({"type": "WeightTraining", "icu_rpe": 9, "moving_time": 3600}),
+        )]);
+        let metrics = compute_ndli_7d(&details, &["s1".to_string()], &SrpeCalibration::default());
+        assert_eq!(metrics.high_intensity_days_7d, 1);
+    }
+
     #[test]
     fn compute_acwr_empty_returns_none() {
         assert!(compute_acwr(&[]).is_none());
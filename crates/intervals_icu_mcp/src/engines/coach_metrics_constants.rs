@@ -25,6 +25,8 @@ pub const EFFICIENCY_FACTOR_KEYS: &[&str] = &["icu_efficiency_factor", "efficien
 pub const AEROBIC_DECOUPLING_KEYS: &[&str] = &["decoupling", "aerobic_decoupling"];
 pub const HR_STREAM_KEYS: &[&str] = &["heartrate", "heart_rate", "hr"];
 pub const OUTPUT_STREAM_KEYS: &[&str] = &["watts", "velocity_smooth", "pace"];
+/// Beat-to-beat RR interval streams (ms).
+pub const RR_STREAM_KEYS: &[&str] = &["rr", "hrv", "rr_intervals"];
 pub const MONOTONY_STDDEV_FLOOR_RATIO: f64 = 0.1;
 pub const MONOTONY_CAP: f64 = 10.0;
 
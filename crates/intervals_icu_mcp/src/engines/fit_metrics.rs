@@ -0,0 +1,333 @@
+//! Metrics computed from a decoded FIT file.
+//! The `/streams` endpoint is downsampled and has no RR intervals or developer
+//! fields. The original recording gives per-second power for normalized power,
+//! beat-to-beat intervals for DFA α1, and CORE body temperature for
+//! thermal drift.
+
+use intervals_icu_client::fit::FitActivity;
+use serde::{Deserialize, Serialize};
+
+use crate::engines::threshold_estimation::{DFA_ALPHA1_AET, clean_rr, compute_dfa_alpha1};
+
+/// Rolling window length and step (s) for the α1 time series. Artifact rules,
+/// box sizes and the 0.75 threshold are shared with `threshold_estimation`.
+pub const DFA_WINDOW_SECS: f64 = 120.0;
+const DFA_STEP_SECS: f64 = 30.0;
+
+/// Gaps in the recording up to this many seconds are filled with the last value.
+const MAX_FILL_GAP_SECS: i64 = 5;
+/// Rolling window (s) for normalized power.
+const NP_WINDOW_SECS: usize = 30;
+/// Shortest core temperature recording (s) a drift is reported for.
+const MIN_CORE_DRIFT_SECS: f64 = 600.0;
+/// Developer stream key CORE sensors use for body temperature.
+const CORE_TEMPERATURE_KEY: &str = "core_temperature";
+
+/// One DFA α1 estimate over a rolling time window of the RR recording.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DfaTimeWindow {
+    /// Seconds from the first beat to the window start.
+    pub start_secs: f64,
+    pub alpha1: f64,
+}
+
+/// Summary of the metrics available in a FIT file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FitFileMetrics {
+    pub records: usize,
+    /// Seconds between the first and last record.
+    pub duration_secs: f64,
+    /// Median seconds between consecutive records (1.0 for per-second recording).
+    pub sample_interval_secs: Option<f64>,
+    pub avg_power: Option<f64>,
+    pub normalized_power: Option<f64>,
+    pub avg_heart_rate: Option<f64>,
+    pub rr_beats: usize,
+    /// Share of RR intervals dropped as artifacts.
+    pub rr_artifact_fraction: Option<f64>,
+    pub dfa_alpha1: Vec<DfaTimeWindow>,
+    pub core_temperature_start: Option<f64>,
+    pub core_temperature_peak: Option<f64>,
+    /// Linear trend of core temperature in °C per hour.
+    pub core_temperature_drift_per_hour: Option<f64>,
+    /// Developer stream keys present in the records.
+    pub developer_streams: Vec<String>,
+    /// Product names of the recording device and paired sensors.
+    pub devices: Vec<String>,
+}
+
+impl FitFileMetrics {
+    #[must_use]
+    pub fn mean_alpha1(&self) -> Option<f64> {
+        mean(self.dfa_alpha1.iter().map(|window| window.alpha1))
+    }
+
+    /// Seconds of RR windows with α1 below [`DFA_ALPHA1_AET`].
+    #[must_use]
+    pub fn secs_below_aerobic_threshold(&self) -> f64 {
+        self.dfa_alpha1
+            .iter()
+            .filter(|window| window.alpha1 < DFA_ALPHA1_AET)
+            .count() as f64
+            * DFA_STEP_SECS
+    }
+}
+
+/// Compute the FIT-only metrics for a decoded activity.
+#[must_use]
+pub fn fit_file_metrics(activity: &FitActivity) -> FitFileMetrics {
+    let timestamps = activity
+        .records
+        .iter()
+        .filter_map(|record| record.timestamp)
+        .collect::<Vec<_>>();
+    let duration_secs = match (timestamps.first(), timestamps.last()) {
+        (Some(first), Some(last)) => (last - first) as f64,
+        _ => 0.0,
+    };
+    let mut intervals = timestamps
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]) as f64)
+        .filter(|interval| *interval > 0.0)
+        .collect::<Vec<_>>();
+    intervals.sort_by(f64::total_cmp);
+
+    let power = per_second(activity, |record| record.power);
+    let clean_rr = clean_rr(&activity.rr_intervals);
+    let core = activity
+        .records
+        .iter()
+        .filter_map(|record| {
+            Some((
+                record.timestamp? as f64,
+                *record.developer.get(CORE_TEMPERATURE_KEY)?,
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    FitFileMetrics {
+        records: activity.records.len(),
+        duration_secs,
+        sample_interval_secs: intervals.get(intervals.len() / 2).copied(),
+        avg_power: mean(power.iter().copied()),
+        normalized_power: normalized_power(&power),
+        avg_heart_rate: mean(
+            activity
+                .records
+                .iter()
+                .filter_map(|record| record.heart_rate),
+        ),
+        rr_beats: activity.rr_intervals.len(),
+        rr_artifact_fraction: (!activity.rr_intervals.is_empty())
+            .then(|| 1.0 - clean_rr.len() as f64 / activity.rr_intervals.len() as f64),
+        dfa_alpha1: dfa_alpha1_windows(&clean_rr, DFA_WINDOW_SECS, DFA_STEP_SECS),
+        core_temperature_start: core.first().map(|(_, temp)| *temp),
+        core_temperature_peak: core.iter().map(|(_, temp)| *temp).reduce(f64::max),
+        core_temperature_drift_per_hour: core_temperature_drift(&core),
+        developer_streams: activity
+            .developer_fields
+            .iter()
+            .map(|field| field.stream_key())
+            .filter(|key| {
+                activity
+                    .records
+                    .iter()
+                    .any(|record| record.developer.contains_key(key))
+            })
+            .collect(),
+        devices: activity
+            .devices
+            .iter()
+            .filter_map(|device| device.product_name.clone())
+            .collect(),
+    }
+}
+
+/// α1 over rolling windows of `window_secs`, advancing `step_secs` at a time.
+#[must_use]
+pub fn dfa_alpha1_windows(rr_ms: &[f64], window_secs: f64, step_secs: f64) -> Vec<DfaTimeWindow> {
+    let beat_times = rr_ms
+        .iter()
+        .scan(0.0, |elapsed, rr| {
+            let start = *elapsed;
+            *elapsed += rr / 1000.0;
+            Some(start)
+        })
+        .collect::<Vec<_>>();
+    let Some(&last) = beat_times.last() else {
+        return Vec::new();
+    };
+
+    let mut windows = Vec::new();
+    let mut start_secs = 0.0;
+    while start_secs + window_secs <= last {
+        let from = beat_times.partition_point(|time| *time < start_secs);
+        let to = beat_times.partition_point(|time| *time < start_secs + window_secs);
+        if let Some(alpha1) = compute_dfa_alpha1(&rr_ms[from..to]) {
+            windows.push(DfaTimeWindow { start_secs, alpha1 });
+        }
+        start_secs += step_secs;
+    }
+    windows
+}
+
+/// Core temperature trend in °C per hour from `(unix seconds, °C)` samples.
+#[must_use]
+pub fn core_temperature_drift(samples: &[(f64, f64)]) -> Option<f64> {
+    let (first, last) = (samples.first()?.0, samples.last()?.0);
+    if last - first < MIN_CORE_DRIFT_SECS {
+        return None;
+    }
+    slope(samples).map(|per_second| per_second * 3600.0)
+}
+
+/// Normalized power of a per-second power series.
+#[must_use]
+pub fn normalized_power(watts: &[f64]) -> Option<f64> {
+    if watts.len() < NP_WINDOW_SECS {
+        return None;
+    }
+    let rolling = watts
+        .windows(NP_WINDOW_SECS)
+        .map(|window| (window.iter().sum::<f64>() / NP_WINDOW_SECS as f64).powi(4));
+    mean(rolling).map(|fourth| fourth.powf(0.25))
+}
+
+/// One value per second, carrying the last sample across short gaps.
+/// Invalid samples count as 0 (coasting); longer gaps (pauses) are skipped.
+fn per_second(
+    activity: &FitActivity,
+    sample: impl Fn(&intervals_icu_client::fit::FitRecord) -> Option<f64>,
+) -> Vec<f64> {
+    let mut series = Vec::new();
+    let mut previous: Option<(i64, f64)> = None;
+    for record in &activity.records {
+        let Some(timestamp) = record.timestamp else {
+            continue;
+        };
+        let value = sample(record).unwrap_or(0.0);
+        if let Some((last_time, last_value)) = previous {
+            let gap = timestamp - last_time;
+            if gap <= 0 {
+                continue;
+            }
+            if gap <= MAX_FILL_GAP_SECS {
+                series.extend(std::iter::repeat_n(last_value, (gap - 1) as usize));
+            }
+        }
+        series.push(value);
+        previous = Some((timestamp, value));
+    }
+    if series.iter().all(|value| *value == 0.0) {
+        series.clear();
+    }
+    series
+}
+
+fn linear_fit(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+    let n = points.len() as f64;
+    if points.len() < 2 {
+        return None;
+    }
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let sxx = points
+        .iter()
+        .map(|(x, _)| (x - mean_x).powi(2))
+        .sum::<f64>();
+    if sxx.abs() < f64::EPSILON {
+        return None;
+    }
+    let sxy = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum::<f64>();
+    let b = sxy / sxx;
+    Some((mean_y - b * mean_x, b))
+}
+
+fn slope(points: &[(f64, f64)]) -> Option<f64> {
+    linear_fit(points).map(|(_, b)| b)
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, count), value| {
+        (sum + value, count + 1)
+    });
+    (count > 0).then(|| sum / count as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use intervals_icu_client::fit::FitRecord;
+
+    /// Deterministic uniform noise in [-1, 1).
+    fn noise(count: usize) -> Vec<f64> {
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        (0..count)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                (state >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dfa_windows_roll_over_the_recording() {
+        let rr = noise(1_000)
+            .into_iter()
+            .map(|n| 600.0 + 20.0 * n)
+            .collect::<Vec<_>>();
+        // 1000 beats at ~0.6 s = ~600 s: windows start every 30 s up to ~480 s.
+        let windows = dfa_alpha1_windows(&rr, DFA_WINDOW_SECS, DFA_STEP_SECS);
+        assert!((15..=17).contains(&windows.len()), "{}", windows.len());
+        assert_eq!(windows[1].start_secs, 30.0);
+    }
+
+    #[test]
+    fn core_drift_is_reported_per_hour_for_long_enough_recordings() {
+        let samples = (0..=1_200)
+            .map(|i| (f64::from(i), 37.5 + f64::from(i) / 3600.0))
+            .collect::<Vec<_>>();
+        let drift = core_temperature_drift(&samples).unwrap();
+        assert!((drift - 1.0).abs() < 1e-9);
+        assert_eq!(core_temperature_drift(&samples[..300]), None);
+    }
+
+    #[test]
+    fn normalized_power_rewards_variability() {
+        assert_eq!(normalized_power(&[200.0; 60]), Some(200.0));
+        let surges = (0..600)
+            .map(|i| if (i / 60) % 2 == 0 { 300.0 } else { 100.0 })
+            .collect::<Vec<_>>();
+        let np = normalized_power(&surges).unwrap();
+        assert!(np > 230.0 && np < 300.0, "NP {np}");
+        assert_eq!(normalized_power(&[200.0; 10]), None);
+    }
+
+    #[test]
+    fn metrics_fill_short_gaps_and_skip_pauses() {
+        let record = |timestamp, power| FitRecord {
+            timestamp: Some(timestamp),
+            power: Some(power),
+            ..FitRecord::default()
+        };
+        let activity = FitActivity {
+            records: vec![record(0, 100.0), record(3, 200.0), record(600, 300.0)],
+            ..FitActivity::default()
+        };
+        let series = per_second(&activity, |record| record.power);
+        assert_eq!(series, vec![100.0, 100.0, 100.0, 200.0, 300.0]);
+
+        let metrics = fit_file_metrics(&activity);
+        assert_eq!(metrics.records, 3);
+        assert_eq!(metrics.duration_secs, 600.0);
+        assert_eq!(metrics.sample_interval_secs, Some(597.0));
+        assert_eq!(metrics.avg_power, Some(160.0));
+        assert!(metrics.dfa_alpha1.is_empty());
+        assert_eq!(metrics.rr_artifact_fraction, None);
+    }
+}
@@ -0,0 +1,482 @@
+//! Shoe wear forecasting.
+//! Projects each shoe's retirement date from its recent weekly mileage and its
+//! share of the planned run sessions on the calendar, and flags planned races
+//! that would be run in shoes past their distance limit.
+
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::engines::coach_metrics::value_number;
+
+// =============================================================================
+// Gear Wear Constants
+// Sources: common manufacturer guidance (500-800 km per running shoe);
+//          Intervals.icu gear reminders (distance in metres, time in seconds)
+// =============================================================================
+
+/// Default running-shoe life when no distance reminder is configured (km).
+pub const DEFAULT_SHOE_LIMIT_KM: f64 = 700.0;
+
+/// Window used for the recent weekly mileage rate (days).
+pub const GEAR_USAGE_WINDOW_DAYS: i64 = 28;
+
+/// Forecasts beyond this horizon report no retirement date (days).
+const FORECAST_HORIZON_DAYS: i64 = 365;
+
+/// Planned-session speed when no recent run pace is available (km/h).
+const DEFAULT_RUN_SPEED_KMH: f64 = 10.0;
+
+const RUN_TYPES: &[&str] = &["Run", "TrailRun", "VirtualRun"];
+
+/// Current state and recent usage of one pair of shoes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShoeUsage {
+    pub gear_id: String,
+    pub name: String,
+    pub distance_km: f64,
+    /// Distance limit from the gear's distance reminder, if any.
+    pub reminder_limit_km: Option<f64>,
+    /// Distance counted toward the limit: since the reminder's last reset, or
+    /// the gear total when there is no reminder or it was never reset (km).
+    pub wear_km: f64,
+    /// Run distance logged in these shoes within the usage window (km).
+    pub recent_km: f64,
+}
+
+/// Planned run session from the calendar.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedRun {
+    pub date: NaiveDate,
+    pub name: String,
+    pub km: f64,
+    pub is_race: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShoeForecast {
+    pub gear_id: String,
+    pub name: String,
+    pub distance_km: f64,
+    /// Distance counted toward `limit_km` (see [`ShoeUsage::wear_km`]).
+    pub wear_km: f64,
+    pub limit_km: f64,
+    /// True when the limit is the default rather than a configured reminder.
+    pub default_limit: bool,
+    pub remaining_km: f64,
+    pub weekly_km: f64,
+    /// Share of run mileage these shoes carry (0-1).
+    pub share: f64,
+    pub retirement_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RaceShoeWarning {
+    pub race_name: String,
+    pub race_date: NaiveDate,
+    pub gear_name: String,
+    pub projected_km: f64,
+    pub limit_km: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct GearWearForecast {
+    pub shoes: Vec<ShoeForecast>,
+    pub race_warnings: Vec<RaceShoeWarning>,
+    /// Last date covered by planned sessions; recent weekly rate applies afterwards.
+    pub planned_until: Option<NaiveDate>,
+}
+
+/// Intervals.icu ids are numeric or string; normalise to a string.
+#[must_use]
+pub fn value_id(value: &Value) -> Option<String> {
+    value
+        .as_str()
+        .map(str::to_owned)
+        .or_else(|| value.as_i64().map(|id| id.to_string()))
+}
+
+/// First distance-based gear reminder.
+fn distance_reminder(gear: &Value) -> Option<&Value> {
+    gear.get("reminders")
+        .and_then(Value::as_array)?
+        .iter()
+        .find(|reminder| value_number(reminder, &["distance"]).is_some_and(|metres| metres > 0.0))
+}
+
+/// Distance limit (km) from the first distance-based gear reminder.
+#[must_use]
+pub fn reminder_limit_km(gear: &Value) -> Option<f64> {
+    distance_reminder(gear)
+        .and_then(|reminder| value_number(reminder, &["distance"]))
+        .map(|metres| metres / 1000.0)
+}
+
+/// Distance (km) a reminder has counted since its last reset. Intervals.icu
+/// reports this as `percent_used`; without it, runs dated on or after
+/// `last_reset` are summed when the reset falls inside the usage window.
+fn reminder_wear_km(
+    reminder: &Value,
+    limit_km: f64,
+    recent_runs: &[(NaiveDate, f64)],
+    window_start: NaiveDate,
+) -> Option<f64> {
+    if let Some(percent) = value_number(reminder, &["percent_used"]) {
+        return Some(limit_km * percent / 100.0);
+    }
+    let last_reset = reminder
+        .get("last_reset")
+        .and_then(Value::as_str)
+        .and_then(|value| value.get(..10))
+        .and_then(|value| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())?;
+    (last_reset >= window_start).then(|| {
+        recent_runs
+            .iter()
+            .filter(|(date, _)| *date >= last_reset)
+            .map(|(_, km)| km)
+            .sum()
+    })
+}
+
+/// Active (non-retired) shoes from the gear list, with the dated runs logged
+/// per gear id since `window_start`. A reminder reset before the window with no
+/// `percent_used` falls back to the gear total, which errs towards retiring early.
+#[must_use]
+pub fn shoe_usage(
+    gear_list: &Value,
+    recent_runs_by_gear: &HashMap<String, Vec<(NaiveDate, f64)>>,
+    window_start: NaiveDate,
+) -> Vec<ShoeUsage> {
+    let Some(gear) = gear_list.as_array() else {
+        return Vec::new();
+    };
+    gear.iter()
+        .filter(|item| item.get("type").and_then(Value::as_str) == Some("Shoes"))
+        .filter(|item| {
+            item.get("retired")
+                .and_then(Value::as_str)
+                .is_none_or(str::is_empty)
+        })
+        .filter_map(|item| {
+            let gear_id = item.get("id").and_then(value_id)?;
+            let recent_runs = recent_runs_by_gear
+                .get(&gear_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let distance_km = value_number(item, &["distance"]).unwrap_or(0.0) / 1000.0;
+            let reminder_limit_km = reminder_limit_km(item);
+            let wear_km = distance_reminder(item)
+                .zip(reminder_limit_km)
+                .and_then(|(reminder, limit_km)| {
+                    reminder_wear_km(reminder, limit_km, recent_runs, window_start)
+                })
+                .unwrap_or(distance_km);
+            Some(ShoeUsage {
+                name: item
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or("Unknown")
+                    .to_string(),
+                distance_km,
+                reminder_limit_km,
+                wear_km,
+                recent_km: recent_runs.iter().map(|(_, km)| km).sum(),
+                gear_id,
+            })
+        })
+        .collect()
+}
+
+/// Gear id and run distance (km) from an activity detail, for runs with gear set.
+#[must_use]
+pub fn activity_gear_km(detail: &Value) -> Option<(String, f64)> {
+    let sport = detail.get("type").and_then(Value::as_str)?;
+    if !RUN_TYPES.contains(&sport) {
+        return None;
+    }
+    let gear_id = detail
+        .get("gear")
+        .and_then(|gear| gear.get("id"))
+        .and_then(value_id)?;
+    let km = value_number(detail, &["distance"])? / 1000.0;
+    (km > 0.0).then_some((gear_id, km))
+}
+
+/// Planned run sessions from calendar events. Sessions without a planned
+/// distance are converted from planned moving time at `speed_kmh`.
+#[must_use]
+pub fn planned_runs(events: &Value, speed_kmh: Option<f64>) -> Vec<PlannedRun> {
+    let Some(events) = events.as_array() else {
+        return Vec::new();
+    };
+    let speed_kmh = speed_kmh
+        .filter(|speed| *speed > 0.0)
+        .unwrap_or(DEFAULT_RUN_SPEED_KMH);
+    events
+        .iter()
+        .filter_map(|event| {
+            let sport = event.get("type").and_then(Value::as_str)?;
+            if !RUN_TYPES.contains(&sport) {
+                return None;
+            }
+            let date = event
+                .get("start_date_local")
+                .and_then(Value::as_str)
+                .and_then(|value| value.get(..10))
+                .and_then(|value| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())?;
+            let km = value_number(event, &["distance"])
+                .filter(|metres| *metres > 0.0)
+                .map(|metres| metres / 1000.0)
+                .or_else(|| {
+                    value_number(event, &["moving_time"])
+                        .filter(|secs| *secs > 0.0)
+                        .map(|secs| secs / 3600.0 * speed_kmh)
+                })?;
+            let is_race = event
+                .get("category")
+                .and_then(Value::as_str)
+                .is_some_and(|category| category.starts_with("RACE"));
+            Some(PlannedRun {
+                date,
+                name: event
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or("Planned run")
+                    .to_string(),
+                km,
+                is_race,
+            })
+        })
+        .collect()
+}
+
+/// Project shoe wear forward from `today`. Planned sessions are split across
+/// shoes by their share of recent run mileage; after the last planned session
+/// each shoe wears at its recent weekly rate.
+#[must_use]
+pub fn forecast_gear_wear(
+    shoes: &[ShoeUsage],
+    planned: &[PlannedRun],
+    today: NaiveDate,
+) -> GearWearForecast {
+    let total_recent_km: f64 = shoes.iter().map(|shoe| shoe.recent_km).sum();
+    let mut planned = planned
+        .iter()
+        .filter(|session| session.date >= today)
+        .collect::<Vec<_>>();
+    planned.sort_by_key(|session| session.date);
+    let planned_until = planned.last().map(|session| session.date);
+
+    let mut forecast = GearWearForecast {
+        planned_until,
+        ..GearWearForecast::default()
+    };
+    for shoe in shoes {
+        let share = if total_recent_km > 0.0 {
+            shoe.recent_km / total_recent_km
+        } else {
+            0.0
+        };
+        let weekly_km = shoe.recent_km * 7.0 / GEAR_USAGE_WINDOW_DAYS as f64;
+        let limit_km = shoe.reminder_limit_km.unwrap_or(DEFAULT_SHOE_LIMIT_KM);
+        let projected_at = |date: NaiveDate| {
+            let planned_km: f64 = planned
+                .iter()
+                .filter(|session| session.date <= date)
+                .map(|session| session.km * share)
+                .sum();
+            let extrapolated_days = match planned_until {
+                Some(until) if date > until => (date - until).num_days(),
+                Some(_) => 0,
+                None => (date - today).num_days(),
+            };
+            shoe.wear_km + planned_km + weekly_km / 7.0 * extrapolated_days as f64
+        };
+
+        let retirement_date = (0..=FORECAST_HORIZON_DAYS)
+            .map(|offset| today + Duration::days(offset))
+            .find(|date| projected_at(*date) >= limit_km);
+
+        for race in planned.iter().filter(|session| session.is_race) {
+            // Wear before the start line: sessions on race day are excluded.
+            let projected_km = projected_at(race.date - Duration::days(1));
+            if share > 0.0 && projected_km >= limit_km {
+                forecast.race_warnings.push(RaceShoeWarning {
+                    race_name: race.name.clone(),
+                    race_date: race.date,
+                    gear_name: shoe.name.clone(),
+                    projected_km,
+                    limit_km,
+                });
+            }
+        }
+
+        forecast.shoes.push(ShoeForecast {
+            gear_id: shoe.gear_id.clone(),
+            name: shoe.name.clone(),
+            distance_km: shoe.distance_km,
+            wear_km: shoe.wear_km,
+            limit_km,
+            default_limit: shoe.reminder_limit_km.is_none(),
+            remaining_km: (limit_km - shoe.wear_km).max(0.0),
+            weekly_km,
+            share,
+            retirement_date,
+        });
+    }
+    forecast
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 5, 1).unwrap()
+    }
+
+    fn shoe(id: &str, distance_km: f64, limit: Option<f64>, recent_km: f64) -> ShoeUsage {
+        ShoeUsage {
+            gear_id: id.into(),
+            name: format!("Shoe {id}"),
+            distance_km,
+            reminder_limit_km: limit,
+            wear_km: distance_km,
+            recent_km,
+        }
+    }
+
+    #[test]
+    fn shoe_usage_skips_retired_and_non_shoes() {
+        let gear = json!([
+            {"id": "g1", "name": "Daily", "type": "Shoes", "distance": 420000.0,
+             "reminders": [{"id": 7, "distance": 600000.0}]},
+            {"id": "g2", "name": "Old", "type": "Shoes", "retired": "2026-01-01"},
+            {"id": "b1", "name": "Bike", "type": "Bike", "distance": 1000000.0}
+        ]);
+        let recent =
+            HashMap::from([("g1".to_string(), vec![(today() - Duration::days(3), 120.0)])]);
+        let shoes = shoe_usage(
+            &gear,
+            &recent,
+            today() - Duration::days(GEAR_USAGE_WINDOW_DAYS),
+        );
+        assert_eq!(shoes.len(), 1);
+        assert_eq!(shoes[0].distance_km, 420.0);
+        assert_eq!(shoes[0].reminder_limit_km, Some(600.0));
+        assert_eq!(shoes[0].wear_km, 420.0);
+        assert_eq!(shoes[0].recent_km, 120.0);
+    }
+
+    #[test]
+    fn shoe_usage_measures_wear_since_reminder_reset() {
+        let window_start = today() - Duration::days(GEAR_USAGE_WINDOW_DAYS);
+        let gear = json!([
+            {"id": "g1", "type": "Shoes", "distance": 900000.0,
+             "reminders": [{"id": 7, "distance": 600000.0, "last_reset": "2026-04-20"}]},
+            {"id": "g2", "type": "Shoes", "distance": 1500000.0,
+             "reminders": [{"id": 8, "distance": 800000.0, "percent_used": 25.0,
+                            "last_reset": "2025-06-01"}]}
+        ]);
+        let recent = HashMap::from([(
+            "g1".to_string(),
+            vec![
+                (NaiveDate::from_ymd_opt(2026, 4, 12).unwrap(), 30.0),
+                (NaiveDate::from_ymd_opt(2026, 4, 20).unwrap(), 12.0),
+                (NaiveDate::from_ymd_opt(2026, 4, 27).unwrap(), 18.0),
+            ],
+        )]);
+        let shoes = shoe_usage(&gear, &recent, window_start);
+        assert_eq!(shoes[0].wear_km, 30.0);
+        assert_eq!(shoes[0].recent_km, 60.0);
+        assert_eq!(shoes[1].wear_km, 200.0);
+
+        // 900 km in total, but only 30 km since the reset: months of life left.
+        let forecast = forecast_gear_wear(&shoes[..1], &[], today());
+        assert_eq!(forecast.shoes[0].remaining_km, 570.0);
+        assert!(
+            forecast.shoes[0]
+                .retirement_date
+                .is_some_and(|date| date > today() + Duration::days(180))
+        );
+    }
+
+    #[test]
+    fn activity_gear_km_and_planned_runs_parse_intervals_payloads() {
+        assert_eq!(
+            activity_gear_km(&json!({"type": "Run", "distance": 10000.0, "gear": {"id": "g1"}})),
+            Some(("g1".to_string(), 10.0))
+        );
+        assert!(
+            activity_gear_km(&json!({"type": "Ride", "distance": 40000.0, "gear": {"id": "b1"}}))
+                .is_none()
+        );
+
+        let events = json!([
+            {"start_date_local": "2026-05-03T00:00:00", "type": "Run", "name": "Long", "distance": 24000.0},
+            {"start_date_local": "2026-05-04", "type": "Run", "name": "Easy", "moving_time": 3600},
+            {"start_date_local": "2026-05-10", "type": "Run", "name": "10K", "category": "RACE_A", "distance": 10000.0},
+            {"start_date_local": "2026-05-05", "type": "Ride", "moving_time": 3600}
+        ]);
+        let runs = planned_runs(&events, Some(12.0));
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].km, 24.0);
+        assert_eq!(runs[1].km, 12.0);
+        assert!(runs[2].is_race);
+    }
+
+    #[test]
+    fn forecast_extrapolates_recent_weekly_rate_without_plan() {
+        // 40 km/week, 80 km left → 14 days.
+        let forecast = forecast_gear_wear(&[shoe("g1", 620.0, None, 160.0)], &[], today());
+        let shoe = &forecast.shoes[0];
+        assert!(shoe.default_limit);
+        assert_eq!(shoe.remaining_km, 80.0);
+        assert!((shoe.weekly_km - 40.0).abs() < 1e-9);
+        assert_eq!(shoe.retirement_date, Some(today() + Duration::days(14)));
+    }
+
+    #[test]
+    fn forecast_splits_planned_sessions_and_warns_for_race() {
+        let shoes = [
+            shoe("g1", 570.0, Some(600.0), 90.0),
+            shoe("g2", 100.0, None, 30.0),
+        ];
+        let planned = [
+            PlannedRun {
+                date: today() + Duration::days(2),
+                name: "Long".into(),
+                km: 30.0,
+                is_race: false,
+            },
+            PlannedRun {
+                date: today() + Duration::days(4),
+                name: "Tempo".into(),
+                km: 12.0,
+                is_race: false,
+            },
+            PlannedRun {
+                date: today() + Duration::days(6),
+                name: "Spring 10K".into(),
+                km: 10.0,
+                is_race: true,
+            },
+        ];
+        let forecast = forecast_gear_wear(&shoes, &planned, today());
+        assert_eq!(forecast.planned_until, Some(today() + Duration::days(6)));
+        // g1 carries 75%: 570 + 22.5 + 9 = 601.5 km before the race.
+        assert_eq!(forecast.race_warnings.len(), 1);
+        let warning = &forecast.race_warnings[0];
+        assert_eq!(warning.gear_name, "Shoe g1");
+        assert!((warning.projected_km - 601.5).abs() < 1e-9);
+        assert_eq!(
+            forecast.shoes[0].retirement_date,
+            Some(today() + Duration::days(4))
+        );
+        // 7.5 km/week with 600 km left lies beyond the forecast horizon.
+        assert!(forecast.shoes[1].retirement_date.is_none());
+    }
+}
@@ -0,0 +1,444 @@
+//! Illness and injury episodes.
+//! Groups SICK / INJURED calendar events and wellness injury flags into
+//! episodes with start/end dates, measures the return to training and the CTL
+//! lost, prescribes a graded return-to-training ramp, and masks episode windows
+//! out of CTL series so a forced break is not read as a training plateau.
+
+use chrono::{Duration, NaiveDate};
+use intervals_icu_client::{ActivitySummary, Event, EventCategory};
+use serde_json::Value;
+
+use crate::domains::progress::{EpisodeKind, HealthEpisode};
+use crate::engines::race_readiness::compute_ctl_drop;
+
+// =============================================================================
+// Health Episode Constants
+// Sources: Elliott et al., BJSM 2019 ("neck check" and graded return after
+//          illness); Ardern et al., BJSM 2016 (return-to-sport continuum);
+//          Intervals.icu wellness `injury` scale (1 = none … 4 = serious)
+// =============================================================================
+
+/// Days without a flag that still join two flagged days into one episode.
+pub const EPISODE_MERGE_GAP_DAYS: i64 = 2;
+
+/// Wellness `injury` value at or above which a day counts as injured.
+pub const WELLNESS_INJURY_FLAG: i64 = 3;
+
+/// Shortest session that counts as a return to training (minutes).
+pub const RETURN_SESSION_MIN_MINUTES: f64 = 20.0;
+
+/// Days before the episode used for the pre-episode CTL peak.
+const PRE_EPISODE_CTL_DAYS: i64 = 7;
+
+/// Longest calendar entry expanded day by day; longer ranges are clamped.
+const MAX_EVENT_SPAN_DAYS: i64 = 366;
+
+/// Episodes that ended within this many days still call for a return ramp.
+pub const RETURN_RAMP_LOOKBACK_DAYS: i64 = 28;
+
+const ILLNESS_GUIDANCE: &str =
+    "Symptoms above the neck only; stop and rest if fever, chest or GI symptoms return";
+const INJURY_GUIDANCE: &str =
+    "Pain at most 2/10 during and the morning after; hold the step if pain rises";
+
+/// One week of a return-to-training ramp.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReturnRampWeek {
+    pub week: u32,
+    /// Volume as a share of the planned week (%).
+    pub volume_pct: u32,
+    pub intensity_allowed: bool,
+    pub guidance: &'static str,
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    value
+        .get(..10)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+}
+
+/// Flagged days from SICK / INJURED calendar events; a multi-day entry flags
+/// every day from its start through its end date.
+fn event_days(events: &[Event]) -> Vec<(NaiveDate, EpisodeKind, String)> {
+    events
+        .iter()
+        .filter_map(|event| {
+            let kind = match event.category {
+                EventCategory::Sick => EpisodeKind::Illness,
+                EventCategory::Injured => EpisodeKind::Injury,
+                _ => return None,
+            };
+            let start = parse_date(&event.start_date_local)?;
+            let span = event
+                .end_date_local
+                .as_deref()
+                .and_then(parse_date)
+                .map_or(0, |end| {
+                    (end - start).num_days().clamp(0, MAX_EVENT_SPAN_DAYS)
+                });
+            Some(
+                (0..=span)
+                    .map(move |offset| (start + Duration::days(offset), kind, event.name.clone())),
+            )
+        })
+        .flatten()
+        .collect()
+}
+
+/// Flagged days from wellness entries with `injury` at or above the flag level.
+fn wellness_days(wellness: Option<&Value>) -> Vec<(NaiveDate, EpisodeKind, String)> {
+    wellness
+        .and_then(Value::as_array)
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|entry| {
+                    let injury = entry.get("injury").and_then(Value::as_i64)?;
+                    if injury < WELLNESS_INJURY_FLAG {
+                        return None;
+                    }
+                    let date = ["id", "date"]
+                        .iter()
+                        .find_map(|key| entry.get(*key).and_then(Value::as_str))
+                        .and_then(parse_date)?;
+                    Some((
+                        date,
+                        EpisodeKind::Injury,
+                        format!("Wellness injury {injury}/4"),
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn return_session(activities: &[ActivitySummary], after: NaiveDate) -> Option<NaiveDate> {
+    activities
+        .iter()
+        .filter(|activity| {
+            activity
+                .moving_time
+                .is_some_and(|secs| f64::from(secs) / 60.0 >= RETURN_SESSION_MIN_MINUTES)
+        })
+        .filter_map(|activity| parse_date(&activity.start_date_local))
+        .filter(|date| *date > after)
+        .min()
+}
+
+fn episode_ctl_lost(
+    ctl: Option<(&[String], &[f64])>,
+    start: NaiveDate,
+    until: NaiveDate,
+) -> Option<f64> {
+    let (dates, values) = ctl?;
+    let pre_start = start - Duration::days(PRE_EPISODE_CTL_DAYS);
+    let window = dates
+        .iter()
+        .zip(values)
+        .filter_map(|(date, value)| {
+            let date = parse_date(date)?;
+            (date >= pre_start && date <= until).then_some((date, *value))
+        })
+        .collect::<Vec<_>>();
+    let trough = window
+        .iter()
+        .filter(|(date, _)| *date >= start)
+        .map(|(_, value)| *value)
+        .reduce(f64::min);
+    let values = window.iter().map(|(_, value)| *value).collect::<Vec<_>>();
+    compute_ctl_drop(&values, trough)
+}
+
+/// Build illness / injury episodes. Flagged days of the same kind merge when no
+/// more than `EPISODE_MERGE_GAP_DAYS` apart. `ctl` is an optional daily CTL
+/// series (dates, values) for the fitness-lost estimate.
+#[must_use]
+pub fn build_health_episodes(
+    events: &[Event],
+    wellness: Option<&Value>,
+    activities: &[ActivitySummary],
+    ctl: Option<(&[String], &[f64])>,
+    today: NaiveDate,
+) -> Vec<HealthEpisode> {
+    let mut days = event_days(events);
+    days.extend(wellness_days(wellness));
+    days.sort_by_key(|(date, kind, _)| (*kind == EpisodeKind::Injury, *date));
+
+    let mut grouped: Vec<(EpisodeKind, NaiveDate, NaiveDate, Vec<String>)> = Vec::new();
+    for (date, kind, source) in days {
+        match grouped.last_mut() {
+            Some((last_kind, _, end, sources))
+                if *last_kind == kind && (date - *end).num_days() <= EPISODE_MERGE_GAP_DAYS + 1 =>
+            {
+                *end = (*end).max(date);
+                if !sources.contains(&source) {
+                    sources.push(source);
+                }
+            }
+            _ => grouped.push((kind, date, date, vec![source])),
+        }
+    }
+
+    let mut episodes = grouped
+        .into_iter()
+        .map(|(kind, start, end, sources)| {
+            let return_date = return_session(activities, end);
+            HealthEpisode {
+                kind,
+                start,
+                end,
+                sources,
+                return_date,
+                days_to_return: return_date.map(|date| (date - end).num_days()),
+                ctl_lost: episode_ctl_lost(ctl, start, return_date.unwrap_or(today)),
+                ongoing: return_date.is_none(),
+            }
+        })
+        .collect::<Vec<_>>();
+    episodes.sort_by_key(|episode| episode.start);
+    episodes
+}
+
+/// Whether `date` falls inside an episode window (start through return, or
+/// through `today` while the episode is ongoing).
+#[must_use]
+pub fn in_episode_window(episodes: &[HealthEpisode], date: NaiveDate, today: NaiveDate) -> bool {
+    episodes.iter().any(|episode| {
+        let until = episode.return_date.unwrap_or(today);
+        date >= episode.start && date <= until
+    })
+}
+
+/// Drop series points inside episode windows. Returns the kept series and the
+/// number of points removed.
+#[must_use]
+pub fn exclude_episode_windows(
+    dates: &[String],
+    values: &[f64],
+    episodes: &[HealthEpisode],
+    today: NaiveDate,
+) -> (Vec<String>, Vec<f64>, usize) {
+    let mut kept_dates = Vec::with_capacity(dates.len());
+    let mut kept_values = Vec::with_capacity(values.len());
+    for (date, value) in dates.iter().zip(values) {
+        if parse_date(date).is_some_and(|day| in_episode_window(episodes, day, today)) {
+            continue;
+        }
+        kept_dates.push(date.clone());
+        kept_values.push(*value);
+    }
+    let excluded = dates.len() - kept_dates.len();
+    (kept_dates, kept_values, excluded)
+}
+
+/// Graded return-to-training ramp sized by episode kind and length.
+#[must_use]
+pub fn return_to_training_ramp(episode: &HealthEpisode) -> Vec<ReturnRampWeek> {
+    let length_days = (episode.end - episode.start).num_days() + 1;
+    let (steps, guidance): (&[(u32, bool)], &'static str) = match episode.kind {
+        EpisodeKind::Illness if length_days <= 3 => (&[(75, false), (90, true)], ILLNESS_GUIDANCE),
+        EpisodeKind::Illness if length_days <= 10 => {
+            (&[(50, false), (70, false), (90, true)], ILLNESS_GUIDANCE)
+        }
+        EpisodeKind::Illness => (
+            &[(40, false), (55, false), (70, true), (85, true)],
+            ILLNESS_GUIDANCE,
+        ),
+        EpisodeKind::Injury if length_days <= 7 => {
+            (&[(60, false), (80, false), (90, true)], INJURY_GUIDANCE)
+        }
+        EpisodeKind::Injury => (
+            &[(40, false), (55, false), (70, false), (85, true)],
+            INJURY_GUIDANCE,
+        ),
+    };
+    steps
+        .iter()
+        .zip(1..)
+        .map(|((volume_pct, intensity_allowed), week)| ReturnRampWeek {
+            week,
+            volume_pct: *volume_pct,
+            intensity_allowed: *intensity_allowed,
+            guidance,
+        })
+        .collect()
+}
+
+/// Most recent episode that ended within `RETURN_RAMP_LOOKBACK_DAYS` of `as_of`.
+#[must_use]
+pub fn episode_needing_return(
+    episodes: &[HealthEpisode],
+    as_of: NaiveDate,
+) -> Option<&HealthEpisode> {
+    episodes
+        .iter()
+        .filter(|episode| {
+            episode.end <= as_of && (as_of - episode.end).num_days() <= RETURN_RAMP_LOOKBACK_DAYS
+        })
+        .max_by_key(|episode| episode.end)
+}
+
+/// Ramp weeks still ahead on `as_of`, skipping full weeks already trained since
+/// the return session.
+#[must_use]
+pub fn remaining_return_ramp(episode: &HealthEpisode, as_of: NaiveDate) -> Vec<ReturnRampWeek> {
+    let completed = episode
+        .return_date
+        .map(|date| ((as_of - date).num_days().max(0) / 7) as usize)
+        .unwrap_or(0);
+    return_to_training_ramp(episode)
+        .into_iter()
+        .skip(completed)
+        .collect()
+}
+
+#[must_use]
+pub fn episode_label(kind: EpisodeKind) -> &'static str {
+    match kind {
+        EpisodeKind::Illness => "Illness",
+        EpisodeKind::Injury => "Injury",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn event(date: &str, category: EventCategory, name: &str) -> Event {
+        Event {
+            id: None,
+            start_date_local: date.into(),
+            name: name.into(),
+            category,
+            description: None,
+            r#type: None,
+            end_date_local: None,
+        }
+    }
+
+    fn run(date: &str, minutes: i32) -> ActivitySummary {
+        ActivitySummary {
+            id: date.into(),
+            start_date_local: format!("{date}T07:00:00"),
+            moving_time: Some(minutes * 60),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn merges_nearby_days_and_measures_return() {
+        let events = vec![
+            event("2026-03-02", EventCategory::Sick, "Flu"),
+            event("2026-03-04", EventCategory::Sick, "Flu"),
+            event("2026-03-20", EventCategory::Injured, "Calf strain"),
+        ];
+        let activities = vec![run("2026-03-03", 10), run("2026-03-07", 40)];
+        let episodes = build_health_episodes(&events, None, &activities, None, day(25));
+        assert_eq!(episodes.len(), 2);
+
+        let flu = &episodes[0];
+        assert_eq!(flu.kind, EpisodeKind::Illness);
+        assert_eq!((flu.start, flu.end), (day(2), day(4)));
+        assert_eq!(flu.sources, vec!["Flu".to_string()]);
+        // The 10-minute jog does not count as a return.
+        assert_eq!(flu.return_date, Some(day(7)));
+        assert_eq!(flu.days_to_return, Some(3));
+        assert!(!flu.ongoing);
+
+        assert!(episodes[1].ongoing);
+        assert_eq!(episodes[1].kind, EpisodeKind::Injury);
+    }
+
+    #[test]
+    fn multi_day_event_spans_its_range() {
+        let mut flu = event("2026-03-02", EventCategory::Sick, "Flu");
+        flu.end_date_local = Some("2026-03-08T00:00:00".into());
+        // The session on day 5 falls inside the illness and is not the return.
+        let activities = vec![run("2026-03-05", 40), run("2026-03-10", 45)];
+        let episodes = build_health_episodes(&[flu], None, &activities, None, day(20));
+        assert_eq!(episodes.len(), 1);
+        assert_eq!((episodes[0].start, episodes[0].end), (day(2), day(8)));
+        assert_eq!(episodes[0].return_date, Some(day(10)));
+        assert_eq!(episodes[0].days_to_return, Some(2));
+        // A 7-day illness gets the three-week ramp, not the short one.
+        assert_eq!(return_to_training_ramp(&episodes[0]).len(), 3);
+    }
+
+    #[test]
+    fn wellness_injury_flags_form_episodes() {
+        let wellness = json!([
+            {"id": "2026-03-10", "injury": 3},
+            {"id": "2026-03-11", "injury": 4},
+            {"id": "2026-03-12", "injury": 1}
+        ]);
+        let episodes = build_health_episodes(&[], Some(&wellness), &[], None, day(12));
+        assert_eq!(episodes.len(), 1);
+        assert_eq!((episodes[0].start, episodes[0].end), (day(10), day(11)));
+        assert_eq!(episodes[0].sources.len(), 2);
+    }
+
+    #[test]
+    fn ctl_lost_is_pre_episode_peak_minus_trough() {
+        let dates = (1..=12)
+            .map(|d| format!("2026-03-{d:02}"))
+            .collect::<Vec<_>>();
+        let values = vec![
+            50.0, 51.0, 52.0, 52.0, 52.0, 48.0, 45.0, 43.0, 42.0, 43.0, 44.0, 45.0,
+        ];
+        let events = vec![event("2026-03-06", EventCategory::Sick, "Cold")];
+        let activities = vec![run("2026-03-10", 45)];
+        let episodes =
+            build_health_episodes(&events, None, &activities, Some((&dates, &values)), day(12));
+        assert_eq!(episodes[0].ctl_lost, Some(10.0));
+
+        let (kept, _, excluded) = exclude_episode_windows(&dates, &values, &episodes, day(12));
+        assert_eq!(excluded, 5);
+        assert!(!kept.contains(&"2026-03-08".to_string()));
+        assert!(kept.contains(&"2026-03-11".to_string()));
+    }
+
+    #[test]
+    fn ramp_scales_with_kind_and_length() {
+        let episode = |kind, days: i64| HealthEpisode {
+            kind,
+            start: day(1),
+            end: day(1) + Duration::days(days - 1),
+            sources: Vec::new(),
+            return_date: None,
+            days_to_return: None,
+            ctl_lost: None,
+            ongoing: true,
+        };
+        let short_cold = return_to_training_ramp(&episode(EpisodeKind::Illness, 2));
+        assert_eq!(short_cold.len(), 2);
+        assert!(short_cold[1].intensity_allowed);
+
+        let mut returned = episode(EpisodeKind::Illness, 6);
+        returned.return_date = Some(day(8));
+        let remaining = remaining_return_ramp(&returned, day(16));
+        assert_eq!(remaining.len(), 2);
+        assert_eq!(remaining[0].week, 2);
+        assert!(episode_needing_return(std::slice::from_ref(&returned), day(20)).is_some());
+        assert!(
+            episode_needing_return(
+                std::slice::from_ref(&returned),
+                day(30) + Duration::days(10)
+            )
+            .is_none()
+        );
+
+        let long_injury = return_to_training_ramp(&episode(EpisodeKind::Injury, 14));
+        assert_eq!(long_injury.len(), 4);
+        assert!(long_injury[..3].iter().all(|week| !week.intensity_allowed));
+        assert!(
+            long_injury
+                .windows(2)
+                .all(|pair| pair[0].volume_pct < pair[1].volume_pct)
+        );
+    }
+}
@@ -0,0 +1,487 @@
+//! Heat-acclimation state model.
+//! Accumulates exposure minutes above a temperature threshold from activity
+//! weather fields and temperature streams, decays the state between exposures,
+//! and adjusts decoupling / efficiency factor for the heat penalty.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::domains::coach::HeatAcclimationMetrics;
+use crate::engines::coach_metrics::get_number;
+use crate::engines::coach_metrics_constants::{EFFICIENCY_FACTOR_KEYS, HEAT_BASELINE_TEMP_C};
+use std::collections::HashMap;
+
+// =============================================================================
+// Heat Acclimation Constants
+// Sources: Périard et al. SJMSS 2015, Daanen et al. Sports Med 2018,
+//          Racinais et al. BJSM 2015 (consensus on heat training)
+// =============================================================================
+
+/// Ambient temperature above which training counts as heat exposure (°C).
+pub const HEAT_EXPOSURE_THRESHOLD_C: f64 = 25.0;
+
+/// Exposure minutes that close ~63% of the gap to full acclimation.
+/// Calibrated so 60-90 min/day for 5 days reaches ~75% (Périard et al. 2015).
+const HEAT_ACCLIMATION_SATURATION_MINUTES: f64 = 375.0;
+
+/// Fractional loss of acclimation per day without exposure (Daanen et al. 2018: ~2.5%/day).
+const HEAT_ACCLIMATION_DECAY_PER_DAY: f64 = 0.025;
+
+/// Acclimation index at or above which the athlete is considered acclimated.
+const ACCLIMATED_INDEX: f64 = 0.75;
+
+/// Acclimation index at or above which the athlete is partially acclimated.
+const PARTIAL_ACCLIMATION_INDEX: f64 = 0.3;
+
+/// Activities whose mean temperature is within this margin below the exposure
+/// threshold may still have hot stretches, so their `temp` stream is worth fetching (°C).
+const HEAT_STREAM_CANDIDATE_MARGIN_C: f64 = 5.0;
+
+/// Rolling window for the exposure summary (days).
+const EXPOSURE_WINDOW_DAYS: i64 = 14;
+
+/// Expected extra cardiac drift per °C above baseline when unacclimated (%).
+const HEAT_DECOUPLING_PCT_PER_C: f64 = 0.3;
+
+/// Expected efficiency-factor loss per °C above baseline when unacclimated (fraction).
+const HEAT_EF_PENALTY_PER_C: f64 = 0.01;
+
+/// Maximum efficiency-factor heat penalty (fraction).
+const HEAT_EF_PENALTY_MAX: f64 = 0.2;
+
+/// Share of the heat penalty removed by full acclimation.
+const ACCLIMATION_PENALTY_REDUCTION: f64 = 0.5;
+
+/// Days before the race to start an acclimation block (Racinais et al. 2015: ≥ 2 weeks).
+const PROTOCOL_LEAD_DAYS: i64 = 14;
+
+/// Heat sessions in a full acclimation block.
+const PROTOCOL_SESSIONS: u32 = 10;
+
+/// Minimum / maximum heat exposure per session (minutes).
+const PROTOCOL_MIN_MINUTES: u32 = 60;
+const PROTOCOL_MAX_MINUTES: u32 = 90;
+
+/// Days before the race of the last heat exposure.
+const PROTOCOL_FINAL_EXPOSURE_DAYS: i64 = 2;
+
+/// Single-activity heat exposure sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeatExposure {
+    pub date: NaiveDate,
+    /// Minutes spent above `HEAT_EXPOSURE_THRESHOLD_C` (0 when the activity was cool).
+    pub minutes: f64,
+    pub temperature_c: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AcclimationProtocol {
+    pub start_date: NaiveDate,
+    pub final_exposure_date: NaiveDate,
+    pub race_date: NaiveDate,
+    pub race_temperature_c: f64,
+    pub sessions: u32,
+    pub min_minutes: u32,
+    pub max_minutes: u32,
+    /// True when there is less lead time than a full block needs.
+    pub compressed: bool,
+}
+
+/// Activity temperature. Same fallback chain as `compute_heat_metrics_7d`:
+/// average_temp → average_weather_temp → average_feels_like.
+pub fn activity_temperature(detail: &Value) -> Option<f64> {
+    get_number(
+        detail.as_object()?,
+        &["average_temp", "average_weather_temp", "average_feels_like"],
+    )
+}
+
+/// Whether an activity may have spent time above the exposure threshold, so its
+/// `temp` stream can refine the exposure minutes.
+pub fn is_heat_stream_candidate(detail: &Value) -> bool {
+    activity_temperature(detail)
+        .is_some_and(|t| t > HEAT_EXPOSURE_THRESHOLD_C - HEAT_STREAM_CANDIDATE_MARGIN_C)
+}
+
+/// Extract heat exposure for one activity. A `temp` stream, when present, gives
+/// the share of the session above threshold; otherwise the whole moving time
+/// counts if the activity temperature is above threshold.
+pub fn extract_heat_exposure(
+    date: NaiveDate,
+    detail: Option<&Value>,
+    streams: Option<&Value>,
+) -> Option<HeatExposure> {
+    let moving_minutes = detail
+        .and_then(Value::as_object)
+        .and_then(|object| get_number(object, &["moving_time"]))
+        .map(|secs| secs / 60.0);
+
+    let stream_temps: Vec<f64> = streams
+        .and_then(|s| s.get("temp"))
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(Value::as_f64).collect())
+        .unwrap_or_default();
+
+    if !stream_temps.is_empty() {
+        let hot = stream_temps
+            .iter()
+            .filter(|t| **t > HEAT_EXPOSURE_THRESHOLD_C)
+            .count() as f64;
+        let share = hot / stream_temps.len() as f64;
+        // Streams are 1 Hz when moving time is unknown.
+        let minutes = moving_minutes.map(|m| m * share).unwrap_or(hot / 60.0);
+        let mean = stream_temps.iter().sum::<f64>() / stream_temps.len() as f64;
+        return Some(HeatExposure {
+            date,
+            minutes,
+            temperature_c: mean,
+        });
+    }
+
+    let temperature_c = detail.and_then(activity_temperature)?;
+    let minutes = if temperature_c > HEAT_EXPOSURE_THRESHOLD_C {
+        moving_minutes.unwrap_or(0.0)
+    } else {
+        0.0
+    };
+    Some(HeatExposure {
+        date,
+        minutes,
+        temperature_c,
+    })
+}
+
+fn classify_acclimation(index: f64) -> &'static str {
+    if index >= ACCLIMATED_INDEX {
+        "acclimated"
+    } else if index >= PARTIAL_ACCLIMATION_INDEX {
+        "partial"
+    } else {
+        "unacclimated"
+    }
+}
+
+/// Run the acclimation state model up to `as_of`.
+/// Each day the index decays by `HEAT_ACCLIMATION_DECAY_PER_DAY`; each exposure
+/// closes a share of the remaining gap proportional to its minutes.
+pub fn compute_heat_acclimation(
+    exposures: &[HeatExposure],
+    as_of: NaiveDate,
+) -> HeatAcclimationMetrics {
+    let mut sorted: Vec<&HeatExposure> = exposures.iter().filter(|e| e.date <= as_of).collect();
+    if sorted.is_empty() {
+        return HeatAcclimationMetrics::default();
+    }
+    sorted.sort_by_key(|e| e.date);
+
+    let mut index: f64 = 0.0;
+    let mut day = sorted[0].date;
+    let mut cursor = 0;
+    while day <= as_of {
+        while cursor < sorted.len() && sorted[cursor].date == day {
+            let dose = (sorted[cursor].minutes / HEAT_ACCLIMATION_SATURATION_MINUTES).min(1.0);
+            index += (1.0 - index) * dose;
+            cursor += 1;
+        }
+        let Some(next) = day.succ_opt() else { break };
+        day = next;
+        if day <= as_of {
+            index *= 1.0 - HEAT_ACCLIMATION_DECAY_PER_DAY;
+        }
+    }
+
+    let window_start = as_of - chrono::Duration::days(EXPOSURE_WINDOW_DAYS);
+    let recent_hot: Vec<&&HeatExposure> = sorted
+        .iter()
+        .filter(|e| e.date > window_start && e.minutes > 0.0)
+        .collect();
+    let last_exposure = sorted.iter().rev().find(|e| e.minutes > 0.0);
+
+    HeatAcclimationMetrics {
+        supported: true,
+        acclimation_index: Some(index),
+        state: classify_acclimation(index).to_string(),
+        exposure_minutes_14d: recent_hot.iter().map(|e| e.minutes).sum(),
+        heat_sessions_14d: recent_hot.len(),
+        days_since_exposure: last_exposure.map(|e| (as_of - e.date).num_days()),
+        heat_adjusted_ef: None,
+    }
+}
+
+/// Build acclimation metrics from a window of activity details (and `temp`
+/// streams, keyed by activity id, where fetched) and attach the heat-adjusted
+/// mean efficiency factor for activities with a temperature.
+pub fn compute_heat_acclimation_from_details(
+    activity_details: &HashMap<String, Value>,
+    activity_streams: &HashMap<String, Value>,
+    dated_ids: &[(NaiveDate, String)],
+    as_of: NaiveDate,
+) -> HeatAcclimationMetrics {
+    let exposures: Vec<HeatExposure> = dated_ids
+        .iter()
+        .filter_map(|(date, id)| {
+            extract_heat_exposure(*date, activity_details.get(id), activity_streams.get(id))
+        })
+        .collect();
+    let mut metrics = compute_heat_acclimation(&exposures, as_of);
+    if !metrics.supported {
+        return metrics;
+    }
+
+    let adjusted: Vec<f64> = dated_ids
+        .iter()
+        .filter_map(|(_, id)| {
+            let detail = activity_details.get(id)?;
+            let object = detail.as_object()?;
+            let ef = get_number(object, EFFICIENCY_FACTOR_KEYS)?;
+            let temperature = activity_temperature(detail)?;
+            Some(heat_adjusted_efficiency_factor(
+                ef,
+                temperature,
+                metrics.acclimation_index,
+            ))
+        })
+        .collect();
+    if !adjusted.is_empty() {
+        metrics.heat_adjusted_ef = Some(adjusted.iter().sum::<f64>() / adjusted.len() as f64);
+    }
+    metrics
+}
+
+fn heat_load(temperature_c: f64, acclimation_index: Option<f64>) -> f64 {
+    let excess = (temperature_c - HEAT_BASELINE_TEMP_C).max(0.0);
+    let protection =
+        1.0 - ACCLIMATION_PENALTY_REDUCTION * acclimation_index.unwrap_or(0.0).clamp(0.0, 1.0);
+    excess * protection
+}
+
+/// Remove the expected heat-driven cardiac drift from aerobic decoupling (%).
+/// Unknown acclimation is treated as unacclimated (largest correction).
+pub fn heat_adjusted_decoupling(
+    decoupling_pct: f64,
+    temperature_c: f64,
+    acclimation_index: Option<f64>,
+) -> f64 {
+    decoupling_pct - heat_load(temperature_c, acclimation_index) * HEAT_DECOUPLING_PCT_PER_C
+}
+
+/// Restore the efficiency factor lost to heat (output/HR is depressed in heat).
+pub fn heat_adjusted_efficiency_factor(
+    efficiency_factor: f64,
+    temperature_c: f64,
+    acclimation_index: Option<f64>,
+) -> f64 {
+    let penalty = (heat_load(temperature_c, acclimation_index) * HEAT_EF_PENALTY_PER_C)
+        .min(HEAT_EF_PENALTY_MAX);
+    efficiency_factor / (1.0 - penalty)
+}
+
+/// Build an acclimation block for a hot race, or `None` when the race is not
+/// hot, already in the past, or the athlete is already acclimated.
+pub fn build_acclimation_protocol(
+    state: &HeatAcclimationMetrics,
+    race_date: NaiveDate,
+    race_temperature_c: f64,
+    today: NaiveDate,
+) -> Option<AcclimationProtocol> {
+    if race_temperature_c <= HEAT_EXPOSURE_THRESHOLD_C || race_date <= today {
+        return None;
+    }
+    if state.acclimation_index.unwrap_or(0.0) >= ACCLIMATED_INDEX {
+        return None;
+    }
+
+    let ideal_start = race_date - chrono::Duration::days(PROTOCOL_LEAD_DAYS);
+    let start_date = ideal_start.max(today);
+    let final_exposure_date = race_date - chrono::Duration::days(PROTOCOL_FINAL_EXPOSURE_DAYS);
+    let available_days = (final_exposure_date - start_date).num_days().max(0) + 1;
+    let sessions = PROTOCOL_SESSIONS.min(u32::try_from(available_days).unwrap_or(0));
+    if sessions == 0 {
+        return None;
+    }
+
+    Some(AcclimationProtocol {
+        start_date,
+        final_exposure_date,
+        race_date,
+        race_temperature_c,
+        sessions,
+        min_minutes: PROTOCOL_MIN_MINUTES,
+        max_minutes: PROTOCOL_MAX_MINUTES,
+        compressed: start_date > ideal_start,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn exposure(day: &str, minutes: f64) -> HeatExposure {
+        HeatExposure {
+            date: date(day),
+            minutes,
+            temperature_c: 30.0,
+        }
+    }
+
+    #[test]
+    fn extract_exposure_from_detail_temperature() {
+        let detail = json!({"moving_time": 3600, "average_weather_temp": 29.0});
+        let e = extract_heat_exposure(date("2026-07-01"), Some(&detail), None).unwrap();
+        assert!((e.minutes - 60.0).abs() < 1e-9);
+        assert!((e.temperature_c - 29.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn extract_exposure_cool_activity_counts_zero_minutes() {
+        let detail = json!({"moving_time": 3600, "average_temp": 15.0});
+        let e = extract_heat_exposure(date("2026-07-01"), Some(&detail), None).unwrap();
+        assert_eq!(e.minutes, 0.0);
+    }
+
+    #[test]
+    fn extract_exposure_prefers_temp_stream_share() {
+        let detail = json!({"moving_time": 3600, "average_temp": 20.0});
+        let streams = json!({"temp": [20.0, 22.0, 28.0, 30.0]});
+        let e = extract_heat_exposure(date("2026-07-01"), Some(&detail), Some(&streams)).unwrap();
+        assert!((e.minutes - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn extract_exposure_none_without_temperature() {
+        let detail = json!({"moving_time": 3600});
+        assert!(extract_heat_exposure(date("2026-07-01"), Some(&detail), None).is_none());
+    }
+
+    #[test]
+    fn acclimation_builds_with_daily_exposure() {
+        let exposures: Vec<HeatExposure> = (1..=7)
+            .map(|d| exposure(&format!("2026-07-{:02}", d), 90.0))
+            .collect();
+        let state = compute_heat_acclimation(&exposures, date("2026-07-07"));
+        assert!(state.supported);
+        assert_eq!(state.state, "acclimated");
+        assert_eq!(state.heat_sessions_14d, 7);
+        assert_eq!(state.days_since_exposure, Some(0));
+    }
+
+    #[test]
+    fn acclimation_decays_without_exposure() {
+        let exposures: Vec<HeatExposure> = (1..=7)
+            .map(|d| exposure(&format!("2026-07-{:02}", d), 90.0))
+            .collect();
+        let fresh = compute_heat_acclimation(&exposures, date("2026-07-07"));
+        let later = compute_heat_acclimation(&exposures, date("2026-08-20"));
+        assert!(later.acclimation_index.unwrap() < fresh.acclimation_index.unwrap() * 0.5);
+        assert_eq!(later.heat_sessions_14d, 0);
+    }
+
+    #[test]
+    fn acclimation_empty_is_unsupported() {
+        let state = compute_heat_acclimation(&[], date("2026-07-07"));
+        assert!(!state.supported);
+    }
+
+    #[test]
+    fn acclimation_from_details_attaches_adjusted_ef() {
+        let mut details = HashMap::new();
+        details.insert(
+            "a1".to_string(),
+            json!({"moving_time": 3600, "average_temp": 31.0, "icu_efficiency_factor": 1.4}),
+        );
+        details.insert("a2".to_string(), json!({"moving_time": 1800}));
+        let ids = vec![
+            (date("2026-07-01"), "a1".to_string()),
+            (date("2026-07-02"), "a2".to_string()),
+        ];
+        let metrics = compute_heat_acclimation_from_details(
+            &details,
+            &HashMap::new(),
+            &ids,
+            date("2026-07-03"),
+        );
+        assert!(metrics.supported);
+        assert_eq!(metrics.heat_sessions_14d, 1);
+        assert!(metrics.heat_adjusted_ef.unwrap() > 1.4);
+    }
+
+    #[test]
+    fn acclimation_from_details_uses_temp_streams() {
+        let mut details = HashMap::new();
+        details.insert(
+            "a1".to_string(),
+            json!({"moving_time": 3600, "average_temp": 23.0}),
+        );
+        let ids = vec![(date("2026-07-01"), "a1".to_string())];
+        assert!(is_heat_stream_candidate(&details["a1"]));
+        assert!(!is_heat_stream_candidate(&json!({"average_temp": 15.0})));
+
+        let without = compute_heat_acclimation_from_details(
+            &details,
+            &HashMap::new(),
+            &ids,
+            date("2026-07-02"),
+        );
+        assert_eq!(without.heat_sessions_14d, 0);
+
+        let mut streams = HashMap::new();
+        streams.insert("a1".to_string(), json!({"temp": [20.0, 21.0, 27.0, 28.0]}));
+        let with =
+            compute_heat_acclimation_from_details(&details, &streams, &ids, date("2026-07-02"));
+        assert_eq!(with.heat_sessions_14d, 1);
+        assert!((with.exposure_minutes_14d - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn heat_adjustment_reduces_decoupling_and_raises_ef() {
+        let adjusted = heat_adjusted_decoupling(8.0, 30.0, None);
+        assert!((adjusted - (8.0 - 12.0 * 0.3)).abs() < 1e-9);
+        let acclimated = heat_adjusted_decoupling(8.0, 30.0, Some(1.0));
+        assert!(acclimated > adjusted);
+        assert!(heat_adjusted_efficiency_factor(1.5, 30.0, None) > 1.5);
+        assert!((heat_adjusted_efficiency_factor(1.5, 15.0, None) - 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn protocol_for_hot_race_when_unacclimated() {
+        let state = HeatAcclimationMetrics::default();
+        let protocol =
+            build_acclimation_protocol(&state, date("2026-08-30"), 32.0, date("2026-07-01"))
+                .unwrap();
+        assert_eq!(protocol.start_date, date("2026-08-16"));
+        assert_eq!(protocol.final_exposure_date, date("2026-08-28"));
+        assert_eq!(protocol.sessions, 10);
+        assert!(!protocol.compressed);
+    }
+
+    #[test]
+    fn protocol_compressed_when_race_is_close() {
+        let state = HeatAcclimationMetrics::default();
+        let protocol =
+            build_acclimation_protocol(&state, date("2026-07-08"), 32.0, date("2026-07-01"))
+                .unwrap();
+        assert!(protocol.compressed);
+        assert_eq!(protocol.sessions, 6);
+    }
+
+    #[test]
+    fn protocol_skipped_for_cool_race_or_acclimated_athlete() {
+        let today = date("2026-07-01");
+        let race = date("2026-08-30");
+        assert!(
+            build_acclimation_protocol(&HeatAcclimationMetrics::default(), race, 18.0, today)
+                .is_none()
+        );
+        let acclimated = HeatAcclimationMetrics {
+            acclimation_index: Some(0.9),
+            ..Default::default()
+        };
+        assert!(build_acclimation_protocol(&acclimated, race, 32.0, today).is_none());
+    }
+}
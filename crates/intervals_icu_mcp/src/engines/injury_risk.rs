@@ -0,0 +1,490 @@
+//! Per-channel injury-risk model.
+//! Splits training into tissue / sport load channels (running impact, vertical
+//! descent, cycling, strength), computes an EWMA acute:chronic ratio for each,
+//! and flags week-over-week spikes in long-run distance and weekly descent.
+
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDate};
+use intervals_icu_client::ActivitySummary;
+use serde_json::Value;
+
+use crate::domains::coach::{ChannelLoadRisk, InjuryRiskMetrics, LoadSpike};
+use crate::engines::coach_metrics::{compute_acwr, get_number};
+use crate::engines::strength::STRENGTH_TYPES;
+
+// =============================================================================
+// Injury Risk Constants
+// Sources: Gabbett BJSM 2016 (ACWR sweet spot), Williams et al. BJSM 2017 (EWMA ACWR),
+//          Nielsen et al. JOSPT 2014 (weekly running progression),
+//          Frandsen et al. BJSM 2025 (single-session running spikes)
+// =============================================================================
+
+/// Days of channel history needed to seed the chronic EWMA.
+pub const CHANNEL_HISTORY_DAYS: i64 = 42;
+
+/// Days averaged to seed each channel's EWMAs.
+const SEED_WINDOW_DAYS: usize = 7;
+
+/// Intensity factor assumed for a run without `icu_intensity` (easy aerobic).
+const DEFAULT_RUN_INTENSITY: f64 = 0.75;
+
+/// Clamp range for the run intensity factor.
+const MIN_RUN_INTENSITY: f64 = 0.5;
+const MAX_RUN_INTENSITY: f64 = 1.5;
+
+/// Cycling load per moving hour when the activity has no training load.
+const CYCLING_LOAD_PER_HOUR: f64 = 50.0;
+
+/// Strength load per moving minute when the activity has no training load.
+const STRENGTH_LOAD_PER_MINUTE: f64 = 0.6;
+
+/// ACWR ratio above which a channel is moderate / high risk (Gabbett 2016).
+const CHANNEL_MODERATE_RATIO: f64 = 1.3;
+const CHANNEL_HIGH_RATIO: f64 = 1.5;
+
+/// Long run growth over the previous week that counts as a spike (Frandsen 2025: > 10%).
+const LONG_RUN_SPIKE_MODERATE: f64 = 1.1;
+const LONG_RUN_SPIKE_HIGH: f64 = 1.3;
+
+/// Minimum previous-week long run for spike detection (km).
+const LONG_RUN_MIN_BASELINE_KM: f64 = 5.0;
+
+/// Weekly descent growth that counts as a spike (Nielsen 2014: > 30% weekly progression).
+const DESCENT_SPIKE_MODERATE: f64 = 1.3;
+const DESCENT_SPIKE_HIGH: f64 = 1.6;
+
+/// Minimum previous-week descent for spike detection (m).
+const DESCENT_MIN_BASELINE_M: f64 = 300.0;
+
+const RUN_TYPES: &[&str] = &["Run", "TrailRun", "VirtualRun"];
+const FOOT_VERTICAL_TYPES: &[&str] = &["Run", "TrailRun", "Hike", "Walk"];
+const CYCLING_TYPES: &[&str] = &[
+    "Ride",
+    "VirtualRide",
+    "GravelRide",
+    "MountainBikeRide",
+    "EBikeRide",
+    "EMountainBikeRide",
+];
+
+/// Load channels in display order.
+pub const LOAD_CHANNELS: [&str; 4] = ["run_impact", "vertical", "cycling", "strength"];
+
+/// Channel loads contributed by one activity.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ChannelLoads {
+    /// Run distance (km) × intensity factor.
+    pub run_impact: f64,
+    pub run_distance_km: f64,
+    /// Descent on foot (m).
+    pub descent_m: f64,
+    pub cycling: f64,
+    pub strength: f64,
+}
+
+impl ChannelLoads {
+    fn channel(&self, name: &str) -> f64 {
+        match name {
+            "run_impact" => self.run_impact,
+            "vertical" => self.descent_m,
+            "cycling" => self.cycling,
+            "strength" => self.strength,
+            _ => 0.0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        LOAD_CHANNELS
+            .iter()
+            .all(|channel| self.channel(channel) <= 0.0)
+    }
+}
+
+/// Human-readable channel label.
+#[must_use]
+pub fn channel_label(channel: &str) -> &'static str {
+    match channel {
+        "run_impact" => "Run impact",
+        "vertical" => "Vertical / descent",
+        "cycling" => "Cycling",
+        "strength" => "Strength",
+        _ => "Unknown",
+    }
+}
+
+/// Split one activity detail into channel loads. Returns `None` when the
+/// activity type feeds no channel.
+#[must_use]
+pub fn extract_channel_loads(detail: &Value) -> Option<ChannelLoads> {
+    let object = detail.as_object()?;
+    let sport = object.get("type").and_then(Value::as_str)?;
+    let moving_secs = get_number(object, &["moving_time"]).unwrap_or(0.0);
+    let training_load = get_number(object, &["icu_training_load"]).filter(|load| *load > 0.0);
+    let mut loads = ChannelLoads::default();
+
+    if RUN_TYPES.contains(&sport) {
+        let distance_km = get_number(object, &["distance"]).unwrap_or(0.0) / 1000.0;
+        let intensity = get_number(object, &["icu_intensity"])
+            .map(|pct| pct / 100.0)
+            .unwrap_or(DEFAULT_RUN_INTENSITY)
+            .clamp(MIN_RUN_INTENSITY, MAX_RUN_INTENSITY);
+        loads.run_distance_km = distance_km;
+        loads.run_impact = distance_km * intensity;
+    }
+    if FOOT_VERTICAL_TYPES.contains(&sport) {
+        // Loop courses: fall back to gain when descent is not recorded.
+        loads.descent_m =
+            get_number(object, &["total_elevation_loss", "total_elevation_gain"]).unwrap_or(0.0);
+    }
+    if CYCLING_TYPES.contains(&sport) {
+        loads.cycling = training_load.unwrap_or(moving_secs / 3600.0 * CYCLING_LOAD_PER_HOUR);
+    }
+    if STRENGTH_TYPES.contains(&sport) {
+        loads.strength = training_load.unwrap_or(moving_secs / 60.0 * STRENGTH_LOAD_PER_MINUTE);
+    }
+
+    (!loads.is_empty()).then_some(loads)
+}
+
+fn classify_channel_ratio(ratio: f64) -> &'static str {
+    if ratio > CHANNEL_HIGH_RATIO {
+        "high"
+    } else if ratio > CHANNEL_MODERATE_RATIO {
+        "moderate"
+    } else {
+        "low"
+    }
+}
+
+fn risk_rank(risk: &str) -> u8 {
+    match risk {
+        "high" => 2,
+        "moderate" => 1,
+        _ => 0,
+    }
+}
+
+fn detect_spike(
+    kind: &str,
+    channel: &str,
+    previous: f64,
+    current: f64,
+    min_baseline: f64,
+    moderate: f64,
+    high: f64,
+) -> Option<LoadSpike> {
+    if previous < min_baseline {
+        return None;
+    }
+    let ratio = current / previous;
+    let severity = if ratio > high {
+        "high"
+    } else if ratio > moderate {
+        "moderate"
+    } else {
+        return None;
+    };
+    Some(LoadSpike {
+        kind: kind.into(),
+        channel: channel.into(),
+        previous,
+        current,
+        ratio,
+        severity: severity.into(),
+    })
+}
+
+/// Compute per-channel ACWR and week-over-week spikes from dated channel loads.
+#[must_use]
+pub fn compute_injury_risk(
+    sessions: &[(NaiveDate, ChannelLoads)],
+    as_of: NaiveDate,
+) -> InjuryRiskMetrics {
+    let start = as_of - Duration::days(CHANNEL_HISTORY_DAYS - 1);
+    let in_window = sessions
+        .iter()
+        .filter(|(date, _)| *date >= start && *date <= as_of)
+        .collect::<Vec<_>>();
+    if in_window.is_empty() {
+        return InjuryRiskMetrics::unsupported();
+    }
+
+    let mut daily = vec![ChannelLoads::default(); CHANNEL_HISTORY_DAYS as usize];
+    for (date, loads) in &in_window {
+        let day = &mut daily[(*date - start).num_days() as usize];
+        day.run_impact += loads.run_impact;
+        day.run_distance_km = day.run_distance_km.max(loads.run_distance_km);
+        day.descent_m += loads.descent_m;
+        day.cycling += loads.cycling;
+        day.strength += loads.strength;
+    }
+
+    let mut channels = Vec::new();
+    for channel in LOAD_CHANNELS {
+        let series = daily
+            .iter()
+            .map(|day| day.channel(channel))
+            .collect::<Vec<_>>();
+        if series.iter().all(|load| *load <= 0.0) {
+            continue;
+        }
+        // Sessions are sparse per channel: seed both EWMAs with the first
+        // week's mean instead of whatever single day opens the window.
+        let seed = series[..SEED_WINDOW_DAYS].iter().sum::<f64>() / SEED_WINDOW_DAYS as f64;
+        let seeded = std::iter::once(seed)
+            .chain(series.iter().copied())
+            .collect::<Vec<_>>();
+        let Some(acwr) = compute_acwr(&seeded) else {
+            continue;
+        };
+        channels.push(ChannelLoadRisk {
+            channel: channel.into(),
+            risk: classify_channel_ratio(acwr.ratio).into(),
+            load_7d: series[series.len() - 7..].iter().sum(),
+            acwr,
+        });
+    }
+
+    let current_week = &daily[daily.len() - 7..];
+    let previous_week = &daily[daily.len() - 14..daily.len() - 7];
+    let longest = |week: &[ChannelLoads]| {
+        week.iter()
+            .map(|day| day.run_distance_km)
+            .fold(0.0, f64::max)
+    };
+    let descent = |week: &[ChannelLoads]| week.iter().map(|day| day.descent_m).sum::<f64>();
+
+    let spikes = [
+        detect_spike(
+            "long_run_distance",
+            "run_impact",
+            longest(previous_week),
+            longest(current_week),
+            LONG_RUN_MIN_BASELINE_KM,
+            LONG_RUN_SPIKE_MODERATE,
+            LONG_RUN_SPIKE_HIGH,
+        ),
+        detect_spike(
+            "weekly_descent",
+            "vertical",
+            descent(previous_week),
+            descent(current_week),
+            DESCENT_MIN_BASELINE_M,
+            DESCENT_SPIKE_MODERATE,
+            DESCENT_SPIKE_HIGH,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    // A channel's risk is the worse of its ACWR band and any spike on it;
+    // ties go to the higher ratio.
+    let driver = LOAD_CHANNELS
+        .iter()
+        .filter_map(|channel| {
+            let acwr = channels.iter().find(|risk| risk.channel == *channel);
+            let spike_rank = spikes
+                .iter()
+                .filter(|spike| spike.channel == *channel)
+                .map(|spike| risk_rank(&spike.severity))
+                .max()
+                .unwrap_or(0);
+            let rank = acwr
+                .map(|risk| risk_rank(&risk.risk))
+                .unwrap_or(0)
+                .max(spike_rank);
+            let ratio = acwr.map(|risk| risk.acwr.ratio).unwrap_or(0.0);
+            (rank > 0).then_some((*channel, rank, ratio))
+        })
+        .max_by(|a, b| a.1.cmp(&b.1).then(a.2.total_cmp(&b.2)));
+
+    let risk_level = match driver.map(|(_, rank, _)| rank) {
+        Some(2) => "high",
+        Some(_) => "moderate",
+        None => "low",
+    };
+
+    InjuryRiskMetrics {
+        supported: true,
+        channels,
+        spikes,
+        driving_channel: driver.map(|(channel, _, _)| channel.to_string()),
+        risk_level: risk_level.into(),
+    }
+}
+
+/// Convenience wrapper: extract channel loads from activity details and
+/// compute the injury-risk model as of `as_of`.
+#[must_use]
+pub fn compute_injury_risk_from_details(
+    activities: &[ActivitySummary],
+    details: &HashMap<String, Value>,
+    as_of: NaiveDate,
+) -> InjuryRiskMetrics {
+    let sessions = activities
+        .iter()
+        .filter_map(|activity| {
+            let date = activity
+                .start_date_local
+                .get(..10)
+                .and_then(|value| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())?;
+            let loads = extract_channel_loads(details.get(&activity.id)?)?;
+            Some((date, loads))
+        })
+        .collect::<Vec<_>>();
+    compute_injury_risk(&sessions, as_of)
+}
+
+impl InjuryRiskMetrics {
+    #[must_use]
+    pub fn unsupported() -> Self {
+        Self {
+            supported: false,
+            risk_level: "unknown".into(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn date(day: i64) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 31).unwrap() - Duration::days(day)
+    }
+
+    fn run(km: f64, descent: f64) -> ChannelLoads {
+        ChannelLoads {
+            run_impact: km * DEFAULT_RUN_INTENSITY,
+            run_distance_km: km,
+            descent_m: descent,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn extract_channel_loads_splits_run_and_descent() {
+        let detail = json!({
+            "type": "TrailRun",
+            "distance": 20000.0,
+            "icu_intensity": 80.0,
+            "total_elevation_gain": 900.0,
+            "total_elevation_loss": 850.0
+        });
+        let loads = extract_channel_loads(&detail).unwrap();
+        assert!((loads.run_impact - 16.0).abs() < 1e-9);
+        assert!((loads.run_distance_km - 20.0).abs() < 1e-9);
+        assert!((loads.descent_m - 850.0).abs() < 1e-9);
+        assert_eq!(loads.cycling, 0.0);
+    }
+
+    #[test]
+    fn extract_channel_loads_falls_back_to_duration_for_strength_and_cycling() {
+        let strength =
+            extract_channel_loads(&json!({"type": "WeightTraining", "moving_time": 3000})).unwrap();
+        assert!((strength.strength - 30.0).abs() < 1e-9);
+
+        let ride =
+            extract_channel_loads(&json!({"type": "Ride", "icu_training_load": 85})).unwrap();
+        assert!((ride.cycling - 85.0).abs() < 1e-9);
+
+        assert!(extract_channel_loads(&json!({"type": "Swim", "distance": 2000.0})).is_none());
+        assert!(extract_channel_loads(&json!({"distance": 2000.0})).is_none());
+    }
+
+    #[test]
+    fn compute_injury_risk_unsupported_without_sessions() {
+        let risk = compute_injury_risk(&[], date(0));
+        assert!(!risk.supported);
+        assert!(risk.channels.is_empty());
+    }
+
+    #[test]
+    fn steady_running_is_low_risk() {
+        let sessions = (0..CHANNEL_HISTORY_DAYS)
+            .map(|day| (date(day), run(10.0, 100.0)))
+            .collect::<Vec<_>>();
+        let risk = compute_injury_risk(&sessions, date(0));
+        assert!(risk.supported);
+        assert_eq!(risk.risk_level, "low");
+        assert!(risk.driving_channel.is_none());
+        assert!(risk.spikes.is_empty());
+        assert!(risk.channels.iter().any(|c| c.channel == "run_impact"));
+    }
+
+    #[test]
+    fn descent_surge_drives_risk_through_vertical_channel() {
+        let mut sessions = (7..CHANNEL_HISTORY_DAYS)
+            .step_by(2)
+            .map(|day| (date(day), run(10.0, 100.0)))
+            .collect::<Vec<_>>();
+        for day in [0, 2, 4, 6] {
+            sessions.push((date(day), run(10.0, 800.0)));
+        }
+        let risk = compute_injury_risk(&sessions, date(0));
+        assert_eq!(risk.risk_level, "high");
+        assert_eq!(risk.driving_channel.as_deref(), Some("vertical"));
+        let spike = risk
+            .spikes
+            .iter()
+            .find(|spike| spike.kind == "weekly_descent")
+            .unwrap();
+        assert_eq!(spike.severity, "high");
+        let run_channel = risk
+            .channels
+            .iter()
+            .find(|c| c.channel == "run_impact")
+            .unwrap();
+        assert_eq!(run_channel.risk, "low");
+    }
+
+    #[test]
+    fn long_run_spike_is_flagged_against_previous_week() {
+        let mut sessions = (0..CHANNEL_HISTORY_DAYS)
+            .step_by(2)
+            .map(|day| (date(day), run(8.0, 0.0)))
+            .collect::<Vec<_>>();
+        sessions.push((date(9), run(16.0, 0.0)));
+        sessions.push((date(1), run(19.0, 0.0)));
+        let risk = compute_injury_risk(&sessions, date(0));
+        let spike = risk
+            .spikes
+            .iter()
+            .find(|spike| spike.kind == "long_run_distance")
+            .unwrap();
+        assert_eq!(spike.severity, "moderate");
+        assert!((spike.previous - 16.0).abs() < 1e-9);
+        assert!((spike.current - 19.0).abs() < 1e-9);
+        assert_eq!(risk.driving_channel.as_deref(), Some("run_impact"));
+    }
+
+    #[test]
+    fn compute_injury_risk_from_details_uses_activity_dates() {
+        let as_of = date(0);
+        let activities = (0..CHANNEL_HISTORY_DAYS)
+            .step_by(3)
+            .map(|day| ActivitySummary {
+                id: format!("a{day}"),
+                start_date_local: format!("{}T07:00:00", date(day)),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        let details = activities
+            .iter()
+            .map(|activity| {
+                (
+                    activity.id.clone(),
+                    json!({"type": "Ride", "icu_training_load": 60}),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        let risk = compute_injury_risk_from_details(&activities, &details, as_of);
+        assert!(risk.supported);
+        assert_eq!(risk.channels.len(), 1);
+        assert_eq!(risk.channels[0].channel, "cycling");
+    }
+}
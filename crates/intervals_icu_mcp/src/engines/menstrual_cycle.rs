@@ -0,0 +1,273 @@
+//! Menstrual-cycle phase model.
+//! Reads the Intervals.icu `menstrualPhase` wellness field (falling back to the
+//! predicted phase), supplies the expected phase shift in resting HR and HRV used
+//! to adjust personal-baseline comparisons, and predicts the phase for future
+//! dates from the last period start so plans can favour better-tolerated phases.
+
+use chrono::NaiveDate;
+use serde_json::Value;
+
+// =============================================================================
+// Menstrual Cycle Constants
+// Sources: Schmalenberger et al., Front Physiol 2019 (HRV lower in the luteal
+//          phase); Janse de Jonge, Sports Med 2003 (luteal RHR rise);
+//          McNulty et al., Sports Med 2020 (small, highly individual
+//          performance effects — phase guidance should follow athlete feedback)
+// =============================================================================
+
+/// Typical cycle length used when none is supplied (days).
+pub const DEFAULT_CYCLE_LENGTH_DAYS: i64 = 28;
+
+/// Accepted cycle lengths (days).
+pub const MIN_CYCLE_LENGTH_DAYS: i64 = 21;
+pub const MAX_CYCLE_LENGTH_DAYS: i64 = 40;
+
+/// Days of menstruation counted as the period phase.
+const PERIOD_DAYS: i64 = 5;
+
+/// Luteal phase length, fairly constant across cycle lengths (days).
+const LUTEAL_DAYS: i64 = 14;
+
+/// Expected luteal shift relative to the cycle-average baseline.
+const LUTEAL_RHR_SHIFT_BPM: f64 = 2.5;
+const LUTEAL_HRV_SHIFT_PCT: f64 = -6.0;
+
+/// Residual shift carried into the first days of the period.
+const PERIOD_RHR_SHIFT_BPM: f64 = 1.0;
+const PERIOD_HRV_SHIFT_PCT: f64 = -3.0;
+
+/// Day-to-day noise below which a deviation is not worth explaining.
+const HRV_NOISE_PCT: f64 = 3.0;
+const RHR_NOISE_BPM: f64 = 1.0;
+
+const PHASE_KEYS: &[&str] = &[
+    "menstrualPhase",
+    "menstrual_phase",
+    "menstrualPhasePredicted",
+];
+
+/// Cycle phase as reported by Intervals.icu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CyclePhase {
+    Period,
+    Follicular,
+    Ovulating,
+    Luteal,
+}
+
+impl CyclePhase {
+    /// Parse Intervals.icu values (`PERIOD`, `LUTEAL`, …) and common aliases.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "period" | "menstrual" | "menstruation" => Some(Self::Period),
+            "follicular" => Some(Self::Follicular),
+            "ovulating" | "ovulation" | "ovulatory" => Some(Self::Ovulating),
+            "luteal" => Some(Self::Luteal),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Period => "period",
+            Self::Follicular => "follicular",
+            Self::Ovulating => "ovulating",
+            Self::Luteal => "luteal",
+        }
+    }
+}
+
+/// Expected phase shift of resting HR (bpm) and HRV (%) from the personal baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PhaseShift {
+    pub rhr_bpm: f64,
+    pub hrv_pct: f64,
+}
+
+impl PhaseShift {
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.rhr_bpm == 0.0 && self.hrv_pct == 0.0
+    }
+}
+
+#[must_use]
+pub fn expected_shift(phase: CyclePhase) -> PhaseShift {
+    match phase {
+        CyclePhase::Luteal => PhaseShift {
+            rhr_bpm: LUTEAL_RHR_SHIFT_BPM,
+            hrv_pct: LUTEAL_HRV_SHIFT_PCT,
+        },
+        CyclePhase::Period => PhaseShift {
+            rhr_bpm: PERIOD_RHR_SHIFT_BPM,
+            hrv_pct: PERIOD_HRV_SHIFT_PCT,
+        },
+        CyclePhase::Follicular | CyclePhase::Ovulating => PhaseShift::default(),
+    }
+}
+
+/// Phase recorded on a wellness entry (explicit phase before predicted).
+#[must_use]
+pub fn entry_phase(entry: &Value) -> Option<CyclePhase> {
+    PHASE_KEYS.iter().find_map(|key| {
+        entry
+            .get(*key)
+            .and_then(Value::as_str)
+            .and_then(CyclePhase::parse)
+    })
+}
+
+/// Phase on the most recent wellness entry, provided that entry carries one.
+#[must_use]
+pub fn latest_phase(entries: &[Value]) -> Option<CyclePhase> {
+    entries.last().and_then(entry_phase)
+}
+
+fn entry_date(entry: &Value) -> Option<NaiveDate> {
+    ["id", "date"].iter().find_map(|key| {
+        entry
+            .get(*key)
+            .and_then(Value::as_str)
+            .and_then(|date| date.get(..10))
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+    })
+}
+
+/// First day of the most recent logged period in chronologically ordered entries.
+#[must_use]
+pub fn last_period_start(entries: &[Value]) -> Option<NaiveDate> {
+    let mut start = None;
+    let mut previous: Option<(NaiveDate, bool)> = None;
+    for entry in entries {
+        let Some(date) = entry_date(entry) else {
+            continue;
+        };
+        let is_period = entry_phase(entry) == Some(CyclePhase::Period);
+        let continues = previous.is_some_and(|(prev_date, prev_period)| {
+            prev_period && (date - prev_date).num_days() <= 1
+        });
+        if is_period && !continues {
+            start = Some(date);
+        }
+        previous = Some((date, is_period));
+    }
+    start
+}
+
+/// Predicted phase on `date` for a cycle starting on `period_start`.
+/// Ovulation is placed `LUTEAL_DAYS` before the next period, with a 3-day window.
+#[must_use]
+pub fn predict_phase(
+    date: NaiveDate,
+    period_start: NaiveDate,
+    cycle_length_days: i64,
+) -> CyclePhase {
+    let length = cycle_length_days.clamp(MIN_CYCLE_LENGTH_DAYS, MAX_CYCLE_LENGTH_DAYS);
+    let day = (date - period_start).num_days().rem_euclid(length);
+    let ovulation = length - LUTEAL_DAYS;
+    if day < PERIOD_DAYS {
+        CyclePhase::Period
+    } else if day < ovulation - 1 {
+        CyclePhase::Follicular
+    } else if day <= ovulation + 1 {
+        CyclePhase::Ovulating
+    } else {
+        CyclePhase::Luteal
+    }
+}
+
+/// Whether the HRV/resting-HR deviation against the personal baseline is what
+/// the phase predicts: at least one signal moves beyond day-to-day noise, and
+/// every available signal stays within the expected shift plus noise.
+#[must_use]
+pub fn deviation_explained_by_phase(
+    shift: PhaseShift,
+    hrv_deviation_pct: Option<f64>,
+    rhr_delta_bpm: Option<f64>,
+) -> bool {
+    if shift.is_zero() || (hrv_deviation_pct.is_none() && rhr_delta_bpm.is_none()) {
+        return false;
+    }
+    let hrv_within =
+        hrv_deviation_pct.is_none_or(|deviation| deviation >= shift.hrv_pct - HRV_NOISE_PCT);
+    let rhr_within = rhr_delta_bpm.is_none_or(|delta| delta <= shift.rhr_bpm + RHR_NOISE_BPM);
+    let deviates = hrv_deviation_pct.is_some_and(|deviation| deviation <= -HRV_NOISE_PCT)
+        || rhr_delta_bpm.is_some_and(|delta| delta >= RHR_NOISE_BPM);
+    hrv_within && rhr_within && deviates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    #[test]
+    fn parses_intervals_and_alias_values() {
+        assert_eq!(CyclePhase::parse("LUTEAL"), Some(CyclePhase::Luteal));
+        assert_eq!(CyclePhase::parse("ovulation"), Some(CyclePhase::Ovulating));
+        assert_eq!(CyclePhase::parse("NONE"), None);
+        assert_eq!(
+            entry_phase(&json!({"menstrualPhasePredicted": "PERIOD"})),
+            Some(CyclePhase::Period)
+        );
+        assert_eq!(
+            entry_phase(
+                &json!({"menstrualPhase": "FOLLICULAR", "menstrualPhasePredicted": "LUTEAL"})
+            ),
+            Some(CyclePhase::Follicular)
+        );
+    }
+
+    #[test]
+    fn predicts_phases_across_a_28_day_cycle() {
+        let start = date(1);
+        assert_eq!(predict_phase(date(1), start, 28), CyclePhase::Period);
+        assert_eq!(predict_phase(date(8), start, 28), CyclePhase::Follicular);
+        assert_eq!(predict_phase(date(15), start, 28), CyclePhase::Ovulating);
+        assert_eq!(predict_phase(date(20), start, 28), CyclePhase::Luteal);
+        // Next cycle wraps back to the period.
+        assert_eq!(predict_phase(date(29), start, 28), CyclePhase::Period);
+    }
+
+    #[test]
+    fn finds_start_of_latest_period() {
+        let entries = vec![
+            json!({"id": "2026-02-01", "menstrualPhase": "PERIOD"}),
+            json!({"id": "2026-02-02", "menstrualPhase": "PERIOD"}),
+            json!({"id": "2026-02-20", "menstrualPhase": "LUTEAL"}),
+            json!({"id": "2026-03-01", "menstrualPhase": "PERIOD"}),
+            json!({"id": "2026-03-02", "menstrualPhase": "PERIOD"}),
+        ];
+        assert_eq!(last_period_start(&entries), Some(date(1)));
+        assert_eq!(last_period_start(&entries[2..3]), None);
+    }
+
+    #[test]
+    fn luteal_shift_explains_moderate_deviation_only() {
+        let luteal = expected_shift(CyclePhase::Luteal);
+        assert!(deviation_explained_by_phase(luteal, Some(-7.0), None));
+        assert!(deviation_explained_by_phase(luteal, None, Some(3.0)));
+        assert!(!deviation_explained_by_phase(
+            luteal,
+            Some(-15.0),
+            Some(6.0)
+        ));
+        assert!(!deviation_explained_by_phase(luteal, Some(-1.0), Some(0.5)));
+        // One signal in range does not explain the other one far outside it.
+        assert!(deviation_explained_by_phase(luteal, Some(-5.0), Some(2.0)));
+        assert!(!deviation_explained_by_phase(
+            luteal,
+            Some(-25.0),
+            Some(2.0)
+        ));
+        assert!(!deviation_explained_by_phase(luteal, Some(-5.0), Some(8.0)));
+        let follicular = expected_shift(CyclePhase::Follicular);
+        assert!(!deviation_explained_by_phase(follicular, Some(-7.0), None));
+    }
+}
@@ -0,0 +1,491 @@
+//! Personal records and best-effort progression.
+//! Reads all-time, season and rolling-90-day bests at standard durations (power)
+//! and distances (pace) from Intervals.icu activity curves, flags the records an
+//! analysed activity holds, and charts one best-effort anchor month by month
+//! from curves bounded to each month.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{Datelike, Months, NaiveDate};
+use intervals_icu_client::ActivitySummary;
+use serde_json::Value;
+
+use crate::domains::records::{
+    NewRecord, PersonalRecord, ProgressionPoint, RecordMetric, RecordRow, RecordWindow,
+};
+use crate::domains::season::BestEffortKind;
+use crate::engines::season_review::{
+    EffortCurves, parse_date, record_curve_best_efforts, record_wellness_best_efforts,
+};
+
+// =============================================================================
+// Personal Records Constants
+// Sources: Intervals.icu athlete activity-power-curves / activity-pace-curves
+//          (parallel `secs`/`distance`, `values` and `activity_id` arrays)
+// =============================================================================
+
+/// Lookback used for all-time records (days).
+pub const ALL_TIME_DAYS: i32 = 3650;
+
+/// Lookback of the rolling record window (days).
+pub const ROLLING_RECORD_DAYS: i32 = 90;
+
+/// Standard power durations (seconds).
+pub const POWER_DURATIONS_SECS: &[u32] = &[5, 60, 300, 1200, 3600];
+
+/// Standard pace distances (metres).
+pub const PACE_DISTANCES_M: &[u32] = &[400, 1000, 5000, 10000, 21097, 42195];
+
+/// Default and allowed progression spans (months).
+pub const DEFAULT_PROGRESSION_MONTHS: u32 = 12;
+pub const MIN_PROGRESSION_MONTHS: u32 = 3;
+pub const MAX_PROGRESSION_MONTHS: u32 = 24;
+
+/// One point of a best-effort curve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurvePoint {
+    pub anchor: f64,
+    pub value: f64,
+    pub activity_id: Option<String>,
+}
+
+/// Curve sport for an activity type, or `None` when records are not tracked.
+#[must_use]
+pub fn curve_sport(activity_type: &str) -> Option<&'static str> {
+    match activity_type {
+        "Ride" | "VirtualRide" | "GravelRide" | "MountainBikeRide" | "EBikeRide" => Some("Ride"),
+        "Run" | "TrailRun" | "VirtualRun" => Some("Run"),
+        _ => None,
+    }
+}
+
+/// Metrics tracked for a curve sport: power for rides, power and pace for runs.
+#[must_use]
+pub fn sport_metrics(sport: &str) -> &'static [RecordMetric] {
+    match sport {
+        "Run" => &[RecordMetric::Power, RecordMetric::Pace],
+        _ => &[RecordMetric::Power],
+    }
+}
+
+#[must_use]
+pub fn metric_anchors(metric: RecordMetric) -> &'static [u32] {
+    match metric {
+        RecordMetric::Power => POWER_DURATIONS_SECS,
+        RecordMetric::Pace => PACE_DISTANCES_M,
+    }
+}
+
+/// Days back from `today` that each window covers.
+#[must_use]
+pub fn window_days(window: RecordWindow, today: NaiveDate) -> i32 {
+    match window {
+        RecordWindow::AllTime => ALL_TIME_DAYS,
+        RecordWindow::Season => today.ordinal() as i32,
+        RecordWindow::Rolling90 => ROLLING_RECORD_DAYS,
+    }
+}
+
+fn numbers(item: &Value, key: &str) -> Option<Vec<Option<f64>>> {
+    item.get(key).and_then(Value::as_array).map(|values| {
+        values
+            .iter()
+            .map(|value| value.as_f64().filter(|value| value.is_finite()))
+            .collect()
+    })
+}
+
+fn curve_items(payload: &Value) -> Vec<&Value> {
+    match payload {
+        Value::Array(items) => items.iter().collect(),
+        Value::Object(object) => match object.get("list").and_then(Value::as_array) {
+            Some(items) => items.iter().collect(),
+            None => vec![payload],
+        },
+        _ => Vec::new(),
+    }
+}
+
+/// Parses a curve payload into points sorted by anchor. Accepts a bare item, an
+/// array of items or a `{"list": [...]}` wrapper; power items pair `secs` with
+/// `values` (or `watts`), pace items pair `distance` with `values` (or `secs`).
+#[must_use]
+pub fn parse_curve_points(payload: &Value, metric: RecordMetric) -> Vec<CurvePoint> {
+    let (anchor_key, value_keys): (&str, &[&str]) = match metric {
+        RecordMetric::Power => ("secs", &["values", "watts"]),
+        RecordMetric::Pace => ("distance", &["values", "secs"]),
+    };
+    let mut points = curve_items(payload)
+        .into_iter()
+        .filter_map(|item| {
+            let anchors = numbers(item, anchor_key)?;
+            let values = value_keys.iter().find_map(|key| numbers(item, key))?;
+            let ids = item.get("activity_id").and_then(Value::as_array);
+            Some(
+                anchors
+                    .into_iter()
+                    .zip(values)
+                    .enumerate()
+                    .filter_map(|(index, (anchor, value))| {
+                        let (anchor, value) = (anchor?, value?);
+                        (anchor > 0.0 && value > 0.0).then(|| CurvePoint {
+                            anchor,
+                            value,
+                            activity_id: ids.and_then(|ids| ids.get(index)).and_then(
+                                |id| match id {
+                                    Value::String(id) => Some(id.clone()),
+                                    Value::Number(id) => Some(id.to_string()),
+                                    _ => None,
+                                },
+                            ),
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+    points.sort_by(|a, b| a.anchor.total_cmp(&b.anchor));
+    points
+}
+
+/// Value at an anchor: an exact point keeps its activity; otherwise the value
+/// is interpolated between the bracketing points and carries no activity.
+#[must_use]
+pub fn value_at(points: &[CurvePoint], anchor: u32) -> Option<(f64, Option<String>)> {
+    let anchor = f64::from(anchor);
+    if let Some(point) = points
+        .iter()
+        .find(|point| (point.anchor - anchor).abs() < f64::EPSILON)
+    {
+        return Some((point.value, point.activity_id.clone()));
+    }
+    let upper = points.iter().position(|point| point.anchor > anchor)?;
+    let lower = points.get(upper.checked_sub(1)?)?;
+    let upper = &points[upper];
+    let share = (anchor - lower.anchor) / (upper.anchor - lower.anchor);
+    Some((lower.value + (upper.value - lower.value) * share, None))
+}
+
+fn records_for(points: &[CurvePoint], metric: RecordMetric) -> HashMap<u32, PersonalRecord> {
+    metric_anchors(metric)
+        .iter()
+        .filter_map(|&anchor| {
+            let (value, activity_id) = value_at(points, anchor)?;
+            Some((
+                anchor,
+                PersonalRecord {
+                    metric,
+                    anchor,
+                    value,
+                    activity_id,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Record rows for one metric from the all-time, season and rolling-90-day curves.
+#[must_use]
+pub fn build_record_rows(
+    metric: RecordMetric,
+    all_time: Option<&Value>,
+    season: Option<&Value>,
+    rolling_90: Option<&Value>,
+) -> Vec<RecordRow> {
+    let window = |payload: Option<&Value>| {
+        payload
+            .map(|payload| records_for(&parse_curve_points(payload, metric), metric))
+            .unwrap_or_default()
+    };
+    let (mut all_time, mut season, mut rolling_90) =
+        (window(all_time), window(season), window(rolling_90));
+    metric_anchors(metric)
+        .iter()
+        .map(|anchor| RecordRow {
+            metric,
+            anchor: *anchor,
+            all_time: all_time.remove(anchor),
+            season: season.remove(anchor),
+            rolling_90: rolling_90.remove(anchor),
+        })
+        .filter(|row| row.all_time.is_some() || row.season.is_some() || row.rolling_90.is_some())
+        .collect()
+}
+
+/// Records the activity holds, each in the broadest window it holds it in.
+#[must_use]
+pub fn detect_new_records(rows: &[RecordRow], activity_id: &str) -> Vec<NewRecord> {
+    rows.iter()
+        .filter_map(|row| {
+            [
+                (RecordWindow::AllTime, &row.all_time),
+                (RecordWindow::Season, &row.season),
+                (RecordWindow::Rolling90, &row.rolling_90),
+            ]
+            .into_iter()
+            .find_map(|(window, record)| {
+                let record = record.as_ref()?;
+                (record.activity_id.as_deref() == Some(activity_id)).then_some(NewRecord {
+                    metric: row.metric,
+                    anchor: row.anchor,
+                    value: record.value,
+                    window,
+                })
+            })
+        })
+        .collect()
+}
+
+/// The `months` calendar months ending with `today`'s month, as `YYYY-MM`
+/// with the first and last day; the current month ends at `today`.
+#[must_use]
+pub fn progression_months(months: u32, today: NaiveDate) -> Vec<(String, NaiveDate, NaiveDate)> {
+    let this_month = today.with_day(1).unwrap_or(today);
+    let first_month = this_month
+        .checked_sub_months(Months::new(months.saturating_sub(1)))
+        .unwrap_or(this_month);
+    (0..months)
+        .filter_map(|offset| first_month.checked_add_months(Months::new(offset)))
+        .map(|month| {
+            let last = month
+                .checked_add_months(Months::new(1))
+                .and_then(|next| next.pred_opt())
+                .unwrap_or(month)
+                .min(today);
+            (
+                format!("{:04}-{:02}", month.year(), month.month()),
+                month,
+                last,
+            )
+        })
+        .collect()
+}
+
+/// Monthly best of one effort over the `months` calendar months ending with
+/// `today`'s month. Curve efforts are read from `month_curves`, keyed by
+/// `YYYY-MM` and bounded to that month; eFTP from the wellness eFTP history.
+/// Months without a qualifying value have no best.
+#[must_use]
+pub fn monthly_progression(
+    activities: &[ActivitySummary],
+    wellness: Option<&Value>,
+    month_curves: &HashMap<String, EffortCurves>,
+    kind: BestEffortKind,
+    months: u32,
+    today: NaiveDate,
+) -> Vec<ProgressionPoint> {
+    progression_months(months, today)
+        .into_iter()
+        .map(|(month, first, last)| {
+            let in_month = activities
+                .iter()
+                .filter_map(|activity| {
+                    let date = parse_date(&activity.start_date_local)?;
+                    (date >= first && date <= last).then_some((activity, date))
+                })
+                .collect::<Vec<_>>();
+            let mut best = BTreeMap::new();
+            record_wellness_best_efforts(&mut best, wellness, first, last);
+            if let Some(curves) = month_curves.get(&month) {
+                let dates = in_month
+                    .iter()
+                    .map(|(activity, date)| (activity.id.as_str(), *date))
+                    .collect::<HashMap<_, _>>();
+                record_curve_best_efforts(&mut best, curves, &dates);
+            }
+            ProgressionPoint {
+                month,
+                best: best.remove(&kind),
+            }
+        })
+        .collect()
+}
+
+/// Change from the first to the last month with a value, in percent, signed so
+/// that a positive number is an improvement.
+#[must_use]
+pub fn progression_change_pct(points: &[ProgressionPoint], kind: BestEffortKind) -> Option<f64> {
+    let mut values = points
+        .iter()
+        .filter_map(|point| point.best.as_ref().map(|best| best.value));
+    let first = values.next()?;
+    let last = values.next_back()?;
+    let change = (last - first) / first * 100.0;
+    Some(if kind.higher_is_better() {
+        change
+    } else {
+        -change
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use intervals_icu_client::domains::curve::CurveKind;
+    use serde_json::json;
+
+    fn power_curve() -> Value {
+        json!({"list": [{
+            "secs": [5, 60, 300, 1200, 3600],
+            "values": [900, 420, 330, 290, 260],
+            "activity_id": ["i1", "i2", "i3", "i9", "i4"]
+        }]})
+    }
+
+    #[test]
+    fn parses_power_and_interpolates_pace_anchors() {
+        let points = parse_curve_points(&power_curve(), RecordMetric::Power);
+        assert_eq!(points.len(), 5);
+        assert_eq!(value_at(&points, 1200), Some((290.0, Some("i9".into()))));
+
+        let pace = json!([{"distance": [1000, 4000, 6000], "values": [200, 900, 1400]}]);
+        let points = parse_curve_points(&pace, RecordMetric::Pace);
+        assert_eq!(value_at(&points, 5000), Some((1150.0, None)));
+        assert_eq!(value_at(&points, 10000), None);
+        assert!(parse_curve_points(&json!({"unexpected": true}), RecordMetric::Pace).is_empty());
+    }
+
+    #[test]
+    fn builds_rows_and_flags_broadest_window_records() {
+        let season = json!({"list": [{
+            "secs": [5, 60, 1200],
+            "values": [850, 400, 290],
+            "activity_id": ["i5", "i6", "i9"]
+        }]});
+        let rolling = json!({"list": [{
+            "secs": [5, 60],
+            "values": [800, 380],
+            "activity_id": ["i7", "i10"]
+        }]});
+        let rows = build_record_rows(
+            RecordMetric::Power,
+            Some(&power_curve()),
+            Some(&season),
+            Some(&rolling),
+        );
+        assert_eq!(rows.len(), 5);
+        // 5 min sits between season points and is interpolated without an activity.
+        let season_5min = rows[2].season.as_ref().unwrap();
+        assert!((season_5min.value - (400.0 - 110.0 * 240.0 / 1140.0)).abs() < 1e-9);
+        assert!(season_5min.activity_id.is_none());
+        assert!(rows[2].rolling_90.is_none());
+
+        let records = detect_new_records(&rows, "i9");
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].anchor, 1200);
+        assert_eq!(records[0].window, RecordWindow::AllTime);
+
+        let records = detect_new_records(&rows, "i10");
+        assert_eq!(records[0].window, RecordWindow::Rolling90);
+        assert!(detect_new_records(&rows, "i99").is_empty());
+    }
+
+    #[test]
+    fn charts_monthly_progression_of_an_anchor() {
+        let wellness = json!([
+            {"id": "2026-07-30", "eftp": 270.0},
+            {"id": "2026-08-04", "eftp": 250.0},
+            {"id": "2026-08-20", "sportInfo": [{"type": "Ride", "eftp": 255.0}]},
+            {"id": "2026-10-02", "eftp": 265.0},
+            {"id": "2026-10-05", "eftp": 260.0}
+        ]);
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+        let points = monthly_progression(
+            &[],
+            Some(&wellness),
+            &HashMap::new(),
+            BestEffortKind::Eftp,
+            3,
+            today,
+        );
+        let months = points
+            .iter()
+            .map(|point| point.month.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(months, ["2026-08", "2026-09", "2026-10"]);
+        assert_eq!(points[0].best.as_ref().unwrap().value, 255.0);
+        assert!(points[1].best.is_none());
+        assert_eq!(
+            points[2].best.as_ref().unwrap().date,
+            NaiveDate::from_ymd_opt(2026, 10, 2)
+        );
+
+        let change = progression_change_pct(&points, BestEffortKind::Eftp).unwrap();
+        assert!((change - 1000.0 / 255.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn charts_curve_anchor_from_month_bounded_curves() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        assert_eq!(
+            progression_months(2, today),
+            [
+                (
+                    "2026-09".to_string(),
+                    NaiveDate::from_ymd_opt(2026, 9, 1).unwrap(),
+                    NaiveDate::from_ymd_opt(2026, 9, 30).unwrap()
+                ),
+                (
+                    "2026-10".to_string(),
+                    NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+                    today
+                ),
+            ]
+        );
+
+        // A long easy run has the better whole-session average over 5 km+, but
+        // the 5 km curve value comes from the short fast run.
+        let activities = vec![
+            ActivitySummary {
+                id: "easy".into(),
+                start_date_local: "2026-09-06T07:00:00".into(),
+                distance: Some(21_000.0),
+                moving_time: Some(6300),
+                ..Default::default()
+            },
+            ActivitySummary {
+                id: "tempo".into(),
+                start_date_local: "2026-09-10T07:00:00".into(),
+                distance: Some(8000.0),
+                moving_time: Some(2700),
+                ..Default::default()
+            },
+        ];
+        let curve = |values: [u32; 2], ids: [&str; 2]| {
+            HashMap::from([(
+                (CurveKind::Pace, "Run"),
+                json!({"list": [{"distance": [1000, 5000], "values": values,
+                                 "activity_id": ids}]}),
+            )])
+        };
+        let month_curves = HashMap::from([
+            (
+                "2026-09".to_string(),
+                curve([220, 1300], ["tempo", "tempo"]),
+            ),
+            ("2026-10".to_string(), curve([215, 1250], ["r9", "r9"])),
+        ]);
+        let points = monthly_progression(
+            &activities,
+            None,
+            &month_curves,
+            BestEffortKind::Pace5k,
+            2,
+            today,
+        );
+        let september = points[0].best.as_ref().unwrap();
+        assert_eq!(september.value, 260.0);
+        assert_eq!(september.activity_id.as_deref(), Some("tempo"));
+        assert_eq!(
+            september.date,
+            Some(NaiveDate::from_ymd_opt(2026, 9, 10).unwrap())
+        );
+        assert_eq!(points[1].best.as_ref().unwrap().value, 250.0);
+        assert_eq!(points[1].best.as_ref().unwrap().date, None);
+
+        let change = progression_change_pct(&points, BestEffortKind::Pace5k).unwrap();
+        assert!((change - 1000.0 / 260.0).abs() < 1e-9);
+    }
+}
@@ -307,6 +307,10 @@ pub fn generate_workout_for_phase(
     focus: &TrainingFocus,
     duration_minutes: u32,
 ) -> WorkoutTemplate {
+    if *focus == TrainingFocus::Strength {
+        return crate::engines::strength::strength_workout_template(phase, duration_minutes);
+    }
+
     let distribution = phase.zone_distribution();
 
     let zones = match focus {
@@ -1284,6 +1288,7 @@ mod tests {
 
         assert_eq!(workout.duration_minutes, 60);
         assert!(!workout.zones.is_empty());
+        assert_eq!(workout.sport, "WeightTraining");
     }
 
     #[test]
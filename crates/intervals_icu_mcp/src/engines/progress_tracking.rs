@@ -6,7 +6,8 @@ use serde_json::Value;
 
 use crate::domains::coach::AnalysisWindow;
 use crate::domains::progress::{
-    HypothesisDomain, ProgressHypothesis, ProgressReport, TidDriftMetrics, TidDriftState,
+    HealthEpisode, HypothesisDomain, ProgressHypothesis, ProgressReport, TidDriftMetrics,
+    TidDriftState,
 };
 use crate::engines::analysis_fetch::build_daily_load_series;
 use crate::engines::changepoint::detect_trailing_ctl_plateau;
@@ -14,6 +15,9 @@ use crate::engines::coach_metrics::{
     compute_acwr, compute_lnrmssd_rollup, compute_monotony, compute_strain, compute_tid_entropy,
     extract_ctl_series, extract_hrv_series, parse_wellness_metrics,
 };
+use crate::engines::health_episodes::exclude_episode_windows;
+use crate::engines::injury_risk::{channel_label, compute_injury_risk_from_details};
+use crate::engines::session_rpe::SrpeCalibration;
 
 const DEFAULT_TID_DRIFT_DELTA_THRESHOLD: f64 = 0.15;
 const MIN_WEEKS_FOR_TID_DRIFT: usize = 4;
@@ -58,7 +62,7 @@ fn parse_activity_date(value: &str) -> Option<NaiveDate> {
         .or_else(|| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())
 }
 
-fn extract_zone_distribution(detail: &Value) -> Option<(f64, f64, f64)> {
+pub(crate) fn extract_zone_distribution(detail: &Value) -> Option<(f64, f64, f64)> {
     let zone_times = detail.get("icu_zone_times")?.as_array()?;
     let mut z1 = 0.0;
     let mut z2 = 0.0;
@@ -344,10 +348,37 @@ pub fn build_progress_report(
     activities: &[ActivitySummary],
     activity_details: &HashMap<String, Value>,
     window: &AnalysisWindow,
+    calibration: &SrpeCalibration,
 ) -> ProgressReport {
-    let mut report = ProgressReport::default();
+    build_progress_report_with_episodes(
+        wellness,
+        activities,
+        activity_details,
+        window,
+        calibration,
+        &[],
+    )
+}
+
+/// Progress report with illness / injury episode windows masked out of plateau
+/// detection, so a forced break is not mistaken for a training plateau.
+pub fn build_progress_report_with_episodes(
+    wellness: &Value,
+    activities: &[ActivitySummary],
+    activity_details: &HashMap<String, Value>,
+    window: &AnalysisWindow,
+    calibration: &SrpeCalibration,
+    episodes: &[HealthEpisode],
+) -> ProgressReport {
+    let mut report = ProgressReport {
+        episodes: episodes.to_vec(),
+        ..Default::default()
+    };
 
     if let Some((dates, ctl_values)) = extract_ctl_series(Some(wellness)) {
+        let (dates, ctl_values, excluded) =
+            exclude_episode_windows(&dates, &ctl_values, episodes, window.end_date);
+        report.excluded_episode_days = excluded;
         report.plateau = detect_trailing_ctl_plateau(&dates, &ctl_values);
     } else {
         report.warnings.push(format!(
@@ -377,7 +408,8 @@ pub fn build_progress_report(
     }
 
     let activity_refs = activities.iter().collect::<Vec<_>>();
-    let daily_load_series = build_daily_load_series(&activity_refs, activity_details, window);
+    let daily_load_series =
+        build_daily_load_series(&activity_refs, activity_details, window, calibration);
     let daily_load_values = daily_load_series
         .iter()
         .map(|(_, load)| *load)
@@ -453,8 +485,18 @@ pub fn build_progress_report(
         }
     }
 
+    report.injury_risk =
+        compute_injury_risk_from_details(activities, activity_details, window.end_date);
+
     report.hypotheses = match_hypotheses(&report);
     report.recommendations = generate_recommendations(&report.hypotheses);
+    if let Some(channel) = report.injury_risk.driving_channel.as_deref() {
+        report.recommendations.push(format!(
+            "Injury risk is {} and driven by {} load; stabilise that channel before adding volume elsewhere.",
+            report.injury_risk.risk_level,
+            channel_label(channel).to_lowercase()
+        ));
+    }
     report
 }
 
@@ -578,7 +620,13 @@ mod tests {
         let end = NaiveDate::from_ymd_opt(2026, 1, 14).unwrap();
         let window = AnalysisWindow::new(start, end);
 
-        let report = build_progress_report(&wellness, &[], &HashMap::new(), &window);
+        let report = build_progress_report(
+            &wellness,
+            &[],
+            &HashMap::new(),
+            &window,
+            &SrpeCalibration::default(),
+        );
         assert!(!report.plateau.supported);
         let joined = report.warnings.join("\n");
         assert!(
@@ -611,7 +659,13 @@ mod tests {
         let end = NaiveDate::from_ymd_opt(2026, 2, 9).unwrap();
         let window = AnalysisWindow::new(start, end);
 
-        let report = build_progress_report(&wellness, &[], &HashMap::new(), &window);
+        let report = build_progress_report(
+            &wellness,
+            &[],
+            &HashMap::new(),
+            &window,
+            &SrpeCalibration::default(),
+        );
         let joined = report.warnings.join("\n");
         // 40 days should be enough to attempt a plateau but personalization is short.
         // Depending on slope, plateau may or may not be `supported`; we mainly assert
@@ -652,7 +706,13 @@ mod tests {
         let end = NaiveDate::from_ymd_opt(2026, 1, 30).unwrap();
         let window = AnalysisWindow::new(start, end);
 
-        let report = build_progress_report(&wellness, &[], &HashMap::new(), &window);
+        let report = build_progress_report(
+            &wellness,
+            &[],
+            &HashMap::new(),
+            &window,
+            &SrpeCalibration::default(),
+        );
         assert!(!report.lnrmssd.supported);
         let joined = report.warnings.join("\n");
         assert!(
@@ -686,7 +746,13 @@ mod tests {
         let end = NaiveDate::from_ymd_opt(2026, 1, 30).unwrap();
         let window = AnalysisWindow::new(start, end);
 
-        let report = build_progress_report(&wellness, &[], &HashMap::new(), &window);
+        let report = build_progress_report(
+            &wellness,
+            &[],
+            &HashMap::new(),
+            &window,
+            &SrpeCalibration::default(),
+        );
         assert!(!report.tid_drift.supported);
         let joined = report.warnings.join("\n");
         assert!(
@@ -694,4 +760,54 @@ mod tests {
             "expected TID warning, got: {joined}"
         );
     }
+
+    #[test]
+    fn build_progress_report_masks_episode_windows_from_plateau_detection() {
+        use crate::domains::coach::AnalysisWindow;
+        use crate::domains::progress::{EpisodeKind, HealthEpisode};
+        use chrono::{Duration, NaiveDate};
+
+        let start = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let entries: Vec<Value> = (0..42)
+            .map(|i| {
+                json!({
+                    "date": (start + Duration::days(i)).format("%Y-%m-%d").to_string(),
+                    "fitness": 60.0 + (i as f64) * 0.2,
+                })
+            })
+            .collect();
+        let wellness = Value::Array(entries);
+        let window = AnalysisWindow::new(start, start + Duration::days(41));
+        let episode = HealthEpisode {
+            kind: EpisodeKind::Illness,
+            start: start + Duration::days(30),
+            end: start + Duration::days(34),
+            sources: vec!["Flu".into()],
+            return_date: Some(start + Duration::days(37)),
+            days_to_return: Some(3),
+            ctl_lost: Some(4.0),
+            ongoing: false,
+        };
+
+        let report = build_progress_report_with_episodes(
+            &wellness,
+            &[],
+            &HashMap::new(),
+            &window,
+            &SrpeCalibration::default(),
+            std::slice::from_ref(&episode),
+        );
+        assert_eq!(report.excluded_episode_days, 8);
+        assert_eq!(report.episodes, vec![episode]);
+
+        let plain = build_progress_report(
+            &wellness,
+            &[],
+            &HashMap::new(),
+            &window,
+            &SrpeCalibration::default(),
+        );
+        assert_eq!(plain.excluded_episode_days, 0);
+        assert!(plain.episodes.is_empty());
+    }
 }
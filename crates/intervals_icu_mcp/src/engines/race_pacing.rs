@@ -0,0 +1,965 @@
+//! Pre-race pacing and fueling plan from a course elevation profile.
+//! Splits a route (intervals.icu route path or GPX) into climb/descent/flat
+//! segments, assigns per-segment targets from thresholds and CP/CS, and
+//! schedules carbohydrate/fluid intake and aid-station arrival times.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::domains::nutrition::{
+    compute_carb_demand, compute_fluid_rate, compute_in_race_carb_rate,
+};
+use crate::engines::coach_metrics::get_number;
+use crate::engines::trail_execution::{TerrainContext, compute_terrain_context};
+
+// =============================================================================
+// Race Pacing Constants
+// Sources: Minetti et al. JAP 2002, Di Prampero et al. JAP 1986,
+//          Jones & Vanhatalo Sports Med 2017, Martin et al. JAB 1998
+// =============================================================================
+
+/// Mean Earth radius used for haversine distances (m).
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
+
+/// Resampling window used to smooth elevation noise before classification (m).
+const SEGMENT_WINDOW_M: f64 = 200.0;
+
+/// Minimum segment length; shorter runs are folded into a neighbour (m).
+const MIN_SEGMENT_LENGTH_M: f64 = 500.0;
+
+/// Grade above which a window is classified as climb (%).
+const CLIMB_GRADE_PCT: f64 = 3.0;
+
+/// Grade below which a window is classified as descent (%).
+const DESCENT_GRADE_PCT: f64 = -3.0;
+
+/// Grade clamp for the Minetti cost polynomial (fraction, valid range ±0.45).
+const MINETTI_GRADE_CLAMP: f64 = 0.45;
+
+/// Energy cost of level running (J/kg/m). Source: Minetti et al. JAP 2002.
+const MINETTI_FLAT_COST: f64 = 3.6;
+
+/// Floor on the descent pace factor — technical footing limits the speed gain.
+const DESCENT_PACE_FACTOR_FLOOR: f64 = 0.8;
+
+/// Race intensity (fraction of threshold/CS/CP) by expected duration.
+/// Source: Jones & Vanhatalo Sports Med 2017 (sustainable fraction of CP/CS vs time).
+const INTENSITY_BY_DURATION: &[(f64, f64)] = &[
+    (1.0, 0.97),
+    (2.0, 0.92),
+    (4.0, 0.85),
+    (8.0, 0.75),
+    (f64::INFINITY, 0.65),
+];
+
+/// Starting intensity guess for the duration ↔ intensity fixed-point iteration.
+const INITIAL_INTENSITY_GUESS: f64 = 0.85;
+
+/// Fixed-point iterations to converge duration and intensity.
+const INTENSITY_ITERATIONS: usize = 5;
+
+/// Power multiplier on climbs — modest surges above race power are economical.
+const CLIMB_POWER_FACTOR: f64 = 1.05;
+
+/// Power multiplier on descents — recover, keep pedals/legs turning.
+const DESCENT_POWER_FACTOR: f64 = 0.6;
+
+/// HR cap multiplier on climbs relative to race HR.
+const CLIMB_HR_FACTOR: f64 = 1.03;
+
+/// HR cap multiplier on descents relative to race HR.
+const DESCENT_HR_FACTOR: f64 = 0.95;
+
+/// Cycling drag area CdA (m²), hoods position. Source: Martin et al. JAB 1998.
+const BIKE_CDA_M2: f64 = 0.32;
+
+/// Cycling rolling resistance coefficient.
+const BIKE_CRR: f64 = 0.005;
+
+/// Air density at sea level (kg/m³).
+const AIR_DENSITY: f64 = 1.225;
+
+/// Bike and equipment mass added to body weight (kg).
+const BIKE_MASS_KG: f64 = 9.0;
+
+/// Gravitational acceleration (m/s²).
+const GRAVITY: f64 = 9.81;
+
+/// Descent speed cap for the cycling model (m/s, ~72 km/h).
+const BIKE_MAX_SPEED_MPS: f64 = 20.0;
+
+/// Default body weight when none is supplied (kg).
+pub const DEFAULT_BODY_WEIGHT_KG: f64 = 70.0;
+
+/// Interval between fueling prompts (seconds).
+const FUEL_INTERVAL_SECS: f64 = 20.0 * 60.0;
+
+/// Raw profile sample: optional (lat, lon), elevation (m), optional distance (m).
+type ProfileSample = (Option<(f64, f64)>, f64, Option<f64>);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoursePoint {
+    pub distance_m: f64,
+    pub elevation_m: f64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentKind {
+    Climb,
+    Descent,
+    Flat,
+}
+
+impl SegmentKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Climb => "climb",
+            Self::Descent => "descent",
+            Self::Flat => "flat",
+        }
+    }
+
+    fn from_grade(grade_pct: f64) -> Self {
+        if grade_pct >= CLIMB_GRADE_PCT {
+            Self::Climb
+        } else if grade_pct <= DESCENT_GRADE_PCT {
+            Self::Descent
+        } else {
+            Self::Flat
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PacingSport {
+    Run,
+    Ride,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CourseSegment {
+    pub kind: SegmentKind,
+    pub start_m: f64,
+    pub end_m: f64,
+    pub elevation_gain_m: f64,
+    pub elevation_loss_m: f64,
+    pub avg_grade_pct: f64,
+}
+
+impl CourseSegment {
+    pub fn length_m(&self) -> f64 {
+        self.end_m - self.start_m
+    }
+
+    fn absorb(&mut self, other: &CourseSegment) {
+        self.end_m = other.end_m.max(self.end_m);
+        self.start_m = other.start_m.min(self.start_m);
+        self.elevation_gain_m += other.elevation_gain_m;
+        self.elevation_loss_m += other.elevation_loss_m;
+        let length = self.length_m();
+        self.avg_grade_pct = if length > 0.0 {
+            (self.elevation_gain_m - self.elevation_loss_m) / length * 100.0
+        } else {
+            0.0
+        };
+    }
+}
+
+/// Threshold anchors used to derive per-segment targets.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PacingAnchors {
+    /// Critical speed (m/s). Preferred over threshold pace when present.
+    pub critical_speed_mps: Option<f64>,
+    /// Threshold running speed (m/s), converted from threshold pace.
+    pub threshold_speed_mps: Option<f64>,
+    /// Critical power (W). Preferred over FTP when present.
+    pub critical_power_watts: Option<f64>,
+    pub ftp_watts: Option<f64>,
+    pub lthr: Option<f64>,
+    pub body_weight_kg: Option<f64>,
+}
+
+impl PacingAnchors {
+    fn speed(&self) -> Option<f64> {
+        self.critical_speed_mps
+            .or(self.threshold_speed_mps)
+            .filter(|v| *v > 0.0)
+    }
+
+    fn power(&self) -> Option<f64> {
+        self.critical_power_watts
+            .or(self.ftp_watts)
+            .filter(|v| *v > 0.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SegmentPlan {
+    pub segment: CourseSegment,
+    pub effort: String,
+    pub target_pace_secs_per_km: Option<f64>,
+    pub target_power_watts: Option<f64>,
+    pub target_hr_cap: Option<f64>,
+    pub est_time_secs: f64,
+    pub cumulative_time_secs: f64,
+    pub terrain: TerrainContext,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FuelingStep {
+    pub elapsed_secs: f64,
+    pub distance_m: f64,
+    pub carbs_g: f64,
+    pub fluid_ml: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AidStationPlan {
+    pub distance_m: f64,
+    pub eta_secs: f64,
+    /// Carbohydrate to pick up to reach the next station (or finish).
+    pub carbs_to_next_g: f64,
+    /// Fluid to pick up to reach the next station (or finish).
+    pub fluid_to_next_ml: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FuelingPlan {
+    pub carbs_g_per_hour: f64,
+    pub fluid_ml_per_hour: f64,
+    pub total_carbs_g: f64,
+    pub total_fluid_ml: f64,
+    /// Day-before carbohydrate target from `compute_carb_demand` (g/kg).
+    pub carb_loading_g_kg: f64,
+    pub schedule: Vec<FuelingStep>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RacePacingPlan {
+    pub supported: bool,
+    pub unsupported_reason: String,
+    pub sport: PacingSport,
+    pub total_distance_m: f64,
+    pub total_gain_m: f64,
+    pub total_loss_m: f64,
+    pub est_duration_secs: f64,
+    pub intensity_fraction: f64,
+    pub segments: Vec<SegmentPlan>,
+    pub fueling: Option<FuelingPlan>,
+    pub aid_stations: Vec<AidStationPlan>,
+    pub terrain: TerrainContext,
+}
+
+impl RacePacingPlan {
+    pub fn unsupported(sport: PacingSport, reason: &str) -> Self {
+        Self {
+            supported: false,
+            unsupported_reason: reason.to_string(),
+            sport,
+            total_distance_m: 0.0,
+            total_gain_m: 0.0,
+            total_loss_m: 0.0,
+            est_duration_secs: 0.0,
+            intensity_fraction: 0.0,
+            segments: Vec::new(),
+            fueling: None,
+            aid_stations: Vec::new(),
+            terrain: TerrainContext::unsupported(),
+        }
+    }
+}
+
+fn haversine_m(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let d_phi = (lat2 - lat1).to_radians();
+    let d_lambda = (lon2 - lon1).to_radians();
+    let a = (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_M * a.sqrt().asin()
+}
+
+/// Build a course profile from (lat, lon, elevation, distance) samples,
+/// accumulating haversine distance when no explicit distance is present.
+fn points_from_samples(samples: &[ProfileSample]) -> Vec<CoursePoint> {
+    let mut points = Vec::with_capacity(samples.len());
+    let mut cumulative = 0.0;
+    let mut previous: Option<(f64, f64)> = None;
+    for (latlng, elevation, distance) in samples {
+        if let (Some((lat, lon)), Some((prev_lat, prev_lon))) = (latlng, previous) {
+            cumulative += haversine_m(prev_lat, prev_lon, *lat, *lon);
+        }
+        if latlng.is_some() {
+            previous = *latlng;
+        }
+        points.push(CoursePoint {
+            distance_m: distance.unwrap_or(cumulative),
+            elevation_m: *elevation,
+        });
+    }
+    points
+}
+
+/// Parse an intervals.icu route (fetched with `include_path`) into a course profile.
+/// Accepts parallel `distance`/`altitude` arrays, or a `latlngs`/`path`/`points`
+/// array of `[lat, lng, alt]` tuples or `{lat, lng, alt}` objects.
+pub fn parse_route_profile(route: &Value) -> Vec<CoursePoint> {
+    let distances = route.get("distance").and_then(Value::as_array);
+    let altitudes = ["altitude", "elevation", "fixed_altitude"]
+        .iter()
+        .find_map(|key| route.get(*key).and_then(Value::as_array));
+    if let (Some(distances), Some(altitudes)) = (distances, altitudes) {
+        let points: Vec<CoursePoint> = distances
+            .iter()
+            .zip(altitudes)
+            .filter_map(|(d, a)| {
+                Some(CoursePoint {
+                    distance_m: d.as_f64()?,
+                    elevation_m: a.as_f64()?,
+                })
+            })
+            .collect();
+        if points.len() >= 2 {
+            return points;
+        }
+    }
+
+    let Some(path) = ["latlngs", "path", "points"]
+        .iter()
+        .find_map(|key| route.get(*key).and_then(Value::as_array))
+    else {
+        return Vec::new();
+    };
+
+    let samples: Vec<ProfileSample> = path
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entry)| {
+            if let Some(tuple) = entry.as_array() {
+                let lat = tuple.first().and_then(Value::as_f64)?;
+                let lon = tuple.get(1).and_then(Value::as_f64)?;
+                let elevation = tuple
+                    .get(2)
+                    .and_then(Value::as_f64)
+                    .or_else(|| altitudes.and_then(|a| a.get(index)).and_then(Value::as_f64))?;
+                return Some((Some((lat, lon)), elevation, None));
+            }
+            let object = entry.as_object()?;
+            let latlng = get_number(object, &["lat", "latitude"])
+                .zip(get_number(object, &["lng", "lon", "longitude"]));
+            let elevation = get_number(object, &["alt", "altitude", "ele", "elevation"])?;
+            let distance = get_number(object, &["distance", "dist"]);
+            if latlng.is_none() && distance.is_none() {
+                return None;
+            }
+            Some((latlng, elevation, distance))
+        })
+        .collect();
+    points_from_samples(&samples)
+}
+
+fn xml_attr(tag: &str, name: &str) -> Option<f64> {
+    let needle = format!("{name}=");
+    let start = tag.find(&needle)? + needle.len();
+    let quote = tag[start..].chars().next()?;
+    let rest = &tag[start + quote.len_utf8()..];
+    let end = rest.find(quote)?;
+    rest[..end].trim().parse().ok()
+}
+
+/// Parse GPX track or route points (`<trkpt>`/`<rtept>` with `<ele>`) into a course profile.
+/// Points without elevation are skipped.
+pub fn parse_gpx(gpx: &str) -> Vec<CoursePoint> {
+    let mut samples = Vec::new();
+    for tag in ["<trkpt", "<rtept"] {
+        let mut rest = gpx;
+        while let Some(start) = rest.find(tag) {
+            let after = &rest[start..];
+            let close_tag = if tag == "<trkpt" {
+                "</trkpt>"
+            } else {
+                "</rtept>"
+            };
+            let end = after
+                .find(close_tag)
+                .map(|i| i + close_tag.len())
+                .or_else(|| after.find("/>").map(|i| i + 2))
+                .unwrap_or(after.len());
+            let element = &after[..end];
+            let head_end = element.find('>').unwrap_or(element.len());
+            let head = &element[..head_end];
+            let latlng = xml_attr(head, "lat").zip(xml_attr(head, "lon"));
+            let elevation = element.find("<ele>").and_then(|i| {
+                let body = &element[i + "<ele>".len()..];
+                body.find("</ele>")
+                    .and_then(|j| body[..j].trim().parse::<f64>().ok())
+            });
+            if let (Some(latlng), Some(elevation)) = (latlng, elevation) {
+                samples.push((Some(latlng), elevation, None));
+            }
+            rest = &after[end.max(1)..];
+        }
+        if !samples.is_empty() {
+            break;
+        }
+    }
+    points_from_samples(&samples)
+}
+
+fn elevation_at(points: &[CoursePoint], distance_m: f64) -> f64 {
+    match points.iter().position(|p| p.distance_m >= distance_m) {
+        Some(0) => points[0].elevation_m,
+        Some(index) => {
+            let (a, b) = (points[index - 1], points[index]);
+            let span = b.distance_m - a.distance_m;
+            if span <= 0.0 {
+                b.elevation_m
+            } else {
+                a.elevation_m + (b.elevation_m - a.elevation_m) * (distance_m - a.distance_m) / span
+            }
+        }
+        None => points.last().map(|p| p.elevation_m).unwrap_or(0.0),
+    }
+}
+
+/// Split a course profile into climb/descent/flat segments.
+/// Elevation is resampled into fixed windows, classified by grade, merged into
+/// runs of the same kind, and runs shorter than the minimum length are folded
+/// into their neighbour.
+pub fn segment_course(points: &[CoursePoint]) -> Vec<CourseSegment> {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return Vec::new();
+    };
+    let total = last.distance_m - first.distance_m;
+    if total <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut windows: Vec<CourseSegment> = Vec::new();
+    let mut start = first.distance_m;
+    while start < last.distance_m {
+        let end = (start + SEGMENT_WINDOW_M).min(last.distance_m);
+        let delta = elevation_at(points, end) - elevation_at(points, start);
+        let grade_pct = delta / (end - start) * 100.0;
+        let window = CourseSegment {
+            kind: SegmentKind::from_grade(grade_pct),
+            start_m: start,
+            end_m: end,
+            elevation_gain_m: delta.max(0.0),
+            elevation_loss_m: (-delta).max(0.0),
+            avg_grade_pct: grade_pct,
+        };
+        match windows.last_mut() {
+            Some(previous) if previous.kind == window.kind => previous.absorb(&window),
+            _ => windows.push(window),
+        }
+        start = end;
+    }
+
+    let mut merged: Vec<CourseSegment> = Vec::new();
+    for segment in windows {
+        match merged.last_mut() {
+            Some(previous)
+                if segment.length_m() < MIN_SEGMENT_LENGTH_M
+                    || previous.length_m() < MIN_SEGMENT_LENGTH_M
+                    || previous.kind == segment.kind =>
+            {
+                let keep_kind = if previous.length_m() >= segment.length_m() {
+                    previous.kind
+                } else {
+                    segment.kind
+                };
+                previous.absorb(&segment);
+                previous.kind = keep_kind;
+            }
+            _ => merged.push(segment),
+        }
+    }
+    merged
+}
+
+/// Metabolic cost multiplier of running at a grade relative to level running.
+/// Minetti et al. JAP 2002 polynomial; descents floored for footing/eccentric limits.
+pub fn grade_cost_factor(grade_pct: f64) -> f64 {
+    let i = (grade_pct / 100.0).clamp(-MINETTI_GRADE_CLAMP, MINETTI_GRADE_CLAMP);
+    let cost = 155.4 * i.powi(5) - 30.4 * i.powi(4) - 43.3 * i.powi(3)
+        + 46.3 * i.powi(2)
+        + 19.5 * i
+        + MINETTI_FLAT_COST;
+    (cost / MINETTI_FLAT_COST).max(DESCENT_PACE_FACTOR_FLOOR)
+}
+
+/// Sustainable fraction of threshold/CS/CP for the expected race duration.
+pub fn race_intensity_fraction(duration_hours: f64) -> f64 {
+    INTENSITY_BY_DURATION
+        .iter()
+        .find(|(hours, _)| duration_hours <= *hours)
+        .map(|(_, fraction)| *fraction)
+        .unwrap_or(INITIAL_INTENSITY_GUESS)
+}
+
+/// Steady-state cycling speed (m/s) for a power and grade. Solves
+/// P = m·g·(Crr·cosθ + sinθ)·v + ½·ρ·CdA·v³ by bisection.
+pub fn bike_speed_for_power(power_watts: f64, grade_pct: f64, total_mass_kg: f64) -> f64 {
+    let theta = (grade_pct / 100.0).atan();
+    let resistive = total_mass_kg * GRAVITY * (BIKE_CRR * theta.cos() + theta.sin());
+    let demand = |v: f64| resistive * v + 0.5 * AIR_DENSITY * BIKE_CDA_M2 * v.powi(3);
+    let (mut lo, mut hi) = (0.1_f64, BIKE_MAX_SPEED_MPS);
+    if demand(hi) <= power_watts {
+        return BIKE_MAX_SPEED_MPS;
+    }
+    if demand(lo) >= power_watts {
+        return lo;
+    }
+    for _ in 0..60 {
+        let mid = (lo + hi) / 2.0;
+        if demand(mid) < power_watts {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+fn terrain_factor(kind: SegmentKind, climb: f64, descent: f64) -> f64 {
+    match kind {
+        SegmentKind::Climb => climb,
+        SegmentKind::Descent => descent,
+        SegmentKind::Flat => 1.0,
+    }
+}
+
+fn effort_label(kind: SegmentKind, sport: PacingSport) -> &'static str {
+    match (kind, sport) {
+        (SegmentKind::Climb, PacingSport::Run) => {
+            "steady climb — hold effort, not pace; hike if HR cap exceeded"
+        }
+        (SegmentKind::Climb, PacingSport::Ride) => {
+            "seated climb — small surge above race power allowed"
+        }
+        (SegmentKind::Descent, PacingSport::Run) => {
+            "relaxed descent — quick cadence, protect quads"
+        }
+        (SegmentKind::Descent, PacingSport::Ride) => "recover — soft pedal and fuel",
+        (SegmentKind::Flat, _) => "goal race effort",
+    }
+}
+
+/// Per-segment (speed m/s, target power) at a given intensity fraction.
+fn segment_speed(
+    segment: &CourseSegment,
+    sport: PacingSport,
+    anchors: &PacingAnchors,
+    fraction: f64,
+) -> Option<(f64, Option<f64>)> {
+    let power = anchors.power().map(|p| {
+        p * fraction * terrain_factor(segment.kind, CLIMB_POWER_FACTOR, DESCENT_POWER_FACTOR)
+    });
+    match sport {
+        PacingSport::Ride => {
+            if let Some(power) = power {
+                let mass = anchors.body_weight_kg.unwrap_or(DEFAULT_BODY_WEIGHT_KG) + BIKE_MASS_KG;
+                Some((
+                    bike_speed_for_power(power, segment.avg_grade_pct, mass),
+                    Some(power),
+                ))
+            } else {
+                anchors.speed().map(|v| {
+                    (
+                        v * fraction / grade_cost_factor(segment.avg_grade_pct),
+                        None,
+                    )
+                })
+            }
+        }
+        PacingSport::Run => anchors.speed().map(|v| {
+            (
+                v * fraction / grade_cost_factor(segment.avg_grade_pct),
+                power,
+            )
+        }),
+    }
+}
+
+fn course_duration(
+    segments: &[CourseSegment],
+    sport: PacingSport,
+    anchors: &PacingAnchors,
+    fraction: f64,
+) -> Option<f64> {
+    segments.iter().try_fold(0.0, |total, segment| {
+        let (speed, _) = segment_speed(segment, sport, anchors, fraction)?;
+        Some(total + segment.length_m() / speed)
+    })
+}
+
+fn distance_at_time(segments: &[SegmentPlan], elapsed_secs: f64) -> f64 {
+    for plan in segments {
+        if elapsed_secs <= plan.cumulative_time_secs {
+            let segment_start = plan.cumulative_time_secs - plan.est_time_secs;
+            let fraction = if plan.est_time_secs > 0.0 {
+                (elapsed_secs - segment_start) / plan.est_time_secs
+            } else {
+                1.0
+            };
+            return plan.segment.start_m + plan.segment.length_m() * fraction.clamp(0.0, 1.0);
+        }
+    }
+    segments.last().map(|p| p.segment.end_m).unwrap_or(0.0)
+}
+
+fn time_at_distance(segments: &[SegmentPlan], distance_m: f64) -> f64 {
+    for plan in segments {
+        if distance_m <= plan.segment.end_m {
+            let segment_start = plan.cumulative_time_secs - plan.est_time_secs;
+            let length = plan.segment.length_m();
+            let fraction = if length > 0.0 {
+                (distance_m - plan.segment.start_m) / length
+            } else {
+                1.0
+            };
+            return segment_start + plan.est_time_secs * fraction.clamp(0.0, 1.0);
+        }
+    }
+    segments
+        .last()
+        .map(|p| p.cumulative_time_secs)
+        .unwrap_or(0.0)
+}
+
+/// Build the full pacing and fueling plan for a course.
+/// Race intensity and duration are solved together: intensity depends on the
+/// expected duration, which in turn depends on the per-segment speeds.
+pub fn build_pacing_plan(
+    points: &[CoursePoint],
+    sport: PacingSport,
+    anchors: &PacingAnchors,
+    aid_stations_km: &[f64],
+    temperature_c: Option<f64>,
+) -> RacePacingPlan {
+    let segments = segment_course(points);
+    if segments.is_empty() {
+        return RacePacingPlan::unsupported(
+            sport,
+            "course profile has no usable elevation/distance points",
+        );
+    }
+
+    let mut fraction = INITIAL_INTENSITY_GUESS;
+    let mut duration = None;
+    for _ in 0..INTENSITY_ITERATIONS {
+        duration = course_duration(&segments, sport, anchors, fraction);
+        let Some(seconds) = duration else { break };
+        fraction = race_intensity_fraction(seconds / 3600.0);
+    }
+    if duration.is_none() {
+        return RacePacingPlan::unsupported(
+            sport,
+            "no threshold pace, critical speed, FTP or critical power available",
+        );
+    }
+
+    let race_hr = anchors.lthr.map(|lthr| lthr * fraction);
+    let mut cumulative = 0.0;
+    let mut plans = Vec::with_capacity(segments.len());
+    for segment in segments {
+        let Some((speed, power)) = segment_speed(&segment, sport, anchors, fraction) else {
+            continue;
+        };
+        let est_time_secs = segment.length_m() / speed;
+        cumulative += est_time_secs;
+        let terrain = compute_terrain_context(
+            segment.elevation_gain_m,
+            segment.length_m(),
+            est_time_secs.round() as i64,
+            None,
+        );
+        plans.push(SegmentPlan {
+            effort: effort_label(segment.kind, sport).to_string(),
+            target_pace_secs_per_km: (sport == PacingSport::Run).then(|| 1000.0 / speed),
+            target_power_watts: power,
+            target_hr_cap: race_hr.map(|hr| {
+                let cap = hr * terrain_factor(segment.kind, CLIMB_HR_FACTOR, DESCENT_HR_FACTOR);
+                anchors.lthr.map(|lthr| cap.min(lthr)).unwrap_or(cap)
+            }),
+            est_time_secs,
+            cumulative_time_secs: cumulative,
+            terrain,
+            segment,
+        });
+    }
+
+    let total_distance_m = plans.last().map(|p| p.segment.end_m).unwrap_or(0.0)
+        - plans.first().map(|p| p.segment.start_m).unwrap_or(0.0);
+    let total_gain_m: f64 = plans.iter().map(|p| p.segment.elevation_gain_m).sum();
+    let total_loss_m: f64 = plans.iter().map(|p| p.segment.elevation_loss_m).sum();
+    let est_duration_secs = cumulative;
+    let duration_hours = est_duration_secs / 3600.0;
+
+    let carbs_g_per_hour = compute_in_race_carb_rate(duration_hours);
+    let fluid_ml_per_hour = compute_fluid_rate(temperature_c);
+    let mut schedule = Vec::new();
+    let mut elapsed = FUEL_INTERVAL_SECS;
+    while elapsed < est_duration_secs {
+        schedule.push(FuelingStep {
+            elapsed_secs: elapsed,
+            distance_m: distance_at_time(&plans, elapsed),
+            carbs_g: carbs_g_per_hour * FUEL_INTERVAL_SECS / 3600.0,
+            fluid_ml: fluid_ml_per_hour * FUEL_INTERVAL_SECS / 3600.0,
+        });
+        elapsed += FUEL_INTERVAL_SECS;
+    }
+    let fueling = FuelingPlan {
+        carbs_g_per_hour,
+        fluid_ml_per_hour,
+        total_carbs_g: carbs_g_per_hour * duration_hours,
+        total_fluid_ml: fluid_ml_per_hour * duration_hours,
+        carb_loading_g_kg: compute_carb_demand(duration_hours, Some(fraction)),
+        schedule,
+    };
+
+    let course_end = plans.last().map(|p| p.segment.end_m).unwrap_or(0.0);
+    let mut station_distances: Vec<f64> = aid_stations_km
+        .iter()
+        .map(|km| km * 1000.0)
+        .filter(|m| *m > 0.0 && *m < course_end)
+        .collect();
+    station_distances.sort_by(|a, b| a.total_cmp(b));
+    let aid_stations = station_distances
+        .iter()
+        .enumerate()
+        .map(|(index, distance_m)| {
+            let eta_secs = time_at_distance(&plans, *distance_m);
+            let next_secs = station_distances
+                .get(index + 1)
+                .map(|next| time_at_distance(&plans, *next))
+                .unwrap_or(est_duration_secs);
+            let leg_hours = (next_secs - eta_secs).max(0.0) / 3600.0;
+            AidStationPlan {
+                distance_m: *distance_m,
+                eta_secs,
+                carbs_to_next_g: carbs_g_per_hour * leg_hours,
+                fluid_to_next_ml: fluid_ml_per_hour * leg_hours,
+            }
+        })
+        .collect();
+
+    RacePacingPlan {
+        supported: true,
+        unsupported_reason: String::new(),
+        sport,
+        total_distance_m,
+        total_gain_m,
+        total_loss_m,
+        est_duration_secs,
+        intensity_fraction: fraction,
+        terrain: compute_terrain_context(
+            total_gain_m,
+            total_distance_m,
+            est_duration_secs.round() as i64,
+            None,
+        ),
+        segments: plans,
+        fueling: Some(fueling),
+        aid_stations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// 2 km flat, 2 km at +10%, 2 km at -10%, 2 km flat.
+    fn hill_course() -> Vec<CoursePoint> {
+        vec![
+            CoursePoint {
+                distance_m: 0.0,
+                elevation_m: 100.0,
+            },
+            CoursePoint {
+                distance_m: 2000.0,
+                elevation_m: 100.0,
+            },
+            CoursePoint {
+                distance_m: 4000.0,
+                elevation_m: 300.0,
+            },
+            CoursePoint {
+                distance_m: 6000.0,
+                elevation_m: 100.0,
+            },
+            CoursePoint {
+                distance_m: 8000.0,
+                elevation_m: 100.0,
+            },
+        ]
+    }
+
+    fn run_anchors() -> PacingAnchors {
+        PacingAnchors {
+            threshold_speed_mps: Some(1000.0 / 270.0),
+            lthr: Some(170.0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn segment_course_detects_climb_and_descent() {
+        let segments = segment_course(&hill_course());
+        let kinds: Vec<SegmentKind> = segments.iter().map(|s| s.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                SegmentKind::Flat,
+                SegmentKind::Climb,
+                SegmentKind::Descent,
+                SegmentKind::Flat
+            ]
+        );
+        assert!((segments[1].elevation_gain_m - 200.0).abs() < 1.0);
+        assert!((segments[2].elevation_loss_m - 200.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn segment_course_folds_short_bumps() {
+        let points = vec![
+            CoursePoint {
+                distance_m: 0.0,
+                elevation_m: 0.0,
+            },
+            CoursePoint {
+                distance_m: 3000.0,
+                elevation_m: 0.0,
+            },
+            CoursePoint {
+                distance_m: 3200.0,
+                elevation_m: 20.0,
+            },
+            CoursePoint {
+                distance_m: 6000.0,
+                elevation_m: 20.0,
+            },
+        ];
+        let segments = segment_course(&points);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].kind, SegmentKind::Flat);
+    }
+
+    #[test]
+    fn grade_cost_factor_is_one_on_flat() {
+        assert!((grade_cost_factor(0.0) - 1.0).abs() < 1e-9);
+        assert!(grade_cost_factor(10.0) > 1.5);
+        assert!((grade_cost_factor(-30.0) - DESCENT_PACE_FACTOR_FLOOR).abs() < 1e-9);
+    }
+
+    #[test]
+    fn race_intensity_drops_with_duration() {
+        assert!(race_intensity_fraction(0.5) > race_intensity_fraction(3.0));
+        assert!(race_intensity_fraction(3.0) > race_intensity_fraction(12.0));
+    }
+
+    #[test]
+    fn bike_speed_slower_uphill() {
+        let flat = bike_speed_for_power(200.0, 0.0, 80.0);
+        let climb = bike_speed_for_power(200.0, 8.0, 80.0);
+        assert!(flat > 8.0 && flat < 12.0);
+        assert!(climb < flat / 2.0);
+    }
+
+    #[test]
+    fn pacing_plan_slows_on_climbs() {
+        let plan = build_pacing_plan(&hill_course(), PacingSport::Run, &run_anchors(), &[], None);
+        assert!(plan.supported);
+        let flat = plan.segments[0].target_pace_secs_per_km.unwrap();
+        let climb = plan.segments[1].target_pace_secs_per_km.unwrap();
+        let descent = plan.segments[2].target_pace_secs_per_km.unwrap();
+        assert!(climb > flat);
+        assert!(descent < flat);
+        assert!(plan.segments[1].target_hr_cap.unwrap() <= 170.0);
+        assert!((plan.total_gain_m - 200.0).abs() < 1.0);
+        assert!(plan.segments[1].terrain.vam.is_some());
+    }
+
+    #[test]
+    fn pacing_plan_unsupported_without_anchors() {
+        let plan = build_pacing_plan(
+            &hill_course(),
+            PacingSport::Run,
+            &PacingAnchors::default(),
+            &[],
+            None,
+        );
+        assert!(!plan.supported);
+        assert!(plan.unsupported_reason.contains("threshold"));
+    }
+
+    #[test]
+    fn pacing_plan_ride_uses_power() {
+        let anchors = PacingAnchors {
+            ftp_watts: Some(250.0),
+            ..Default::default()
+        };
+        let plan = build_pacing_plan(&hill_course(), PacingSport::Ride, &anchors, &[], None);
+        assert!(plan.supported);
+        let climb = &plan.segments[1];
+        assert!(climb.target_power_watts.unwrap() > plan.segments[0].target_power_watts.unwrap());
+        assert!(climb.target_pace_secs_per_km.is_none());
+    }
+
+    #[test]
+    fn aid_stations_get_eta_and_carry() {
+        let plan = build_pacing_plan(
+            &hill_course(),
+            PacingSport::Run,
+            &run_anchors(),
+            &[5.0, 3.0, 99.0],
+            Some(30.0),
+        );
+        assert_eq!(plan.aid_stations.len(), 2);
+        assert!((plan.aid_stations[0].distance_m - 3000.0).abs() < 1e-6);
+        assert!(plan.aid_stations[0].eta_secs < plan.aid_stations[1].eta_secs);
+        let fueling = plan.fueling.unwrap();
+        assert!((fueling.fluid_ml_per_hour - 900.0).abs() < 0.01);
+        assert!(plan.aid_stations[1].carbs_to_next_g > 0.0);
+    }
+
+    #[test]
+    fn parse_route_profile_from_latlngs() {
+        let route = json!({
+            "latlngs": [[46.0, 7.0, 1000.0], [46.01, 7.0, 1100.0], [46.02, 7.0, 1100.0]]
+        });
+        let points = parse_route_profile(&route);
+        assert_eq!(points.len(), 3);
+        assert!((points[1].distance_m - 1112.0).abs() < 5.0);
+        assert!((points[1].elevation_m - 1100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn parse_route_profile_from_parallel_arrays() {
+        let route = json!({"distance": [0.0, 1000.0, 2000.0], "altitude": [10, 60, 10]});
+        let points = parse_route_profile(&route);
+        assert_eq!(points.len(), 3);
+        assert!((points[2].distance_m - 2000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn parse_route_profile_empty_without_path() {
+        assert!(parse_route_profile(&json!({"name": "x"})).is_empty());
+    }
+
+    #[test]
+    fn parse_gpx_track_points() {
+        let gpx = r#"<?xml version="1.0"?>
+<gpx><trk><trkseg>
+<trkpt lat="46.0" lon="7.0"><ele>1000</ele></trkpt>
+<trkpt lon="7.0" lat='46.01'><ele>1050.5</ele></trkpt>
+<trkpt lat="46.02" lon="7.0"></trkpt>
+</trkseg></trk></gpx>"#;
+        let points = parse_gpx(gpx);
+        assert_eq!(points.len(), 2);
+        assert!((points[1].elevation_m - 1050.5).abs() < 1e-6);
+        assert!(points[1].distance_m > 1000.0);
+    }
+}
@@ -0,0 +1,274 @@
+//! Coach roster summary.
+//! Condenses each coached athlete's recent wellness into fitness, form, ACWR,
+//! readiness and HRV state, flags athletes who need the coach's attention, and
+//! ranks the roster most urgent first.
+
+use chrono::NaiveDate;
+use intervals_icu_client::domains::athlete::CoachedAthlete;
+use intervals_icu_client::domains::wellness::WellnessEntry;
+use serde_json::Value;
+
+use crate::domains::coach::CoachAlertSeverity;
+use crate::domains::roster::{AthleteSnapshot, RosterFlag, RosterStatus};
+use crate::engines::coach_metrics::{acwr_from_wellness, parse_wellness_metrics};
+
+/// Wellness days fetched per athlete: enough for the 28-day HRV baseline.
+pub const ROSTER_WELLNESS_DAYS: i32 = 42;
+/// Latest wellness older than this many days is reported as stale.
+pub const STALE_WELLNESS_DAYS: i64 = 3;
+/// Form (TSB) below this is flagged as deep fatigue.
+const DEEP_FATIGUE_FORM: f64 = -30.0;
+/// Readiness below this is flagged; matches assess_recovery's "Low" band.
+const LOW_READINESS: f64 = 5.0;
+
+fn flag(severity: CoachAlertSeverity, code: &str, message: String) -> RosterFlag {
+    RosterFlag {
+        severity,
+        code: code.to_string(),
+        message,
+    }
+}
+
+fn entry_day(entry: &WellnessEntry) -> Option<&str> {
+    entry.id.as_deref().and_then(|id| id.get(..10))
+}
+
+fn entry_date(entry: &WellnessEntry) -> Option<NaiveDate> {
+    entry_day(entry).and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+}
+
+fn status_for(flags: &[RosterFlag], has_recent_data: bool) -> RosterStatus {
+    if flags
+        .iter()
+        .any(|flag| flag.severity == CoachAlertSeverity::Priority)
+    {
+        RosterStatus::Attention
+    } else if flags
+        .iter()
+        .any(|flag| flag.severity == CoachAlertSeverity::Caution)
+    {
+        RosterStatus::Watch
+    } else if !has_recent_data {
+        RosterStatus::NoData
+    } else {
+        RosterStatus::Ready
+    }
+}
+
+/// Summarise one athlete from their raw wellness payload.
+#[must_use]
+pub fn summarize_athlete(
+    athlete: &CoachedAthlete,
+    wellness: &Value,
+    today: NaiveDate,
+) -> AthleteSnapshot {
+    let entries: Vec<WellnessEntry> = serde_json::from_value(wellness.clone()).unwrap_or_default();
+    let latest = entries
+        .iter()
+        .filter(|entry| entry.ctl.is_some() || entry.atl.is_some())
+        .max_by(|a, b| a.id.cmp(&b.id));
+    let metrics = parse_wellness_metrics(Some(wellness));
+
+    let form = latest.and_then(WellnessEntry::form);
+    let acwr = latest.and_then(acwr_from_wellness);
+    let readiness = metrics.as_ref().and_then(|m| m.readiness_score);
+    let hrv_ratio = metrics.as_ref().and_then(|m| m.hrv_ratio);
+
+    let mut flags = Vec::new();
+    let age_days = latest
+        .and_then(entry_date)
+        .map(|date| (today - date).num_days());
+    match age_days {
+        None => flags.push(flag(
+            CoachAlertSeverity::Info,
+            "no_wellness",
+            format!("No fitness data in the last {ROSTER_WELLNESS_DAYS} days"),
+        )),
+        Some(days) if days > STALE_WELLNESS_DAYS => flags.push(flag(
+            CoachAlertSeverity::Info,
+            "stale_wellness",
+            format!("Latest wellness is {days} days old"),
+        )),
+        Some(_) => {}
+    }
+    if let Some(acwr) = &acwr {
+        match acwr.state.as_str() {
+            "overreaching" => flags.push(flag(
+                CoachAlertSeverity::Priority,
+                "acwr_overreaching",
+                format!("ACWR {:.2}: acute load far above chronic", acwr.ratio),
+            )),
+            "watch" => flags.push(flag(
+                CoachAlertSeverity::Caution,
+                "acwr_watch",
+                format!("ACWR {:.2}: load rising quickly", acwr.ratio),
+            )),
+            "underloaded" => flags.push(flag(
+                CoachAlertSeverity::Info,
+                "acwr_underloaded",
+                format!("ACWR {:.2}: load below chronic level", acwr.ratio),
+            )),
+            _ => {}
+        }
+    }
+    if let Some(form) = form.filter(|form| *form < DEEP_FATIGUE_FORM) {
+        flags.push(flag(
+            CoachAlertSeverity::Priority,
+            "deep_fatigue",
+            format!("Form {form:.0}: deep fatigue"),
+        ));
+    }
+    if metrics.as_ref().is_some_and(|m| m.hrv_suppression_flag) {
+        flags.push(flag(
+            CoachAlertSeverity::Caution,
+            "hrv_suppressed",
+            format!(
+                "HRV {:.0}% of baseline",
+                hrv_ratio.unwrap_or_default() * 100.0
+            ),
+        ));
+    }
+    if let Some(readiness) = readiness.filter(|readiness| *readiness < LOW_READINESS) {
+        flags.push(flag(
+            CoachAlertSeverity::Caution,
+            "low_readiness",
+            format!("Readiness {readiness:.1}"),
+        ));
+    }
+
+    let has_recent_data = age_days.is_some_and(|days| days <= STALE_WELLNESS_DAYS);
+    AthleteSnapshot {
+        athlete_id: athlete.id.clone(),
+        name: athlete.name.clone(),
+        latest_date: latest.and_then(entry_day).map(str::to_string),
+        ctl: latest.and_then(|entry| entry.ctl),
+        atl: latest.and_then(|entry| entry.atl),
+        form,
+        acwr,
+        readiness,
+        hrv_ratio,
+        status: status_for(&flags, has_recent_data),
+        flags,
+    }
+}
+
+/// Snapshot for an athlete whose wellness could not be fetched.
+#[must_use]
+pub fn unavailable_athlete(athlete: &CoachedAthlete, error: &str) -> AthleteSnapshot {
+    AthleteSnapshot {
+        athlete_id: athlete.id.clone(),
+        name: athlete.name.clone(),
+        latest_date: None,
+        ctl: None,
+        atl: None,
+        form: None,
+        acwr: None,
+        readiness: None,
+        hrv_ratio: None,
+        flags: vec![flag(
+            CoachAlertSeverity::Info,
+            "unavailable",
+            format!("Wellness unavailable: {error}"),
+        )],
+        status: RosterStatus::NoData,
+    }
+}
+
+/// Order the roster by status, most urgent first, then by lowest form.
+pub fn rank_roster(snapshots: &mut [AthleteSnapshot]) {
+    snapshots.sort_by(|a, b| {
+        a.status.cmp(&b.status).then_with(|| {
+            let form = |snapshot: &AthleteSnapshot| snapshot.form.unwrap_or(f64::INFINITY);
+            form(a).total_cmp(&form(b))
+        })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn athlete(id: &str) -> CoachedAthlete {
+        CoachedAthlete {
+            id: id.to_string(),
+            name: Some(format!("Athlete {id}")),
+            ..Default::default()
+        }
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 10).unwrap()
+    }
+
+    #[test]
+    fn overreaching_and_deep_fatigue_need_attention() {
+        let wellness = json!([
+            {"id": "2026-03-09", "ctl": 50.0, "atl": 70.0},
+            {"id": "2026-03-10", "ctl": 50.0, "atl": 85.0}
+        ]);
+        let snapshot = summarize_athlete(&athlete("i1"), &wellness, today());
+
+        assert_eq!(snapshot.status, RosterStatus::Attention);
+        assert_eq!(snapshot.latest_date.as_deref(), Some("2026-03-10"));
+        assert_eq!(snapshot.form, Some(-35.0));
+        let codes: Vec<_> = snapshot.flags.iter().map(|f| f.code.as_str()).collect();
+        assert!(codes.contains(&"acwr_overreaching"), "{codes:?}");
+        assert!(codes.contains(&"deep_fatigue"), "{codes:?}");
+    }
+
+    #[test]
+    fn balanced_recent_wellness_is_ready() {
+        let wellness = json!([{"id": "2026-03-09", "ctl": 60.0, "atl": 62.0, "readiness": 8.0}]);
+        let snapshot = summarize_athlete(&athlete("i2"), &wellness, today());
+
+        assert_eq!(snapshot.status, RosterStatus::Ready, "{:?}", snapshot.flags);
+        assert_eq!(snapshot.readiness, Some(8.0));
+        assert!(snapshot.flags.is_empty());
+    }
+
+    #[test]
+    fn missing_or_stale_wellness_reports_no_data() {
+        let empty = summarize_athlete(&athlete("i3"), &json!([]), today());
+        assert_eq!(empty.status, RosterStatus::NoData);
+        assert_eq!(empty.flags[0].code, "no_wellness");
+
+        let stale = summarize_athlete(
+            &athlete("i4"),
+            &json!([{"id": "2026-02-20", "ctl": 40.0, "atl": 41.0}]),
+            today(),
+        );
+        assert_eq!(stale.status, RosterStatus::NoData);
+        assert_eq!(stale.flags[0].code, "stale_wellness");
+
+        let failed = unavailable_athlete(&athlete("i5"), "403 Forbidden");
+        assert_eq!(failed.status, RosterStatus::NoData);
+        assert!(failed.flags[0].message.contains("403"));
+    }
+
+    #[test]
+    fn rank_roster_puts_urgent_and_most_fatigued_first() {
+        let mut roster = vec![
+            summarize_athlete(
+                &athlete("ready"),
+                &json!([{"id": "2026-03-10", "ctl": 60.0, "atl": 60.0}]),
+                today(),
+            ),
+            summarize_athlete(&athlete("none"), &json!([]), today()),
+            summarize_athlete(
+                &athlete("tired"),
+                &json!([{"id": "2026-03-10", "ctl": 50.0, "atl": 90.0}]),
+                today(),
+            ),
+            summarize_athlete(
+                &athlete("fresh"),
+                &json!([{"id": "2026-03-10", "ctl": 60.0, "atl": 55.0}]),
+                today(),
+            ),
+        ];
+        rank_roster(&mut roster);
+
+        let order: Vec<_> = roster.iter().map(|s| s.athlete_id.as_str()).collect();
+        assert_eq!(order, vec!["tired", "none", "ready", "fresh"]);
+    }
+}
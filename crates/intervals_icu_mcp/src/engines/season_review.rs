@@ -0,0 +1,835 @@
+//! Season and year-over-year review.
+//! Summarises a season (up to a year) from activity summaries, best-effort
+//! curves, calendar events and wellness: monthly volume, load and intensity
+//! distribution, peak CTL, a best-efforts set, race outcomes, consistency,
+//! illness / injury downtime and gear usage, and compares it with the same span
+//! a year earlier. Activity details are optional and only refine intensity
+//! distribution and gear usage for the sessions they cover.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::{Datelike, Months, NaiveDate};
+use intervals_icu_client::domains::curve::CurveKind;
+use intervals_icu_client::{ActivitySummary, Event, EventCategory};
+use serde_json::Value;
+
+use crate::domains::records::RecordMetric;
+use crate::domains::season::{
+    BestEffort, BestEffortKind, GearUsage, MonthSummary, RaceOutcome, SeasonDelta, SeasonReview,
+};
+use crate::engines::analysis_fetch::estimate_activity_load;
+use crate::engines::coach_metrics::{compute_consistency_index, extract_ctl_series, value_number};
+use crate::engines::gear_wear::value_id;
+use crate::engines::health_episodes::build_health_episodes;
+use crate::engines::personal_records::{parse_curve_points, value_at};
+use crate::engines::progress_tracking::extract_zone_distribution;
+use crate::engines::session_rpe::calibrate_srpe_to_tss;
+use crate::engines::threshold_drift::extract_eftp_history;
+
+// =============================================================================
+// Season Review Constants
+// Sources: Intervals.icu wellness eFTP history; peak, duration and distance
+//          bests are read from the activity power / HR / pace curves bounded
+//          to the season
+// =============================================================================
+
+/// Longest season that can be reviewed (days).
+pub const MAX_SEASON_DAYS: i64 = 366;
+
+/// Shortest season that can be reviewed (days).
+pub const MIN_SEASON_DAYS: i64 = 28;
+
+/// Sessions in a week for it to count as consistent.
+pub const CONSISTENT_WEEK_MIN_SESSIONS: usize = 3;
+
+/// Curve anchors: 1 second and 20 minutes (seconds) and race distances (metres).
+const PEAK_EFFORT_SECS: u32 = 1;
+const SUSTAINED_EFFORT_SECS: u32 = 1200;
+const PACE_5K_M: u32 = 5000;
+const PACE_10K_M: u32 = 10000;
+const PACE_HALF_M: u32 = 21097;
+
+/// Curve payloads for one window, keyed by curve kind and curve sport.
+pub type EffortCurves = HashMap<(CurveKind, &'static str), Value>;
+
+/// Curve a best effort is read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EffortCurve {
+    pub kind: CurveKind,
+    /// Curve sports searched; the best across them wins.
+    pub sports: &'static [&'static str],
+    /// Seconds for power and HR curves, metres for pace curves.
+    pub anchor: u32,
+}
+
+impl BestEffortKind {
+    pub const ALL: [Self; 8] = [
+        Self::Eftp,
+        Self::PeakPower,
+        Self::Power20m,
+        Self::Pace5k,
+        Self::Pace10k,
+        Self::PaceHalf,
+        Self::MaxHr,
+        Self::Hr20m,
+    ];
+
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Eftp => "eFTP",
+            Self::PeakPower => "Peak power",
+            Self::Power20m => "20 min power",
+            Self::Pace5k => "5k pace",
+            Self::Pace10k => "10k pace",
+            Self::PaceHalf => "Half marathon pace",
+            Self::MaxHr => "Max HR",
+            Self::Hr20m => "20 min HR",
+        }
+    }
+
+    /// Curve the effort is read from; `None` for eFTP, which comes from wellness.
+    #[must_use]
+    pub fn curve(self) -> Option<EffortCurve> {
+        let (kind, sports, anchor): (_, &'static [&'static str], _) = match self {
+            Self::Eftp => return None,
+            Self::PeakPower => (CurveKind::Power, &["Ride"], PEAK_EFFORT_SECS),
+            Self::MaxHr => (CurveKind::HeartRate, &["Ride", "Run"], PEAK_EFFORT_SECS),
+            Self::Power20m => (CurveKind::Power, &["Ride"], SUSTAINED_EFFORT_SECS),
+            Self::Pace5k => (CurveKind::Pace, &["Run"], PACE_5K_M),
+            Self::Pace10k => (CurveKind::Pace, &["Run"], PACE_10K_M),
+            Self::PaceHalf => (CurveKind::Pace, &["Run"], PACE_HALF_M),
+            Self::Hr20m => (
+                CurveKind::HeartRate,
+                &["Ride", "Run"],
+                SUSTAINED_EFFORT_SECS,
+            ),
+        };
+        Some(EffortCurve {
+            kind,
+            sports,
+            anchor,
+        })
+    }
+
+    /// Pace is better when lower; every other effort is better when higher.
+    #[must_use]
+    pub fn higher_is_better(self) -> bool {
+        !matches!(self, Self::Pace5k | Self::Pace10k | Self::PaceHalf)
+    }
+
+    #[must_use]
+    pub fn unit(self) -> &'static str {
+        match self {
+            Self::Eftp | Self::PeakPower | Self::Power20m => "W",
+            Self::Pace5k | Self::Pace10k | Self::PaceHalf => "/km",
+            Self::MaxHr | Self::Hr20m => "bpm",
+        }
+    }
+}
+
+/// Curve requests needed to read `kinds`, without duplicates.
+#[must_use]
+pub fn effort_curve_requests(
+    kinds: impl IntoIterator<Item = BestEffortKind>,
+) -> Vec<(CurveKind, &'static str)> {
+    kinds
+        .into_iter()
+        .filter_map(BestEffortKind::curve)
+        .flat_map(|curve| curve.sports.iter().map(move |sport| (curve.kind, *sport)))
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Data a season review is built from. Activities, events and wellness may
+/// cover more than the season; only entries inside it are used.
+#[derive(Debug, Clone, Copy)]
+pub struct SeasonSources<'a> {
+    pub activities: &'a [ActivitySummary],
+    pub details: &'a HashMap<String, Value>,
+    pub events: &'a [Event],
+    pub wellness: Option<&'a Value>,
+    pub gear_list: Option<&'a Value>,
+}
+
+/// The same span one year earlier.
+#[must_use]
+pub fn previous_season(start: NaiveDate, end: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let shift = |date: NaiveDate| date.checked_sub_months(Months::new(12)).unwrap_or(date);
+    (shift(start), shift(end))
+}
+
+pub(crate) fn parse_date(value: &str) -> Option<NaiveDate> {
+    value
+        .get(..10)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+}
+
+fn moving_secs(activity: &ActivitySummary, detail: Option<&Value>) -> f64 {
+    activity
+        .moving_time
+        .map(f64::from)
+        .or_else(|| detail.and_then(|detail| value_number(detail, &["moving_time"])))
+        .unwrap_or(0.0)
+}
+
+fn distance_km(activity: &ActivitySummary, detail: Option<&Value>) -> f64 {
+    activity
+        .distance
+        .or_else(|| detail.and_then(|detail| value_number(detail, &["distance"])))
+        .unwrap_or(0.0)
+        / 1000.0
+}
+
+fn consider(
+    best: &mut BTreeMap<BestEffortKind, BestEffort>,
+    kind: BestEffortKind,
+    value: Option<f64>,
+    date: Option<NaiveDate>,
+    activity_id: Option<&str>,
+) {
+    let Some(value) = value.filter(|value| *value > 0.0) else {
+        return;
+    };
+    let better = best.get(&kind).is_none_or(|current| {
+        if kind.higher_is_better() {
+            value > current.value
+        } else {
+            value < current.value
+        }
+    });
+    if better {
+        best.insert(
+            kind,
+            BestEffort {
+                kind,
+                value,
+                date,
+                activity_id: activity_id.map(str::to_string),
+            },
+        );
+    }
+}
+
+/// Best eFTP in `start..=end` from the wellness eFTP history.
+pub(crate) fn record_wellness_best_efforts(
+    best: &mut BTreeMap<BestEffortKind, BestEffort>,
+    wellness: Option<&Value>,
+    start: NaiveDate,
+    end: NaiveDate,
+) {
+    for (date, eftp) in wellness.map(extract_eftp_history).unwrap_or_default() {
+        if date >= start && date <= end {
+            consider(best, BestEffortKind::Eftp, Some(eftp), Some(date), None);
+        }
+    }
+}
+
+/// Peak, duration and distance bests read from curves covering one window. Pace
+/// curves give the time over the distance, stored as seconds per km. `dates`
+/// resolves the activity that set a value to its date.
+pub(crate) fn record_curve_best_efforts(
+    best: &mut BTreeMap<BestEffortKind, BestEffort>,
+    curves: &EffortCurves,
+    dates: &HashMap<&str, NaiveDate>,
+) {
+    for kind in BestEffortKind::ALL {
+        let Some(curve) = kind.curve() else {
+            continue;
+        };
+        // HR curves share the power layout: `secs` paired with `values`.
+        let metric = match curve.kind {
+            CurveKind::Pace => RecordMetric::Pace,
+            CurveKind::Power | CurveKind::HeartRate => RecordMetric::Power,
+        };
+        for sport in curve.sports {
+            let Some(payload) = curves.get(&(curve.kind, *sport)) else {
+                continue;
+            };
+            let Some((value, activity_id)) =
+                value_at(&parse_curve_points(payload, metric), curve.anchor)
+            else {
+                continue;
+            };
+            let value = match curve.kind {
+                CurveKind::Pace => value / (f64::from(curve.anchor) / 1000.0),
+                CurveKind::Power | CurveKind::HeartRate => value,
+            };
+            let date = activity_id.as_deref().and_then(|id| dates.get(id)).copied();
+            consider(best, kind, Some(value), date, activity_id.as_deref());
+        }
+    }
+}
+
+pub(crate) fn race_priority(category: &EventCategory) -> Option<&'static str> {
+    match category {
+        EventCategory::RaceA => Some("A"),
+        EventCategory::RaceB => Some("B"),
+        EventCategory::RaceC => Some("C"),
+        _ => None,
+    }
+}
+
+fn race_outcomes(
+    events: &[Event],
+    in_season: &[(NaiveDate, &ActivitySummary)],
+    details: &HashMap<String, Value>,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Vec<RaceOutcome> {
+    let mut races = events
+        .iter()
+        .filter_map(|event| {
+            let priority = race_priority(&event.category)?;
+            let date = parse_date(&event.start_date_local)?;
+            if date < start || date > end {
+                return None;
+            }
+            // The longest session on race day is taken as the race itself.
+            let activity = in_season
+                .iter()
+                .filter(|(day, _)| *day == date)
+                .map(|(_, activity)| *activity)
+                .max_by(|a, b| {
+                    moving_secs(a, details.get(&a.id))
+                        .total_cmp(&moving_secs(b, details.get(&b.id)))
+                });
+            Some(RaceOutcome {
+                date,
+                name: event.name.clone(),
+                priority: priority.to_string(),
+                activity_id: activity.map(|activity| activity.id.clone()),
+                moving_time_secs: activity
+                    .map(|activity| moving_secs(activity, details.get(&activity.id)) as i64),
+                distance_km: activity
+                    .map(|activity| distance_km(activity, details.get(&activity.id)))
+                    .filter(|km| *km > 0.0),
+            })
+        })
+        .collect::<Vec<_>>();
+    races.sort_by_key(|race| race.date);
+    races
+}
+
+fn gear_usage(
+    in_season: &[(NaiveDate, &ActivitySummary)],
+    details: &HashMap<String, Value>,
+    gear_list: Option<&Value>,
+) -> Vec<GearUsage> {
+    let names = gear_list
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|gear| {
+                    let id = gear.get("id").and_then(value_id)?;
+                    let name = gear.get("name").and_then(Value::as_str)?;
+                    Some((id, name.to_string()))
+                })
+                .collect::<HashMap<_, _>>()
+        })
+        .unwrap_or_default();
+
+    let mut usage = BTreeMap::<String, GearUsage>::new();
+    for (_, activity) in in_season {
+        let detail = details.get(&activity.id);
+        let Some(gear_id) = detail
+            .and_then(|detail| detail.get("gear"))
+            .and_then(|gear| gear.get("id"))
+            .and_then(value_id)
+        else {
+            continue;
+        };
+        let entry = usage.entry(gear_id.clone()).or_insert_with(|| GearUsage {
+            name: names.get(&gear_id).cloned().or_else(|| {
+                detail
+                    .and_then(|detail| detail.get("gear"))
+                    .and_then(|gear| gear.get("name"))
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            }),
+            gear_id,
+            ..Default::default()
+        });
+        entry.sessions += 1;
+        entry.distance_km += distance_km(activity, detail);
+        entry.hours += moving_secs(activity, detail) / 3600.0;
+    }
+    let mut usage = usage.into_values().collect::<Vec<_>>();
+    usage.sort_by(|a, b| b.hours.total_cmp(&a.hours));
+    usage
+}
+
+/// Build the review for `start..=end`; `curves` must cover the same window.
+#[must_use]
+pub fn build_season_review(
+    sources: &SeasonSources<'_>,
+    curves: &EffortCurves,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> SeasonReview {
+    let details = sources.details;
+    let in_season = sources
+        .activities
+        .iter()
+        .filter_map(|activity| {
+            let date = parse_date(&activity.start_date_local)?;
+            (date >= start && date <= end).then_some((date, activity))
+        })
+        .collect::<Vec<_>>();
+    let calibration = calibrate_srpe_to_tss(
+        in_season
+            .iter()
+            .filter_map(|(_, activity)| details.get(&activity.id)),
+    );
+
+    let mut review = SeasonReview {
+        start,
+        end,
+        ..Default::default()
+    };
+    let mut months = BTreeMap::<String, (MonthSummary, Vec<(f64, f64, f64)>)>::new();
+    let mut weekly_sessions = HashMap::<(i32, u32), usize>::new();
+    let mut best = BTreeMap::new();
+    for (date, activity) in &in_season {
+        let detail = details.get(&activity.id);
+        let hours = moving_secs(activity, detail) / 3600.0;
+        let km = distance_km(activity, detail);
+        let load = activity
+            .training_load
+            .map(f64::from)
+            .or_else(|| estimate_activity_load(detail, &calibration).map(|(load, _)| load))
+            .unwrap_or(0.0);
+
+        review.sessions += 1;
+        review.hours += hours;
+        review.distance_km += km;
+        review.load += load;
+
+        let key = format!("{:04}-{:02}", date.year(), date.month());
+        let (month, zones) = months.entry(key.clone()).or_insert_with(|| {
+            (
+                MonthSummary {
+                    month: key,
+                    ..Default::default()
+                },
+                Vec::new(),
+            )
+        });
+        month.sessions += 1;
+        month.hours += hours;
+        month.distance_km += km;
+        month.load += load;
+        if let Some(split) = detail.and_then(extract_zone_distribution) {
+            zones.push(split);
+        }
+
+        let week = date.iso_week();
+        *weekly_sessions
+            .entry((week.year(), week.week()))
+            .or_default() += 1;
+    }
+    review.months = months
+        .into_values()
+        .map(|(mut month, zones)| {
+            if !zones.is_empty() {
+                let n = zones.len() as f64;
+                month.tid = Some((
+                    zones.iter().map(|zone| zone.0).sum::<f64>() / n,
+                    zones.iter().map(|zone| zone.1).sum::<f64>() / n,
+                    zones.iter().map(|zone| zone.2).sum::<f64>() / n,
+                ));
+            }
+            month
+        })
+        .collect();
+    let dates = in_season
+        .iter()
+        .map(|(date, activity)| (activity.id.as_str(), *date))
+        .collect::<HashMap<_, _>>();
+    record_wellness_best_efforts(&mut best, sources.wellness, start, end);
+    record_curve_best_efforts(&mut best, curves, &dates);
+    review.best_efforts = best.into_values().collect();
+
+    let season_weeks = (0..=(end - start).num_days())
+        .map(|offset| {
+            let week = (start + chrono::Duration::days(offset)).iso_week();
+            (week.year(), week.week())
+        })
+        .collect::<HashSet<_>>();
+    review.total_weeks = season_weeks.len();
+    review.consistent_weeks = season_weeks
+        .iter()
+        .filter(|week| {
+            weekly_sessions.get(*week).copied().unwrap_or(0) >= CONSISTENT_WEEK_MIN_SESSIONS
+        })
+        .count();
+
+    let planned = sources
+        .events
+        .iter()
+        .filter(|event| event.category == EventCategory::Workout)
+        .filter_map(|event| parse_date(&event.start_date_local))
+        .filter(|date| *date >= start && *date <= end)
+        .count();
+    review.consistency = compute_consistency_index(review.sessions, planned);
+
+    let ctl_series = extract_ctl_series(sources.wellness);
+    if let Some((dates, values)) = &ctl_series {
+        let peak = dates
+            .iter()
+            .zip(values)
+            .filter_map(|(date, value)| {
+                let date = parse_date(date)?;
+                (date >= start && date <= end).then_some((date, *value))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        review.peak_ctl_date = peak.map(|(date, _)| date);
+        review.peak_ctl = peak.map(|(_, value)| value);
+    }
+
+    let activities = in_season
+        .iter()
+        .map(|(_, activity)| (*activity).clone())
+        .collect::<Vec<_>>();
+    review.episodes = build_health_episodes(
+        sources.events,
+        sources.wellness,
+        &activities,
+        ctl_series
+            .as_ref()
+            .map(|(dates, values)| (dates.as_slice(), values.as_slice())),
+        end,
+    )
+    .into_iter()
+    .filter(|episode| episode.start <= end && episode.end >= start)
+    .collect();
+    review.downtime_days = review
+        .episodes
+        .iter()
+        .map(|episode| {
+            let from = episode.start.max(start);
+            let until = episode
+                .return_date
+                .map(|date| date - chrono::Duration::days(1))
+                .unwrap_or(end)
+                .max(episode.end)
+                .min(end);
+            (until - from).num_days() + 1
+        })
+        .sum();
+
+    review.races = race_outcomes(sources.events, &in_season, details, start, end);
+    review.gear = gear_usage(&in_season, details, sources.gear_list);
+    review
+}
+
+fn delta(
+    metric: &str,
+    current: Option<f64>,
+    previous: Option<f64>,
+    higher_is_better: bool,
+) -> SeasonDelta {
+    let change_pct = current
+        .zip(previous)
+        .filter(|(_, previous)| previous.abs() > f64::EPSILON)
+        .map(|(current, previous)| (current - previous) / previous * 100.0);
+    let improved = current
+        .zip(previous)
+        .filter(|(current, previous)| (current - previous).abs() > f64::EPSILON)
+        .map(|(current, previous)| (current > previous) == higher_is_better);
+    SeasonDelta {
+        metric: metric.to_string(),
+        effort: None,
+        current,
+        previous,
+        change_pct,
+        improved,
+    }
+}
+
+/// Year-over-year deltas for headline metrics and every best effort set in
+/// either season.
+#[must_use]
+pub fn compare_seasons(current: &SeasonReview, previous: &SeasonReview) -> Vec<SeasonDelta> {
+    let consistent_pct = |review: &SeasonReview| {
+        (review.total_weeks > 0)
+            .then(|| review.consistent_weeks as f64 / review.total_weeks as f64 * 100.0)
+    };
+    let mut deltas = vec![
+        delta(
+            "Sessions",
+            Some(current.sessions as f64),
+            Some(previous.sessions as f64),
+            true,
+        ),
+        delta("Hours", Some(current.hours), Some(previous.hours), true),
+        delta(
+            "Distance (km)",
+            Some(current.distance_km),
+            Some(previous.distance_km),
+            true,
+        ),
+        delta("Load", Some(current.load), Some(previous.load), true),
+        delta("Peak CTL", current.peak_ctl, previous.peak_ctl, true),
+        delta(
+            "Consistent weeks (%)",
+            consistent_pct(current),
+            consistent_pct(previous),
+            true,
+        ),
+        delta(
+            "Downtime days",
+            Some(current.downtime_days as f64),
+            Some(previous.downtime_days as f64),
+            false,
+        ),
+    ];
+
+    let kinds = current
+        .best_efforts
+        .iter()
+        .chain(&previous.best_efforts)
+        .map(|effort| effort.kind)
+        .collect::<std::collections::BTreeSet<_>>();
+    let value = |review: &SeasonReview, kind| {
+        review
+            .best_efforts
+            .iter()
+            .find(|effort| effort.kind == kind)
+            .map(|effort| effort.value)
+    };
+    for kind in kinds {
+        deltas.push(SeasonDelta {
+            effort: Some(kind),
+            ..delta(
+                kind.label(),
+                value(current, kind),
+                value(previous, kind),
+                kind.higher_is_better(),
+            )
+        });
+    }
+    deltas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn activity(id: &str, date: &str, minutes: i32, km: f64, load: i32) -> ActivitySummary {
+        ActivitySummary {
+            id: id.into(),
+            start_date_local: format!("{date}T08:00:00"),
+            moving_time: Some(minutes * 60),
+            distance: Some(km * 1000.0),
+            training_load: Some(load),
+            ..Default::default()
+        }
+    }
+
+    fn event(date: &str, category: EventCategory, name: &str) -> Event {
+        Event {
+            id: None,
+            start_date_local: date.into(),
+            name: name.into(),
+            category,
+            description: None,
+            r#type: None,
+            end_date_local: None,
+        }
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn sample_curves() -> EffortCurves {
+        HashMap::from([
+            (
+                (CurveKind::Power, "Ride"),
+                json!({"list": [{"secs": [1, 60, 1200], "values": [900, 330, 232],
+                                 "activity_id": ["b1", "b1", "b1"]}]}),
+            ),
+            (
+                (CurveKind::Pace, "Run"),
+                json!({"list": [{"distance": [5000, 10000, 21097],
+                                 "values": [1250, 2700, 5700],
+                                 "activity_id": ["r2", "r1", "race"]}]}),
+            ),
+            (
+                (CurveKind::HeartRate, "Ride"),
+                json!({"list": [{"secs": [1, 1200], "values": [171, 161],
+                                 "activity_id": ["b1", "b1"]}]}),
+            ),
+            (
+                (CurveKind::HeartRate, "Run"),
+                json!({"list": [{"secs": [1, 600, 1800], "values": [188, 178, 170],
+                                 "activity_id": ["r1", "r1", "race"]}]}),
+            ),
+        ])
+    }
+
+    fn sample_review() -> SeasonReview {
+        let activities = vec![
+            activity("r1", "2026-03-02", 50, 10.0, 60),
+            activity("r2", "2026-03-04", 25, 5.0, 30),
+            activity("b1", "2026-03-05", 90, 40.0, 90),
+            activity("race", "2026-04-12", 95, 21.1, 140),
+            activity("old", "2025-03-02", 60, 12.0, 70),
+        ];
+        // Details only for the sessions the review samples; b1 has none.
+        let details = HashMap::from([
+            (
+                "r1".to_string(),
+                json!({"type": "Run", "gear": {"id": "g1"},
+                       "icu_zone_times": [{"id": "Z2", "secs": 2400}, {"id": "Z4", "secs": 600}]}),
+            ),
+            (
+                "r2".to_string(),
+                json!({"type": "Run", "gear": {"id": "g1"}}),
+            ),
+            (
+                "race".to_string(),
+                json!({"type": "Run", "gear": {"id": "g2", "name": "Race flats"}}),
+            ),
+        ]);
+        let events = vec![
+            event("2026-03-02", EventCategory::Workout, "Easy"),
+            event("2026-03-04", EventCategory::Workout, "Tempo"),
+            event("2026-03-06", EventCategory::Workout, "Long"),
+            event("2026-03-20", EventCategory::Sick, "Cold"),
+            event("2026-04-12", EventCategory::RaceA, "Spring Half"),
+        ];
+        let wellness = json!([
+            {"date": "2026-03-01", "ctl": 50.0, "sportInfo": [{"type": "Ride", "eftp": 258.0}]},
+            {"date": "2026-04-11", "ctl": 58.5, "sportInfo": [{"type": "Ride", "eftp": 265.0}]},
+            {"date": "2026-04-20", "ctl": 55.0, "sportInfo": [{"type": "Ride", "eftp": 262.0}]}
+        ]);
+        let gear = json!([{"id": "g1", "name": "Daily trainer"}]);
+        build_season_review(
+            &SeasonSources {
+                activities: &activities,
+                details: &details,
+                events: &events,
+                wellness: Some(&wellness),
+                gear_list: Some(&gear),
+            },
+            &sample_curves(),
+            date(2026, 3, 1),
+            date(2026, 4, 30),
+        )
+    }
+
+    #[test]
+    fn summarises_months_peak_ctl_and_consistency() {
+        let review = sample_review();
+        assert_eq!(review.sessions, 4);
+        assert_eq!(review.months.len(), 2);
+        assert_eq!(review.months[0].month, "2026-03");
+        assert_eq!(review.months[0].sessions, 3);
+        assert!((review.months[0].load - 180.0).abs() < 1e-9);
+        let (z1, _, z3) = review.months[0].tid.unwrap();
+        assert!((z1 - 0.8).abs() < 1e-9 && (z3 - 0.2).abs() < 1e-9);
+        assert_eq!(review.peak_ctl, Some(58.5));
+        assert_eq!(review.peak_ctl_date, Some(date(2026, 4, 11)));
+        assert_eq!(review.consistency.sessions_planned, 3);
+        assert_eq!(review.consistent_weeks, 1);
+        assert_eq!(review.total_weeks, 10);
+    }
+
+    #[test]
+    fn collects_best_efforts_races_downtime_and_gear() {
+        let review = sample_review();
+        let effort = |kind| {
+            review
+                .best_efforts
+                .iter()
+                .find(|effort| effort.kind == kind)
+                .map(|effort| effort.value)
+        };
+        assert_eq!(effort(BestEffortKind::Eftp), Some(265.0));
+        assert_eq!(effort(BestEffortKind::PeakPower), Some(900.0));
+        // The run HR curve's 1-second value beats the ride's.
+        assert_eq!(effort(BestEffortKind::MaxHr), Some(188.0));
+        // The 20-minute curve value, not the ride's 210 W session average.
+        assert_eq!(effort(BestEffortKind::Power20m), Some(232.0));
+        // Each distance keeps its own curve best: 5 km in 20:50 is 250 s/km, not
+        // the half-marathon race pace.
+        assert_eq!(effort(BestEffortKind::Pace5k), Some(250.0));
+        assert_eq!(effort(BestEffortKind::Pace10k), Some(270.0));
+        let half = effort(BestEffortKind::PaceHalf).unwrap();
+        assert!((half - 5700.0 / 21.097).abs() < 1e-9);
+        // 20 min HR interpolates the run curve (174 bpm) and beats the ride curve.
+        let hr = review
+            .best_efforts
+            .iter()
+            .find(|effort| effort.kind == BestEffortKind::Hr20m)
+            .unwrap();
+        assert!((hr.value - 174.0).abs() < 1e-9);
+        assert_eq!((hr.date, hr.activity_id.as_deref()), (None, None));
+        let five_k = review
+            .best_efforts
+            .iter()
+            .find(|effort| effort.kind == BestEffortKind::Pace5k)
+            .unwrap();
+        assert_eq!(five_k.date, Some(date(2026, 3, 4)));
+        assert_eq!(five_k.activity_id.as_deref(), Some("r2"));
+
+        assert_eq!(review.races.len(), 1);
+        assert_eq!(review.races[0].priority, "A");
+        assert_eq!(review.races[0].activity_id.as_deref(), Some("race"));
+
+        assert_eq!(review.episodes.len(), 1);
+        // Ill on 20 March, back running on 12 April.
+        assert_eq!(review.downtime_days, 23);
+
+        assert_eq!(review.gear[0].gear_id, "g2");
+        assert_eq!(review.gear[0].name.as_deref(), Some("Race flats"));
+        assert_eq!(review.gear[1].name.as_deref(), Some("Daily trainer"));
+        assert_eq!(review.gear[1].sessions, 2);
+    }
+
+    #[test]
+    fn effort_curve_requests_are_deduplicated() {
+        assert_eq!(
+            effort_curve_requests(BestEffortKind::ALL),
+            [
+                (CurveKind::Power, "Ride"),
+                (CurveKind::HeartRate, "Ride"),
+                (CurveKind::HeartRate, "Run"),
+                (CurveKind::Pace, "Run"),
+            ]
+        );
+        assert!(effort_curve_requests([BestEffortKind::Eftp]).is_empty());
+        assert_eq!(
+            effort_curve_requests([BestEffortKind::PeakPower]),
+            [(CurveKind::Power, "Ride")]
+        );
+    }
+
+    #[test]
+    fn compares_against_last_year_with_pace_improving_downward() {
+        let (start, end) = previous_season(date(2026, 3, 1), date(2026, 4, 30));
+        assert_eq!((start, end), (date(2025, 3, 1), date(2025, 4, 30)));
+
+        let current = sample_review();
+        let mut previous = current.clone();
+        previous.hours = current.hours / 2.0;
+        for effort in &mut previous.best_efforts {
+            if effort.kind == BestEffortKind::Pace5k {
+                effort.value = 330.0;
+            }
+        }
+        let deltas = compare_seasons(&current, &previous);
+        let hours = deltas.iter().find(|d| d.metric == "Hours").unwrap();
+        assert!((hours.change_pct.unwrap() - 100.0).abs() < 1e-9);
+        assert_eq!(hours.improved, Some(true));
+        let pace = deltas.iter().find(|d| d.metric == "5k pace").unwrap();
+        assert_eq!(pace.improved, Some(true));
+        let sessions = deltas.iter().find(|d| d.metric == "Sessions").unwrap();
+        assert_eq!(sessions.improved, None);
+    }
+}
@@ -0,0 +1,261 @@
+//! Session-RPE load.
+//! Foster session load (RPE × minutes) from the RPE and feel recorded on an
+//! activity, and a TSS-equivalent estimate used for sessions recorded without
+//! power or heart rate. The conversion factor is calibrated per athlete from
+//! sessions that carry both RPE and a sensor-based training load.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::engines::coach_metrics::get_number;
+use crate::engines::strength::{is_strength_activity, strength_session_load};
+
+// =============================================================================
+// Session-RPE Constants
+// Sources: Foster et al., J Strength Cond Res 2001 (session-RPE, CR-10 scale);
+//          Intervals.icu activity fields `icu_rpe` (1-10) and `feel` (1-5)
+// =============================================================================
+
+/// Lowest and highest CR-10 rating accepted as RPE.
+pub const RPE_MIN: f64 = 1.0;
+pub const RPE_MAX: f64 = 10.0;
+
+/// Intervals.icu feel scale: 1 = strong … 5 = weak.
+pub const FEEL_MIN: i64 = 1;
+pub const FEEL_MAX: i64 = 5;
+
+/// Default conversion from session-RPE arbitrary units to TSS-equivalent load.
+/// RPE 7 for 60 min (420 AU) maps to ~84, close to an hour at threshold-ish effort.
+pub const DEFAULT_SRPE_TO_TSS: f64 = 0.2;
+
+/// Sessions with both RPE and sensor load needed before calibrating the factor.
+pub const MIN_CALIBRATION_SESSIONS: usize = 5;
+
+/// Bounds on the calibrated factor, guarding against a few mislabelled sessions.
+const CALIBRATION_FACTOR_MIN: f64 = 0.08;
+const CALIBRATION_FACTOR_MAX: f64 = 0.5;
+
+const RPE_KEYS: &[&str] = &["icu_rpe", "perceived_exertion", "rpe"];
+const SENSOR_KEYS: &[&str] = &[
+    "average_heartrate",
+    "icu_average_watts",
+    "average_watts",
+    "icu_weighted_avg_watts",
+];
+const LOAD_KEYS: &[&str] = &["icu_training_load", "training_load", "icuTrainingLoad"];
+
+/// Where the load used for a session came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadSource {
+    /// Intervals.icu training load from power, HR or pace.
+    Recorded,
+    /// Estimated from session RPE × duration.
+    SessionRpe,
+}
+
+/// Athlete-specific conversion from session-RPE units to TSS-equivalent load.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SrpeCalibration {
+    pub factor: f64,
+    /// Sessions with both sensor load and RPE used for the fit.
+    pub sessions: usize,
+    /// False when too few paired sessions exist and the default factor is used.
+    pub calibrated: bool,
+}
+
+impl Default for SrpeCalibration {
+    fn default() -> Self {
+        Self {
+            factor: DEFAULT_SRPE_TO_TSS,
+            sessions: 0,
+            calibrated: false,
+        }
+    }
+}
+
+/// RPE recorded on the activity (CR-10), if any.
+#[must_use]
+pub fn activity_rpe(detail: &Value) -> Option<f64> {
+    detail
+        .as_object()
+        .and_then(|object| get_number(object, RPE_KEYS))
+        .filter(|rpe| (RPE_MIN..=RPE_MAX).contains(rpe))
+}
+
+/// Feel recorded on the activity (1 = strong … 5 = weak), if any.
+#[must_use]
+pub fn activity_feel(detail: &Value) -> Option<i64> {
+    detail
+        .get("feel")
+        .and_then(Value::as_i64)
+        .filter(|feel| (FEEL_MIN..=FEEL_MAX).contains(feel))
+}
+
+#[must_use]
+pub fn feel_label(feel: i64) -> &'static str {
+    match feel {
+        1 => "strong",
+        2 => "good",
+        3 => "normal",
+        4 => "poor",
+        _ => "weak",
+    }
+}
+
+/// Foster session load in arbitrary units: RPE × moving minutes.
+#[must_use]
+pub fn session_rpe_load(detail: &Value) -> Option<f64> {
+    let rpe = activity_rpe(detail)?;
+    let seconds = detail
+        .as_object()
+        .and_then(|object| get_number(object, &["moving_time", "elapsed_time"]))
+        .filter(|s| *s > 0.0)?;
+    Some(rpe * seconds / 60.0)
+}
+
+/// Whether the activity carries power or heart-rate data.
+#[must_use]
+pub fn has_sensor_data(detail: &Value) -> bool {
+    detail
+        .as_object()
+        .and_then(|object| get_number(object, SENSOR_KEYS))
+        .is_some_and(|value| value > 0.0)
+}
+
+/// Load for the session: recorded training load when power/HR exist or the
+/// load is non-zero, otherwise the session-RPE estimate scaled by `srpe_to_tss`.
+/// Strength sessions always use [`strength_session_load`], so the daily load
+/// series and the strength summary agree.
+#[must_use]
+pub fn effective_session_load(detail: &Value, srpe_to_tss: f64) -> Option<(f64, LoadSource)> {
+    if is_strength_activity(detail) {
+        return Some((
+            strength_session_load(detail, srpe_to_tss),
+            LoadSource::SessionRpe,
+        ));
+    }
+    let recorded = detail
+        .as_object()
+        .and_then(|object| get_number(object, LOAD_KEYS));
+    if has_sensor_data(detail) || recorded.is_some_and(|load| load > 0.0) {
+        return recorded.map(|load| (load, LoadSource::Recorded));
+    }
+    session_rpe_load(detail)
+        .map(|srpe| (srpe * srpe_to_tss, LoadSource::SessionRpe))
+        .or_else(|| recorded.map(|load| (load, LoadSource::Recorded)))
+}
+
+/// Fit the sRPE → TSS factor as Σload / ΣsRPE over sessions that have power or
+/// HR load and an RPE; falls back to the default below `MIN_CALIBRATION_SESSIONS`.
+#[must_use]
+pub fn calibrate_srpe_to_tss<'a>(details: impl IntoIterator<Item = &'a Value>) -> SrpeCalibration {
+    let (sessions, load_sum, srpe_sum) = details
+        .into_iter()
+        .filter(|detail| has_sensor_data(detail))
+        .filter_map(|detail| {
+            let load = detail
+                .as_object()
+                .and_then(|object| get_number(object, LOAD_KEYS))
+                .filter(|load| *load > 0.0)?;
+            Some((load, session_rpe_load(detail)?))
+        })
+        .fold((0usize, 0.0, 0.0), |(n, loads, srpes), (load, srpe)| {
+            (n + 1, loads + load, srpes + srpe)
+        });
+
+    if sessions < MIN_CALIBRATION_SESSIONS || srpe_sum <= 0.0 {
+        return SrpeCalibration {
+            sessions,
+            ..SrpeCalibration::default()
+        };
+    }
+    SrpeCalibration {
+        factor: (load_sum / srpe_sum).clamp(CALIBRATION_FACTOR_MIN, CALIBRATION_FACTOR_MAX),
+        sessions,
+        calibrated: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn session_rpe_load_multiplies_rpe_by_minutes() {
+        let detail = json!({"icu_rpe": 7, "moving_time": 3600});
+        assert_eq!(session_rpe_load(&detail), Some(420.0));
+        assert!(session_rpe_load(&json!({"icu_rpe": 11, "moving_time": 3600})).is_none());
+        assert!(session_rpe_load(&json!({"moving_time": 3600})).is_none());
+    }
+
+    #[test]
+    fn effective_load_prefers_recorded_load_with_sensors() {
+        let sensored = json!({
+            "icu_rpe": 7, "moving_time": 3600,
+            "average_heartrate": 150.0, "icu_training_load": 70
+        });
+        assert_eq!(
+            effective_session_load(&sensored, DEFAULT_SRPE_TO_TSS),
+            Some((70.0, LoadSource::Recorded))
+        );
+
+        let unsensored = json!({"type": "WeightTraining", "icu_rpe": 6, "moving_time": 2700});
+        let (load, source) = effective_session_load(&unsensored, DEFAULT_SRPE_TO_TSS).unwrap();
+        assert_eq!(source, LoadSource::SessionRpe);
+        assert!((load - 54.0).abs() < 1e-9);
+
+        // HR under-reads lifting: strength keeps its session-RPE load.
+        let lifting = json!({
+            "type": "WeightTraining", "moving_time": 3600,
+            "average_heartrate": 112.0, "icu_training_load": 24
+        });
+        assert_eq!(
+            effective_session_load(&lifting, DEFAULT_SRPE_TO_TSS),
+            Some((60.0, LoadSource::SessionRpe))
+        );
+
+        let zero_load = json!({"icu_training_load": 0, "moving_time": 1800});
+        assert_eq!(
+            effective_session_load(&zero_load, DEFAULT_SRPE_TO_TSS),
+            Some((0.0, LoadSource::Recorded))
+        );
+    }
+
+    #[test]
+    fn feel_is_range_checked() {
+        assert_eq!(activity_feel(&json!({"feel": 2})), Some(2));
+        assert_eq!(activity_feel(&json!({"feel": 0})), None);
+        assert_eq!(feel_label(5), "weak");
+    }
+
+    fn paired_session(load: f64, rpe: f64) -> Value {
+        json!({"icu_training_load": load, "average_heartrate": 140.0, "icu_rpe": rpe, "moving_time": 3600})
+    }
+
+    #[test]
+    fn calibration_fits_ratio_of_sums() {
+        let details = (0..5)
+            .map(|_| paired_session(60.0, 5.0))
+            .collect::<Vec<_>>();
+        let calibration = calibrate_srpe_to_tss(&details);
+        assert!(calibration.calibrated);
+        assert_eq!(calibration.sessions, 5);
+        assert!((calibration.factor - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn calibration_falls_back_and_clamps() {
+        let few = vec![paired_session(60.0, 5.0); 2];
+        let calibration = calibrate_srpe_to_tss(&few);
+        assert!(!calibration.calibrated);
+        assert_eq!(calibration.factor, DEFAULT_SRPE_TO_TSS);
+        assert_eq!(calibration.sessions, 2);
+
+        let inflated = vec![paired_session(600.0, 1.0); 6];
+        assert_eq!(
+            calibrate_srpe_to_tss(&inflated).factor,
+            CALIBRATION_FACTOR_MAX
+        );
+    }
+}
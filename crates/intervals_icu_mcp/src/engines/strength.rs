@@ -0,0 +1,523 @@
+//! Strength and mobility training.
+//! Weekly strength prescription by training phase, placement of strength days
+//! away from key endurance sessions, frequency/consistency and load estimates
+//! for completed strength work, and detection of heavy lower-body sessions
+//! whose residual fatigue compromises the next quality session.
+
+use std::collections::HashMap;
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use intervals_icu_client::ActivitySummary;
+use serde_json::Value;
+
+use crate::engines::planning::{TrainingPhase, WorkoutTemplate, ZoneSegment};
+use crate::engines::session_rpe::{SrpeCalibration, activity_rpe};
+
+// =============================================================================
+// Strength Constants
+// Sources: Beattie et al., Sports Med 2014 (2-3 sessions/week to develop,
+//          1/week to maintain); Rønnestad & Mujika, SJMSS 2015 (concurrent
+//          strength for endurance athletes); Doma et al., Sports Med 2017
+//          (lower-body resistance work impairs running economy for 24-48 h)
+// =============================================================================
+
+/// Intervals.icu activity types counted as strength training.
+pub const STRENGTH_TYPES: &[&str] = &["WeightTraining", "Crossfit"];
+
+/// Intervals.icu activity types counted as mobility work.
+pub const MOBILITY_TYPES: &[&str] = &["Yoga", "Pilates"];
+
+/// Residual-fatigue window after heavy lower-body work.
+pub const HEAVY_LOWER_BODY_WINDOW_HOURS: i64 = 48;
+
+/// RPE at or above which a lower-body strength session counts as heavy.
+pub const HEAVY_STRENGTH_RPE: f64 = 7.0;
+
+/// RPE assumed for strength sessions logged without one (moderate effort).
+pub const DEFAULT_STRENGTH_RPE: f64 = 5.0;
+
+const LOWER_BODY_KEYWORDS: &[&str] = &[
+    "squat", "deadlift", "lunge", "leg", "lower", "plyo", "step-up", "step up", "hinge",
+];
+
+/// A prescribed strength session.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrengthSession {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub duration_minutes: u32,
+    /// Heavy lower-body loading that needs 48 h before quality endurance work.
+    pub heavy_lower_body: bool,
+}
+
+const MAINTENANCE_SESSION: StrengthSession = StrengthSession {
+    name: "Strength Maintenance",
+    description: "Upper body, core and single-leg stability, 2x8 at RPE 5-6",
+    duration_minutes: 30,
+    heavy_lower_body: false,
+};
+
+/// Completed strength and mobility work over an analysis window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrengthSummary {
+    pub strength_sessions: usize,
+    pub mobility_sessions: usize,
+    pub sessions_per_week: f64,
+    /// Share of calendar weeks in the window with at least one strength session.
+    pub consistency_pct: f64,
+    pub total_minutes: f64,
+    /// TSS-equivalent load, from session RPE (or a default RPE) × duration.
+    pub estimated_load: f64,
+    pub heavy_lower_body_sessions: usize,
+}
+
+/// A heavy lower-body session close enough to affect a planned quality day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeavyStrengthSession {
+    pub activity_id: String,
+    pub name: String,
+    pub date: NaiveDate,
+}
+
+/// Strength sessions per week for a phase: develop in base, maintain closer to racing.
+#[must_use]
+pub fn strength_sessions_per_week(phase: &TrainingPhase) -> u8 {
+    match phase {
+        TrainingPhase::EarlyBase => 3,
+        TrainingPhase::Transition | TrainingPhase::LateBase | TrainingPhase::Build => 2,
+        TrainingPhase::Specific
+        | TrainingPhase::Taper
+        | TrainingPhase::Race
+        | TrainingPhase::Recovery => 1,
+    }
+}
+
+/// Main strength session for a phase; `light` forces the maintenance variant.
+#[must_use]
+pub fn strength_session_for_phase(phase: &TrainingPhase, light: bool) -> StrengthSession {
+    if light {
+        return MAINTENANCE_SESSION;
+    }
+    match phase {
+        TrainingPhase::Transition | TrainingPhase::EarlyBase => StrengthSession {
+            name: "General Strength",
+            description: "Squat, hinge, lunge, push, pull and core, 3x10-12 at RPE 6-7",
+            duration_minutes: 45,
+            heavy_lower_body: false,
+        },
+        TrainingPhase::LateBase | TrainingPhase::Build => StrengthSession {
+            name: "Max Strength (Lower Body)",
+            description: "Back squat, deadlift and step-ups, 4x4-6 at RPE 8, full rest",
+            duration_minutes: 50,
+            heavy_lower_body: true,
+        },
+        TrainingPhase::Specific => StrengthSession {
+            name: "Power + Plyometrics",
+            description: "Jumps, bounds and light explosive lifts, 3x5 at RPE 6",
+            duration_minutes: 35,
+            heavy_lower_body: false,
+        },
+        TrainingPhase::Taper | TrainingPhase::Race | TrainingPhase::Recovery => MAINTENANCE_SESSION,
+    }
+}
+
+/// Strength session as a planning template (warm-up, main lifts, mobility).
+#[must_use]
+pub fn strength_workout_template(phase: &TrainingPhase, duration_minutes: u32) -> WorkoutTemplate {
+    let session = strength_session_for_phase(phase, false);
+    let warm_up = (duration_minutes as f32 * 0.15).round() as u32;
+    let mobility = (duration_minutes as f32 * 0.15).round() as u32;
+    WorkoutTemplate {
+        name: session.name.to_string(),
+        duration_minutes,
+        zones: vec![
+            ZoneSegment {
+                zone: 1,
+                duration_minutes: warm_up,
+                description: Some("Dynamic warm-up".into()),
+            },
+            ZoneSegment {
+                zone: if session.heavy_lower_body { 4 } else { 3 },
+                duration_minutes: duration_minutes.saturating_sub(warm_up + mobility),
+                description: Some(session.description.into()),
+            },
+            ZoneSegment {
+                zone: 1,
+                duration_minutes: mobility,
+                description: Some("Mobility and stretching".into()),
+            },
+        ],
+        sport: "WeightTraining".into(),
+    }
+}
+
+/// Pick up to `count` strength days, never on a key endurance day.
+/// Days that are neither key days nor the day before one come first (returned
+/// with `light = false`); the day before a key day is used only when needed and
+/// is flagged `light` so it gets the maintenance session. Strength days are kept
+/// apart where possible.
+#[must_use]
+pub fn place_strength_days(key_days: &[Weekday], count: u8) -> Vec<(Weekday, bool)> {
+    let week = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+    let free = week
+        .iter()
+        .copied()
+        .filter(|day| !key_days.contains(day))
+        .collect::<Vec<_>>();
+    let (open, before_key): (Vec<_>, Vec<_>) = free
+        .into_iter()
+        .partition(|day| !key_days.contains(&day.succ()));
+
+    let mut chosen: Vec<(Weekday, bool)> = Vec::new();
+    let adjacent = |chosen: &[(Weekday, bool)], day: Weekday| {
+        chosen
+            .iter()
+            .any(|(other, _)| other.succ() == day || other.pred() == day)
+    };
+    for (candidates, light) in [(&open, false), (&before_key, true)] {
+        for relax_spacing in [false, true] {
+            for day in candidates {
+                if chosen.len() >= usize::from(count) {
+                    break;
+                }
+                if chosen.iter().any(|(other, _)| other == day)
+                    || (!relax_spacing && adjacent(&chosen, *day))
+                {
+                    continue;
+                }
+                chosen.push((*day, light));
+            }
+        }
+    }
+    chosen.sort_by_key(|(day, _)| day.num_days_from_monday());
+    chosen
+}
+
+fn activity_type(detail: &Value) -> Option<&str> {
+    detail.get("type").and_then(Value::as_str)
+}
+
+#[must_use]
+pub fn is_strength_activity(detail: &Value) -> bool {
+    activity_type(detail).is_some_and(|sport| STRENGTH_TYPES.contains(&sport))
+}
+
+#[must_use]
+pub fn is_mobility_activity(detail: &Value) -> bool {
+    activity_type(detail).is_some_and(|sport| MOBILITY_TYPES.contains(&sport))
+}
+
+fn duration_minutes(detail: &Value) -> f64 {
+    ["moving_time", "elapsed_time"]
+        .iter()
+        .find_map(|key| detail.get(*key).and_then(Value::as_f64))
+        .filter(|secs| *secs > 0.0)
+        .map_or(0.0, |secs| secs / 60.0)
+}
+
+/// Load for a strength session: session RPE × minutes scaled by the athlete's
+/// calibrated factor, with a moderate RPE assumed when none was logged.
+/// HR-based load under-reads lifting, so recorded load is not used here or in
+/// [`effective_session_load`](crate::engines::session_rpe::effective_session_load).
+#[must_use]
+pub fn strength_session_load(detail: &Value, srpe_to_tss: f64) -> f64 {
+    let rpe = activity_rpe(detail).unwrap_or(DEFAULT_STRENGTH_RPE);
+    rpe * duration_minutes(detail) * srpe_to_tss
+}
+
+/// Heavy lower-body strength: lower-body keywords in the name or description
+/// and RPE at or above `HEAVY_STRENGTH_RPE` (assumed heavy when RPE is missing).
+#[must_use]
+pub fn is_heavy_lower_body(detail: &Value) -> bool {
+    if !is_strength_activity(detail) {
+        return false;
+    }
+    let text = ["name", "description"]
+        .iter()
+        .filter_map(|key| detail.get(*key).and_then(Value::as_str))
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    LOWER_BODY_KEYWORDS
+        .iter()
+        .any(|keyword| text.contains(keyword))
+        && activity_rpe(detail).is_none_or(|rpe| rpe >= HEAVY_STRENGTH_RPE)
+}
+
+fn activity_date(activity: &ActivitySummary) -> Option<NaiveDate> {
+    activity
+        .start_date_local
+        .get(..10)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+}
+
+/// Summarise strength and mobility work between `start` and `end` (inclusive).
+/// Returns `None` when the window holds no strength or mobility sessions.
+#[must_use]
+pub fn summarize_strength(
+    activities: &[&ActivitySummary],
+    details: &HashMap<String, Value>,
+    start: NaiveDate,
+    end: NaiveDate,
+    calibration: &SrpeCalibration,
+) -> Option<StrengthSummary> {
+    let mut strength_sessions = 0usize;
+    let mut mobility_sessions = 0usize;
+    let mut total_minutes = 0.0;
+    let mut estimated_load = 0.0;
+    let mut heavy_lower_body_sessions = 0usize;
+    let mut weeks_with_strength = std::collections::HashSet::new();
+
+    for activity in activities {
+        let (Some(detail), Some(date)) = (details.get(&activity.id), activity_date(activity))
+        else {
+            continue;
+        };
+        if date < start || date > end {
+            continue;
+        }
+        if is_strength_activity(detail) {
+            strength_sessions += 1;
+            total_minutes += duration_minutes(detail);
+            estimated_load += strength_session_load(detail, calibration.factor);
+            if is_heavy_lower_body(detail) {
+                heavy_lower_body_sessions += 1;
+            }
+            weeks_with_strength.insert((date - start).num_days() / 7);
+        } else if is_mobility_activity(detail) {
+            mobility_sessions += 1;
+            total_minutes += duration_minutes(detail);
+        }
+    }
+
+    if strength_sessions + mobility_sessions == 0 {
+        return None;
+    }
+    let days = (end - start).num_days() + 1;
+    let weeks = (days as f64 / 7.0).max(1.0);
+    let calendar_weeks = ((days + 6) / 7).max(1);
+    Some(StrengthSummary {
+        strength_sessions,
+        mobility_sessions,
+        sessions_per_week: strength_sessions as f64 / weeks,
+        consistency_pct: weeks_with_strength.len() as f64 / calendar_weeks as f64 * 100.0,
+        total_minutes,
+        estimated_load,
+        heavy_lower_body_sessions,
+    })
+}
+
+/// Most recent heavy lower-body session within `HEAVY_LOWER_BODY_WINDOW_HOURS`
+/// of `as_of` (today or the previous day at daily resolution).
+#[must_use]
+pub fn recent_heavy_lower_body(
+    activities: &[ActivitySummary],
+    details: &HashMap<String, Value>,
+    as_of: NaiveDate,
+) -> Option<HeavyStrengthSession> {
+    let earliest = as_of - Duration::hours(HEAVY_LOWER_BODY_WINDOW_HOURS) + Duration::days(1);
+    activities
+        .iter()
+        .filter_map(|activity| {
+            let date = activity_date(activity)?;
+            let detail = details.get(&activity.id)?;
+            (date >= earliest && date <= as_of && is_heavy_lower_body(detail)).then(|| {
+                HeavyStrengthSession {
+                    activity_id: activity.id.clone(),
+                    name: activity
+                        .name
+                        .clone()
+                        .or_else(|| {
+                            detail
+                                .get("name")
+                                .and_then(Value::as_str)
+                                .map(str::to_string)
+                        })
+                        .unwrap_or_else(|| "Strength session".to_string()),
+                    date,
+                }
+            })
+        })
+        .max_by_key(|session| session.date)
+}
+
+/// Weekday helper for plan generation: the first `weekday` on or after `date`.
+#[must_use]
+pub fn next_weekday(date: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let offset = (7 + weekday.num_days_from_monday() as i64
+        - date.weekday().num_days_from_monday() as i64)
+        % 7;
+    date + Duration::days(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn summary(id: &str, date: &str) -> ActivitySummary {
+        ActivitySummary {
+            id: id.into(),
+            name: None,
+            start_date_local: format!("{date}T07:00:00"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sessions_per_week_stay_within_one_to_three() {
+        for phase in [
+            TrainingPhase::Transition,
+            TrainingPhase::EarlyBase,
+            TrainingPhase::LateBase,
+            TrainingPhase::Build,
+            TrainingPhase::Specific,
+            TrainingPhase::Taper,
+            TrainingPhase::Race,
+            TrainingPhase::Recovery,
+        ] {
+            assert!((1..=3).contains(&strength_sessions_per_week(&phase)));
+        }
+        assert!(strength_session_for_phase(&TrainingPhase::Build, false).heavy_lower_body);
+        assert!(!strength_session_for_phase(&TrainingPhase::Build, true).heavy_lower_body);
+    }
+
+    #[test]
+    fn placement_avoids_key_days_and_the_day_before() {
+        let key = [Weekday::Tue, Weekday::Thu, Weekday::Sun];
+        let days = place_strength_days(&key, 2);
+        assert_eq!(days.len(), 2);
+        assert!(days.iter().all(|(day, _)| !key.contains(day)));
+        assert_eq!(days[0], (Weekday::Mon, true));
+        assert_eq!(days[1], (Weekday::Fri, false));
+
+        let days = place_strength_days(&[Weekday::Sun], 3);
+        assert_eq!(
+            days,
+            vec![
+                (Weekday::Mon, false),
+                (Weekday::Wed, false),
+                (Weekday::Fri, false)
+            ]
+        );
+    }
+
+    #[test]
+    fn strength_template_is_weight_training() {
+        let template = strength_workout_template(&TrainingPhase::Build, 60);
+        assert_eq!(template.sport, "WeightTraining");
+        assert_eq!(
+            template
+                .zones
+                .iter()
+                .map(|z| z.duration_minutes)
+                .sum::<u32>(),
+            60
+        );
+    }
+
+    #[test]
+    fn heavy_lower_body_requires_keywords_and_effort() {
+        let heavy = json!({"type": "WeightTraining", "name": "Leg day", "icu_rpe": 8});
+        let easy = json!({"type": "WeightTraining", "name": "Leg day", "icu_rpe": 4});
+        let upper = json!({"type": "WeightTraining", "name": "Upper body push", "icu_rpe": 9});
+        let run = json!({"type": "Run", "name": "Hill squats?", "icu_rpe": 9});
+        assert!(is_heavy_lower_body(&heavy));
+        assert!(!is_heavy_lower_body(&easy));
+        assert!(!is_heavy_lower_body(&upper));
+        assert!(!is_heavy_lower_body(&run));
+        assert!(is_heavy_lower_body(
+            &json!({"type": "WeightTraining", "description": "Back squat 5x5"})
+        ));
+    }
+
+    #[test]
+    fn summary_counts_frequency_consistency_and_load() {
+        let activities = [
+            summary("s1", "2026-03-02"),
+            summary("s2", "2026-03-05"),
+            summary("y1", "2026-03-10"),
+            summary("r1", "2026-03-11"),
+        ];
+        let details = HashMap::from([
+            (
+                "s1".to_string(),
+                json!({"type": "WeightTraining", "moving_time": 2700, "icu_rpe": 6}),
+            ),
+            (
+                "s2".to_string(),
+                json!({"type": "WeightTraining", "moving_time": 1800}),
+            ),
+            (
+                "y1".to_string(),
+                json!({"type": "Yoga", "moving_time": 1800}),
+            ),
+            (
+                "r1".to_string(),
+                json!({"type": "Run", "moving_time": 3600}),
+            ),
+        ]);
+        let refs = activities.iter().collect::<Vec<_>>();
+        let start = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
+        let summary =
+            summarize_strength(&refs, &details, start, end, &SrpeCalibration::default()).unwrap();
+        assert_eq!(summary.strength_sessions, 2);
+        assert_eq!(summary.mobility_sessions, 1);
+        assert!((summary.sessions_per_week - 1.0).abs() < 1e-9);
+        assert!((summary.consistency_pct - 50.0).abs() < 1e-9);
+        // 6 × 45 × 0.2 + 5 × 30 × 0.2
+        assert!((summary.estimated_load - 84.0).abs() < 1e-9);
+        assert!((summary.total_minutes - 105.0).abs() < 1e-9);
+
+        let runs_only = [&activities[3]];
+        assert!(
+            summarize_strength(
+                &runs_only,
+                &details,
+                start,
+                end,
+                &SrpeCalibration::default()
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn recent_heavy_session_looks_back_48_hours() {
+        let activities = vec![
+            summary("old", "2026-03-01"),
+            summary("recent", "2026-03-04"),
+        ];
+        let heavy = json!({"type": "WeightTraining", "name": "Squat session", "icu_rpe": 8});
+        let details = HashMap::from([
+            ("old".to_string(), heavy.clone()),
+            ("recent".to_string(), heavy),
+        ]);
+        let as_of = NaiveDate::from_ymd_opt(2026, 3, 5).unwrap();
+        let found = recent_heavy_lower_body(&activities, &details, as_of).unwrap();
+        assert_eq!(found.activity_id, "recent");
+        assert_eq!(found.name, "Squat session");
+
+        let later = NaiveDate::from_ymd_opt(2026, 3, 6).unwrap();
+        assert!(recent_heavy_lower_body(&activities, &details, later).is_none());
+    }
+
+    #[test]
+    fn next_weekday_rolls_forward() {
+        let monday = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        assert_eq!(next_weekday(monday, Weekday::Mon), monday);
+        assert_eq!(
+            next_weekday(monday, Weekday::Fri),
+            NaiveDate::from_ymd_opt(2026, 3, 6).unwrap()
+        );
+    }
+}
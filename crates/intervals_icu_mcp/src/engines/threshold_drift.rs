@@ -0,0 +1,371 @@
+//! Stale-threshold detection.
+//! Compares configured FTP / threshold pace in sport settings with recent
+//! modelled values (eFTP, hard run efforts), flags sustained drift beyond a
+//! tolerance, and quantifies the effect on zones and training load.
+
+use std::collections::BTreeMap;
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::engines::coach_metrics::value_number;
+
+// =============================================================================
+// Threshold Drift Constants
+// Sources: Allen & Coggan, Training and Racing with a Power Meter (TSS ∝ IF²),
+//          Riegel, American Scientist 1981 (endurance fatigue exponent 1.06)
+// =============================================================================
+
+/// Relative drift tolerated before a threshold counts as stale (%).
+pub const DEFAULT_DRIFT_TOLERANCE_PCT: f64 = 5.0;
+
+/// Consecutive recent weeks beyond tolerance before flagging.
+pub const DEFAULT_DRIFT_MIN_WEEKS: usize = 3;
+
+/// Lookback for modelled values (weeks).
+pub const DEFAULT_DRIFT_LOOKBACK_WEEKS: i64 = 8;
+
+/// Minimum `icu_intensity` (%) for a run to count as a threshold-pace effort.
+const HARD_RUN_MIN_INTENSITY_PCT: f64 = 88.0;
+
+/// Duration window for hard run efforts (s).
+const HARD_RUN_MIN_SECS: f64 = 15.0 * 60.0;
+const HARD_RUN_MAX_SECS: f64 = 90.0 * 60.0;
+
+/// Riegel speed-duration exponent: speed ∝ t^-(1.06 - 1).
+const RIEGEL_SPEED_EXPONENT: f64 = 0.06;
+
+/// Threshold-pace reference duration (s).
+const THRESHOLD_REFERENCE_SECS: f64 = 60.0 * 60.0;
+
+const RIDE_TYPES: &[&str] = &["Ride", "VirtualRide", "GravelRide", "MountainBikeRide"];
+const RUN_TYPES: &[&str] = &["Run", "TrailRun", "VirtualRun"];
+
+/// Threshold whose drift is being tracked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThresholdMetric {
+    /// FTP in watts.
+    Ftp,
+    /// Threshold pace, carried as speed (m/s) so "higher = fitter" for both metrics.
+    ThresholdPace,
+}
+
+impl ThresholdMetric {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Ftp => "FTP",
+            Self::ThresholdPace => "Threshold pace",
+        }
+    }
+}
+
+/// One week of modelled values compared with the configured threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeeklyDrift {
+    pub week_start: NaiveDate,
+    pub modelled: f64,
+    pub drift_pct: f64,
+}
+
+/// Configured vs modelled zone bound.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZoneShift {
+    pub zone: String,
+    pub current_upper: f64,
+    pub proposed_upper: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThresholdDrift {
+    pub metric: ThresholdMetric,
+    pub configured: f64,
+    /// Most recent weekly modelled value.
+    pub modelled: f64,
+    pub drift_pct: f64,
+    pub weeks_beyond_tolerance: usize,
+    pub stale: bool,
+    pub weekly: Vec<WeeklyDrift>,
+    /// Change in computed load for the same session once the new value is
+    /// applied (%); negative = loads were inflated by a stale low threshold.
+    pub load_impact_pct: f64,
+    pub zone_shifts: Vec<ZoneShift>,
+}
+
+fn entry_date(entry: &Value, keys: &[&str]) -> Option<NaiveDate> {
+    keys.iter().find_map(|key| {
+        entry
+            .get(*key)
+            .and_then(Value::as_str)
+            .and_then(|value| value.get(..10))
+            .and_then(|value| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())
+    })
+}
+
+/// Daily eFTP from wellness `sportInfo[]` (cycling entries) or a normalised
+/// top-level `eftp`.
+#[must_use]
+pub fn extract_eftp_history(wellness: &Value) -> Vec<(NaiveDate, f64)> {
+    let Some(entries) = wellness.as_array() else {
+        return Vec::new();
+    };
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let date = entry_date(entry, &["id", "date"])?;
+            let from_sport_info =
+                entry
+                    .get("sportInfo")
+                    .and_then(Value::as_array)
+                    .and_then(|infos| {
+                        infos.iter().find_map(|info| {
+                            let sport = info.get("type").and_then(Value::as_str)?;
+                            RIDE_TYPES
+                                .contains(&sport)
+                                .then(|| value_number(info, &["eftp"]))
+                                .flatten()
+                        })
+                    });
+            let eftp = from_sport_info.or_else(|| value_number(entry, &["eftp"]))?;
+            (eftp > 0.0).then_some((date, eftp))
+        })
+        .collect()
+}
+
+/// Modelled FTP from a ride's activity detail (power-model or rolling FTP).
+#[must_use]
+pub fn activity_modelled_ftp(detail: &Value) -> Option<f64> {
+    let sport = detail.get("type").and_then(Value::as_str)?;
+    if !RIDE_TYPES.contains(&sport) {
+        return None;
+    }
+    value_number(detail, &["icu_pm_ftp", "icu_rolling_ftp"]).filter(|ftp| *ftp > 0.0)
+}
+
+/// Threshold speed (m/s) implied by a hard run, normalised to a 60-min effort.
+#[must_use]
+pub fn activity_threshold_speed(detail: &Value) -> Option<f64> {
+    let sport = detail.get("type").and_then(Value::as_str)?;
+    if !RUN_TYPES.contains(&sport) {
+        return None;
+    }
+    let intensity = value_number(detail, &["icu_intensity"])?;
+    let secs = value_number(detail, &["moving_time"])?;
+    let distance = value_number(detail, &["distance"])?;
+    if intensity < HARD_RUN_MIN_INTENSITY_PCT
+        || !(HARD_RUN_MIN_SECS..=HARD_RUN_MAX_SECS).contains(&secs)
+        || distance <= 0.0
+    {
+        return None;
+    }
+    let speed = distance / secs;
+    Some(speed * (secs / THRESHOLD_REFERENCE_SECS).powf(RIEGEL_SPEED_EXPONENT))
+}
+
+/// Convert sport-settings threshold pace (minutes per km) to m/s.
+#[must_use]
+pub fn pace_min_per_km_to_speed(pace: f64) -> Option<f64> {
+    (pace > 0.0).then(|| 1000.0 / (pace * 60.0))
+}
+
+/// Convert m/s to minutes per km.
+#[must_use]
+pub fn speed_to_pace_min_per_km(speed: f64) -> Option<f64> {
+    (speed > 0.0).then(|| 1000.0 / speed / 60.0)
+}
+
+/// Compare configured and modelled thresholds week by week. Each trailing
+/// 7-day bin keeps its best modelled value; the threshold is stale when the
+/// most recent `min_weeks` bins all drift the same way beyond `tolerance_pct`.
+#[must_use]
+pub fn detect_threshold_drift(
+    metric: ThresholdMetric,
+    configured: f64,
+    samples: &[(NaiveDate, f64)],
+    as_of: NaiveDate,
+    lookback_weeks: i64,
+    tolerance_pct: f64,
+    min_weeks: usize,
+) -> Option<ThresholdDrift> {
+    if configured <= 0.0 {
+        return None;
+    }
+    let mut bins: BTreeMap<i64, f64> = BTreeMap::new();
+    for &(date, value) in samples {
+        let age = (as_of - date).num_days();
+        if age < 0 || age >= lookback_weeks * 7 || value <= 0.0 {
+            continue;
+        }
+        let bin = bins.entry(age / 7).or_insert(value);
+        *bin = bin.max(value);
+    }
+    if bins.is_empty() {
+        return None;
+    }
+
+    // Oldest first for display.
+    let weekly = bins
+        .iter()
+        .rev()
+        .map(|(&week, &modelled)| WeeklyDrift {
+            week_start: as_of - Duration::days(week * 7 + 6),
+            modelled,
+            drift_pct: (modelled - configured) / configured * 100.0,
+        })
+        .collect::<Vec<_>>();
+
+    let latest = weekly.last()?;
+    let direction = latest.drift_pct.signum();
+    let weeks_beyond_tolerance = weekly
+        .iter()
+        .rev()
+        .take_while(|week| {
+            week.drift_pct.abs() > tolerance_pct && week.drift_pct.signum() == direction
+        })
+        .count();
+    let modelled = latest.modelled;
+    let ratio = modelled / configured;
+
+    Some(ThresholdDrift {
+        metric,
+        configured,
+        modelled,
+        drift_pct: latest.drift_pct,
+        weeks_beyond_tolerance,
+        stale: weeks_beyond_tolerance >= min_weeks,
+        weekly,
+        // Load scales with IF² and IF = output / threshold.
+        load_impact_pct: (1.0 / (ratio * ratio) - 1.0) * 100.0,
+        zone_shifts: Vec::new(),
+    })
+}
+
+/// Zone upper bounds before and after the threshold change, from zone
+/// percentages of threshold (Intervals.icu `power_zones` / pace zones).
+#[must_use]
+pub fn compute_zone_shifts(zone_pcts: &[f64], configured: f64, proposed: f64) -> Vec<ZoneShift> {
+    zone_pcts
+        .iter()
+        .enumerate()
+        .map(|(index, pct)| ZoneShift {
+            zone: format!("Z{}", index + 1),
+            current_upper: configured * pct / 100.0,
+            proposed_upper: proposed * pct / 100.0,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn as_of() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, 30).unwrap()
+    }
+
+    fn weekly_samples(values: &[f64]) -> Vec<(NaiveDate, f64)> {
+        // values[0] is the most recent week.
+        values
+            .iter()
+            .enumerate()
+            .map(|(week, value)| (as_of() - Duration::days(week as i64 * 7 + 1), *value))
+            .collect()
+    }
+
+    #[test]
+    fn extract_eftp_history_reads_cycling_sport_info_and_top_level() {
+        let wellness = json!([
+            {"id": "2026-06-01", "sportInfo": [{"type": "Run", "eftp": 300.0}, {"type": "Ride", "eftp": 262.0}]},
+            {"id": "2026-06-02", "eftp": 265.0},
+            {"id": "2026-06-03", "sportInfo": [{"type": "Run", "eftp": 300.0}]},
+            {"date": "2026-06-04"}
+        ]);
+        let history = extract_eftp_history(&wellness);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].1, 262.0);
+        assert_eq!(history[1].1, 265.0);
+    }
+
+    #[test]
+    fn activity_threshold_speed_normalises_hard_runs_only() {
+        let tempo =
+            json!({"type": "Run", "icu_intensity": 92.0, "moving_time": 2400, "distance": 9000.0});
+        let speed = activity_threshold_speed(&tempo).unwrap();
+        // 40 min at 3.75 m/s → slightly slower over 60 min.
+        assert!(speed < 3.75 && speed > 3.6);
+
+        let easy =
+            json!({"type": "Run", "icu_intensity": 70.0, "moving_time": 2400, "distance": 7000.0});
+        assert!(activity_threshold_speed(&easy).is_none());
+        let ride = json!({"type": "Ride", "icu_intensity": 95.0, "moving_time": 2400, "distance": 30000.0});
+        assert!(activity_threshold_speed(&ride).is_none());
+        assert_eq!(
+            activity_modelled_ftp(&json!({"type": "Ride", "icu_pm_ftp": 270})),
+            Some(270.0)
+        );
+    }
+
+    #[test]
+    fn pace_conversions_round_trip() {
+        let speed = pace_min_per_km_to_speed(4.0).unwrap();
+        assert!((speed - 4.1667).abs() < 1e-3);
+        assert!((speed_to_pace_min_per_km(speed).unwrap() - 4.0).abs() < 1e-9);
+        assert!(pace_min_per_km_to_speed(0.0).is_none());
+    }
+
+    #[test]
+    fn sustained_drift_flags_stale_ftp_and_load_inflation() {
+        let samples = weekly_samples(&[272.0, 270.0, 268.0, 252.0]);
+        let drift = detect_threshold_drift(
+            ThresholdMetric::Ftp,
+            250.0,
+            &samples,
+            as_of(),
+            DEFAULT_DRIFT_LOOKBACK_WEEKS,
+            DEFAULT_DRIFT_TOLERANCE_PCT,
+            DEFAULT_DRIFT_MIN_WEEKS,
+        )
+        .unwrap();
+        assert!(drift.stale);
+        assert_eq!(drift.weeks_beyond_tolerance, 3);
+        assert_eq!(drift.modelled, 272.0);
+        assert!((drift.drift_pct - 8.8).abs() < 1e-9);
+        // Moving a 272 W rider off a 250 W FTP drops computed load by ~15.5%.
+        assert!((drift.load_impact_pct + 15.5).abs() < 0.1);
+        assert_eq!(drift.weekly.len(), 4);
+        assert!(drift.weekly[0].week_start < drift.weekly[3].week_start);
+    }
+
+    #[test]
+    fn short_or_mixed_drift_is_not_stale() {
+        let samples = weekly_samples(&[272.0, 251.0, 270.0]);
+        let drift = detect_threshold_drift(
+            ThresholdMetric::Ftp,
+            250.0,
+            &samples,
+            as_of(),
+            DEFAULT_DRIFT_LOOKBACK_WEEKS,
+            DEFAULT_DRIFT_TOLERANCE_PCT,
+            DEFAULT_DRIFT_MIN_WEEKS,
+        )
+        .unwrap();
+        assert!(!drift.stale);
+        assert_eq!(drift.weeks_beyond_tolerance, 1);
+
+        assert!(
+            detect_threshold_drift(ThresholdMetric::Ftp, 250.0, &[], as_of(), 8, 5.0, 3).is_none()
+        );
+    }
+
+    #[test]
+    fn zone_shifts_scale_with_threshold() {
+        let shifts = compute_zone_shifts(&[55.0, 75.0, 90.0], 250.0, 270.0);
+        assert_eq!(shifts.len(), 3);
+        assert_eq!(shifts[1].zone, "Z2");
+        assert!((shifts[1].current_upper - 187.5).abs() < 1e-9);
+        assert!((shifts[1].proposed_upper - 202.5).abs() < 1e-9);
+    }
+}
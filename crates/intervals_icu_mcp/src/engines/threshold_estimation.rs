@@ -0,0 +1,654 @@
+//! Aerobic (AeT) and lactate (LT) threshold heart-rate estimation from the
+//! athlete's own steady-state activities.
+//! AeT comes from the decoupling boundary across repeated Z2 runs; when RR
+//! streams exist, DFA α1 crossings (0.75 / 0.5) give AeT and LT directly.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::engines::coach_metrics::{compute_aerobic_decoupling, extract_numeric_stream};
+use crate::engines::coach_metrics_constants::{
+    DECOUPLING_ACCEPTABLE_PCT, HR_STREAM_KEYS, OUTPUT_STREAM_KEYS, RR_STREAM_KEYS,
+};
+
+// =============================================================================
+// Threshold Estimation Constants
+// Sources: Maffetone / Uphill Athlete HR drift test (< 5% = at or below AeT),
+//          Rogers et al. Front. Physiol. 2021 (DFA α1 0.75 ≈ VT1),
+//          Rogers et al. Front. Physiol. 2022 (DFA α1 0.5 ≈ VT2),
+//          Gronwald et al. Front. Physiol. 2020 (DFA α1 methodology)
+// =============================================================================
+
+/// Minimum 1 Hz samples for a run to count as a steady-state sample (~20 min).
+const MIN_STEADY_POINTS: usize = 1200;
+
+/// Share of the run dropped as warm-up before steadiness / drift analysis.
+const WARMUP_FRACTION: f64 = 0.1;
+
+/// Maximum coefficient of variation of output for a steady run.
+const MAX_STEADY_OUTPUT_CV: f64 = 0.15;
+
+/// DFA α1 at the aerobic threshold (Rogers 2021).
+pub const DFA_ALPHA1_AET: f64 = 0.75;
+
+/// DFA α1 at the anaerobic / lactate threshold (Rogers 2022).
+pub const DFA_ALPHA1_LT: f64 = 0.5;
+
+/// Short-term DFA box sizes (beats).
+const DFA_MIN_BOX: usize = 4;
+const DFA_MAX_BOX: usize = 16;
+
+/// Beats per DFA window and step between windows.
+const DFA_WINDOW_BEATS: usize = 200;
+const DFA_WINDOW_STEP: usize = 50;
+
+/// Physiological RR bounds (ms) and maximum beat-to-beat change for artifact filtering.
+const RR_MIN_MS: f64 = 300.0;
+const RR_MAX_MS: f64 = 2000.0;
+const RR_MAX_SUCCESSIVE_CHANGE: f64 = 0.2;
+
+/// In-range beats whose median is the reference for the change check, and the
+/// rejections in a row after which the reference restarts from the current beat.
+const RR_REFERENCE_BEATS: usize = 5;
+const RR_MAX_CONSECUTIVE_REJECTS: usize = 3;
+
+/// Minimum DFA windows before a regression is trusted.
+const MIN_DFA_WINDOWS: usize = 6;
+
+/// How far beyond the observed HR range a DFA crossing may be extrapolated (bpm).
+const DFA_EXTRAPOLATION_BPM: f64 = 5.0;
+
+/// Steady runs for full decoupling confidence.
+const FULL_CONFIDENCE_RUNS: usize = 6;
+
+/// DFA windows for full DFA confidence.
+const FULL_CONFIDENCE_WINDOWS: usize = 30;
+
+/// Method agreement within this spread (bpm) raises confidence; beyond the
+/// disagreement spread it lowers it.
+const METHOD_AGREEMENT_BPM: f64 = 5.0;
+const METHOD_DISAGREEMENT_BPM: f64 = 10.0;
+
+/// One steady-state run summarised for the decoupling boundary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SteadyRunSample {
+    pub activity_id: String,
+    pub avg_hr: f64,
+    /// Signed Pa:HR drift (%); positive = HR rose relative to output.
+    pub drift_pct: f64,
+}
+
+/// DFA α1 over one RR window, paired with the window's mean HR.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DfaWindow {
+    pub mean_hr: f64,
+    pub alpha1: f64,
+}
+
+/// AeT bracket from coupled (< 5% drift) and decoupled runs.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DecouplingBoundary {
+    pub estimate: f64,
+    /// Highest average HR that stayed coupled.
+    pub coupled_max_hr: Option<f64>,
+    /// Lowest average HR that decoupled.
+    pub decoupled_min_hr: Option<f64>,
+}
+
+/// DFA α1 vs HR regression and its threshold crossings.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DfaThresholds {
+    pub aet_hr: Option<f64>,
+    pub lt_hr: Option<f64>,
+    pub r_squared: f64,
+    pub windows: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ThresholdEstimate {
+    pub supported: bool,
+    pub aet_hr: Option<f64>,
+    pub aet_method: Option<String>,
+    pub lt_hr: Option<f64>,
+    pub lt_method: Option<String>,
+    /// 0.0 – 1.0
+    pub confidence: f64,
+    pub steady_runs: usize,
+    pub dfa_windows: usize,
+    pub decoupling: Option<DecouplingBoundary>,
+    pub dfa: Option<DfaThresholds>,
+    pub notes: Vec<String>,
+}
+
+impl ThresholdEstimate {
+    #[must_use]
+    pub fn unsupported() -> Self {
+        Self {
+            supported: false,
+            notes: vec![
+                "No steady Z2 runs or RR data found; thresholds cannot be estimated.".into(),
+            ],
+            ..Default::default()
+        }
+    }
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Summarise a run for the decoupling boundary. Returns `None` when the run
+/// is too short, not steady, or above `z2_ceiling_hr`.
+#[must_use]
+pub fn steady_run_sample(
+    activity_id: &str,
+    streams: &Value,
+    z2_ceiling_hr: Option<f64>,
+) -> Option<SteadyRunSample> {
+    let hr = extract_numeric_stream(streams, HR_STREAM_KEYS)?;
+    let output = extract_numeric_stream(streams, OUTPUT_STREAM_KEYS)?;
+    if hr.len() != output.len() || hr.len() < MIN_STEADY_POINTS {
+        return None;
+    }
+    let skip = (hr.len() as f64 * WARMUP_FRACTION) as usize;
+    let hr = &hr[skip..];
+    let output = &output[skip..];
+
+    let output_mean = mean(output)?;
+    if output_mean <= 0.0 {
+        return None;
+    }
+    let output_sd = (output
+        .iter()
+        .map(|value| (value - output_mean).powi(2))
+        .sum::<f64>()
+        / output.len() as f64)
+        .sqrt();
+    if output_sd / output_mean > MAX_STEADY_OUTPUT_CV {
+        return None;
+    }
+
+    let avg_hr = mean(hr)?;
+    if z2_ceiling_hr.is_some_and(|ceiling| avg_hr > ceiling) {
+        return None;
+    }
+    let decoupling = compute_aerobic_decoupling(hr, output)?;
+    Some(SteadyRunSample {
+        activity_id: activity_id.to_string(),
+        avg_hr,
+        drift_pct: decoupling.signed_decoupling_pct,
+    })
+}
+
+/// AeT from the boundary between coupled and decoupled steady runs.
+#[must_use]
+pub fn aet_from_decoupling(samples: &[SteadyRunSample]) -> Option<DecouplingBoundary> {
+    let coupled_max_hr = samples
+        .iter()
+        .filter(|sample| sample.drift_pct < DECOUPLING_ACCEPTABLE_PCT)
+        .map(|sample| sample.avg_hr)
+        .reduce(f64::max);
+    let decoupled_min_hr = samples
+        .iter()
+        .filter(|sample| sample.drift_pct >= DECOUPLING_ACCEPTABLE_PCT)
+        .map(|sample| sample.avg_hr)
+        .reduce(f64::min);
+
+    let estimate = match (coupled_max_hr, decoupled_min_hr) {
+        (Some(low), Some(high)) => (low + high) / 2.0,
+        // One-sided brackets: AeT is at least / at most this HR.
+        (Some(low), None) => low,
+        (None, Some(high)) => high,
+        (None, None) => return None,
+    };
+    Some(DecouplingBoundary {
+        estimate,
+        coupled_max_hr,
+        decoupled_min_hr,
+    })
+}
+
+/// Drop RR artifacts: out-of-range beats and beats more than 20% away from the
+/// median of the preceding in-range beats. Rejected beats still feed the median,
+/// and a run of rejections restarts it, so a single outlier (even the first
+/// beat) cannot lock out the rest of the stream.
+#[must_use]
+pub fn clean_rr(rr_ms: &[f64]) -> Vec<f64> {
+    let mut cleaned: Vec<f64> = Vec::with_capacity(rr_ms.len());
+    let mut recent: VecDeque<f64> = VecDeque::with_capacity(RR_REFERENCE_BEATS);
+    let mut rejected_in_row = 0;
+    for &rr in rr_ms {
+        if !(RR_MIN_MS..=RR_MAX_MS).contains(&rr) {
+            continue;
+        }
+        let reference = median(recent.iter().copied().collect());
+        if reference.is_some_and(|reference| {
+            ((rr - reference) / reference).abs() > RR_MAX_SUCCESSIVE_CHANGE
+        }) {
+            rejected_in_row += 1;
+            if rejected_in_row >= RR_MAX_CONSECUTIVE_REJECTS {
+                recent.clear();
+                rejected_in_row = 0;
+            }
+        } else {
+            rejected_in_row = 0;
+            cleaned.push(rr);
+        }
+        if recent.len() == RR_REFERENCE_BEATS {
+            recent.pop_front();
+        }
+        recent.push_back(rr);
+    }
+    cleaned
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    })
+}
+
+/// Least-squares slope, intercept and R² of `y` on `x`.
+fn linear_fit(x: &[f64], y: &[f64]) -> Option<(f64, f64, f64)> {
+    let n = x.len() as f64;
+    let mean_x = mean(x)?;
+    let mean_y = mean(y)?;
+    let sxx = x.iter().map(|v| (v - mean_x).powi(2)).sum::<f64>();
+    let syy = y.iter().map(|v| (v - mean_y).powi(2)).sum::<f64>();
+    if sxx <= f64::EPSILON || n < 2.0 {
+        return None;
+    }
+    let sxy = x
+        .iter()
+        .zip(y)
+        .map(|(a, b)| (a - mean_x) * (b - mean_y))
+        .sum::<f64>();
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    let r_squared = if syy <= f64::EPSILON {
+        0.0
+    } else {
+        (sxy * sxy) / (sxx * syy)
+    };
+    Some((slope, intercept, r_squared))
+}
+
+/// Short-term detrended fluctuation analysis exponent (α1, box sizes 4–16 beats).
+#[must_use]
+pub fn compute_dfa_alpha1(rr_ms: &[f64]) -> Option<f64> {
+    if rr_ms.len() < DFA_MAX_BOX * 4 {
+        return None;
+    }
+    let rr_mean = mean(rr_ms)?;
+    let mut profile = Vec::with_capacity(rr_ms.len());
+    let mut cumulative = 0.0;
+    for rr in rr_ms {
+        cumulative += rr - rr_mean;
+        profile.push(cumulative);
+    }
+
+    let mut log_n = Vec::new();
+    let mut log_f = Vec::new();
+    for box_size in DFA_MIN_BOX..=DFA_MAX_BOX {
+        let boxes = profile.len() / box_size;
+        let xs = (0..box_size).map(|i| i as f64).collect::<Vec<_>>();
+        let mut squared = 0.0;
+        for chunk in profile.chunks_exact(box_size).take(boxes) {
+            let (slope, intercept, _) = linear_fit(&xs, chunk)?;
+            squared += chunk
+                .iter()
+                .enumerate()
+                .map(|(i, y)| (y - (intercept + slope * i as f64)).powi(2))
+                .sum::<f64>();
+        }
+        let fluctuation = (squared / (boxes * box_size) as f64).sqrt();
+        if fluctuation <= 0.0 {
+            return None;
+        }
+        log_n.push((box_size as f64).ln());
+        log_f.push(fluctuation.ln());
+    }
+
+    linear_fit(&log_n, &log_f).map(|(slope, _, _)| slope)
+}
+
+/// Slide DFA windows across an RR stream and pair α1 with mean HR.
+#[must_use]
+pub fn dfa_windows_from_streams(streams: &Value) -> Vec<DfaWindow> {
+    let Some(rr) = extract_numeric_stream(streams, RR_STREAM_KEYS) else {
+        return Vec::new();
+    };
+    let rr = clean_rr(&rr);
+    if rr.len() < DFA_WINDOW_BEATS {
+        return Vec::new();
+    }
+    (0..=rr.len() - DFA_WINDOW_BEATS)
+        .step_by(DFA_WINDOW_STEP)
+        .filter_map(|start| {
+            let window = &rr[start..start + DFA_WINDOW_BEATS];
+            let alpha1 = compute_dfa_alpha1(window)?;
+            let mean_hr = 60_000.0 / mean(window)?;
+            Some(DfaWindow { mean_hr, alpha1 })
+        })
+        .collect()
+}
+
+/// Regress α1 on HR and solve for the 0.75 (AeT) and 0.5 (LT) crossings.
+/// Crossings far outside the observed HR range are not reported.
+#[must_use]
+pub fn thresholds_from_dfa(windows: &[DfaWindow]) -> Option<DfaThresholds> {
+    if windows.len() < MIN_DFA_WINDOWS {
+        return None;
+    }
+    let hr = windows.iter().map(|w| w.mean_hr).collect::<Vec<_>>();
+    let alpha = windows.iter().map(|w| w.alpha1).collect::<Vec<_>>();
+    let (slope, intercept, r_squared) = linear_fit(&hr, &alpha)?;
+    // α1 must fall as intensity rises for the crossings to mean anything.
+    if slope >= 0.0 {
+        return None;
+    }
+    let min_hr = hr.iter().copied().fold(f64::INFINITY, f64::min) - DFA_EXTRAPOLATION_BPM;
+    let max_hr = hr.iter().copied().fold(f64::NEG_INFINITY, f64::max) + DFA_EXTRAPOLATION_BPM;
+    let crossing = |target: f64| {
+        let value = (target - intercept) / slope;
+        (min_hr..=max_hr).contains(&value).then_some(value)
+    };
+    Some(DfaThresholds {
+        aet_hr: crossing(DFA_ALPHA1_AET),
+        lt_hr: crossing(DFA_ALPHA1_LT),
+        r_squared,
+        windows: windows.len(),
+    })
+}
+
+/// Combine the decoupling boundary and DFA α1 crossings into one estimate.
+#[must_use]
+pub fn estimate_thresholds(
+    samples: &[SteadyRunSample],
+    windows: &[DfaWindow],
+) -> ThresholdEstimate {
+    let decoupling = aet_from_decoupling(samples);
+    let dfa = thresholds_from_dfa(windows);
+    if decoupling.is_none() && dfa.is_none_or(|d| d.aet_hr.is_none() && d.lt_hr.is_none()) {
+        return ThresholdEstimate {
+            steady_runs: samples.len(),
+            dfa_windows: windows.len(),
+            ..ThresholdEstimate::unsupported()
+        };
+    }
+
+    let mut notes = Vec::new();
+    let decoupling_confidence = decoupling.map(|boundary| {
+        let coverage = (samples.len() as f64 / FULL_CONFIDENCE_RUNS as f64).min(1.0);
+        let bracketed = boundary.coupled_max_hr.is_some() && boundary.decoupled_min_hr.is_some();
+        if !bracketed {
+            notes.push(
+                "Decoupling boundary is one-sided; add Z2 runs slightly above / below the estimate to bracket AeT."
+                    .into(),
+            );
+        } else if boundary.coupled_max_hr > boundary.decoupled_min_hr {
+            notes.push(
+                "Coupled and decoupled runs overlap in HR; heat, fuelling or fatigue may be confounding drift."
+                    .into(),
+            );
+        }
+        coverage * if bracketed { 0.6 } else { 0.35 }
+    });
+    let dfa_confidence = dfa
+        .map(|d| (d.windows as f64 / FULL_CONFIDENCE_WINDOWS as f64).min(1.0) * d.r_squared * 0.8);
+    if dfa.is_none() {
+        notes.push("No usable RR data; LT HR needs DFA α1 from a chest-strap recording.".into());
+    }
+
+    let dfa_aet = dfa.and_then(|d| d.aet_hr);
+    let (aet_hr, aet_method, agreement) = match (decoupling, dfa_aet) {
+        (Some(boundary), Some(dfa_hr)) => (
+            Some((boundary.estimate + dfa_hr) / 2.0),
+            Some("decoupling+dfa_alpha1".to_string()),
+            Some((boundary.estimate - dfa_hr).abs()),
+        ),
+        (Some(boundary), None) => (Some(boundary.estimate), Some("decoupling".into()), None),
+        (None, Some(dfa_hr)) => (Some(dfa_hr), Some("dfa_alpha1".into()), None),
+        (None, None) => (None, None, None),
+    };
+    let lt_hr = dfa.and_then(|d| d.lt_hr);
+
+    let mut confidence = decoupling_confidence
+        .unwrap_or(0.0)
+        .max(dfa_confidence.unwrap_or(0.0));
+    match agreement {
+        Some(spread) if spread <= METHOD_AGREEMENT_BPM => confidence += 0.2,
+        Some(spread) if spread > METHOD_DISAGREEMENT_BPM => {
+            confidence -= 0.2;
+            notes.push(format!(
+                "Decoupling and DFA α1 disagree on AeT by {spread:.0} bpm; retest before applying."
+            ));
+        }
+        _ => {}
+    }
+
+    ThresholdEstimate {
+        supported: true,
+        aet_hr,
+        aet_method,
+        lt_hr,
+        lt_method: lt_hr.map(|_| "dfa_alpha1".into()),
+        confidence: confidence.clamp(0.0, 1.0),
+        steady_runs: samples.len(),
+        dfa_windows: windows.len(),
+        decoupling,
+        dfa,
+        notes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Deterministic uniform noise in [-0.5, 0.5).
+    fn noise(count: usize, seed: u64) -> Vec<f64> {
+        let mut state = seed;
+        (0..count)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1_442_695_040_888_963_407);
+                ((state >> 33) as f64 / (1u64 << 31) as f64) - 0.5
+            })
+            .collect()
+    }
+
+    fn sample(hr: f64, drift: f64) -> SteadyRunSample {
+        SteadyRunSample {
+            activity_id: format!("run-{hr}"),
+            avg_hr: hr,
+            drift_pct: drift,
+        }
+    }
+
+    fn dfa_line(points: &[(f64, f64)]) -> Vec<DfaWindow> {
+        points
+            .iter()
+            .map(|&(mean_hr, alpha1)| DfaWindow { mean_hr, alpha1 })
+            .collect()
+    }
+
+    #[test]
+    fn steady_run_sample_measures_drift_and_skips_unsteady_or_hot_runs() {
+        let hr = (0..1800)
+            .map(|i| if i < 900 { 140.0 } else { 150.0 })
+            .collect::<Vec<_>>();
+        let speed = vec![3.0; 1800];
+        let streams = json!({"heartrate": hr, "velocity_smooth": speed});
+        let run = steady_run_sample("a1", &streams, None).unwrap();
+        assert!(run.drift_pct > DECOUPLING_ACCEPTABLE_PCT);
+        assert!(run.avg_hr > 140.0 && run.avg_hr < 150.0);
+
+        assert!(steady_run_sample("a1", &streams, Some(135.0)).is_none());
+
+        let intervals = (0..1800)
+            .map(|i| if (i / 60) % 2 == 0 { 2.0 } else { 4.5 })
+            .collect::<Vec<_>>();
+        let unsteady = json!({"heartrate": vec![140.0; 1800], "velocity_smooth": intervals});
+        assert!(steady_run_sample("a2", &unsteady, None).is_none());
+
+        let short = json!({"heartrate": vec![140.0; 600], "velocity_smooth": vec![3.0; 600]});
+        assert!(steady_run_sample("a3", &short, None).is_none());
+    }
+
+    #[test]
+    fn decoupling_boundary_brackets_aet() {
+        let boundary = aet_from_decoupling(&[
+            sample(138.0, 1.5),
+            sample(142.0, 3.0),
+            sample(148.0, 6.5),
+            sample(152.0, 9.0),
+        ])
+        .unwrap();
+        assert_eq!(boundary.coupled_max_hr, Some(142.0));
+        assert_eq!(boundary.decoupled_min_hr, Some(148.0));
+        assert!((boundary.estimate - 145.0).abs() < 1e-9);
+
+        let one_sided = aet_from_decoupling(&[sample(138.0, 1.0)]).unwrap();
+        assert!((one_sided.estimate - 138.0).abs() < 1e-9);
+        assert!(aet_from_decoupling(&[]).is_none());
+    }
+
+    #[test]
+    fn clean_rr_drops_out_of_range_beats_and_jumps() {
+        let clean = clean_rr(&[800.0, 250.0, 810.0, 1200.0, 790.0, 2500.0, 805.0]);
+        assert_eq!(clean, vec![800.0, 810.0, 790.0, 805.0]);
+    }
+
+    #[test]
+    fn clean_rr_recovers_from_an_early_outlier() {
+        // An ectopic first beat must not become the reference for the stream.
+        let clean = clean_rr(&[450.0, 800.0, 805.0, 795.0, 810.0, 800.0, 790.0]);
+        assert!(clean.ends_with(&[795.0, 810.0, 800.0, 790.0]));
+
+        // A sustained step change restarts the reference instead of rejecting
+        // every later beat.
+        let clean = clean_rr(&[800.0, 800.0, 800.0, 600.0, 600.0, 600.0, 600.0, 600.0]);
+        assert!(clean.ends_with(&[600.0, 600.0]));
+    }
+
+    #[test]
+    fn dfa_alpha1_separates_white_noise_from_correlated_rr() {
+        let white = noise(400, 7)
+            .into_iter()
+            .map(|n| 800.0 + n * 60.0)
+            .collect::<Vec<_>>();
+        let alpha_white = compute_dfa_alpha1(&white).unwrap();
+        assert!(
+            (0.3..0.7).contains(&alpha_white),
+            "white noise α1 {alpha_white}"
+        );
+
+        let mut level = 800.0;
+        let brown = noise(400, 11)
+            .into_iter()
+            .map(|n| {
+                level += n * 10.0;
+                level
+            })
+            .collect::<Vec<_>>();
+        let alpha_brown = compute_dfa_alpha1(&brown).unwrap();
+        assert!(alpha_brown > 1.2, "random walk α1 {alpha_brown}");
+
+        assert!(compute_dfa_alpha1(&white[..20]).is_none());
+    }
+
+    #[test]
+    fn dfa_windows_filter_artifacts_and_report_mean_hr() {
+        let mut rr = noise(600, 3)
+            .into_iter()
+            .map(|n| 500.0 + n * 30.0)
+            .collect::<Vec<_>>();
+        rr[100] = 2500.0;
+        rr[200] = 250.0;
+        let windows = dfa_windows_from_streams(&json!({"hrv": rr}));
+        assert!(!windows.is_empty());
+        assert!(windows.iter().all(|w| (w.mean_hr - 120.0).abs() < 2.0));
+        assert!(dfa_windows_from_streams(&json!({"heartrate": [140.0]})).is_empty());
+    }
+
+    #[test]
+    fn dfa_regression_solves_aet_and_lt_crossings() {
+        // α1 falls 0.015 per bpm: 0.75 at ~146.7 bpm, 0.5 at ~163.3 bpm.
+        let windows = dfa_line(&[
+            (120.0, 1.15),
+            (130.0, 1.0),
+            (140.0, 0.85),
+            (150.0, 0.7),
+            (160.0, 0.55),
+            (170.0, 0.4),
+        ]);
+        let dfa = thresholds_from_dfa(&windows).unwrap();
+        assert!((dfa.aet_hr.unwrap() - 146.666).abs() < 0.01);
+        assert!((dfa.lt_hr.unwrap() - 163.333).abs() < 0.01);
+        assert!(dfa.r_squared > 0.99);
+
+        let rising = dfa_line(&[
+            (120.0, 0.5),
+            (130.0, 0.6),
+            (140.0, 0.7),
+            (150.0, 0.8),
+            (160.0, 0.9),
+            (170.0, 1.0),
+        ]);
+        assert!(thresholds_from_dfa(&rising).is_none());
+        assert!(thresholds_from_dfa(&windows[..3]).is_none());
+    }
+
+    #[test]
+    fn estimate_combines_methods_and_rewards_agreement() {
+        let samples = [
+            sample(138.0, 1.5),
+            sample(142.0, 3.0),
+            sample(148.0, 6.5),
+            sample(152.0, 9.0),
+            sample(140.0, 2.0),
+            sample(150.0, 7.0),
+        ];
+        let windows = dfa_line(&[
+            (120.0, 1.15),
+            (130.0, 1.0),
+            (140.0, 0.85),
+            (150.0, 0.7),
+            (160.0, 0.55),
+            (170.0, 0.4),
+        ]);
+        let decoupling_only = estimate_thresholds(&samples, &[]);
+        assert_eq!(decoupling_only.aet_method.as_deref(), Some("decoupling"));
+        assert!(decoupling_only.lt_hr.is_none());
+
+        let combined = estimate_thresholds(&samples, &windows);
+        assert!(combined.supported);
+        assert_eq!(
+            combined.aet_method.as_deref(),
+            Some("decoupling+dfa_alpha1")
+        );
+        assert!((combined.aet_hr.unwrap() - 145.833).abs() < 0.01);
+        assert_eq!(combined.lt_method.as_deref(), Some("dfa_alpha1"));
+        assert!(combined.confidence > decoupling_only.confidence);
+    }
+
+    #[test]
+    fn estimate_unsupported_without_inputs() {
+        let estimate = estimate_thresholds(&[], &[]);
+        assert!(!estimate.supported);
+        assert!(estimate.aet_hr.is_none());
+        assert!(!estimate.notes.is_empty());
+    }
+}
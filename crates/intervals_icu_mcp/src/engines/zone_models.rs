@@ -0,0 +1,191 @@
+//! Training-zone models.
+//! Zone upper bounds as percentages of threshold (FTP, LTHR, threshold speed)
+//! for the models `manage_profile` can apply, and helpers that turn them into
+//! Intervals.icu sport-settings arrays.
+
+// =============================================================================
+// Zone Model Constants
+// Sources: Allen & Coggan, Training and Racing with a Power Meter (power / %LTHR),
+//          Seiler & Kjerland, Scand J Med Sci Sports 2006 (3-zone model),
+//          Friel, The Triathlete's Training Bible (run %LTHR and pace zones)
+// =============================================================================
+
+/// Open-ended top power / pace zone marker used by Intervals.icu.
+pub const OPEN_ZONE_PCT: f64 = 999.0;
+
+/// Coggan power zones (% FTP): active recovery … neuromuscular.
+const COGGAN_POWER_PCTS: &[f64] = &[55.0, 75.0, 90.0, 105.0, 120.0, 150.0, OPEN_ZONE_PCT];
+/// Coggan HR zones (% LTHR); the top zone runs to max HR.
+const COGGAN_HR_PCTS: &[f64] = &[68.0, 83.0, 94.0, 105.0];
+
+/// Seiler 3-zone model: below VT1, VT1–VT2, above VT2.
+const SEILER_POWER_PCTS: &[f64] = &[80.0, 100.0, OPEN_ZONE_PCT];
+const SEILER_HR_PCTS: &[f64] = &[87.0, 100.0];
+/// Seiler pace zones (% threshold speed).
+const SEILER_PACE_PCTS: &[f64] = &[88.0, 100.0, OPEN_ZONE_PCT];
+
+/// Friel power zones (% FTP).
+const FRIEL_POWER_PCTS: &[f64] = &[55.0, 75.0, 90.0, 105.0, 120.0, OPEN_ZONE_PCT];
+/// Friel run HR zones Z1–Z5b (% LTHR); Z5c runs to max HR.
+const FRIEL_HR_PCTS: &[f64] = &[84.0, 89.0, 94.0, 99.0, 102.0, 106.0];
+/// Friel run pace zones as % threshold speed (129/114/106/101/97/90 % of threshold pace time).
+const FRIEL_PACE_PCTS: &[f64] = &[77.5, 87.7, 94.3, 99.0, 103.1, 111.1, OPEN_ZONE_PCT];
+
+/// Five-zone %LTHR model (HR only).
+const LTHR_PCT_HR_PCTS: &[f64] = &[81.0, 89.0, 93.0, 99.0];
+
+/// Zone model applied by `manage_profile update_thresholds`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneModel {
+    Coggan,
+    Seiler3,
+    Friel,
+    LthrPct,
+}
+
+impl ZoneModel {
+    pub const NAMES: [&'static str; 4] = ["coggan", "seiler_3", "friel", "lthr_pct"];
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "coggan" => Some(Self::Coggan),
+            "seiler_3" => Some(Self::Seiler3),
+            "friel" => Some(Self::Friel),
+            "lthr_pct" => Some(Self::LthrPct),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Coggan => "Coggan",
+            Self::Seiler3 => "Seiler 3-zone",
+            Self::Friel => "Friel",
+            Self::LthrPct => "%LTHR",
+        }
+    }
+
+    /// Power zone upper bounds (% FTP), if the model defines power zones.
+    #[must_use]
+    pub fn power_pcts(self) -> Option<&'static [f64]> {
+        match self {
+            Self::Coggan => Some(COGGAN_POWER_PCTS),
+            Self::Seiler3 => Some(SEILER_POWER_PCTS),
+            Self::Friel => Some(FRIEL_POWER_PCTS),
+            Self::LthrPct => None,
+        }
+    }
+
+    /// HR zone upper bounds (% LTHR), excluding the top zone that ends at max HR.
+    #[must_use]
+    pub fn hr_pcts(self) -> &'static [f64] {
+        match self {
+            Self::Coggan => COGGAN_HR_PCTS,
+            Self::Seiler3 => SEILER_HR_PCTS,
+            Self::Friel => FRIEL_HR_PCTS,
+            Self::LthrPct => LTHR_PCT_HR_PCTS,
+        }
+    }
+
+    /// Pace zone upper bounds (% threshold speed), if the model defines pace zones.
+    #[must_use]
+    pub fn pace_pcts(self) -> Option<&'static [f64]> {
+        match self {
+            Self::Seiler3 => Some(SEILER_PACE_PCTS),
+            Self::Friel => Some(FRIEL_PACE_PCTS),
+            Self::Coggan | Self::LthrPct => None,
+        }
+    }
+}
+
+/// HR zone upper bounds in bpm from % LTHR, closed with max HR.
+/// Returns `None` when max HR does not sit above the highest zone bound.
+#[must_use]
+pub fn hr_zone_bounds(pcts: &[f64], lthr: f64, max_hr: f64) -> Option<Vec<f64>> {
+    let mut bounds = pcts
+        .iter()
+        .map(|pct| (lthr * pct / 100.0).round())
+        .collect::<Vec<_>>();
+    if bounds.last().is_some_and(|last| max_hr <= *last) {
+        return None;
+    }
+    bounds.push(max_hr.round());
+    Some(bounds)
+}
+
+/// Zone boundaries must be positive and strictly increasing.
+pub fn validate_zone_bounds(bounds: &[f64]) -> Result<(), String> {
+    if bounds.is_empty() {
+        return Err("zone list is empty".into());
+    }
+    if bounds
+        .iter()
+        .any(|bound| !bound.is_finite() || *bound <= 0.0)
+    {
+        return Err("zone bounds must be positive".into());
+    }
+    if bounds.windows(2).any(|pair| pair[1] <= pair[0]) {
+        return Err("zone bounds must be strictly increasing".into());
+    }
+    Ok(())
+}
+
+/// Absolute zone upper bounds from % of threshold; the open-ended top zone maps to `None`.
+#[must_use]
+pub fn absolute_zone_bounds(pcts: &[f64], threshold: f64) -> Vec<Option<f64>> {
+    pcts.iter()
+        .map(|pct| (*pct < OPEN_ZONE_PCT).then(|| threshold * pct / 100.0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_round_trips_model_names() {
+        for name in ZoneModel::NAMES {
+            assert!(ZoneModel::parse(name).is_some(), "{name}");
+        }
+        assert!(ZoneModel::parse("zone2").is_none());
+        assert_eq!(ZoneModel::Seiler3.label(), "Seiler 3-zone");
+    }
+
+    #[test]
+    fn model_tables_are_ascending() {
+        for name in ZoneModel::NAMES {
+            let model = ZoneModel::parse(name).unwrap();
+            assert!(validate_zone_bounds(model.hr_pcts()).is_ok());
+            if let Some(pcts) = model.power_pcts() {
+                assert!(validate_zone_bounds(pcts).is_ok());
+            }
+            if let Some(pcts) = model.pace_pcts() {
+                assert!(validate_zone_bounds(pcts).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn hr_zone_bounds_close_with_max_hr() {
+        let bounds = hr_zone_bounds(ZoneModel::Coggan.hr_pcts(), 170.0, 192.0).unwrap();
+        assert_eq!(bounds, vec![116.0, 141.0, 160.0, 179.0, 192.0]);
+        assert!(hr_zone_bounds(ZoneModel::Friel.hr_pcts(), 170.0, 175.0).is_none());
+    }
+
+    #[test]
+    fn validate_zone_bounds_rejects_unordered_and_empty() {
+        assert!(validate_zone_bounds(&[55.0, 75.0, 90.0]).is_ok());
+        assert!(validate_zone_bounds(&[55.0, 55.0]).is_err());
+        assert!(validate_zone_bounds(&[75.0, 55.0]).is_err());
+        assert!(validate_zone_bounds(&[0.0, 55.0]).is_err());
+        assert!(validate_zone_bounds(&[]).is_err());
+    }
+
+    #[test]
+    fn absolute_zone_bounds_leave_open_top_zone() {
+        let bounds = absolute_zone_bounds(&[55.0, 105.0, OPEN_ZONE_PCT], 260.0);
+        assert_eq!(bounds, vec![Some(143.0), Some(273.0), None]);
+    }
+}
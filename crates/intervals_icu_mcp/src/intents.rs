@@ -2,6 +2,7 @@
 pub mod error;
 pub mod handlers;
 pub mod idempotency;
+pub mod roster_cache;
 pub mod router;
 pub mod types;
 pub mod utils;
@@ -9,6 +10,7 @@ pub mod validator;
 
 pub use error::ErrorGuidance;
 pub use idempotency::IdempotencyMiddleware;
+pub use roster_cache::RosterCache;
 pub use router::IntentRouter;
 pub use types::{
     ContentBlock, IdempotencyCache, IntentError, IntentHandler, IntentOutput, OutputMetadata,
@@ -1,20 +1,30 @@
 mod analyze_race;
 mod analyze_training;
+mod annotate_activity;
 mod assess_recovery;
 mod compare_periods;
+mod import_activity;
+mod log_wellness;
 mod manage_gear;
 mod manage_profile;
 mod modify_training;
 mod plan_training;
 pub mod render;
+mod review_roster;
+mod review_season;
 mod track_progress;
 
 pub use analyze_race::AnalyzeRaceHandler;
 pub use analyze_training::AnalyzeTrainingHandler;
+pub use annotate_activity::AnnotateActivityHandler;
 pub use assess_recovery::AssessRecoveryHandler;
 pub use compare_periods::ComparePeriodsHandler;
+pub use import_activity::ImportActivityHandler;
+pub use log_wellness::LogWellnessHandler;
 pub use manage_gear::ManageGearHandler;
 pub use manage_profile::ManageProfileHandler;
 pub use modify_training::ModifyTrainingHandler;
 pub use plan_training::PlanTrainingHandler;
+pub use review_roster::ReviewRosterHandler;
+pub use review_season::ReviewSeasonHandler;
 pub use track_progress::TrackProgressHandler;
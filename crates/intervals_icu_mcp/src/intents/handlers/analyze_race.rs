@@ -15,7 +15,12 @@ use crate::engines::coach_guidance::{build_alerts, build_guidance};
 use crate::engines::coach_metrics::{
     extract_ctl_series, parse_fitness_metrics, parse_wellness_metrics,
 };
+use crate::engines::race_pacing::{
+    DEFAULT_BODY_WEIGHT_KG, PacingAnchors, PacingSport, build_pacing_plan, parse_gpx,
+    parse_route_profile,
+};
 use crate::engines::race_readiness::{compute_ctl_drop, compute_race_readiness};
+use crate::intents::handlers::render::pacing::render_pacing_plan;
 use crate::intents::utils::{data_availability_block, filter_activities_by_description};
 
 pub struct AnalyzeRaceHandler;
@@ -64,6 +69,135 @@ impl AnalyzeRaceHandler {
         .iter()
         .any(|token| name.contains(token))
     }
+
+    /// The sport setting whose types cover the pacing sport. Other sports'
+    /// thresholds are never used as a fallback.
+    fn pacing_setting(
+        settings: Option<&intervals_icu_client::domains::workout::SportSettings>,
+        sport: PacingSport,
+    ) -> Option<&intervals_icu_client::domains::workout::SportSetting> {
+        let wanted: &[&str] = match sport {
+            PacingSport::Run => &["Run", "TrailRun", "VirtualRun"],
+            PacingSport::Ride => &["Ride", "VirtualRide", "GravelRide", "MountainBikeRide"],
+        };
+        settings?.sports.iter().find(|s| {
+            s.types
+                .as_ref()
+                .map(|types| types.iter().any(|t| wanted.contains(&t.as_str())))
+                .unwrap_or(false)
+        })
+    }
+
+    /// Threshold anchors from the matching sport setting and input overrides.
+    fn pacing_anchors(
+        settings: Option<&intervals_icu_client::domains::workout::SportSettings>,
+        sport: PacingSport,
+        input: &Value,
+    ) -> PacingAnchors {
+        let setting = Self::pacing_setting(settings, sport);
+        // Threshold pace is stored as minutes per km when pace_units is MINS_KM.
+        let threshold_speed_mps = setting.and_then(|s| match s.pace_units.as_deref() {
+            Some("MINS_KM") => s
+                .threshold_pace
+                .filter(|pace| *pace > 0.0)
+                .map(|pace| 1000.0 / (pace * 60.0)),
+            _ => None,
+        });
+        PacingAnchors {
+            critical_speed_mps: input.get("critical_speed_mps").and_then(Value::as_f64),
+            threshold_speed_mps,
+            critical_power_watts: input.get("critical_power_watts").and_then(Value::as_f64),
+            ftp_watts: setting.and_then(|s| s.ftp),
+            lthr: setting.and_then(|s| s.lthr.or(s.threshold_lt_hr)),
+            body_weight_kg: input
+                .get("body_weight_kg")
+                .and_then(Value::as_f64)
+                .or_else(|| settings.and_then(|s| s.weight)),
+        }
+    }
+
+    async fn execute_pacing_plan(
+        &self,
+        input: &Value,
+        client: Arc<dyn IntervalsClient>,
+    ) -> Result<IntentOutput, IntentError> {
+        let sport = match input.get("sport").and_then(Value::as_str) {
+            Some("ride") => PacingSport::Ride,
+            _ => PacingSport::Run,
+        };
+        let points = if let Some(gpx) = input.get("gpx").and_then(Value::as_str) {
+            parse_gpx(gpx)
+        } else if let Some(route_id) = input.get("route_id").and_then(Value::as_i64) {
+            let route = client
+                .get_route(route_id, true)
+                .await
+                .map_err(|e| IntentError::api(format!("Failed to fetch route: {}", e)))?;
+            parse_route_profile(&route)
+        } else {
+            return Err(IntentError::validation(
+                "pacing_plan requires route_id or gpx".to_string(),
+            ));
+        };
+        if points.len() < 2 {
+            return Err(IntentError::validation(
+                "Course has no usable elevation profile (need at least 2 points with elevation)"
+                    .to_string(),
+            ));
+        }
+
+        let sport_settings = client.get_sport_settings().await.ok();
+        let anchors = Self::pacing_anchors(sport_settings.as_ref(), sport, input);
+        let aid_stations_km: Vec<f64> = input
+            .get("aid_stations_km")
+            .and_then(Value::as_array)
+            .map(|arr| arr.iter().filter_map(Value::as_f64).collect())
+            .unwrap_or_default();
+        let temperature_c = input.get("temperature_c").and_then(Value::as_f64);
+
+        let plan = build_pacing_plan(&points, sport, &anchors, &aid_stations_km, temperature_c);
+
+        let mut content = vec![ContentBlock::markdown(
+            "# Race Pacing Plan\nPre-race pacing and fueling by course segment",
+        )];
+        content.extend(render_pacing_plan(&plan));
+
+        let mut suggestions = Vec::new();
+        if sport_settings
+            .as_ref()
+            .is_some_and(|settings| !settings.sports.is_empty())
+            && Self::pacing_setting(sport_settings.as_ref(), sport).is_none()
+        {
+            let label = match sport {
+                PacingSport::Run => "run",
+                PacingSport::Ride => "ride",
+            };
+            suggestions.push(format!(
+                "No {label} sport setting found; thresholds from other sports were not used."
+            ));
+        }
+        if !plan.supported {
+            suggestions.push(
+                "Set threshold pace or FTP in sport settings, or pass critical_speed_mps / critical_power_watts.".into(),
+            );
+        } else {
+            suggestions
+                .push("Pace climbs by effort (HR cap/power), not by the pace target.".into());
+            suggestions.push("Rehearse the fueling schedule in long training sessions.".into());
+            if anchors.body_weight_kg.is_none() {
+                suggestions.push(format!(
+                    "Body weight not found; {:.0} kg assumed for carb loading and bike speed.",
+                    DEFAULT_BODY_WEIGHT_KG
+                ));
+            }
+        }
+
+        Ok(IntentOutput::new(content)
+            .with_suggestions(suggestions)
+            .with_next_actions(vec![
+                "To taper into race day: plan_training with focus: taper".into(),
+                "To check readiness: assess_recovery".into(),
+            ]))
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -71,6 +205,7 @@ enum RaceAnalysisMode {
     Performance,
     Strategy,
     Recovery,
+    PacingPlan,
 }
 
 impl RaceAnalysisMode {
@@ -78,6 +213,7 @@ impl RaceAnalysisMode {
         match value.unwrap_or("performance") {
             "strategy" => Self::Strategy,
             "recovery" => Self::Recovery,
+            "pacing_plan" => Self::PacingPlan,
             _ => Self::Performance,
         }
     }
@@ -94,9 +230,10 @@ impl IntentHandler for AnalyzeRaceHandler {
          and race readiness scoring. Returns race metrics (distance, time, avg HR), \
          efficiency factor, aerobic decoupling, interval/segment detection, \
          post-race load context, and a 5-factor Race Readiness score (score/100 \
-         with tier: ready/monitor/caution/not_ready). Three modes: performance \
+         with tier: ready/monitor/caution/not_ready). Four modes: performance \
          (race execution), strategy (pacing, fueling, terrain), recovery (post-race \
-         recovery outlook).
+         recovery outlook), pacing_plan (pre-race per-segment pacing, fueling and \
+         aid-station plan from a route_id or GPX).
          
          Use this tool when: you need a post-race debrief, evaluate race execution \
          vs plan, check recovery needs, or assess readiness for a next race. \
@@ -111,8 +248,16 @@ impl IntentHandler for AnalyzeRaceHandler {
                 "date": {"type": "string", "description": "Race date (YYYY-MM-DD) or 'last_race'. Preferred canonical field."},
                 "target_date": {"type": "string", "description": "Alias for date. Accepts YYYY-MM-DD or 'today'."},
                 "description_contains": {"type": "string", "description": "Search by description (e.g., '50K', 'marathon')"},
-                "analysis_type": {"type": "string", "enum": ["performance", "strategy", "recovery"], "default": "performance", "description": "Analysis type"},
-                "compare_to_planned": {"type": "boolean", "default": true, "description": "Compare to planned workout"}
+                "analysis_type": {"type": "string", "enum": ["performance", "strategy", "recovery", "pacing_plan"], "default": "performance", "description": "Analysis type"},
+                "compare_to_planned": {"type": "boolean", "default": true, "description": "Compare to planned workout"},
+                "route_id": {"type": "integer", "description": "pacing_plan: intervals.icu route ID (fetched with path)"},
+                "gpx": {"type": "string", "description": "pacing_plan: inline GPX document (alternative to route_id)"},
+                "sport": {"type": "string", "enum": ["run", "ride"], "default": "run", "description": "pacing_plan: sport used for thresholds and speed model"},
+                "aid_stations_km": {"type": "array", "items": {"type": "number"}, "description": "pacing_plan: aid station positions in km"},
+                "temperature_c": {"type": "number", "description": "pacing_plan: expected race temperature (°C) for fluid targets"},
+                "body_weight_kg": {"type": "number", "description": "pacing_plan: body weight override (kg)"},
+                "critical_speed_mps": {"type": "number", "description": "pacing_plan: critical speed (m/s), preferred over threshold pace"},
+                "critical_power_watts": {"type": "number", "description": "pacing_plan: critical power (W), preferred over FTP"}
             }
         })
     }
@@ -135,6 +280,10 @@ impl IntentHandler for AnalyzeRaceHandler {
             .and_then(Value::as_bool)
             .unwrap_or(true);
 
+        if analysis_mode == RaceAnalysisMode::PacingPlan {
+            return self.execute_pacing_plan(&input, client).await;
+        }
+
         // Fetch recent activities to find race
         let activities = client
             .get_recent_activities(Some(50), Some(60))
@@ -1506,4 +1655,138 @@ mod tests {
         let output = result.unwrap();
         assert!(output.suggestions.iter().any(|s| s.contains("nutrition")));
     }
+
+    fn hilly_route() -> serde_json::Value {
+        json!({
+            "id": 42,
+            "name": "Sky Race",
+            "distance": [0.0, 3000.0, 6000.0, 9000.0, 12000.0],
+            "altitude": [500.0, 500.0, 900.0, 500.0, 500.0]
+        })
+    }
+
+    fn run_sport_settings() -> intervals_icu_client::domains::workout::SportSettings {
+        intervals_icu_client::domains::workout::SportSettings::from_value(&json!([{
+            "types": ["Run", "TrailRun"],
+            "threshold_pace": 4.5,
+            "pace_units": "MINS_KM",
+            "lthr": 172
+        }]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_input_schema_includes_pacing_plan() {
+        let schema = AnalyzeRaceHandler::new().input_schema();
+        let props = schema.get("properties").unwrap().as_object().unwrap();
+        assert!(props.contains_key("route_id"));
+        assert!(props.contains_key("gpx"));
+        assert!(props.contains_key("aid_stations_km"));
+        let analysis_enum = props["analysis_type"]["enum"].as_array().unwrap();
+        assert!(analysis_enum.contains(&json!("pacing_plan")));
+    }
+
+    #[tokio::test]
+    async fn test_pacing_plan_from_route() {
+        let client = Arc::new(
+            MockIntervalsClient::builder()
+                .with_route(hilly_route())
+                .with_sport_settings(run_sport_settings()),
+        );
+        let output = AnalyzeRaceHandler::new()
+            .execute(
+                json!({
+                    "analysis_type": "pacing_plan",
+                    "route_id": 42,
+                    "aid_stations_km": [6.0],
+                    "temperature_c": 25.0
+                }),
+                client,
+                None,
+            )
+            .await
+            .unwrap();
+        let content_str = format!("{:?}", output.content);
+        assert!(content_str.contains("Race Pacing Plan"));
+        assert!(content_str.contains("climb"));
+        assert!(content_str.contains("descent"));
+        assert!(content_str.contains("km 6.0"));
+        assert!(content_str.contains("700 ml/h"));
+    }
+
+    #[tokio::test]
+    async fn test_pacing_plan_from_gpx() {
+        let gpx = r#"<gpx><trk><trkseg>
+<trkpt lat="46.00" lon="7.0"><ele>1000</ele></trkpt>
+<trkpt lat="46.02" lon="7.0"><ele>1200</ele></trkpt>
+<trkpt lat="46.04" lon="7.0"><ele>1000</ele></trkpt>
+</trkseg></trk></gpx>"#;
+        let client =
+            Arc::new(MockIntervalsClient::builder().with_sport_settings(run_sport_settings()));
+        let output = AnalyzeRaceHandler::new()
+            .execute(
+                json!({"analysis_type": "pacing_plan", "gpx": gpx}),
+                client,
+                None,
+            )
+            .await
+            .unwrap();
+        let content_str = format!("{:?}", output.content);
+        assert!(content_str.contains("Fueling Plan"));
+    }
+
+    #[tokio::test]
+    async fn test_pacing_plan_requires_course() {
+        let client = Arc::new(MockIntervalsClient::default());
+        let result = AnalyzeRaceHandler::new()
+            .execute(json!({"analysis_type": "pacing_plan"}), client, None)
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ride_pacing_plan_ignores_run_thresholds() {
+        let client = Arc::new(
+            MockIntervalsClient::builder()
+                .with_route(hilly_route())
+                .with_sport_settings(run_sport_settings()),
+        );
+        let output = AnalyzeRaceHandler::new()
+            .execute(
+                json!({"analysis_type": "pacing_plan", "route_id": 42, "sport": "ride"}),
+                client,
+                None,
+            )
+            .await
+            .unwrap();
+        let content_str = format!("{:?}", output.content);
+        assert!(content_str.contains("Pacing plan unavailable"));
+        assert!(
+            output
+                .suggestions
+                .iter()
+                .any(|s| s.contains("No ride sport setting found"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pacing_plan_without_thresholds_is_unsupported() {
+        let client = Arc::new(MockIntervalsClient::builder().with_route(hilly_route()));
+        let output = AnalyzeRaceHandler::new()
+            .execute(
+                json!({"analysis_type": "pacing_plan", "route_id": 42}),
+                client,
+                None,
+            )
+            .await
+            .unwrap();
+        let content_str = format!("{:?}", output.content);
+        assert!(content_str.contains("Pacing plan unavailable"));
+        assert!(
+            output
+                .suggestions
+                .iter()
+                .any(|s| s.contains("critical_speed_mps"))
+        );
+    }
 }
@@ -10,6 +10,7 @@ use serde_json::{Value, json};
 use std::sync::Arc;
 
 use super::render::analysis::*;
+use super::render::records::render_new_records;
 use crate::domains::coach::{AnalysisKind, AnalysisWindow, CoachContext};
 use crate::engines::adaptation::classify_curve_profile;
 use crate::engines::analysis::{
@@ -18,7 +19,8 @@ use crate::engines::analysis::{
 use crate::engines::analysis_audit::build_data_audit;
 use crate::engines::analysis_fetch::{
     PeriodFetchRequest, SingleWorkoutFetchRequest, build_daily_load_series, build_previous_window,
-    fetch_period_data, fetch_single_workout_data,
+    fetch_heat_acclimation, fetch_heat_streams, fetch_period_data, fetch_record_book,
+    fetch_single_workout_data, fetch_srpe_calibration,
 };
 use crate::engines::coach_guidance::{build_alerts, build_guidance};
 use crate::engines::coach_metrics::{
@@ -29,6 +31,14 @@ use crate::engines::coach_metrics::{
     parse_api_load_snapshot, parse_fitness_metrics, parse_polarisation_from_api,
 };
 use crate::engines::cp_regression::{fit_cp, validate_cp};
+use crate::engines::fit_metrics::fit_file_metrics;
+use crate::engines::heat_acclimation::{
+    HEAT_EXPOSURE_THRESHOLD_C, activity_temperature, compute_heat_acclimation_from_details,
+    heat_adjusted_decoupling, heat_adjusted_efficiency_factor,
+};
+use crate::engines::personal_records::{curve_sport, detect_new_records};
+use crate::engines::session_rpe::effective_session_load;
+use crate::engines::strength::summarize_strength;
 use crate::engines::trail_execution::compute_terrain_context;
 
 use crate::domains::activity_analysis::{back_to_back_load, vert_per_week};
@@ -38,6 +48,7 @@ use crate::intents::utils::{
     filter_events_by_range, parse_date,
 };
 use intervals_icu_client::EventCategory;
+use intervals_icu_client::fit::FitActivity;
 
 pub struct AnalyzeTrainingHandler;
 impl AnalyzeTrainingHandler {
@@ -178,6 +189,11 @@ impl IntentHandler for AnalyzeTrainingHandler {
                     "default": false,
                     "description": "Include power/HR/pace histograms. Only valid when target_type is 'single'."
                 },
+                "include_fit_file": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Download and decode the original FIT file for per-second normalized power, DFA α1 from RR intervals, core temperature drift and developer fields (Stryd, CORE). Only valid when target_type is 'single'."
+                },
                 "metrics": {
                     "type": "array",
                     "items": {"type": "string"},
@@ -424,6 +440,10 @@ impl AnalyzeTrainingHandler {
             .get("include_histograms")
             .and_then(Value::as_bool)
             .unwrap_or(false);
+        let include_fit_file = input
+            .get("include_fit_file")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
 
         let mut fetched = fetch_single_workout_data(
             client,
@@ -678,6 +698,49 @@ impl AnalyzeTrainingHandler {
                     decoupling.decoupling_pct, decoupling.state
                 ));
             }
+            // Heat-adjusted interpretation, scaled by the athlete's acclimation
+            // going into the session.
+            if let Some(temperature) = workout_detail
+                .and_then(activity_temperature)
+                .filter(|t| *t > HEAT_EXPOSURE_THRESHOLD_C)
+            {
+                let acclimation =
+                    fetch_heat_acclimation(client, target_date.pred_opt().unwrap_or(target_date))
+                        .await;
+                let acclimation_index = acclimation.acclimation_index;
+                let acclimation_label = if acclimation.supported {
+                    format!(
+                        "{}, index {:.2}",
+                        acclimation.state,
+                        acclimation_index.unwrap_or(0.0)
+                    )
+                } else {
+                    "no recent temperature data, assumes unacclimated".to_string()
+                };
+                if let Some(efficiency_factor) = workout.efficiency_factor {
+                    lines.push(format!(
+                        "Heat-adjusted EF: {:.2} at {:.0} °C ({})",
+                        heat_adjusted_efficiency_factor(
+                            efficiency_factor,
+                            temperature,
+                            acclimation_index
+                        ),
+                        temperature,
+                        acclimation_label
+                    ));
+                }
+                if let Some(decoupling) = &workout.aerobic_decoupling {
+                    lines.push(format!(
+                        "Heat-adjusted Decoupling: {:.1}% at {:.0} °C (drift beyond expected heat effect)",
+                        heat_adjusted_decoupling(
+                            decoupling.decoupling_pct,
+                            temperature,
+                            acclimation_index
+                        ),
+                        temperature
+                    ));
+                }
+            }
             content.push(ContentBlock::markdown(format!(
                 "Execution Context\n  {}",
                 lines.join("\n  ")
@@ -720,6 +783,10 @@ impl AnalyzeTrainingHandler {
             content.push(ContentBlock::markdown(fit_text));
         }
 
+        if include_fit_file {
+            content.push(fit_file_block(client, &activity_id).await);
+        }
+
         // Add interval analysis
         if analysis_mode.show_interval_section() {
             if let Some(ref intervals) = fetched.intervals
@@ -798,6 +865,19 @@ impl AnalyzeTrainingHandler {
             append_best_efforts_section(&mut content, best);
         }
 
+        // Records this activity holds on the athlete's all-time, season or 90-day curves
+        if let Some(sport) = workout_detail
+            .and_then(|detail| detail.get("type"))
+            .and_then(Value::as_str)
+            .and_then(curve_sport)
+        {
+            let book = fetch_record_book(client, sport, chrono::Local::now().date_naive()).await;
+            let records = detect_new_records(&book.rows, &activity_id);
+            if !records.is_empty() {
+                content.push(render_new_records(&records));
+            }
+        }
+
         if analysis_mode.show_stream_section() {
             append_stream_insights(&mut content, fetched.streams.as_ref());
         }
@@ -1110,6 +1190,20 @@ impl AnalyzeTrainingHandler {
                 .with_next_actions(next_actions));
         }
 
+        let period_ids: Vec<String> = period.iter().map(|a| a.id.clone()).collect();
+        let (heat_streams, failed_heat_streams) =
+            fetch_heat_streams(client, &fetched.activity_details, &period_ids).await;
+        if failed_heat_streams > 0 {
+            fetched.fetch_warnings.push(format!(
+                "{failed_heat_streams} temperature stream request(s) failed; heat exposure for those sessions uses mean temperature"
+            ));
+        }
+
+        // One sRPE calibration, so every load figure below uses the same factor
+        // whatever subset of details it reads.
+        let srpe_calibration =
+            fetch_srpe_calibration(client, fetched.activity_details.values()).await;
+
         let mut period_context = CoachContext::new(AnalysisKind::TrainingPeriod, window.clone());
         period_context.audit = build_data_audit(&fetched);
 
@@ -1164,8 +1258,12 @@ impl AnalyzeTrainingHandler {
                         .unwrap_or(false)
                 })
                 .collect::<Vec<_>>();
-            let daily_loads =
-                build_daily_load_series(&load_activities, &fetched.activity_details, &load_window);
+            let daily_loads = build_daily_load_series(
+                &load_activities,
+                &fetched.activity_details,
+                &load_window,
+                &srpe_calibration,
+            );
             let load_values = daily_loads
                 .iter()
                 .map(|(_, load)| *load)
@@ -1187,14 +1285,23 @@ impl AnalyzeTrainingHandler {
                 .acwr = Some(api_acwr);
         }
 
-        let period_ids: Vec<String> = period.iter().map(|a| a.id.clone()).collect();
-        let ndli = compute_ndli_7d(&fetched.activity_details, &period_ids);
+        let ndli = compute_ndli_7d(&fetched.activity_details, &period_ids, &srpe_calibration);
         period_context.metrics.ndli = Some(ndli);
 
         period_context.metrics.heat = Some(compute_heat_metrics_7d(
             &fetched.activity_details,
             &period_ids,
         ));
+        let dated_ids: Vec<(chrono::NaiveDate, String)> = period
+            .iter()
+            .filter_map(|a| parse_activity_date(&a.start_date_local).map(|d| (d, a.id.clone())))
+            .collect();
+        period_context.metrics.heat_acclimation = Some(compute_heat_acclimation_from_details(
+            &fetched.activity_details,
+            &heat_streams,
+            &dated_ids,
+            end_date.min(chrono::Local::now().date_naive()),
+        ));
 
         // Polarisation / TID from the most recent activity's zone distribution
         if let Some(last_id) = period_ids.last()
@@ -1238,12 +1345,39 @@ impl AnalyzeTrainingHandler {
             start, end
         )));
 
-        let rows = build_period_summary_rows(period.len(), &period_snapshot, weekly_hrs);
+        let mut rows = build_period_summary_rows(period.len(), &period_snapshot, weekly_hrs);
+        let completed = period
+            .iter()
+            .copied()
+            .filter(|activity| !is_planned_workout_id(&activity.id))
+            .collect::<Vec<_>>();
+        rows.extend(build_period_load_row(
+            &completed,
+            &fetched.activity_details,
+            &srpe_calibration,
+        ));
         content.push(ContentBlock::table(
             vec!["Metric".into(), "Value".into()],
             rows,
         ));
 
+        if let (Ok(start_day), Ok(end_day)) = (
+            chrono::NaiveDate::parse_from_str(start, "%Y-%m-%d"),
+            chrono::NaiveDate::parse_from_str(end, "%Y-%m-%d"),
+        ) && let Some(strength) = summarize_strength(
+            &completed,
+            &fetched.activity_details,
+            start_day,
+            end_day,
+            &srpe_calibration,
+        ) {
+            content.push(ContentBlock::markdown("## Strength & Mobility".to_string()));
+            content.push(ContentBlock::table(
+                vec!["Metric".into(), "Value".into()],
+                build_strength_rows(&strength),
+            ));
+        }
+
         let planned_workouts = period
             .iter()
             .filter(|activity| is_planned_workout_id(&activity.id))
@@ -1368,11 +1502,8 @@ impl AnalyzeTrainingHandler {
                         && let Some(detail) = fetched.activity_details.get(&activity.id)
                         && let Some(obj) = detail.as_object()
                     {
-                        if let Some(tss) = obj
-                            .get("icu_training_load")
-                            .or_else(|| obj.get("training_load"))
-                            .or_else(|| obj.get("icuTrainingLoad"))
-                            .and_then(|v| v.as_f64().or_else(|| v.as_i64().map(|n| n as f64)))
+                        if let Some((tss, _)) =
+                            effective_session_load(detail, srpe_calibration.factor)
                         {
                             tss_series.push((date, tss as f32));
                         }
@@ -1432,6 +1563,11 @@ impl AnalyzeTrainingHandler {
             if let Some(heat_text) = render_heat_section(&period_context.metrics.heat) {
                 content.push(ContentBlock::markdown(heat_text));
             }
+            if let Some(acclimation_text) =
+                render_heat_acclimation_section(&period_context.metrics.heat_acclimation)
+            {
+                content.push(ContentBlock::markdown(acclimation_text));
+            }
 
             if let Some(fit_text) = render_fitness_snapshot(&period_context.metrics.fitness) {
                 content.push(ContentBlock::markdown(fit_text));
@@ -1528,8 +1664,12 @@ impl AnalyzeTrainingHandler {
             // Ultra-specific tokens
             if !period.is_empty() {
                 let period_ids: Vec<String> = period.iter().map(|a| a.id.clone()).collect();
-                let daily_loads =
-                    build_daily_load_series(&period, &fetched.activity_details, &window);
+                let daily_loads = build_daily_load_series(
+                    &period,
+                    &fetched.activity_details,
+                    &window,
+                    &srpe_calibration,
+                );
                 let loads: Vec<f64> = daily_loads.iter().map(|(_, l)| *l).collect();
                 if !loads.is_empty() {
                     let b2b = back_to_back_load(&loads);
@@ -1566,8 +1706,12 @@ impl AnalyzeTrainingHandler {
 
         if analysis_type == "streams" {
             let load_activities = period.to_vec();
-            let daily_series =
-                build_daily_load_series(&load_activities, &fetched.activity_details, &window);
+            let daily_series = build_daily_load_series(
+                &load_activities,
+                &fetched.activity_details,
+                &window,
+                &srpe_calibration,
+            );
             let rows = daily_series
                 .iter()
                 .rev()
@@ -1660,6 +1804,30 @@ impl Default for AnalyzeTrainingHandler {
     }
 }
 
+/// "FIT File Metrics" section; download or decode failures become a note.
+async fn fit_file_block(client: &dyn IntervalsClient, activity_id: &str) -> ContentBlock {
+    use base64::Engine as _;
+
+    let unavailable = |reason: String| {
+        ContentBlock::markdown(format!("FIT File Metrics\n  Unavailable: {reason}"))
+    };
+    let encoded = match client.download_fit_file(activity_id, None).await {
+        Ok(Some(encoded)) => encoded,
+        Ok(None) => return unavailable("no FIT file for this activity".into()),
+        Err(e) => return unavailable(format!("download failed: {e}")),
+    };
+    let activity = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| FitActivity::decode(&bytes).map_err(|e| e.to_string()));
+    match activity {
+        Ok(activity) => {
+            ContentBlock::markdown(render_fit_file_section(&fit_file_metrics(&activity)))
+        }
+        Err(e) => unavailable(format!("could not decode FIT file: {e}")),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2370,6 +2538,7 @@ mod tests {
                 category: EventCategory::RaceA,
                 description: Some("Marathon".to_string()),
                 r#type: None,
+                end_date_local: None,
             },
             intervals_icu_client::Event {
                 id: Some("e2".to_string()),
@@ -2378,6 +2547,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
         ];
         let refs = events.iter().collect::<Vec<_>>();
@@ -2830,6 +3000,63 @@ mod tests {
         assert!(content_str.contains("Test Workout"));
     }
 
+    #[tokio::test]
+    async fn test_analyze_single_includes_fit_file_metrics() {
+        let fit = include_bytes!(
+            "../../../../intervals_icu_client/tests/fixtures/fit/run_stryd_core_hrv.fit"
+        );
+        let handler = AnalyzeTrainingHandler::new();
+        let client = Arc::new(
+            MockIntervalsClient::with_activity("12345", "2026-03-01", "Threshold Run")
+                .with_fit_file(fit.to_vec()),
+        );
+
+        let input = json!({
+            "target_type": "single",
+            "date": "2026-03-01",
+            "include_fit_file": true
+        });
+        let output = handler.execute(input, client, None).await.unwrap();
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("FIT File Metrics"));
+        assert!(content_str.contains("Records: 900 over 14:59, every 1 s"));
+        assert!(content_str.contains("DFA α1: mean"));
+        assert!(content_str.contains("Core Temp Drift: +1."));
+        assert!(content_str.contains("form_power, leg_spring_stiffness, core_temperature"));
+        assert!(content_str.contains("Devices: Forerunner 965, Stryd, CORE"));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_single_fit_file_unavailable_is_a_note() {
+        let handler = AnalyzeTrainingHandler::new();
+        let client = Arc::new(
+            MockIntervalsClient::with_activity("12345", "2026-03-01", "Indoor Ride")
+                .with_fit_file(b"not a fit file".to_vec()),
+        );
+        let input = json!({
+            "target_type": "single",
+            "date": "2026-03-01",
+            "include_fit_file": true
+        });
+        let output = handler.execute(input, client, None).await.unwrap();
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("FIT File Metrics\n  Unavailable: could not decode"));
+
+        let without = handler
+            .execute(
+                json!({"target_type": "single", "date": "2026-03-01"}),
+                Arc::new(MockIntervalsClient::with_activity(
+                    "12345",
+                    "2026-03-01",
+                    "Indoor Ride",
+                )),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(!content_text(&without.content).contains("FIT File Metrics"));
+    }
+
     #[tokio::test]
     async fn test_analyze_single_detailed_mode() {
         let handler = AnalyzeTrainingHandler::new();
@@ -2866,6 +3093,127 @@ mod tests {
         assert!(content_str.contains("Execution Context"));
     }
 
+    #[tokio::test]
+    async fn test_analyze_single_hot_session_shows_heat_adjusted_metrics() {
+        let handler = AnalyzeTrainingHandler::new();
+        let client = Arc::new(
+            MockIntervalsClient::with_activity("12345", "2026-03-01", "Hot Run")
+                .with_workout_detail(json!({
+                    "distance": 10000.0,
+                    "moving_time": 3600,
+                    "average_heartrate": 150.0,
+                    "average_temp": 31.0,
+                    "icu_efficiency_factor": 1.5,
+                    "decoupling": 9.0,
+                })),
+        );
+
+        let input = json!({
+            "target_type": "single",
+            "date": "2026-03-01",
+            "analysis_type": "detailed"
+        });
+
+        let output = handler.execute(input, client, None).await.unwrap();
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("Heat-adjusted EF"));
+        assert!(content_str.contains("Heat-adjusted Decoupling: 5.1%"));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_single_hot_session_uses_acclimation_index() {
+        let handler = AnalyzeTrainingHandler::new();
+        let mut activities: Vec<ActivitySummary> = (19..=28)
+            .map(|day| ActivitySummary {
+                id: format!("hot{day}"),
+                name: Some("Heat Block".to_string()),
+                start_date_local: format!("2026-02-{day}"),
+                ..Default::default()
+            })
+            .collect();
+        activities.push(ActivitySummary {
+            id: "12345".to_string(),
+            name: Some("Hot Run".to_string()),
+            start_date_local: "2026-03-01".to_string(),
+            ..Default::default()
+        });
+        let mut client = MockIntervalsClient::builder()
+            .with_activities(activities)
+            .with_workout_detail(json!({
+                "distance": 10000.0,
+                "moving_time": 3600,
+                "average_heartrate": 150.0,
+                "average_temp": 31.0,
+                "icu_efficiency_factor": 1.5,
+                "decoupling": 9.0,
+            }));
+        for day in 19..=28 {
+            client = client.with_activity_detail(
+                &format!("hot{day}"),
+                json!({"moving_time": 5400, "average_temp": 31.0}),
+            );
+        }
+
+        let input = json!({
+            "target_type": "single",
+            "date": "2026-03-01",
+            "analysis_type": "detailed"
+        });
+
+        let output = handler
+            .execute(input, Arc::new(client), None)
+            .await
+            .unwrap();
+        let content_str = content_text(&output.content);
+        // Acclimation halves part of the heat penalty, so less drift is removed.
+        assert!(content_str.contains("(acclimated, index"));
+        assert!(!content_str.contains("Heat-adjusted Decoupling: 5.1%"));
+        assert!(content_str.contains("Heat-adjusted Decoupling: 6.8%"));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_period_renders_heat_acclimation() {
+        let handler = AnalyzeTrainingHandler::new();
+        let client = Arc::new(
+            MockIntervalsClient::builder()
+                .with_activities(vec![
+                    ActivitySummary {
+                        id: "h1".to_string(),
+                        name: Some("Hot Run".to_string()),
+                        start_date_local: "2026-06-02".to_string(),
+                        ..Default::default()
+                    },
+                    ActivitySummary {
+                        id: "h2".to_string(),
+                        name: Some("Hot Run 2".to_string()),
+                        start_date_local: "2026-06-03".to_string(),
+                        ..Default::default()
+                    },
+                ])
+                .with_activity_detail(
+                    "h1",
+                    json!({"moving_time": 3600, "average_temp": 30.0, "icu_efficiency_factor": 1.4}),
+                )
+                .with_activity_detail(
+                    "h2",
+                    json!({"moving_time": 3600, "average_weather_temp": 29.0}),
+                ),
+        );
+
+        let input = json!({
+            "target_type": "period",
+            "period_start": "2026-06-01",
+            "period_end": "2026-06-07",
+            "analysis_type": "detailed"
+        });
+
+        let output = handler.execute(input, client, None).await.unwrap();
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("Heat Acclimation"));
+        assert!(content_str.contains("120 min across 2 sessions"));
+        assert!(content_str.contains("Heat-adjusted EF"));
+    }
+
     #[tokio::test]
     async fn test_analyze_single_intervals_mode() {
         let handler = AnalyzeTrainingHandler::new();
@@ -2997,6 +3345,32 @@ mod tests {
         assert!(content_str.contains("Best Efforts"));
     }
 
+    #[tokio::test]
+    async fn test_analyze_single_flags_new_personal_records() {
+        let handler = AnalyzeTrainingHandler::new();
+        let client = Arc::new(
+            MockIntervalsClient::with_activity("12345", "2026-03-01", "Test Workout")
+                .with_workout_detail(json!({
+                    "type": "Ride",
+                    "moving_time": 3600,
+                    "average_watts": 250.0,
+                }))
+                .with_power_curves(json!({"list": [{
+                    "secs": [5, 300, 1200],
+                    "values": [950, 340, 305],
+                    "activity_id": ["999", "12345", "12345"]
+                }]})),
+        );
+
+        let input = json!({"target_type": "single", "date": "2026-03-01"});
+
+        let output = handler.execute(input, client, None).await.unwrap();
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("New Personal Records"));
+        assert!(content_str.contains("20:00 power: 305 W — all-time best"));
+        assert!(!content_str.contains("950 W"));
+    }
+
     #[tokio::test]
     async fn test_analyze_single_no_activities_found() {
         let handler = AnalyzeTrainingHandler::new();
@@ -3339,6 +3713,7 @@ mod tests {
                     category: EventCategory::RaceA,
                     description: Some("Marathon".to_string()),
                     r#type: None,
+                    end_date_local: None,
                 }]),
         );
 
@@ -3484,6 +3859,46 @@ mod tests {
         assert!(content_str.contains("Period:"));
     }
 
+    #[tokio::test]
+    async fn test_analyze_period_reports_strength_and_mobility() {
+        let handler = AnalyzeTrainingHandler::new();
+        let client = Arc::new(
+            MockIntervalsClient::builder()
+                .with_activities(vec![
+                    ActivitySummary {
+                        id: "lift".to_string(),
+                        name: Some("Leg day".to_string()),
+                        start_date_local: "2026-03-02".to_string(),
+                        ..Default::default()
+                    },
+                    ActivitySummary {
+                        id: "yoga".to_string(),
+                        name: Some("Yoga".to_string()),
+                        start_date_local: "2026-03-04".to_string(),
+                        ..Default::default()
+                    },
+                ])
+                .with_activity_detail(
+                    "lift",
+                    json!({"type": "WeightTraining", "name": "Leg day", "moving_time": 3000, "icu_rpe": 8}),
+                )
+                .with_activity_detail("yoga", json!({"type": "Yoga", "moving_time": 1800})),
+        );
+
+        let input = json!({
+            "target_type": "period",
+            "period_start": "2026-03-01",
+            "period_end": "2026-03-07"
+        });
+
+        let output = handler.execute(input, client, None).await.unwrap();
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("Strength & Mobility"));
+        assert!(content_str.contains("Strength Sessions"));
+        assert!(content_str.contains("100% of weeks"));
+        assert!(content_str.contains("Heavy Lower-Body Sessions"));
+    }
+
     #[tokio::test]
     async fn test_analyze_period_with_requested_metrics() {
         let handler = AnalyzeTrainingHandler::new();
@@ -0,0 +1,638 @@
+use crate::intents::{ContentBlock, IdempotencyCache, IntentError, IntentHandler, IntentOutput};
+use async_trait::async_trait;
+use intervals_icu_client::{Event, EventCategory, IntervalsClient};
+use serde_json::{Map, Value, json};
+/// Annotate Activity Intent Handler
+///
+/// Records how a completed session felt (RPE, feel, notes), links it to the
+/// planned workout it fulfilled, and corrects sport type or gear.
+use std::sync::Arc;
+
+use crate::engines::analysis_fetch::{
+    fetch_calendar_events_between, fetch_srpe_calibration, forget_cached_activity_detail,
+};
+use crate::engines::gear_wear::value_id;
+use crate::engines::session_rpe::{
+    FEEL_MAX, FEEL_MIN, RPE_MAX, RPE_MIN, feel_label, has_sensor_data,
+};
+use crate::intents::utils::parse_date;
+
+use super::manage_gear::ManageGearHandler;
+
+/// Calendar events fetched when matching an activity to its planned workout.
+const MATCH_EVENT_LIMIT: u32 = 100;
+/// Recent activities searched when `activity_id` is "latest".
+const LATEST_ACTIVITY_DAYS: i32 = 14;
+
+pub struct AnnotateActivityHandler;
+
+impl AnnotateActivityHandler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Validated annotation: activity field updates, coach note, and display rows.
+#[derive(Debug, Default)]
+struct Annotation {
+    fields: Map<String, Value>,
+    note: Option<String>,
+    rows: Vec<Vec<String>>,
+}
+
+fn parse_scale(input: &Value, field: &str, min: f64, max: f64) -> Result<Option<i64>, IntentError> {
+    let Some(raw) = input.get(field).filter(|value| !value.is_null()) else {
+        return Ok(None);
+    };
+    let value = raw
+        .as_f64()
+        .ok_or_else(|| IntentError::validation(format!("{field} must be a number.")))?;
+    if value < min || value > max || value.fract() != 0.0 {
+        return Err(IntentError::validation(format!(
+            "{field} must be a whole number between {min} and {max} (got {value})."
+        )));
+    }
+    Ok(Some(value as i64))
+}
+
+/// Intervals.icu event ids are integers; accept them as numbers or numeric strings.
+fn parse_event_id(raw: &Value) -> Option<i64> {
+    raw.as_i64()
+        .or_else(|| raw.as_str().and_then(|id| id.trim().parse().ok()))
+}
+
+fn non_empty_str<'a>(input: &'a Value, field: &str) -> Option<&'a str> {
+    input
+        .get(field)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// Map the annotation inputs to Intervals.icu activity fields.
+fn build_annotation(input: &Value) -> Result<Annotation, IntentError> {
+    let mut annotation = Annotation::default();
+
+    if let Some(rpe) = parse_scale(input, "rpe", RPE_MIN, RPE_MAX)? {
+        annotation.fields.insert("icu_rpe".into(), json!(rpe));
+        annotation
+            .rows
+            .push(vec!["RPE".into(), format!("{rpe}/10")]);
+    }
+    if let Some(feel) = parse_scale(input, "feel", FEEL_MIN as f64, FEEL_MAX as f64)? {
+        annotation.fields.insert("feel".into(), json!(feel));
+        annotation.rows.push(vec![
+            "Feel".into(),
+            format!("{feel}/5 ({})", feel_label(feel)),
+        ]);
+    }
+    if let Some(sport_type) = non_empty_str(input, "sport_type") {
+        annotation.fields.insert("type".into(), json!(sport_type));
+        annotation
+            .rows
+            .push(vec!["Sport type".into(), sport_type.to_string()]);
+    }
+    if let Some(raw) = input
+        .get("planned_event_id")
+        .filter(|value| !value.is_null())
+    {
+        let event_id = parse_event_id(raw).ok_or_else(|| {
+            IntentError::validation(format!("planned_event_id must be an integer (got {raw})."))
+        })?;
+        annotation
+            .fields
+            .insert("paired_event_id".into(), json!(event_id));
+        annotation
+            .rows
+            .push(vec!["Planned workout".into(), format!("event {event_id}")]);
+    }
+    if let Some(note) = non_empty_str(input, "notes") {
+        annotation.note = Some(note.to_string());
+        annotation.rows.push(vec!["Note".into(), note.to_string()]);
+    }
+
+    Ok(annotation)
+}
+
+/// Planned WORKOUT events on `date` whose sport matches the activity.
+fn matching_planned_workouts<'a>(
+    events: &'a [Event],
+    date: &str,
+    activity_type: Option<&str>,
+) -> Vec<&'a Event> {
+    events
+        .iter()
+        .filter(|event| matches!(event.category, EventCategory::Workout))
+        .filter(|event| event.start_date_local.get(..10) == Some(date))
+        .filter(|event| match (activity_type, event.r#type.as_deref()) {
+            (Some(activity), Some(planned)) => activity.eq_ignore_ascii_case(planned),
+            _ => true,
+        })
+        .collect()
+}
+
+impl AnnotateActivityHandler {
+    async fn resolve_activity_id(
+        &self,
+        input: &Value,
+        client: &dyn IntervalsClient,
+    ) -> Result<String, IntentError> {
+        let activity_id = non_empty_str(input, "activity_id")
+            .ok_or_else(|| IntentError::validation("Missing required field: activity_id"))?;
+        if activity_id != "latest" {
+            return Ok(activity_id.to_string());
+        }
+        let activities = client
+            .get_recent_activities(Some(20), Some(LATEST_ACTIVITY_DAYS))
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to fetch activities: {e}")))?;
+        activities
+            .into_iter()
+            .max_by(|a, b| a.start_date_local.cmp(&b.start_date_local))
+            .map(|activity| activity.id)
+            .ok_or_else(|| {
+                IntentError::validation(format!(
+                    "No activities in the last {LATEST_ACTIVITY_DAYS} days to annotate."
+                ))
+            })
+    }
+
+    async fn resolve_gear(
+        &self,
+        input: &Value,
+        client: &dyn IntervalsClient,
+    ) -> Result<Option<(String, String)>, IntentError> {
+        if let Some(gear_id) = non_empty_str(input, "gear_id") {
+            return Ok(Some((gear_id.to_string(), gear_id.to_string())));
+        }
+        let Some(gear_name) = non_empty_str(input, "gear_name") else {
+            return Ok(None);
+        };
+        let gear_list = client
+            .get_gear_list()
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to fetch gear: {e}")))?;
+        let gear_array = gear_list.as_array().map(Vec::as_slice).unwrap_or_default();
+        let gear = ManageGearHandler::find_gear(gear_array, gear_name)?;
+        let id = gear
+            .get("id")
+            .and_then(value_id)
+            .ok_or_else(|| IntentError::validation(format!("Gear '{gear_name}' has no ID")))?;
+        let name = gear
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or(gear_name)
+            .to_string();
+        Ok(Some((id, name)))
+    }
+
+    async fn match_planned_workout(
+        &self,
+        detail: &Value,
+        client: &dyn IntervalsClient,
+    ) -> Result<Result<Event, String>, IntentError> {
+        let Some(date_str) = detail
+            .get("start_date_local")
+            .and_then(Value::as_str)
+            .and_then(|value| value.get(..10))
+        else {
+            return Ok(Err("activity has no start date".into()));
+        };
+        let date = parse_date(date_str, "start_date_local")?;
+        let events = fetch_calendar_events_between(client, &date, &date, MATCH_EVENT_LIMIT).await?;
+        let activity_type = detail.get("type").and_then(Value::as_str);
+        let candidates = matching_planned_workouts(&events, date_str, activity_type);
+        Ok(match candidates.as_slice() {
+            [event] => Ok((*event).clone()),
+            [] => Err(format!("no planned workout found on {date_str}")),
+            many => Err(format!(
+                "{} planned workouts on {date_str}; pass planned_event_id to choose one",
+                many.len()
+            )),
+        })
+    }
+}
+
+#[async_trait]
+impl IntentHandler for AnnotateActivityHandler {
+    fn name(&self) -> &'static str {
+        "annotate_activity"
+    }
+
+    fn description(&self) -> &'static str {
+        "Annotates a completed activity: sets RPE (1-10) and feel (1 = strong … 5 = weak), \
+         posts coach notes as activity comments, links the activity to the planned WORKOUT \
+         event it fulfilled (planned_event_id, or match_planned to find it by date and sport), \
+         and corrects sport type or gear. Use after a session when the athlete says how it \
+         felt. RPE feeds session-RPE load in analyze_training for sessions without power/HR. \
+         Prefer `dry_run: true` to preview. Saving requires an idempotency token; a dry_run \
+         preview does not."
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "activity_id": {"type": "string", "description": "Activity ID, or 'latest' for the most recent activity"},
+                "rpe": {"type": "integer", "minimum": 1, "maximum": 10, "description": "Session RPE (CR-10)"},
+                "feel": {"type": "integer", "minimum": 1, "maximum": 5, "description": "1 = strong, 2 = good, 3 = normal, 4 = poor, 5 = weak"},
+                "notes": {"type": "string", "description": "Coach note posted as an activity comment"},
+                "planned_event_id": {"type": "integer", "description": "Planned WORKOUT event this activity fulfilled"},
+                "match_planned": {"type": "boolean", "default": false, "description": "Find the planned workout on the same date and sport and link it"},
+                "sport_type": {"type": "string", "description": "Corrected sport type (e.g. 'Run', 'TrailRun', 'WeightTraining')"},
+                "gear_id": {"type": "string", "description": "Gear ID to set on the activity"},
+                "gear_name": {"type": "string", "description": "Exact gear name to set on the activity (case-insensitive)"},
+                "dry_run": {"type": "boolean", "default": false, "description": "Preview changes only"},
+                "idempotency_token": {"type": "string", "description": "Idempotency token (required unless dry_run)"}
+            },
+            "required": ["activity_id"]
+        })
+    }
+
+    async fn execute(
+        &self,
+        input: Value,
+        client: Arc<dyn IntervalsClient>,
+        _cache: Option<&IdempotencyCache>,
+    ) -> Result<IntentOutput, IntentError> {
+        let dry_run = input
+            .get("dry_run")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        if !dry_run {
+            input
+                .get("idempotency_token")
+                .and_then(Value::as_str)
+                .ok_or_else(|| {
+                    IntentError::validation("Missing required field: idempotency_token")
+                })?;
+        }
+        let match_planned = input
+            .get("match_planned")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        let mut annotation = build_annotation(&input)?;
+        if let Some((gear_id, gear_name)) = self.resolve_gear(&input, client.as_ref()).await? {
+            annotation
+                .fields
+                .insert("gear".into(), json!({"id": gear_id}));
+            annotation.rows.push(vec!["Gear".into(), gear_name]);
+        }
+
+        let activity_id = self.resolve_activity_id(&input, client.as_ref()).await?;
+        let detail = client
+            .get_activity_details(&activity_id)
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to fetch activity: {e}")))?;
+
+        let mut warnings = Vec::new();
+        if match_planned && !annotation.fields.contains_key("paired_event_id") {
+            match self.match_planned_workout(&detail, client.as_ref()).await? {
+                Ok(event) => match event.id.as_deref().and_then(|id| id.parse::<i64>().ok()) {
+                    Some(event_id) => {
+                        annotation
+                            .fields
+                            .insert("paired_event_id".into(), json!(event_id));
+                        annotation.rows.push(vec![
+                            "Planned workout".into(),
+                            format!("{} (event {event_id})", event.name),
+                        ]);
+                    }
+                    None => warnings.push(format!(
+                        "Planned workout not linked: {} has no numeric event id.",
+                        event.name
+                    )),
+                },
+                Err(reason) => warnings.push(format!("Planned workout not linked: {reason}.")),
+            }
+        }
+
+        if annotation.fields.is_empty() && annotation.note.is_none() {
+            return Err(IntentError::validation(
+                "Nothing to annotate. Provide rpe, feel, notes, planned_event_id, match_planned, \
+                 sport_type, gear_id or gear_name.",
+            ));
+        }
+
+        let activity_name = detail
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or("Activity");
+        let status = if dry_run {
+            "Preview only (dry_run) - nothing written."
+        } else {
+            "Saved to Intervals.icu."
+        };
+        let mut markdown = format!(
+            "# Activity Annotation\n\n**Activity:** {activity_name} ({activity_id})\n\n{status}"
+        );
+        for warning in &warnings {
+            markdown.push_str(&format!("\n\n⚠️ {warning}"));
+        }
+        let mut content = vec![ContentBlock::markdown(markdown)];
+        content.push(ContentBlock::table(
+            vec!["Field".into(), "Value".into()],
+            annotation.rows.clone(),
+        ));
+
+        let mut suggestions = Vec::new();
+        if let Some(rpe) = annotation.fields.get("icu_rpe").and_then(Value::as_f64)
+            && !has_sensor_data(&detail)
+            && let Some(seconds) = detail.get("moving_time").and_then(Value::as_f64)
+        {
+            let srpe = rpe * seconds / 60.0;
+            let calibration = fetch_srpe_calibration(client.as_ref(), [&detail]).await;
+            let basis = if calibration.calibrated {
+                format!("calibrated from {} sessions", calibration.sessions)
+            } else {
+                "default factor".to_string()
+            };
+            suggestions.push(format!(
+                "No power/HR on this session: its load will be estimated from session RPE \
+                 ({srpe:.0} AU ≈ {:.0}, {basis}).",
+                srpe * calibration.factor
+            ));
+        }
+
+        if dry_run {
+            return Ok(IntentOutput::new(content)
+                .with_suggestions(suggestions)
+                .with_next_actions(vec![
+                    "To save: call annotate_activity again without dry_run and with an idempotency_token."
+                        .into(),
+                ]));
+        }
+
+        if !annotation.fields.is_empty() {
+            client
+                .update_activity(&activity_id, &Value::Object(annotation.fields.clone()))
+                .await
+                .map_err(|e| IntentError::api(format!("Failed to update activity: {e}")))?;
+            forget_cached_activity_detail(client.as_ref(), &activity_id);
+        }
+        if let Some(note) = &annotation.note {
+            client
+                .add_activity_message(&activity_id, note)
+                .await
+                .map_err(|e| IntentError::api(format!("Failed to post note: {e}")))?;
+        }
+
+        Ok(IntentOutput::new(content)
+            .with_suggestions(suggestions)
+            .with_next_actions(vec![format!(
+                "To review the session: analyze_training with target_type: single, activity_id: {activity_id}"
+            )]))
+    }
+
+    fn requires_idempotency_token(&self) -> bool {
+        false
+    }
+
+    /// A dry_run preview needs no token; saves check for one in `execute` and
+    /// are deduplicated by it.
+    fn accepts_idempotency_token(&self) -> bool {
+        true
+    }
+}
+
+impl Default for AnnotateActivityHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::mock::MockIntervalsClient;
+    use intervals_icu_client::ActivitySummary;
+
+    fn event(id: &str, date: &str, sport: Option<&str>, category: EventCategory) -> Event {
+        Event {
+            id: Some(id.into()),
+            start_date_local: date.into(),
+            name: format!("Planned {id}"),
+            category,
+            description: None,
+            r#type: sport.map(str::to_owned),
+            end_date_local: None,
+        }
+    }
+
+    #[test]
+    fn test_name_and_schema() {
+        let handler = AnnotateActivityHandler::new();
+        assert_eq!(handler.name(), "annotate_activity");
+        assert!(!handler.requires_idempotency_token());
+        assert!(handler.accepts_idempotency_token());
+        let schema = handler.input_schema();
+        assert_eq!(schema["required"], json!(["activity_id"]));
+    }
+
+    #[test]
+    fn test_build_annotation_maps_fields() {
+        let input = json!({
+            "rpe": 7,
+            "feel": 4,
+            "notes": " Heavy legs ",
+            "sport_type": "TrailRun",
+            "planned_event_id": "9"
+        });
+        let annotation = build_annotation(&input).unwrap();
+        assert_eq!(annotation.fields["icu_rpe"], json!(7));
+        assert_eq!(annotation.fields["feel"], json!(4));
+        assert_eq!(annotation.fields["type"], json!("TrailRun"));
+        assert_eq!(annotation.fields["paired_event_id"], json!(9));
+        assert_eq!(annotation.note.as_deref(), Some("Heavy legs"));
+    }
+
+    #[test]
+    fn test_build_annotation_rejects_out_of_range() {
+        assert!(build_annotation(&json!({"rpe": 11})).is_err());
+        assert!(build_annotation(&json!({"rpe": 6.5})).is_err());
+        assert!(build_annotation(&json!({"feel": 0})).is_err());
+        assert!(build_annotation(&json!({"planned_event_id": "evt-9"})).is_err());
+    }
+
+    #[test]
+    fn test_matching_planned_workouts_filters_by_date_sport_and_category() {
+        let events = vec![
+            event(
+                "1",
+                "2026-03-08T00:00:00",
+                Some("Run"),
+                EventCategory::Workout,
+            ),
+            event(
+                "2",
+                "2026-03-08T00:00:00",
+                Some("Ride"),
+                EventCategory::Workout,
+            ),
+            event("3", "2026-03-08", None, EventCategory::Note),
+            event("4", "2026-03-09", Some("Run"), EventCategory::Workout),
+        ];
+        let matches = matching_planned_workouts(&events, "2026-03-08", Some("Run"));
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].id.as_deref(), Some("1"));
+        assert_eq!(
+            matching_planned_workouts(&events, "2026-03-08", None).len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_requires_token_and_changes() {
+        let handler = AnnotateActivityHandler::new();
+        let client = Arc::new(MockIntervalsClient::default());
+        let err = handler
+            .execute(json!({"activity_id": "a1", "rpe": 5}), client.clone(), None)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("idempotency_token"));
+
+        let err = handler
+            .execute(
+                json!({"activity_id": "a1", "idempotency_token": "t"}),
+                client,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Nothing to annotate"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_matches_planned_workout_and_posts_note() {
+        let today = chrono::Local::now().date_naive().to_string();
+        let client = Arc::new(
+            MockIntervalsClient::builder()
+                .with_activities(vec![ActivitySummary {
+                    id: "a1".into(),
+                    name: Some("Strength".into()),
+                    start_date_local: format!("{today}T07:00:00"),
+                    ..Default::default()
+                }])
+                .with_activity_detail(
+                    "a1",
+                    json!({
+                        "name": "Strength",
+                        "type": "WeightTraining",
+                        "start_date_local": format!("{today}T07:00:00"),
+                        "moving_time": 2700
+                    }),
+                )
+                .with_events(vec![event(
+                    "101",
+                    &today,
+                    Some("WeightTraining"),
+                    EventCategory::Workout,
+                )]),
+        );
+        let output = AnnotateActivityHandler::new()
+            .execute(
+                json!({
+                    "activity_id": "latest",
+                    "rpe": 6,
+                    "notes": "Squats felt heavy",
+                    "match_planned": true,
+                    "idempotency_token": "t-1"
+                }),
+                client,
+                None,
+            )
+            .await
+            .unwrap();
+        let rendered = format!("{:?}", output.content);
+        assert!(rendered.contains("Saved to Intervals.icu"));
+        assert!(rendered.contains("event 101"));
+        assert!(rendered.contains("Squats felt heavy"));
+        assert!(output.suggestions[0].contains("270 AU ≈ 54, default factor"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_gear_name_requires_exact_match() {
+        let client = Arc::new(
+            MockIntervalsClient::builder()
+                .with_activity_detail("a1", json!({"name": "Run", "type": "Run"}))
+                .with_gear_list(json!([
+                    {"id": "g1", "name": "Trail Shoe"},
+                    {"id": "g2", "name": "Trail Shoe 2"}
+                ])),
+        );
+        let handler = AnnotateActivityHandler::new();
+        let err = handler
+            .execute(
+                json!({"activity_id": "a1", "gear_name": "trail", "idempotency_token": "t-1"}),
+                client.clone(),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Available gear: Trail Shoe, Trail Shoe 2")
+        );
+
+        let output = handler
+            .execute(
+                json!({"activity_id": "a1", "gear_name": "trail shoe 2", "idempotency_token": "t-2"}),
+                client,
+                None,
+            )
+            .await
+            .unwrap();
+        let rendered = format!("{:?}", output.content);
+        assert!(rendered.contains("Trail Shoe 2"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_scales_srpe_with_athlete_calibration() {
+        let today = chrono::Local::now().date_naive();
+        let mut builder = MockIntervalsClient::builder().with_activity_detail(
+            "a1",
+            json!({
+                "name": "Yoga",
+                "type": "Yoga",
+                "start_date_local": format!("{today}T07:00:00"),
+                "moving_time": 2700
+            }),
+        );
+        let mut activities = Vec::new();
+        for day in 1..=5 {
+            let id = format!("hr-{day}");
+            let date = format!("{}T07:00:00", today - chrono::Duration::days(day));
+            builder = builder.with_activity_detail(
+                &id,
+                json!({
+                    "start_date_local": date,
+                    "average_heartrate": 140,
+                    "icu_training_load": 120,
+                    "icu_rpe": 6,
+                    "moving_time": 3600
+                }),
+            );
+            activities.push(ActivitySummary {
+                id,
+                start_date_local: date,
+                ..Default::default()
+            });
+        }
+        let client = Arc::new(builder.with_activities(activities));
+        let output = AnnotateActivityHandler::new()
+            .execute(
+                json!({"activity_id": "a1", "rpe": 6, "dry_run": true}),
+                client,
+                None,
+            )
+            .await
+            .unwrap();
+        // 5 × 120 load over 5 × 360 AU: factor 1/3, so 270 AU ≈ 90.
+        assert!(
+            output.suggestions[0].contains("270 AU ≈ 90, calibrated from 5 sessions"),
+            "{:?}",
+            output.suggestions
+        );
+    }
+}
@@ -18,15 +18,23 @@ const READINESS_TSB_RACE: f64 = 5.0;
 const READINESS_RECOVERY_INDEX_INTENSITY: f64 = 0.95;
 const READINESS_RECOVERY_INDEX_LONG: f64 = 0.9;
 const READINESS_RECOVERY_INDEX_RACE: f64 = 1.1;
+const LOAD_CHANNEL_ACTIVITY_LIMIT: usize = 60;
 
+use super::render::analysis::render_injury_risk_section;
 #[cfg(test)]
 use crate::domains::coach::CoachMetrics;
 use crate::domains::coach::{AnalysisKind, AnalysisWindow, CoachContext, WellnessMetrics};
 use crate::engines::ade::compute_ade;
 use crate::engines::analysis_audit::build_data_audit;
-use crate::engines::analysis_fetch::{RecoveryFetchRequest, fetch_recovery_data};
+use crate::engines::analysis_fetch::{
+    RecoveryFetchRequest, fetch_load_channel_history, fetch_recovery_data,
+};
 use crate::engines::coach_guidance::{build_alerts, build_guidance};
 use crate::engines::coach_metrics::{parse_fitness_metrics, parse_wellness_metrics};
+use crate::engines::injury_risk::{
+    CHANNEL_HISTORY_DAYS, channel_label, compute_injury_risk_from_details,
+};
+use crate::engines::strength::{HEAVY_LOWER_BODY_WINDOW_HOURS, recent_heavy_lower_body};
 use crate::intents::utils::data_availability_block;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -218,6 +226,16 @@ impl AssessRecoveryHandler {
             vec!["TSB".into(), format!("{:.0}", tsb), tsb_status.into()],
         ];
 
+        if let Some(phase) = &wellness.cycle_phase {
+            let status = match (wellness.cycle_rhr_shift_bpm, wellness.cycle_hrv_shift_pct) {
+                (Some(rhr), Some(hrv)) => {
+                    format!("Baseline adjusted: RHR {rhr:+.1} bpm, HRV {hrv:+.0}%")
+                }
+                _ => "No expected HRV/RHR shift".to_string(),
+            };
+            rows.push(vec!["Cycle Phase".into(), phase.clone(), status]);
+        }
+
         if let Some(ctl) = fitness.ctl {
             rows.push(vec!["CTL".into(), format!("{:.0}", ctl), "".into()]);
         }
@@ -317,6 +335,26 @@ impl AssessRecoveryHandler {
         rows
     }
 
+    /// Readiness line explaining how the menstrual-cycle phase shaped the verdict.
+    fn cycle_phase_note(wellness: &WellnessMetrics) -> Option<String> {
+        let phase = wellness.cycle_phase.as_deref()?;
+        let (Some(rhr), Some(hrv)) = (wellness.cycle_rhr_shift_bpm, wellness.cycle_hrv_shift_pct)
+        else {
+            return Some(format!(
+                "Cycle phase: {phase} (no expected HRV/RHR shift; deviations are read against your usual baseline)."
+            ));
+        };
+        Some(if wellness.cycle_explained_deviation {
+            format!(
+                "Cycle phase: {phase}. The HRV/RHR deviation from baseline is within the expected {phase} shift (RHR {rhr:+.1} bpm, HRV {hrv:+.0}%), so it is not treated as a fatigue signal."
+            )
+        } else {
+            format!(
+                "Cycle phase: {phase}. Baselines allow for the expected shift (RHR {rhr:+.1} bpm, HRV {hrv:+.0}%); any remaining deviation is not explained by phase."
+            )
+        })
+    }
+
     #[cfg(test)]
     fn check_red_flags(&self, sleep: f64, rhr: f64, hrv: f64, tsb: f64) -> Vec<String> {
         let metrics = CoachMetrics {
@@ -350,6 +388,8 @@ impl IntentHandler for AssessRecoveryHandler {
          index), wellness metrics (sleep, RHR, HRV, recovery index, readiness), \
          fitness metrics (TSB, CTL, ATL), and ADE system state assessment \
          (LoadAccepting/RecoveryPriority with risk level and active flags). \
+         Per-channel injury risk (run impact, vertical/descent, cycling, strength \
+         ACWR plus long-run and weekly-descent spikes) naming the driving channel. \
          Activity-specific readiness verdict for easy/intensity/long/race sessions.
          
          Use this tool when: you need to check if today is safe for a key workout, \
@@ -417,6 +457,24 @@ impl IntentHandler for AssessRecoveryHandler {
         recovery_context.audit = build_data_audit(&fetched);
         recovery_context.metrics.fitness = parse_fitness_metrics(fetched.fitness.as_ref());
         recovery_context.metrics.wellness = parse_wellness_metrics(fetched.wellness.as_ref());
+        // Injury risk is supplementary: a failed history fetch must not block readiness.
+        let mut heavy_strength = None;
+        if let Ok(history) = fetch_load_channel_history(
+            client.as_ref(),
+            end_date,
+            CHANNEL_HISTORY_DAYS,
+            LOAD_CHANNEL_ACTIVITY_LIMIT,
+        )
+        .await
+        {
+            recovery_context.metrics.injury_risk = Some(compute_injury_risk_from_details(
+                &history.activities,
+                &history.activity_details,
+                end_date,
+            ));
+            heavy_strength =
+                recent_heavy_lower_body(&history.activities, &history.activity_details, end_date);
+        }
         if include_red_flags {
             recovery_context.alerts = build_alerts(&recovery_context.metrics);
         }
@@ -514,8 +572,12 @@ impl IntentHandler for AssessRecoveryHandler {
             )));
         }
 
+        if let Some(section) = render_injury_risk_section(&recovery_context.metrics.injury_risk) {
+            content.push(ContentBlock::markdown(section));
+        }
+
         // Calculate red flags first
-        let red_flags = if include_red_flags {
+        let mut red_flags = if include_red_flags {
             recovery_context
                 .alerts
                 .iter()
@@ -524,6 +586,19 @@ impl IntentHandler for AssessRecoveryHandler {
         } else {
             Vec::new()
         };
+        // Heavy lower-body lifting leaves residual leg fatigue that blunts quality work.
+        if include_red_flags
+            && planned_activity != PlannedActivity::Easy
+            && let Some(session) = &heavy_strength
+        {
+            red_flags.push(format!(
+                "Heavy lower-body strength: {} on {} (allow {} h before {} work)",
+                session.name,
+                session.date,
+                HEAVY_LOWER_BODY_WINDOW_HOURS,
+                planned_activity.as_str()
+            ));
+        }
 
         if include_red_flags {
             let flags_md = if red_flags.is_empty() {
@@ -541,12 +616,16 @@ impl IntentHandler for AssessRecoveryHandler {
 
         let (readiness_status, readiness_note) =
             planned_activity.readiness_copy(&wellness, &fitness, &red_flags);
-        content.push(ContentBlock::markdown(format!(
+        let mut readiness_md = format!(
             "Activity-Specific Readiness\n  {} for {} work.\n  {}",
             readiness_status,
             planned_activity.as_str(),
             readiness_note
-        )));
+        );
+        if let Some(note) = Self::cycle_phase_note(&wellness) {
+            readiness_md.push_str(&format!("\n  {note}"));
+        }
+        content.push(ContentBlock::markdown(readiness_md));
 
         if let Some(block) = data_availability_block(
             &recovery_context.audit.degraded_mode_reasons,
@@ -557,6 +636,17 @@ impl IntentHandler for AssessRecoveryHandler {
 
         // Use shared guidance from coach engine
         let mut suggestions = recovery_context.guidance.suggestions.clone();
+        if let Some(channel) = recovery_context
+            .metrics
+            .injury_risk
+            .as_ref()
+            .and_then(|risk| risk.driving_channel.as_deref())
+        {
+            suggestions.push(format!(
+                "Injury risk is driven by {} load - hold that channel flat this week and shift work to lower-risk channels.",
+                channel_label(channel).to_lowercase()
+            ));
+        }
 
         // Look-ahead: warn if key workout scheduled in next 7 days
         if let Some(ref workouts) = upcoming
@@ -1964,4 +2054,118 @@ mod tests {
         assert!(content_str.contains("None detected"));
         assert!(!content_str.contains("Red Flags Detected"));
     }
+
+    #[tokio::test]
+    async fn test_execute_surfaces_injury_risk_driving_channel() {
+        let today = Local::now().date_naive();
+        let mut activities = Vec::new();
+        let mut client = make_good_client();
+        for day in (0..CHANNEL_HISTORY_DAYS).step_by(2) {
+            let id = format!("run-{day}");
+            // Steady 10 km runs, with descent quadrupling in the last week.
+            let descent = if day < 7 { 800.0 } else { 100.0 };
+            activities.push(ActivitySummary {
+                id: id.clone(),
+                start_date_local: format!("{}T07:00:00", today - chrono::Duration::days(day)),
+                ..Default::default()
+            });
+            client = client.with_activity_detail(
+                &id,
+                json!({"type": "TrailRun", "distance": 10000.0, "total_elevation_loss": descent}),
+            );
+        }
+        let client = Arc::new(client.with_activities(activities));
+
+        let output = AssessRecoveryHandler::new()
+            .execute(json!({}), client, None)
+            .await
+            .unwrap();
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("Injury Risk by Load Channel"));
+        assert!(content_str.contains("driven by Vertical / descent"));
+        assert!(content_str.contains("Weekly descent spike"));
+        assert!(
+            output
+                .suggestions
+                .iter()
+                .any(|s| s.contains("driven by vertical / descent load"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_explains_luteal_hrv_dip() {
+        let mut wellness = (0..28)
+            .map(|_| json!({"sleep_hours": 8.0, "resting_hr": 50.0, "hrv": 80.0}))
+            .collect::<Vec<_>>();
+        wellness.extend((0..7).map(|_| {
+            json!({"sleep_hours": 8.0, "resting_hr": 52.0, "hrv": 75.0, "menstrualPhase": "LUTEAL"})
+        }));
+        let client = MockIntervalsClient::builder()
+            .with_wellness(Value::Array(wellness))
+            .with_fitness_summary(json!({"form": 15.0}));
+
+        let output = AssessRecoveryHandler::new()
+            .execute(json!({"for_activity": "intensity"}), Arc::new(client), None)
+            .await
+            .unwrap();
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("Cycle Phase"));
+        assert!(content_str.contains("within the expected luteal shift"));
+        assert!(!content_str.contains("HRV below personal baseline"));
+    }
+
+    #[test]
+    fn test_cycle_phase_note_without_shift() {
+        let wellness = WellnessMetrics {
+            cycle_phase: Some("follicular".into()),
+            ..Default::default()
+        };
+        let note = AssessRecoveryHandler::cycle_phase_note(&wellness).unwrap();
+        assert!(note.contains("no expected HRV/RHR shift"));
+        assert!(AssessRecoveryHandler::cycle_phase_note(&WellnessMetrics::default()).is_none());
+    }
+
+    fn heavy_leg_day_client() -> MockIntervalsClient {
+        let yesterday = Local::now().date_naive() - chrono::Duration::days(1);
+        make_good_client()
+            .with_activity_detail(
+                "lift-1",
+                json!({"type": "WeightTraining", "name": "Squat + deadlift", "icu_rpe": 8, "moving_time": 3000}),
+            )
+            .with_activities(vec![ActivitySummary {
+                id: "lift-1".into(),
+                name: Some("Squat + deadlift".into()),
+                start_date_local: format!("{yesterday}T18:00:00"),
+                ..Default::default()
+            }])
+    }
+
+    #[tokio::test]
+    async fn test_execute_flags_heavy_lower_body_before_intensity() {
+        let output = AssessRecoveryHandler::new()
+            .execute(
+                json!({"for_activity": "intensity"}),
+                Arc::new(heavy_leg_day_client()),
+                None,
+            )
+            .await
+            .unwrap();
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("Heavy lower-body strength: Squat + deadlift"));
+        assert!(content_str.contains("Red Flags Detected"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_ignores_heavy_lower_body_for_easy_day() {
+        let output = AssessRecoveryHandler::new()
+            .execute(
+                json!({"for_activity": "easy"}),
+                Arc::new(heavy_leg_day_client()),
+                None,
+            )
+            .await
+            .unwrap();
+        let content_str = content_text(&output.content);
+        assert!(!content_str.contains("Heavy lower-body strength"));
+    }
 }
@@ -0,0 +1,375 @@
+use crate::intents::{ContentBlock, IdempotencyCache, IntentError, IntentHandler, IntentOutput};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use intervals_icu_client::{ActivityUpload, IntervalsClient};
+use serde_json::{Value, json};
+/// Import Activity Intent Handler
+///
+/// Uploads a FIT, TCX or GPX file from a local path as a new activity,
+/// skipping files whose start time matches an existing activity.
+use std::path::Path;
+use std::sync::Arc;
+use tokio::io::AsyncReadExt;
+
+use crate::engines::activity_import::{
+    ActivityFileFormat, DUPLICATE_TOLERANCE_SECS, START_TIME_SCAN_BYTES, detect_start_time,
+    starts_at,
+};
+use crate::uploads::resolve_upload_path;
+
+pub struct ImportActivityHandler;
+
+impl ImportActivityHandler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ImportActivityHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn non_empty_str<'a>(input: &'a Value, field: &str) -> Option<&'a str> {
+    input
+        .get(field)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+async fn read_head(path: &Path) -> Result<Vec<u8>, IntentError> {
+    let file = tokio::fs::File::open(path)
+        .await
+        .map_err(|e| IntentError::validation(format!("Cannot open {}: {e}", path.display())))?;
+    let mut head = Vec::with_capacity(START_TIME_SCAN_BYTES);
+    file.take(START_TIME_SCAN_BYTES as u64)
+        .read_to_end(&mut head)
+        .await
+        .map_err(|e| IntentError::validation(format!("Cannot read {}: {e}", path.display())))?;
+    Ok(head)
+}
+
+/// Existing activity that starts at `start`, checked against the UTC start of
+/// activities dated within a day of it.
+async fn find_duplicate(
+    client: &dyn IntervalsClient,
+    start: DateTime<Utc>,
+) -> Result<Option<(String, String)>, IntentError> {
+    let start_date = start.date_naive();
+    let activities = client
+        .get_activities_between(
+            start_date - Duration::days(1),
+            start_date + Duration::days(1),
+        )
+        .await
+        .map_err(|e| IntentError::api(format!("Failed to fetch activities: {e}")))?;
+
+    for activity in activities {
+        let detail = client
+            .get_activity_details(&activity.id)
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to fetch activity: {e}")))?;
+        if starts_at(&detail, start) {
+            let name = activity.name.unwrap_or_else(|| "Activity".into());
+            return Ok(Some((activity.id, name)));
+        }
+    }
+    Ok(None)
+}
+
+#[async_trait]
+impl IntentHandler for ImportActivityHandler {
+    fn name(&self) -> &'static str {
+        "import_activity"
+    }
+
+    fn description(&self) -> &'static str {
+        "Import a FIT, TCX or GPX file from a local path as a new completed activity.
+
+Use this tool when: the athlete has a recording on disk (a watch export, a file from another platform) that is missing from Intervals.icu.
+
+Do NOT use when: the activity already synced from a device or platform, or to change an existing activity (use annotate_activity).
+
+Arguments:
+- path (string, required): Local file path (.fit, .tcx, .gpx, optionally .gz, or .zip). In HTTP mode the path is relative to the server's upload directory.
+- name, description (string, optional): Activity name and description.
+- external_id (string, optional): Your own identifier stored on the activity.
+- allow_duplicate (boolean, default false): Upload even if an activity already starts at the same time.
+- dry_run (boolean, default false): Check the file and duplicates without uploading; needs no idempotency token.
+
+Returns: The created activity ids, or the existing activity when the file's start time matches one within a minute.
+On error: Validation errors for unsupported or unreadable files and disabled path imports, API errors from Intervals.icu. Uploads require an idempotency token."
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": {"type": "string", "description": "Local path of the FIT, TCX or GPX file"},
+                "name": {"type": "string", "description": "Activity name"},
+                "description": {"type": "string", "description": "Activity description"},
+                "external_id": {"type": "string", "description": "Caller-side identifier stored on the activity"},
+                "allow_duplicate": {"type": "boolean", "default": false, "description": "Upload even when an activity starts at the same time"},
+                "dry_run": {"type": "boolean", "default": false, "description": "Preview only"},
+                "idempotency_token": {"type": "string", "description": "Idempotency token (required unless dry_run)"}
+            },
+            "required": ["path"]
+        })
+    }
+
+    async fn execute(
+        &self,
+        input: Value,
+        client: Arc<dyn IntervalsClient>,
+        _cache: Option<&IdempotencyCache>,
+    ) -> Result<IntentOutput, IntentError> {
+        let dry_run = input
+            .get("dry_run")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        if !dry_run {
+            non_empty_str(&input, "idempotency_token").ok_or_else(|| {
+                IntentError::validation("Missing required field: idempotency_token")
+            })?;
+        }
+        let allow_duplicate = input
+            .get("allow_duplicate")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let requested = non_empty_str(&input, "path")
+            .ok_or_else(|| IntentError::validation("Missing required field: path"))?;
+
+        let path = resolve_upload_path(requested).map_err(IntentError::validation)?;
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| requested.to_string());
+        let format = ActivityFileFormat::from_file_name(&file_name).ok_or_else(|| {
+            IntentError::validation(format!(
+                "Unsupported file {file_name}: expected .fit, .tcx or .gpx (optionally .gz), or .zip"
+            ))
+        })?;
+
+        let start = detect_start_time(format, &read_head(&path).await?);
+        let mut warnings = Vec::new();
+        let mut rows = vec![vec!["File".into(), file_name.clone()]];
+        match start {
+            Some(start) => rows.push(vec![
+                "Start".into(),
+                start.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            ]),
+            None => warnings.push(
+                "Could not read a start time from the file; duplicate check skipped.".to_string(),
+            ),
+        }
+
+        let duplicate = match start {
+            Some(start) => find_duplicate(client.as_ref(), start).await?,
+            None => None,
+        };
+        if let Some((id, name)) = &duplicate {
+            rows.push(vec!["Existing activity".into(), format!("{name} ({id})")]);
+            if !allow_duplicate {
+                let markdown = format!(
+                    "# Activity Import\n\nSkipped: {name} ({id}) already starts within \
+                     {DUPLICATE_TOLERANCE_SECS} s of this file."
+                );
+                return Ok(IntentOutput::new(vec![
+                    ContentBlock::markdown(markdown),
+                    ContentBlock::table(vec!["Field".into(), "Value".into()], rows),
+                ])
+                .with_next_actions(vec![
+                    format!(
+                        "To review it: analyze_training with target_type: single, activity_id: {id}"
+                    ),
+                    "To import anyway: call import_activity again with allow_duplicate: true."
+                        .into(),
+                ]));
+            }
+            warnings.push(format!(
+                "{name} ({id}) starts at the same time; importing anyway (allow_duplicate)."
+            ));
+        }
+
+        let mut upload = ActivityUpload::new(&path);
+        upload.name = non_empty_str(&input, "name").map(str::to_string);
+        upload.description = non_empty_str(&input, "description").map(str::to_string);
+        upload.external_id = non_empty_str(&input, "external_id").map(str::to_string);
+        for (label, value) in [
+            ("Name", &upload.name),
+            ("Description", &upload.description),
+            ("External ID", &upload.external_id),
+        ] {
+            if let Some(value) = value {
+                rows.push(vec![label.into(), value.clone()]);
+            }
+        }
+
+        let render = |status: &str, rows: Vec<Vec<String>>| {
+            let mut markdown = format!("# Activity Import\n\n{status}");
+            for warning in &warnings {
+                markdown.push_str(&format!("\n\n⚠️ {warning}"));
+            }
+            vec![
+                ContentBlock::markdown(markdown),
+                ContentBlock::table(vec!["Field".into(), "Value".into()], rows),
+            ]
+        };
+
+        if dry_run {
+            return Ok(
+                IntentOutput::new(render("Preview only (dry_run) - nothing uploaded.", rows))
+                    .with_next_actions(vec![
+                        "To upload: call import_activity again without dry_run and with an idempotency_token."
+                            .into(),
+                    ]),
+            );
+        }
+
+        let result = client
+            .upload_activity_file(&upload)
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to upload activity: {e}")))?;
+        let ids = result.activity_ids();
+        rows.push(vec!["Created".into(), ids.join(", ")]);
+
+        let mut next_actions = ids
+            .first()
+            .map(|id| {
+                vec![
+                    format!("To review the session: analyze_training with target_type: single, activity_id: {id}"),
+                    format!("To add RPE or notes: annotate_activity with activity_id: {id}"),
+                ]
+            })
+            .unwrap_or_default();
+        if ids.is_empty() {
+            next_actions.push(
+                "Intervals.icu may still be processing the file; check recent activities shortly."
+                    .into(),
+            );
+        }
+        Ok(
+            IntentOutput::new(render("Uploaded to Intervals.icu.", rows))
+                .with_next_actions(next_actions),
+        )
+    }
+
+    fn requires_idempotency_token(&self) -> bool {
+        false
+    }
+
+    /// A dry_run check needs no token; uploads check for one in `execute` and
+    /// are deduplicated by it.
+    fn accepts_idempotency_token(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::mock::MockIntervalsClient;
+    use intervals_icu_client::ActivitySummary;
+
+    const GPX: &str = "<gpx><trk><trkseg><trkpt lat=\"1\" lon=\"2\">\
+        <time>2026-03-10T06:00:00Z</time></trkpt></trkseg></trk></gpx>";
+
+    fn gpx_file() -> (tempfile::TempDir, String) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("morning.gpx");
+        std::fs::write(&path, GPX).unwrap();
+        let path = path.to_string_lossy().into_owned();
+        (dir, path)
+    }
+
+    fn existing(start_date: &str) -> MockIntervalsClient {
+        MockIntervalsClient::builder()
+            .with_activities(vec![ActivitySummary {
+                id: "i1".into(),
+                name: Some("Morning Run".into()),
+                start_date_local: "2026-03-10T07:00:00".into(),
+                ..Default::default()
+            }])
+            .with_activity_detail("i1", json!({"id": "i1", "start_date": start_date}))
+    }
+
+    #[test]
+    fn test_name_and_schema() {
+        let handler = ImportActivityHandler::new();
+        assert_eq!(handler.name(), "import_activity");
+        assert!(!handler.requires_idempotency_token());
+        assert!(handler.accepts_idempotency_token());
+        assert_eq!(handler.input_schema()["required"], json!(["path"]));
+    }
+
+    #[tokio::test]
+    async fn test_execute_uploads_new_file() {
+        let (_dir, path) = gpx_file();
+        let mock = existing("2026-03-10T09:00:00Z");
+        let observations = mock.observations();
+
+        let output = ImportActivityHandler::new()
+            .execute(
+                json!({"path": path, "name": "Tempo", "idempotency_token": "t1"}),
+                Arc::new(mock),
+                None,
+            )
+            .await
+            .unwrap();
+        let rendered = format!("{:?}", output.content);
+
+        assert!(rendered.contains("Uploaded to Intervals.icu."));
+        assert!(rendered.contains("i9001"));
+        let uploads = observations.uploads();
+        assert_eq!(uploads.len(), 1);
+        assert_eq!(uploads[0].name.as_deref(), Some("Tempo"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_skips_duplicate_start_time() {
+        let (_dir, path) = gpx_file();
+        let mock = existing("2026-03-10T06:00:30Z");
+        let observations = mock.observations();
+
+        let output = ImportActivityHandler::new()
+            .execute(
+                json!({"path": path, "idempotency_token": "t1"}),
+                Arc::new(mock),
+                None,
+            )
+            .await
+            .unwrap();
+        let rendered = format!("{:?}", output.content);
+
+        assert!(rendered.contains("Skipped: Morning Run (i1)"));
+        assert!(observations.uploads().is_empty());
+        assert!(output.next_actions[1].contains("allow_duplicate"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_unsupported_files_and_dry_run_uploads_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let notes = dir.path().join("notes.txt");
+        std::fs::write(&notes, "hello").unwrap();
+        let result = ImportActivityHandler::new()
+            .execute(
+                json!({"path": notes.to_string_lossy(), "idempotency_token": "t1"}),
+                Arc::new(MockIntervalsClient::builder()),
+                None,
+            )
+            .await;
+        assert!(matches!(result, Err(IntentError::ValidationError(_))));
+
+        let (_dir, path) = gpx_file();
+        let mock = MockIntervalsClient::builder();
+        let observations = mock.observations();
+        let output = ImportActivityHandler::new()
+            .execute(json!({"path": path, "dry_run": true}), Arc::new(mock), None)
+            .await
+            .unwrap();
+        assert!(format!("{:?}", output.content).contains("Preview only"));
+        assert!(observations.uploads().is_empty());
+    }
+}
@@ -0,0 +1,694 @@
+use crate::intents::{ContentBlock, IdempotencyCache, IntentError, IntentHandler, IntentOutput};
+use async_trait::async_trait;
+use chrono::{Local, NaiveDate};
+use intervals_icu_client::IntervalsClient;
+use serde_json::{Map, Value, json};
+/// Log Wellness Intent Handler
+///
+/// Writes daily subjective and objective wellness data (single day, backfilled
+/// range or per-day entries) and re-runs the readiness summary.
+use std::sync::Arc;
+
+use super::AssessRecoveryHandler;
+use crate::intents::utils::parse_date;
+
+/// Longest date range accepted for a single backfill.
+const MAX_BACKFILL_DAYS: i64 = 92;
+/// Readiness window re-assessed after a write.
+const READINESS_PERIOD_DAYS: i64 = 7;
+
+/// Natural input field mapped to its Intervals.icu wellness key.
+struct WellnessField {
+    input: &'static str,
+    key: &'static str,
+    label: &'static str,
+    min: f64,
+    max: f64,
+    integer: bool,
+}
+
+/// Intervals.icu subjective scales run 1–4 with 1 as the best state
+/// (e.g. soreness 1 = none, 4 = extreme; mood 1 = great, 4 = grumpy).
+const WELLNESS_FIELDS: &[WellnessField] = &[
+    WellnessField {
+        input: "sleep_hours",
+        key: "sleepSecs",
+        label: "Sleep (h)",
+        min: 0.0,
+        max: 24.0,
+        integer: false,
+    },
+    WellnessField {
+        input: "sleep_quality",
+        key: "sleepQuality",
+        label: "Sleep quality (1-4)",
+        min: 1.0,
+        max: 4.0,
+        integer: true,
+    },
+    WellnessField {
+        input: "soreness",
+        key: "soreness",
+        label: "Soreness (1-4)",
+        min: 1.0,
+        max: 4.0,
+        integer: true,
+    },
+    WellnessField {
+        input: "fatigue",
+        key: "fatigue",
+        label: "Fatigue (1-4)",
+        min: 1.0,
+        max: 4.0,
+        integer: true,
+    },
+    WellnessField {
+        input: "stress",
+        key: "stress",
+        label: "Stress (1-4)",
+        min: 1.0,
+        max: 4.0,
+        integer: true,
+    },
+    WellnessField {
+        input: "mood",
+        key: "mood",
+        label: "Mood (1-4)",
+        min: 1.0,
+        max: 4.0,
+        integer: true,
+    },
+    WellnessField {
+        input: "motivation",
+        key: "motivation",
+        label: "Motivation (1-4)",
+        min: 1.0,
+        max: 4.0,
+        integer: true,
+    },
+    WellnessField {
+        input: "injury",
+        key: "injury",
+        label: "Injury (1-4)",
+        min: 1.0,
+        max: 4.0,
+        integer: true,
+    },
+    WellnessField {
+        input: "weight_kg",
+        key: "weight",
+        label: "Weight (kg)",
+        min: 20.0,
+        max: 300.0,
+        integer: false,
+    },
+    WellnessField {
+        input: "hrv",
+        key: "hrv",
+        label: "HRV rMSSD (ms)",
+        min: 1.0,
+        max: 300.0,
+        integer: false,
+    },
+    WellnessField {
+        input: "resting_hr",
+        key: "restingHR",
+        label: "Resting HR (bpm)",
+        min: 20.0,
+        max: 150.0,
+        integer: true,
+    },
+];
+
+const MENSTRUAL_PHASES: [&str; 5] = ["period", "follicular", "ovulating", "luteal", "none"];
+
+pub struct LogWellnessHandler;
+
+impl LogWellnessHandler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// One day of validated wellness data: display rows plus the API payload.
+#[derive(Debug, Clone, PartialEq)]
+struct WellnessEntry {
+    date: NaiveDate,
+    rows: Vec<(String, String)>,
+    payload: Map<String, Value>,
+}
+
+impl WellnessEntry {
+    fn api_record(&self) -> Value {
+        let mut record = self.payload.clone();
+        record.insert("id".into(), json!(self.date.to_string()));
+        Value::Object(record)
+    }
+}
+
+/// Display rows (label, value) and the Intervals.icu payload for one day.
+type MappedWellness = (Vec<(String, String)>, Map<String, Value>);
+
+/// Validate and map the natural wellness fields of `source` to Intervals.icu keys.
+fn map_wellness_fields(source: &Value, context: &str) -> Result<MappedWellness, IntentError> {
+    let mut rows = Vec::new();
+    let mut payload = Map::new();
+
+    for field in WELLNESS_FIELDS {
+        let Some(raw) = source.get(field.input).filter(|value| !value.is_null()) else {
+            continue;
+        };
+        let value = raw.as_f64().ok_or_else(|| {
+            IntentError::validation(format!("{context}{} must be a number.", field.input))
+        })?;
+        if !value.is_finite() || value < field.min || value > field.max {
+            return Err(IntentError::validation(format!(
+                "{context}{} must be between {} and {} (got {value}).",
+                field.input, field.min, field.max
+            )));
+        }
+        if field.integer && value.fract() != 0.0 {
+            return Err(IntentError::validation(format!(
+                "{context}{} must be a whole number (got {value}).",
+                field.input
+            )));
+        }
+        let api_value = match field.input {
+            "sleep_hours" => json!((value * 3600.0).round() as i64),
+            _ if field.integer => json!(value as i64),
+            _ => json!(value),
+        };
+        payload.insert(field.key.into(), api_value);
+        rows.push((field.label.to_string(), format_number(value)));
+    }
+
+    if let Some(raw) = source
+        .get("menstrual_phase")
+        .filter(|value| !value.is_null())
+    {
+        let phase = raw
+            .as_str()
+            .map(str::to_ascii_lowercase)
+            .filter(|phase| MENSTRUAL_PHASES.contains(&phase.as_str()))
+            .ok_or_else(|| {
+                IntentError::validation(format!(
+                    "{context}menstrual_phase must be one of: {}.",
+                    MENSTRUAL_PHASES.join(", ")
+                ))
+            })?;
+        payload.insert("menstrualPhase".into(), json!(phase.to_ascii_uppercase()));
+        rows.push(("Menstrual phase".into(), phase));
+    }
+
+    if let Some(raw) = source.get("comments").filter(|value| !value.is_null()) {
+        let comments = raw.as_str().map(str::trim).ok_or_else(|| {
+            IntentError::validation(format!("{context}comments must be a string."))
+        })?;
+        if !comments.is_empty() {
+            payload.insert("comments".into(), json!(comments));
+            rows.push(("Comments".into(), comments.to_string()));
+        }
+    }
+
+    Ok((rows, payload))
+}
+
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+/// Reject dates in the future; wellness is logged for today or earlier.
+fn ensure_not_future(date: NaiveDate, today: NaiveDate, field: &str) -> Result<(), IntentError> {
+    if date > today {
+        return Err(IntentError::validation(format!(
+            "{field} {date} is in the future; wellness can only be logged for today or earlier."
+        )));
+    }
+    Ok(())
+}
+
+/// Build the per-day entries from `entries`, a `date`/`end_date` range, or a single day.
+fn collect_entries(input: &Value, today: NaiveDate) -> Result<Vec<WellnessEntry>, IntentError> {
+    if let Some(entries) = input.get("entries").filter(|value| !value.is_null()) {
+        let entries = entries
+            .as_array()
+            .filter(|entries| !entries.is_empty())
+            .ok_or_else(|| IntentError::validation("entries must be a non-empty array."))?;
+        if entries.len() as i64 > MAX_BACKFILL_DAYS {
+            return Err(IntentError::validation(format!(
+                "entries is limited to {MAX_BACKFILL_DAYS} days per call."
+            )));
+        }
+        let mut collected: Vec<WellnessEntry> = Vec::with_capacity(entries.len());
+        for (index, entry) in entries.iter().enumerate() {
+            let context = format!("entries[{index}].");
+            let date_str = entry.get("date").and_then(Value::as_str).ok_or_else(|| {
+                IntentError::validation(format!("Missing required field: {context}date"))
+            })?;
+            let date = parse_date(date_str, &format!("{context}date"))?;
+            ensure_not_future(date, today, &format!("{context}date"))?;
+            if collected.iter().any(|existing| existing.date == date) {
+                return Err(IntentError::validation(format!(
+                    "entries contains {date} more than once."
+                )));
+            }
+            let (rows, payload) = map_wellness_fields(entry, &context)?;
+            if payload.is_empty() {
+                return Err(IntentError::validation(format!(
+                    "entries[{index}] ({date}) has no wellness fields to log."
+                )));
+            }
+            collected.push(WellnessEntry {
+                date,
+                rows,
+                payload,
+            });
+        }
+        collected.sort_by_key(|entry| entry.date);
+        return Ok(collected);
+    }
+
+    let start = match input.get("date").and_then(Value::as_str) {
+        Some(date) => parse_date(date, "date")?,
+        None => today,
+    };
+    ensure_not_future(start, today, "date")?;
+    let end = match input.get("end_date").and_then(Value::as_str) {
+        Some(date) => parse_date(date, "end_date")?,
+        None => start,
+    };
+    ensure_not_future(end, today, "end_date")?;
+    if end < start {
+        return Err(IntentError::validation("end_date must not be before date."));
+    }
+    let days = (end - start).num_days() + 1;
+    if days > MAX_BACKFILL_DAYS {
+        return Err(IntentError::validation(format!(
+            "Backfill range is {days} days; the limit is {MAX_BACKFILL_DAYS}."
+        )));
+    }
+
+    let (rows, payload) = map_wellness_fields(input, "")?;
+    if payload.is_empty() {
+        return Err(IntentError::validation(format!(
+            "No wellness fields provided. Supply at least one of: {}, menstrual_phase, comments.",
+            WELLNESS_FIELDS
+                .iter()
+                .map(|field| field.input)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+
+    Ok(start
+        .iter_days()
+        .take(days as usize)
+        .map(|date| WellnessEntry {
+            date,
+            rows: rows.clone(),
+            payload: payload.clone(),
+        })
+        .collect())
+}
+
+fn render_entries(entries: &[WellnessEntry], dry_run: bool) -> Vec<ContentBlock> {
+    let first = entries.first().map(|entry| entry.date);
+    let last = entries.last().map(|entry| entry.date);
+    let dates = match (first, last) {
+        (Some(first), Some(last)) if first != last => {
+            format!("{first} → {last} ({} days)", entries.len())
+        }
+        (Some(first), _) => first.to_string(),
+        _ => "-".into(),
+    };
+    let status = if dry_run {
+        "Preview only (dry_run) - nothing written."
+    } else {
+        "Saved to Intervals.icu."
+    };
+    let mut content = vec![ContentBlock::markdown(format!(
+        "# Wellness Log\n\n**Dates:** {dates}\n\n{status}"
+    ))];
+
+    let uniform = entries.windows(2).all(|pair| pair[0].rows == pair[1].rows);
+    if uniform {
+        let rows = entries
+            .first()
+            .map(|entry| {
+                entry
+                    .rows
+                    .iter()
+                    .map(|(label, value)| vec![label.clone(), value.clone()])
+                    .collect()
+            })
+            .unwrap_or_default();
+        content.push(ContentBlock::table(
+            vec!["Field".into(), "Value".into()],
+            rows,
+        ));
+    } else {
+        let rows = entries
+            .iter()
+            .map(|entry| {
+                vec![
+                    entry.date.to_string(),
+                    entry
+                        .rows
+                        .iter()
+                        .map(|(label, value)| format!("{label}: {value}"))
+                        .collect::<Vec<_>>()
+                        .join("; "),
+                ]
+            })
+            .collect();
+        content.push(ContentBlock::table(
+            vec!["Date".into(), "Fields".into()],
+            rows,
+        ));
+    }
+    content
+}
+
+#[async_trait]
+impl IntentHandler for LogWellnessHandler {
+    fn name(&self) -> &'static str {
+        "log_wellness"
+    }
+
+    fn description(&self) -> &'static str {
+        "Logs daily wellness data to Intervals.icu: sleep, sleep quality, soreness, fatigue, \
+         stress, mood, motivation, injury (Intervals 1-4 scales, 1 = best), weight, HRV, \
+         resting HR, menstrual phase and comments. Use when the athlete reports how they slept \
+         or feel (\"slept 5h, legs very sore, weight 71.2\"). Backfill a range with \
+         date/end_date (same values each day) or pass per-day `entries`. After saving, the \
+         recovery readiness summary is re-run. Prefer `dry_run: true` to preview. \
+         Saving requires an idempotency token; a dry_run preview does not."
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "date": {"type": "string", "description": "Day to log (YYYY-MM-DD, 'today' or 'yesterday'; default today). Range start when end_date is set."},
+                "end_date": {"type": "string", "description": "Last day of a backfill range; every day gets the same values"},
+                "entries": {
+                    "type": "array",
+                    "description": "Per-day entries for backfill; each has a date plus any wellness fields",
+                    "items": {"type": "object", "required": ["date"]}
+                },
+                "sleep_hours": {"type": "number", "minimum": 0, "maximum": 24, "description": "Hours slept"},
+                "sleep_quality": {"type": "integer", "minimum": 1, "maximum": 4, "description": "1 = great, 4 = poor"},
+                "soreness": {"type": "integer", "minimum": 1, "maximum": 4, "description": "1 = none, 4 = extreme"},
+                "fatigue": {"type": "integer", "minimum": 1, "maximum": 4, "description": "1 = fresh, 4 = extreme"},
+                "stress": {"type": "integer", "minimum": 1, "maximum": 4, "description": "1 = low, 4 = extreme"},
+                "mood": {"type": "integer", "minimum": 1, "maximum": 4, "description": "1 = great, 4 = grumpy"},
+                "motivation": {"type": "integer", "minimum": 1, "maximum": 4, "description": "1 = extreme, 4 = none"},
+                "injury": {"type": "integer", "minimum": 1, "maximum": 4, "description": "1 = none, 4 = serious"},
+                "weight_kg": {"type": "number", "minimum": 20, "maximum": 300, "description": "Body weight in kg"},
+                "hrv": {"type": "number", "minimum": 1, "maximum": 300, "description": "HRV (rMSSD, ms)"},
+                "resting_hr": {"type": "integer", "minimum": 20, "maximum": 150, "description": "Resting heart rate (bpm)"},
+                "menstrual_phase": {"type": "string", "enum": MENSTRUAL_PHASES, "description": "Menstrual cycle phase"},
+                "comments": {"type": "string", "description": "Free-text note for the day"},
+                "dry_run": {"type": "boolean", "default": false, "description": "Preview the mapped values without writing"},
+                "idempotency_token": {"type": "string", "description": "Idempotency token (required unless dry_run)"}
+            },
+            "required": []
+        })
+    }
+
+    async fn execute(
+        &self,
+        input: Value,
+        client: Arc<dyn IntervalsClient>,
+        _cache: Option<&IdempotencyCache>,
+    ) -> Result<IntentOutput, IntentError> {
+        let dry_run = input
+            .get("dry_run")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        if !dry_run {
+            input
+                .get("idempotency_token")
+                .and_then(Value::as_str)
+                .ok_or_else(|| {
+                    IntentError::validation("Missing required field: idempotency_token")
+                })?;
+        }
+
+        let today = Local::now().date_naive();
+        let entries = collect_entries(&input, today)?;
+        let mut content = render_entries(&entries, dry_run);
+
+        if dry_run {
+            return Ok(IntentOutput::new(content).with_next_actions(vec![
+                "To save: call log_wellness again without dry_run and with an idempotency_token."
+                    .into(),
+            ]));
+        }
+
+        match entries.as_slice() {
+            [single] => {
+                client
+                    .update_wellness(
+                        &single.date.to_string(),
+                        &Value::Object(single.payload.clone()),
+                    )
+                    .await
+                    .map_err(|e| IntentError::api(format!("Failed to update wellness: {e}")))?;
+            }
+            _ => {
+                let records = entries
+                    .iter()
+                    .map(WellnessEntry::api_record)
+                    .collect::<Vec<_>>();
+                client
+                    .update_wellness_bulk(&records)
+                    .await
+                    .map_err(|e| IntentError::api(format!("Failed to update wellness: {e}")))?;
+            }
+        }
+
+        let readiness = AssessRecoveryHandler::new()
+            .execute(json!({"period_days": READINESS_PERIOD_DAYS}), client, None)
+            .await;
+        let output = match readiness {
+            Ok(readiness) => {
+                content.push(ContentBlock::markdown("## Readiness After Update"));
+                content.extend(readiness.content);
+                IntentOutput::new(content)
+                    .with_suggestions(readiness.suggestions)
+                    .with_next_actions(readiness.next_actions)
+            }
+            Err(error) => IntentOutput::new(content).with_note(format!(
+                "Wellness saved, but the readiness summary could not be refreshed: {error}"
+            )),
+        };
+        Ok(output)
+    }
+
+    fn requires_idempotency_token(&self) -> bool {
+        false
+    }
+
+    /// A dry_run preview needs no token; saves check for one in `execute` and
+    /// are deduplicated by it.
+    fn accepts_idempotency_token(&self) -> bool {
+        true
+    }
+}
+
+impl Default for LogWellnessHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::mock::MockIntervalsClient;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 10).unwrap()
+    }
+
+    #[test]
+    fn test_name_and_token_requirement() {
+        let handler = LogWellnessHandler::new();
+        assert_eq!(handler.name(), "log_wellness");
+        assert!(!handler.requires_idempotency_token());
+        assert!(handler.accepts_idempotency_token());
+        let schema = handler.input_schema();
+        assert!(schema["properties"]["menstrual_phase"]["enum"].is_array());
+        assert_eq!(schema["required"], json!([]));
+    }
+
+    #[test]
+    fn test_maps_natural_fields_to_wellness_keys() {
+        let input = json!({
+            "sleep_hours": 5.5,
+            "soreness": 4,
+            "weight_kg": 71.2,
+            "resting_hr": 48,
+            "menstrual_phase": "Luteal",
+            "comments": "  heavy legs  "
+        });
+        let (rows, payload) = map_wellness_fields(&input, "").unwrap();
+        assert_eq!(payload["sleepSecs"], json!(19800));
+        assert_eq!(payload["soreness"], json!(4));
+        assert_eq!(payload["weight"], json!(71.2));
+        assert_eq!(payload["restingHR"], json!(48));
+        assert_eq!(payload["menstrualPhase"], json!("LUTEAL"));
+        assert_eq!(payload["comments"], json!("heavy legs"));
+        assert_eq!(rows.len(), 6);
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_and_fractional_scales() {
+        let err = map_wellness_fields(&json!({"soreness": 5}), "").unwrap_err();
+        assert!(err.to_string().contains("between 1 and 4"));
+        let err = map_wellness_fields(&json!({"mood": 2.5}), "").unwrap_err();
+        assert!(err.to_string().contains("whole number"));
+        let err = map_wellness_fields(&json!({"sleep_hours": 30}), "").unwrap_err();
+        assert!(err.to_string().contains("sleep_hours"));
+        let err = map_wellness_fields(&json!({"menstrual_phase": "late"}), "").unwrap_err();
+        assert!(err.to_string().contains("menstrual_phase"));
+    }
+
+    #[test]
+    fn test_collect_entries_backfills_range() {
+        let input = json!({"date": "2026-03-01", "end_date": "2026-03-03", "fatigue": 3});
+        let entries = collect_entries(&input, today()).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[2].date.to_string(), "2026-03-03");
+        assert_eq!(entries[0].api_record()["id"], json!("2026-03-01"));
+        assert_eq!(entries[0].api_record()["fatigue"], json!(3));
+    }
+
+    #[test]
+    fn test_collect_entries_validates_dates_and_fields() {
+        assert!(collect_entries(&json!({"mood": 2}), today()).is_ok());
+        assert!(collect_entries(&json!({}), today()).is_err());
+        assert!(collect_entries(&json!({"date": "2026-03-11", "mood": 2}), today()).is_err());
+        assert!(
+            collect_entries(
+                &json!({"date": "2026-03-05", "end_date": "2026-03-01", "mood": 2}),
+                today()
+            )
+            .is_err()
+        );
+        assert!(
+            collect_entries(
+                &json!({"date": "2025-01-01", "end_date": "2026-03-01", "mood": 2}),
+                today()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_collect_entries_from_per_day_list() {
+        let input = json!({"entries": [
+            {"date": "2026-03-02", "hrv": 62},
+            {"date": "2026-03-01", "hrv": 55, "sleep_hours": 7}
+        ]});
+        let entries = collect_entries(&input, today()).unwrap();
+        assert_eq!(entries[0].date.to_string(), "2026-03-01");
+        assert_eq!(entries[0].payload["sleepSecs"], json!(25200));
+
+        let duplicate = json!({"entries": [
+            {"date": "2026-03-02", "hrv": 62},
+            {"date": "2026-03-02", "hrv": 55}
+        ]});
+        assert!(collect_entries(&duplicate, today()).is_err());
+        let empty_fields = json!({"entries": [{"date": "2026-03-02"}]});
+        assert!(collect_entries(&empty_fields, today()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute_requires_token_unless_dry_run() {
+        let handler = LogWellnessHandler::new();
+        let client = Arc::new(MockIntervalsClient::default());
+        let err = handler
+            .execute(json!({"mood": 2}), client.clone(), None)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("idempotency_token"));
+
+        let output = handler
+            .execute(json!({"mood": 2, "dry_run": true}), client, None)
+            .await
+            .unwrap();
+        assert!(!output.next_actions.is_empty());
+    }
+
+    fn readiness_client() -> MockIntervalsClient {
+        MockIntervalsClient::builder()
+            .with_wellness(json!([
+                {"sleep_hours": 8.0, "resting_hr": 50.0, "hrv": 70.0}
+            ]))
+            .with_fitness_summary(json!({"form": 5.0}))
+            .with_activities(vec![intervals_icu_client::ActivitySummary {
+                id: "act-1".into(),
+                name: Some("Easy Run".into()),
+                start_date_local: Local::now().date_naive().to_string(),
+                ..Default::default()
+            }])
+    }
+
+    #[tokio::test]
+    async fn test_execute_writes_and_appends_readiness() {
+        let handler = LogWellnessHandler::new();
+        let client = Arc::new(readiness_client());
+        let observations = client.observations.clone();
+        let output = handler
+            .execute(
+                json!({"sleep_hours": 5, "soreness": 4, "idempotency_token": "w-1"}),
+                client.clone(),
+                None,
+            )
+            .await
+            .unwrap();
+        let rendered = format!("{:?}", output.content);
+        assert!(rendered.contains("Saved to Intervals.icu"));
+        assert!(rendered.contains("Readiness After Update"));
+        assert!(rendered.contains("Recovery Assessment"));
+        assert!(output.note.is_none());
+
+        let today = Local::now().date_naive();
+        assert_eq!(
+            observations.wellness_updates(),
+            [(
+                today.to_string(),
+                json!({"sleepSecs": 18000, "soreness": 4})
+            )]
+        );
+
+        let yesterday = today - chrono::Duration::days(1);
+        let output = handler
+            .execute(
+                json!({
+                    "date": yesterday.to_string(),
+                    "end_date": "today",
+                    "stress": 3,
+                    "idempotency_token": "w-2"
+                }),
+                client,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(format!("{:?}", output.content).contains("2 days"));
+        assert_eq!(
+            observations.wellness_bulk_updates(),
+            [vec![
+                json!({"id": yesterday.to_string(), "stress": 3}),
+                json!({"id": today.to_string(), "stress": 3}),
+            ]]
+        );
+    }
+}
@@ -1,13 +1,24 @@
+use crate::engines::gear_wear::{
+    GEAR_USAGE_WINDOW_DAYS, GearWearForecast, activity_gear_km, forecast_gear_wear, planned_runs,
+    shoe_usage, value_id,
+};
 use crate::intents::{ContentBlock, IdempotencyCache, IntentError, IntentHandler, IntentOutput};
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{Duration, NaiveDate, Utc};
 use intervals_icu_client::IntervalsClient;
 use serde_json::{Value, json};
+use std::collections::HashMap;
 /// Manage Gear Intent Handler
 ///
-/// Manages athlete gear (view, add, retire).
+/// Manages athlete gear (view, add, retire, reminders, wear forecast).
 use std::sync::Arc;
 
+const FORECAST_DEFAULT_PLAN_DAYS: u32 = 56;
+const FORECAST_MIN_PLAN_DAYS: u32 = 7;
+const FORECAST_MAX_PLAN_DAYS: u32 = 180;
+const FORECAST_ACTIVITY_LIMIT: u32 = 60;
+const DEFAULT_SNOOZE_DAYS: u32 = 7;
+
 pub struct ManageGearHandler;
 impl ManageGearHandler {
     pub fn new() -> Self {
@@ -23,19 +34,30 @@ impl IntentHandler for ManageGearHandler {
 
     fn description(&self) -> &'static str {
         "Manages athlete gear (view, add, retire). \
-         Use for tracking shoe mileage, managing bikes, and monitoring gear wear."
+         Use for tracking shoe mileage, managing bikes, and monitoring gear wear. \
+         forecast projects each shoe's retirement date from recent weekly mileage plus \
+         planned runs and warns when a planned race falls past a shoe's limit; \
+         create_reminder / reset_reminder / snooze_reminder manage distance or time \
+         reminders, and assign sets the gear on activities."
     }
 
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
             "properties": {
-                "action": {"type": "string", "enum": ["list", "add", "retire"], "description": "Action to perform"},
+                "action": {"type": "string", "enum": ["list", "add", "retire", "forecast", "create_reminder", "reset_reminder", "snooze_reminder", "assign"], "description": "Action to perform"},
                 "gear_type": {"type": "string", "enum": ["shoes", "bike", "watch", "other"], "description": "Gear type"},
-                "gear_name": {"type": "string", "description": "Gear name (for retire)"},
+                "gear_name": {"type": "string", "description": "Gear name (for retire, reminders, assign)"},
                 "new_gear_name": {"type": "string", "description": "New gear name (for add)"},
                 "new_gear_type": {"type": "string", "description": "New gear type (for add)"},
-                "idempotency_token": {"type": "string", "description": "Idempotency token (required for add/retire)"}
+                "reminder_id": {"type": "string", "description": "Reminder ID for reset/snooze (defaults to the gear's first reminder)"},
+                "reminder_name": {"type": "string", "description": "Reminder name (for create_reminder)"},
+                "reminder_distance_km": {"type": "number", "description": "Distance reminder limit in km (for create_reminder)"},
+                "reminder_hours": {"type": "number", "description": "Time reminder limit in hours (for create_reminder)"},
+                "snooze_days": {"type": "integer", "minimum": 1, "default": DEFAULT_SNOOZE_DAYS, "description": "Days to snooze a reminder"},
+                "activity_ids": {"type": "array", "items": {"type": "string"}, "description": "Activities to assign the gear to (for assign)"},
+                "plan_days": {"type": "integer", "minimum": FORECAST_MIN_PLAN_DAYS, "maximum": FORECAST_MAX_PLAN_DAYS, "default": FORECAST_DEFAULT_PLAN_DAYS, "description": "Calendar horizon of planned runs used by forecast (days)"},
+                "idempotency_token": {"type": "string", "description": "Idempotency token (required for add/retire/reminder/assign actions)"}
             },
             "required": ["action"]
         })
@@ -54,26 +76,26 @@ impl IntentHandler for ManageGearHandler {
 
         match action {
             "list" => self.list_gear(&input, client.as_ref()).await,
-            "add" => {
+            "forecast" => self.forecast_gear(&input, client.as_ref()).await,
+            "add" | "retire" | "create_reminder" | "reset_reminder" | "snooze_reminder"
+            | "assign" => {
                 let _token = input
                     .get("idempotency_token")
                     .and_then(Value::as_str)
                     .ok_or_else(|| {
                         IntentError::validation("Missing required field: idempotency_token")
                     })?;
-                self.add_gear(&input, client.as_ref()).await
-            }
-            "retire" => {
-                let _token = input
-                    .get("idempotency_token")
-                    .and_then(Value::as_str)
-                    .ok_or_else(|| {
-                        IntentError::validation("Missing required field: idempotency_token")
-                    })?;
-                self.retire_gear(&input, client.as_ref()).await
+                match action {
+                    "add" => self.add_gear(&input, client.as_ref()).await,
+                    "retire" => self.retire_gear(&input, client.as_ref()).await,
+                    "create_reminder" => self.create_reminder(&input, client.as_ref()).await,
+                    "assign" => self.assign_gear(&input, client.as_ref()).await,
+                    _ => self.update_reminder(action, &input, client.as_ref()).await,
+                }
             }
             _ => Err(IntentError::validation(format!(
-                "Invalid action: {}. Must be 'list', 'add', or 'retire'",
+                "Invalid action: {}. Must be 'list', 'add', 'retire', 'forecast', \
+                 'create_reminder', 'reset_reminder', 'snooze_reminder', or 'assign'",
                 action
             ))),
         }
@@ -82,6 +104,12 @@ impl IntentHandler for ManageGearHandler {
     fn requires_idempotency_token(&self) -> bool {
         false
     }
+
+    /// `list` and `forecast` need no token; the add, retire, reminder and
+    /// assign writes are deduplicated when one is supplied.
+    fn accepts_idempotency_token(&self) -> bool {
+        true
+    }
 }
 
 impl ManageGearHandler {
@@ -301,54 +329,457 @@ impl ManageGearHandler {
             .as_array()
             .ok_or_else(|| IntentError::api("Invalid gear list format".to_string()))?;
 
-        let target_gear = gear_array.iter().find(|g| {
-            g.get("name")
-                .and_then(|n| n.as_str())
-                .map(|n| n.to_lowercase() == gear_name.to_lowercase())
-                .unwrap_or(false)
-        });
+        let gear = Self::find_gear(gear_array, gear_name)?;
+        let gear_id = gear
+            .get("id")
+            .and_then(|id| id.as_str())
+            .ok_or_else(|| IntentError::api("Gear has no ID".to_string()))?;
+
+        let mut updated = gear.clone();
+        updated["retired"] = Value::String(Utc::now().date_naive().to_string());
+
+        client
+            .update_gear(gear_id, &updated)
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to retire gear: {}", e)))?;
+
+        let mut content = Vec::new();
+        content.push(ContentBlock::markdown(format!(
+            "# Retire Gear\nName: {}\nID: {}\nRetired via Intervals.icu API.",
+            gear_name, gear_id
+        )));
 
-        if let Some(gear) = target_gear {
-            let gear_id = gear
+        let suggestions = vec![format!(
+            "{} is now excluded from active gear rotation.",
+            gear_name
+        )];
+        let next_actions = vec!["View updated list: manage_gear action: list".into()];
+
+        Ok(IntentOutput::new(content)
+            .with_suggestions(suggestions)
+            .with_next_actions(next_actions))
+    }
+
+    /// Gear whose name matches `gear_name` exactly (case-insensitive).
+    pub(crate) fn find_gear<'a>(
+        gear_array: &'a [Value],
+        gear_name: &str,
+    ) -> Result<&'a Value, IntentError> {
+        gear_array
+            .iter()
+            .find(|g| {
+                g.get("name")
+                    .and_then(|n| n.as_str())
+                    .map(|n| n.to_lowercase() == gear_name.to_lowercase())
+                    .unwrap_or(false)
+            })
+            .ok_or_else(|| {
+                let available: Vec<&str> = gear_array
+                    .iter()
+                    .filter_map(|g| g.get("name").and_then(|n| n.as_str()))
+                    .collect();
+                IntentError::validation(format!(
+                    "Gear '{}' not found. Available gear: {}",
+                    gear_name,
+                    available.join(", ")
+                ))
+            })
+    }
+
+    /// Resolve `gear_name` to its gear object and ID.
+    async fn lookup_gear(
+        input: &Value,
+        client: &dyn IntervalsClient,
+    ) -> Result<(Value, String), IntentError> {
+        let gear_name = input
+            .get("gear_name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| IntentError::validation("Missing required field: gear_name"))?;
+        let gear_list = client
+            .get_gear_list()
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to fetch gear: {}", e)))?;
+        let gear_array = gear_list
+            .as_array()
+            .ok_or_else(|| IntentError::api("Invalid gear list format".to_string()))?;
+        let gear = Self::find_gear(gear_array, gear_name)?.clone();
+        let gear_id = gear
+            .get("id")
+            .and_then(value_id)
+            .ok_or_else(|| IntentError::api("Gear has no ID".to_string()))?;
+        Ok((gear, gear_id))
+    }
+
+    async fn create_reminder(
+        &self,
+        input: &Value,
+        client: &dyn IntervalsClient,
+    ) -> Result<IntentOutput, IntentError> {
+        let distance_km = input
+            .get("reminder_distance_km")
+            .and_then(Value::as_f64)
+            .filter(|km| *km > 0.0);
+        let hours = input
+            .get("reminder_hours")
+            .and_then(Value::as_f64)
+            .filter(|hours| *hours > 0.0);
+        if distance_km.is_none() && hours.is_none() {
+            return Err(IntentError::validation(
+                "Missing required field: reminder_distance_km or reminder_hours",
+            ));
+        }
+        let (gear, gear_id) = Self::lookup_gear(input, client).await?;
+        let gear_name = gear.get("name").and_then(Value::as_str).unwrap_or("Gear");
+        let reminder_name = input
+            .get("reminder_name")
+            .and_then(Value::as_str)
+            .map(str::to_owned)
+            .unwrap_or_else(|| format!("Replace {}", gear_name));
+
+        let mut reminder = json!({"name": reminder_name});
+        if let Some(km) = distance_km {
+            reminder["distance"] = json!(km * 1000.0);
+        }
+        if let Some(hours) = hours {
+            reminder["time"] = json!((hours * 3600.0).round());
+        }
+        let created = client
+            .create_gear_reminder(&gear_id, &reminder)
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to create gear reminder: {}", e)))?;
+
+        let limits = [
+            distance_km.map(|km| format!("{:.0} km", km)),
+            hours.map(|hours| format!("{:.0} h", hours)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" / ");
+        let content = vec![ContentBlock::markdown(format!(
+            "# Gear Reminder\nGear: {}\nReminder: {}\nLimit: {}\nID: {}\nCreated via Intervals.icu API.",
+            gear_name,
+            reminder_name,
+            limits,
+            created
                 .get("id")
-                .and_then(|id| id.as_str())
-                .ok_or_else(|| IntentError::api("Gear has no ID".to_string()))?;
+                .and_then(value_id)
+                .unwrap_or_else(|| "pending".into())
+        ))];
+
+        Ok(IntentOutput::new(content)
+            .with_suggestions(vec![format!(
+                "{} will be flagged once it reaches {}.",
+                gear_name, limits
+            )])
+            .with_next_actions(vec![
+                "To forecast retirement: manage_gear action: forecast".into(),
+            ]))
+    }
+
+    async fn update_reminder(
+        &self,
+        action: &str,
+        input: &Value,
+        client: &dyn IntervalsClient,
+    ) -> Result<IntentOutput, IntentError> {
+        let reset = action == "reset_reminder";
+        let snooze_days = if reset {
+            0
+        } else {
+            input
+                .get("snooze_days")
+                .and_then(Value::as_u64)
+                .map(|days| days.max(1) as u32)
+                .unwrap_or(DEFAULT_SNOOZE_DAYS)
+        };
+        let (gear, gear_id) = Self::lookup_gear(input, client).await?;
+        let gear_name = gear.get("name").and_then(Value::as_str).unwrap_or("Gear");
+        let reminders = gear
+            .get("reminders")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        let reminder = match input.get("reminder_id").and_then(value_id) {
+            Some(id) => reminders
+                .iter()
+                .find(|reminder| reminder.get("id").and_then(value_id).as_ref() == Some(&id))
+                .cloned()
+                .ok_or_else(|| {
+                    let available: Vec<String> = reminders
+                        .iter()
+                        .filter_map(|reminder| reminder.get("id").and_then(value_id))
+                        .collect();
+                    IntentError::validation(format!(
+                        "Reminder '{}' not found on {}. Available reminder IDs: {}",
+                        id,
+                        gear_name,
+                        if available.is_empty() {
+                            "none".to_string()
+                        } else {
+                            available.join(", ")
+                        }
+                    ))
+                })?,
+            None => reminders.first().cloned().ok_or_else(|| {
+                IntentError::validation(format!(
+                    "{} has no reminders. Create one with manage_gear action: create_reminder",
+                    gear_name
+                ))
+            })?,
+        };
+        let reminder_id = reminder
+            .get("id")
+            .and_then(value_id)
+            .ok_or_else(|| IntentError::api("Reminder has no ID".to_string()))?;
+
+        client
+            .update_gear_reminder(&gear_id, &reminder_id, reset, snooze_days, &json!({}))
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to update gear reminder: {}", e)))?;
 
-            let mut updated = gear.clone();
-            updated["retired"] = Value::String(Utc::now().date_naive().to_string());
+        let reminder_name = reminder
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or("Reminder");
+        let outcome = if reset {
+            "Reset: usage counter restarted from today.".to_string()
+        } else {
+            format!("Snoozed for {} days.", snooze_days)
+        };
+        let content = vec![ContentBlock::markdown(format!(
+            "# Gear Reminder\nGear: {}\nReminder: {} (ID {})\n{}",
+            gear_name, reminder_name, reminder_id, outcome
+        ))];
 
+        Ok(IntentOutput::new(content)
+            .with_suggestions(Vec::new())
+            .with_next_actions(vec!["View updated list: manage_gear action: list".into()]))
+    }
+
+    async fn assign_gear(
+        &self,
+        input: &Value,
+        client: &dyn IntervalsClient,
+    ) -> Result<IntentOutput, IntentError> {
+        let activity_ids = input
+            .get("activity_ids")
+            .and_then(Value::as_array)
+            .map(|ids| ids.iter().filter_map(value_id).collect::<Vec<_>>())
+            .filter(|ids| !ids.is_empty())
+            .ok_or_else(|| IntentError::validation("Missing required field: activity_ids"))?;
+        let (gear, gear_id) = Self::lookup_gear(input, client).await?;
+        let gear_name = gear.get("name").and_then(Value::as_str).unwrap_or("Gear");
+
+        for activity_id in &activity_ids {
             client
-                .update_gear(gear_id, &updated)
+                .update_activity(activity_id, &json!({"gear": {"id": gear_id}}))
                 .await
-                .map_err(|e| IntentError::api(format!("Failed to retire gear: {}", e)))?;
+                .map_err(|e| {
+                    IntentError::api(format!(
+                        "Failed to assign gear to activity {}: {}",
+                        activity_id, e
+                    ))
+                })?;
+        }
 
-            let mut content = Vec::new();
-            content.push(ContentBlock::markdown(format!(
-                "# Retire Gear\nName: {}\nID: {}\nRetired via Intervals.icu API.",
-                gear_name, gear_id
-            )));
+        let content = vec![
+            ContentBlock::markdown(format!(
+                "# Assign Gear\nGear: {}\nAssigned to {} activities via Intervals.icu API.",
+                gear_name,
+                activity_ids.len()
+            )),
+            ContentBlock::table(
+                vec!["Activity".into(), "Gear".into()],
+                activity_ids
+                    .iter()
+                    .map(|id| vec![id.clone(), gear_name.to_string()])
+                    .collect(),
+            ),
+        ];
 
-            let suggestions = vec![format!(
-                "{} is now excluded from active gear rotation.",
+        Ok(IntentOutput::new(content)
+            .with_suggestions(vec![format!(
+                "{} mileage now includes these activities.",
                 gear_name
-            )];
-            let next_actions = vec!["View updated list: manage_gear action: list".into()];
+            )])
+            .with_next_actions(vec![
+                "To forecast retirement: manage_gear action: forecast".into(),
+            ]))
+    }
 
-            Ok(IntentOutput::new(content)
-                .with_suggestions(suggestions)
-                .with_next_actions(next_actions))
-        } else {
-            let available: Vec<&str> = gear_array
+    async fn forecast_gear(
+        &self,
+        input: &Value,
+        client: &dyn IntervalsClient,
+    ) -> Result<IntentOutput, IntentError> {
+        let plan_days = input
+            .get("plan_days")
+            .and_then(Value::as_u64)
+            .map(|days| {
+                days.clamp(
+                    u64::from(FORECAST_MIN_PLAN_DAYS),
+                    u64::from(FORECAST_MAX_PLAN_DAYS),
+                ) as u32
+            })
+            .unwrap_or(FORECAST_DEFAULT_PLAN_DAYS);
+
+        let gear_list = client
+            .get_gear_list()
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to fetch gear: {}", e)))?;
+        let activities = client
+            .get_recent_activities(
+                Some(FORECAST_ACTIVITY_LIMIT),
+                Some(GEAR_USAGE_WINDOW_DAYS as i32),
+            )
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to fetch activities: {}", e)))?;
+
+        let today = Utc::now().date_naive();
+        let mut recent_runs_by_gear: HashMap<String, Vec<(NaiveDate, f64)>> = HashMap::new();
+        let (mut run_km, mut run_hours) = (0.0, 0.0);
+        for activity in &activities {
+            let Some(date) = activity
+                .start_date_local
+                .get(..10)
+                .and_then(|value| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok())
+            else {
+                continue;
+            };
+            let Ok(detail) = client.get_activity_details(&activity.id).await else {
+                continue;
+            };
+            let moving_hours = detail
+                .get("moving_time")
+                .and_then(Value::as_f64)
+                .unwrap_or(0.0)
+                / 3600.0;
+            if let Some((gear_id, km)) = activity_gear_km(&detail) {
+                recent_runs_by_gear
+                    .entry(gear_id)
+                    .or_default()
+                    .push((date, km));
+                if moving_hours > 0.0 {
+                    run_km += km;
+                    run_hours += moving_hours;
+                }
+            }
+        }
+        let run_speed = (run_hours > 0.0).then(|| run_km / run_hours);
+
+        let events = client
+            .get_upcoming_workouts(Some(plan_days), Some(200), None)
+            .await
+            .unwrap_or_else(|_| json!([]));
+        let forecast = forecast_gear_wear(
+            &shoe_usage(
+                &gear_list,
+                &recent_runs_by_gear,
+                today - Duration::days(GEAR_USAGE_WINDOW_DAYS),
+            ),
+            &planned_runs(&events, run_speed),
+            today,
+        );
+        Ok(render_gear_forecast(&forecast))
+    }
+}
+
+fn render_gear_forecast(forecast: &GearWearForecast) -> IntentOutput {
+    let mut content = vec![ContentBlock::markdown(format!(
+        "# Gear Forecast: Shoes\nRecent usage: last {} days. Planned runs: {}.",
+        GEAR_USAGE_WINDOW_DAYS,
+        forecast
+            .planned_until
+            .map(|date| format!("through {}", date))
+            .unwrap_or_else(|| "none on the calendar".into())
+    ))];
+
+    if forecast.shoes.is_empty() {
+        content.push(ContentBlock::markdown("No active shoes found.".to_string()));
+        return IntentOutput::new(content)
+            .with_suggestions(vec!["Add shoes to start tracking wear.".into()])
+            .with_next_actions(vec!["Add new gear: manage_gear action: add".into()]);
+    }
+
+    content.push(ContentBlock::table(
+        vec![
+            "Name".into(),
+            "Distance".into(),
+            "Limit".into(),
+            "Remaining".into(),
+            "Weekly".into(),
+            "Share".into(),
+            "Projected retirement".into(),
+        ],
+        forecast
+            .shoes
+            .iter()
+            .map(|shoe| {
+                vec![
+                    shoe.name.clone(),
+                    if (shoe.wear_km - shoe.distance_km).abs() < 0.05 {
+                        format!("{:.1} km", shoe.distance_km)
+                    } else {
+                        format!(
+                            "{:.1} km ({:.1} since reset)",
+                            shoe.distance_km, shoe.wear_km
+                        )
+                    },
+                    format!(
+                        "{:.0} km{}",
+                        shoe.limit_km,
+                        if shoe.default_limit { " (default)" } else { "" }
+                    ),
+                    format!("{:.1} km", shoe.remaining_km),
+                    format!("{:.1} km", shoe.weekly_km),
+                    format!("{:.0}%", shoe.share * 100.0),
+                    shoe.retirement_date
+                        .map(|date| date.to_string())
+                        .unwrap_or_else(|| "beyond 12 months".into()),
+                ]
+            })
+            .collect(),
+    ));
+
+    let mut suggestions = Vec::new();
+    if !forecast.race_warnings.is_empty() {
+        content.push(ContentBlock::markdown(format!(
+            "Race Warnings\n{}",
+            forecast
+                .race_warnings
                 .iter()
-                .filter_map(|g| g.get("name").and_then(|n| n.as_str()))
-                .collect();
-            Err(IntentError::validation(format!(
-                "Gear '{}' not found. Available gear: {}",
-                gear_name,
-                available.join(", ")
-            )))
+                .map(|warning| format!(
+                    "  ⚠️ {} ({}): {} projected at {:.0} km, past its {:.0} km limit",
+                    warning.race_name,
+                    warning.race_date,
+                    warning.gear_name,
+                    warning.projected_km,
+                    warning.limit_km
+                ))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )));
+        for warning in &forecast.race_warnings {
+            suggestions.push(format!(
+                "Break in a fresh pair before {}: {} will be past its limit on race day.",
+                warning.race_name, warning.gear_name
+            ));
         }
     }
+    if forecast.shoes.iter().any(|shoe| shoe.default_limit) {
+        suggestions.push(format!(
+            "Shoes without a distance reminder use a default {:.0} km limit; set one with manage_gear action: create_reminder.",
+            crate::engines::gear_wear::DEFAULT_SHOE_LIMIT_KM
+        ));
+    }
+
+    IntentOutput::new(content)
+        .with_suggestions(suggestions)
+        .with_next_actions(vec![
+            "To set a limit: manage_gear action: create_reminder gear_name: <name> reminder_distance_km: <km>".into(),
+            "To record shoes on past runs: manage_gear action: assign".into(),
+        ])
 }
 
 impl Default for ManageGearHandler {
@@ -422,6 +853,7 @@ mod tests {
     fn test_requires_idempotency_token() {
         let handler = ManageGearHandler::new();
         assert!(!IntentHandler::requires_idempotency_token(&handler));
+        assert!(IntentHandler::accepts_idempotency_token(&handler));
     }
 
     // ========================================================================
@@ -972,4 +1404,173 @@ mod tests {
         let content_text = format!("{:?}", output.content);
         assert!(content_text.contains("km"));
     }
+
+    fn wear_mock_client() -> MockIntervalsClient {
+        let today = Utc::now().date_naive();
+        let day = |offset: i64| (today + chrono::Duration::days(offset)).to_string();
+        let mut client = MockIntervalsClient::builder()
+            .with_gear_list(json!([
+                {"id": "g1", "name": "Daily Trainer", "type": "Shoes", "distance": 570000.0,
+                 "reminders": [{"id": 11, "name": "Replace", "distance": 600000.0, "percent_used": 95.0}]},
+                {"id": "g2", "name": "Trail Shoe", "type": "Shoes", "distance": 100000.0},
+                {"id": "g3", "name": "Road Bike", "type": "Bike", "distance": 5000000.0}
+            ]))
+            .with_activities(
+                (0..4)
+                    .map(|index| intervals_icu_client::ActivitySummary {
+                        id: format!("run-{}", index),
+                        start_date_local: day(-(index * 5 + 1)),
+                        ..Default::default()
+                    })
+                    .collect(),
+            )
+            .with_upcoming_workouts(json!([
+                {"start_date_local": day(2), "type": "Run", "name": "Long run", "distance": 30000.0},
+                {"start_date_local": day(4), "type": "Run", "name": "Tempo", "moving_time": 3600},
+                {"start_date_local": day(6), "type": "Run", "name": "Spring 10K", "category": "RACE_A", "distance": 10000.0}
+            ]));
+        for index in 0..4 {
+            let gear = if index < 3 { "g1" } else { "g2" };
+            client = client.with_activity_detail(
+                &format!("run-{}", index),
+                json!({"type": "Run", "distance": 30000.0, "moving_time": 9000, "gear": {"id": gear}}),
+            );
+        }
+        client
+    }
+
+    #[tokio::test]
+    async fn test_execute_forecast_projects_retirement_and_race_warning() {
+        let output = ManageGearHandler::new()
+            .execute(
+                json!({"action": "forecast"}),
+                Arc::new(wear_mock_client()),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let content_str = format!("{:?}", output.content);
+        assert!(content_str.contains("Gear Forecast: Shoes"));
+        assert!(content_str.contains("Daily Trainer"));
+        assert!(content_str.contains("600 km"));
+        assert!(content_str.contains("700 km (default)"));
+        assert!(content_str.contains("75%"));
+        assert!(!content_str.contains("Road Bike"));
+        assert!(content_str.contains("Spring 10K"));
+        assert!(content_str.contains("past its 600 km limit"));
+        assert!(
+            output
+                .suggestions
+                .iter()
+                .any(|s| s.contains("Break in a fresh pair before Spring 10K"))
+        );
+        assert!(
+            output
+                .suggestions
+                .iter()
+                .any(|s| s.contains("default 700 km limit"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_reminder_actions() {
+        let handler = ManageGearHandler::new();
+        let created = handler
+            .execute(
+                json!({
+                    "action": "create_reminder",
+                    "gear_name": "trail shoe",
+                    "reminder_distance_km": 650,
+                    "idempotency_token": "rem-1"
+                }),
+                Arc::new(wear_mock_client()),
+                None,
+            )
+            .await
+            .unwrap();
+        let created_str = format!("{:?}", created.content);
+        assert!(created_str.contains("Replace Trail Shoe"));
+        assert!(created_str.contains("650 km"));
+
+        let reset = handler
+            .execute(
+                json!({"action": "reset_reminder", "gear_name": "Daily Trainer", "idempotency_token": "rem-2"}),
+                Arc::new(wear_mock_client()),
+                None,
+            )
+            .await
+            .unwrap();
+        let reset_str = format!("{:?}", reset.content);
+        assert!(reset_str.contains("ID 11"));
+        assert!(reset_str.contains("Reset"));
+
+        let snoozed = handler
+            .execute(
+                json!({"action": "snooze_reminder", "gear_name": "Daily Trainer", "snooze_days": 14, "idempotency_token": "rem-3"}),
+                Arc::new(wear_mock_client()),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(format!("{:?}", snoozed.content).contains("Snoozed for 14 days"));
+
+        for input in [
+            json!({"action": "snooze_reminder", "gear_name": "Trail Shoe", "idempotency_token": "rem-4"}),
+            json!({"action": "create_reminder", "gear_name": "Trail Shoe", "idempotency_token": "rem-5"}),
+            json!({"action": "reset_reminder", "gear_name": "Daily Trainer"}),
+        ] {
+            let result = handler
+                .execute(input.clone(), Arc::new(wear_mock_client()), None)
+                .await;
+            assert!(
+                matches!(result, Err(IntentError::ValidationError(_))),
+                "{input}"
+            );
+        }
+
+        let unknown = handler
+            .execute(
+                json!({"action": "reset_reminder", "gear_name": "Daily Trainer", "reminder_id": "99", "idempotency_token": "rem-6"}),
+                Arc::new(wear_mock_client()),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(unknown, IntentError::ValidationError(_)));
+        assert!(unknown.to_string().contains("Available reminder IDs: 11"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_assign_gear_to_activities() {
+        let handler = ManageGearHandler::new();
+        let output = handler
+            .execute(
+                json!({
+                    "action": "assign",
+                    "gear_name": "Trail Shoe",
+                    "activity_ids": ["i101", "i102"],
+                    "idempotency_token": "assign-1"
+                }),
+                Arc::new(wear_mock_client()),
+                None,
+            )
+            .await
+            .unwrap();
+        let content_str = format!("{:?}", output.content);
+        assert!(content_str.contains("Assigned to 2 activities"));
+        assert!(content_str.contains("i102"));
+
+        let missing = handler
+            .execute(
+                json!({"action": "assign", "gear_name": "Trail Shoe", "idempotency_token": "assign-2"}),
+                Arc::new(wear_mock_client()),
+                None,
+            )
+            .await;
+        assert!(matches!(
+            missing.unwrap_err(),
+            IntentError::ValidationError(_)
+        ));
+    }
 }
@@ -1,4 +1,16 @@
-use crate::engines::coach_metrics::parse_fitness_metrics;
+use crate::engines::coach_metrics::{fitness_metrics_from_wellness, parse_fitness_metrics};
+use crate::engines::threshold_drift::{
+    DEFAULT_DRIFT_LOOKBACK_WEEKS, DEFAULT_DRIFT_MIN_WEEKS, DEFAULT_DRIFT_TOLERANCE_PCT,
+    ThresholdDrift, ThresholdMetric, activity_modelled_ftp, activity_threshold_speed,
+    compute_zone_shifts, detect_threshold_drift, extract_eftp_history, pace_min_per_km_to_speed,
+    speed_to_pace_min_per_km,
+};
+use crate::engines::threshold_estimation::{
+    ThresholdEstimate, dfa_windows_from_streams, estimate_thresholds, steady_run_sample,
+};
+use crate::engines::zone_models::{
+    ZoneModel, absolute_zone_bounds, hr_zone_bounds, validate_zone_bounds,
+};
 use crate::intents::{ContentBlock, IdempotencyCache, IntentError, IntentHandler, IntentOutput};
 use async_trait::async_trait;
 use chrono::Utc;
@@ -9,6 +21,18 @@ use serde_json::{Value, json};
 /// Manages athlete profile, zones, and thresholds.
 use std::sync::Arc;
 
+const ESTIMATE_DEFAULT_DAYS: i64 = 42;
+const ESTIMATE_MAX_DAYS: i64 = 120;
+const ESTIMATE_DEFAULT_RUNS: u64 = 12;
+const ESTIMATE_ACTIVITY_LIMIT: u32 = 100;
+/// Runs averaging above this share of LT HR are not Z2 samples.
+const Z2_CEILING_LT_FRACTION: f64 = 0.92;
+/// Below this confidence the proposed update is flagged for retesting.
+const ESTIMATE_LOW_CONFIDENCE: f64 = 0.5;
+const ESTIMATE_STREAM_TYPES: &[&str] = &["time", "heartrate", "velocity_smooth", "watts", "hrv"];
+const DRIFT_MAX_LOOKBACK_WEEKS: i64 = 16;
+const DRIFT_ACTIVITY_LIMIT: u32 = 80;
+
 pub struct ManageProfileHandler;
 impl ManageProfileHandler {
     pub fn new() -> Self {
@@ -119,18 +143,44 @@ impl IntentHandler for ManageProfileHandler {
     fn description(&self) -> &'static str {
         "Manages athlete profile, zones, and thresholds. \
          Use for viewing profile, updating thresholds from test results, \
-         and synchronizing zones with lab data."
+         and synchronizing zones with lab data. \
+         update_thresholds edits AeT/LT HR, FTP, indoor FTP, threshold pace, swim CSS, \
+         max HR and power/HR/pace zones (custom or from a zone model) for any sport \
+         setting; dry_run previews a before/after zone table. \
+         estimate_thresholds derives AeT HR from the decoupling boundary across \
+         steady Z2 runs and AeT/LT HR from DFA α1 (0.75 / 0.5) when RR data exists, \
+         returning a confidence score and a dry-run update_thresholds diff. \
+         detect_threshold_drift compares configured FTP / threshold pace with recent \
+         eFTP and hard-run efforts and reports zone and load impact; \
+         apply_threshold_drift writes the stale values back to sport settings."
     }
 
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
             "properties": {
-                "action": {"type": "string", "enum": ["get", "update_thresholds"], "description": "Action: view or update"},
+                "action": {"type": "string", "enum": ["get", "update_thresholds", "estimate_thresholds", "detect_threshold_drift", "apply_threshold_drift"], "description": "Action: view, update, estimate thresholds from recent runs (read-only), detect stale FTP / threshold pace (read-only) or apply the detected values"},
                 "sections": {"type": "array", "items": {"type": "string"}, "description": "Sections: overview, zones, thresholds, metrics (fitness/load snapshot)"},
-                "new_aet_hr": {"type": "number", "description": "New AeT HR (bpm) for update_thresholds"},
-                "new_lt_hr": {"type": "number", "description": "New LT HR (bpm) for update_thresholds"},
-                "thresholds_source": {"type": "string", "enum": ["manual", "lab_test"], "description": "Threshold source"},
+                "sport": {"type": "string", "description": "Sport setting to edit by type or name (e.g. Ride, Swim); defaults to the primary run setting"},
+                "new_aet_hr": {"type": "number", "description": "New AeT HR (bpm) for update_thresholds; set together with new_lt_hr"},
+                "new_lt_hr": {"type": "number", "description": "New LT HR (bpm) for update_thresholds; set together with new_aet_hr"},
+                "new_ftp": {"type": "number", "description": "New FTP (W)"},
+                "new_indoor_ftp": {"type": "number", "description": "New indoor FTP (W)"},
+                "new_threshold_pace": {"type": ["number", "string"], "description": "New threshold pace in the sport's pace units, as minutes or \"m:ss\""},
+                "new_css": {"type": ["number", "string"], "description": "New swim CSS as seconds per 100 m or \"m:ss\""},
+                "new_max_hr": {"type": "number", "description": "New max HR (bpm)"},
+                "zone_model": {"type": "string", "enum": ZoneModel::NAMES, "description": "Regenerate zones from a model: Coggan, Seiler 3-zone, Friel or %LTHR"},
+                "power_zones": {"type": "array", "items": {"type": "number"}, "description": "Custom power zone upper bounds (% FTP, ascending; 999 = open top zone)"},
+                "hr_zones": {"type": "array", "items": {"type": "number"}, "description": "Custom HR zone upper bounds (bpm, ascending; last = max HR)"},
+                "pace_zones": {"type": "array", "items": {"type": "number"}, "description": "Custom pace zone upper bounds (% threshold speed, ascending; 999 = open top zone)"},
+                "dry_run": {"type": "boolean", "default": false, "description": "Preview update_thresholds with a before/after zone table without writing"},
+                "thresholds_source": {"type": "string", "enum": ["manual", "lab_test", "estimated"], "description": "Threshold source"},
+                "days_back": {"type": "integer", "minimum": 7, "maximum": ESTIMATE_MAX_DAYS, "default": ESTIMATE_DEFAULT_DAYS, "description": "Lookback for estimate_thresholds (days)"},
+                "max_activities": {"type": "integer", "minimum": 1, "default": ESTIMATE_DEFAULT_RUNS, "description": "Maximum runs analysed by estimate_thresholds"},
+                "tolerance_pct": {"type": "number", "minimum": 1, "default": DEFAULT_DRIFT_TOLERANCE_PCT, "description": "Drift tolerance for threshold drift actions (%)"},
+                "min_weeks": {"type": "integer", "minimum": 1, "default": DEFAULT_DRIFT_MIN_WEEKS, "description": "Consecutive recent weeks beyond tolerance before a threshold is stale"},
+                "lookback_weeks": {"type": "integer", "minimum": 2, "maximum": DRIFT_MAX_LOOKBACK_WEEKS, "default": DEFAULT_DRIFT_LOOKBACK_WEEKS, "description": "Lookback for modelled thresholds (weeks)"},
+                "recalc_hr_zones": {"type": "boolean", "default": true, "description": "Recalculate HR zones when apply_threshold_drift writes new values"},
                 "apply_to_activities": {"type": "boolean", "default": true, "description": "Apply to historical activities"},
                 "idempotency_token": {"type": "string", "description": "Idempotency token (required for update)"}
             },
@@ -151,17 +201,39 @@ impl IntentHandler for ManageProfileHandler {
 
         match action {
             "get" => self.get_profile(&input, client.as_ref()).await,
+            "estimate_thresholds" => self.estimate_thresholds(&input, client.as_ref()).await,
             "update_thresholds" => {
+                let dry_run = input
+                    .get("dry_run")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false);
+                if !dry_run {
+                    let _token = input
+                        .get("idempotency_token")
+                        .and_then(Value::as_str)
+                        .ok_or_else(|| {
+                            IntentError::validation("Missing required field: idempotency_token")
+                        })?;
+                }
+                self.update_thresholds(&input, client.as_ref(), dry_run)
+                    .await
+            }
+            "detect_threshold_drift" => {
+                let drifts = collect_threshold_drift(&input, client.as_ref()).await?;
+                Ok(render_threshold_drift(&drifts, None))
+            }
+            "apply_threshold_drift" => {
                 let _token = input
                     .get("idempotency_token")
                     .and_then(Value::as_str)
                     .ok_or_else(|| {
                         IntentError::validation("Missing required field: idempotency_token")
                     })?;
-                self.update_thresholds(&input, client.as_ref()).await
+                self.apply_threshold_drift(&input, client.as_ref()).await
             }
             _ => Err(IntentError::validation(format!(
-                "Invalid action: {}. Must be 'get' or 'update_thresholds'",
+                "Invalid action: {}. Must be 'get', 'update_thresholds', 'estimate_thresholds', \
+                 'detect_threshold_drift' or 'apply_threshold_drift'",
                 action
             ))),
         }
@@ -170,6 +242,12 @@ impl IntentHandler for ManageProfileHandler {
     fn requires_idempotency_token(&self) -> bool {
         false
     }
+
+    /// `get` and the estimate/detect actions need no token; settings writes are
+    /// deduplicated when one is supplied.
+    fn accepts_idempotency_token(&self) -> bool {
+        true
+    }
 }
 
 impl ManageProfileHandler {
@@ -208,7 +286,7 @@ impl ManageProfileHandler {
             || sections.contains(&"metrics".to_string())
         {
             client
-                .get_wellness_for_date(&Utc::now().date_naive().to_string())
+                .get_wellness_for_date_typed(&Utc::now().date_naive().to_string())
                 .await
                 .ok()
         } else {
@@ -225,7 +303,6 @@ impl ManageProfileHandler {
 
         if sections.contains(&"overview".to_string()) {
             let settings_obj = sport_settings_value.as_object();
-            let wellness_obj = wellness_for_today.as_ref().and_then(Value::as_object);
             let age = settings_obj
                 .and_then(|o| o.get("age"))
                 .and_then(|v| v.as_i64())
@@ -234,11 +311,7 @@ impl ManageProfileHandler {
             let weight = settings_obj
                 .and_then(|o| o.get("weight"))
                 .and_then(|v| v.as_f64())
-                .or_else(|| {
-                    wellness_obj
-                        .and_then(|o| o.get("weight"))
-                        .and_then(Value::as_f64)
-                })
+                .or_else(|| wellness_for_today.as_ref().and_then(|entry| entry.weight))
                 .map(|v| format!("{:.1} kg", v))
                 .unwrap_or_else(|| "Not set".to_string());
 
@@ -317,9 +390,11 @@ impl ManageProfileHandler {
         if sections.contains(&"metrics".to_string()) {
             content.push(ContentBlock::markdown("Metrics".to_string()));
 
-            if let Some(fitness) = parse_fitness_metrics(fitness_summary.as_ref())
-                .or_else(|| parse_fitness_metrics(wellness_for_today.as_ref()))
-            {
+            if let Some(fitness) = parse_fitness_metrics(fitness_summary.as_ref()).or_else(|| {
+                wellness_for_today
+                    .as_ref()
+                    .map(fitness_metrics_from_wellness)
+            }) {
                 let mut metric_rows = vec![vec!["Metric".to_string(), "Value".to_string()]];
                 if let Some(ctl) = fitness.ctl {
                     metric_rows.push(vec!["CTL (Fitness)".to_string(), format!("{:.1}", ctl)]);
@@ -374,15 +449,53 @@ impl ManageProfileHandler {
         &self,
         input: &Value,
         client: &dyn IntervalsClient,
+        dry_run: bool,
     ) -> Result<IntentOutput, IntentError> {
-        let new_aet_hr = input
-            .get("new_aet_hr")
-            .and_then(Value::as_i64)
-            .ok_or_else(|| IntentError::validation("Missing required field: new_aet_hr"))?;
-        let new_lt_hr = input
-            .get("new_lt_hr")
-            .and_then(Value::as_i64)
-            .ok_or_else(|| IntentError::validation("Missing required field: new_lt_hr"))?;
+        let new_aet_hr = input.get("new_aet_hr").and_then(Value::as_i64);
+        let new_lt_hr = input.get("new_lt_hr").and_then(Value::as_i64);
+        match (new_aet_hr, new_lt_hr) {
+            (Some(_), None) => {
+                return Err(IntentError::validation(
+                    "Missing required field: new_lt_hr (AeT and LT HR are updated together)",
+                ));
+            }
+            (None, Some(_)) => {
+                return Err(IntentError::validation(
+                    "Missing required field: new_aet_hr (AeT and LT HR are updated together)",
+                ));
+            }
+            _ => {}
+        }
+        let new_ftp = positive_number(input, "new_ftp")?;
+        let new_indoor_ftp = positive_number(input, "new_indoor_ftp")?;
+        let new_max_hr = positive_number(input, "new_max_hr")?;
+        let new_threshold_pace = pace_input(input, "new_threshold_pace")?;
+        // Numeric CSS is seconds per 100 m; sport settings store minutes per 100 m.
+        let new_css = pace_input(input, "new_css")?.map(|css| {
+            if input.get("new_css").is_some_and(Value::is_number) {
+                css / 60.0
+            } else {
+                css
+            }
+        });
+        let custom_power_zones = zone_input(input, "power_zones")?;
+        let custom_hr_zones = zone_input(input, "hr_zones")?;
+        let custom_pace_zones = zone_input(input, "pace_zones")?;
+        let zone_model = match input.get("zone_model").and_then(Value::as_str) {
+            Some(name) => Some(ZoneModel::parse(name).ok_or_else(|| {
+                IntentError::validation(format!(
+                    "Invalid zone_model: {}. Must be one of: {}",
+                    name,
+                    ZoneModel::NAMES.join(", ")
+                ))
+            })?),
+            None => None,
+        };
+        if new_threshold_pace.is_some() && new_css.is_some() {
+            return Err(IntentError::validation(
+                "Provide either new_threshold_pace or new_css, not both",
+            ));
+        }
         let source = input
             .get("thresholds_source")
             .and_then(Value::as_str)
@@ -399,107 +512,1020 @@ impl ManageProfileHandler {
         let sport_settings_value =
             serde_json::to_value(&sport_settings).unwrap_or_else(|_| json!([]));
         let sport_entries = sport_settings_entries(&sport_settings_value);
-        let primary = primary_sport_setting(&sport_entries)
-            .ok_or_else(|| IntentError::api("No sport settings available".to_string()))?;
-        let sport_type = primary
+        let setting = match input.get("sport").and_then(Value::as_str) {
+            Some(sport) => find_sport_setting(&sport_entries, sport).ok_or_else(|| {
+                IntentError::validation(format!("No sport settings found for sport: {}", sport))
+            })?,
+            None => primary_sport_setting(&sport_entries)
+                .ok_or_else(|| IntentError::api("No sport settings available".to_string()))?,
+        };
+        let sport_type = setting
             .get("types")
             .and_then(Value::as_array)
             .and_then(|types| types.iter().find_map(Value::as_str))
-            .or_else(|| primary.get("type").and_then(Value::as_str))
-            .unwrap_or("Run");
+            .or_else(|| setting.get("type").and_then(Value::as_str))
+            .unwrap_or("Run")
+            .to_string();
+        let pace_units = setting.get("pace_units").and_then(Value::as_str);
+        let is_swim = pace_units == Some("SECS_100M")
+            || setting_types(setting)
+                .iter()
+                .any(|sport| sport.contains("Swim"));
+        if new_css.is_some() && !is_swim {
+            return Err(IntentError::validation(format!(
+                "new_css applies to swim sport settings; {} is not a swim sport (pass sport: Swim)",
+                sport_type
+            )));
+        }
+        let new_threshold_pace = new_threshold_pace.or(new_css);
 
-        let old_aet = get_number(primary, &["threshold_aet_hr", "aet_hr"])
+        let old_aet = get_number(setting, &["threshold_aet_hr", "aet_hr"])
             .map(|value| value.round() as i64)
             .unwrap_or(150);
-        let old_lt = get_number(primary, &["threshold_lt_hr", "lthr"])
+        let old_lt = get_number(setting, &["threshold_lt_hr", "lthr"])
             .map(|value| value.round() as i64)
             .unwrap_or(170);
-
-        let update_fields = json!({
-            "threshold_aet_hr": new_aet_hr,
-            "threshold_lt_hr": new_lt_hr,
-            "lthr": new_lt_hr,
-            "thresholds_source": source,
+        let old_ftp = get_number(setting, &["ftp"]);
+        let old_indoor_ftp = get_number(setting, &["indoor_ftp"]);
+        let old_max_hr = get_number(setting, &["max_hr"]);
+        let old_threshold_pace = get_number(setting, &["threshold_pace"]);
+        let old_power_zones = zone_numbers(setting.get("power_zones"));
+        let old_hr_zones = zone_numbers(setting.get("hr_zones"));
+        let old_pace_zones = zone_numbers(setting.get("pace_zones"));
+
+        let ftp = new_ftp.or(old_ftp);
+        // The 170 bpm display default above must never seed HR zones.
+        let lt_hr = new_lt_hr
+            .map(|hr| hr as f64)
+            .or_else(|| get_number(setting, &["threshold_lt_hr", "lthr"]));
+        let max_hr = new_max_hr.or(old_max_hr);
+        let threshold_pace = new_threshold_pace.or(old_threshold_pace);
+
+        let mut notes = Vec::new();
+        let power_zones = custom_power_zones.or_else(|| {
+            zone_model
+                .and_then(ZoneModel::power_pcts)
+                .filter(|_| ftp.is_some())
+                .map(<[f64]>::to_vec)
+        });
+        let hr_zones = match (custom_hr_zones, zone_model) {
+            (Some(zones), _) => Some(zones),
+            (None, Some(model)) => match (lt_hr, max_hr) {
+                (Some(lt_hr), Some(max_hr)) => {
+                    let bounds = hr_zone_bounds(model.hr_pcts(), lt_hr, max_hr);
+                    if bounds.is_none() {
+                        notes.push(format!(
+                            "HR zones unchanged: max HR must exceed the top {} zone bound.",
+                            model.label()
+                        ));
+                    }
+                    bounds
+                }
+                (None, _) => {
+                    notes.push(format!(
+                        "HR zones unchanged: no LT HR is set for {}; pass new_aet_hr/new_lt_hr to \
+                         generate {} HR zones.",
+                        sport_type,
+                        model.label()
+                    ));
+                    None
+                }
+                (Some(_), None) => {
+                    notes.push("HR zones unchanged: LT HR and max HR are both required.".into());
+                    None
+                }
+            },
+            (None, None) => None,
+        };
+        let pace_zones = custom_pace_zones.or_else(|| {
+            zone_model
+                .and_then(ZoneModel::pace_pcts)
+                .filter(|_| threshold_pace.is_some())
+                .map(<[f64]>::to_vec)
         });
+        if let Some(model) = zone_model {
+            notes.push(format!("Zone model: {}", model.label()));
+        }
 
-        client
-            .update_sport_settings(sport_type, true, &update_fields)
-            .await
-            .map_err(|e| IntentError::api(format!("Failed to update sport settings: {}", e)))?;
+        let mut fields = serde_json::Map::new();
+        if let (Some(aet), Some(lt)) = (new_aet_hr, new_lt_hr) {
+            fields.insert("threshold_aet_hr".into(), json!(aet));
+            fields.insert("threshold_lt_hr".into(), json!(lt));
+            fields.insert("lthr".into(), json!(lt));
+        }
+        if let Some(value) = new_ftp {
+            fields.insert("ftp".into(), json!(value.round()));
+        }
+        if let Some(value) = new_indoor_ftp {
+            fields.insert("indoor_ftp".into(), json!(value.round()));
+        }
+        if let Some(value) = new_threshold_pace {
+            fields.insert("threshold_pace".into(), json!(value));
+        }
+        if let Some(value) = new_max_hr {
+            fields.insert("max_hr".into(), json!(value.round()));
+        }
+        if let Some(zones) = &power_zones {
+            fields.insert("power_zones".into(), json!(zones));
+        }
+        if let Some(zones) = &hr_zones {
+            fields.insert(
+                "hr_zones".into(),
+                json!(
+                    zones
+                        .iter()
+                        .map(|bpm| bpm.round() as i64)
+                        .collect::<Vec<_>>()
+                ),
+            );
+        }
+        if let Some(zones) = &pace_zones {
+            fields.insert("pace_zones".into(), json!(zones));
+        }
+        if fields.is_empty() {
+            return Err(IntentError::validation(
+                "No threshold or zone changes provided: pass new_aet_hr/new_lt_hr, new_ftp, \
+                 new_indoor_ftp, new_threshold_pace, new_css, new_max_hr, zone_model or custom zones",
+            ));
+        }
+        fields.insert("thresholds_source".into(), json!(source));
+        // Explicit HR zones would be overwritten by an LTHR-based recalculation.
+        let recalc_hr_zones = input
+            .get("recalc_hr_zones")
+            .and_then(Value::as_bool)
+            .unwrap_or(hr_zones.is_none());
 
-        if apply {
+        if !dry_run {
             client
-                .apply_sport_settings(sport_type)
+                .update_sport_settings(&sport_type, recalc_hr_zones, &Value::Object(fields))
                 .await
-                .map_err(|e| IntentError::api(format!("Failed to apply sport settings: {}", e)))?;
-        }
+                .map_err(|e| IntentError::api(format!("Failed to update sport settings: {}", e)))?;
 
-        let gap_change = ((new_lt_hr - new_aet_hr) as f64 * 100.0 / new_aet_hr as f64)
-            - ((old_lt - old_aet) as f64 * 100.0 / old_aet as f64);
+            if apply {
+                client
+                    .apply_sport_settings(&sport_type)
+                    .await
+                    .map_err(|e| {
+                        IntentError::api(format!("Failed to apply sport settings: {}", e))
+                    })?;
+            }
+        }
 
         let mut content = Vec::new();
         content.push(ContentBlock::markdown(format!(
-            "# Threshold Update\nSource: {}\nApply to history: {}",
+            "# Threshold Update{}\nSport: {}\nSource: {}\nApply to history: {}",
+            if dry_run {
+                " — Preview (dry_run)"
+            } else {
+                ""
+            },
+            sport_type,
             source,
             if apply { "Yes" } else { "No" }
         )));
 
-        let mut rows = vec![vec![
-            "Parameter".into(),
-            "Old".into(),
-            "New".into(),
-            "Δ".into(),
-        ]];
-        rows.push(vec![
-            "AeT HR".into(),
-            format!("{} bpm", old_aet),
-            format!("{} bpm", new_aet_hr),
-            format!("+{} bpm", new_aet_hr - old_aet),
-        ]);
-        rows.push(vec![
-            "LT HR".into(),
-            format!("{} bpm", old_lt),
-            format!("{} bpm", new_lt_hr),
-            format!("+{} bpm", new_lt_hr - old_lt),
-        ]);
-        rows.push(vec![
-            "AeT-LT Gap".into(),
-            format!("{:.1}%", (old_lt - old_aet) as f64 * 100.0 / old_aet as f64),
-            format!(
-                "{:.1}%",
-                (new_lt_hr - new_aet_hr) as f64 * 100.0 / new_aet_hr as f64
-            ),
-            format!("{:+.1}%", gap_change),
-        ]);
-        content.push(ContentBlock::table(rows[0].clone(), rows[1..].to_vec()));
-        content.push(ContentBlock::markdown(format!(
-            "Updated sport settings for {} via Intervals.icu API.{}",
-            sport_type,
-            if apply {
-                " Historical activities were queued for recalculation."
-            } else {
-                " Historical activity recalculation was skipped."
-            }
-        )));
-
-        let suggestions = vec![
-            format!(
+        let mut rows = Vec::new();
+        let mut suggestions = Vec::new();
+        if let (Some(new_aet_hr), Some(new_lt_hr)) = (new_aet_hr, new_lt_hr) {
+            let gap_change = ((new_lt_hr - new_aet_hr) as f64 * 100.0 / new_aet_hr as f64)
+                - ((old_lt - old_aet) as f64 * 100.0 / old_aet as f64);
+            rows.push(vec![
+                "AeT HR".into(),
+                format!("{} bpm", old_aet),
+                format!("{} bpm", new_aet_hr),
+                format!("{:+} bpm", new_aet_hr - old_aet),
+            ]);
+            rows.push(vec![
+                "LT HR".into(),
+                format!("{} bpm", old_lt),
+                format!("{} bpm", new_lt_hr),
+                format!("{:+} bpm", new_lt_hr - old_lt),
+            ]);
+            rows.push(vec![
+                "AeT-LT Gap".into(),
+                format!("{:.1}%", (old_lt - old_aet) as f64 * 100.0 / old_aet as f64),
+                format!(
+                    "{:.1}%",
+                    (new_lt_hr - new_aet_hr) as f64 * 100.0 / new_aet_hr as f64
+                ),
+                format!("{:+.1}%", gap_change),
+            ]);
+            suggestions.push(format!(
                 "AeT-LT Gap changed to {:.1}% ({:+.1}%)",
                 (new_lt_hr - new_aet_hr) as f64 * 100.0 / new_aet_hr as f64,
                 gap_change
-            ),
-            "Recommended to verify zones after threshold update.".into(),
-        ];
+            ));
+        }
+        let watts = |value: Option<f64>| {
+            value
+                .map(|value| format!("{:.0} W", value))
+                .unwrap_or_else(|| "not set".into())
+        };
+        let delta = |old: Option<f64>, new: f64, unit: &str| {
+            old.map(|old| format!("{:+.0}{}", new - old, unit))
+                .unwrap_or_else(|| "-".into())
+        };
+        if let Some(value) = new_ftp {
+            rows.push(vec![
+                "FTP".into(),
+                watts(old_ftp),
+                watts(Some(value)),
+                delta(old_ftp, value, " W"),
+            ]);
+        }
+        if let Some(value) = new_indoor_ftp {
+            rows.push(vec![
+                "Indoor FTP".into(),
+                watts(old_indoor_ftp),
+                watts(Some(value)),
+                delta(old_indoor_ftp, value, " W"),
+            ]);
+        }
+        if let Some(value) = new_threshold_pace {
+            let units = if is_swim {
+                Some("SECS_100M")
+            } else {
+                pace_units
+            };
+            rows.push(vec![
+                if is_swim { "CSS" } else { "Threshold pace" }.into(),
+                old_threshold_pace
+                    .map(|pace| format_threshold_pace(pace, units))
+                    .unwrap_or_else(|| "not set".into()),
+                format_threshold_pace(value, units),
+                old_threshold_pace
+                    .map(|old| format!("{:+.0} s", (value - old) * 60.0))
+                    .unwrap_or_else(|| "-".into()),
+            ]);
+        }
+        if let Some(value) = new_max_hr {
+            rows.push(vec![
+                "Max HR".into(),
+                old_max_hr
+                    .map(|hr| format!("{:.0} bpm", hr))
+                    .unwrap_or_else(|| "not set".into()),
+                format!("{:.0} bpm", value),
+                delta(old_max_hr, value, " bpm"),
+            ]);
+        }
+        if !rows.is_empty() {
+            content.push(ContentBlock::table(
+                vec!["Parameter".into(), "Old".into(), "New".into(), "Δ".into()],
+                rows,
+            ));
+        }
 
-        let next_actions = vec![
-            "To verify recalculation: assess_recovery in 5-10 minutes".into(),
-            "To plan based on new zones: plan_training".into(),
-        ];
+        if let (Some(old), Some(new)) = (old_ftp.or(ftp), ftp)
+            && (power_zones.is_some() || new_ftp.is_some())
+        {
+            let after = power_zones.as_deref().unwrap_or(&old_power_zones);
+            if let Some(table) = zone_shift_table(
+                "Power zone",
+                &absolute_zone_bounds(&old_power_zones, old),
+                &absolute_zone_bounds(after, new),
+                |watts| format!("{:.0} W", watts),
+            ) {
+                content.push(table);
+            }
+        }
+        if let Some(after) = &hr_zones {
+            let before = old_hr_zones.iter().copied().map(Some).collect::<Vec<_>>();
+            let after = after.iter().copied().map(Some).collect::<Vec<_>>();
+            if let Some(table) =
+                zone_shift_table("HR zone", &before, &after, |bpm| format!("{:.0} bpm", bpm))
+            {
+                content.push(table);
+            }
+        }
+        if let (Some(old), Some(new)) = (old_threshold_pace.or(threshold_pace), threshold_pace)
+            && (pace_zones.is_some() || new_threshold_pace.is_some())
+        {
+            let units = if is_swim {
+                Some("SECS_100M")
+            } else {
+                pace_units
+            };
+            // Pace zones are % of threshold speed, so the pace bound is threshold / pct.
+            let to_pace = |pcts: &[f64], threshold: f64| {
+                absolute_zone_bounds(pcts, 100.0)
+                    .into_iter()
+                    .map(|pct| pct.map(|pct| threshold * 100.0 / pct))
+                    .collect::<Vec<_>>()
+            };
+            let after = pace_zones.as_deref().unwrap_or(&old_pace_zones);
+            if let Some(table) = zone_shift_table(
+                "Pace zone",
+                &to_pace(&old_pace_zones, old),
+                &to_pace(after, new),
+                |pace| format_threshold_pace(pace, units),
+            ) {
+                content.push(table);
+            }
+        }
+        if !notes.is_empty() {
+            content.push(ContentBlock::markdown(notes.join("\n")));
+        }
+
+        let mut next_actions = Vec::new();
+        if dry_run {
+            content.push(ContentBlock::markdown(
+                "Preview only: no changes were written.".to_string(),
+            ));
+            next_actions
+                .push("To apply: call again without dry_run and with an idempotency_token".into());
+        } else {
+            content.push(ContentBlock::markdown(format!(
+                "Updated sport settings for {} via Intervals.icu API.{}",
+                sport_type,
+                if apply {
+                    " Historical activities were queued for recalculation."
+                } else {
+                    " Historical activity recalculation was skipped."
+                }
+            )));
+            next_actions.push("To verify recalculation: assess_recovery in 5-10 minutes".into());
+        }
+        suggestions.push("Recommended to verify zones after threshold update.".into());
+        next_actions.push("To plan based on new zones: plan_training".into());
 
         Ok(IntentOutput::new(content)
             .with_suggestions(suggestions)
             .with_next_actions(next_actions))
     }
+
+    async fn estimate_thresholds(
+        &self,
+        input: &Value,
+        client: &dyn IntervalsClient,
+    ) -> Result<IntentOutput, IntentError> {
+        let days_back = input
+            .get("days_back")
+            .and_then(Value::as_i64)
+            .unwrap_or(ESTIMATE_DEFAULT_DAYS)
+            .clamp(1, ESTIMATE_MAX_DAYS);
+        let max_runs = input
+            .get("max_activities")
+            .and_then(Value::as_u64)
+            .unwrap_or(ESTIMATE_DEFAULT_RUNS)
+            .max(1) as usize;
+
+        let sport_settings = client
+            .get_sport_settings()
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to fetch sport settings: {}", e)))?;
+        let sport_settings_value =
+            serde_json::to_value(&sport_settings).unwrap_or_else(|_| json!([]));
+        let sport_entries = sport_settings_entries(&sport_settings_value);
+        let primary = primary_sport_setting(&sport_entries);
+        let current_aet = primary
+            .and_then(|setting| get_number(setting, &["threshold_aet_hr", "aet_hr"]))
+            .map(|value| value.round() as i64);
+        let current_lt = primary
+            .and_then(|setting| get_number(setting, &["threshold_lt_hr", "lthr"]))
+            .map(|value| value.round() as i64);
+        let z2_ceiling = current_lt.map(|lt| lt as f64 * Z2_CEILING_LT_FRACTION);
+
+        let activities = client
+            .get_recent_activities(Some(ESTIMATE_ACTIVITY_LIMIT), Some(days_back as i32))
+            .await
+            .map_err(|e| IntentError::api(format!("Failed to fetch activities: {}", e)))?;
+
+        let mut samples = Vec::new();
+        let mut windows = Vec::new();
+        let mut runs_checked = 0;
+        for activity in &activities {
+            if runs_checked >= max_runs {
+                break;
+            }
+            let Ok(detail) = client.get_activity_details(&activity.id).await else {
+                continue;
+            };
+            let is_run = matches!(
+                detail.get("type").and_then(Value::as_str),
+                Some("Run" | "TrailRun" | "VirtualRun")
+            );
+            if !is_run {
+                continue;
+            }
+            runs_checked += 1;
+            let Ok(streams) = client
+                .get_activity_streams(
+                    &activity.id,
+                    Some(
+                        ESTIMATE_STREAM_TYPES
+                            .iter()
+                            .map(|s| s.to_string())
+                            .collect(),
+                    ),
+                )
+                .await
+            else {
+                continue;
+            };
+            samples.extend(steady_run_sample(&activity.id, &streams, z2_ceiling));
+            windows.extend(dfa_windows_from_streams(&streams));
+        }
+
+        let estimate = estimate_thresholds(&samples, &windows);
+        Ok(render_threshold_estimate(
+            &estimate,
+            current_aet,
+            current_lt,
+            runs_checked,
+            days_back,
+        ))
+    }
+
+    async fn apply_threshold_drift(
+        &self,
+        input: &Value,
+        client: &dyn IntervalsClient,
+    ) -> Result<IntentOutput, IntentError> {
+        let recalc_hr_zones = input
+            .get("recalc_hr_zones")
+            .and_then(Value::as_bool)
+            .unwrap_or(true);
+        let apply = input
+            .get("apply_to_activities")
+            .and_then(Value::as_bool)
+            .unwrap_or(true);
+
+        // Re-run detection so only values that are still stale get written.
+        let drifts = collect_threshold_drift(input, client).await?;
+        let mut applied = Vec::new();
+        for sport_drift in drifts.iter().filter(|entry| entry.drift.stale) {
+            let fields = match sport_drift.drift.metric {
+                ThresholdMetric::Ftp => json!({"ftp": sport_drift.drift.modelled.round()}),
+                ThresholdMetric::ThresholdPace => {
+                    let Some(pace) = speed_to_pace_min_per_km(sport_drift.drift.modelled) else {
+                        continue;
+                    };
+                    json!({"threshold_pace": (pace * 100.0).round() / 100.0})
+                }
+            };
+            client
+                .update_sport_settings(&sport_drift.sport_type, recalc_hr_zones, &fields)
+                .await
+                .map_err(|e| IntentError::api(format!("Failed to update sport settings: {}", e)))?;
+            if apply {
+                client
+                    .apply_sport_settings(&sport_drift.sport_type)
+                    .await
+                    .map_err(|e| {
+                        IntentError::api(format!("Failed to apply sport settings: {}", e))
+                    })?;
+            }
+            applied.push(format!(
+                "{} ({})",
+                sport_drift.drift.metric.label(),
+                sport_drift.sport_type
+            ));
+        }
+
+        Ok(render_threshold_drift(
+            &drifts,
+            Some(ThresholdDriftApplied {
+                updated: applied,
+                recalc_hr_zones,
+                apply_to_activities: apply,
+            }),
+        ))
+    }
+}
+
+/// Drift of one configured threshold, tied to the sport setting it belongs to.
+struct SportThresholdDrift {
+    sport_type: String,
+    drift: ThresholdDrift,
+}
+
+struct ThresholdDriftApplied {
+    updated: Vec<String>,
+    recalc_hr_zones: bool,
+    apply_to_activities: bool,
+}
+
+fn setting_types(setting: &serde_json::Map<String, Value>) -> Vec<&str> {
+    let mut types = setting
+        .get("types")
+        .and_then(Value::as_array)
+        .map(|types| types.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+        .unwrap_or_default();
+    if types.is_empty()
+        && let Some(sport) = setting.get("type").and_then(Value::as_str)
+    {
+        types.push(sport);
+    }
+    types
+}
+
+fn find_sport_setting<'a>(
+    settings: &'a [&serde_json::Map<String, Value>],
+    sport: &str,
+) -> Option<&'a serde_json::Map<String, Value>> {
+    settings.iter().copied().find(|setting| {
+        setting_types(setting)
+            .iter()
+            .any(|sport_type| sport_type.eq_ignore_ascii_case(sport))
+            || setting
+                .get("name")
+                .and_then(Value::as_str)
+                .is_some_and(|name| name.eq_ignore_ascii_case(sport))
+    })
+}
+
+fn zone_numbers(zones: Option<&Value>) -> Vec<f64> {
+    zones
+        .and_then(Value::as_array)
+        .map(|zones| zones.iter().filter_map(Value::as_f64).collect())
+        .unwrap_or_default()
+}
+
+fn positive_number(input: &Value, key: &str) -> Result<Option<f64>, IntentError> {
+    match input.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value
+            .as_f64()
+            .filter(|number| *number > 0.0)
+            .map(Some)
+            .ok_or_else(|| IntentError::validation(format!("{} must be a positive number", key))),
+    }
+}
+
+/// Pace as minutes (number) or "m:ss" (string), returned in minutes.
+fn pace_input(input: &Value, key: &str) -> Result<Option<f64>, IntentError> {
+    let Some(value) = input.get(key).filter(|value| !value.is_null()) else {
+        return Ok(None);
+    };
+    let minutes = match value {
+        Value::String(text) => text.split_once(':').and_then(|(minutes, seconds)| {
+            let minutes = minutes.trim().parse::<f64>().ok()?;
+            let seconds = seconds.trim().parse::<f64>().ok()?;
+            (seconds < 60.0).then_some(minutes + seconds / 60.0)
+        }),
+        _ => value.as_f64(),
+    };
+    minutes
+        .filter(|minutes| *minutes > 0.0)
+        .map(Some)
+        .ok_or_else(|| {
+            IntentError::validation(format!(
+                "{} must be a positive number or an \"m:ss\" string",
+                key
+            ))
+        })
+}
+
+fn zone_input(input: &Value, key: &str) -> Result<Option<Vec<f64>>, IntentError> {
+    let Some(value) = input.get(key).filter(|value| !value.is_null()) else {
+        return Ok(None);
+    };
+    let zones = value
+        .as_array()
+        .and_then(|zones| zones.iter().map(Value::as_f64).collect::<Option<Vec<_>>>())
+        .ok_or_else(|| IntentError::validation(format!("{} must be an array of numbers", key)))?;
+    validate_zone_bounds(&zones)
+        .map_err(|reason| IntentError::validation(format!("Invalid {}: {}", key, reason)))?;
+    Ok(Some(zones))
+}
+
+/// Before/after table of zone upper bounds; `None` marks the open-ended top zone.
+fn zone_shift_table(
+    label: &str,
+    before: &[Option<f64>],
+    after: &[Option<f64>],
+    format: impl Fn(f64) -> String,
+) -> Option<ContentBlock> {
+    let zones = before.len().max(after.len());
+    if zones == 0 {
+        return None;
+    }
+    let cell = |bounds: &[Option<f64>], index: usize| match bounds.get(index) {
+        Some(Some(value)) => format!("≤ {}", format(*value)),
+        Some(None) => "open".into(),
+        None => "-".into(),
+    };
+    Some(ContentBlock::table(
+        vec![label.into(), "Before".into(), "After".into()],
+        (0..zones)
+            .map(|index| {
+                vec![
+                    format!("Z{}", index + 1),
+                    cell(before, index),
+                    cell(after, index),
+                ]
+            })
+            .collect(),
+    ))
+}
+
+/// Sport setting whose types match `sports` and which has `key` configured.
+fn threshold_setting<'a>(
+    settings: &'a [&serde_json::Map<String, Value>],
+    sports: &[&str],
+    key: &str,
+) -> Option<(&'a serde_json::Map<String, Value>, String)> {
+    settings.iter().copied().find_map(|setting| {
+        let sport = setting_types(setting)
+            .into_iter()
+            .find(|sport| sports.contains(sport))?;
+        get_number(setting, &[key])
+            .filter(|value| *value > 0.0)
+            .map(|_| (setting, sport.to_string()))
+    })
+}
+
+async fn collect_threshold_drift(
+    input: &Value,
+    client: &dyn IntervalsClient,
+) -> Result<Vec<SportThresholdDrift>, IntentError> {
+    let tolerance_pct = input
+        .get("tolerance_pct")
+        .and_then(Value::as_f64)
+        .unwrap_or(DEFAULT_DRIFT_TOLERANCE_PCT)
+        .max(0.0);
+    let min_weeks = input
+        .get("min_weeks")
+        .and_then(Value::as_u64)
+        .unwrap_or(DEFAULT_DRIFT_MIN_WEEKS as u64)
+        .max(1) as usize;
+    let lookback_weeks = input
+        .get("lookback_weeks")
+        .and_then(Value::as_i64)
+        .unwrap_or(DEFAULT_DRIFT_LOOKBACK_WEEKS)
+        .clamp(1, DRIFT_MAX_LOOKBACK_WEEKS);
+    let days_back = (lookback_weeks * 7) as i32;
+
+    let sport_settings = client
+        .get_sport_settings()
+        .await
+        .map_err(|e| IntentError::api(format!("Failed to fetch sport settings: {}", e)))?;
+    let sport_settings_value = serde_json::to_value(&sport_settings).unwrap_or_else(|_| json!([]));
+    let sport_entries = sport_settings_entries(&sport_settings_value);
+
+    let wellness = client
+        .get_wellness(Some(days_back))
+        .await
+        .unwrap_or_else(|_| json!([]));
+    let activities = client
+        .get_recent_activities(Some(DRIFT_ACTIVITY_LIMIT), Some(days_back))
+        .await
+        .map_err(|e| IntentError::api(format!("Failed to fetch activities: {}", e)))?;
+
+    let mut ftp_samples = extract_eftp_history(&wellness);
+    let mut speed_samples = Vec::new();
+    for activity in &activities {
+        let Some(date) = activity
+            .start_date_local
+            .get(..10)
+            .and_then(|date| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        else {
+            continue;
+        };
+        let Ok(detail) = client.get_activity_details(&activity.id).await else {
+            continue;
+        };
+        ftp_samples.extend(activity_modelled_ftp(&detail).map(|ftp| (date, ftp)));
+        speed_samples.extend(activity_threshold_speed(&detail).map(|speed| (date, speed)));
+    }
+
+    let as_of = Utc::now().date_naive();
+    let mut drifts = Vec::new();
+    if let Some((setting, sport_type)) = threshold_setting(
+        &sport_entries,
+        &["Ride", "VirtualRide", "GravelRide", "MountainBikeRide"],
+        "ftp",
+    ) && let Some(configured) = get_number(setting, &["ftp"])
+        && let Some(mut drift) = detect_threshold_drift(
+            ThresholdMetric::Ftp,
+            configured,
+            &ftp_samples,
+            as_of,
+            lookback_weeks,
+            tolerance_pct,
+            min_weeks,
+        )
+    {
+        // Intervals.icu power zones are % of FTP; the last bound is an open-ended sentinel.
+        let zone_pcts = setting
+            .get("power_zones")
+            .and_then(Value::as_array)
+            .map(|zones| {
+                zones
+                    .iter()
+                    .filter_map(Value::as_f64)
+                    .filter(|pct| *pct < 500.0)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        drift.zone_shifts = compute_zone_shifts(&zone_pcts, configured, drift.modelled);
+        drifts.push(SportThresholdDrift { sport_type, drift });
+    }
+    if let Some((setting, sport_type)) = threshold_setting(
+        &sport_entries,
+        &["Run", "TrailRun", "VirtualRun"],
+        "threshold_pace",
+    ) && matches!(
+        setting.get("pace_units").and_then(Value::as_str),
+        None | Some("MINS_KM")
+    ) && let Some(configured) =
+        get_number(setting, &["threshold_pace"]).and_then(pace_min_per_km_to_speed)
+        && let Some(drift) = detect_threshold_drift(
+            ThresholdMetric::ThresholdPace,
+            configured,
+            &speed_samples,
+            as_of,
+            lookback_weeks,
+            tolerance_pct,
+            min_weeks,
+        )
+    {
+        drifts.push(SportThresholdDrift { sport_type, drift });
+    }
+    Ok(drifts)
+}
+
+fn format_threshold_value(metric: ThresholdMetric, value: f64) -> String {
+    match metric {
+        ThresholdMetric::Ftp => format!("{:.0} W", value),
+        ThresholdMetric::ThresholdPace => speed_to_pace_min_per_km(value)
+            .map(|pace| format_threshold_pace(pace, Some("MINS_KM")))
+            .unwrap_or_else(|| "-".into()),
+    }
+}
+
+fn render_threshold_drift(
+    drifts: &[SportThresholdDrift],
+    applied: Option<ThresholdDriftApplied>,
+) -> IntentOutput {
+    let mut content = vec![ContentBlock::markdown(
+        "# Threshold Drift\nConfigured sport-settings thresholds vs recent modelled values (best per week)."
+            .to_string(),
+    )];
+
+    if drifts.is_empty() {
+        content.push(ContentBlock::markdown(
+            "No configured FTP / threshold pace with recent modelled values to compare. \
+             Threshold drift needs eFTP (power rides) or hard 15-90 min runs in the lookback."
+                .to_string(),
+        ));
+        return IntentOutput::new(content)
+            .with_suggestions(vec![
+                "Set FTP / threshold pace in sport settings and record a hard effort to enable drift checks."
+                    .into(),
+            ])
+            .with_next_actions(vec![
+                "To estimate HR thresholds instead: manage_profile action: estimate_thresholds"
+                    .into(),
+            ]);
+    }
+
+    let rows = drifts
+        .iter()
+        .map(|entry| {
+            let drift = &entry.drift;
+            vec![
+                format!("{} ({})", drift.metric.label(), entry.sport_type),
+                format_threshold_value(drift.metric, drift.configured),
+                format_threshold_value(drift.metric, drift.modelled),
+                format!("{:+.1}%", drift.drift_pct),
+                drift.weeks_beyond_tolerance.to_string(),
+                if drift.stale { "stale" } else { "ok" }.into(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    content.push(ContentBlock::table(
+        vec![
+            "Threshold".into(),
+            "Configured".into(),
+            "Modelled".into(),
+            "Drift".into(),
+            "Weeks beyond tolerance".into(),
+            "Status".into(),
+        ],
+        rows,
+    ));
+
+    let mut suggestions = Vec::new();
+    for entry in drifts {
+        let drift = &entry.drift;
+        content.push(ContentBlock::table(
+            vec![
+                format!("{} week of", drift.metric.label()),
+                "Modelled".into(),
+                "Drift".into(),
+            ],
+            drift
+                .weekly
+                .iter()
+                .map(|week| {
+                    vec![
+                        week.week_start.to_string(),
+                        format_threshold_value(drift.metric, week.modelled),
+                        format!("{:+.1}%", week.drift_pct),
+                    ]
+                })
+                .collect(),
+        ));
+        if !drift.stale {
+            continue;
+        }
+        if !drift.zone_shifts.is_empty() {
+            content.push(ContentBlock::table(
+                vec![
+                    "Power zone".into(),
+                    "Current upper".into(),
+                    "Proposed upper".into(),
+                ],
+                drift
+                    .zone_shifts
+                    .iter()
+                    .map(|shift| {
+                        vec![
+                            shift.zone.clone(),
+                            format!("{:.0} W", shift.current_upper),
+                            format!("{:.0} W", shift.proposed_upper),
+                        ]
+                    })
+                    .collect(),
+            ));
+        }
+        suggestions.push(format!(
+            "{} for {} has drifted {:+.1}% for {} weeks; training load for the same session changes by {:+.0}% once updated.",
+            drift.metric.label(),
+            entry.sport_type,
+            drift.drift_pct,
+            drift.weeks_beyond_tolerance,
+            drift.load_impact_pct
+        ));
+    }
+
+    let stale = drifts.iter().filter(|entry| entry.drift.stale).count();
+    let mut next_actions = Vec::new();
+    match applied {
+        Some(applied) => {
+            content.push(ContentBlock::markdown(if applied.updated.is_empty() {
+                "No stale thresholds; sport settings were left unchanged.".to_string()
+            } else {
+                format!(
+                    "Updated sport settings: {}. HR zones recalculated: {}. {}",
+                    applied.updated.join(", "),
+                    if applied.recalc_hr_zones { "Yes" } else { "No" },
+                    if applied.apply_to_activities {
+                        "Historical activities were queued for recalculation."
+                    } else {
+                        "Historical activity recalculation was skipped."
+                    }
+                )
+            }));
+            next_actions.push("To verify zones: manage_profile action: get sections: zones".into());
+        }
+        None if stale > 0 => {
+            content.push(ContentBlock::markdown(
+                "Dry run: no changes were written.".to_string(),
+            ));
+            next_actions.push(
+                "To apply: manage_profile action: apply_threshold_drift idempotency_token: <token>"
+                    .into(),
+            );
+        }
+        None => {
+            suggestions.push("Configured thresholds match recent modelled values.".into());
+        }
+    }
+    next_actions.push("To review load trends: track_progress".into());
+
+    IntentOutput::new(content)
+        .with_suggestions(suggestions)
+        .with_next_actions(next_actions)
+}
+
+fn render_threshold_estimate(
+    estimate: &ThresholdEstimate,
+    current_aet: Option<i64>,
+    current_lt: Option<i64>,
+    runs_checked: usize,
+    days_back: i64,
+) -> IntentOutput {
+    let mut content = vec![ContentBlock::markdown(format!(
+        "# Threshold Estimate\nRuns analysed: {} (last {} days) — steady Z2 samples: {}, DFA α1 windows: {}",
+        runs_checked, days_back, estimate.steady_runs, estimate.dfa_windows
+    ))];
+
+    if !estimate.supported {
+        content.push(ContentBlock::markdown(estimate.notes.join("\n")));
+        return IntentOutput::new(content)
+            .with_suggestions(vec![
+                "Record 3-6 steady 45-90 min Z2 runs (flat route, constant pace) to bracket AeT."
+                    .into(),
+                "Wear a chest strap that records RR intervals to enable DFA α1 estimates.".into(),
+            ])
+            .with_next_actions(vec![
+                "To set thresholds manually: manage_profile action: update_thresholds".into(),
+            ]);
+    }
+
+    let format_bpm = |value: Option<i64>| {
+        value
+            .map(|bpm| format!("{} bpm", bpm))
+            .unwrap_or_else(|| "not set".into())
+    };
+    let format_delta = |current: Option<i64>, proposed: Option<i64>| match (current, proposed) {
+        (Some(current), Some(proposed)) => format!("{:+} bpm", proposed - current),
+        _ => "-".into(),
+    };
+    let estimated_aet = estimate.aet_hr.map(|hr| hr.round() as i64);
+    let estimated_lt = estimate.lt_hr.map(|hr| hr.round() as i64);
+
+    let mut rows = Vec::new();
+    rows.push(vec![
+        "AeT HR".into(),
+        format_bpm(current_aet),
+        estimated_aet
+            .map(|bpm| format!("{} bpm", bpm))
+            .unwrap_or_else(|| "insufficient data".into()),
+        format_delta(current_aet, estimated_aet),
+        estimate.aet_method.clone().unwrap_or_else(|| "-".into()),
+    ]);
+    rows.push(vec![
+        "LT HR".into(),
+        format_bpm(current_lt),
+        estimated_lt
+            .map(|bpm| format!("{} bpm", bpm))
+            .unwrap_or_else(|| "needs RR data".into()),
+        format_delta(current_lt, estimated_lt),
+        estimate.lt_method.clone().unwrap_or_else(|| "-".into()),
+    ]);
+    content.push(ContentBlock::table(
+        vec![
+            "Parameter".into(),
+            "Current".into(),
+            "Estimated".into(),
+            "Δ".into(),
+            "Method".into(),
+        ],
+        rows,
+    ));
+
+    let mut evidence = vec![format!(
+        "Confidence: {:.0}% (steady runs, DFA fit and method agreement)",
+        estimate.confidence * 100.0
+    )];
+    if let Some(boundary) = &estimate.decoupling {
+        evidence.push(format!(
+            "Decoupling boundary: coupled up to {}, decoupled from {}",
+            boundary
+                .coupled_max_hr
+                .map(|hr| format!("{:.0} bpm", hr))
+                .unwrap_or_else(|| "-".into()),
+            boundary
+                .decoupled_min_hr
+                .map(|hr| format!("{:.0} bpm", hr))
+                .unwrap_or_else(|| "-".into())
+        ));
+    }
+    if let Some(dfa) = &estimate.dfa {
+        evidence.push(format!(
+            "DFA α1 regression: R² {:.2} over {} windows",
+            dfa.r_squared, dfa.windows
+        ));
+    }
+    evidence.extend(estimate.notes.iter().cloned());
+    content.push(ContentBlock::markdown(evidence.join("\n")));
+
+    let mut suggestions = Vec::new();
+    let mut next_actions = Vec::new();
+    // update_thresholds needs both values: keep the current one where no estimate exists.
+    match (estimated_aet.or(current_aet), estimated_lt.or(current_lt)) {
+        (Some(new_aet), Some(new_lt)) if new_lt > new_aet => {
+            content.push(ContentBlock::markdown(format!(
+                "Dry run: update_thresholds\n  AeT HR: {} → {} bpm\n  LT HR: {} → {} bpm\n  No changes were written.",
+                format_bpm(current_aet),
+                new_aet,
+                format_bpm(current_lt),
+                new_lt
+            )));
+            next_actions.push(format!(
+                "To apply: manage_profile action: update_thresholds new_aet_hr: {} new_lt_hr: {} thresholds_source: estimated idempotency_token: <token>",
+                new_aet, new_lt
+            ));
+        }
+        _ => suggestions.push(
+            "Set an LT HR (manual or lab) or record RR data before applying the AeT estimate."
+                .into(),
+        ),
+    }
+    if estimate.confidence < ESTIMATE_LOW_CONFIDENCE {
+        suggestions.push(
+            "Confidence is low; confirm with a field test before applying the proposed thresholds."
+                .into(),
+        );
+    }
+    next_actions.push("To review current zones: manage_profile action: get sections: zones".into());
+
+    IntentOutput::new(content)
+        .with_suggestions(suggestions)
+        .with_next_actions(next_actions)
 }
 
 impl Default for ManageProfileHandler {
@@ -590,6 +1616,7 @@ mod tests {
     fn test_requires_idempotency_token() {
         let handler = ManageProfileHandler::new();
         assert!(!IntentHandler::requires_idempotency_token(&handler));
+        assert!(IntentHandler::accepts_idempotency_token(&handler));
     }
 
     // ========================================================================
@@ -1260,4 +2287,391 @@ mod tests {
                 .any(|a| a.contains("assess_recovery"))
         );
     }
+
+    fn steady_run_streams() -> Value {
+        let hr = (0..2400)
+            .map(|i| if i < 1200 { 140.0 } else { 143.0 })
+            .collect::<Vec<_>>();
+        json!({"heartrate": hr, "velocity_smooth": vec![3.0; 2400]})
+    }
+
+    #[tokio::test]
+    async fn test_execute_estimate_thresholds_returns_dry_run_diff() {
+        let client = profile_mock_client()
+            .with_activities(vec![intervals_icu_client::ActivitySummary {
+                id: "run-1".into(),
+                start_date_local: "2026-05-01".into(),
+                ..Default::default()
+            }])
+            .with_activity_detail("run-1", json!({"type": "Run"}))
+            .with_streams(steady_run_streams());
+        let output = ManageProfileHandler::new()
+            .execute(
+                json!({"action": "estimate_thresholds"}),
+                Arc::new(client),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("Threshold Estimate"));
+        assert!(content_str.contains("steady Z2 samples: 1"));
+        assert!(content_str.contains("decoupling"));
+        assert!(content_str.contains("needs RR data"));
+        assert!(content_str.contains("Dry run: update_thresholds"));
+        assert!(content_str.contains("No changes were written"));
+        assert!(
+            output
+                .next_actions
+                .iter()
+                .any(|a| a.contains("new_lt_hr: 170") && a.contains("thresholds_source: estimated"))
+        );
+        assert!(
+            output
+                .suggestions
+                .iter()
+                .any(|s| s.contains("Confidence is low"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_estimate_thresholds_without_runs_is_unsupported() {
+        let client = profile_mock_client()
+            .with_activities(vec![intervals_icu_client::ActivitySummary {
+                id: "ride-1".into(),
+                start_date_local: "2026-05-01".into(),
+                ..Default::default()
+            }])
+            .with_activity_detail("ride-1", json!({"type": "Ride"}));
+        let output = ManageProfileHandler::new()
+            .execute(
+                json!({"action": "estimate_thresholds"}),
+                Arc::new(client),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("cannot be estimated"));
+        assert!(!content_str.contains("Dry run"));
+    }
+
+    fn drift_mock_client(modelled_ftp: f64) -> MockIntervalsClient {
+        let today = Utc::now().date_naive();
+        let activities = (0..4)
+            .map(|week| intervals_icu_client::ActivitySummary {
+                id: format!("ride-{}", week),
+                start_date_local: (today - chrono::Duration::days(week * 7 + 1)).to_string(),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        let mut client = MockIntervalsClient::builder()
+            .with_sport_settings(intervals_icu_client::domains::workout::SportSettings {
+                sports: vec![intervals_icu_client::domains::workout::SportSetting {
+                    name: Some("Ride".into()),
+                    types: Some(vec!["Ride".into(), "VirtualRide".into()]),
+                    ftp: Some(250.0),
+                    power_zones: vec![json!(55), json!(75), json!(90), json!(105), json!(999)],
+                    ..Default::default()
+                }],
+                age: None,
+                weight: None,
+            })
+            .with_activities(activities);
+        for week in 0..4 {
+            client = client.with_activity_detail(
+                &format!("ride-{}", week),
+                json!({"type": "Ride", "icu_pm_ftp": modelled_ftp}),
+            );
+        }
+        client
+    }
+
+    #[tokio::test]
+    async fn test_execute_detect_threshold_drift_flags_stale_ftp() {
+        let output = ManageProfileHandler::new()
+            .execute(
+                json!({"action": "detect_threshold_drift"}),
+                Arc::new(drift_mock_client(272.0)),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("Threshold Drift"));
+        assert!(content_str.contains("stale"));
+        assert!(content_str.contains("+8.8%"));
+        // Z2 upper bound moves from 188 W to 204 W.
+        assert!(content_str.contains("Power zone"));
+        assert!(content_str.contains("204 W"));
+        assert!(content_str.contains("Dry run"));
+        assert!(output.suggestions.iter().any(|s| s.contains("-16%")));
+        assert!(
+            output
+                .next_actions
+                .iter()
+                .any(|a| a.contains("apply_threshold_drift"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_detect_threshold_drift_within_tolerance() {
+        let output = ManageProfileHandler::new()
+            .execute(
+                json!({"action": "detect_threshold_drift"}),
+                Arc::new(drift_mock_client(255.0)),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("ok"));
+        assert!(!content_str.contains("Dry run"));
+        assert!(
+            output
+                .suggestions
+                .iter()
+                .any(|s| s.contains("match recent modelled values"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_apply_threshold_drift_updates_stale_values() {
+        let handler = ManageProfileHandler::new();
+        let missing_token = handler
+            .execute(
+                json!({"action": "apply_threshold_drift"}),
+                Arc::new(drift_mock_client(272.0)),
+                None,
+            )
+            .await;
+        assert!(matches!(
+            missing_token.unwrap_err(),
+            IntentError::ValidationError(_)
+        ));
+
+        let output = handler
+            .execute(
+                json!({
+                    "action": "apply_threshold_drift",
+                    "apply_to_activities": false,
+                    "idempotency_token": "drift-token"
+                }),
+                Arc::new(drift_mock_client(272.0)),
+                None,
+            )
+            .await
+            .unwrap();
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("Updated sport settings: FTP (Ride)"));
+        assert!(content_str.contains("HR zones recalculated: Yes"));
+        assert!(content_str.contains("Historical activity recalculation was skipped"));
+        assert!(!content_str.contains("Dry run"));
+    }
+
+    fn multi_sport_mock_client() -> MockIntervalsClient {
+        use intervals_icu_client::domains::workout::{SportSetting, SportSettings};
+        MockIntervalsClient::builder().with_sport_settings(SportSettings {
+            sports: vec![
+                SportSetting {
+                    name: Some("Run".into()),
+                    types: Some(vec!["Run".into()]),
+                    lthr: Some(170.0),
+                    max_hr: Some(192.0),
+                    threshold_pace: Some(4.5),
+                    pace_units: Some("MINS_KM".into()),
+                    hr_zones: vec![json!(140), json!(155), json!(165), json!(175), json!(192)],
+                    pace_zones: vec![
+                        json!(77.5),
+                        json!(87.7),
+                        json!(94.3),
+                        json!(100),
+                        json!(999),
+                    ],
+                    ..Default::default()
+                },
+                SportSetting {
+                    name: Some("Ride".into()),
+                    types: Some(vec!["Ride".into(), "VirtualRide".into()]),
+                    ftp: Some(250.0),
+                    indoor_ftp: Some(240.0),
+                    power_zones: vec![json!(55), json!(75), json!(90), json!(105), json!(999)],
+                    ..Default::default()
+                },
+                SportSetting {
+                    name: Some("Swim".into()),
+                    types: Some(vec!["Swim".into()]),
+                    threshold_pace: Some(1.75),
+                    pace_units: Some("SECS_100M".into()),
+                    ..Default::default()
+                },
+            ],
+            age: None,
+            weight: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_execute_update_thresholds_dry_run_previews_zone_model() {
+        let output = ManageProfileHandler::new()
+            .execute(
+                json!({
+                    "action": "update_thresholds",
+                    "sport": "ride",
+                    "new_ftp": 260,
+                    "new_indoor_ftp": 250,
+                    "zone_model": "coggan",
+                    "dry_run": true
+                }),
+                Arc::new(multi_sport_mock_client()),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("Preview (dry_run)"));
+        assert!(content_str.contains("Sport: Ride"));
+        assert!(content_str.contains("+10 W"));
+        assert!(content_str.contains("Indoor FTP"));
+        // Z1 keeps 55% but moves with FTP; Coggan adds Z6/Z7.
+        assert!(content_str.contains("≤ 138 W"));
+        assert!(content_str.contains("≤ 143 W"));
+        assert!(content_str.contains("≤ 390 W"));
+        assert!(content_str.contains("Zone model: Coggan"));
+        assert!(content_str.contains("no changes were written"));
+        assert!(
+            !output
+                .next_actions
+                .iter()
+                .any(|a| a.contains("assess_recovery"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_update_thresholds_zone_model_skips_hr_without_lthr() {
+        use intervals_icu_client::domains::workout::{SportSetting, SportSettings};
+        let client = MockIntervalsClient::builder().with_sport_settings(SportSettings {
+            sports: vec![SportSetting {
+                name: Some("Ride".into()),
+                types: Some(vec!["Ride".into()]),
+                ftp: Some(250.0),
+                max_hr: Some(185.0),
+                power_zones: vec![json!(55), json!(75), json!(90), json!(105), json!(999)],
+                hr_zones: vec![json!(130), json!(150), json!(165), json!(185)],
+                ..Default::default()
+            }],
+            age: None,
+            weight: None,
+        });
+        let output = ManageProfileHandler::new()
+            .execute(
+                json!({
+                    "action": "update_thresholds",
+                    "sport": "Ride",
+                    "zone_model": "friel",
+                    "dry_run": true
+                }),
+                Arc::new(client),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("HR zones unchanged: no LT HR is set for Ride"));
+        assert!(!content_str.contains("bpm"));
+        assert!(content_str.contains("Power zone"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_update_thresholds_run_pace_and_hr_model() {
+        let output = ManageProfileHandler::new()
+            .execute(
+                json!({
+                    "action": "update_thresholds",
+                    "new_threshold_pace": "4:20",
+                    "new_max_hr": 194,
+                    "zone_model": "friel",
+                    "idempotency_token": "pace-token"
+                }),
+                Arc::new(multi_sport_mock_client()),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("Threshold pace"));
+        assert!(content_str.contains("4:30 /km"));
+        assert!(content_str.contains("4:20 /km"));
+        assert!(content_str.contains("-10 s"));
+        assert!(content_str.contains("HR zone"));
+        assert!(content_str.contains("≤ 143 bpm"));
+        assert!(content_str.contains("≤ 194 bpm"));
+        assert!(content_str.contains("Pace zone"));
+        assert!(content_str.contains("Updated sport settings for Run"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_update_thresholds_swim_css() {
+        let handler = ManageProfileHandler::new();
+        let output = handler
+            .execute(
+                json!({
+                    "action": "update_thresholds",
+                    "sport": "Swim",
+                    "new_css": "1:40",
+                    "idempotency_token": "css-token"
+                }),
+                Arc::new(multi_sport_mock_client()),
+                None,
+            )
+            .await
+            .unwrap();
+        let content_str = content_text(&output.content);
+        assert!(content_str.contains("CSS"));
+        assert!(content_str.contains("105.0 sec/100m"));
+        assert!(content_str.contains("100.0 sec/100m"));
+
+        let wrong_sport = handler
+            .execute(
+                json!({
+                    "action": "update_thresholds",
+                    "new_css": 100,
+                    "idempotency_token": "css-token-2"
+                }),
+                Arc::new(multi_sport_mock_client()),
+                None,
+            )
+            .await;
+        assert!(matches!(
+            wrong_sport.unwrap_err(),
+            IntentError::ValidationError(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execute_update_thresholds_rejects_invalid_edits() {
+        let handler = ManageProfileHandler::new();
+        for input in [
+            json!({"action": "update_thresholds", "dry_run": true}),
+            json!({"action": "update_thresholds", "dry_run": true, "hr_zones": [150, 140]}),
+            json!({"action": "update_thresholds", "dry_run": true, "zone_model": "zone2"}),
+            json!({"action": "update_thresholds", "dry_run": true, "sport": "Rowing", "new_ftp": 200}),
+            json!({"action": "update_thresholds", "dry_run": true, "new_ftp": -5}),
+        ] {
+            let result = handler
+                .execute(input.clone(), Arc::new(multi_sport_mock_client()), None)
+                .await;
+            assert!(
+                matches!(result, Err(IntentError::ValidationError(_))),
+                "{input}"
+            );
+        }
+    }
 }
@@ -557,6 +557,7 @@ impl ModifyTrainingHandler {
             category: event_category,
             description: new_description,
             r#type: new_type,
+            end_date_local: None,
         })
         .map_err(|e| IntentError::validation(validation_error_to_string(e)))?;
 
@@ -883,6 +884,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: Some("first copy".to_string()),
                 r#type: Some("Run".to_string()),
+                end_date_local: None,
             },
             Event {
                 id: Some("event-1".to_string()),
@@ -891,6 +893,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: Some("duplicate id".to_string()),
                 r#type: Some("Run".to_string()),
+                end_date_local: None,
             },
             Event {
                 id: None,
@@ -899,6 +902,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: Some("fallback key".to_string()),
                 r#type: Some("Gym".to_string()),
+                end_date_local: None,
             },
             Event {
                 id: None,
@@ -907,6 +911,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: Some("duplicate fallback key".to_string()),
                 r#type: Some("Gym".to_string()),
+                end_date_local: None,
             },
             Event {
                 id: None,
@@ -915,6 +920,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: Some("unique fallback key".to_string()),
                 r#type: Some("Run".to_string()),
+                end_date_local: None,
             },
         ]);
 
@@ -1384,6 +1390,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         };
         assert!(ModifyTrainingHandler::event_matches_description(
             &event, "tempo"
@@ -1409,6 +1416,7 @@ mod tests {
             category: EventCategory::Workout,
             description: Some("Threshold intervals at lactate turnpoint".to_string()),
             r#type: None,
+            end_date_local: None,
         };
         assert!(ModifyTrainingHandler::event_matches_description(
             &event,
@@ -1432,6 +1440,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         };
         assert!(ModifyTrainingHandler::event_matches_description(
             &event, "long"
@@ -1479,6 +1488,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: Some("e2".to_string()),
@@ -1487,6 +1497,7 @@ mod tests {
                 category: EventCategory::RaceA,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
         ];
         let deduped = ModifyTrainingHandler::dedupe_events(events);
@@ -1503,6 +1514,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: None,
@@ -1511,6 +1523,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
         ];
         let deduped = ModifyTrainingHandler::dedupe_events(events);
@@ -1536,6 +1549,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         }]));
 
         let input = json!({
@@ -1564,6 +1578,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         }]));
 
         let input = json!({
@@ -1613,6 +1628,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: Some("event-124".to_string()),
@@ -1621,6 +1637,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: Some("Threshold workout".to_string()),
                 r#type: None,
+                end_date_local: None,
             },
         ]));
 
@@ -1649,6 +1666,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         }]));
 
         let input = json!({
@@ -1678,6 +1696,7 @@ mod tests {
                     category: EventCategory::Workout,
                     description: None,
                     r#type: None,
+                    end_date_local: None,
                 }])
                 .with_update_error("API error"),
         );
@@ -1705,6 +1724,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: Some("event-124".to_string()),
@@ -1713,6 +1733,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
         ]));
 
@@ -1954,6 +1975,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         }]));
 
         let input = json!({
@@ -1981,6 +2003,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         }]));
 
         let input = json!({
@@ -2009,6 +2032,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: Some("event-124".to_string()),
@@ -2017,6 +2041,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
         ]));
 
@@ -2045,6 +2070,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: Some("event-124".to_string()),
@@ -2053,6 +2079,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: Some("Threshold workout".to_string()),
                 r#type: None,
+                end_date_local: None,
             },
         ]));
 
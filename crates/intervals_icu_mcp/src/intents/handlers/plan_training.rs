@@ -5,14 +5,31 @@ use async_trait::async_trait;
 use chrono::Datelike;
 use intervals_icu_client::IntervalsClient;
 use serde_json::{Value, json};
+use std::collections::HashSet;
 /// Plan Training Intent Handler
 ///
 /// Plans training across various horizons (microcycle to annual plan).
 use std::sync::Arc;
 
 use crate::domains::events::validate_and_prepare_event;
+use crate::engines::analysis_fetch::fetch_heat_acclimation;
 use crate::engines::coach_metrics::parse_fitness_metrics;
 use crate::engines::forecast::project_tsb;
+use crate::engines::health_episodes::{
+    ReturnRampWeek, build_health_episodes, episode_label, episode_needing_return,
+    remaining_return_ramp,
+};
+use crate::engines::heat_acclimation::{
+    AcclimationProtocol, HEAT_EXPOSURE_THRESHOLD_C, build_acclimation_protocol,
+};
+use crate::engines::menstrual_cycle::{
+    CyclePhase, DEFAULT_CYCLE_LENGTH_DAYS, MAX_CYCLE_LENGTH_DAYS, MIN_CYCLE_LENGTH_DAYS,
+    last_period_start, predict_phase,
+};
+use crate::engines::planning::TrainingPhase;
+use crate::engines::strength::{
+    next_weekday, place_strength_days, strength_session_for_phase, strength_sessions_per_week,
+};
 use crate::intents::utils::parse_date;
 
 pub struct PlanTrainingHandler;
@@ -54,6 +71,30 @@ impl TrainingFocus {
             Self::Recovery => "recovery",
         }
     }
+
+    /// Phase used for strength prescription.
+    fn strength_phase(self) -> TrainingPhase {
+        match self {
+            Self::AerobicBase => TrainingPhase::EarlyBase,
+            Self::Intensity => TrainingPhase::Build,
+            Self::Specific => TrainingPhase::Specific,
+            Self::Taper => TrainingPhase::Taper,
+            Self::Recovery => TrainingPhase::Recovery,
+        }
+    }
+
+    /// Quality endurance days in the generated week, kept free of strength work.
+    fn key_days(self) -> &'static [chrono::Weekday] {
+        match self {
+            Self::AerobicBase => &[chrono::Weekday::Sun],
+            Self::Intensity | Self::Specific | Self::Taper => &[
+                chrono::Weekday::Tue,
+                chrono::Weekday::Thu,
+                chrono::Weekday::Sun,
+            ],
+            Self::Recovery => &[],
+        }
+    }
 }
 
 #[async_trait]
@@ -68,7 +109,11 @@ impl IntentHandler for PlanTrainingHandler {
          anchors from calendar, conflict detection against existing events, and \
          Banister TSB forecast (CTL/ATL/TSB projection with fatigue class per \
          milestone). Adaptive mode uses current fitness (TSB, CTL, ATL) and \
-         wellness (readiness, HRV, sleep) to calibrate volume and detect overshoot.
+         wellness (readiness, HRV, sleep) to calibrate volume and detect overshoot. \
+         A hot target race (race_temperature_c) adds a heat acclimation protocol \
+         based on recent heat exposure. Schedules 1-3 strength sessions per week \
+         by phase, placed away from key endurance days. An optional cycle_preference \
+         moves key sessions into the athlete's better-tolerated menstrual-cycle phases.
          
          Use this tool when: you need to create a race preparation plan, periodize \
          training for a target event, or generate structured weekly workouts. \
@@ -87,6 +132,18 @@ impl IntentHandler for PlanTrainingHandler {
                 "target_race": {"type": "string", "description": "Target race (description)"},
                 "max_hours_per_week": {"type": "number", "description": "Maximum hours per week"},
                 "adaptive": {"type": "boolean", "default": true, "description": "Adaptive planning based on current state"},
+                "race_temperature_c": {"type": "number", "description": "Expected race-day temperature (°C); hot races add a heat acclimation protocol"},
+                "strength_sessions_per_week": {"type": "integer", "minimum": 0, "maximum": 3, "description": "Strength sessions per week (default by phase: 1-3; 0 disables). Not accepted with focus recovery, whose weeks already include a strength maintenance day"},
+                "cycle_preference": {
+                    "type": "object",
+                    "description": "Bias key sessions toward better-tolerated menstrual-cycle phases",
+                    "properties": {
+                        "preferred_phases": {"type": "array", "items": {"type": "string", "enum": ["period", "follicular", "ovulating", "luteal"]}, "description": "Phases in which key sessions feel best"},
+                        "last_period_start": {"type": "string", "description": "First day of the last period (YYYY-MM-DD); defaults to the latest logged period in wellness"},
+                        "cycle_length_days": {"type": "integer", "minimum": 21, "maximum": 40, "default": 28, "description": "Typical cycle length"}
+                    },
+                    "required": ["preferred_phases"]
+                },
                 "idempotency_token": {"type": "string", "description": "Idempotency token (required)"}
             },
             "required": ["period_start", "period_end", "idempotency_token"]
@@ -117,6 +174,28 @@ impl IntentHandler for PlanTrainingHandler {
             .get("adaptive")
             .and_then(Value::as_bool)
             .unwrap_or(true);
+        let strength_sessions = match input.get("strength_sessions_per_week") {
+            None | Some(Value::Null) => None,
+            Some(value) => Some(
+                value
+                    .as_u64()
+                    .filter(|n| *n <= 3)
+                    .and_then(|n| u8::try_from(n).ok())
+                    .ok_or_else(|| {
+                        IntentError::validation(
+                            "strength_sessions_per_week must be an integer between 0 and 3",
+                        )
+                    })?,
+            ),
+        };
+        if focus == TrainingFocus::Recovery && strength_sessions.is_some_and(|n| n > 0) {
+            return Err(IntentError::validation(
+                "strength_sessions_per_week is not used with focus recovery: recovery weeks \
+                 already include a mobility and strength maintenance day. Omit it or pass 0.",
+            ));
+        }
+
+        let cycle_preference = CyclePreference::parse(&input)?;
 
         let start_date = parse_date(period_start, "period_start")?;
         let end_date = parse_date(period_end, "period_end")?;
@@ -167,43 +246,42 @@ impl IntentHandler for PlanTrainingHandler {
             .ok();
 
         // --- Historical volume (actual weeks, not hardcoded) ---
-        let (historical_avg_hours, historical_weeks) = if adaptive {
-            client
-                .get_recent_activities(Some(60), Some(56))
-                .await
-                .ok()
-                .and_then(|activities| {
-                    let dated: Vec<(chrono::NaiveDate, f64, f64)> = activities
-                        .iter()
-                        .filter_map(|a| {
-                            let date =
-                                chrono::NaiveDate::parse_from_str(&a.start_date_local, "%Y-%m-%d")
-                                    .ok()?;
-                            let moving_secs = a.moving_time? as f64;
-                            let elapsed_secs = a.elapsed_time? as f64;
-                            Some((date, moving_secs, elapsed_secs))
-                        })
-                        .collect();
-                    if dated.is_empty() {
-                        return None;
-                    }
-                    let oldest = dated.iter().map(|(d, _, _)| *d).min()?;
-                    let newest = dated.iter().map(|(d, _, _)| *d).max()?;
-                    let weeks = ((newest - oldest).num_days() as f64 / 7.0).max(1.0);
-                    let total_moving_seconds: f64 = dated.iter().map(|(_, s, _)| s).sum();
-                    let total_elapsed_seconds: f64 = dated.iter().map(|(_, _, e)| e).sum();
-                    Some((
-                        (
-                            total_moving_seconds / 3600.0 / weeks,
-                            total_elapsed_seconds / 3600.0 / weeks,
-                        ),
-                        weeks,
-                    ))
-                })
-                .unzip()
+        let recent_activities = if adaptive {
+            client.get_recent_activities(Some(60), Some(56)).await.ok()
         } else {
-            (None, None)
+            None
         };
+        let (historical_avg_hours, historical_weeks) = recent_activities
+            .as_ref()
+            .and_then(|activities| {
+                let dated: Vec<(chrono::NaiveDate, f64, f64)> = activities
+                    .iter()
+                    .filter_map(|a| {
+                        let date =
+                            chrono::NaiveDate::parse_from_str(&a.start_date_local, "%Y-%m-%d")
+                                .ok()?;
+                        let moving_secs = a.moving_time? as f64;
+                        let elapsed_secs = a.elapsed_time? as f64;
+                        Some((date, moving_secs, elapsed_secs))
+                    })
+                    .collect();
+                if dated.is_empty() {
+                    return None;
+                }
+                let oldest = dated.iter().map(|(d, _, _)| *d).min()?;
+                let newest = dated.iter().map(|(d, _, _)| *d).max()?;
+                let weeks = ((newest - oldest).num_days() as f64 / 7.0).max(1.0);
+                let total_moving_seconds: f64 = dated.iter().map(|(_, s, _)| s).sum();
+                let total_elapsed_seconds: f64 = dated.iter().map(|(_, _, e)| e).sum();
+                Some((
+                    (
+                        total_moving_seconds / 3600.0 / weeks,
+                        total_elapsed_seconds / 3600.0 / weeks,
+                    ),
+                    weeks,
+                ))
+            })
+            .unzip();
 
         // --- Parse extracted data ---
         let sport_info = sport_settings
@@ -216,6 +294,18 @@ impl IntentHandler for PlanTrainingHandler {
             .map(WellnessSnapshot::from_value)
             .unwrap_or_default();
 
+        // --- Illness / injury return ramp ---
+        let episodes = build_health_episodes(
+            &past_events,
+            wellness.as_ref(),
+            recent_activities.as_deref().unwrap_or_default(),
+            None,
+            start_date,
+        );
+        let return_ramp = episode_needing_return(&episodes, start_date)
+            .map(|episode| (episode.clone(), remaining_return_ramp(episode, start_date)))
+            .filter(|(_, ramp)| !ramp.is_empty());
+
         // --- Conflict detection (Fix 1+2: exclude RaceA/B, check upcoming) ---
         // Non-race existing events in period
         let existing_conflicts: Vec<&intervals_icu_client::Event> = past_events
@@ -357,6 +447,24 @@ impl IntentHandler for PlanTrainingHandler {
             }
         }
 
+        // --- Heat acclimation for a hot target race ---
+        let race_temperature_c = input.get("race_temperature_c").and_then(Value::as_f64);
+        let acclimation_protocol = match race_temperature_c {
+            Some(temperature) if temperature > HEAT_EXPOSURE_THRESHOLD_C => {
+                let race_date = race_anchors
+                    .iter()
+                    .filter_map(|(date, _, _)| {
+                        chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+                    })
+                    .min()
+                    .unwrap_or(end_date);
+                let today = chrono::Local::now().date_naive();
+                let acclimation = fetch_heat_acclimation(client.as_ref(), today).await;
+                build_acclimation_protocol(&acclimation, race_date, temperature, today)
+            }
+            _ => None,
+        };
+
         // --- Build output ---
         let athlete_name = profile.name.as_deref().unwrap_or("Athlete");
         let mut content = Vec::new();
@@ -472,6 +580,12 @@ impl IntentHandler for PlanTrainingHandler {
             ));
         }
 
+        if let Some(protocol) = &acclimation_protocol {
+            content.push(ContentBlock::markdown(render_acclimation_protocol(
+                protocol,
+            )));
+        }
+
         // --- Periodization ---
         let (phases, structure) = self.build_periodization(weeks, focus, max_hours);
 
@@ -539,7 +653,55 @@ impl IntentHandler for PlanTrainingHandler {
         )));
 
         // --- Task 5: Generate and create events ---
-        let events_to_create = generate_events(&phases, start_date, focus, weeks);
+        let mut events_to_create = generate_events(&phases, start_date, focus, weeks);
+        let mut key_dates = key_session_dates(&events_to_create, focus.key_days());
+        if let Some(preference) = &cycle_preference {
+            let period_start = match preference.last_period_start {
+                Some(date) => Some(date),
+                None => client
+                    .get_wellness(Some(CYCLE_HISTORY_DAYS))
+                    .await
+                    .ok()
+                    .and_then(|value| value.as_array().and_then(|e| last_period_start(e))),
+            };
+            content.push(ContentBlock::markdown(match period_start {
+                Some(period_start) => {
+                    let (moved, remaining) = bias_key_sessions_by_cycle(
+                        &mut events_to_create,
+                        &mut key_dates,
+                        preference,
+                        period_start,
+                    );
+                    format!(
+                        "Cycle-Aware Placement\n  Preferred phases: {}\n  Moved {} key session(s) into preferred phases; {} remain in other phases (adjust by feel).",
+                        preference
+                            .preferred_phases
+                            .iter()
+                            .map(|phase| phase.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        moved,
+                        remaining
+                    )
+                }
+                None => "Cycle-Aware Placement\n  Skipped: no last_period_start given and no period logged in recent wellness.".to_string(),
+            }));
+        }
+        let strength_events =
+            generate_strength_events(start_date, focus, weeks, strength_sessions, &key_dates);
+        if !strength_events.is_empty() {
+            content.push(ContentBlock::markdown(render_strength_plan(
+                focus,
+                strength_sessions,
+            )));
+        }
+        events_to_create.extend(strength_events);
+        if let Some((episode, ramp)) = &return_ramp {
+            let eased = apply_return_ramp(&mut events_to_create, ramp, start_date, &key_dates);
+            content.push(ContentBlock::markdown(render_return_ramp(
+                episode, ramp, eased,
+            )));
+        }
         let events_count = u32::try_from(events_to_create.len()).unwrap_or(0);
 
         let validated_events: Result<Vec<_>, _> = events_to_create
@@ -611,6 +773,17 @@ impl IntentHandler for PlanTrainingHandler {
             ));
         }
 
+        if let Some(protocol) = &acclimation_protocol {
+            suggestions.push(format!(
+                "Hot race ({:.0} °C): start heat acclimation on {} — {} sessions of {}-{} min.",
+                protocol.race_temperature_c,
+                protocol.start_date,
+                protocol.sessions,
+                protocol.min_minutes,
+                protocol.max_minutes
+            ));
+        }
+
         // Task 4: Volume overshoot warning
         if let Some((moving_avg, _elapsed_avg)) = historical_avg_hours
             && max_hours > moving_avg * 1.3
@@ -643,6 +816,34 @@ impl IntentHandler for PlanTrainingHandler {
     }
 }
 
+fn render_acclimation_protocol(protocol: &AcclimationProtocol) -> String {
+    let mut lines = vec![
+        "Heat Acclimation Protocol".to_string(),
+        format!(
+            "  Race: {} at ~{:.0} °C",
+            protocol.race_date, protocol.race_temperature_c
+        ),
+        format!(
+            "  Block: {} to {} — {} heat sessions, {}-{} min each",
+            protocol.start_date,
+            protocol.final_exposure_date,
+            protocol.sessions,
+            protocol.min_minutes,
+            protocol.max_minutes
+        ),
+        "  Sessions: easy aerobic training in heat, overdressing, or 30-40 min sauna/hot bath after training".to_string(),
+        "  Keep intensity low for the first 4-5 exposures; increase fluids and sodium".to_string(),
+        "  Last heat exposure 2 days before the race; adaptations decay ~2.5%/day after".to_string(),
+    ];
+    if protocol.compressed {
+        lines.push(
+            "  Short lead time: expect partial adaptation (most gains arrive in the first 5-7 days)"
+                .to_string(),
+        );
+    }
+    lines.join("\n")
+}
+
 #[must_use]
 struct Phase {
     name: String,
@@ -778,6 +979,7 @@ fn generate_events(
                 category: intervals_icu_client::EventCategory::Workout,
                 description: Some(description.to_string()),
                 r#type: None,
+                end_date_local: None,
             });
         }
     }
@@ -785,6 +987,281 @@ fn generate_events(
     events
 }
 
+/// Wellness history scanned for the latest logged period start.
+const CYCLE_HISTORY_DAYS: i32 = 45;
+
+/// Athlete preference for placing key sessions by menstrual-cycle phase.
+#[derive(Debug, Clone, PartialEq)]
+struct CyclePreference {
+    preferred_phases: Vec<CyclePhase>,
+    last_period_start: Option<chrono::NaiveDate>,
+    cycle_length_days: i64,
+}
+
+impl CyclePreference {
+    fn parse(input: &Value) -> Result<Option<Self>, IntentError> {
+        let Some(raw) = input.get("cycle_preference").filter(|v| !v.is_null()) else {
+            return Ok(None);
+        };
+        let preferred_phases = raw
+            .get("preferred_phases")
+            .and_then(Value::as_array)
+            .map(|phases| {
+                phases
+                    .iter()
+                    .map(|phase| phase.as_str().and_then(CyclePhase::parse))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .unwrap_or_default()
+            .filter(|phases| !phases.is_empty())
+            .ok_or_else(|| {
+                IntentError::validation(
+                    "cycle_preference.preferred_phases must list period, follicular, ovulating or luteal",
+                )
+            })?;
+        let last_period_start = raw
+            .get("last_period_start")
+            .and_then(Value::as_str)
+            .map(|date| parse_date(date, "cycle_preference.last_period_start"))
+            .transpose()?;
+        let cycle_length_days = match raw.get("cycle_length_days") {
+            None | Some(Value::Null) => DEFAULT_CYCLE_LENGTH_DAYS,
+            Some(value) => value
+                .as_i64()
+                .filter(|days| (MIN_CYCLE_LENGTH_DAYS..=MAX_CYCLE_LENGTH_DAYS).contains(days))
+                .ok_or_else(|| {
+                    IntentError::validation(format!(
+                        "cycle_preference.cycle_length_days must be between {MIN_CYCLE_LENGTH_DAYS} and {MAX_CYCLE_LENGTH_DAYS}"
+                    ))
+                })?,
+        };
+        Ok(Some(Self {
+            preferred_phases,
+            last_period_start,
+            cycle_length_days,
+        }))
+    }
+}
+
+/// Dates of the key endurance sessions: planned sessions on the focus's key days.
+fn key_session_dates(
+    events: &[intervals_icu_client::Event],
+    key_days: &[chrono::Weekday],
+) -> HashSet<chrono::NaiveDate> {
+    events
+        .iter()
+        .filter(|event| event.r#type.is_none())
+        .filter_map(|event| {
+            chrono::NaiveDate::parse_from_str(&event.start_date_local, "%Y-%m-%d").ok()
+        })
+        .filter(|date| key_days.contains(&date.weekday()))
+        .collect()
+}
+
+/// Swap key endurance sessions that fall in a non-preferred cycle phase with an
+/// easier session of the same week that falls in a preferred phase, moving their
+/// dates in `key_dates` along with them.
+/// Returns (sessions moved, key sessions left in non-preferred phases).
+fn bias_key_sessions_by_cycle(
+    events: &mut [intervals_icu_client::Event],
+    key_dates: &mut HashSet<chrono::NaiveDate>,
+    preference: &CyclePreference,
+    period_start: chrono::NaiveDate,
+) -> (usize, usize) {
+    let dated = events
+        .iter()
+        .map(|event| chrono::NaiveDate::parse_from_str(&event.start_date_local, "%Y-%m-%d").ok())
+        .collect::<Vec<_>>();
+    let preferred = |date: chrono::NaiveDate| {
+        preference.preferred_phases.contains(&predict_phase(
+            date,
+            period_start,
+            preference.cycle_length_days,
+        ))
+    };
+    let mut used = vec![false; events.len()];
+    let (mut moved, mut remaining) = (0, 0);
+    for key_index in 0..events.len() {
+        let Some(key_date) = dated[key_index] else {
+            continue;
+        };
+        if !key_dates.contains(&key_date) || preferred(key_date) {
+            continue;
+        }
+        let swap = (0..events.len()).find(|&other| {
+            !used[other]
+                && dated[other].is_some_and(|date| {
+                    date.iso_week() == key_date.iso_week()
+                        && !key_dates.contains(&date)
+                        && preferred(date)
+                })
+        });
+        match swap {
+            Some(other) => {
+                used[other] = true;
+                key_dates.remove(&key_date);
+                key_dates.extend(dated[other]);
+                let (name, description) = (
+                    events[key_index].name.clone(),
+                    events[key_index].description.clone(),
+                );
+                events[key_index].name = std::mem::replace(&mut events[other].name, name);
+                events[key_index].description =
+                    std::mem::replace(&mut events[other].description, description);
+                moved += 1;
+            }
+            None => remaining += 1,
+        }
+    }
+    (moved, remaining)
+}
+
+/// Scale the first plan weeks down a return-to-training ramp: every session in a
+/// ramp week is tagged with its volume target, and key endurance sessions become
+/// easy aerobic while intensity is still off. Returns the key sessions eased.
+fn apply_return_ramp(
+    events: &mut [intervals_icu_client::Event],
+    ramp: &[ReturnRampWeek],
+    start_date: chrono::NaiveDate,
+    key_dates: &HashSet<chrono::NaiveDate>,
+) -> usize {
+    let mut eased = 0;
+    for event in events.iter_mut() {
+        let Ok(date) = chrono::NaiveDate::parse_from_str(&event.start_date_local, "%Y-%m-%d")
+        else {
+            continue;
+        };
+        let Some(step) = usize::try_from((date - start_date).num_days() / 7)
+            .ok()
+            .and_then(|index| ramp.get(index))
+        else {
+            continue;
+        };
+        let prefix = format!("Return week {}: {}% volume.", step.week, step.volume_pct);
+        let endurance = event.r#type.is_none();
+        if endurance && !step.intensity_allowed && key_dates.contains(&date) {
+            event.name = "Easy Aerobic (return ramp)".into();
+            event.description = Some(format!("{prefix} Easy aerobic only, no intensity yet"));
+            eased += 1;
+        } else {
+            event.description = Some(match event.description.take() {
+                Some(description) => format!("{prefix} {description}"),
+                None => prefix,
+            });
+        }
+    }
+    eased
+}
+
+fn render_return_ramp(
+    episode: &crate::domains::progress::HealthEpisode,
+    ramp: &[ReturnRampWeek],
+    eased: usize,
+) -> String {
+    let mut lines = vec![format!(
+        "Return-to-Training Ramp\n  {} {} to {} ({})",
+        episode_label(episode.kind),
+        episode.start,
+        episode.end,
+        episode.sources.join(", ")
+    )];
+    for (index, step) in ramp.iter().enumerate() {
+        lines.push(format!(
+            "  Plan week {}: {}% volume, {}",
+            index + 1,
+            step.volume_pct,
+            if step.intensity_allowed {
+                "intensity allowed"
+            } else {
+                "easy only"
+            }
+        ));
+    }
+    lines.push(format!(
+        "  {eased} key session(s) eased to aerobic. {}.",
+        ramp[0].guidance
+    ));
+    lines.join("\n")
+}
+
+/// Strength sessions per week for the focus, or the explicit override.
+fn strength_count(focus: TrainingFocus, sessions_override: Option<u8>) -> u8 {
+    match focus {
+        // Recovery weeks already carry a mobility + strength maintenance day.
+        TrainingFocus::Recovery => 0,
+        _ => {
+            sessions_override.unwrap_or_else(|| strength_sessions_per_week(&focus.strength_phase()))
+        }
+    }
+}
+
+/// Strength sessions for each plan week, placed around that week's key session
+/// dates (after any cycle-aware moves) rather than the focus's default key days.
+#[must_use]
+fn generate_strength_events(
+    start_date: chrono::NaiveDate,
+    focus: TrainingFocus,
+    weeks: u32,
+    sessions_override: Option<u8>,
+    key_dates: &HashSet<chrono::NaiveDate>,
+) -> Vec<intervals_icu_client::Event> {
+    let phase = focus.strength_phase();
+    let count = strength_count(focus, sessions_override);
+    let skip_recovery = matches!(
+        focus,
+        TrainingFocus::AerobicBase | TrainingFocus::Intensity | TrainingFocus::Specific
+    );
+
+    let mut events = Vec::new();
+    for week in 0..weeks {
+        if skip_recovery && week > 0 && (week + 1) % 4 == 0 {
+            continue;
+        }
+        let week_start = start_date + chrono::Duration::weeks(i64::from(week));
+        let week_key_days = (0..7)
+            .map(|offset| week_start + chrono::Duration::days(offset))
+            .filter(|date| key_dates.contains(date))
+            .map(|date| date.weekday())
+            .collect::<Vec<_>>();
+        for (day, light) in &place_strength_days(&week_key_days, count) {
+            let session = strength_session_for_phase(&phase, *light);
+            events.push(intervals_icu_client::Event {
+                id: None,
+                start_date_local: next_weekday(week_start, *day)
+                    .format("%Y-%m-%d")
+                    .to_string(),
+                name: session.name.to_string(),
+                category: intervals_icu_client::EventCategory::Workout,
+                description: Some(format!(
+                    "{} ({} min)",
+                    session.description, session.duration_minutes
+                )),
+                r#type: Some("WeightTraining".into()),
+                end_date_local: None,
+            });
+        }
+    }
+    events
+}
+
+fn render_strength_plan(focus: TrainingFocus, sessions_override: Option<u8>) -> String {
+    let phase = focus.strength_phase();
+    let days = place_strength_days(focus.key_days(), strength_count(focus, sessions_override));
+    let mut lines = vec![format!("Strength Plan ({} per week)", days.len())];
+    for (day, light) in &days {
+        let session = strength_session_for_phase(&phase, *light);
+        lines.push(format!(
+            "  {:?}: {} ({} min) - {}",
+            day, session.name, session.duration_minutes, session.description
+        ));
+    }
+    lines.push(
+        "  Strength days avoid key endurance sessions; heavy lower-body work stays 48 h clear of intensity."
+            .to_string(),
+    );
+    lines.join("\n")
+}
+
 impl PlanTrainingHandler {
     fn build_periodization(
         &self,
@@ -1435,6 +1912,178 @@ mod tests {
         assert_eq!(events.len(), 12);
     }
 
+    #[test]
+    fn test_generate_strength_events_avoid_key_days() {
+        let start = chrono::NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let (phases, _) =
+            PlanTrainingHandler::new().build_periodization(6, TrainingFocus::Intensity, 10.0);
+        let key_dates = key_session_dates(
+            &generate_events(&phases, start, TrainingFocus::Intensity, 6),
+            TrainingFocus::Intensity.key_days(),
+        );
+        let events = generate_strength_events(start, TrainingFocus::Intensity, 6, None, &key_dates);
+        // Build phase: 2 sessions/week, week 4 is recovery -> 5 * 2 = 10
+        assert_eq!(events.len(), 10);
+        for event in &events {
+            assert_eq!(event.r#type.as_deref(), Some("WeightTraining"));
+            let date =
+                chrono::NaiveDate::parse_from_str(&event.start_date_local, "%Y-%m-%d").unwrap();
+            assert!(
+                !TrainingFocus::Intensity
+                    .key_days()
+                    .contains(&date.weekday())
+            );
+            let validated = validate_and_prepare_event(event.clone()).unwrap();
+            assert_eq!(validated.r#type.as_deref(), Some("WeightTraining"));
+        }
+        // The day before a key session only gets the light maintenance variant.
+        assert!(
+            events
+                .iter()
+                .filter(|event| event.start_date_local == "2026-03-02")
+                .all(|event| event.name == "Strength Maintenance")
+        );
+    }
+
+    #[test]
+    fn test_generate_strength_events_override_and_recovery() {
+        let start = chrono::NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let key_dates = HashSet::new();
+        assert_eq!(
+            generate_strength_events(start, TrainingFocus::AerobicBase, 3, None, &key_dates).len(),
+            9
+        );
+        assert!(
+            generate_strength_events(start, TrainingFocus::Taper, 3, Some(0), &key_dates)
+                .is_empty()
+        );
+        assert!(
+            generate_strength_events(start, TrainingFocus::Recovery, 2, None, &key_dates)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_bias_key_sessions_by_cycle_swaps_within_week() {
+        let handler = PlanTrainingHandler::new();
+        let (phases, _) = handler.build_periodization(1, TrainingFocus::Intensity, 10.0);
+        let start = chrono::NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let mut events = generate_events(&phases, start, TrainingFocus::Intensity, 1);
+        let preference = CyclePreference {
+            preferred_phases: vec![CyclePhase::Follicular],
+            last_period_start: None,
+            cycle_length_days: 28,
+        };
+        let period_start = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let mut key_dates = key_session_dates(&events, TrainingFocus::Intensity.key_days());
+        // Tue/Thu fall in the period, Sat/Sun in the follicular phase.
+        let (moved, remaining) =
+            bias_key_sessions_by_cycle(&mut events, &mut key_dates, &preference, period_start);
+        assert_eq!((moved, remaining), (1, 1));
+        assert_eq!(events[0].name, "Easy Aerobic");
+        assert_eq!(events[2].name, "Threshold Session");
+        assert_eq!(events[3].name, "Long Aerobic + Strides");
+        let day = |d| chrono::NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        assert_eq!(key_dates, HashSet::from([day(5), day(7), day(8)]));
+    }
+
+    #[test]
+    fn test_strength_follows_cycle_moved_key_sessions() {
+        let handler = PlanTrainingHandler::new();
+        let (phases, _) = handler.build_periodization(2, TrainingFocus::Intensity, 10.0);
+        let start = chrono::NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let mut events = generate_events(&phases, start, TrainingFocus::Intensity, 2);
+        let mut key_dates = key_session_dates(&events, TrainingFocus::Intensity.key_days());
+        let preference = CyclePreference {
+            preferred_phases: vec![CyclePhase::Follicular],
+            last_period_start: None,
+            cycle_length_days: 28,
+        };
+        let period_start = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        bias_key_sessions_by_cycle(&mut events, &mut key_dates, &preference, period_start);
+        // The threshold session moved to Saturday 7 March.
+        assert!(key_dates.contains(&chrono::NaiveDate::from_ymd_opt(2026, 3, 7).unwrap()));
+
+        let strength =
+            generate_strength_events(start, TrainingFocus::Intensity, 2, Some(2), &key_dates);
+        assert_eq!(strength.len(), 4);
+        for event in &strength {
+            let date =
+                chrono::NaiveDate::parse_from_str(&event.start_date_local, "%Y-%m-%d").unwrap();
+            assert!(!key_dates.contains(&date), "strength on key day {date}");
+            if key_dates.contains(&date.succ_opt().unwrap()) {
+                assert_eq!(event.name, "Strength Maintenance", "heavy before {date}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_cycle_preference_parse_validates() {
+        assert_eq!(CyclePreference::parse(&json!({})).unwrap(), None);
+        let parsed = CyclePreference::parse(&json!({
+            "cycle_preference": {"preferred_phases": ["follicular", "OVULATING"], "last_period_start": "2026-03-01"}
+        }))
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            parsed.preferred_phases,
+            vec![CyclePhase::Follicular, CyclePhase::Ovulating]
+        );
+        assert_eq!(parsed.cycle_length_days, 28);
+        assert!(
+            CyclePreference::parse(&json!({"cycle_preference": {"preferred_phases": ["spring"]}}))
+                .is_err()
+        );
+        assert!(
+            CyclePreference::parse(&json!({
+                "cycle_preference": {"preferred_phases": ["luteal"], "cycle_length_days": 60}
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_apply_return_ramp_eases_key_sessions() {
+        let handler = PlanTrainingHandler::new();
+        let (phases, _) = handler.build_periodization(3, TrainingFocus::Intensity, 10.0);
+        let start = chrono::NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let mut events = generate_events(&phases, start, TrainingFocus::Intensity, 3);
+        let ramp = [
+            ReturnRampWeek {
+                week: 1,
+                volume_pct: 50,
+                intensity_allowed: false,
+                guidance: "",
+            },
+            ReturnRampWeek {
+                week: 2,
+                volume_pct: 90,
+                intensity_allowed: true,
+                guidance: "",
+            },
+        ];
+        let key_dates = key_session_dates(&events, TrainingFocus::Intensity.key_days());
+        let eased = apply_return_ramp(&mut events, &ramp, start, &key_dates);
+        assert_eq!(eased, 3);
+        assert_eq!(events[0].name, "Easy Aerobic (return ramp)");
+        assert!(
+            events[2]
+                .description
+                .as_deref()
+                .unwrap()
+                .starts_with("Return week 1: 50% volume.")
+        );
+        assert_eq!(events[4].name, "Threshold Session");
+        assert!(
+            events[4]
+                .description
+                .as_deref()
+                .unwrap()
+                .starts_with("Return week 2: 90% volume.")
+        );
+        assert!(!events[8].description.as_deref().unwrap().contains("Return"));
+    }
+
     #[test]
     fn test_conflict_detection_excludes_races() {
         let race_a = intervals_icu_client::EventCategory::RaceA;
@@ -1542,6 +2191,7 @@ mod tests {
             category: intervals_icu_client::EventCategory::Unknown,
             description: Some("test".into()),
             r#type: None,
+            end_date_local: None,
         };
         let result = validate_and_prepare_event(ev);
         assert!(
@@ -1589,7 +2239,8 @@ mod tests {
         let content_str = format!("{:?}", output.content);
         assert!(content_str.contains("Training Plan"));
         assert!(content_str.contains("AEROBIC BASE"));
-        assert_eq!(output.metadata.events_created, Some(12));
+        // 3 active weeks: 4 endurance + 3 base-phase strength sessions each
+        assert_eq!(output.metadata.events_created, Some(21));
     }
 
     #[tokio::test]
@@ -1607,7 +2258,8 @@ mod tests {
         let content_str = format!("{:?}", output.content);
         assert!(content_str.contains("Training Plan"));
         assert!(content_str.contains("Test Athlete"));
-        assert_eq!(output.metadata.events_created, Some(12));
+        // 3 active weeks: 4 endurance + 3 base-phase strength sessions each
+        assert_eq!(output.metadata.events_created, Some(21));
     }
 
     #[tokio::test]
@@ -1620,6 +2272,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         }]));
         let input = json!({
             "period_start": "2026-03-01",
@@ -1669,6 +2322,7 @@ mod tests {
                     category: EventCategory::Workout,
                     description: None,
                     r#type: None,
+                    end_date_local: None,
                 }])
                 .with_upcoming_workouts(json!([
                     {"start_date_local": "2026-03-20", "name": "Upcoming Workout"}
@@ -1699,6 +2353,7 @@ mod tests {
             category: EventCategory::RaceA,
             description: None,
             r#type: None,
+            end_date_local: None,
         }]));
         let input = json!({
             "period_start": "2026-03-01",
@@ -1991,6 +2646,119 @@ mod tests {
         assert!(content_str.contains("INTENSITY"));
     }
 
+    #[tokio::test]
+    async fn test_execute_includes_strength_plan() {
+        let handler = PlanTrainingHandler::new();
+        let client = Arc::new(MockIntervalsClient::builder());
+        let input = json!({
+            "period_start": "2026-03-02",
+            "period_end": "2026-03-15",
+            "idempotency_token": "test-token",
+            "focus": "intensity"
+        });
+        let output = handler.execute(input, client, None).await.unwrap();
+        let content_str = format!("{:?}", output.content);
+        assert!(content_str.contains("Strength Plan (2 per week)"));
+        assert!(content_str.contains("Max Strength (Lower Body)"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_cycle_preference_uses_logged_period() {
+        let handler = PlanTrainingHandler::new();
+        let client = Arc::new(MockIntervalsClient::builder().with_wellness(json!([
+            {"id": "2026-03-01", "menstrualPhase": "PERIOD"},
+            {"id": "2026-03-02", "menstrualPhase": "PERIOD"}
+        ])));
+        let input = json!({
+            "period_start": "2026-03-02",
+            "period_end": "2026-03-08",
+            "idempotency_token": "test-token",
+            "focus": "intensity",
+            "cycle_preference": {"preferred_phases": ["follicular"]}
+        });
+        let output = handler.execute(input, client, None).await.unwrap();
+        let content_str = format!("{:?}", output.content);
+        assert!(content_str.contains("Cycle-Aware Placement"));
+        assert!(content_str.contains("Moved 1 key session(s)"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_builds_return_ramp_after_illness() {
+        let sick = |date: &str| intervals_icu_client::Event {
+            id: None,
+            start_date_local: date.into(),
+            name: "Flu".into(),
+            category: intervals_icu_client::EventCategory::Sick,
+            description: None,
+            r#type: None,
+            end_date_local: None,
+        };
+        let handler = PlanTrainingHandler::new();
+        let client = Arc::new(MockIntervalsClient::builder().with_events(vec![
+            sick("2026-02-20"),
+            sick("2026-02-22"),
+            sick("2026-02-24"),
+        ]));
+        let input = json!({
+            "period_start": "2026-03-02",
+            "period_end": "2026-03-15",
+            "idempotency_token": "test-token",
+            "focus": "intensity"
+        });
+        let output = handler.execute(input, client, None).await.unwrap();
+        let content_str = format!("{:?}", output.content);
+        assert!(content_str.contains("Return-to-Training Ramp"));
+        assert!(content_str.contains("Illness 2026-02-20 to 2026-02-24 (Flu)"));
+        assert!(content_str.contains("Plan week 1: 50% volume, easy only"));
+        assert!(content_str.contains("6 key session(s) eased to aerobic"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_invalid_strength_sessions() {
+        let handler = PlanTrainingHandler::new();
+        let client = Arc::new(MockIntervalsClient::builder());
+        let input = json!({
+            "period_start": "2026-03-02",
+            "period_end": "2026-03-15",
+            "idempotency_token": "test-token",
+            "strength_sessions_per_week": 5
+        });
+        let err = handler.execute(input, client, None).await.unwrap_err();
+        assert!(err.to_string().contains("strength_sessions_per_week"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_strength_sessions_for_recovery_focus() {
+        let handler = PlanTrainingHandler::new();
+        let input = json!({
+            "period_start": "2026-03-02",
+            "period_end": "2026-03-15",
+            "focus": "recovery",
+            "idempotency_token": "test-token",
+            "strength_sessions_per_week": 2
+        });
+        let err = handler
+            .execute(input, Arc::new(MockIntervalsClient::builder()), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, IntentError::ValidationError(_)));
+        assert!(err.to_string().contains("focus recovery"));
+
+        let input = json!({
+            "period_start": "2026-03-02",
+            "period_end": "2026-03-15",
+            "focus": "recovery",
+            "idempotency_token": "test-token-0",
+            "strength_sessions_per_week": 0
+        });
+        assert!(
+            handler
+                .execute(input, Arc::new(MockIntervalsClient::builder()), None)
+                .await
+                .is_ok()
+        );
+    }
+
     #[tokio::test]
     async fn test_execute_with_specific_focus() {
         let handler = PlanTrainingHandler::new();
@@ -2007,4 +2775,75 @@ mod tests {
         let content_str = format!("{:?}", output.content);
         assert!(content_str.contains("SPECIFIC"));
     }
+
+    #[tokio::test]
+    async fn test_execute_hot_race_adds_acclimation_protocol() {
+        let today = chrono::Local::now().date_naive();
+        let start = today + chrono::Duration::days(7);
+        let end = today + chrono::Duration::days(55);
+        let client = Arc::new(MockIntervalsClient::builder());
+        let input = json!({
+            "period_start": start.format("%Y-%m-%d").to_string(),
+            "period_end": end.format("%Y-%m-%d").to_string(),
+            "race_temperature_c": 32.0,
+            "idempotency_token": "test-token"
+        });
+        let output = PlanTrainingHandler::new()
+            .execute(input, client, None)
+            .await
+            .unwrap();
+        let content_str = format!("{:?}", output.content);
+        assert!(content_str.contains("Heat Acclimation Protocol"));
+        assert!(
+            content_str.contains(
+                &(end - chrono::Duration::days(14))
+                    .format("%Y-%m-%d")
+                    .to_string()
+            )
+        );
+        assert!(
+            output
+                .suggestions
+                .iter()
+                .any(|s| s.contains("heat acclimation"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_cool_race_has_no_acclimation_protocol() {
+        let today = chrono::Local::now().date_naive();
+        let start = today + chrono::Duration::days(7);
+        let end = today + chrono::Duration::days(55);
+        let client = Arc::new(MockIntervalsClient::builder());
+        let input = json!({
+            "period_start": start.format("%Y-%m-%d").to_string(),
+            "period_end": end.format("%Y-%m-%d").to_string(),
+            "race_temperature_c": 14.0,
+            "idempotency_token": "test-token"
+        });
+        let output = PlanTrainingHandler::new()
+            .execute(input, client, None)
+            .await
+            .unwrap();
+        let content_str = format!("{:?}", output.content);
+        assert!(!content_str.contains("Heat Acclimation Protocol"));
+    }
+
+    #[test]
+    fn test_render_acclimation_protocol_compressed_note() {
+        let date = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let protocol = AcclimationProtocol {
+            start_date: date("2026-07-01"),
+            final_exposure_date: date("2026-07-06"),
+            race_date: date("2026-07-08"),
+            race_temperature_c: 31.0,
+            sessions: 6,
+            min_minutes: 60,
+            max_minutes: 90,
+            compressed: true,
+        };
+        let text = render_acclimation_protocol(&protocol);
+        assert!(text.contains("6 heat sessions"));
+        assert!(text.contains("Short lead time"));
+    }
 }
@@ -1,2 +1,6 @@
 pub mod analysis;
+pub mod pacing;
 pub mod progress;
+pub mod records;
+pub mod roster;
+pub mod season;
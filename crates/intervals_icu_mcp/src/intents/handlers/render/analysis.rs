@@ -2,9 +2,17 @@ use chrono::NaiveDate;
 use serde_json::Value;
 
 use crate::domains::coach::{
-    DecouplingMetrics, EspeDerivedMetrics, EspePowerAnchors, FitnessMetrics, HeatMetrics,
-    NdliMetrics, WdrMetrics,
+    DecouplingMetrics, EspeDerivedMetrics, EspePowerAnchors, FitnessMetrics,
+    HeatAcclimationMetrics, HeatMetrics, InjuryRiskMetrics, NdliMetrics, WdrMetrics,
 };
+use crate::engines::fit_metrics::FitFileMetrics;
+use crate::engines::injury_risk::channel_label;
+use crate::engines::session_rpe::{
+    DEFAULT_SRPE_TO_TSS, LoadSource, SrpeCalibration, activity_feel, activity_rpe,
+    effective_session_load, feel_label, has_sensor_data, session_rpe_load,
+};
+use crate::engines::strength::StrengthSummary;
+use crate::engines::threshold_estimation::DFA_ALPHA1_AET;
 use crate::intents::ContentBlock;
 
 pub(crate) fn build_load_management_text(
@@ -310,6 +318,111 @@ pub(crate) fn build_detailed_workout_rows(workout_detail: Option<&Value>) -> Vec
         rows.push(vec!["Temperature".into(), format!("{temp:.1} °C")]);
     }
 
+    rows.extend(build_session_rpe_rows(workout_detail));
+
+    rows
+}
+
+/// RPE, feel and session-RPE load rows; sessions without power/HR also get the
+/// TSS-equivalent estimate used in place of `icu_training_load`.
+pub(crate) fn build_session_rpe_rows(workout_detail: Option<&Value>) -> Vec<Vec<String>> {
+    let Some(detail) = workout_detail else {
+        return Vec::new();
+    };
+
+    let mut rows = Vec::new();
+    if let Some(rpe) = activity_rpe(detail) {
+        rows.push(vec!["RPE".into(), format!("{rpe:.0}/10")]);
+    }
+    if let Some(feel) = activity_feel(detail) {
+        rows.push(vec![
+            "Feel".into(),
+            format!("{feel}/5 ({})", feel_label(feel)),
+        ]);
+    }
+    if let Some(srpe) = session_rpe_load(detail) {
+        let value = if has_sensor_data(detail) {
+            format!("{srpe:.0} AU")
+        } else {
+            format!(
+                "{srpe:.0} AU (≈{:.0} load, no power/HR)",
+                srpe * DEFAULT_SRPE_TO_TSS
+            )
+        };
+        rows.push(vec!["Session RPE Load".into(), value]);
+    }
+    rows
+}
+
+/// Total period training load, with sessions lacking power/HR estimated from
+/// session RPE × duration. `None` when no session carries any load.
+pub(crate) fn build_period_load_row(
+    activities: &[&intervals_icu_client::ActivitySummary],
+    details: &std::collections::HashMap<String, Value>,
+    calibration: &SrpeCalibration,
+) -> Option<Vec<String>> {
+    let mut total = 0.0;
+    let mut estimated = 0usize;
+    let mut any = false;
+    for activity in activities {
+        let Some((load, source)) = details
+            .get(&activity.id)
+            .and_then(|detail| effective_session_load(detail, calibration.factor))
+        else {
+            continue;
+        };
+        any = true;
+        total += load;
+        if source == LoadSource::SessionRpe {
+            estimated += 1;
+        }
+    }
+    if !any {
+        return None;
+    }
+    let value = if estimated > 0 {
+        format!("{total:.0} ({estimated} session(s) estimated from RPE × duration)")
+    } else {
+        format!("{total:.0}")
+    };
+    Some(vec!["Training Load".into(), value])
+}
+
+/// Strength & mobility rows for a period: frequency, consistency, time and estimated load.
+pub(crate) fn build_strength_rows(summary: &StrengthSummary) -> Vec<Vec<String>> {
+    let mut rows = vec![
+        vec![
+            "Strength Sessions".into(),
+            format!(
+                "{} ({:.1}/week)",
+                summary.strength_sessions, summary.sessions_per_week
+            ),
+        ],
+        vec![
+            "Strength Consistency".into(),
+            format!("{:.0}% of weeks", summary.consistency_pct),
+        ],
+        vec![
+            "Strength Load (est.)".into(),
+            format!("{:.0} (session RPE × duration)", summary.estimated_load),
+        ],
+        vec![
+            "Strength + Mobility Time".into(),
+            format_duration_hhmm((summary.total_minutes * 60.0).round() as i64),
+        ],
+    ];
+    if summary.mobility_sessions > 0 {
+        rows.push(vec![
+            "Mobility Sessions".into(),
+            summary.mobility_sessions.to_string(),
+        ]);
+    }
+    if summary.heavy_lower_body_sessions > 0 {
+        rows.push(vec![
+            "Heavy Lower-Body Sessions".into(),
+            summary.heavy_lower_body_sessions.to_string(),
+        ]);
+    }
     rows
 }
 
@@ -1238,6 +1351,80 @@ pub(crate) fn render_heat_section(heat: &Option<HeatMetrics>) -> Option<String>
     Some(lines.join("\n"))
 }
 
+pub(crate) fn render_heat_acclimation_section(
+    acclimation: &Option<HeatAcclimationMetrics>,
+) -> Option<String> {
+    let acclimation = acclimation.as_ref()?;
+    if !acclimation.supported {
+        return None;
+    }
+    let mut lines = vec!["Heat Acclimation".to_string()];
+    if let Some(index) = acclimation.acclimation_index {
+        lines.push(format!(
+            "  State: {} (index {:.2}, 1.0 = fully acclimated)",
+            acclimation.state, index
+        ));
+    }
+    lines.push(format!(
+        "  Heat exposure (14d): {:.0} min across {} sessions",
+        acclimation.exposure_minutes_14d, acclimation.heat_sessions_14d
+    ));
+    if let Some(days) = acclimation.days_since_exposure {
+        lines.push(format!(
+            "  Days since last heat exposure: {} (~2.5%/day decay)",
+            days
+        ));
+    }
+    if let Some(ef) = acclimation.heat_adjusted_ef {
+        lines.push(format!(
+            "  Heat-adjusted EF: {:.2} (EF with heat penalty removed)",
+            ef
+        ));
+    }
+    Some(lines.join("\n"))
+}
+
+pub(crate) fn render_injury_risk_section(risk: &Option<InjuryRiskMetrics>) -> Option<String> {
+    let risk = risk.as_ref()?;
+    if !risk.supported {
+        return None;
+    }
+    let mut lines = vec!["Injury Risk by Load Channel".to_string()];
+    lines.push(match risk.driving_channel.as_deref() {
+        Some(channel) => format!(
+            "  Overall: {} — driven by {}",
+            risk.risk_level,
+            channel_label(channel)
+        ),
+        None => format!("  Overall: {} — no channel elevated", risk.risk_level),
+    });
+    for channel in &risk.channels {
+        lines.push(format!(
+            "  {}: ACWR {:.2} ({}) — 7d load {:.0}",
+            channel_label(&channel.channel),
+            channel.acwr.ratio,
+            channel.risk,
+            channel.load_7d
+        ));
+    }
+    for spike in &risk.spikes {
+        let (label, unit) = match spike.kind.as_str() {
+            "long_run_distance" => ("Long-run spike", "km"),
+            _ => ("Weekly descent spike", "m"),
+        };
+        lines.push(format!(
+            "  {}: {:.0} → {:.0} {} (+{:.0}% week over week, {})",
+            label,
+            spike.previous,
+            spike.current,
+            unit,
+            (spike.ratio - 1.0) * 100.0,
+            spike.severity
+        ));
+    }
+    Some(lines.join("\n"))
+}
+
 pub(crate) fn render_fitness_snapshot(fitness: &Option<FitnessMetrics>) -> Option<String> {
     let metrics = fitness.as_ref()?;
     let mut lines = vec!["Fitness Snapshot".to_string()];
@@ -1263,6 +1450,74 @@ pub(crate) fn render_fitness_snapshot(fitness: &Option<FitnessMetrics>) -> Optio
     Some(lines.join("\n"))
 }
 
+pub(crate) fn render_fit_file_section(metrics: &FitFileMetrics) -> String {
+    let mut lines = vec!["FIT File Metrics".to_string()];
+    let rate = metrics
+        .sample_interval_secs
+        .map(|secs| format!(", every {secs:.0} s"))
+        .unwrap_or_default();
+    lines.push(format!(
+        "  Records: {} over {}{rate}",
+        metrics.records,
+        format_duration_compact(metrics.duration_secs as i64)
+    ));
+    if let Some(avg) = metrics.avg_power {
+        lines.push(format!(
+            "  Avg Power: {avg:.0} W (per-second, coasting as 0)"
+        ));
+    }
+    if let Some(np) = metrics.normalized_power {
+        lines.push(format!(
+            "  Normalized Power: {np:.0} W (from native samples)"
+        ));
+    }
+    if let Some(hr) = metrics.avg_heart_rate {
+        lines.push(format!("  Avg HR: {hr:.0} bpm"));
+    }
+    if metrics.rr_beats > 0 {
+        let artifacts = metrics.rr_artifact_fraction.unwrap_or(0.0) * 100.0;
+        lines.push(format!(
+            "  RR Intervals: {} beats ({artifacts:.1}% artifacts removed)",
+            metrics.rr_beats
+        ));
+    }
+    if let Some(alpha1) = metrics.mean_alpha1() {
+        let min = metrics
+            .dfa_alpha1
+            .iter()
+            .map(|window| window.alpha1)
+            .fold(f64::INFINITY, f64::min);
+        lines.push(format!(
+            "  DFA α1: mean {alpha1:.2}, min {min:.2} (>{DFA_ALPHA1_AET} = below aerobic threshold)"
+        ));
+        lines.push(format!(
+            "  Time with α1 < {DFA_ALPHA1_AET}: {}",
+            format_duration_compact(metrics.secs_below_aerobic_threshold() as i64)
+        ));
+    } else if metrics.rr_beats > 0 {
+        lines.push("  DFA α1: not enough clean beats for a 2-minute window".to_string());
+    }
+    if let (Some(start), Some(peak)) = (
+        metrics.core_temperature_start,
+        metrics.core_temperature_peak,
+    ) {
+        lines.push(format!("  Core Temp: {start:.2} → peak {peak:.2} °C"));
+    }
+    if let Some(drift) = metrics.core_temperature_drift_per_hour {
+        lines.push(format!("  Core Temp Drift: {drift:+.2} °C/h"));
+    }
+    if !metrics.developer_streams.is_empty() {
+        lines.push(format!(
+            "  Developer Fields: {}",
+            metrics.developer_streams.join(", ")
+        ));
+    }
+    if !metrics.devices.is_empty() {
+        lines.push(format!("  Devices: {}", metrics.devices.join(", ")));
+    }
+    lines.join("\n")
+}
+
 pub(crate) fn render_z2_stability_section(
     z2_lower: f64,
     z2_upper: f64,
@@ -1363,6 +1618,7 @@ mod tests {
                 category: EventCategory::Workout,
                 description: Some("Zone 2".into()),
                 r#type: None,
+                end_date_local: None,
             },
             Event {
                 id: Some("2".into()),
@@ -1371,6 +1627,7 @@ mod tests {
                 category: EventCategory::Holiday,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
         ];
         let refs: Vec<&Event> = events.iter().collect();
@@ -1936,6 +2193,45 @@ mod tests {
         assert!(rows[3][1].contains("10.0 hrs"));
     }
 
+    #[test]
+    fn build_session_rpe_rows_estimates_load_without_sensors() {
+        let detail = serde_json::json!({"icu_rpe": 6, "feel": 2, "moving_time": 2700});
+        let rows = build_session_rpe_rows(Some(&detail));
+        assert_eq!(rows[0], vec!["RPE".to_string(), "6/10".to_string()]);
+        assert_eq!(rows[1][1], "2/5 (good)");
+        assert!(rows[2][1].contains("270 AU"));
+        assert!(rows[2][1].contains("≈54 load"));
+
+        let sensored =
+            serde_json::json!({"icu_rpe": 6, "moving_time": 2700, "average_heartrate": 140.0});
+        let rows = build_session_rpe_rows(Some(&sensored));
+        assert_eq!(rows[1][1], "270 AU");
+    }
+
+    #[test]
+    fn build_period_load_row_blends_session_rpe_estimates() {
+        let summary = |id: &str| intervals_icu_client::ActivitySummary {
+            id: id.into(),
+            name: None,
+            start_date_local: "2026-03-01T08:00:00".into(),
+            ..Default::default()
+        };
+        let (a, b) = (summary("a"), summary("b"));
+        let mut details = std::collections::HashMap::new();
+        details.insert(
+            "a".to_string(),
+            serde_json::json!({"icu_training_load": 80, "average_heartrate": 150.0}),
+        );
+        details.insert(
+            "b".to_string(),
+            serde_json::json!({"type": "WeightTraining", "icu_rpe": 5, "moving_time": 3600}),
+        );
+        let calibration = SrpeCalibration::default();
+        let row = build_period_load_row(&[&a, &b], &details, &calibration).unwrap();
+        assert_eq!(row[1], "140 (1 session(s) estimated from RPE × duration)");
+        assert!(build_period_load_row(&[], &details, &calibration).is_none());
+    }
+
     // ── build_requested_single_metric_rows ────────────────────────────
 
     #[test]
@@ -2968,6 +3264,75 @@ mod tests {
         assert!(!text.contains("Max Temperature"));
     }
 
+    // ── render_heat_acclimation_section ──────────────────────────────
+
+    #[test]
+    fn render_heat_acclimation_section_unsupported() {
+        assert!(render_heat_acclimation_section(&None).is_none());
+        assert!(
+            render_heat_acclimation_section(&Some(HeatAcclimationMetrics::default())).is_none()
+        );
+    }
+
+    #[test]
+    fn render_heat_acclimation_section_supported() {
+        let acclimation = HeatAcclimationMetrics {
+            supported: true,
+            acclimation_index: Some(0.42),
+            state: "partial".into(),
+            exposure_minutes_14d: 180.0,
+            heat_sessions_14d: 3,
+            days_since_exposure: Some(2),
+            heat_adjusted_ef: Some(1.55),
+        };
+        let text = render_heat_acclimation_section(&Some(acclimation)).expect("should render");
+        assert!(text.contains("State: partial"));
+        assert!(text.contains("180 min across 3 sessions"));
+        assert!(text.contains("Heat-adjusted EF: 1.55"));
+    }
+
+    // ── render_injury_risk_section ───────────────────────────────────
+
+    #[test]
+    fn render_injury_risk_section_unsupported() {
+        assert!(render_injury_risk_section(&None).is_none());
+        assert!(render_injury_risk_section(&Some(InjuryRiskMetrics::default())).is_none());
+    }
+
+    #[test]
+    fn render_injury_risk_section_names_driving_channel_and_spikes() {
+        use crate::domains::coach::{AcwrMetrics, ChannelLoadRisk, LoadSpike};
+
+        let risk = InjuryRiskMetrics {
+            supported: true,
+            channels: vec![ChannelLoadRisk {
+                channel: "vertical".into(),
+                acwr: AcwrMetrics {
+                    acute_load: 300.0,
+                    chronic_load: 150.0,
+                    ratio: 2.0,
+                    state: "overreaching".into(),
+                },
+                load_7d: 2400.0,
+                risk: "high".into(),
+            }],
+            spikes: vec![LoadSpike {
+                kind: "weekly_descent".into(),
+                channel: "vertical".into(),
+                previous: 800.0,
+                current: 2400.0,
+                ratio: 3.0,
+                severity: "high".into(),
+            }],
+            driving_channel: Some("vertical".into()),
+            risk_level: "high".into(),
+        };
+        let text = render_injury_risk_section(&Some(risk)).expect("should render");
+        assert!(text.contains("Overall: high — driven by Vertical / descent"));
+        assert!(text.contains("Vertical / descent: ACWR 2.00 (high)"));
+        assert!(text.contains("Weekly descent spike: 800 → 2400 m (+200% week over week, high)"));
+    }
+
     // ── render_z2_stability_section ──────────────────────────────────
 
     #[test]
@@ -0,0 +1,197 @@
+use crate::engines::race_pacing::RacePacingPlan;
+use crate::intents::ContentBlock;
+
+fn format_clock(secs: f64) -> String {
+    let total = secs.max(0.0).round() as i64;
+    format!(
+        "{}:{:02}:{:02}",
+        total / 3600,
+        (total % 3600) / 60,
+        total % 60
+    )
+}
+
+fn format_pace(secs_per_km: f64) -> String {
+    let total = secs_per_km.round() as i64;
+    format!("{}:{:02} /km", total / 60, total % 60)
+}
+
+pub(crate) fn render_pacing_plan(plan: &RacePacingPlan) -> Vec<ContentBlock> {
+    let mut sections = Vec::new();
+
+    if !plan.supported {
+        sections.push(ContentBlock::markdown(format!(
+            "## Pacing Plan\nPacing plan unavailable: {}.",
+            plan.unsupported_reason
+        )));
+        return sections;
+    }
+
+    sections.push(ContentBlock::markdown(format!(
+        "## Pacing Plan\n- Course: {:.2} km, +{:.0} m / -{:.0} m\n- Terrain index: {} (m/km, >20 = steep)\n- Estimated finish: {}\n- Race intensity: {:.0}% of threshold (duration-adjusted)",
+        plan.total_distance_m / 1000.0,
+        plan.total_gain_m,
+        plan.total_loss_m,
+        plan.terrain
+            .terrain_index
+            .map(|value| format!("{value:.1}"))
+            .unwrap_or_else(|| "unavailable".into()),
+        format_clock(plan.est_duration_secs),
+        plan.intensity_fraction * 100.0,
+    )));
+
+    let rows = plan
+        .segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            vec![
+                (index + 1).to_string(),
+                segment.segment.kind.as_str().to_string(),
+                format!(
+                    "{:.1}-{:.1}",
+                    segment.segment.start_m / 1000.0,
+                    segment.segment.end_m / 1000.0
+                ),
+                format!(
+                    "+{:.0}/-{:.0} m",
+                    segment.segment.elevation_gain_m, segment.segment.elevation_loss_m
+                ),
+                format!("{:+.1}%", segment.segment.avg_grade_pct),
+                segment
+                    .target_pace_secs_per_km
+                    .map(format_pace)
+                    .unwrap_or_else(|| "-".into()),
+                segment
+                    .target_power_watts
+                    .map(|watts| format!("{watts:.0} W"))
+                    .unwrap_or_else(|| "-".into()),
+                segment
+                    .target_hr_cap
+                    .map(|hr| format!("≤{hr:.0} bpm"))
+                    .unwrap_or_else(|| "-".into()),
+                format_clock(segment.est_time_secs),
+                format_clock(segment.cumulative_time_secs),
+                segment.effort.clone(),
+            ]
+        })
+        .collect();
+    sections.push(ContentBlock::table(
+        [
+            "#",
+            "Type",
+            "Km",
+            "Gain/Loss",
+            "Grade",
+            "Pace",
+            "Power",
+            "HR cap",
+            "Split",
+            "Elapsed",
+            "Effort",
+        ]
+        .iter()
+        .map(|header| header.to_string())
+        .collect(),
+        rows,
+    ));
+
+    if let Some(fueling) = &plan.fueling {
+        sections.push(ContentBlock::markdown(format!(
+            "## Fueling Plan\n- Carbohydrate: {:.0} g/h ({:.0} g total)\n- Fluid: {:.0} ml/h ({:.0} ml total)\n- Day-before carb loading: {:.1} g/kg",
+            fueling.carbs_g_per_hour,
+            fueling.total_carbs_g,
+            fueling.fluid_ml_per_hour,
+            fueling.total_fluid_ml,
+            fueling.carb_loading_g_kg,
+        )));
+        if !fueling.schedule.is_empty() {
+            sections.push(ContentBlock::table(
+                vec![
+                    "Elapsed".into(),
+                    "Km".into(),
+                    "Carbs".into(),
+                    "Fluid".into(),
+                ],
+                fueling
+                    .schedule
+                    .iter()
+                    .map(|step| {
+                        vec![
+                            format_clock(step.elapsed_secs),
+                            format!("{:.1}", step.distance_m / 1000.0),
+                            format!("{:.0} g", step.carbs_g),
+                            format!("{:.0} ml", step.fluid_ml),
+                        ]
+                    })
+                    .collect(),
+            ));
+        }
+    }
+
+    if !plan.aid_stations.is_empty() {
+        sections.push(ContentBlock::table(
+            vec![
+                "Aid station".into(),
+                "ETA".into(),
+                "Carbs to next".into(),
+                "Fluid to next".into(),
+            ],
+            plan.aid_stations
+                .iter()
+                .map(|station| {
+                    vec![
+                        format!("km {:.1}", station.distance_m / 1000.0),
+                        format_clock(station.eta_secs),
+                        format!("{:.0} g", station.carbs_to_next_g),
+                        format!("{:.0} ml", station.fluid_to_next_ml),
+                    ]
+                })
+                .collect(),
+        ));
+    }
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::race_pacing::{CoursePoint, PacingAnchors, PacingSport, build_pacing_plan};
+
+    #[test]
+    fn render_pacing_plan_includes_split_and_aid_tables() {
+        let points = vec![
+            CoursePoint {
+                distance_m: 0.0,
+                elevation_m: 0.0,
+            },
+            CoursePoint {
+                distance_m: 5000.0,
+                elevation_m: 400.0,
+            },
+            CoursePoint {
+                distance_m: 10000.0,
+                elevation_m: 0.0,
+            },
+        ];
+        let anchors = PacingAnchors {
+            threshold_speed_mps: Some(3.5),
+            ..Default::default()
+        };
+        let plan = build_pacing_plan(&points, PacingSport::Run, &anchors, &[5.0], None);
+        let blocks = render_pacing_plan(&plan);
+        let rendered = serde_json::to_string(&blocks).unwrap();
+        assert!(rendered.contains("Pacing Plan"));
+        assert!(rendered.contains("climb"));
+        assert!(rendered.contains("Fueling Plan"));
+        assert!(rendered.contains("km 5.0"));
+    }
+
+    #[test]
+    fn render_pacing_plan_unsupported() {
+        let plan = RacePacingPlan::unsupported(PacingSport::Run, "no anchors");
+        let blocks = render_pacing_plan(&plan);
+        assert_eq!(blocks.len(), 1);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::domains::coach::FitnessMetrics;
 use crate::domains::progress::ProgressReport;
+use crate::engines::health_episodes::episode_label;
+use crate::engines::injury_risk::channel_label;
 use crate::intents::ContentBlock;
 
 pub(crate) fn render_progress_report(
@@ -54,6 +56,66 @@ pub(crate) fn render_progress_report(
         report.tid_drift.dominant_zone.map(|value| value.to_string()).unwrap_or_else(|| "unavailable".into()),
     )));
 
+    if report.injury_risk.supported {
+        let risk = &report.injury_risk;
+        let mut lines = vec![format!(
+            "### Injury Risk by Load Channel\n- Overall: {}{}",
+            risk.risk_level,
+            risk.driving_channel
+                .as_deref()
+                .map(|channel| format!(" (driven by {})", channel_label(channel)))
+                .unwrap_or_default()
+        )];
+        for channel in &risk.channels {
+            lines.push(format!(
+                "- {}: ACWR {:.2} ({})",
+                channel_label(&channel.channel),
+                channel.acwr.ratio,
+                channel.risk
+            ));
+        }
+        for spike in &risk.spikes {
+            lines.push(format!(
+                "- Spike in {}: {:.0} → {:.0} (+{:.0}% week over week, {})",
+                spike.kind.replace('_', " "),
+                spike.previous,
+                spike.current,
+                (spike.ratio - 1.0) * 100.0,
+                spike.severity
+            ));
+        }
+        sections.push(ContentBlock::markdown(lines.join("\n")));
+    }
+
+    if !report.episodes.is_empty() {
+        let mut lines = vec!["### Illness & Injury Episodes".to_string()];
+        for episode in &report.episodes {
+            let ret = match (episode.return_date, episode.days_to_return) {
+                (Some(date), Some(days)) => format!("returned {date} ({days} days after)"),
+                _ => "ongoing — no return session yet".into(),
+            };
+            lines.push(format!(
+                "- {} {} → {} ({}): {}, CTL lost {}",
+                episode_label(episode.kind),
+                episode.start,
+                episode.end,
+                episode.sources.join(", "),
+                ret,
+                episode
+                    .ctl_lost
+                    .map(|value| format!("{value:.1}"))
+                    .unwrap_or_else(|| "unavailable".into()),
+            ));
+        }
+        if report.excluded_episode_days > 0 {
+            lines.push(format!(
+                "- {} day(s) inside episode windows excluded from plateau detection",
+                report.excluded_episode_days
+            ));
+        }
+        sections.push(ContentBlock::markdown(lines.join("\n")));
+    }
+
     if let Some(fm) = fitness {
         let mut fit_lines = vec!["### Fitness Snapshot".to_string()];
         if let Some(ctl) = fm.ctl {
@@ -151,5 +213,34 @@ mod tests {
         assert!(markdown.contains("TID drift unavailable"));
         assert!(markdown.contains("lnRMSSD 7-day Rollup"));
         assert!(markdown.contains("Sample count"));
+        assert!(!markdown.contains("Injury Risk by Load Channel"));
+    }
+
+    #[test]
+    fn render_progress_report_names_injury_risk_driver() {
+        use crate::domains::coach::{AcwrMetrics, ChannelLoadRisk, InjuryRiskMetrics};
+
+        let report = ProgressReport {
+            injury_risk: InjuryRiskMetrics {
+                supported: true,
+                channels: vec![ChannelLoadRisk {
+                    channel: "run_impact".into(),
+                    acwr: AcwrMetrics {
+                        ratio: 1.42,
+                        ..Default::default()
+                    },
+                    load_7d: 45.0,
+                    risk: "moderate".into(),
+                }],
+                driving_channel: Some("run_impact".into()),
+                risk_level: "moderate".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let markdown = format!("{:?}", render_progress_report(&report, false, None));
+        assert!(markdown.contains("Overall: moderate (driven by Run impact)"));
+        assert!(markdown.contains("Run impact: ACWR 1.42 (moderate)"));
     }
 }
@@ -0,0 +1,185 @@
+use crate::domains::records::{
+    NewRecord, PersonalRecord, ProgressionPoint, RecordBook, RecordMetric, RecordWindow,
+};
+use crate::domains::season::BestEffortKind;
+use crate::intents::ContentBlock;
+
+use super::analysis::{format_best_effort_duration, format_duration_hhmm};
+
+/// Width of the longest bar in the progression chart.
+const PROGRESSION_BAR_WIDTH: f64 = 20.0;
+
+fn anchor_label(metric: RecordMetric, anchor: u32) -> String {
+    match (metric, anchor) {
+        (RecordMetric::Power, secs) => {
+            format!("{} power", format_best_effort_duration(secs.into()))
+        }
+        (RecordMetric::Pace, 21097) => "Half marathon".into(),
+        (RecordMetric::Pace, 42195) => "Marathon".into(),
+        (RecordMetric::Pace, metres) if metres % 1000 == 0 => format!("{} km", metres / 1000),
+        (RecordMetric::Pace, metres) => format!("{metres} m"),
+    }
+}
+
+fn record_value(metric: RecordMetric, anchor: u32, value: f64) -> String {
+    match metric {
+        RecordMetric::Power => format!("{value:.0} W"),
+        RecordMetric::Pace => {
+            let per_km = (value / f64::from(anchor) * 1000.0).round() as i64;
+            format!(
+                "{} ({}:{:02} /km)",
+                format_duration_hhmm(value.round() as i64),
+                per_km / 60,
+                per_km % 60
+            )
+        }
+    }
+}
+
+fn window_label(window: RecordWindow) -> &'static str {
+    match window {
+        RecordWindow::AllTime => "all-time best",
+        RecordWindow::Season => "season best",
+        RecordWindow::Rolling90 => "best in 90 days",
+    }
+}
+
+fn record_cell(record: Option<&PersonalRecord>) -> String {
+    record
+        .map(|record| {
+            let activity = record
+                .activity_id
+                .as_deref()
+                .map(|id| format!(" [{id}]"))
+                .unwrap_or_default();
+            format!(
+                "{}{activity}",
+                record_value(record.metric, record.anchor, record.value)
+            )
+        })
+        .unwrap_or_else(|| "—".into())
+}
+
+/// Records the analysed activity set, for the single-workout report.
+pub(crate) fn render_new_records(records: &[NewRecord]) -> ContentBlock {
+    let lines = records
+        .iter()
+        .map(|record| {
+            format!(
+                "  - {}: {} — {}",
+                anchor_label(record.metric, record.anchor),
+                record_value(record.metric, record.anchor, record.value),
+                window_label(record.window)
+            )
+        })
+        .collect::<Vec<_>>();
+    ContentBlock::markdown(format!("New Personal Records\n{}", lines.join("\n")))
+}
+
+/// All-time, season and rolling-90-day bests, one table per sport.
+pub(crate) fn render_record_books(books: &[RecordBook]) -> Vec<ContentBlock> {
+    let mut blocks = vec![ContentBlock::markdown("### Personal Records".to_string())];
+    for book in books {
+        if book.rows.is_empty() {
+            blocks.push(ContentBlock::markdown(format!(
+                "{}: no curve data available.",
+                book.sport
+            )));
+            continue;
+        }
+        blocks.push(ContentBlock::markdown(book.sport.clone()));
+        blocks.push(ContentBlock::table(
+            vec![
+                "Anchor".into(),
+                "All-time".into(),
+                "Season".into(),
+                "90 days".into(),
+            ],
+            book.rows
+                .iter()
+                .map(|row| {
+                    vec![
+                        anchor_label(row.metric, row.anchor),
+                        record_cell(row.all_time.as_ref()),
+                        record_cell(row.season.as_ref()),
+                        record_cell(row.rolling_90.as_ref()),
+                    ]
+                })
+                .collect(),
+        ));
+    }
+    blocks
+}
+
+fn effort_value(kind: BestEffortKind, value: f64) -> String {
+    if kind.higher_is_better() {
+        format!("{value:.0} {}", kind.unit())
+    } else {
+        let total = value.round() as i64;
+        format!("{}:{:02} /km", total / 60, total % 60)
+    }
+}
+
+/// Month-by-month chart of one best-effort anchor. Bars are scaled so that the
+/// best month is longest, including for pace where lower is better.
+pub(crate) fn render_progression(
+    kind: BestEffortKind,
+    points: &[ProgressionPoint],
+    change_pct: Option<f64>,
+) -> ContentBlock {
+    let values = points
+        .iter()
+        .filter_map(|point| point.best.as_ref().map(|best| best.value))
+        .collect::<Vec<_>>();
+    let best = if kind.higher_is_better() {
+        values.iter().copied().fold(f64::MIN, f64::max)
+    } else {
+        values.iter().copied().fold(f64::MAX, f64::min)
+    };
+
+    let mut lines = vec![format!("### {} Progression", kind.label())];
+    if values.is_empty() {
+        lines.push("No qualifying sessions in this span.".into());
+        return ContentBlock::markdown(lines.join("\n"));
+    }
+    lines.push("```".into());
+    for point in points {
+        let row = match &point.best {
+            Some(effort) => {
+                let ratio = if kind.higher_is_better() {
+                    effort.value / best
+                } else {
+                    best / effort.value
+                };
+                let bar = "█".repeat((ratio * PROGRESSION_BAR_WIDTH).round().max(1.0) as usize);
+                format!(
+                    "{} {bar:<width$} {}",
+                    point.month,
+                    effort_value(kind, effort.value),
+                    width = PROGRESSION_BAR_WIDTH as usize
+                )
+            }
+            None => format!(
+                "{} {:<width$} —",
+                point.month,
+                "",
+                width = PROGRESSION_BAR_WIDTH as usize
+            ),
+        };
+        lines.push(row);
+    }
+    lines.push("```".into());
+    if let Some(change) = change_pct {
+        lines.push(format!(
+            "Change over the span: {change:+.1}% ({}).",
+            if change > 0.0 {
+                "improving"
+            } else if change < 0.0 {
+                "declining"
+            } else {
+                "flat"
+            }
+        ));
+    }
+    ContentBlock::markdown(lines.join("\n"))
+}
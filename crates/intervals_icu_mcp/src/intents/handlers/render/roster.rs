@@ -0,0 +1,96 @@
+use crate::domains::coach::CoachAlertSeverity;
+use crate::domains::roster::{AthleteSnapshot, RosterStatus};
+use crate::intents::ContentBlock;
+
+fn format_optional(value: Option<f64>, decimals: usize) -> String {
+    value
+        .map(|value| format!("{value:.decimals$}"))
+        .unwrap_or_else(|| "n/a".into())
+}
+
+fn severity_marker(severity: &CoachAlertSeverity) -> &'static str {
+    match severity {
+        CoachAlertSeverity::Priority => "❗",
+        CoachAlertSeverity::Caution => "⚠️",
+        CoachAlertSeverity::Info => "ℹ️",
+    }
+}
+
+/// Status counts, one row per athlete, and the flags of athletes needing attention.
+pub(crate) fn render_roster(snapshots: &[AthleteSnapshot]) -> Vec<ContentBlock> {
+    let count = |status: RosterStatus| {
+        snapshots
+            .iter()
+            .filter(|snapshot| snapshot.status == status)
+            .count()
+    };
+    let mut blocks = vec![ContentBlock::markdown(format!(
+        "## Roster\n{} athletes: {} attention, {} watch, {} ready, {} without recent data",
+        snapshots.len(),
+        count(RosterStatus::Attention),
+        count(RosterStatus::Watch),
+        count(RosterStatus::Ready),
+        count(RosterStatus::NoData),
+    ))];
+
+    blocks.push(ContentBlock::table(
+        vec![
+            "Athlete".into(),
+            "ID".into(),
+            "Status".into(),
+            "CTL".into(),
+            "Form".into(),
+            "ACWR".into(),
+            "Readiness".into(),
+            "HRV vs baseline".into(),
+            "Latest".into(),
+        ],
+        snapshots
+            .iter()
+            .map(|snapshot| {
+                vec![
+                    snapshot.display_name().to_string(),
+                    snapshot.athlete_id.clone(),
+                    snapshot.status.label().to_string(),
+                    format_optional(snapshot.ctl, 0),
+                    format_optional(snapshot.form, 0),
+                    snapshot
+                        .acwr
+                        .as_ref()
+                        .map(|acwr| format!("{:.2} ({})", acwr.ratio, acwr.state))
+                        .unwrap_or_else(|| "n/a".into()),
+                    format_optional(snapshot.readiness, 1),
+                    snapshot
+                        .hrv_ratio
+                        .map(|ratio| format!("{:.0}%", ratio * 100.0))
+                        .unwrap_or_else(|| "n/a".into()),
+                    snapshot.latest_date.clone().unwrap_or_else(|| "n/a".into()),
+                ]
+            })
+            .collect(),
+    ));
+
+    let flagged = snapshots
+        .iter()
+        .filter(|snapshot| !snapshot.flags.is_empty())
+        .map(|snapshot| {
+            format!(
+                "- **{}**: {}",
+                snapshot.display_name(),
+                snapshot
+                    .flags
+                    .iter()
+                    .map(|flag| format!("{} {}", severity_marker(&flag.severity), flag.message))
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            )
+        })
+        .collect::<Vec<_>>();
+    if !flagged.is_empty() {
+        blocks.push(ContentBlock::markdown(format!(
+            "### Flags\n{}",
+            flagged.join("\n")
+        )));
+    }
+    blocks
+}
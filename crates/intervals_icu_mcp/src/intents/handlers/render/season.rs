@@ -0,0 +1,394 @@
+use crate::domains::season::{BestEffortKind, SeasonDelta, SeasonReview};
+use crate::engines::health_episodes::episode_label;
+use crate::engines::season_review::CONSISTENT_WEEK_MIN_SESSIONS;
+use crate::intents::ContentBlock;
+
+use super::analysis::format_duration_hhmm;
+
+/// Gear items listed in the compact render.
+const COMPACT_GEAR_ITEMS: usize = 3;
+
+fn format_effort(kind: BestEffortKind, value: f64) -> String {
+    if kind.higher_is_better() {
+        format!("{value:.0} {}", kind.unit())
+    } else {
+        let total = value.round() as i64;
+        format!("{}:{:02} /km", total / 60, total % 60)
+    }
+}
+
+fn format_delta_value(delta: &SeasonDelta, value: Option<f64>) -> String {
+    match (value, delta.effort) {
+        (Some(value), Some(kind)) => format_effort(kind, value),
+        (Some(value), None) if value.fract().abs() < f64::EPSILON => format!("{value:.0}"),
+        (Some(value), None) => format!("{value:.1}"),
+        (None, _) => "n/a".into(),
+    }
+}
+
+fn format_change(delta: &SeasonDelta) -> String {
+    let marker = match delta.improved {
+        Some(true) => " ↑",
+        Some(false) => " ↓",
+        None => "",
+    };
+    delta
+        .change_pct
+        .map(|pct| format!("{pct:+.0}%{marker}"))
+        .unwrap_or_else(|| "n/a".into())
+}
+
+fn format_tid(tid: Option<(f64, f64, f64)>) -> String {
+    tid.map(|(low, mid, high)| format!("{:.0}/{:.0}/{:.0}", low * 100.0, mid * 100.0, high * 100.0))
+        .unwrap_or_else(|| "n/a".into())
+}
+
+fn consistency_line(review: &SeasonReview) -> String {
+    let planned = match (
+        review.consistency.ratio,
+        review.consistency.state.as_deref(),
+    ) {
+        (Some(ratio), Some(state)) => format!(
+            "{:.0}% of {} planned sessions ({state}); ",
+            ratio * 100.0,
+            review.consistency.sessions_planned
+        ),
+        _ => String::new(),
+    };
+    format!(
+        "{planned}{}/{} weeks with ≥{} sessions",
+        review.consistent_weeks, review.total_weeks, CONSISTENT_WEEK_MIN_SESSIONS
+    )
+}
+
+fn peak_ctl_line(review: &SeasonReview, previous: Option<&SeasonReview>) -> String {
+    let current = match (review.peak_ctl, review.peak_ctl_date) {
+        (Some(ctl), Some(date)) => format!("{ctl:.1} on {date}"),
+        _ => "unavailable".into(),
+    };
+    let last_year = previous
+        .and_then(|previous| previous.peak_ctl)
+        .map(|ctl| format!(" (last year {ctl:.1})"))
+        .unwrap_or_default();
+    format!("{current}{last_year}")
+}
+
+fn change_for(deltas: &[SeasonDelta], metric: &str) -> String {
+    deltas
+        .iter()
+        .find(|delta| delta.metric == metric)
+        .and_then(|delta| delta.change_pct)
+        .map(|pct| format!(" ({pct:+.0}% vs last year)"))
+        .unwrap_or_default()
+}
+
+/// Token-lean summary for LLM context: headline lines plus a monthly table.
+pub(crate) fn render_season_compact(
+    review: &SeasonReview,
+    previous: Option<&SeasonReview>,
+    deltas: &[SeasonDelta],
+) -> Vec<ContentBlock> {
+    let mut lines = vec![format!(
+        "## Season Review {} → {}",
+        review.start, review.end
+    )];
+    if let Some(previous) = previous {
+        lines.push(format!(
+            "Compared with {} → {}.",
+            previous.start, previous.end
+        ));
+    }
+    lines.push(format!(
+        "- Volume: {} sessions, {:.1} h{}, {:.0} km, load {:.0}",
+        review.sessions,
+        review.hours,
+        change_for(deltas, "Hours"),
+        review.distance_km,
+        review.load
+    ));
+    lines.push(format!("- Peak CTL: {}", peak_ctl_line(review, previous)));
+    lines.push(format!("- Consistency: {}", consistency_line(review)));
+    lines.push(format!(
+        "- Downtime: {} illness/injury episode(s), {} day(s)",
+        review.episodes.len(),
+        review.downtime_days
+    ));
+    if !review.races.is_empty() {
+        lines.push(format!(
+            "- Races: {}",
+            review
+                .races
+                .iter()
+                .map(|race| {
+                    let time = race
+                        .moving_time_secs
+                        .map(|secs| format!(" {}", format_duration_hhmm(secs)))
+                        .unwrap_or_default();
+                    format!("{} ({}){}", race.name, race.priority, time)
+                })
+                .collect::<Vec<_>>()
+                .join("; ")
+        ));
+    }
+    if !review.best_efforts.is_empty() {
+        lines.push(format!(
+            "- Best efforts: {}",
+            review
+                .best_efforts
+                .iter()
+                .map(|effort| {
+                    let change = deltas
+                        .iter()
+                        .find(|delta| delta.effort == Some(effort.kind))
+                        .filter(|delta| delta.previous.is_some())
+                        .map(|delta| format!(" ({})", format_change(delta)))
+                        .unwrap_or_default();
+                    format!(
+                        "{} {}{}",
+                        effort.kind.label(),
+                        format_effort(effort.kind, effort.value),
+                        change
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("; ")
+        ));
+    }
+    if !review.gear.is_empty() {
+        lines.push(format!(
+            "- Gear: {}",
+            review
+                .gear
+                .iter()
+                .take(COMPACT_GEAR_ITEMS)
+                .map(|gear| {
+                    format!(
+                        "{} {:.0} km / {:.0} h",
+                        gear.name.as_deref().unwrap_or(&gear.gear_id),
+                        gear.distance_km,
+                        gear.hours
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("; ")
+        ));
+    }
+
+    let mut blocks = vec![ContentBlock::markdown(lines.join("\n"))];
+    if !review.months.is_empty() {
+        blocks.push(ContentBlock::table(
+            vec![
+                "Month".into(),
+                "Hours".into(),
+                "Load".into(),
+                "TID Z1-2/Z3/Z4+ %".into(),
+            ],
+            review
+                .months
+                .iter()
+                .map(|month| {
+                    vec![
+                        month.month.clone(),
+                        format!("{:.1}", month.hours),
+                        format!("{:.0}", month.load),
+                        format_tid(month.tid),
+                    ]
+                })
+                .collect(),
+        ));
+    }
+    blocks
+}
+
+fn markdown_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut lines = vec![
+        format!("| {} |", headers.join(" | ")),
+        format!("|{}|", vec!["---"; headers.len()].join("|")),
+    ];
+    lines.extend(rows.iter().map(|row| format!("| {} |", row.join(" | "))));
+    lines.join("\n")
+}
+
+/// Full report as one Markdown document, suitable for saving or sharing.
+pub(crate) fn render_season_markdown(
+    review: &SeasonReview,
+    previous: Option<&SeasonReview>,
+    deltas: &[SeasonDelta],
+) -> Vec<ContentBlock> {
+    let mut sections = vec![format!(
+        "# Season Review: {} → {}",
+        review.start, review.end
+    )];
+    if let Some(previous) = previous {
+        sections.push(format!(
+            "Compared with the same span last year ({} → {}).",
+            previous.start, previous.end
+        ));
+    }
+
+    let summary_rows = if deltas.is_empty() {
+        vec![
+            vec!["Sessions".into(), review.sessions.to_string()],
+            vec!["Hours".into(), format!("{:.1}", review.hours)],
+            vec!["Distance (km)".into(), format!("{:.0}", review.distance_km)],
+            vec!["Load".into(), format!("{:.0}", review.load)],
+        ]
+    } else {
+        deltas
+            .iter()
+            .map(|delta| {
+                vec![
+                    delta.metric.clone(),
+                    format_delta_value(delta, delta.current),
+                    format_delta_value(delta, delta.previous),
+                    format_change(delta),
+                ]
+            })
+            .collect()
+    };
+    let summary_headers: &[&str] = if deltas.is_empty() {
+        &["Metric", "This season"]
+    } else {
+        &["Metric", "This season", "Last year", "Change"]
+    };
+    sections.push(format!(
+        "## Summary\n\n{}\n\n- Peak CTL: {}\n- Consistency: {}",
+        markdown_table(summary_headers, &summary_rows),
+        peak_ctl_line(review, previous),
+        consistency_line(review)
+    ));
+
+    let month_rows = review
+        .months
+        .iter()
+        .map(|month| {
+            vec![
+                month.month.clone(),
+                month.sessions.to_string(),
+                format!("{:.1}", month.hours),
+                format!("{:.0}", month.distance_km),
+                format!("{:.0}", month.load),
+                format_tid(month.tid),
+            ]
+        })
+        .collect::<Vec<_>>();
+    sections.push(format!(
+        "## Monthly Volume, Load and Intensity\n\n{}",
+        markdown_table(
+            &[
+                "Month",
+                "Sessions",
+                "Hours",
+                "km",
+                "Load",
+                "TID Z1-2/Z3/Z4+ %"
+            ],
+            &month_rows
+        )
+    ));
+
+    if !review.best_efforts.is_empty() {
+        let rows = review
+            .best_efforts
+            .iter()
+            .map(|effort| {
+                let last_year = previous
+                    .and_then(|previous| {
+                        previous
+                            .best_efforts
+                            .iter()
+                            .find(|prior| prior.kind == effort.kind)
+                    })
+                    .map(|prior| format_effort(prior.kind, prior.value))
+                    .unwrap_or_else(|| "n/a".into());
+                let mut row = vec![
+                    effort.kind.label().to_string(),
+                    format_effort(effort.kind, effort.value),
+                    effort
+                        .date
+                        .map(|date| date.to_string())
+                        .unwrap_or_else(|| "-".into()),
+                    effort.activity_id.clone().unwrap_or_else(|| "-".into()),
+                ];
+                if previous.is_some() {
+                    row.push(last_year);
+                }
+                row
+            })
+            .collect::<Vec<_>>();
+        let headers: &[&str] = if previous.is_some() {
+            &["Effort", "Best", "Date", "Activity", "Last year"]
+        } else {
+            &["Effort", "Best", "Date", "Activity"]
+        };
+        sections.push(format!(
+            "## Best Efforts\n\neFTP from the wellness eFTP history; peak power and HR, 20 min power and HR and race-distance pace from the season's power, HR and pace curves.\n\n{}",
+            markdown_table(headers, &rows)
+        ));
+    }
+
+    if !review.races.is_empty() {
+        let rows = review
+            .races
+            .iter()
+            .map(|race| {
+                vec![
+                    race.date.to_string(),
+                    race.name.clone(),
+                    race.priority.clone(),
+                    race.moving_time_secs
+                        .map(format_duration_hhmm)
+                        .unwrap_or_else(|| "no activity".into()),
+                    race.distance_km
+                        .map(|km| format!("{km:.1}"))
+                        .unwrap_or_else(|| "n/a".into()),
+                ]
+            })
+            .collect::<Vec<_>>();
+        sections.push(format!(
+            "## Races\n\n{}",
+            markdown_table(&["Date", "Race", "Priority", "Time", "km"], &rows)
+        ));
+    }
+
+    let mut downtime = vec![format!(
+        "## Illness & Injury Downtime\n\n{} day(s) lost across {} episode(s).",
+        review.downtime_days,
+        review.episodes.len()
+    )];
+    for episode in &review.episodes {
+        downtime.push(format!(
+            "- {} {} → {} ({}){}",
+            episode_label(episode.kind),
+            episode.start,
+            episode.end,
+            episode.sources.join(", "),
+            episode
+                .ctl_lost
+                .map(|ctl| format!(", CTL lost {ctl:.1}"))
+                .unwrap_or_default()
+        ));
+    }
+    sections.push(downtime.join("\n"));
+
+    if !review.gear.is_empty() {
+        let rows = review
+            .gear
+            .iter()
+            .map(|gear| {
+                vec![
+                    gear.name.clone().unwrap_or_else(|| gear.gear_id.clone()),
+                    gear.sessions.to_string(),
+                    format!("{:.0}", gear.distance_km),
+                    format!("{:.1}", gear.hours),
+                ]
+            })
+            .collect::<Vec<_>>();
+        sections.push(format!(
+            "## Gear Usage\n\n{}",
+            markdown_table(&["Gear", "Sessions", "km", "Hours"], &rows)
+        ));
+    }
+
+    vec![ContentBlock::markdown(sections.join("\n\n"))]
+}
@@ -0,0 +1,225 @@
+use crate::intents::{IdempotencyCache, IntentError, IntentHandler, IntentOutput, OutputMetadata};
+use async_trait::async_trait;
+use chrono::Utc;
+use intervals_icu_client::IntervalsClient;
+use serde_json::{Value, json};
+/// Review Roster Intent Handler
+///
+/// Summarises readiness, ACWR and flags across every athlete a coach account
+/// can access.
+use std::sync::Arc;
+
+use crate::domains::roster::RosterStatus;
+use crate::engines::roster::{
+    ROSTER_WELLNESS_DAYS, rank_roster, summarize_athlete, unavailable_athlete,
+};
+
+use super::render::roster::render_roster;
+
+/// Athletes summarised per call; larger rosters are truncated with a warning.
+const MAX_ROSTER_ATHLETES: usize = 100;
+
+pub struct ReviewRosterHandler;
+
+impl ReviewRosterHandler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ReviewRosterHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl IntentHandler for ReviewRosterHandler {
+    fn name(&self) -> &'static str {
+        "review_roster"
+    }
+
+    fn description(&self) -> &'static str {
+        "Summarise readiness, load and warning flags across every athlete on a coach account.
+
+Use this tool when: a coach wants a morning check of their squad, asks who needs attention, or needs the athlete ids to pass as athlete_id to other tools. Helps answer 'who is overreaching?' or 'how is my team doing?'.
+
+Do NOT use when: the account is not a coach account, or for a single athlete's detail (call assess_recovery or analyze_training with athlete_id).
+
+Arguments:
+- only_flagged (boolean, default false): List only athletes with attention or watch status.
+
+Returns: One row per athlete with status (attention / watch / ready / no data), CTL, form (TSB), ACWR with its band, readiness, HRV versus baseline and the latest wellness date, ranked most urgent first, plus each athlete's flags (ACWR overreaching, deep fatigue, suppressed HRV, low readiness, stale data).
+On error: API errors, or a validation error when the account coaches no athletes."
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "only_flagged": {"type": "boolean", "default": false}
+            },
+            "required": []
+        })
+    }
+
+    fn athlete_scoped(&self) -> bool {
+        false
+    }
+
+    async fn execute(
+        &self,
+        input: Value,
+        client: Arc<dyn IntervalsClient>,
+        _cache: Option<&IdempotencyCache>,
+    ) -> Result<IntentOutput, IntentError> {
+        let only_flagged = input
+            .get("only_flagged")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        let mut roster = client.list_coached_athletes().await.map_err(|error| {
+            IntentError::api(format!("Failed to fetch coached athletes: {error}"))
+        })?;
+        if roster.is_empty() {
+            return Err(IntentError::validation(
+                "This account coaches no athletes. review_roster needs an Intervals.icu coach account.",
+            ));
+        }
+        let mut warnings = Vec::new();
+        if roster.len() > MAX_ROSTER_ATHLETES {
+            warnings.push(format!(
+                "Roster has {} athletes; only the first {MAX_ROSTER_ATHLETES} are summarised.",
+                roster.len()
+            ));
+            roster.truncate(MAX_ROSTER_ATHLETES);
+        }
+
+        let today = Utc::now().date_naive();
+        let mut snapshots = Vec::with_capacity(roster.len());
+        for athlete in &roster {
+            let Some(athlete_client) = client.for_athlete(&athlete.id) else {
+                return Err(IntentError::internal(
+                    "This client cannot act on behalf of coached athletes",
+                ));
+            };
+            let snapshot = match athlete_client
+                .get_wellness(Some(ROSTER_WELLNESS_DAYS))
+                .await
+            {
+                Ok(wellness) => summarize_athlete(athlete, &wellness, today),
+                Err(error) => unavailable_athlete(athlete, &error.to_string()),
+            };
+            snapshots.push(snapshot);
+        }
+        rank_roster(&mut snapshots);
+
+        let urgent = snapshots
+            .iter()
+            .filter(|snapshot| snapshot.status == RosterStatus::Attention)
+            .map(|snapshot| snapshot.display_name().to_string())
+            .collect::<Vec<_>>();
+        if only_flagged {
+            snapshots.retain(|snapshot| {
+                matches!(
+                    snapshot.status,
+                    RosterStatus::Attention | RosterStatus::Watch
+                )
+            });
+        }
+
+        let mut content = render_roster(&snapshots);
+        if !warnings.is_empty() {
+            content.push(crate::intents::ContentBlock::markdown(format!(
+                "### Warnings\n{}",
+                warnings
+                    .iter()
+                    .map(|warning| format!("- {warning}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )));
+        }
+
+        let mut suggestions = Vec::new();
+        if !urgent.is_empty() {
+            suggestions.push(format!(
+                "Review {} first; consider reducing load.",
+                urgent.join(", ")
+            ));
+        }
+
+        Ok(IntentOutput::new(content)
+            .with_suggestions(suggestions)
+            .with_next_actions(vec![
+                "assess_recovery with athlete_id for a flagged athlete".into(),
+                "analyze_training with athlete_id to inspect recent sessions".into(),
+                "modify_training with athlete_id to adjust a plan".into(),
+            ])
+            .with_metadata(OutputMetadata::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::mock::MockIntervalsClient;
+    use chrono::Duration;
+
+    fn wellness(ctl: f64, atl: f64) -> Value {
+        let today = Utc::now().date_naive();
+        json!([
+            {"id": (today - Duration::days(1)).to_string(), "ctl": ctl, "atl": atl},
+            {"id": today.to_string(), "ctl": ctl, "atl": atl}
+        ])
+    }
+
+    fn coach() -> MockIntervalsClient {
+        MockIntervalsClient::builder()
+            .with_coached_athlete(
+                "i1",
+                "Steady Sam",
+                MockIntervalsClient::builder().with_wellness(wellness(60.0, 61.0)),
+            )
+            .with_coached_athlete(
+                "i2",
+                "Overloaded Olu",
+                MockIntervalsClient::builder().with_wellness(wellness(40.0, 80.0)),
+            )
+    }
+
+    #[tokio::test]
+    async fn execute_ranks_flagged_athletes_first() {
+        let output = ReviewRosterHandler::new()
+            .execute(json!({}), Arc::new(coach()), None)
+            .await
+            .unwrap();
+        let rendered = format!("{:?}", output.content);
+
+        assert!(rendered.contains("2 athletes: 1 attention, 0 watch, 1 ready"));
+        let olu = rendered.find("Overloaded Olu").unwrap();
+        let sam = rendered.find("Steady Sam").unwrap();
+        assert!(olu < sam, "most urgent athlete should be listed first");
+        assert!(rendered.contains("deep fatigue"));
+        assert!(output.suggestions[0].contains("Overloaded Olu"));
+    }
+
+    #[tokio::test]
+    async fn execute_only_flagged_hides_ready_athletes() {
+        let output = ReviewRosterHandler::new()
+            .execute(json!({"only_flagged": true}), Arc::new(coach()), None)
+            .await
+            .unwrap();
+        let rendered = format!("{:?}", output.content);
+
+        assert!(rendered.contains("Overloaded Olu"));
+        assert!(!rendered.contains("Steady Sam"));
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_accounts_without_athletes() {
+        let result = ReviewRosterHandler::new()
+            .execute(json!({}), Arc::new(MockIntervalsClient::builder()), None)
+            .await;
+        assert!(matches!(result, Err(IntentError::ValidationError(_))));
+    }
+}
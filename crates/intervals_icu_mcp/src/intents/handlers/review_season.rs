@@ -0,0 +1,494 @@
+use crate::intents::{IdempotencyCache, IntentError, IntentHandler, IntentOutput, OutputMetadata};
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate, Utc};
+use intervals_icu_client::{ActivitySummary, IntervalsClient};
+use serde_json::{Value, json};
+/// Review Season Intent Handler
+///
+/// Reviews a full season or year and compares it with the same span last year.
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::domains::season::BestEffortKind;
+use crate::engines::analysis_fetch::{
+    fetch_activities_between, fetch_calendar_events_between, fetch_effort_curves,
+};
+use crate::engines::season_review::{
+    MAX_SEASON_DAYS, MIN_SEASON_DAYS, SeasonSources, build_season_review, compare_seasons,
+    parse_date as parse_activity_date, previous_season, race_priority,
+};
+use crate::intents::utils::parse_date;
+
+use super::render::season::{render_season_compact, render_season_markdown};
+
+/// Upcoming calendar events fetched for the current season.
+const MAX_SEASON_EVENTS: u32 = 2000;
+/// Activity details fetched per review, across both seasons: race-day sessions
+/// first, then the most recent sessions for gear usage and intensity split.
+const MAX_SEASON_DETAIL_FETCHES: usize = 16;
+/// Changes smaller than this are left out of the suggestions (%).
+const NOTABLE_CHANGE_PCT: f64 = 5.0;
+const MAX_CHANGE_SUGGESTIONS: usize = 4;
+
+pub struct ReviewSeasonHandler;
+
+impl ReviewSeasonHandler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ReviewSeasonHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReviewFormat {
+    Compact,
+    Markdown,
+}
+
+fn parse_format(input: &Value) -> Result<ReviewFormat, IntentError> {
+    match input.get("format").and_then(Value::as_str) {
+        None | Some("compact") => Ok(ReviewFormat::Compact),
+        Some("markdown") => Ok(ReviewFormat::Markdown),
+        Some(other) => Err(IntentError::validation(format!(
+            "Invalid format '{other}'. Use 'compact' or 'markdown'."
+        ))),
+    }
+}
+
+fn in_range(date: &str, start: NaiveDate, end: NaiveDate) -> bool {
+    date.get(..10)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        .is_some_and(|date| date >= start && date <= end)
+}
+
+#[async_trait]
+impl IntentHandler for ReviewSeasonHandler {
+    fn name(&self) -> &'static str {
+        "review_season"
+    }
+
+    fn description(&self) -> &'static str {
+        "Review a full season or year and compare it with the same span last year.
+
+Use this tool when: the athlete wants an end-of-season or annual review, a year-over-year comparison, or a shareable season report. Helps answer 'how did my season go?' or 'am I better than last year?'.
+
+Do NOT use when: comparing two arbitrary short windows (use compare_periods), diagnosing a current plateau (use track_progress), or analyzing a single workout (use analyze_training).
+
+Arguments:
+- season_start / season_end (YYYY-MM-DD, 'today', 'tomorrow', or 'yesterday'; default the last 365 days): Season window, 28–366 days.
+- compare_last_year (boolean, default true): Also build the same span one year earlier and report deltas.
+- format ('compact' | 'markdown', default 'compact'): Compact summary for LLM context, or a full Markdown export.
+
+Returns: Monthly volume, load and intensity distribution (Z1-2/Z3/Z4+), peak CTL and its date, best efforts (eFTP from wellness; peak power, max HR, 20 min power and HR and 5k/10k/half-marathon pace from the season's curves), race outcomes, consistency (planned vs completed, weeks with 3+ sessions), illness/injury downtime, gear usage (from races and the most recent sessions), and year-over-year deltas.
+On error: API or validation errors with descriptive messages."
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "season_start": {"type": "string", "description": "Season start (YYYY-MM-DD, 'today', 'tomorrow', or 'yesterday')"},
+                "season_end": {"type": "string", "description": "Season end (YYYY-MM-DD, 'today', 'tomorrow', or 'yesterday')"},
+                "compare_last_year": {"type": "boolean", "default": true},
+                "format": {"type": "string", "enum": ["compact", "markdown"], "default": "compact"}
+            },
+            "required": []
+        })
+    }
+
+    fn requires_idempotency_token(&self) -> bool {
+        false
+    }
+
+    async fn execute(
+        &self,
+        input: Value,
+        client: Arc<dyn IntervalsClient>,
+        _cache: Option<&IdempotencyCache>,
+    ) -> Result<IntentOutput, IntentError> {
+        let today = Utc::now().date_naive();
+        let end = match input.get("season_end").and_then(Value::as_str) {
+            Some(value) => parse_date(value, "season_end")?,
+            None => today,
+        };
+        let start = match input.get("season_start").and_then(Value::as_str) {
+            Some(value) => parse_date(value, "season_start")?,
+            None => end - Duration::days(MAX_SEASON_DAYS - 2),
+        };
+        let span = (end - start).num_days() + 1;
+        if !(MIN_SEASON_DAYS..=MAX_SEASON_DAYS).contains(&span) {
+            return Err(IntentError::validation(format!(
+                "Season must span {MIN_SEASON_DAYS}–{MAX_SEASON_DAYS} days (got {span}). Use compare_periods for shorter windows."
+            )));
+        }
+        let compare = input
+            .get("compare_last_year")
+            .and_then(Value::as_bool)
+            .unwrap_or(true);
+        let format = parse_format(&input)?;
+
+        let previous_window = compare.then(|| previous_season(start, end));
+        let fetch_start = previous_window.map_or(start, |(previous_start, _)| previous_start);
+        let days_back = ((today - fetch_start).num_days() + 1).max(1) as i32;
+
+        let activities = fetch_activities_between(client.as_ref(), fetch_start, end).await?;
+
+        let mut warnings = Vec::new();
+        let events = match fetch_calendar_events_between(
+            client.as_ref(),
+            &fetch_start,
+            &end,
+            MAX_SEASON_EVENTS,
+        )
+        .await
+        {
+            Ok(events) => events,
+            Err(error) => {
+                warnings.push(format!(
+                        "Calendar unavailable ({error}); races, planned sessions and downtime may be incomplete."
+                    ));
+                Vec::new()
+            }
+        };
+
+        // Volume, load and consistency come from the summaries. Details are
+        // fetched one by one, so only a small sample is read: race-day sessions,
+        // then each season's most recent sessions (activities are newest first).
+        let race_days = events
+            .iter()
+            .filter(|event| race_priority(&event.category).is_some())
+            .filter_map(|event| parse_activity_date(&event.start_date_local))
+            .collect::<HashSet<_>>();
+        let in_season =
+            |activity: &&ActivitySummary| in_range(&activity.start_date_local, start, end);
+        let in_previous = |activity: &&ActivitySummary| {
+            previous_window.is_some_and(|(previous_start, previous_end)| {
+                in_range(&activity.start_date_local, previous_start, previous_end)
+            })
+        };
+        let races = activities.iter().filter(|activity| {
+            (in_season(activity) || in_previous(activity))
+                && parse_activity_date(&activity.start_date_local)
+                    .is_some_and(|date| race_days.contains(&date))
+        });
+        let recent_budget = if previous_window.is_some() {
+            MAX_SEASON_DETAIL_FETCHES / 2
+        } else {
+            MAX_SEASON_DETAIL_FETCHES
+        };
+        let recent = activities
+            .iter()
+            .filter(in_season)
+            .take(recent_budget)
+            .chain(activities.iter().filter(in_previous).take(recent_budget));
+        let mut sampled = HashSet::new();
+        let mut details = HashMap::new();
+        let mut failed_details = 0;
+        for activity in races.chain(recent) {
+            if sampled.len() >= MAX_SEASON_DETAIL_FETCHES || !sampled.insert(activity.id.as_str()) {
+                continue;
+            }
+            match client.get_activity_details(&activity.id).await {
+                Ok(detail) => {
+                    details.insert(activity.id.clone(), detail);
+                }
+                Err(_) => failed_details += 1,
+            }
+        }
+        let season_sessions = activities.iter().filter(in_season).count();
+        let sampled_sessions = activities
+            .iter()
+            .filter(in_season)
+            .filter(|activity| sampled.contains(activity.id.as_str()))
+            .count();
+        if sampled_sessions < season_sessions {
+            warnings.push(format!(
+                "Gear usage and intensity distribution cover {sampled_sessions} of {season_sessions} sessions (races and the most recent); volume, load and consistency cover all."
+            ));
+        }
+        if failed_details > 0 {
+            warnings.push(format!(
+                "{failed_details} activity detail request(s) failed; gear and intensity figures may be incomplete."
+            ));
+        }
+
+        let wellness = client.get_wellness(Some(days_back)).await.ok();
+        if wellness.is_none() {
+            warnings.push("Wellness unavailable; peak CTL not reported.".to_string());
+        }
+        let gear_list = client.get_gear_list().await.ok();
+
+        let (curves, mut failed_curves) =
+            fetch_effort_curves(client.as_ref(), BestEffortKind::ALL, start, end).await;
+        let mut previous_curves = None;
+        if let Some((previous_start, previous_end)) = previous_window {
+            let (curves, failed) = fetch_effort_curves(
+                client.as_ref(),
+                BestEffortKind::ALL,
+                previous_start,
+                previous_end,
+            )
+            .await;
+            failed_curves += failed;
+            previous_curves = Some(curves);
+        }
+        if failed_curves > 0 {
+            warnings.push(format!(
+                "{failed_curves} power/HR/pace curve request(s) failed; some duration and distance bests are missing."
+            ));
+        }
+
+        let sources = SeasonSources {
+            activities: &activities,
+            details: &details,
+            events: &events,
+            wellness: wellness.as_ref(),
+            gear_list: gear_list.as_ref(),
+        };
+        let review = build_season_review(&sources, &curves, start, end);
+        let previous = previous_window.zip(previous_curves.as_ref()).map(
+            |((previous_start, previous_end), curves)| {
+                build_season_review(&sources, curves, previous_start, previous_end)
+            },
+        );
+        let deltas = previous
+            .as_ref()
+            .map(|previous| compare_seasons(&review, previous))
+            .unwrap_or_default();
+
+        let mut content = match format {
+            ReviewFormat::Compact => render_season_compact(&review, previous.as_ref(), &deltas),
+            ReviewFormat::Markdown => render_season_markdown(&review, previous.as_ref(), &deltas),
+        };
+        if previous
+            .as_ref()
+            .is_some_and(|previous| previous.sessions == 0)
+        {
+            warnings.push(
+                "No activities found for last year's span; year-over-year deltas are empty."
+                    .to_string(),
+            );
+        }
+        if !warnings.is_empty() {
+            content.push(crate::intents::ContentBlock::markdown(format!(
+                "### Warnings\n{}",
+                warnings
+                    .iter()
+                    .map(|warning| format!("- {warning}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )));
+        }
+
+        let mut notable = deltas
+            .iter()
+            .filter(|delta| delta.improved.is_some())
+            .filter_map(|delta| {
+                let pct = delta.change_pct?;
+                (pct.abs() >= NOTABLE_CHANGE_PCT).then_some((delta, pct))
+            })
+            .collect::<Vec<_>>();
+        notable.sort_by(|a, b| b.1.abs().total_cmp(&a.1.abs()));
+        let mut suggestions = notable
+            .into_iter()
+            .take(MAX_CHANGE_SUGGESTIONS)
+            .map(|(delta, pct)| {
+                format!(
+                    "{}: {pct:+.0}% vs last year ({}).",
+                    delta.metric,
+                    if delta.improved == Some(true) {
+                        "better"
+                    } else {
+                        "worse"
+                    }
+                )
+            })
+            .collect::<Vec<_>>();
+        if review.downtime_days > 0 {
+            suggestions.push(format!(
+                "{} day(s) lost to illness or injury; review what preceded each episode before planning next season.",
+                review.downtime_days
+            ));
+        }
+
+        Ok(IntentOutput::new(content)
+            .with_suggestions(suggestions)
+            .with_next_actions(vec![
+                "track_progress for current plateau diagnosis".into(),
+                "compare_periods to drill into specific blocks".into(),
+                "plan_training to build next season".into(),
+            ])
+            .with_metadata(OutputMetadata::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::mock::MockIntervalsClient;
+    use intervals_icu_client::domains::curve::CurveKind;
+    use intervals_icu_client::{Event, EventCategory};
+
+    fn run(id: &str, date: NaiveDate, minutes: i32, km: f64) -> ActivitySummary {
+        ActivitySummary {
+            id: id.into(),
+            start_date_local: format!("{date}T07:00:00"),
+            moving_time: Some(minutes * 60),
+            distance: Some(km * 1000.0),
+            training_load: Some(minutes + 20),
+            ..Default::default()
+        }
+    }
+
+    fn mock() -> MockIntervalsClient {
+        let today = Utc::now().date_naive();
+        let season_start = today - Duration::days(MAX_SEASON_DAYS - 2);
+        let this_year = today - Duration::days(30);
+        let last_year = previous_season(this_year, this_year).0;
+        MockIntervalsClient::builder()
+            .with_activities(vec![
+                run("now-2", this_year + Duration::days(1), 90, 18.0),
+                run("now-1", this_year, 50, 11.0),
+                run("then-1", last_year, 55, 10.0),
+            ])
+            .with_activity_detail("now-1", json!({"type": "Run", "gear": {"id": "g1"}}))
+            .with_activity_detail("now-2", json!({"type": "Run", "gear": {"id": "g1"}}))
+            .with_activity_detail("then-1", json!({"type": "Run"}))
+            .with_events(vec![Event {
+                id: None,
+                start_date_local: (this_year + Duration::days(1)).to_string(),
+                name: "Autumn 30K".into(),
+                category: EventCategory::RaceB,
+                description: None,
+                r#type: None,
+                end_date_local: None,
+            }])
+            .with_gear_list(json!([{"id": "g1", "name": "Trail shoe"}]))
+            .with_wellness(json!([
+                {"date": last_year.to_string(), "ctl": 58.0, "eftp": 262.0},
+                {"date": this_year.to_string(), "ctl": 64.0, "eftp": 280.0}
+            ]))
+            .with_curves_between(
+                CurveKind::Pace,
+                "Run",
+                season_start,
+                json!({"list": [{"distance": [5000, 10000], "values": [1380, 2880],
+                                 "activity_id": ["now-2", "now-2"]}]}),
+            )
+            .with_curves_between(
+                CurveKind::Pace,
+                "Run",
+                previous_season(season_start, season_start).0,
+                json!({"list": [{"distance": [5000], "values": [1440],
+                                 "activity_id": ["then-1"]}]}),
+            )
+    }
+
+    #[tokio::test]
+    async fn execute_compact_reports_season_and_year_over_year() {
+        let output = ReviewSeasonHandler::new()
+            .execute(json!({}), Arc::new(mock()), None)
+            .await
+            .unwrap();
+        let rendered = format!("{:?}", output.content);
+        assert!(rendered.contains("Season Review"));
+        assert!(rendered.contains("2 sessions"));
+        assert!(rendered.contains("Peak CTL: 64.0"));
+        assert!(rendered.contains("Autumn 30K (B) 1:30:00"));
+        assert!(rendered.contains("eFTP 280 W (+7% ↑)"));
+        assert!(rendered.contains("5k pace 4:36 /km (-4% ↑)"));
+        assert!(rendered.contains("Trail shoe 29 km"));
+        assert!(rendered.contains("TID Z1-2/Z3/Z4+ %"));
+        assert!(
+            output
+                .suggestions
+                .iter()
+                .any(|suggestion| suggestion.starts_with("Hours:"))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_markdown_export_has_full_sections() {
+        let output = ReviewSeasonHandler::new()
+            .execute(
+                json!({"format": "markdown", "compare_last_year": false}),
+                Arc::new(mock()),
+                None,
+            )
+            .await
+            .unwrap();
+        let rendered = format!("{:?}", output.content);
+        assert!(rendered.contains("# Season Review:"));
+        assert!(rendered.contains("## Monthly Volume, Load and Intensity"));
+        assert!(rendered.contains("## Best Efforts"));
+        assert!(rendered.contains("## Races"));
+        assert!(rendered.contains("## Gear Usage"));
+        assert!(!rendered.contains("Last year"));
+    }
+
+    #[tokio::test]
+    async fn execute_warns_about_failed_detail_fetches() {
+        let output = ReviewSeasonHandler::new()
+            .execute(
+                json!({"compare_last_year": false}),
+                Arc::new(mock().with_activity_detail_error("now-2")),
+                None,
+            )
+            .await
+            .unwrap();
+        let rendered = format!("{:?}", output.content);
+        // The session still counts from its summary.
+        assert!(rendered.contains("2 sessions"));
+        assert!(rendered.contains("1 activity detail request(s) failed"));
+    }
+
+    #[tokio::test]
+    async fn execute_samples_details_and_stops_at_season_end() {
+        let today = Utc::now().date_naive();
+        let end = today - Duration::days(60);
+        let start = end - Duration::days(MIN_SEASON_DAYS);
+        let mut activities = (0..30)
+            .map(|day| run(&format!("s{day}"), end - Duration::days(day), 40, 8.0))
+            .collect::<Vec<_>>();
+        activities.insert(0, run("after-end", today - Duration::days(1), 40, 8.0));
+        let output = ReviewSeasonHandler::new()
+            .execute(
+                json!({"season_start": start.to_string(), "season_end": end.to_string(),
+                       "compare_last_year": false}),
+                Arc::new(MockIntervalsClient::builder().with_activities(activities)),
+                None,
+            )
+            .await
+            .unwrap();
+        let rendered = format!("{:?}", output.content);
+        // Summaries cover every session in the window; nothing after its end.
+        assert!(rendered.contains("29 sessions"));
+        assert!(rendered.contains(&format!("cover {MAX_SEASON_DETAIL_FETCHES} of 29 sessions")));
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_bad_window_and_format() {
+        let handler = ReviewSeasonHandler::new();
+        let short = handler
+            .execute(
+                json!({"season_start": "2026-01-01", "season_end": "2026-01-10"}),
+                Arc::new(MockIntervalsClient::builder()),
+                None,
+            )
+            .await;
+        assert!(short.is_err());
+        let format = handler
+            .execute(
+                json!({"format": "pdf"}),
+                Arc::new(MockIntervalsClient::builder()),
+                None,
+            )
+            .await;
+        assert!(format.is_err());
+    }
+}
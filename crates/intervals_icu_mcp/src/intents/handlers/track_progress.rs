@@ -9,12 +9,25 @@ use serde_json::Value;
 use serde_json::json;
 
 use crate::domains::coach::AnalysisWindow;
+use crate::domains::season::BestEffortKind;
+use crate::engines::analysis_fetch::{
+    fetch_activities_between, fetch_effort_curves, fetch_record_book, fetch_srpe_calibration,
+};
+use crate::engines::coach_metrics::extract_ctl_series;
+use crate::engines::health_episodes::build_health_episodes;
+use crate::engines::injury_risk::CHANNEL_HISTORY_DAYS;
+use crate::engines::personal_records::{
+    DEFAULT_PROGRESSION_MONTHS, MAX_PROGRESSION_MONTHS, MIN_PROGRESSION_MONTHS,
+    monthly_progression, progression_change_pct, progression_months as progression_month_spans,
+};
 use crate::engines::progress_tracking::{
-    MAX_WELLNESS_DAYS_FALLBACK, MIN_DAYS_FOR_PLATEAU, build_progress_report, count_ctl_points,
+    MAX_WELLNESS_DAYS_FALLBACK, MIN_DAYS_FOR_PLATEAU, build_progress_report_with_episodes,
+    count_ctl_points,
 };
 use crate::intents::{IdempotencyCache, IntentError, IntentHandler, IntentOutput, OutputMetadata};
 
 use super::render::progress::render_progress_report;
+use super::render::records::{render_progression, render_record_books};
 
 const DEFAULT_PERIOD_WEEKS: i64 = 12;
 const MIN_PERIOD_WEEKS: i64 = 4;
@@ -60,8 +73,11 @@ Do NOT use when: you need to analyze a specific workout in detail (use analyze_t
 Arguments:
 - period_weeks (integer, 4–24, default 12): How far back to analyze.
 - hypothesis_mode (boolean, default true): Whether to compute coaching hypotheses (volume, intensity distribution, recovery) and recommendations.
+- record_sports (array of 'Ride' | 'Run', optional): Add all-time, season, and rolling-90-day personal records at standard durations (power) and distances (pace) for each sport.
+- progression_anchor (string, optional): Chart the monthly best of one anchor: eftp, peak_power, power20m, pace5k, pace10k, pace_half, max_hr, hr20m.
+- progression_months (integer, 3–24, default 12): Months covered by the progression chart.
 
-Returns: Progress Tracking Report with plateau detection, load context (ACWR, monotony, strain), HRV context, TID drift analysis, coaching hypotheses with confidence scores, recommendations, and warnings when data is insufficient. Includes a Fitness Snapshot with current CTL, ATL, TSB, and ramp rate when athlete-summary data is available.
+Returns: Progress Tracking Report with plateau detection, load context (ACWR, monotony, strain), per-channel injury risk (run impact, vertical/descent, cycling, strength) with the driving channel, HRV context, TID drift analysis, coaching hypotheses with confidence scores, recommendations, and warnings when data is insufficient. Includes a Fitness Snapshot with current CTL, ATL, TSB, and ramp rate when athlete-summary data is available, plus personal records and anchor progression when requested.
 On error: API or validation errors with descriptive messages."
     }
 
@@ -78,6 +94,23 @@ On error: API or validation errors with descriptive messages."
                 "hypothesis_mode": {
                     "type": "boolean",
                     "default": true
+                },
+                "record_sports": {
+                    "type": "array",
+                    "items": {"type": "string", "enum": ["Ride", "Run"]}
+                },
+                "progression_anchor": {
+                    "type": "string",
+                    "enum": [
+                        "eftp", "peak_power", "power20m", "pace5k",
+                        "pace10k", "pace_half", "max_hr", "hr20m"
+                    ]
+                },
+                "progression_months": {
+                    "type": "integer",
+                    "minimum": MIN_PROGRESSION_MONTHS,
+                    "maximum": MAX_PROGRESSION_MONTHS,
+                    "default": DEFAULT_PROGRESSION_MONTHS
                 }
             },
             "required": []
@@ -102,6 +135,32 @@ On error: API or validation errors with descriptive messages."
             .get("hypothesis_mode")
             .and_then(Value::as_bool)
             .unwrap_or(true);
+        let record_sports = match input.get("record_sports") {
+            None | Some(Value::Null) => Vec::new(),
+            Some(value) => serde_json::from_value::<Vec<String>>(value.clone())
+                .ok()
+                .filter(|sports| sports.iter().all(|sport| sport == "Ride" || sport == "Run"))
+                .ok_or_else(|| {
+                    IntentError::validation("record_sports must be a list of 'Ride' or 'Run'")
+                })?,
+        };
+        let progression_anchor = match input.get("progression_anchor") {
+            None | Some(Value::Null) => None,
+            Some(value) => Some(
+                serde_json::from_value::<BestEffortKind>(value.clone()).map_err(|_| {
+                    IntentError::validation(format!("Unknown progression_anchor: {value}"))
+                })?,
+            ),
+        };
+        let progression_months = input
+            .get("progression_months")
+            .and_then(Value::as_u64)
+            .map_or(DEFAULT_PROGRESSION_MONTHS, |months| months as u32);
+        if !(MIN_PROGRESSION_MONTHS..=MAX_PROGRESSION_MONTHS).contains(&progression_months) {
+            return Err(IntentError::validation(format!(
+                "progression_months must be between {MIN_PROGRESSION_MONTHS} and {MAX_PROGRESSION_MONTHS}"
+            )));
+        }
 
         let period_days = (period_weeks * 7) as i32;
         let end_date = Utc::now().date_naive();
@@ -154,13 +213,115 @@ On error: API or validation errors with descriptive messages."
             }
         }
 
-        let report = build_progress_report(&wellness, &activities, &activity_details, &window);
+        // The injury-risk channels need sport type and elevation for every
+        // activity in the chronic window, not just the TID sample.
+        let channel_start = end_date - Duration::days(CHANNEL_HISTORY_DAYS - 1);
+        for activity in &activities {
+            let in_channel_window = activity
+                .start_date_local
+                .get(..10)
+                .and_then(|date| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+                .is_some_and(|date| date >= channel_start);
+            if in_channel_window
+                && !activity_details.contains_key(&activity.id)
+                && let Ok(detail) = client.get_activity_details(&activity.id).await
+            {
+                activity_details.insert(activity.id.clone(), detail);
+            }
+        }
+
+        // Illness / injury episodes come from SICK and INJURED calendar entries plus
+        // wellness injury flags; a missing calendar only means no episodes.
+        let wellness_days = count_ctl_points(&wellness).max(period_days as usize) as i32;
+        let events = client
+            .get_events(Some(wellness_days), None)
+            .await
+            .unwrap_or_default();
+        let ctl_series = extract_ctl_series(Some(&wellness));
+        let episodes = build_health_episodes(
+            &events,
+            Some(&wellness),
+            &activities,
+            ctl_series
+                .as_ref()
+                .map(|(dates, values)| (dates.as_slice(), values.as_slice())),
+            end_date,
+        );
+
+        let calibration = fetch_srpe_calibration(client.as_ref(), activity_details.values()).await;
+        let report = build_progress_report_with_episodes(
+            &wellness,
+            &activities,
+            &activity_details,
+            &window,
+            &calibration,
+            &episodes,
+        );
 
         let fitness = client.get_fitness_summary().await.ok();
         let fitness_metrics =
             crate::engines::coach_metrics::parse_fitness_metrics(fitness.as_ref());
 
-        let content = render_progress_report(&report, hypothesis_mode, fitness_metrics.as_ref());
+        let mut content =
+            render_progress_report(&report, hypothesis_mode, fitness_metrics.as_ref());
+
+        if !record_sports.is_empty() {
+            let mut books = Vec::new();
+            for sport in &record_sports {
+                books.push(fetch_record_book(client.as_ref(), sport, end_date).await);
+            }
+            content.extend(render_record_books(&books));
+        }
+
+        if let Some(kind) = progression_anchor {
+            // Progression spans whole calendar months up to end_date, usually
+            // longer than the period.
+            let months = progression_month_spans(progression_months, end_date);
+            let history_start = months.first().map_or(end_date, |(_, first, _)| *first);
+            let history = fetch_activities_between(client.as_ref(), history_start, end_date)
+                .await
+                .map_err(|error| {
+                    IntentError::api(format!("Failed to fetch progression activities: {error}"))
+                })?;
+            let mut month_curves = HashMap::new();
+            let mut failed_curves = 0;
+            let mut eftp_history = None;
+            let mut eftp_unavailable = false;
+            if kind.curve().is_some() {
+                // Curve anchors read a curve bounded to each month, matching the record book.
+                for (month, first, last) in months {
+                    let (curves, failed) =
+                        fetch_effort_curves(client.as_ref(), [kind], first, last).await;
+                    failed_curves += failed;
+                    month_curves.insert(month, curves);
+                }
+            } else {
+                // eFTP comes from one wellness read, not from per-activity details.
+                let days_back = ((end_date - history_start).num_days() + 1) as i32;
+                eftp_history = client.get_wellness(Some(days_back)).await.ok();
+                eftp_unavailable = eftp_history.is_none();
+            }
+            let points = monthly_progression(
+                &history,
+                eftp_history.as_ref(),
+                &month_curves,
+                kind,
+                progression_months,
+                end_date,
+            );
+            let change = progression_change_pct(&points, kind);
+            content.push(render_progression(kind, &points, change));
+            if failed_curves > 0 {
+                content.push(crate::intents::ContentBlock::markdown(format!(
+                    "Note: {failed_curves} monthly curve request(s) failed; those months show no best."
+                )));
+            }
+            if eftp_unavailable {
+                content.push(crate::intents::ContentBlock::markdown(
+                    "Note: wellness eFTP history unavailable; no month shows a best.",
+                ));
+            }
+        }
 
         Ok(IntentOutput::new(content)
             .with_suggestions(report.recommendations.clone())
@@ -177,7 +338,9 @@ On error: API or validation errors with descriptive messages."
 mod tests {
     use super::*;
     use crate::test_support::mock::MockIntervalsClient;
+    use chrono::Datelike;
     use intervals_icu_client::ActivitySummary;
+    use intervals_icu_client::domains::curve::CurveKind;
     use serde_json::json;
     use std::sync::Arc;
 
@@ -401,4 +564,186 @@ mod tests {
             "rendered output should contain actionable data-availability warnings; got: {rendered}"
         );
     }
+
+    #[tokio::test]
+    async fn execute_surfaces_injury_risk_channel_outside_tid_sample() {
+        let today = Utc::now().date_naive();
+        let mut activities = Vec::new();
+        let mut mock = MockIntervalsClient::builder().with_wellness(short_wellness());
+        for day in (0..CHANNEL_HISTORY_DAYS).step_by(2) {
+            let km = match day {
+                0 => 19.0,
+                8 => 16.0,
+                _ => 8.0,
+            };
+            let id = format!("run-{day}");
+            activities.push(ActivitySummary {
+                id: id.clone(),
+                start_date_local: format!("{}T07:00:00", today - Duration::days(day)),
+                ..Default::default()
+            });
+            mock = mock.with_activity_detail(
+                &id,
+                json!({"type": "Run", "distance": km * 1000.0, "icu_intensity": 75.0}),
+            );
+        }
+        let mock = mock.with_activities(activities);
+
+        let output = TrackProgressHandler::new()
+            .execute(
+                json!({"period_weeks": 4, "hypothesis_mode": true}),
+                Arc::new(mock),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let rendered = format!("{:?}", output.content);
+        assert!(rendered.contains("Injury Risk by Load Channel"));
+        assert!(rendered.contains("driven by Run impact"));
+        assert!(rendered.contains("Spike in long run distance: 16 → 19"));
+        assert!(
+            output
+                .suggestions
+                .iter()
+                .any(|s| s.contains("driven by run impact load"))
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_reports_episodes_and_masks_them_from_plateau() {
+        use intervals_icu_client::{Event, EventCategory};
+
+        let today = Utc::now().date_naive();
+        let wellness = json!(
+            (0..35)
+                .rev()
+                .map(|back| json!({
+                    "date": (today - Duration::days(back)).to_string(),
+                    "fitness": if (8..=12).contains(&back) { 52.0 } else { 58.0 },
+                }))
+                .collect::<Vec<_>>()
+        );
+        let sick_day = today - Duration::days(10);
+        let mock = MockIntervalsClient::builder()
+            .with_wellness(wellness)
+            .with_events(vec![Event {
+                id: None,
+                start_date_local: sick_day.to_string(),
+                name: "Chest cold".into(),
+                category: EventCategory::Sick,
+                description: None,
+                r#type: None,
+                end_date_local: None,
+            }])
+            .with_activities(vec![ActivitySummary {
+                id: "back".into(),
+                start_date_local: format!("{}T07:00:00", today - Duration::days(7)),
+                moving_time: Some(2400),
+                ..Default::default()
+            }]);
+
+        let output = TrackProgressHandler::new()
+            .execute(
+                json!({"period_weeks": 5, "hypothesis_mode": true}),
+                Arc::new(mock),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let rendered = format!("{:?}", output.content);
+        assert!(rendered.contains("Illness & Injury Episodes"));
+        assert!(rendered.contains("Chest cold"));
+        assert!(rendered.contains("3 days after"));
+        assert!(rendered.contains("CTL lost 6.0"));
+        assert!(rendered.contains("4 day(s) inside episode windows excluded"));
+    }
+
+    #[tokio::test]
+    async fn execute_adds_record_book_and_anchor_progression() {
+        let today = Utc::now().date_naive();
+        let mock = MockIntervalsClient::builder()
+            .with_wellness(json!([{"date": today.to_string(), "ctl": 50.0}]))
+            .with_activities(vec![ActivitySummary {
+                id: "run-1".into(),
+                start_date_local: format!("{today}T07:00:00"),
+                moving_time: Some(1500),
+                distance: Some(5000.0),
+                ..Default::default()
+            }])
+            .with_activity_detail("run-1", json!({"type": "Run"}))
+            .with_pace_curves(json!({"list": [{
+                "distance": [1000, 5000],
+                "values": [210, 1200],
+                "activity_id": ["run-0", "run-1"]
+            }]}))
+            .with_curves_between(
+                CurveKind::Pace,
+                "Run",
+                today.with_day(1).unwrap(),
+                json!({"list": [{
+                    "distance": [5000],
+                    "values": [1500],
+                    "activity_id": ["run-1"]
+                }]}),
+            );
+
+        let output = TrackProgressHandler::new()
+            .execute(
+                json!({
+                    "period_weeks": 4,
+                    "record_sports": ["Run"],
+                    "progression_anchor": "pace5k",
+                    "progression_months": 3
+                }),
+                Arc::new(mock),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let rendered = format!("{:?}", output.content);
+        assert!(rendered.contains("Personal Records"));
+        assert!(rendered.contains("20:00 (4:00 /km) [run-1]"));
+        assert!(rendered.contains("5k pace Progression"));
+        assert!(rendered.contains("5:00 /km"));
+    }
+
+    #[tokio::test]
+    async fn execute_charts_eftp_progression_from_wellness() {
+        let today = Utc::now().date_naive();
+        let mock = MockIntervalsClient::builder().with_wellness(json!([
+            {"date": today.to_string(), "ctl": 50.0, "eftp": 271.0}
+        ]));
+
+        let output = TrackProgressHandler::new()
+            .execute(
+                json!({"period_weeks": 4, "progression_anchor": "eftp", "progression_months": 3}),
+                Arc::new(mock),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let rendered = format!("{:?}", output.content);
+        assert!(rendered.contains("eFTP Progression"));
+        assert!(rendered.contains("271 W"), "{rendered}");
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_unknown_progression_anchor_and_sport() {
+        let handler = TrackProgressHandler::new();
+        for input in [
+            json!({"progression_anchor": "pace_mile"}),
+            json!({"record_sports": ["Swim"]}),
+            json!({"progression_anchor": "pace5k", "progression_months": 30}),
+        ] {
+            let error = handler
+                .execute(input, Arc::new(MockIntervalsClient::builder()), None)
+                .await
+                .unwrap_err();
+            assert!(matches!(error, IntentError::ValidationError(_)));
+        }
+    }
 }
@@ -0,0 +1,79 @@
+//! Short-lived cache of coach rosters used to authorize coach-scoped calls.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// How long a fetched roster is trusted before it is fetched again.
+pub const DEFAULT_ROSTER_TTL: Duration = Duration::from_secs(60);
+
+struct RosterEntry {
+    fetched_at: Instant,
+    athlete_ids: HashSet<String>,
+}
+
+/// Athlete ids on each coach's roster, keyed by the coach's athlete id.
+/// Shared across routers, so the per-request routers of HTTP mode reuse it.
+pub struct RosterCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, RosterEntry>>,
+}
+
+impl RosterCache {
+    pub fn new() -> Self {
+        Self::with_ttl(DEFAULT_ROSTER_TTL)
+    }
+
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether a fresh cached roster of `coach_id` lists `athlete_id`. A miss
+    /// means "unknown": the roster may have changed since it was cached.
+    pub fn contains(&self, coach_id: &str, athlete_id: &str) -> bool {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.get(coach_id).is_some_and(|entry| {
+            entry.fetched_at.elapsed() < self.ttl && entry.athlete_ids.contains(athlete_id)
+        })
+    }
+
+    /// Store `coach_id`'s roster, dropping expired rosters of other coaches.
+    pub fn insert(&self, coach_id: &str, athlete_ids: HashSet<String>) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.retain(|_, entry| entry.fetched_at.elapsed() < self.ttl);
+        entries.insert(
+            coach_id.to_string(),
+            RosterEntry {
+                fetched_at: Instant::now(),
+                athlete_ids,
+            },
+        );
+    }
+}
+
+impl Default for RosterCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rosters_expire_after_ttl() {
+        let cache = RosterCache::with_ttl(Duration::ZERO);
+        cache.insert("coach", HashSet::from(["i2".to_string()]));
+        assert!(!cache.contains("coach", "i2"));
+
+        let cache = RosterCache::new();
+        cache.insert("coach", HashSet::from(["i2".to_string()]));
+        assert!(cache.contains("coach", "i2"));
+        assert!(!cache.contains("coach", "i3"));
+        assert!(!cache.contains("other", "i2"));
+    }
+}
@@ -1,19 +1,25 @@
 use super::idempotency::IdempotencyMiddleware;
+use super::roster_cache::RosterCache;
 use super::types::{
     IntentError, IntentHandler, IntentOutput, ToolDefinition, standard_output_schema,
 };
 use crate::metrics;
 use intervals_icu_client::IntervalsClient;
 use serde_json::{Map, Value};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{debug, info};
 
+/// Optional argument on every athlete-scoped intent: the coached athlete to act
+/// on. Coach accounts may name any athlete on their roster.
+pub const ATHLETE_ID_ARG: &str = "athlete_id";
+
 #[derive(Clone)]
 pub struct IntentRouter {
     handlers: Arc<HashMap<String, Box<dyn IntentHandler>>>,
     idempotency: Arc<IdempotencyMiddleware>,
     client: Arc<dyn IntervalsClient>,
+    rosters: Arc<RosterCache>,
 }
 
 impl IntentRouter {
@@ -31,8 +37,18 @@ impl IntentRouter {
             handlers: Arc::new(map),
             idempotency,
             client,
+            rosters: Arc::new(RosterCache::new()),
         }
     }
+
+    /// Share a roster cache with other routers, e.g. the per-request routers
+    /// of HTTP mode.
+    #[must_use]
+    pub fn with_roster_cache(mut self, rosters: Arc<RosterCache>) -> Self {
+        self.rosters = rosters;
+        self
+    }
+
     pub async fn route(
         &self,
         name: &str,
@@ -52,30 +68,48 @@ impl IntentRouter {
             .get(name)
             .ok_or_else(|| IntentError::UnknownIntent(name.to_string()))?;
 
-        let result = if handler.requires_idempotency_token() {
-            if let Some(token) = handler.extract_idempotency_token(&input) {
+        let scope = self
+            .scoped_client(handler.as_ref(), &input, athlete_id)
+            .await;
+        let (client, coached_athlete) = match scope {
+            Ok(scope) => scope,
+            Err(error) => {
+                metrics::record_tool_call(name, false, start.elapsed().as_secs_f64());
+                return Err(error);
+            }
+        };
+        let target_athlete = coached_athlete.as_deref().or(athlete_id);
+
+        // Handlers that opt in to optional tokens (e.g. manage_profile writes) are
+        // deduplicated whenever a token is supplied. The fingerprint keeps
+        // `athlete_id`, so a token reused for another athlete is a conflict, not a replay.
+        let token = if handler.accepts_idempotency_token() {
+            handler.extract_idempotency_token(&input)
+        } else {
+            None
+        };
+        let result = match token {
+            Some(token) => {
                 let dry_run = input
                     .get("dry_run")
                     .and_then(Value::as_bool)
                     .unwrap_or(false);
 
                 if dry_run {
-                    handler.execute(input, self.client.clone(), None).await
+                    handler.execute(input, client, None).await
                 } else {
                     let request_fingerprint = fingerprint_request(name, &input);
                     self.idempotency
                         .execute_with_idempotency(&token, &request_fingerprint, || async {
-                            handler
-                                .execute(input.clone(), self.client.clone(), None)
-                                .await
+                            handler.execute(input.clone(), client.clone(), None).await
                         })
                         .await
                 }
-            } else {
+            }
+            None if handler.requires_idempotency_token() => {
                 Err(IntentError::validation("Idempotency token required"))
             }
-        } else {
-            handler.execute(input, self.client.clone(), None).await
+            None => handler.execute(input, client, None).await,
         };
 
         let duration = start.elapsed().as_secs_f64();
@@ -85,13 +119,14 @@ impl IntentRouter {
         metrics::record_tool_call(name, success, duration);
 
         // Track athlete activity for observability (no high-cardinality labels)
-        if let Some(aid) = athlete_id {
+        if let Some(aid) = target_athlete {
             metrics::record_athlete_activity(aid);
         }
 
         info!(
             tool = name,
-            athlete_id = athlete_id.unwrap_or("unknown"),
+            athlete_id = target_athlete.unwrap_or("unknown"),
+            coach_id = coached_athlete.as_ref().and(athlete_id),
             duration_secs = duration,
             "Tool call completed"
         );
@@ -99,12 +134,73 @@ impl IntentRouter {
         result
     }
 
+    /// The client for this call: the router's own client, or, when the input
+    /// names a coached athlete, a client re-scoped to that athlete after
+    /// checking the coach's roster. Also returns the coached athlete's id.
+    async fn scoped_client(
+        &self,
+        handler: &dyn IntentHandler,
+        input: &Value,
+        caller: Option<&str>,
+    ) -> Result<(Arc<dyn IntervalsClient>, Option<String>), IntentError> {
+        let Some(requested) = input.get(ATHLETE_ID_ARG).filter(|value| !value.is_null()) else {
+            return Ok((self.client.clone(), None));
+        };
+        let requested = requested
+            .as_str()
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .ok_or_else(|| IntentError::validation("athlete_id must be a non-empty string"))?;
+        if !handler.athlete_scoped() {
+            return Err(IntentError::validation(format!(
+                "{} covers the whole roster; omit athlete_id.",
+                handler.name()
+            )));
+        }
+        if caller == Some(requested) || self.client.athlete_id() == Some(requested) {
+            return Ok((self.client.clone(), None));
+        }
+
+        let coach = caller.or(self.client.athlete_id());
+        if !coach.is_some_and(|coach| self.rosters.contains(coach, requested)) {
+            let roster = self.client.list_coached_athletes().await.map_err(|error| {
+                IntentError::api(format!("Failed to fetch coached athletes: {error}"))
+            })?;
+            let athlete_ids = roster
+                .into_iter()
+                .map(|athlete| athlete.id)
+                .collect::<HashSet<_>>();
+            let on_roster = athlete_ids.contains(requested);
+            if let Some(coach) = coach {
+                self.rosters.insert(coach, athlete_ids);
+            }
+            if !on_roster {
+                return Err(IntentError::validation(format!(
+                    "Athlete '{requested}' is not on this account's roster. Use review_roster to list coached athletes."
+                )));
+            }
+        }
+        let client = self.client.for_athlete(requested).ok_or_else(|| {
+            IntentError::internal("This client cannot act on behalf of coached athletes")
+        })?;
+        debug!(
+            coach_id = caller.unwrap_or("unknown"),
+            athlete_id = requested,
+            "Scoped tool call to coached athlete"
+        );
+        Ok((client, Some(requested.to_string())))
+    }
+
     pub fn tool_definitions(&self) -> Vec<ToolDefinition> {
         let output_schema = standard_output_schema();
         self.handlers
             .values()
             .map(|h| {
-                ToolDefinition::new(h.name(), h.description(), h.input_schema())
+                let mut input_schema = h.input_schema();
+                if h.athlete_scoped() {
+                    add_athlete_id_arg(&mut input_schema);
+                }
+                ToolDefinition::new(h.name(), h.description(), input_schema)
                     .with_output_schema(output_schema.clone())
             })
             .collect()
@@ -119,6 +215,24 @@ impl IntentRouter {
     }
 }
 
+fn add_athlete_id_arg(schema: &mut Value) {
+    let Some(object) = schema.as_object_mut() else {
+        return;
+    };
+    if let Value::Object(properties) = object
+        .entry("properties")
+        .or_insert_with(|| Value::Object(Map::new()))
+    {
+        properties.insert(
+            ATHLETE_ID_ARG.to_string(),
+            serde_json::json!({
+                "type": "string",
+                "description": "Coach accounts only: run for this coached athlete (see review_roster). Defaults to the authenticated athlete."
+            }),
+        );
+    }
+}
+
 fn fingerprint_request(name: &str, input: &Value) -> String {
     fn canonicalize(value: &Value) -> Value {
         match value {
@@ -483,6 +597,109 @@ mod tests {
         CALL_COUNT.store(0, Ordering::SeqCst);
     }
 
+    #[tokio::test]
+    async fn router_optional_token_deduplicates_when_supplied() {
+        use crate::intents::ContentBlock;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static OPTIONAL_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        struct OptionalTokenHandler;
+
+        #[async_trait]
+        impl IntentHandler for OptionalTokenHandler {
+            fn name(&self) -> &'static str {
+                "optional_token"
+            }
+            fn description(&self) -> &'static str {
+                "Reads without a token, deduplicates writes with one"
+            }
+            fn input_schema(&self) -> serde_json::Value {
+                json!({})
+            }
+            fn accepts_idempotency_token(&self) -> bool {
+                true
+            }
+            async fn execute(
+                &self,
+                _input: Value,
+                _client: Arc<dyn IntervalsClient>,
+                _idempotency_cache: Option<&IdempotencyCache>,
+            ) -> Result<IntentOutput, IntentError> {
+                let call = OPTIONAL_CALLS.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(IntentOutput::new(vec![ContentBlock::text(format!(
+                    "Call {}",
+                    call
+                ))]))
+            }
+        }
+
+        let handlers = vec![Box::new(OptionalTokenHandler) as Box<dyn IntentHandler>];
+        let client = Arc::new(MockIntervalsClient::default());
+        let idempotency = Arc::new(IdempotencyMiddleware::new());
+        let router = IntentRouter::new(handlers, client, idempotency);
+
+        router
+            .route("optional_token", json!({}), None)
+            .await
+            .unwrap();
+        let input = json!({"action": "write", "idempotency_token": "write-once"});
+        router
+            .route("optional_token", input.clone(), None)
+            .await
+            .unwrap();
+        router.route("optional_token", input, None).await.unwrap();
+
+        assert_eq!(OPTIONAL_CALLS.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn router_ignores_tokens_for_handlers_without_opt_in() {
+        use crate::intents::ContentBlock;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static PLAIN_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        struct PlainHandler;
+
+        #[async_trait]
+        impl IntentHandler for PlainHandler {
+            fn name(&self) -> &'static str {
+                "plain"
+            }
+            fn description(&self) -> &'static str {
+                "Runs every call, token or not"
+            }
+            fn input_schema(&self) -> serde_json::Value {
+                json!({})
+            }
+            async fn execute(
+                &self,
+                _input: Value,
+                _client: Arc<dyn IntervalsClient>,
+                _idempotency_cache: Option<&IdempotencyCache>,
+            ) -> Result<IntentOutput, IntentError> {
+                let call = PLAIN_CALLS.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(IntentOutput::new(vec![ContentBlock::text(format!(
+                    "Call {}",
+                    call
+                ))]))
+            }
+        }
+
+        let handlers = vec![Box::new(PlainHandler) as Box<dyn IntentHandler>];
+        let client = Arc::new(MockIntervalsClient::default());
+        let idempotency = Arc::new(IdempotencyMiddleware::new());
+        let router = IntentRouter::new(handlers, client, idempotency);
+
+        let input = json!({"idempotency_token": "ignored"});
+        router.route("plain", input.clone(), None).await.unwrap();
+        let second = router.route("plain", input, None).await.unwrap();
+
+        assert_eq!(PLAIN_CALLS.load(Ordering::SeqCst), 2);
+        assert!(format!("{:?}", second.content).contains("Call 2"));
+    }
+
     #[test]
     fn router_tool_definitions_with_empty_handlers() {
         let handlers: Vec<Box<dyn IntentHandler>> = vec![];
@@ -1187,4 +1404,282 @@ mod tests {
         );
         TRY_COUNT.store(0, Ordering::SeqCst);
     }
+
+    // ------------------------------------------------------------------
+    // Coach mode — athlete_id argument
+    // ------------------------------------------------------------------
+
+    /// Reports which athlete the client it was given belongs to.
+    struct ProfileEchoHandler {
+        scoped: bool,
+    }
+
+    #[async_trait]
+    impl IntentHandler for ProfileEchoHandler {
+        fn name(&self) -> &'static str {
+            "profile_echo"
+        }
+        fn description(&self) -> &'static str {
+            "Echoes the athlete profile id"
+        }
+        fn input_schema(&self) -> serde_json::Value {
+            json!({"type": "object", "properties": {}})
+        }
+        fn athlete_scoped(&self) -> bool {
+            self.scoped
+        }
+        async fn execute(
+            &self,
+            _input: Value,
+            client: Arc<dyn IntervalsClient>,
+            _idempotency_cache: Option<&IdempotencyCache>,
+        ) -> Result<IntentOutput, IntentError> {
+            let profile = client
+                .get_athlete_profile()
+                .await
+                .map_err(|e| IntentError::api(e.to_string()))?;
+            Ok(IntentOutput::new(vec![ContentBlock::text(profile.id)]))
+        }
+    }
+
+    fn coach_router(scoped: bool) -> IntentRouter {
+        let athlete = MockIntervalsClient::builder().with_athlete_profile(
+            intervals_icu_client::AthleteProfile {
+                id: "i2".into(),
+                name: None,
+            },
+        );
+        let coach = MockIntervalsClient::builder()
+            .with_athlete_profile(intervals_icu_client::AthleteProfile {
+                id: "coach".into(),
+                name: None,
+            })
+            .with_coached_athlete("i2", "Athlete Two", athlete);
+        IntentRouter::new(
+            vec![Box::new(ProfileEchoHandler { scoped }) as Box<dyn IntentHandler>],
+            Arc::new(coach),
+            Arc::new(IdempotencyMiddleware::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn router_reuses_cached_roster_across_routers() {
+        let athlete = MockIntervalsClient::builder().with_athlete_profile(
+            intervals_icu_client::AthleteProfile {
+                id: "i2".into(),
+                name: None,
+            },
+        );
+        let coach = Arc::new(MockIntervalsClient::builder().with_coached_athlete(
+            "i2",
+            "Athlete Two",
+            athlete,
+        ));
+        let observations = coach.observations.clone();
+        let rosters = Arc::new(RosterCache::new());
+        let router = || {
+            IntentRouter::new(
+                vec![Box::new(ProfileEchoHandler { scoped: true }) as Box<dyn IntentHandler>],
+                coach.clone(),
+                Arc::new(IdempotencyMiddleware::new()),
+            )
+            .with_roster_cache(rosters.clone())
+        };
+
+        for _ in 0..2 {
+            router()
+                .route("profile_echo", json!({"athlete_id": "i2"}), Some("coach"))
+                .await
+                .unwrap();
+        }
+        assert_eq!(observations.coached_athlete_calls(), 1);
+
+        // Unknown athletes are re-checked upstream in case the roster changed.
+        assert!(
+            router()
+                .route("profile_echo", json!({"athlete_id": "i9"}), Some("coach"))
+                .await
+                .is_err()
+        );
+        assert_eq!(observations.coached_athlete_calls(), 2);
+    }
+
+    fn output_text(output: &IntentOutput) -> String {
+        format!("{:?}", output.content)
+    }
+
+    #[tokio::test]
+    async fn router_scopes_call_to_coached_athlete() {
+        let router = coach_router(true);
+
+        let own = router
+            .route("profile_echo", json!({}), Some("coach"))
+            .await
+            .unwrap();
+        assert!(output_text(&own).contains("\"coach\""));
+
+        let scoped = router
+            .route("profile_echo", json!({"athlete_id": "i2"}), Some("coach"))
+            .await
+            .unwrap();
+        assert!(output_text(&scoped).contains("\"i2\""));
+
+        let self_scoped = router
+            .route(
+                "profile_echo",
+                json!({"athlete_id": "coach"}),
+                Some("coach"),
+            )
+            .await
+            .unwrap();
+        assert!(output_text(&self_scoped).contains("\"coach\""));
+    }
+
+    #[tokio::test]
+    async fn router_rejects_athletes_outside_the_roster() {
+        let router = coach_router(true);
+
+        let result = router
+            .route("profile_echo", json!({"athlete_id": "i9"}), Some("coach"))
+            .await;
+        match result {
+            Err(IntentError::ValidationError(message)) => assert!(message.contains("roster")),
+            other => panic!("expected roster validation error, got {other:?}"),
+        }
+
+        let blank = router
+            .route("profile_echo", json!({"athlete_id": " "}), Some("coach"))
+            .await;
+        assert!(matches!(blank, Err(IntentError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn router_rejects_athlete_id_for_roster_wide_intents() {
+        let router = coach_router(false);
+
+        let result = router
+            .route("profile_echo", json!({"athlete_id": "i2"}), Some("coach"))
+            .await;
+        assert!(matches!(result, Err(IntentError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn router_lets_log_wellness_preview_without_token() {
+        let handlers = vec![
+            Box::new(crate::intents::handlers::LogWellnessHandler::new()) as Box<dyn IntentHandler>,
+        ];
+        let client = Arc::new(MockIntervalsClient::default());
+        let idempotency = Arc::new(IdempotencyMiddleware::new());
+        let router = IntentRouter::new(handlers, client, idempotency);
+
+        let preview = router
+            .route("log_wellness", json!({"mood": 2, "dry_run": true}), None)
+            .await;
+        assert!(preview.is_ok());
+
+        let save = router
+            .route("log_wellness", json!({"mood": 2}), None)
+            .await
+            .unwrap_err();
+        assert!(save.to_string().contains("idempotency_token"));
+    }
+
+    #[tokio::test]
+    async fn router_lets_annotate_activity_preview_without_token() {
+        let handlers = vec![
+            Box::new(crate::intents::handlers::AnnotateActivityHandler::new())
+                as Box<dyn IntentHandler>,
+        ];
+        let client = Arc::new(
+            MockIntervalsClient::builder().with_activity_detail("a1", json!({"name": "Run"})),
+        );
+        let idempotency = Arc::new(IdempotencyMiddleware::new());
+        let router = IntentRouter::new(handlers, client, idempotency);
+
+        let input = json!({"activity_id": "a1", "rpe": 6, "dry_run": true});
+        let preview = router.route("annotate_activity", input, None).await;
+        assert!(preview.is_ok());
+
+        let save = router
+            .route(
+                "annotate_activity",
+                json!({"activity_id": "a1", "rpe": 6}),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(save.to_string().contains("idempotency_token"));
+    }
+
+    #[tokio::test]
+    async fn router_lets_import_activity_dry_run_without_token() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("morning.gpx");
+        std::fs::write(
+            &path,
+            "<gpx><trk><trkseg><trkpt lat=\"1\" lon=\"2\">\
+             <time>2026-03-10T06:00:00Z</time></trkpt></trkseg></trk></gpx>",
+        )
+        .unwrap();
+        let path = path.to_string_lossy().into_owned();
+        let handlers = vec![
+            Box::new(crate::intents::handlers::ImportActivityHandler::new())
+                as Box<dyn IntentHandler>,
+        ];
+        let client = Arc::new(MockIntervalsClient::default());
+        let idempotency = Arc::new(IdempotencyMiddleware::new());
+        let router = IntentRouter::new(handlers, client, idempotency);
+
+        let preview = router
+            .route(
+                "import_activity",
+                json!({"path": path, "dry_run": true}),
+                None,
+            )
+            .await;
+        assert!(preview.is_ok());
+
+        let upload = router
+            .route("import_activity", json!({"path": path}), None)
+            .await
+            .unwrap_err();
+        assert!(upload.to_string().contains("idempotency_token"));
+    }
+
+    #[tokio::test]
+    async fn router_deduplicates_manage_gear_writes() {
+        let handlers =
+            vec![Box::new(crate::intents::handlers::ManageGearHandler::new())
+                as Box<dyn IntentHandler>];
+        let client = Arc::new(MockIntervalsClient::builder().with_gear_list(json!([
+            {"id": "g1", "name": "Daily Trainer", "type": "Shoes",
+             "reminders": [{"id": 11, "name": "Replace", "distance": 600000.0}]}
+        ])));
+        let idempotency = Arc::new(IdempotencyMiddleware::new());
+        let router = IntentRouter::new(handlers, client, idempotency);
+
+        let reset = json!({
+            "action": "reset_reminder",
+            "gear_name": "Daily Trainer",
+            "idempotency_token": "gear-reset"
+        });
+        assert!(router.route("manage_gear", reset, None).await.is_ok());
+
+        let snooze = json!({
+            "action": "snooze_reminder",
+            "gear_name": "Daily Trainer",
+            "idempotency_token": "gear-reset"
+        });
+        let conflict = router.route("manage_gear", snooze, None).await;
+        assert!(matches!(conflict, Err(IntentError::IdempotencyConflict(_))));
+    }
+
+    #[test]
+    fn tool_definitions_advertise_athlete_id_only_on_scoped_intents() {
+        let scoped = coach_router(true).tool_definitions();
+        assert!(scoped[0].input_schema["properties"]["athlete_id"].is_object());
+
+        let roster_wide = coach_router(false).tool_definitions();
+        assert!(roster_wide[0].input_schema["properties"]["athlete_id"].is_null());
+    }
 }
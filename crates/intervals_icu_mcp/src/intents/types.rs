@@ -224,6 +224,16 @@ pub trait IntentHandler: Send + Sync {
     fn requires_idempotency_token(&self) -> bool {
         false
     }
+    /// Whether a supplied `idempotency_token` deduplicates the call. Handlers
+    /// whose token is optional (e.g. read and write actions in one intent) opt in.
+    fn accepts_idempotency_token(&self) -> bool {
+        self.requires_idempotency_token()
+    }
+    /// Whether the intent accepts the coach-mode `athlete_id` argument. Roster-wide
+    /// intents opt out.
+    fn athlete_scoped(&self) -> bool {
+        true
+    }
     fn extract_idempotency_token(&self, input: &Value) -> Option<String> {
         input
             .get("idempotency_token")
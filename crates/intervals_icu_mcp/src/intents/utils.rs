@@ -655,6 +655,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         }
     }
 
@@ -16,15 +16,19 @@ use tokio::sync::Mutex;
 use crate::auth::{DecryptedCredentials, HttpBaseUrl};
 
 use crate::intents::handlers::{
-    AnalyzeRaceHandler, AnalyzeTrainingHandler, AssessRecoveryHandler, ComparePeriodsHandler,
-    ManageGearHandler, ManageProfileHandler, ModifyTrainingHandler, PlanTrainingHandler,
-    TrackProgressHandler,
+    AnalyzeRaceHandler, AnalyzeTrainingHandler, AnnotateActivityHandler, AssessRecoveryHandler,
+    ComparePeriodsHandler, ImportActivityHandler, LogWellnessHandler, ManageGearHandler,
+    ManageProfileHandler, ModifyTrainingHandler, PlanTrainingHandler, ReviewRosterHandler,
+    ReviewSeasonHandler, TrackProgressHandler,
 };
 use crate::intents::{
     IdempotencyMiddleware, IntentRouter, intent_error_to_error_data,
     intent_output_to_call_tool_result,
 };
 use intervals_icu_client::IntervalsClient;
+use intervals_icu_client::config::{Config, HttpSettings};
+use intervals_icu_client::http_client::ReqwestIntervalsClient;
+use intervals_icu_client::rate_limit::RateLimiter;
 
 pub mod auth;
 pub mod auth_ui;
@@ -40,6 +44,7 @@ mod state;
 #[cfg(test)]
 mod test_support;
 pub mod types;
+pub mod uploads;
 
 pub use event_id::{EventId, FolderId};
 pub use state::{DownloadState, DownloadStatus, WebhookEvent};
@@ -56,6 +61,11 @@ fn all_intent_handlers() -> Vec<Box<dyn intents::IntentHandler>> {
         Box::new(ManageGearHandler::new()) as Box<dyn intents::IntentHandler>,
         Box::new(AnalyzeRaceHandler::new()) as Box<dyn intents::IntentHandler>,
         Box::new(TrackProgressHandler::new()) as Box<dyn intents::IntentHandler>,
+        Box::new(LogWellnessHandler::new()) as Box<dyn intents::IntentHandler>,
+        Box::new(AnnotateActivityHandler::new()) as Box<dyn intents::IntentHandler>,
+        Box::new(ReviewSeasonHandler::new()) as Box<dyn intents::IntentHandler>,
+        Box::new(ReviewRosterHandler::new()) as Box<dyn intents::IntentHandler>,
+        Box::new(ImportActivityHandler::new()) as Box<dyn intents::IntentHandler>,
     ]
 }
 
@@ -81,13 +91,11 @@ impl IntervalsMcpHandler {
     pub fn new_multi_tenant() -> Result<Self, Box<dyn std::error::Error>> {
         // Create a minimal placeholder client - it won't be used in multi-tenant mode
         // because we create per-request clients from JWT credentials
-        let placeholder_client = Arc::new(
-            intervals_icu_client::http_client::ReqwestIntervalsClient::new(
-                "https://intervals.icu",
-                "placeholder".to_string(),
-                SecretString::new("placeholder".into()),
-            )?,
-        );
+        let placeholder_client = Arc::new(upstream_client(
+            "https://intervals.icu",
+            "placeholder".to_string(),
+            SecretString::new("placeholder".into()),
+        )?);
         Ok(Self::with_dynamic_runtime(
             placeholder_client,
             dynamic::DynamicRuntime::from_env(),
@@ -118,7 +126,7 @@ impl IntervalsMcpHandler {
 
     #[must_use]
     pub fn tool_count(&self) -> usize {
-        // Return only intent tool count (8 high-level business intents)
+        // Return only intent tool count (14 high-level business intents)
         // Dynamic OpenAPI tools are internal-only and NOT exposed to LLM host
         self.intent_router.tool_definitions().len()
     }
@@ -191,12 +199,7 @@ impl IntervalsMcpHandler {
             .unwrap_or_else(|| "https://intervals.icu".to_string());
 
         Some(Arc::new(
-            intervals_icu_client::http_client::ReqwestIntervalsClient::new(
-                &base_url,
-                credentials.athlete_id,
-                credentials.api_key,
-            )
-            .ok()?,
+            upstream_client(&base_url, credentials.athlete_id, credentials.credentials).ok()?,
         ) as Arc<dyn IntervalsClient>)
     }
 }
@@ -221,7 +224,7 @@ impl ServerHandler for IntervalsMcpHandler {
         )
         .with_instructions(
             "Intervals.icu MCP server with intent-driven architecture. \
-                 Provides 8 high-level intents for training planning, analysis, and management. \
+                 Provides 14 high-level intents for training planning, analysis, and management. \
                  Dynamic OpenAPI tools are available for advanced usage.",
         )
     }
@@ -232,7 +235,7 @@ impl ServerHandler for IntervalsMcpHandler {
         _context: RequestContext<RoleServer>,
     ) -> Result<ListToolsResult, ErrorData> {
         metrics::record_mcp_method_call("tools/list");
-        // Return only intent tools (8 high-level business intents)
+        // Return only intent tools (14 high-level business intents)
         // Dynamic OpenAPI tools are internal-only and NOT exposed to LLM host
         let intent_tools = self.intent_router.tool_definitions();
         let mut all_tools = Vec::with_capacity(intent_tools.len());
@@ -291,7 +294,10 @@ impl ServerHandler for IntervalsMcpHandler {
                 // Create temporary router with per-request client
                 let idempotency = Arc::new(intents::IdempotencyMiddleware::new());
                 let handlers = all_intent_handlers();
-                let router = Arc::new(intents::IntentRouter::new(handlers, client, idempotency));
+                let router = Arc::new(
+                    intents::IntentRouter::new(handlers, client, idempotency)
+                        .with_roster_cache(Arc::clone(shared_roster_cache())),
+                );
 
                 match router
                     .route(
@@ -408,10 +414,63 @@ pub fn init_tracing() {
         .init();
 }
 
+/// Upstream HTTP settings for this deployment, read once from the
+/// `INTERVALS_ICU_*` transport variables (see [`HttpSettings::from_env_with`]).
+/// Malformed values fall back to the defaults with a warning.
+fn upstream_http_settings() -> &'static HttpSettings {
+    static SETTINGS: std::sync::OnceLock<HttpSettings> = std::sync::OnceLock::new();
+    SETTINGS.get_or_init(|| {
+        HttpSettings::from_env_with(|key| std::env::var(key).ok()).unwrap_or_else(|error| {
+            tracing::warn!("ignoring upstream HTTP settings: {error}");
+            HttpSettings::default()
+        })
+    })
+}
+
+/// Coach rosters shared by the per-request routers of HTTP mode, so
+/// coach-scoped calls do not refetch the roster on every tool call.
+fn shared_roster_cache() -> &'static Arc<intents::RosterCache> {
+    static ROSTERS: std::sync::OnceLock<Arc<intents::RosterCache>> = std::sync::OnceLock::new();
+    ROSTERS.get_or_init(|| Arc::new(intents::RosterCache::new()))
+}
+
+/// Upstream rate limiter shared by every tenant's client, so one athlete's
+/// heavy analysis cannot use up the deployment's Intervals.icu quota.
+fn upstream_rate_limiter() -> Option<&'static Arc<RateLimiter>> {
+    static LIMITER: std::sync::OnceLock<Option<Arc<RateLimiter>>> = std::sync::OnceLock::new();
+    LIMITER
+        .get_or_init(|| {
+            upstream_http_settings()
+                .rate_limit
+                .map(|limits| Arc::new(RateLimiter::new(limits)))
+        })
+        .as_ref()
+}
+
+/// Build an Intervals.icu client with the deployment's upstream HTTP settings
+/// and the shared upstream rate limiter.
+///
+/// No `TokenRefresher` is attached: JWTs carry no refresh token, and `/auth`
+/// caps their lifetime at the access token's `expires_in` instead.
+///
+/// # Errors
+///
+/// Returns a configuration error when the proxy or a root certificate is invalid.
+pub fn upstream_client(
+    base_url: &str,
+    athlete_id: impl Into<String>,
+    credentials: impl Into<intervals_icu_client::Credentials>,
+) -> intervals_icu_client::Result<ReqwestIntervalsClient> {
+    let mut builder = ReqwestIntervalsClient::builder(base_url, athlete_id, credentials)
+        .settings(upstream_http_settings().clone());
+    if let Some(limiter) = upstream_rate_limiter() {
+        builder = builder.rate_limiter(Arc::clone(limiter));
+    }
+    builder.build()
+}
+
 /// STDIO mode: initialize with credentials from env vars.
 pub async fn initialize_handler_single_user() -> Result<IntervalsMcpHandler, String> {
-    let base = std::env::var("INTERVALS_ICU_BASE_URL")
-        .unwrap_or_else(|_| "https://intervals.icu".to_string());
     let athlete = std::env::var("INTERVALS_ICU_ATHLETE_ID")
         .map_err(|_| "INTERVALS_ICU_ATHLETE_ID is required for STDIO mode")?;
     let api_key = std::env::var("INTERVALS_ICU_API_KEY")
@@ -423,9 +482,12 @@ pub async fn initialize_handler_single_user() -> Result<IntervalsMcpHandler, Str
 
     tracing::info!(athlete_id = %athlete, "credentials validated");
 
-    let api_key = secrecy::SecretString::new(api_key.into());
+    let config = Config::from_env_with(|key| std::env::var(key).ok())
+        .map_err(|e| format!("invalid configuration: {e}"))?;
     let client =
-        intervals_icu_client::http_client::ReqwestIntervalsClient::new(&base, athlete, api_key)
+        ReqwestIntervalsClient::builder(&config.base_url, config.athlete_id, config.api_key)
+            .settings(config.http)
+            .build()
             .map_err(|e| format!("failed to create client: {e}"))?;
     let handler = IntervalsMcpHandler::new(std::sync::Arc::new(client));
 
@@ -489,6 +551,9 @@ pub async fn run_http_server(
         jwt_manager: jwt_manager.clone(),
         jwt_ttl_seconds,
         base_url: base_url.clone(),
+        allow_api_keys: parse_allow_api_keys(
+            std::env::var("MCP_AUTH_ALLOW_API_KEYS").ok().as_deref(),
+        ),
     });
 
     let handler = IntervalsMcpHandler::new_multi_tenant().expect("new_multi_tenant");
@@ -717,6 +782,17 @@ impl tower_governor::key_extractor::KeyExtractor for AthleteKeyExtractor {
     }
 }
 
+/// Parse `MCP_AUTH_ALLOW_API_KEYS`; API keys stay accepted unless it is
+/// `false`, `0`, `no` or `off`.
+fn parse_allow_api_keys(value: Option<&str>) -> bool {
+    !value.is_some_and(|value| {
+        matches!(
+            value.trim().to_ascii_lowercase().as_str(),
+            "false" | "0" | "no" | "off"
+        )
+    })
+}
+
 /// Parse rate limit config from optional string values.
 /// Returns `(per_second, burst_size)` with defaults of 5 and 15.
 fn parse_rate_limit_values(per_second: Option<&str>, burst_size: Option<&str>) -> (u64, u32) {
@@ -792,7 +868,7 @@ mod tests {
     #[tokio::test]
     async fn handler_registers_tools() {
         let handler = test_handler();
-        assert_eq!(handler.tool_count(), 9);
+        assert_eq!(handler.tool_count(), 14);
     }
 
     #[test]
@@ -814,7 +890,7 @@ mod tests {
     fn tool_count_matches_internal_tools_without_cache() {
         let handler = test_handler();
         // tool_count() includes 8 intent tools even before dynamic registry load
-        assert_eq!(handler.tool_count(), 9);
+        assert_eq!(handler.tool_count(), 14);
     }
 
     #[tokio::test]
@@ -872,7 +948,7 @@ mod tests {
 
         parts.extensions.insert(DecryptedCredentials {
             athlete_id: "i123456".to_string(),
-            api_key: SecretString::new("per-request-key".to_string().into()),
+            credentials: SecretString::new("per-request-key".to_string().into()).into(),
         });
         parts
             .extensions
@@ -885,7 +961,10 @@ mod tests {
             IntervalsMcpHandler::request_base_url(&extensions).expect("base url should exist");
 
         assert_eq!(credentials.athlete_id, "i123456");
-        assert_eq!(credentials.api_key.expose_secret(), "per-request-key");
+        assert_eq!(
+            credentials.credentials.secret().expose_secret(),
+            "per-request-key"
+        );
         assert_eq!(base_url, "http://mock.local");
     }
 
@@ -913,7 +992,7 @@ mod tests {
     #[test]
     fn new_multi_tenant_creates_placeholder_client() {
         let handler = IntervalsMcpHandler::new_multi_tenant().expect("new_multi_tenant");
-        assert_eq!(handler.tool_count(), 9);
+        assert_eq!(handler.tool_count(), 14);
     }
 
     #[tokio::test]
@@ -1007,7 +1086,7 @@ mod tests {
 
         parts.extensions.insert(DecryptedCredentials {
             athlete_id: "i123456".to_string(),
-            api_key: SecretString::new("test-key".to_string().into()),
+            credentials: SecretString::new("test-key".to_string().into()).into(),
         });
         parts
             .extensions
@@ -1029,7 +1108,7 @@ mod tests {
 
         parts.extensions.insert(DecryptedCredentials {
             athlete_id: "i123456".to_string(),
-            api_key: SecretString::new("test-key".to_string().into()),
+            credentials: SecretString::new("test-key".to_string().into()).into(),
         });
         // No HttpBaseUrl inserted
         extensions.insert(parts);
@@ -1048,7 +1127,7 @@ mod tests {
         // Note: Full list_tools testing requires RequestContext which is complex to construct.
         // Integration tests in tests/ directory cover the full flow.
         // Here we just verify the handler has the right tool count.
-        assert_eq!(handler.tool_count(), 9);
+        assert_eq!(handler.tool_count(), 14);
     }
 
     // ========================================================================
@@ -1172,7 +1251,7 @@ mod tests {
             .unwrap();
         req.extensions_mut().insert(DecryptedCredentials {
             athlete_id: "athlete-42".to_string(),
-            api_key: SecretString::new("secret-key".to_string().into()),
+            credentials: SecretString::new("secret-key".to_string().into()).into(),
         });
 
         let key = AthleteKeyExtractor.extract(&req).unwrap();
@@ -1219,7 +1298,7 @@ mod tests {
             .unwrap();
         req.extensions_mut().insert(DecryptedCredentials {
             athlete_id: "athlete-99".to_string(),
-            api_key: SecretString::new("key".to_string().into()),
+            credentials: SecretString::new("key".to_string().into()).into(),
         });
         req.extensions_mut()
             .insert(axum::extract::ConnectInfo(addr));
@@ -1275,6 +1354,16 @@ mod tests {
         assert_eq!(burst, 15);
     }
 
+    #[test]
+    fn parse_allow_api_keys_defaults_to_allowed() {
+        assert!(parse_allow_api_keys(None));
+        assert!(parse_allow_api_keys(Some("true")));
+        assert!(parse_allow_api_keys(Some("unexpected")));
+        assert!(!parse_allow_api_keys(Some("false")));
+        assert!(!parse_allow_api_keys(Some(" OFF ")));
+        assert!(!parse_allow_api_keys(Some("0")));
+    }
+
     // ── TCP keepalive tests ────────────────────────────────────────────
 
     #[test]
@@ -111,13 +111,17 @@ fn clone_intervals_error(
 #[cfg(test)]
 pub(crate) mod mock {
     use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use intervals_icu_client::domains::activity::ActivityUploadResult;
+    use intervals_icu_client::domains::athlete::CoachedAthlete;
+    use intervals_icu_client::domains::curve::CurveKind;
     use intervals_icu_client::domains::workout::{Folder, SportSettings, WorkoutItem};
     use intervals_icu_client::{
-        ActivityMessage, ActivitySummary, AthleteProfile, BestEffortsOptions, DownloadProgress,
-        Event, EventCategory, IntervalsClient, IntervalsError,
+        ActivityMessage, ActivitySummary, ActivityUpload, AthleteProfile, BestEffortsOptions,
+        DownloadProgress, Event, EventCategory, IntervalsClient, IntervalsError,
     };
     use serde_json::{Value, json};
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     use std::sync::Arc;
     use std::sync::Mutex;
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -138,6 +142,14 @@ pub(crate) mod mock {
     pub(crate) struct MockObservations {
         pub wellness_last_days_back: Mutex<Option<i32>>,
         pub wellness_calls: AtomicUsize,
+        pub uploads: Mutex<Vec<ActivityUpload>>,
+        /// `(date, payload)` for each `update_wellness` call.
+        pub wellness_updates: Mutex<Vec<(String, Value)>>,
+        /// Records sent by each `update_wellness_bulk` call.
+        pub wellness_bulk_updates: Mutex<Vec<Vec<Value>>>,
+        pub coached_athlete_calls: AtomicUsize,
+        pub recent_activity_calls: AtomicUsize,
+        pub activity_detail_calls: AtomicUsize,
     }
 
     impl MockObservations {
@@ -145,6 +157,36 @@ pub(crate) mod mock {
             self.wellness_calls.load(Ordering::SeqCst)
         }
 
+        pub fn coached_athlete_calls(&self) -> usize {
+            self.coached_athlete_calls.load(Ordering::SeqCst)
+        }
+
+        pub fn recent_activity_calls(&self) -> usize {
+            self.recent_activity_calls.load(Ordering::SeqCst)
+        }
+
+        pub fn activity_detail_calls(&self) -> usize {
+            self.activity_detail_calls.load(Ordering::SeqCst)
+        }
+
+        pub fn uploads(&self) -> Vec<ActivityUpload> {
+            self.uploads.lock().expect("uploads mutex poisoned").clone()
+        }
+
+        pub fn wellness_updates(&self) -> Vec<(String, Value)> {
+            self.wellness_updates
+                .lock()
+                .expect("wellness_updates mutex poisoned")
+                .clone()
+        }
+
+        pub fn wellness_bulk_updates(&self) -> Vec<Vec<Value>> {
+            self.wellness_bulk_updates
+                .lock()
+                .expect("wellness_bulk_updates mutex poisoned")
+                .clone()
+        }
+
         pub fn wellness_last_days_back(&self) -> Option<i32> {
             *self
                 .wellness_last_days_back
@@ -160,6 +202,7 @@ pub(crate) mod mock {
         pub fitness_summary: Option<Value>,
         pub workout_detail: Option<Value>,
         pub streams: Option<Value>,
+        pub fit_file: Option<Vec<u8>>,
         pub intervals: Option<Value>,
         pub best_efforts: Option<Value>,
         pub hr_histogram: Option<Value>,
@@ -168,13 +211,24 @@ pub(crate) mod mock {
         pub activity_messages: Vec<ActivityMessage>,
         pub wellness: Option<Value>,
         pub activity_details: HashMap<String, Value>,
+        /// Activity ids whose `get_activity_details` call fails.
+        pub failing_details: HashSet<String>,
         pub athlete_profile: Option<AthleteProfile>,
         pub sport_settings: Option<SportSettings>,
         pub gear_list: Option<Value>,
+        pub power_curves: Option<Value>,
+        pub pace_curves: Option<Value>,
+        /// Curves served by `get_curves_between`, keyed by kind, sport and window start.
+        pub windowed_curves: HashMap<(CurveKind, String, NaiveDate), Value>,
+        pub route: Option<Value>,
         pub update_error: Option<String>,
         pub upcoming_workouts: Option<Value>,
         pub upcoming_workouts_error: Option<IntervalsError>,
         pub upcoming_workouts_calls: Arc<AtomicUsize>,
+        pub coached_athletes: Vec<CoachedAthlete>,
+        /// Reported by `athlete_id`; `None` like a client with no fixed athlete.
+        pub athlete_id: Option<String>,
+        pub athlete_clients: HashMap<String, Arc<MockIntervalsClient>>,
         /// Observations shared with the test. `Arc` so the test can keep its own
         /// reference after the mock is wrapped in a trait object.
         pub observations: Arc<MockObservations>,
@@ -259,11 +313,27 @@ pub(crate) mod mock {
             self
         }
 
+        /// Raw FIT bytes returned (base64-encoded) by `download_fit_file`.
+        pub fn with_fit_file(mut self, bytes: Vec<u8>) -> Self {
+            self.fit_file = Some(bytes);
+            self
+        }
+
         pub fn with_activity_detail(mut self, id: &str, detail: Value) -> Self {
             self.activity_details.insert(id.to_string(), detail);
             self
         }
 
+        pub fn with_activity_detail_error(mut self, id: &str) -> Self {
+            self.failing_details.insert(id.to_string());
+            self
+        }
+
+        pub fn with_athlete_id(mut self, athlete_id: &str) -> Self {
+            self.athlete_id = Some(athlete_id.to_string());
+            self
+        }
+
         pub fn with_athlete_profile(mut self, profile: AthleteProfile) -> Self {
             self.athlete_profile = Some(profile);
             self
@@ -279,6 +349,33 @@ pub(crate) mod mock {
             self
         }
 
+        pub fn with_power_curves(mut self, curves: Value) -> Self {
+            self.power_curves = Some(curves);
+            self
+        }
+
+        pub fn with_pace_curves(mut self, curves: Value) -> Self {
+            self.pace_curves = Some(curves);
+            self
+        }
+
+        pub fn with_curves_between(
+            mut self,
+            kind: CurveKind,
+            sport: &str,
+            oldest: NaiveDate,
+            curves: Value,
+        ) -> Self {
+            self.windowed_curves
+                .insert((kind, sport.to_string(), oldest), curves);
+            self
+        }
+
+        pub fn with_route(mut self, route: Value) -> Self {
+            self.route = Some(route);
+            self
+        }
+
         pub fn with_update_error(mut self, error: impl Into<String>) -> Self {
             self.update_error = Some(error.into());
             self
@@ -304,6 +401,24 @@ pub(crate) mod mock {
         pub fn upcoming_workouts_call_count(&self) -> usize {
             self.upcoming_workouts_calls.load(Ordering::SeqCst)
         }
+
+        /// Adds an athlete to the coach roster; `client` serves that athlete's
+        /// data through `for_athlete`.
+        pub fn with_coached_athlete(
+            mut self,
+            id: &str,
+            name: &str,
+            client: MockIntervalsClient,
+        ) -> Self {
+            self.coached_athletes.push(CoachedAthlete {
+                id: id.to_string(),
+                name: Some(name.to_string()),
+                ..Default::default()
+            });
+            self.athlete_clients
+                .insert(id.to_string(), Arc::new(client));
+            self
+        }
     }
 
     #[async_trait]
@@ -318,11 +433,31 @@ pub(crate) mod mock {
                 }))
         }
 
+        async fn list_coached_athletes(&self) -> Result<Vec<CoachedAthlete>, IntervalsError> {
+            self.observations
+                .coached_athlete_calls
+                .fetch_add(1, Ordering::SeqCst);
+            Ok(self.coached_athletes.clone())
+        }
+
+        fn athlete_id(&self) -> Option<&str> {
+            self.athlete_id.as_deref()
+        }
+
+        fn for_athlete(&self, athlete_id: &str) -> Option<Arc<dyn IntervalsClient>> {
+            self.athlete_clients
+                .get(athlete_id)
+                .map(|client| client.clone() as Arc<dyn IntervalsClient>)
+        }
+
         async fn get_recent_activities(
             &self,
             _limit: Option<u32>,
             _days_back: Option<i32>,
         ) -> Result<Vec<ActivitySummary>, IntervalsError> {
+            self.observations
+                .recent_activity_calls
+                .fetch_add(1, Ordering::SeqCst);
             Ok(self.activities.clone())
         }
 
@@ -333,6 +468,15 @@ pub(crate) mod mock {
         }
 
         async fn get_activity_details(&self, activity_id: &str) -> Result<Value, IntervalsError> {
+            self.observations
+                .activity_detail_calls
+                .fetch_add(1, Ordering::SeqCst);
+            if self.failing_details.contains(activity_id) {
+                return Err(IntervalsError::from_status(
+                    500,
+                    "activity detail unavailable",
+                ));
+            }
             Ok(self
                 .activity_details
                 .get(activity_id)
@@ -383,6 +527,14 @@ pub(crate) mod mock {
             Ok(self.activity_messages.clone())
         }
 
+        async fn add_activity_message(
+            &self,
+            _activity_id: &str,
+            content: &str,
+        ) -> Result<Value, IntervalsError> {
+            Ok(json!({"id": 1, "content": content}))
+        }
+
         async fn get_events(
             &self,
             _days_back: Option<i32>,
@@ -407,6 +559,7 @@ pub(crate) mod mock {
                 category: EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             })
         }
 
@@ -474,9 +627,14 @@ pub(crate) mod mock {
         async fn download_fit_file(
             &self,
             _activity_id: &str,
-            _output_path: Option<std::path::PathBuf>,
+            output_path: Option<std::path::PathBuf>,
         ) -> Result<Option<String>, IntervalsError> {
-            Ok(None)
+            use base64::Engine as _;
+            Ok(self
+                .fit_file
+                .as_ref()
+                .filter(|_| output_path.is_none())
+                .map(|bytes| base64::engine::general_purpose::STANDARD.encode(bytes)))
         }
 
         async fn download_gpx_file(
@@ -487,6 +645,22 @@ pub(crate) mod mock {
             Ok(None)
         }
 
+        async fn upload_activity_file(
+            &self,
+            upload: &ActivityUpload,
+        ) -> Result<ActivityUploadResult, IntervalsError> {
+            let mut uploads = self
+                .observations
+                .uploads
+                .lock()
+                .expect("uploads mutex poisoned");
+            uploads.push(upload.clone());
+            Ok(serde_json::from_value(json!({
+                "id": format!("i{}", 9000 + uploads.len()),
+                "activities": [{"id": format!("i{}", 9000 + uploads.len())}]
+            }))?)
+        }
+
         async fn get_gear_list(&self) -> Result<Value, IntervalsError> {
             Ok(self.gear_list.clone().unwrap_or_else(|| json!([])))
         }
@@ -500,7 +674,7 @@ pub(crate) mod mock {
             _days_back: Option<i32>,
             _sport: &str,
         ) -> Result<Value, IntervalsError> {
-            Ok(json!([]))
+            Ok(self.power_curves.clone().unwrap_or_else(|| json!([])))
         }
 
         async fn get_gap_histogram(&self, _activity_id: &str) -> Result<Value, IntervalsError> {
@@ -546,11 +720,12 @@ pub(crate) mod mock {
             Ok(self.wellness.clone().unwrap_or_else(|| json!([])))
         }
 
-        async fn update_wellness(
-            &self,
-            _date: &str,
-            _data: &Value,
-        ) -> Result<Value, IntervalsError> {
+        async fn update_wellness(&self, date: &str, data: &Value) -> Result<Value, IntervalsError> {
+            self.observations
+                .wellness_updates
+                .lock()
+                .expect("wellness_updates mutex poisoned")
+                .push((date.to_string(), data.clone()));
             Ok(json!({}))
         }
 
@@ -606,7 +781,21 @@ pub(crate) mod mock {
             _days_back: Option<i32>,
             _sport: &str,
         ) -> Result<Value, IntervalsError> {
-            Ok(json!([]))
+            Ok(self.pace_curves.clone().unwrap_or_else(|| json!([])))
+        }
+
+        async fn get_curves_between(
+            &self,
+            kind: CurveKind,
+            sport: &str,
+            oldest: NaiveDate,
+            _newest: NaiveDate,
+        ) -> Result<Value, IntervalsError> {
+            Ok(self
+                .windowed_curves
+                .get(&(kind, sport.to_string(), oldest))
+                .cloned()
+                .unwrap_or_else(|| json!([])))
         }
 
         async fn get_workout_library(&self) -> Result<Vec<WorkoutItem>, IntervalsError> {
@@ -698,7 +887,12 @@ pub(crate) mod mock {
             Ok(())
         }
 
-        async fn update_wellness_bulk(&self, _entries: &[Value]) -> Result<(), IntervalsError> {
+        async fn update_wellness_bulk(&self, entries: &[Value]) -> Result<(), IntervalsError> {
+            self.observations
+                .wellness_bulk_updates
+                .lock()
+                .expect("wellness_bulk_updates mutex poisoned")
+                .push(entries.to_vec());
             Ok(())
         }
 
@@ -719,7 +913,7 @@ pub(crate) mod mock {
             _route_id: i64,
             _include_path: bool,
         ) -> Result<Value, IntervalsError> {
-            Ok(json!({}))
+            Ok(self.route.clone().unwrap_or_else(|| json!({})))
         }
 
         async fn update_route(
@@ -955,6 +1149,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         };
         let client = MockIntervalsClient::builder().with_events(vec![e]);
         assert_eq!(client.events.len(), 1);
@@ -1171,6 +1366,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         };
         let client = MockIntervalsClient::builder().with_events(vec![e]);
         let result = client.get_events(None, None).await.unwrap();
@@ -1265,6 +1461,7 @@ mod tests {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         };
         let result = client.create_event(e).await.unwrap();
         assert_eq!(result.id, Some("test".into()));
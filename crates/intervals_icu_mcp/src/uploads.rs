@@ -0,0 +1,102 @@
+//! Local file access policy for activity uploads.
+//!
+//! Upload tools read files from the machine running the server. In STDIO mode
+//! that is the user's own machine; in HTTP mode it is shared, so path imports
+//! are refused unless `MCP_UPLOAD_DIR` confines them to one directory.
+
+use std::path::{Path, PathBuf};
+
+/// Directory uploads are confined to; relative paths resolve against it.
+pub const UPLOAD_DIR_ENV: &str = "MCP_UPLOAD_DIR";
+
+/// Resolve a caller-supplied path under the policy configured in the environment.
+pub fn resolve_upload_path(path: &str) -> Result<PathBuf, String> {
+    let upload_dir = std::env::var(UPLOAD_DIR_ENV)
+        .ok()
+        .filter(|dir| !dir.trim().is_empty())
+        .map(PathBuf::from);
+    let http_mode = std::env::var("MCP_TRANSPORT").is_ok_and(|mode| mode == "http");
+    resolve_upload_path_with(path, upload_dir.as_deref(), http_mode)
+}
+
+/// Canonical path of an existing regular file the policy allows reading.
+///
+/// With an upload directory the file must resolve inside it (symlinks are
+/// followed before the check). Without one, HTTP mode refuses every path.
+pub fn resolve_upload_path_with(
+    path: &str,
+    upload_dir: Option<&Path>,
+    http_mode: bool,
+) -> Result<PathBuf, String> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err("file path is empty".into());
+    }
+    let requested = match upload_dir {
+        Some(dir) => dir.join(path),
+        None if http_mode => {
+            return Err(format!(
+                "importing local files is disabled in HTTP mode; set {UPLOAD_DIR_ENV} to allow uploads from one directory"
+            ));
+        }
+        None => PathBuf::from(path),
+    };
+    let resolved = requested
+        .canonicalize()
+        .map_err(|e| format!("cannot open {path}: {e}"))?;
+    if let Some(dir) = upload_dir {
+        let dir = dir
+            .canonicalize()
+            .map_err(|e| format!("{UPLOAD_DIR_ENV} {} is not usable: {e}", dir.display()))?;
+        if !resolved.starts_with(&dir) {
+            return Err(format!("{path} is outside {UPLOAD_DIR_ENV}"));
+        }
+    }
+    if !resolved.is_file() {
+        return Err(format!("{path} is not a file"));
+    }
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stdio_mode_reads_any_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("ride.fit");
+        std::fs::write(&file, b"fit").unwrap();
+
+        let resolved = resolve_upload_path_with(file.to_str().unwrap(), None, false).unwrap();
+        assert_eq!(resolved, file.canonicalize().unwrap());
+        assert!(resolve_upload_path_with(dir.path().to_str().unwrap(), None, false).is_err());
+        assert!(resolve_upload_path_with("  ", None, false).is_err());
+    }
+
+    #[test]
+    fn http_mode_requires_an_upload_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("ride.fit");
+        std::fs::write(&file, b"fit").unwrap();
+
+        let err = resolve_upload_path_with(file.to_str().unwrap(), None, true).unwrap_err();
+        assert!(err.contains(UPLOAD_DIR_ENV));
+        assert!(resolve_upload_path_with("ride.fit", Some(dir.path()), true).is_ok());
+    }
+
+    #[test]
+    fn upload_dir_rejects_paths_that_escape_it() {
+        let root = tempfile::tempdir().unwrap();
+        let uploads = root.path().join("uploads");
+        std::fs::create_dir(&uploads).unwrap();
+        std::fs::write(root.path().join("secret.fit"), b"fit").unwrap();
+
+        let err = resolve_upload_path_with("../secret.fit", Some(&uploads), false).unwrap_err();
+        assert!(err.contains("outside"));
+        let absolute = root.path().join("secret.fit");
+        assert!(
+            resolve_upload_path_with(absolute.to_str().unwrap(), Some(&uploads), false).is_err()
+        );
+    }
+}
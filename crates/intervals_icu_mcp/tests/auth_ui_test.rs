@@ -13,6 +13,7 @@ fn test_ui_state() -> UiState {
         jwt_manager,
         jwt_ttl_seconds: 3600,
         base_url: "https://intervals.icu".to_string(),
+        allow_api_keys: true,
     });
 
     UiState::new(app_state, None)
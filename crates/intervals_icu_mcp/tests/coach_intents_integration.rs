@@ -96,6 +96,7 @@ impl MockCoachClient {
             category: intervals_icu_client::EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         }
     }
 
@@ -137,6 +138,7 @@ impl MockCoachClient {
                 category: intervals_icu_client::EventCategory::RaceA,
                 description: Some("Planned race target".to_string()),
                 r#type: Some("Race".to_string()),
+                end_date_local: None,
             }],
             fitness: Self::fitness_snapshot(42.0, 68.0, -18.0),
             wellness: json!([
@@ -524,6 +526,7 @@ impl MockCoachClient {
                 category: intervals_icu_client::EventCategory::RaceA,
                 description: Some("Goal marathon plan".to_string()),
                 r#type: Some("Race".to_string()),
+                end_date_local: None,
             }],
             fitness: Self::fitness_snapshot(45.0, 60.0, -8.0),
             activity_details: json!({
@@ -5,13 +5,14 @@
 //! - Path parameter substitution
 //! - Query parameter encoding
 //! - JSON body handling
+//! - Multipart file uploads
 //! - Response parsing and compact mode
 //! - Error handling
 
 use intervals_icu_mcp::dynamic::{DynamicOperation, ParamLocation, ParamSpec};
 use rmcp::model::JsonObject;
 use serde_json::json;
-use wiremock::matchers::{method, path, query_param};
+use wiremock::matchers::{body_string_contains, header_regex, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Helper to create a test DynamicOperation
@@ -253,6 +254,80 @@ async fn test_dispatch_post_with_json_body() {
     assert!(!response.content.is_empty());
 }
 
+#[tokio::test]
+async fn test_dispatch_multipart_streams_local_file() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/athlete/test_athlete/activities"))
+        .and(query_param("name", "Lunch run"))
+        .and(header_regex("content-type", "^multipart/form-data"))
+        .and(body_string_contains("filename=\"run.tcx\""))
+        .and(body_string_contains("<TrainingCenterDatabase/>"))
+        .and(body_string_contains("from the watch"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({"id": "i77"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("run.tcx");
+    std::fs::write(&file, "<TrainingCenterDatabase/>").unwrap();
+
+    let mut operation = with_query_param(
+        with_path_param(
+            create_test_operation(
+                "uploadActivity",
+                reqwest::Method::POST,
+                "/api/v1/athlete/{id}/activities",
+            ),
+            "id",
+            true,
+        ),
+        "name",
+    );
+    for (name, location) in [
+        ("file", ParamLocation::FormFile),
+        ("comment", ParamLocation::FormField),
+    ] {
+        operation.params.push(ParamSpec {
+            name: name.to_string(),
+            location,
+            auto_injected: false,
+        });
+    }
+
+    let args = json!({
+        "name": "Lunch run",
+        "file": file.to_str().unwrap(),
+        "comment": "from the watch"
+    });
+    let result = intervals_icu_mcp::dynamic::dispatch_operation(
+        &reqwest::Client::new(),
+        &mock_server.uri(),
+        "test_athlete",
+        "test_api_key",
+        &operation,
+        args.as_object(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(result.structured_content, Some(json!({"id": "i77"})));
+
+    let missing = json!({"file": dir.path().join("absent.fit").to_str().unwrap()});
+    let err = intervals_icu_mcp::dynamic::dispatch_operation(
+        &reqwest::Client::new(),
+        &mock_server.uri(),
+        "test_athlete",
+        "test_api_key",
+        &operation,
+        missing.as_object(),
+    )
+    .await
+    .unwrap_err();
+    assert!(err.message.contains("absent.fit"));
+}
+
 #[tokio::test]
 async fn test_dispatch_auto_injected_athlete_id() {
     let mock_server = MockServer::start().await;
@@ -12,6 +12,7 @@ fn mock_event(event_id: Option<&str>) -> intervals_icu_client::Event {
         category: intervals_icu_client::EventCategory::Workout,
         description: None,
         r#type: None,
+        end_date_local: None,
     }
 }
 
@@ -78,6 +78,7 @@ impl IntervalsClient for E2eMockClient {
                 category: intervals_icu_client::EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
             intervals_icu_client::Event {
                 id: Some("ev-2".to_string()),
@@ -86,6 +87,7 @@ impl IntervalsClient for E2eMockClient {
                 category: intervals_icu_client::EventCategory::Workout,
                 description: None,
                 r#type: None,
+                end_date_local: None,
             },
         ])
     }
@@ -108,6 +110,7 @@ impl IntervalsClient for E2eMockClient {
             category: intervals_icu_client::EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         })
     }
     async fn delete_event(&self, _: &str) -> Result<(), intervals_icu_client::IntervalsError> {
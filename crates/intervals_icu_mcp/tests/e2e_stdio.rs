@@ -153,6 +153,11 @@ async fn e2e_stdio_lists_tools_and_calls_profile() {
         "manage_gear",
         "analyze_race",
         "track_progress",
+        "log_wellness",
+        "annotate_activity",
+        "review_season",
+        "review_roster",
+        "import_activity",
     ];
 
     for tool in &tools.tools {
@@ -222,8 +227,8 @@ async fn e2e_stdio_lists_tools_and_calls_profile() {
         .map(|t| t.name.to_string())
         .collect();
 
-    // Verify only 9 intent tools are exposed (no dynamic OpenAPI tools)
-    assert_eq!(names.len(), 9, "Should have exactly 9 intent tools");
+    // Verify only 14 intent tools are exposed (no dynamic OpenAPI tools)
+    assert_eq!(names.len(), 14, "Should have exactly 14 intent tools");
     for expected_name in expected_tool_names {
         assert!(
             names.iter().any(|name| name == expected_name),
@@ -0,0 +1,146 @@
+//! Multi-step intent flows against the stateful `FakeIntervalsClient`.
+
+use std::sync::Arc;
+
+use chrono::{Duration, Local};
+use intervals_icu_client::IntervalsClient;
+use intervals_icu_client::fake::FakeIntervalsClient;
+use intervals_icu_mcp::intents::IntentHandler;
+use intervals_icu_mcp::intents::handlers::{
+    AnalyzeTrainingHandler, LogWellnessHandler, ModifyTrainingHandler, PlanTrainingHandler,
+};
+use serde_json::{Value, json};
+
+fn day(offset: i64) -> String {
+    (Local::now().date_naive() + Duration::days(offset)).to_string()
+}
+
+/// Two weeks of history ending yesterday, with "today" pinned to the local date
+/// because the intents resolve relative dates against the system clock.
+fn fake_client() -> Arc<FakeIntervalsClient> {
+    let activities = (1..=14)
+        .map(|offset| {
+            json!({
+                "id": format!("i{offset}"),
+                "name": if offset % 7 == 1 { "Long Run" } else { "Easy Run" },
+                "type": "Run",
+                "start_date_local": format!("{}T07:00:00", day(-offset)),
+                "start_date": format!("{}T06:00:00Z", day(-offset)),
+                "moving_time": if offset % 7 == 1 { 6000 } else { 2700 },
+                "distance": if offset % 7 == 1 { 18000.0 } else { 8000.0 },
+                "average_heartrate": 142,
+                "icu_training_load": if offset % 7 == 1 { 110 } else { 45 },
+            })
+        })
+        .collect::<Vec<_>>();
+    let fixtures = json!({
+        "athlete_id": "i42",
+        "today": day(0),
+        "activities": activities,
+        "wellness": [{"id": day(-1), "restingHR": 48, "hrv": 70}],
+        "sport_settings": [{"id": 1, "types": ["Run"], "lthr": 165, "threshold_pace": 4.2}],
+    });
+    Arc::new(FakeIntervalsClient::from_json(&fixtures.to_string()).expect("valid fixtures"))
+}
+
+fn text(content: &[intervals_icu_mcp::intents::ContentBlock]) -> String {
+    format!("{content:?}")
+}
+
+async fn planned_events(client: &FakeIntervalsClient) -> Vec<Value> {
+    client
+        .get_upcoming_workouts(Some(30), None, None)
+        .await
+        .unwrap()
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn plan_modify_then_analyze_uses_written_state() {
+    let client = fake_client();
+    let dyn_client: Arc<dyn IntervalsClient> = client.clone();
+
+    PlanTrainingHandler::new()
+        .execute(
+            json!({
+                "period_start": day(1),
+                "period_end": day(14),
+                "focus": "aerobic_base",
+                "max_hours_per_week": 6,
+                "idempotency_token": "plan-1",
+            }),
+            dyn_client.clone(),
+            None,
+        )
+        .await
+        .expect("plan_training");
+    let planned = planned_events(&client).await;
+    assert!(!planned.is_empty(), "plan_training created no events");
+
+    let first_date = planned[0]["start_date_local"].as_str().unwrap()[..10].to_string();
+    let output = ModifyTrainingHandler::new()
+        .execute(
+            json!({
+                "action": "modify",
+                "target_date": first_date,
+                "new_name": "Moved Session",
+                "new_description": "Shortened: easy 30 min shakeout",
+                "idempotency_token": "modify-1",
+            }),
+            dyn_client.clone(),
+            None,
+        )
+        .await
+        .expect("modify_training");
+    assert!(!output.content.is_empty());
+    assert!(
+        planned_events(&client)
+            .await
+            .iter()
+            .any(|event| event["name"] == "Moved Session")
+    );
+
+    let analysis = AnalyzeTrainingHandler::new()
+        .execute(
+            json!({
+                "target_type": "period",
+                "period_start": day(-14),
+                "period_end": day(14),
+            }),
+            dyn_client,
+            None,
+        )
+        .await
+        .expect("analyze_training");
+    let rendered = text(&analysis.content);
+    assert!(rendered.contains("Planned Workouts"), "{rendered}");
+    assert!(
+        rendered.contains("Shortened: easy 30 min shakeout"),
+        "{rendered}"
+    );
+    assert!(rendered.contains("Fitness Snapshot"), "{rendered}");
+}
+
+#[tokio::test]
+async fn logged_wellness_is_visible_to_later_reads() {
+    let client = fake_client();
+    LogWellnessHandler::new()
+        .execute(
+            json!({
+                "date": day(0),
+                "resting_hr": 52,
+                "idempotency_token": "wellness-1",
+            }),
+            client.clone(),
+            None,
+        )
+        .await
+        .expect("log_wellness");
+
+    let entry = client.get_wellness_for_date(&day(0)).await.unwrap();
+    assert_eq!(entry["restingHR"], 52);
+    let recent = client.get_wellness(Some(7)).await.unwrap();
+    assert_eq!(recent.as_array().unwrap().len(), 2);
+}
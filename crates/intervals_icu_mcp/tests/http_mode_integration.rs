@@ -42,6 +42,7 @@ fn mock_event(event_id: Option<&str>) -> intervals_icu_client::Event {
         category: intervals_icu_client::EventCategory::Workout,
         description: None,
         r#type: None,
+        end_date_local: None,
     }
 }
 
@@ -846,6 +847,7 @@ async fn test_auth_endpoint_issues_jwt_for_valid_credentials() {
         jwt_manager: jwt_manager.clone(),
         jwt_ttl_seconds: 3600,
         base_url: mock_server.uri(),
+        allow_api_keys: true,
     });
 
     let app = axum::Router::new()
@@ -895,7 +897,10 @@ async fn test_auth_endpoint_issues_jwt_for_valid_credentials() {
 
     let credentials = jwt_manager.verify_token(token).expect("jwt should verify");
     assert_eq!(credentials.athlete_id, "i123456");
-    assert_eq!(credentials.api_key.expose_secret(), "test_api_key");
+    assert_eq!(
+        credentials.credentials.secret().expose_secret(),
+        "test_api_key"
+    );
 }
 
 #[tokio::test]
@@ -60,7 +60,7 @@ async fn test_handler_initialization_with_mock_client() {
     .expect("new");
     let handler = intervals_icu_mcp::IntervalsMcpHandler::new(Arc::new(client));
 
-    assert_eq!(handler.tool_count(), 9);
+    assert_eq!(handler.tool_count(), 14);
 }
 
 #[tokio::test]
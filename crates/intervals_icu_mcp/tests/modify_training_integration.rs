@@ -182,6 +182,7 @@ impl IntervalsClient for TrackingMockClient {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         })
     }
 
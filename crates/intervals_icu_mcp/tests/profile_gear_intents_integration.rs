@@ -95,6 +95,7 @@ impl IntervalsClient for MutationTrackingClient {
             category: EventCategory::Workout,
             description: None,
             r#type: None,
+            end_date_local: None,
         })
     }
 
@@ -4,6 +4,7 @@ use intervals_icu_client::ActivitySummary;
 use intervals_icu_mcp::domains::coach::AnalysisWindow;
 use intervals_icu_mcp::domains::progress::{HypothesisDomain, TrendState};
 use intervals_icu_mcp::engines::progress_tracking::build_progress_report;
+use intervals_icu_mcp::engines::session_rpe::SrpeCalibration;
 use serde_json::json;
 
 #[test]
@@ -66,7 +67,13 @@ fn full_report_detects_flat_tail_and_volume_hypothesis() {
         chrono::NaiveDate::from_ymd_opt(2026, 2, 25).unwrap(),
     );
 
-    let report = build_progress_report(&wellness, &activities, &details, &window);
+    let report = build_progress_report(
+        &wellness,
+        &activities,
+        &details,
+        &window,
+        &SrpeCalibration::default(),
+    );
     assert!(report.plateau.plateau_detected);
     assert_eq!(report.plateau.trend, TrendState::Flat);
     assert!(!report.hypotheses.is_empty());
@@ -118,7 +125,13 @@ fn report_degrades_gracefully_without_activity_details() {
         chrono::NaiveDate::from_ymd_opt(2026, 1, 28).unwrap(),
     );
 
-    let report = build_progress_report(&wellness, &activities, &HashMap::new(), &window);
+    let report = build_progress_report(
+        &wellness,
+        &activities,
+        &HashMap::new(),
+        &window,
+        &SrpeCalibration::default(),
+    );
     assert!(
         report
             .warnings
@@ -52,6 +52,7 @@ impl IntervalsClient for MockClient {
             category: EventCategory::Note,
             description: None,
             r#type: None,
+            end_date_local: None,
         })
     }
 
@@ -63,6 +64,7 @@ impl IntervalsClient for MockClient {
             category: EventCategory::Note,
             description: None,
             r#type: None,
+            end_date_local: None,
         })
     }
 
@@ -82,6 +84,7 @@ impl IntervalsClient for MockClient {
             category: EventCategory::Note,
             description: None,
             r#type: None,
+            end_date_local: None,
         }])
     }
 